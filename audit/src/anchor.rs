@@ -0,0 +1,488 @@
+//! Anchoring the audit log root in an external transparency log
+//!
+//! An [`Anchorer`] publishes a Merkle root and returns an [`AnchorProof`]:
+//! where it was published, when, the handle the log assigned, and a
+//! signature over the [anchor hash](AnchorProof::anchor_hash). Proofs are
+//! plain JSON and can be checked later with [`verify_anchor`].
+//!
+//! [`HttpAnchorer`] POSTs to a transparency log endpoint, which replies with
+//! its entry id and signature; [`FileAnchorer`] appends self-signed proofs
+//! to a local JSON Lines file. Roots wait in an [`AnchorQueue`] until
+//! anchored, so an unreachable endpoint delays an anchor rather than losing
+//! it; a queue opened on a file survives restarts.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use sap4d::hashing::FieldHasher;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::fs::{self, OpenOptions};
+use std::io::{self, BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+use thiserror::Error;
+
+/// Domain tag of anchor hashes
+const ANCHOR_DOMAIN: &str = "axiom-audit/anchor";
+
+/// Default timeout for publishing to a transparency log
+pub const DEFAULT_ANCHOR_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Why an anchor attempt failed (the root stays queued)
+#[derive(Error, Debug)]
+pub enum AnchorError {
+    #[error("Transparency log unreachable: {0}")]
+    Network(String),
+
+    #[error("Transparency log rejected the anchor: HTTP {status}: {body}")]
+    Rejected { status: u16, body: String },
+
+    #[error("Invalid transparency log response: {0}")]
+    InvalidResponse(String),
+
+    #[error("Anchor file error: {0}")]
+    Io(String),
+}
+
+/// What the anchored root covers
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnchorMetadata {
+    /// Log entries (sealed and live) under the root
+    pub entry_count: u64,
+    /// When the root was taken and queued
+    pub queued_at: DateTime<Utc>,
+}
+
+impl AnchorMetadata {
+    /// Metadata for a root over `entry_count` entries, taken now
+    pub fn new(entry_count: u64) -> Self {
+        Self {
+            entry_count,
+            queued_at: sap4d::clock::now(),
+        }
+    }
+}
+
+/// Evidence that a root was published
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AnchorProof {
+    /// The anchored audit log root
+    pub root_hash: String,
+    pub metadata: AnchorMetadata,
+    /// Where the root was published (`https://…` or `file://…`)
+    pub endpoint: String,
+    /// When the root was published
+    pub timestamp: DateTime<Utc>,
+    /// Id the transparency log assigned to the entry
+    pub response_id: String,
+    /// Hash over root, metadata, endpoint and timestamp
+    pub anchor_hash: String,
+    /// The publisher's signature over `anchor_hash`
+    pub signature: String,
+}
+
+impl AnchorProof {
+    /// Anchor hash for publishing `root_hash` to `endpoint` at `timestamp`
+    pub fn compute_hash(
+        root_hash: &str,
+        metadata: &AnchorMetadata,
+        endpoint: &str,
+        timestamp: &DateTime<Utc>,
+    ) -> String {
+        FieldHasher::new(ANCHOR_DOMAIN)
+            .str(root_hash)
+            .u64(metadata.entry_count)
+            .str(&metadata.queued_at.to_rfc3339())
+            .str(endpoint)
+            .str(&timestamp.to_rfc3339())
+            .finish()
+    }
+
+    /// Whether `anchor_hash` matches the proof's fields
+    pub fn verify_hash(&self) -> bool {
+        Self::compute_hash(&self.root_hash, &self.metadata, &self.endpoint, &self.timestamp) == self.anchor_hash
+    }
+}
+
+/// Check that `proof` anchors `root_hash`, is intact, and carries a valid
+/// publisher signature (`verify_fn(anchor_hash, signature)`)
+pub fn verify_anchor(proof: &AnchorProof, root_hash: &str, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+    proof.root_hash == root_hash && proof.verify_hash() && verify_fn(&proof.anchor_hash, &proof.signature)
+}
+
+/// Publishes audit log roots
+pub trait Anchorer: Send + Sync {
+    /// Publish `root_hash`, returning the proof of publication
+    fn anchor(&self, root_hash: &str, metadata: &AnchorMetadata) -> Result<AnchorProof, AnchorError>;
+}
+
+/// Anchors to a transparency log over HTTP(S)
+///
+/// POSTs `{root_hash, anchor_hash, entry_count, queued_at, timestamp}` as
+/// JSON and expects `{"id": …, "signature": …}` back, the signature being
+/// the log's over `anchor_hash`. Plain `http://` is accepted for local logs.
+#[derive(Debug, Clone)]
+pub struct HttpAnchorer {
+    endpoint: String,
+    timeout: Duration,
+}
+
+/// Transparency log reply
+#[derive(Deserialize)]
+struct AnchorReply {
+    id: String,
+    signature: String,
+}
+
+impl HttpAnchorer {
+    /// Anchor to `endpoint`
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            timeout: DEFAULT_ANCHOR_TIMEOUT,
+        }
+    }
+
+    /// Give up on a request after `timeout`
+    pub fn with_timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+}
+
+impl Anchorer for HttpAnchorer {
+    fn anchor(&self, root_hash: &str, metadata: &AnchorMetadata) -> Result<AnchorProof, AnchorError> {
+        let timestamp = sap4d::clock::now();
+        let anchor_hash = AnchorProof::compute_hash(root_hash, metadata, &self.endpoint, &timestamp);
+        let body = serde_json::json!({
+            "root_hash": root_hash,
+            "anchor_hash": anchor_hash,
+            "entry_count": metadata.entry_count,
+            "queued_at": metadata.queued_at,
+            "timestamp": timestamp,
+        });
+
+        let response = match ureq::post(&self.endpoint)
+            .timeout(self.timeout)
+            .set("Content-Type", "application/json")
+            .send_string(&body.to_string())
+        {
+            Ok(response) => response,
+            Err(ureq::Error::Status(status, response)) => {
+                return Err(AnchorError::Rejected {
+                    status,
+                    body: response.into_string().unwrap_or_default(),
+                })
+            }
+            Err(e) => return Err(AnchorError::Network(e.to_string())),
+        };
+        let text = response
+            .into_string()
+            .map_err(|e| AnchorError::Network(e.to_string()))?;
+        let reply: AnchorReply =
+            serde_json::from_str(&text).map_err(|e| AnchorError::InvalidResponse(e.to_string()))?;
+
+        Ok(AnchorProof {
+            root_hash: root_hash.to_string(),
+            metadata: metadata.clone(),
+            endpoint: self.endpoint.clone(),
+            timestamp,
+            response_id: reply.id,
+            anchor_hash,
+            signature: reply.signature,
+        })
+    }
+}
+
+/// Anchors to a local JSON Lines file, one self-signed proof per line
+pub struct FileAnchorer<F> {
+    path: PathBuf,
+    sign_fn: F,
+}
+
+impl<F> FileAnchorer<F>
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    /// Append proofs to `path`, signing anchor hashes with `sign_fn`
+    pub fn new(path: impl Into<PathBuf>, sign_fn: F) -> Self {
+        Self {
+            path: path.into(),
+            sign_fn,
+        }
+    }
+
+    fn endpoint(&self) -> String {
+        format!("file://{}", self.path.display())
+    }
+}
+
+impl<F> Anchorer for FileAnchorer<F>
+where
+    F: Fn(&str) -> String + Send + Sync,
+{
+    fn anchor(&self, root_hash: &str, metadata: &AnchorMetadata) -> Result<AnchorProof, AnchorError> {
+        let io_error = |e: io::Error| AnchorError::Io(format!("{}: {}", self.path.display(), e));
+        let sequence = match load_anchor_file(&self.path) {
+            Ok(proofs) => proofs.len(),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => 0,
+            Err(e) => return Err(io_error(e)),
+        };
+
+        let endpoint = self.endpoint();
+        let timestamp = sap4d::clock::now();
+        let anchor_hash = AnchorProof::compute_hash(root_hash, metadata, &endpoint, &timestamp);
+        let proof = AnchorProof {
+            root_hash: root_hash.to_string(),
+            metadata: metadata.clone(),
+            endpoint,
+            timestamp,
+            response_id: format!("local-{:06}", sequence),
+            signature: (self.sign_fn)(&anchor_hash),
+            anchor_hash,
+        };
+
+        let line = serde_json::to_string(&proof).map_err(|e| AnchorError::Io(e.to_string()))?;
+        let mut file = OpenOptions::new().create(true).append(true).open(&self.path).map_err(io_error)?;
+        writeln!(file, "{}", line).and_then(|_| file.sync_data()).map_err(io_error)?;
+        Ok(proof)
+    }
+}
+
+/// Read every proof from a [`FileAnchorer`] file
+pub fn load_anchor_file(path: impl AsRef<Path>) -> io::Result<Vec<AnchorProof>> {
+    let file = fs::File::open(path)?;
+    let mut proofs = Vec::new();
+    for line in BufReader::new(file).lines() {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        proofs.push(serde_json::from_str(&line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?);
+    }
+    Ok(proofs)
+}
+
+/// A root waiting to be anchored
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PendingAnchor {
+    pub root_hash: String,
+    pub metadata: AnchorMetadata,
+    /// Failed attempts so far
+    pub attempts: u32,
+    /// Error from the last failed attempt
+    pub last_error: Option<String>,
+}
+
+/// Outcome of [`AnchorQueue::flush`]
+#[derive(Debug, Default)]
+pub struct FlushReport {
+    /// Proofs for the roots anchored by this flush, oldest first
+    pub anchored: Vec<AnchorProof>,
+    /// Why the flush stopped early; the failed root and those after it stay queued
+    pub error: Option<AnchorError>,
+}
+
+/// Roots waiting to be anchored, oldest first
+///
+/// A queue opened on a file rewrites it (atomically) after every change.
+#[derive(Debug, Default)]
+pub struct AnchorQueue {
+    pending: VecDeque<PendingAnchor>,
+    path: Option<PathBuf>,
+}
+
+impl AnchorQueue {
+    /// A queue that lives only as long as the process
+    pub fn in_memory() -> Self {
+        Self::default()
+    }
+
+    /// A queue persisted at `path`, resuming any roots left there
+    pub fn open(path: impl Into<PathBuf>) -> io::Result<Self> {
+        let path = path.into();
+        let pending = match fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => VecDeque::new(),
+            Err(e) => return Err(e),
+        };
+        Ok(Self {
+            pending,
+            path: Some(path),
+        })
+    }
+
+    /// Queue `root_hash` (a root equal to the last one queued is not queued twice)
+    pub fn push(&mut self, root_hash: &str, metadata: AnchorMetadata) -> io::Result<()> {
+        if self.pending.back().is_some_and(|last| last.root_hash == root_hash) {
+            return Ok(());
+        }
+        self.pending.push_back(PendingAnchor {
+            root_hash: root_hash.to_string(),
+            metadata,
+            attempts: 0,
+            last_error: None,
+        });
+        self.persist()
+    }
+
+    /// Anchor queued roots in order, stopping at the first failure
+    pub fn flush(&mut self, anchorer: &dyn Anchorer) -> FlushReport {
+        let mut report = FlushReport::default();
+        while let Some(next) = self.pending.front_mut() {
+            match anchorer.anchor(&next.root_hash, &next.metadata) {
+                Ok(proof) => {
+                    self.pending.pop_front();
+                    report.anchored.push(proof);
+                }
+                Err(e) => {
+                    next.attempts += 1;
+                    next.last_error = Some(e.to_string());
+                    report.error = Some(e);
+                    break;
+                }
+            }
+        }
+        if let Err(e) = self.persist() {
+            if report.error.is_none() {
+                report.error = Some(AnchorError::Io(e.to_string()));
+            }
+        }
+        report
+    }
+
+    /// Roots still waiting, oldest first
+    pub fn pending(&self) -> &VecDeque<PendingAnchor> {
+        &self.pending
+    }
+
+    fn persist(&self) -> io::Result<()> {
+        let Some(path) = &self.path else {
+            return Ok(());
+        };
+        let json = serde_json::to_string_pretty(&self.pending)?;
+        let mut tmp = path.as_os_str().to_owned();
+        tmp.push(".tmp");
+        fs::write(&tmp, json)?;
+        fs::rename(&tmp, path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    use std::net::TcpListener;
+
+    fn sign(hash: &str) -> String {
+        format!("TEST_SIG:{}", hash)
+    }
+
+    fn verify(hash: &str, signature: &str) -> bool {
+        sign(hash) == signature
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("audit-anchor-{}-{}", std::process::id(), name));
+        let _ = fs::remove_file(&path);
+        path
+    }
+
+    /// A transparency log answering one request with `status` and `body`
+    fn one_shot_log(status: &'static str, body: &'static str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let url = format!("http://{}/anchor", listener.local_addr().unwrap());
+        std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut request = vec![0; 8192];
+            let _ = stream.read(&mut request);
+            let response = format!(
+                "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                status,
+                body.len(),
+                body
+            );
+            let _ = stream.write_all(response.as_bytes());
+        });
+        url
+    }
+
+    #[test]
+    fn test_file_anchor_round_trip() {
+        let path = scratch("anchors.jsonl");
+        let anchorer = FileAnchorer::new(&path, sign);
+
+        let first = anchorer.anchor("root-a", &AnchorMetadata::new(3)).unwrap();
+        let second = anchorer.anchor("root-b", &AnchorMetadata::new(7)).unwrap();
+        assert_eq!(first.response_id, "local-000000");
+        assert_eq!(second.response_id, "local-000001");
+        assert!(first.endpoint.starts_with("file://"));
+
+        let stored = load_anchor_file(&path).unwrap();
+        assert_eq!(stored, vec![first.clone(), second]);
+        assert!(verify_anchor(&stored[0], "root-a", verify));
+        assert!(!verify_anchor(&stored[0], "root-b", verify));
+        assert!(!verify_anchor(&stored[0], "root-a", |_, _| false));
+
+        let mut tampered = first;
+        tampered.metadata.entry_count = 4;
+        assert!(!verify_anchor(&tampered, "root-a", verify));
+        let _ = fs::remove_file(path);
+    }
+
+    #[test]
+    fn test_http_anchor() {
+        let endpoint = one_shot_log("200 OK", r#"{"id":"tlog-42","signature":"log-signature"}"#);
+        let proof = HttpAnchorer::new(&endpoint).anchor("root-a", &AnchorMetadata::new(5)).unwrap();
+        assert_eq!(proof.response_id, "tlog-42");
+        assert_eq!(proof.endpoint, endpoint);
+        assert!(verify_anchor(&proof, "root-a", |hash, sig| hash == proof.anchor_hash && sig == "log-signature"));
+
+        let endpoint = one_shot_log("503 Service Unavailable", "down for maintenance");
+        match HttpAnchorer::new(endpoint).anchor("root-a", &AnchorMetadata::new(5)) {
+            Err(AnchorError::Rejected { status, body }) => assert_eq!((status, body.as_str()), (503, "down for maintenance")),
+            other => panic!("expected rejection, got {:?}", other),
+        }
+
+        let endpoint = one_shot_log("200 OK", r#"{"unexpected":true}"#);
+        let result = HttpAnchorer::new(endpoint).anchor("root-a", &AnchorMetadata::new(5));
+        assert!(matches!(result, Err(AnchorError::InvalidResponse(_))));
+    }
+
+    #[test]
+    fn test_queue_keeps_roots_until_anchored() {
+        let path = scratch("queue.json");
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let unreachable = HttpAnchorer::new(format!("http://{}/anchor", listener.local_addr().unwrap()))
+            .with_timeout(Duration::from_millis(500));
+        drop(listener);
+
+        let mut queue = AnchorQueue::open(&path).unwrap();
+        queue.push("root-a", AnchorMetadata::new(2)).unwrap();
+        queue.push("root-a", AnchorMetadata::new(2)).unwrap();
+        queue.push("root-b", AnchorMetadata::new(4)).unwrap();
+
+        let report = queue.flush(&unreachable);
+        assert!(report.anchored.is_empty());
+        assert!(matches!(report.error, Some(AnchorError::Network(_))));
+        assert_eq!(queue.pending().len(), 2);
+        assert_eq!(queue.pending()[0].attempts, 1);
+        assert!(queue.pending()[0].last_error.is_some());
+
+        // A restart resumes the same roots
+        let mut resumed = AnchorQueue::open(&path).unwrap();
+        assert_eq!(resumed.pending(), queue.pending());
+
+        let anchors = scratch("queue-anchors.jsonl");
+        let report = resumed.flush(&FileAnchorer::new(&anchors, sign));
+        assert!(report.error.is_none());
+        let roots: Vec<&str> = report.anchored.iter().map(|p| p.root_hash.as_str()).collect();
+        assert_eq!(roots, vec!["root-a", "root-b"]);
+        assert!(resumed.pending().is_empty());
+        assert!(AnchorQueue::open(&path).unwrap().pending().is_empty());
+
+        let _ = fs::remove_file(path);
+        let _ = fs::remove_file(anchors);
+    }
+}
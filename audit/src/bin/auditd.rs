@@ -0,0 +1,48 @@
+//! Audit daemon: the audit service over HTTP
+//!
+//! Routes are documented in [`axiom_audit::http`]. Configured from the
+//! environment:
+//!
+//! - `AUDITD_SIGNING_KEY_FILE` (required): file holding the receipt signing key
+//! - `AUDIT_PORT` (default 3001): port to listen on at 127.0.0.1
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use anyhow::Context;
+use axiom_audit::http::{router, AuditdState, KeySigner};
+use axiom_audit::service::AuditConfig;
+use std::path::PathBuf;
+use std::sync::Arc;
+use tower_http::cors::CorsLayer;
+use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
+
+/// Environment variable naming the signing key file
+const KEY_FILE_VAR: &str = "AUDITD_SIGNING_KEY_FILE";
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    tracing_subscriber::registry()
+        .with(tracing_subscriber::fmt::layer().json())
+        .with(tracing_subscriber::EnvFilter::from_default_env())
+        .init();
+
+    tracing::info!("[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]");
+    tracing::info!("Starting audit daemon v{}", env!("CARGO_PKG_VERSION"));
+
+    let key_file = PathBuf::from(std::env::var(KEY_FILE_VAR).with_context(|| format!("{} is not set", KEY_FILE_VAR))?);
+    let signer = KeySigner::from_file(&key_file)
+        .with_context(|| format!("failed to read signing key from {}", key_file.display()))?;
+
+    let state = Arc::new(AuditdState::new(AuditConfig::default(), signer));
+    let app = router(state).layer(CorsLayer::permissive());
+
+    let port = std::env::var("AUDIT_PORT").unwrap_or_else(|_| "3001".to_string());
+    let addr = format!("127.0.0.1:{}", port);
+    let listener = tokio::net::TcpListener::bind(&addr)
+        .await
+        .with_context(|| format!("failed to bind {}", addr))?;
+
+    tracing::info!("Audit daemon listening on {}", addr);
+    axum::serve(listener, app).await.context("audit daemon stopped")?;
+    Ok(())
+}
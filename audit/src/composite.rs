@@ -0,0 +1,171 @@
+//! Daily composite receipts
+//!
+//! Rolls a day's audit receipts into a single signed receipt over their
+//! Merkle root, so one signature covers the day and any individual receipt
+//! can be shown to be included with a Merkle inclusion proof.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, NaiveDate, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::audit::AuditReceipt;
+use crate::merkle::{MerkleProof, MerkleTree};
+use crate::{AuditError, Result};
+
+/// A signed composite over one day's audit receipts
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyComposite {
+    /// UTC day covered
+    pub date: NaiveDate,
+    /// Receipt hashes in composition order (Merkle leaves)
+    pub receipt_hashes: Vec<String>,
+    /// Merkle root over the receipt hashes
+    pub merkle_root: String,
+    /// Whether every constituent receipt proved its claim
+    pub all_proofs_exist: bool,
+    /// Whether every constituent receipt is C=0
+    pub c_zero: bool,
+    /// Hash of the composite contents
+    pub composite_hash: String,
+    /// Signature over the composite hash
+    pub signature: String,
+    /// Timestamp
+    pub timestamp: DateTime<Utc>,
+}
+
+impl DailyComposite {
+    /// Compose receipts issued on `date`; receipts from other days are rejected
+    pub fn compose(
+        date: NaiveDate,
+        receipts: &[AuditReceipt],
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<Self> {
+        if receipts.is_empty() {
+            return Err(AuditError::Internal(format!("No receipts to compose for {}", date)));
+        }
+
+        if let Some(other) = receipts.iter().find(|r| r.timestamp.date_naive() != date) {
+            return Err(AuditError::Internal(format!(
+                "Receipt {} was issued on {}, not {}",
+                other.receipt_hash,
+                other.timestamp.date_naive(),
+                date
+            )));
+        }
+
+        let receipt_hashes: Vec<String> = receipts.iter().map(|r| r.receipt_hash.clone()).collect();
+        let merkle_root = MerkleTree::from_data(&receipt_hashes)
+            .root_hash()
+            .map(str::to_string)
+            .unwrap_or_default();
+        let all_proofs_exist = receipts.iter().all(|r| r.proof_exists());
+        let c_zero = receipts.iter().all(|r| r.c_zero);
+        let timestamp = sap4d::clock::now();
+
+        let composite_hash = Self::compute_hash(&date, &merkle_root, all_proofs_exist, c_zero, &timestamp);
+        let signature = sign_fn(&composite_hash);
+
+        Ok(Self {
+            date,
+            receipt_hashes,
+            merkle_root,
+            all_proofs_exist,
+            c_zero,
+            composite_hash,
+            signature,
+            timestamp,
+        })
+    }
+
+    fn compute_hash(
+        date: &NaiveDate,
+        merkle_root: &str,
+        all_proofs_exist: bool,
+        c_zero: bool,
+        timestamp: &DateTime<Utc>,
+    ) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(date.to_string().as_bytes());
+        hasher.update(merkle_root.as_bytes());
+        hasher.update([all_proofs_exist as u8, c_zero as u8]);
+        hasher.update(timestamp.to_rfc3339().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Inclusion proof for a receipt hash
+    pub fn inclusion_proof(&self, receipt_hash: &str) -> Option<MerkleProof> {
+        let index = self.receipt_hashes.iter().position(|h| h == receipt_hash)?;
+        MerkleTree::from_data(&self.receipt_hashes).generate_proof(index)
+    }
+
+    /// Check an inclusion proof against this composite's root
+    pub fn verify_inclusion(&self, receipt_hash: &str, proof: &MerkleProof) -> bool {
+        let leaf = hex::encode(Sha256::digest(receipt_hash.as_bytes()));
+        proof.leaf_hash == leaf && proof.root_hash == self.merkle_root && proof.verify()
+    }
+
+    /// Verify the composite's hash integrity
+    pub fn verify_hash(&self) -> bool {
+        let root = MerkleTree::from_data(&self.receipt_hashes)
+            .root_hash()
+            .map(str::to_string)
+            .unwrap_or_default();
+        root == self.merkle_root
+            && Self::compute_hash(&self.date, &self.merkle_root, self.all_proofs_exist, self.c_zero, &self.timestamp)
+                == self.composite_hash
+    }
+
+    /// Full verification (hash + signature)
+    pub fn verify(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        self.verify_hash() && verify_fn(&self.composite_hash, &self.signature)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::AuditService;
+
+    fn mock_sign(hash: &str) -> String {
+        format!("SIG:{}", hash)
+    }
+
+    fn receipts(count: usize) -> Vec<AuditReceipt> {
+        let mut service = AuditService::new();
+        (0..count)
+            .map(|i| {
+                service
+                    .audit(&format!("Claim {}", i), &[format!("Claim {} evidence", i)], mock_sign)
+                    .unwrap()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compose_and_prove_inclusion() {
+        let receipts = receipts(3);
+        let date = receipts[0].timestamp.date_naive();
+        let composite = DailyComposite::compose(date, &receipts, mock_sign).unwrap();
+
+        assert!(composite.verify(|hash, sig| sig == mock_sign(hash)));
+        for receipt in &receipts {
+            let proof = composite.inclusion_proof(&receipt.receipt_hash).unwrap();
+            assert!(composite.verify_inclusion(&receipt.receipt_hash, &proof));
+        }
+
+        let proof = composite.inclusion_proof(&receipts[0].receipt_hash).unwrap();
+        assert!(!composite.verify_inclusion(&receipts[1].receipt_hash, &proof));
+        assert!(composite.inclusion_proof("unknown").is_none());
+    }
+
+    #[test]
+    fn test_rejects_other_days_and_empty() {
+        let receipts = receipts(1);
+        let other_day = receipts[0].timestamp.date_naive().succ_opt().unwrap();
+
+        assert!(DailyComposite::compose(other_day, &receipts, mock_sign).is_err());
+        assert!(DailyComposite::compose(other_day, &[], mock_sign).is_err());
+    }
+}
@@ -0,0 +1,573 @@
+//! Receipt corpora and flat exports for analytics
+//!
+//! A [`ReceiptCorpus`] is a set of audit receipts, either in memory or as a
+//! JSON Lines file (one receipt per line) read lazily, so exports hold one
+//! receipt at a time regardless of corpus size.
+//!
+//! ## Receipt schema (one row per receipt)
+//!
+//! | column | type | notes |
+//! |---|---|---|
+//! | `receipt_hash` | string | key for the findings file |
+//! | `timestamp` | string | RFC 3339 |
+//! | `final_proof` | bool | |
+//! | `c_zero` | bool | |
+//! | `l1_proof`, `l2_proof`, `l3_proof` | bool, nullable | empty when the level did not run |
+//! | `evidence_count` | int | evidence items audited at L1 |
+//! | `findings_count` | int | findings across all levels |
+//! | `code_no_evidence`, `code_empty_evidence_dropped` | int | finding-code counts |
+//! | `code_other` | int | codes not listed above |
+//! | `claim` | string | first [`CLAIM_MAX_CHARS`] characters |
+//! | `claim_truncated` | bool | |
+//! | `claim_sha256` | string | hex SHA-256 of the full claim |
+//!
+//! Receipts carry no policy or options fields yet; columns for them belong
+//! here once they do.
+//!
+//! ## Findings schema (long format, one row per finding or code)
+//!
+//! `receipt_hash`, `level` (`L1`..`L3`), `ordinal` (position within the
+//! level), `kind` (`finding` or `code`), `value`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sha2::{Digest, Sha256};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Write};
+use std::path::{Path, PathBuf};
+
+use crate::audit::AuditReceipt;
+use crate::levels::{codes, AuditLevel};
+use crate::{AuditError, Result};
+
+/// Longest claim prefix exported; `claim_sha256` covers the full claim
+pub const CLAIM_MAX_CHARS: usize = 200;
+
+/// Column value type
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ColumnKind {
+    Text,
+    Bool,
+    /// Nullable boolean
+    OptionalBool,
+    Int,
+}
+
+/// Receipt table columns, in export order
+pub const RECEIPT_COLUMNS: &[(&str, ColumnKind)] = &[
+    ("receipt_hash", ColumnKind::Text),
+    ("timestamp", ColumnKind::Text),
+    ("final_proof", ColumnKind::Bool),
+    ("c_zero", ColumnKind::Bool),
+    ("l1_proof", ColumnKind::OptionalBool),
+    ("l2_proof", ColumnKind::OptionalBool),
+    ("l3_proof", ColumnKind::OptionalBool),
+    ("evidence_count", ColumnKind::Int),
+    ("findings_count", ColumnKind::Int),
+    ("code_no_evidence", ColumnKind::Int),
+    ("code_empty_evidence_dropped", ColumnKind::Int),
+    ("code_other", ColumnKind::Int),
+    ("claim", ColumnKind::Text),
+    ("claim_truncated", ColumnKind::Bool),
+    ("claim_sha256", ColumnKind::Text),
+];
+
+/// Findings table columns, in export order
+pub const FINDING_COLUMNS: &[(&str, ColumnKind)] = &[
+    ("receipt_hash", ColumnKind::Text),
+    ("level", ColumnKind::Text),
+    ("ordinal", ColumnKind::Int),
+    ("kind", ColumnKind::Text),
+    ("value", ColumnKind::Text),
+];
+
+/// Finding codes with their own count column, in column order
+const COUNTED_CODES: &[&str] = &[codes::NO_EVIDENCE, codes::EMPTY_EVIDENCE_DROPPED];
+
+/// One exported value
+#[derive(Debug, Clone, PartialEq)]
+enum Cell {
+    Text(String),
+    Bool(bool),
+    OptionalBool(Option<bool>),
+    Int(i64),
+}
+
+impl Cell {
+    fn to_csv(&self) -> String {
+        match self {
+            Cell::Text(s) => s.clone(),
+            Cell::Bool(b) | Cell::OptionalBool(Some(b)) => b.to_string(),
+            Cell::OptionalBool(None) => String::new(),
+            Cell::Int(i) => i.to_string(),
+        }
+    }
+}
+
+/// Flatten a receipt into a `RECEIPT_COLUMNS` row
+fn receipt_row(receipt: &AuditReceipt) -> Vec<Cell> {
+    let level_proof = |level: AuditLevel| {
+        Cell::OptionalBool(
+            receipt
+                .results
+                .iter()
+                .find(|r| r.level == level)
+                .map(|r| r.proof.exists()),
+        )
+    };
+    let all_codes = || receipt.results.iter().flat_map(|r| &r.codes);
+    let code_count = |code: &str| Cell::Int(all_codes().filter(|c| *c == code).count() as i64);
+    let other_codes = all_codes().filter(|c| !COUNTED_CODES.contains(&c.as_str())).count();
+
+    let full_claim = receipt.results.first().map(|r| r.claim.as_str()).unwrap_or("");
+    let (claim, truncated) = truncate_claim(full_claim);
+    let evidence_count = receipt
+        .results
+        .iter()
+        .find(|r| r.level == AuditLevel::L1)
+        .map(|r| r.evidence.len())
+        .unwrap_or(0);
+    let findings_count: usize = receipt.results.iter().map(|r| r.findings.len()).sum();
+
+    let mut row = vec![
+        Cell::Text(receipt.receipt_hash.clone()),
+        Cell::Text(receipt.timestamp.to_rfc3339()),
+        Cell::Bool(receipt.proof_exists()),
+        Cell::Bool(receipt.c_zero),
+        level_proof(AuditLevel::L1),
+        level_proof(AuditLevel::L2),
+        level_proof(AuditLevel::L3),
+        Cell::Int(evidence_count as i64),
+        Cell::Int(findings_count as i64),
+    ];
+    row.extend(COUNTED_CODES.iter().map(|code| code_count(code)));
+    row.extend([
+        Cell::Int(other_codes as i64),
+        Cell::Text(claim.to_string()),
+        Cell::Bool(truncated),
+        Cell::Text(hex::encode(Sha256::digest(full_claim.as_bytes()))),
+    ]);
+    row
+}
+
+/// Flatten a receipt's findings and codes into `FINDING_COLUMNS` rows
+fn finding_rows(receipt: &AuditReceipt) -> impl Iterator<Item = Vec<Cell>> + '_ {
+    receipt.results.iter().flat_map(move |result| {
+        let level = format!("{:?}", result.level);
+        let findings = result.findings.iter().map(|f| ("finding", f));
+        let codes = result.codes.iter().map(|c| ("code", c));
+        findings
+            .chain(codes)
+            .enumerate()
+            .map(move |(ordinal, (kind, value))| {
+                vec![
+                    Cell::Text(receipt.receipt_hash.clone()),
+                    Cell::Text(level.clone()),
+                    Cell::Int(ordinal as i64),
+                    Cell::Text(kind.to_string()),
+                    Cell::Text(value.clone()),
+                ]
+            })
+    })
+}
+
+/// First `CLAIM_MAX_CHARS` characters of `claim`, and whether it was cut
+fn truncate_claim(claim: &str) -> (&str, bool) {
+    match claim.char_indices().nth(CLAIM_MAX_CHARS) {
+        Some((end, _)) => (&claim[..end], true),
+        None => (claim, false),
+    }
+}
+
+fn io_error(context: &str, e: impl std::fmt::Display) -> AuditError {
+    AuditError::Internal(format!("{}: {}", context, e))
+}
+
+/// Where a corpus's receipts come from
+#[derive(Debug, Clone)]
+enum Source {
+    Memory(Vec<AuditReceipt>),
+    JsonLines(PathBuf),
+}
+
+/// A set of audit receipts to export
+#[derive(Debug, Clone)]
+pub struct ReceiptCorpus {
+    source: Source,
+}
+
+impl ReceiptCorpus {
+    /// Corpus over receipts already in memory
+    pub fn from_receipts(receipts: Vec<AuditReceipt>) -> Self {
+        Self { source: Source::Memory(receipts) }
+    }
+
+    /// Corpus over a JSON Lines file, read one receipt at a time
+    pub fn open(path: impl Into<PathBuf>) -> Self {
+        Self { source: Source::JsonLines(path.into()) }
+    }
+
+    /// Receipts in corpus order
+    pub fn iter(&self) -> Result<Box<dyn Iterator<Item = Result<AuditReceipt>> + '_>> {
+        match &self.source {
+            Source::Memory(receipts) => Ok(Box::new(receipts.iter().cloned().map(Ok))),
+            Source::JsonLines(path) => {
+                let file = File::open(path).map_err(|e| io_error(&path.display().to_string(), e))?;
+                let lines = BufReader::new(file).lines().enumerate();
+                Ok(Box::new(lines.filter_map(move |(i, line)| {
+                    let location = format!("{}:{}", path.display(), i + 1);
+                    match line {
+                        Ok(line) if line.trim().is_empty() => None,
+                        Ok(line) => Some(
+                            AuditReceipt::from_json(&line).map_err(|e| io_error(&location, e)),
+                        ),
+                        Err(e) => Some(Err(io_error(&location, e))),
+                    }
+                })))
+            }
+        }
+    }
+
+    /// Write one CSV row per receipt (see the receipt schema); returns the row count
+    pub fn export_csv<W: Write>(&self, writer: W) -> Result<usize> {
+        let mut sink = CsvSink::new(writer, RECEIPT_COLUMNS)?;
+        let mut rows = 0;
+        for receipt in self.iter()? {
+            sink.write_row(receipt_row(&receipt?))?;
+            rows += 1;
+        }
+        sink.finish()?;
+        Ok(rows)
+    }
+
+    /// Write the long-format findings CSV; returns the row count
+    pub fn export_findings_csv<W: Write>(&self, writer: W) -> Result<usize> {
+        let mut sink = CsvSink::new(writer, FINDING_COLUMNS)?;
+        let mut rows = 0;
+        for receipt in self.iter()? {
+            for row in finding_rows(&receipt?) {
+                sink.write_row(row)?;
+                rows += 1;
+            }
+        }
+        sink.finish()?;
+        Ok(rows)
+    }
+
+    /// Write receipts to `path` and findings to `<path stem>.findings.parquet`
+    ///
+    /// Returns the (receipt, finding) row counts.
+    #[cfg(feature = "parquet")]
+    pub fn export_parquet(&self, path: &Path) -> Result<(usize, usize)> {
+        let mut receipts = parquet_sink::ParquetSink::create(path, "audit_receipt", RECEIPT_COLUMNS)?;
+        let mut findings =
+            parquet_sink::ParquetSink::create(&findings_path(path), "audit_finding", FINDING_COLUMNS)?;
+        let (mut receipt_rows, mut finding_count) = (0, 0);
+        for receipt in self.iter()? {
+            let receipt = receipt?;
+            receipts.write_row(receipt_row(&receipt))?;
+            receipt_rows += 1;
+            for row in finding_rows(&receipt) {
+                findings.write_row(row)?;
+                finding_count += 1;
+            }
+        }
+        receipts.finish()?;
+        findings.finish()?;
+        Ok((receipt_rows, finding_count))
+    }
+}
+
+/// Findings file written alongside a receipt export at `path`
+pub fn findings_path(path: &Path) -> PathBuf {
+    let stem = path.file_stem().and_then(|s| s.to_str()).unwrap_or("receipts");
+    let extension = path.extension().and_then(|s| s.to_str()).unwrap_or("csv");
+    path.with_file_name(format!("{}.findings.{}", stem, extension))
+}
+
+/// Streaming CSV writer with a fixed header
+struct CsvSink<W: Write> {
+    writer: csv::Writer<W>,
+}
+
+impl<W: Write> CsvSink<W> {
+    fn new(writer: W, columns: &[(&str, ColumnKind)]) -> Result<Self> {
+        let mut writer = csv::Writer::from_writer(writer);
+        writer
+            .write_record(columns.iter().map(|(name, _)| *name))
+            .map_err(|e| io_error("CSV export", e))?;
+        Ok(Self { writer })
+    }
+
+    fn write_row(&mut self, row: Vec<Cell>) -> Result<()> {
+        self.writer
+            .write_record(row.iter().map(Cell::to_csv))
+            .map_err(|e| io_error("CSV export", e))
+    }
+
+    fn finish(mut self) -> Result<()> {
+        self.writer.flush().map_err(|e| io_error("CSV export", e))
+    }
+}
+
+#[cfg(feature = "parquet")]
+mod parquet_sink {
+    use super::{io_error, Cell, ColumnKind};
+    use crate::Result;
+    use parquet::data_type::{BoolType, ByteArray, ByteArrayType, Int64Type};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+    use std::fs::File;
+    use std::path::Path;
+    use std::sync::Arc;
+
+    /// Rows buffered per row group; bounds export memory
+    const ROW_GROUP_ROWS: usize = 1024;
+
+    /// Streaming Parquet writer, one row group per `ROW_GROUP_ROWS` rows
+    pub(super) struct ParquetSink {
+        writer: SerializedFileWriter<File>,
+        buffer: Vec<Vec<Cell>>,
+    }
+
+    impl ParquetSink {
+        pub(super) fn create(path: &Path, name: &str, columns: &[(&str, ColumnKind)]) -> Result<Self> {
+            let fields: String = columns
+                .iter()
+                .map(|(column, kind)| match kind {
+                    ColumnKind::Text => format!("REQUIRED BYTE_ARRAY {} (UTF8);", column),
+                    ColumnKind::Bool => format!("REQUIRED BOOLEAN {};", column),
+                    ColumnKind::OptionalBool => format!("OPTIONAL BOOLEAN {};", column),
+                    ColumnKind::Int => format!("REQUIRED INT64 {};", column),
+                })
+                .collect();
+            let schema = parse_message_type(&format!("message {} {{ {} }}", name, fields))
+                .map_err(|e| io_error("Parquet schema", e))?;
+
+            let file = File::create(path).map_err(|e| io_error(&path.display().to_string(), e))?;
+            let writer = SerializedFileWriter::new(
+                file,
+                Arc::new(schema),
+                Arc::new(WriterProperties::builder().build()),
+            )
+            .map_err(|e| io_error("Parquet export", e))?;
+
+            Ok(Self { writer, buffer: Vec::with_capacity(ROW_GROUP_ROWS) })
+        }
+
+        pub(super) fn write_row(&mut self, row: Vec<Cell>) -> Result<()> {
+            self.buffer.push(row);
+            if self.buffer.len() >= ROW_GROUP_ROWS {
+                self.flush()?;
+            }
+            Ok(())
+        }
+
+        pub(super) fn finish(mut self) -> Result<()> {
+            self.flush()?;
+            self.writer.close().map_err(|e| io_error("Parquet export", e))?;
+            Ok(())
+        }
+
+        fn flush(&mut self) -> Result<()> {
+            if self.buffer.is_empty() {
+                return Ok(());
+            }
+            let rows = std::mem::take(&mut self.buffer);
+            let err = |e| io_error("Parquet export", e);
+
+            let mut group = self.writer.next_row_group().map_err(err)?;
+            let mut index = 0;
+            while let Some(mut column) = group.next_column().map_err(err)? {
+                let cells = rows.iter().map(|row| &row[index]);
+                match rows[0][index] {
+                    Cell::Text(_) => {
+                        let values: Vec<ByteArray> = cells
+                            .map(|c| match c {
+                                Cell::Text(s) => ByteArray::from(s.as_str()),
+                                _ => ByteArray::from(""),
+                            })
+                            .collect();
+                        column.typed::<ByteArrayType>().write_batch(&values, None, None)
+                    }
+                    Cell::Bool(_) => {
+                        let values: Vec<bool> = cells.map(|c| matches!(c, Cell::Bool(true))).collect();
+                        column.typed::<BoolType>().write_batch(&values, None, None)
+                    }
+                    Cell::OptionalBool(_) => {
+                        let (mut values, mut levels) = (Vec::new(), Vec::new());
+                        for cell in cells {
+                            match cell {
+                                Cell::OptionalBool(Some(b)) => {
+                                    values.push(*b);
+                                    levels.push(1);
+                                }
+                                _ => levels.push(0),
+                            }
+                        }
+                        column.typed::<BoolType>().write_batch(&values, Some(&levels), None)
+                    }
+                    Cell::Int(_) => {
+                        let values: Vec<i64> = cells
+                            .map(|c| match c {
+                                Cell::Int(i) => *i,
+                                _ => 0,
+                            })
+                            .collect();
+                        column.typed::<Int64Type>().write_batch(&values, None, None)
+                    }
+                }
+                .map_err(err)?;
+                column.close().map_err(err)?;
+                index += 1;
+            }
+            group.close().map_err(err)?;
+            Ok(())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::audit::{AuditResult, BinaryProof, Finding, FindingSeverity};
+
+    fn mock_sign(hash: &str) -> String {
+        format!("SIG:{}", hash)
+    }
+
+    fn receipt(claim: &str, l2_passes: bool) -> AuditReceipt {
+        let evidence = vec!["fact one".to_string(), "fact two".to_string()];
+        let l1 = AuditResult::new(
+            AuditLevel::L1,
+            BinaryProof::ProofExists,
+            claim,
+            evidence.clone(),
+            vec![],
+            true,
+            vec![Finding::new("F-TEST-SUPPORTED", AuditLevel::L1, FindingSeverity::Info, "Claim supported")],
+        )
+        .with_codes(vec![codes::EMPTY_EVIDENCE_DROPPED.to_string(), "CUSTOM".to_string()]);
+        let l2 = AuditResult::new(
+            AuditLevel::L2,
+            BinaryProof::from_bool(l2_passes),
+            claim,
+            evidence,
+            vec![],
+            l2_passes,
+            vec![Finding::new("F-TEST-MAPPED", AuditLevel::L2, FindingSeverity::Info, "Mapping checked")],
+        );
+        AuditReceipt::new(vec![l1, l2], mock_sign)
+    }
+
+    fn read_csv(bytes: &[u8]) -> (Vec<String>, Vec<csv::StringRecord>) {
+        let mut reader = csv::Reader::from_reader(bytes);
+        let headers = reader.headers().unwrap().iter().map(str::to_string).collect();
+        let rows = reader.records().map(|r| r.unwrap()).collect();
+        (headers, rows)
+    }
+
+    #[test]
+    fn test_csv_schema_matches_fixture() {
+        let claim = "Revenue rose, \"sharply\"\nin Q3";
+        let corpus = ReceiptCorpus::from_receipts(vec![receipt(claim, true), receipt(claim, false)]);
+
+        let mut out = Vec::new();
+        assert_eq!(corpus.export_csv(&mut out).unwrap(), 2);
+        let (headers, rows) = read_csv(&out);
+
+        let expected: Vec<&str> = RECEIPT_COLUMNS.iter().map(|(name, _)| *name).collect();
+        assert_eq!(headers, expected);
+        let codes: Vec<String> = COUNTED_CODES.iter().map(|c| format!("code_{}", c.to_lowercase())).collect();
+        assert!(codes.iter().all(|c| headers.contains(c)));
+
+        let field = |row: &csv::StringRecord, name: &str| {
+            row[headers.iter().position(|h| h == name).unwrap()].to_string()
+        };
+        assert_eq!(field(&rows[0], "final_proof"), "true");
+        assert_eq!(field(&rows[1], "final_proof"), "false");
+        assert_eq!(field(&rows[1], "l2_proof"), "false");
+        assert_eq!(field(&rows[0], "l3_proof"), "");
+        assert_eq!(field(&rows[0], "evidence_count"), "2");
+        assert_eq!(field(&rows[0], "findings_count"), "2");
+        assert_eq!(field(&rows[0], "code_empty_evidence_dropped"), "1");
+        assert_eq!(field(&rows[0], "code_other"), "1");
+        assert_eq!(field(&rows[0], "claim"), claim);
+
+        let mut findings = Vec::new();
+        assert_eq!(corpus.export_findings_csv(&mut findings).unwrap(), 8);
+        let (headers, rows) = read_csv(&findings);
+        assert_eq!(headers, ["receipt_hash", "level", "ordinal", "kind", "value"]);
+        assert_eq!(rows[2].iter().skip(1).collect::<Vec<_>>(), ["L1", "2", "code", "CUSTOM"]);
+    }
+
+    #[test]
+    fn test_claim_truncation() {
+        let short = "é".repeat(CLAIM_MAX_CHARS);
+        assert_eq!(truncate_claim(&short), (short.as_str(), false));
+
+        let long = format!("{}tail", short);
+        let (claim, truncated) = truncate_claim(&long);
+        assert!(truncated);
+        assert_eq!(claim, short);
+
+        let mut out = Vec::new();
+        ReceiptCorpus::from_receipts(vec![receipt(&long, true)]).export_csv(&mut out).unwrap();
+        let (_, rows) = read_csv(&out);
+        assert_eq!(&rows[0][12], short);
+        assert_eq!(&rows[0][13], "true");
+        assert_eq!(&rows[0][14], hex::encode(Sha256::digest(long.as_bytes())));
+    }
+
+    #[test]
+    fn test_jsonl_corpus_reimport_row_count() {
+        let dir = std::env::temp_dir().join(format!("audit-corpus-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let corpus_path = dir.join("receipts.jsonl");
+
+        let template = receipt("The ledger balances", true);
+        let mut file = std::io::BufWriter::new(File::create(&corpus_path).unwrap());
+        for i in 0..3000 {
+            let mut r = template.clone();
+            r.receipt_hash = format!("{:064x}", i);
+            writeln!(file, "{}", serde_json::to_string(&r).unwrap()).unwrap();
+        }
+        drop(file);
+
+        let corpus = ReceiptCorpus::open(&corpus_path);
+        let csv_path = dir.join("receipts.csv");
+        let rows = corpus.export_csv(File::create(&csv_path).unwrap()).unwrap();
+        assert_eq!(rows, 3000);
+
+        let mut reader = csv::Reader::from_path(&csv_path).unwrap();
+        assert_eq!(reader.records().count(), 3000);
+        assert_eq!(findings_path(&csv_path), dir.join("receipts.findings.csv"));
+
+        std::fs::write(&corpus_path, "{not json}\n").unwrap();
+        let err = corpus.export_csv(Vec::new()).unwrap_err().to_string();
+        assert!(err.contains("receipts.jsonl:1"), "{}", err);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+
+    #[cfg(feature = "parquet")]
+    #[test]
+    fn test_parquet_row_counts() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+
+        let dir = std::env::temp_dir().join(format!("audit-parquet-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("receipts.parquet");
+
+        let receipts: Vec<_> = (0..1500).map(|i| receipt(&format!("claim {}", i), i % 2 == 0)).collect();
+        let counts = ReceiptCorpus::from_receipts(receipts).export_parquet(&path).unwrap();
+        assert_eq!(counts, (1500, 6000));
+
+        let rows = |p: &Path| SerializedFileReader::new(File::open(p).unwrap()).unwrap().metadata().file_metadata().num_rows();
+        assert_eq!(rows(&path), 1500);
+        assert_eq!(rows(&findings_path(&path)), 6000);
+
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
@@ -0,0 +1,171 @@
+//! HTTP interface to the audit service
+//!
+//! Served by the `auditd` binary, and the contract the portal's remote
+//! backend calls:
+//!
+//! | Route                 | Purpose                                    |
+//! |-----------------------|--------------------------------------------|
+//! | `POST /audit`         | Full L1 → L2 → L3 audit, signed receipt    |
+//! | `POST /audit/quick`   | L1 only, no receipt and no log entry       |
+//! | `POST /verify-receipt`| Hash and signature check of a receipt      |
+//! | `GET /log/root`       | Merkle root of the audit log               |
+//! | `GET /healthz`        | Liveness                                   |
+//! | `GET /info`           | Service name, version and policy           |
+//!
+//! Failure reasons are reported by their stable code (`FailureReason::code`),
+//! as in [`AuditResponse`].
+//!
+//! The level auditors hold no state, so each request audits on its own
+//! [`AuditService`] and only the shared audit log sits behind a lock, held
+//! just long enough to append a receipt or read the root. Requests never
+//! wait on each other's audits.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::levels::L1Audit;
+use crate::service::{AuditConfig, AuditRequest, AuditResponse};
+use crate::{AuditError, AuditReceipt, AuditService, FailureReason};
+use axum::{
+    extract::State,
+    http::StatusCode,
+    routing::{get, post},
+    Json, Router,
+};
+use sap4d::signing::ReceiptSigner;
+use std::sync::{Arc, Mutex};
+
+pub use sap4d::signing::KeySigner;
+
+/// State shared by every request
+pub struct AuditdState {
+    /// Keeps the audit log; audits themselves run on per-request services
+    log: Mutex<AuditService>,
+    config: AuditConfig,
+    signer: KeySigner,
+}
+
+impl AuditdState {
+    pub fn new(config: AuditConfig, signer: KeySigner) -> Self {
+        Self {
+            log: Mutex::new(AuditService::with_config(config.clone())),
+            config,
+            signer,
+        }
+    }
+
+    /// A fresh service for one audit; it logs nothing itself
+    fn worker(&self) -> AuditService {
+        AuditService::with_config(AuditConfig {
+            enable_logging: false,
+            ..self.config.clone()
+        })
+    }
+
+    fn lock_log(&self) -> Result<std::sync::MutexGuard<'_, AuditService>, (StatusCode, String)> {
+        self.log
+            .lock()
+            .map_err(|e| (StatusCode::INTERNAL_SERVER_ERROR, format!("Lock error: {}", e)))
+    }
+}
+
+/// Map audit errors to HTTP status (malformed input is 422, not "not verified")
+fn error_status(err: &AuditError) -> StatusCode {
+    match err {
+        AuditError::InvalidClaim => StatusCode::UNPROCESSABLE_ENTITY,
+        AuditError::Internal(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        _ => StatusCode::BAD_REQUEST,
+    }
+}
+
+/// Routes served by `auditd`
+pub fn router(state: Arc<AuditdState>) -> Router {
+    Router::new()
+        .route("/audit", post(audit))
+        .route("/audit/quick", post(quick_audit))
+        .route("/verify-receipt", post(verify_receipt))
+        .route("/log/root", get(log_root))
+        .route("/healthz", get(healthz))
+        .route("/info", get(info))
+        .with_state(state)
+}
+
+async fn audit(
+    State(state): State<Arc<AuditdState>>,
+    Json(request): Json<AuditRequest>,
+) -> Result<Json<AuditResponse>, (StatusCode, String)> {
+    if request.evidence.len() > state.config.max_evidence {
+        return Err((
+            StatusCode::UNPROCESSABLE_ENTITY,
+            format!("At most {} evidence items per audit", state.config.max_evidence),
+        ));
+    }
+
+    let mut receipt = state
+        .worker()
+        .audit_async(&request.claim, &request.evidence, &request.sub_operations, |hash| {
+            state.signer.sign(hash)
+        })
+        .await
+        .map_err(|e| (error_status(&e), format!("Audit error: {}", e)))?;
+    receipt.key_id = Some(state.signer.key_id().to_string());
+
+    state.lock_log()?.record_receipt(&receipt);
+    Ok(Json(AuditResponse::from(receipt)))
+}
+
+async fn quick_audit(Json(request): Json<AuditRequest>) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let result = L1Audit::new()
+        .audit(&request.claim, &request.evidence)
+        .map_err(|e| (error_status(&e), format!("Verification error: {}", e)))?;
+
+    Ok(Json(serde_json::json!({
+        "proof_exists": result.proof.exists(),
+        "c_zero": result.c_zero,
+        "claim": request.claim,
+        "reason": result.reason.as_ref().map(FailureReason::code),
+        "findings": result.findings,
+        "structured_findings": result.structured_findings
+    })))
+}
+
+/// Check a receipt's hashes and that this daemon's key signed it
+async fn verify_receipt(
+    State(state): State<Arc<AuditdState>>,
+    Json(receipt): Json<AuditReceipt>,
+) -> Json<serde_json::Value> {
+    let valid = receipt.verify(|hash, signature| state.signer.verify(hash, signature));
+    Json(serde_json::json!({
+        "valid": valid,
+        "receipt_hash": receipt.receipt_hash,
+        "proof_exists": receipt.proof_exists(),
+        "c_zero": receipt.c_zero
+    }))
+}
+
+async fn log_root(State(state): State<Arc<AuditdState>>) -> Result<Json<serde_json::Value>, (StatusCode, String)> {
+    let mut log = state.lock_log()?;
+    Ok(Json(serde_json::json!({
+        "log_root_hash": log.log_root_hash(),
+        "entries_count": log.log_entries().len(),
+        "segments_count": log.log_segments().len()
+    })))
+}
+
+async fn healthz() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "status": "ok",
+        "version": env!("CARGO_PKG_VERSION")
+    }))
+}
+
+async fn info() -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "name": "Deterministic Fractal Audit Service",
+        "version": env!("CARGO_PKG_VERSION"),
+        "substrate": crate::SUBSTRATE,
+        "projection": crate::PROJECTION,
+        "levels": ["L1", "L2", "L3"],
+        "policy": "C = 0",
+        "output_type": "Binary (Proof Exists | No Proof Exists)"
+    }))
+}
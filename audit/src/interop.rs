@@ -0,0 +1,314 @@
+//! Conversion between audit receipts and SAP-4D proof receipts
+//!
+//! [`AuditReceipt::to_proof_receipt`] issues a sap4d [`Receipt`] for an
+//! audit. Its claim is the audited claim, its axioms the union of every
+//! level's axioms, and it is C=0 only if the audit is C=0 *and* found a
+//! proof. After the audited evidence it carries a reference block, in order:
+//!
+//! - `audit-result:<level>:<hash>` for each level's result hash
+//! - `audit-timestamp:<rfc3339>` for the audit receipt's timestamp
+//! - `audit-receipt:<hash>` for the audit receipt hash
+//!
+//! The audit receipt hash is SHA-256 over the result hashes in level order
+//! followed by the timestamp string, so a consumer holding only the proof
+//! receipt can recompute it with [`AuditReference::verify`]. The proof
+//! receipt's own hash covers its evidence, so the reference cannot be
+//! altered without breaking that receipt.
+//!
+//! In the other direction, [`AuditableReceipt::audit`] verifies a proof
+//! receipt and audits its claim with the receipt itself as L1 evidence.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::signing::ReceiptSigner;
+use sap4d::{Receipt, ReceiptBuilder};
+use sha2::{Digest, Sha256};
+
+use crate::audit::AuditReceipt;
+use crate::service::AuditService;
+use crate::{AuditError, Result};
+
+/// Evidence prefix for a level's result hash
+pub const RESULT_PREFIX: &str = "audit-result:";
+/// Evidence prefix for the audit receipt's timestamp
+pub const TIMESTAMP_PREFIX: &str = "audit-timestamp:";
+/// Evidence prefix for the audit receipt hash
+pub const RECEIPT_PREFIX: &str = "audit-receipt:";
+
+impl AuditReceipt {
+    /// A proof receipt for this audit, signed with `sign_fn`
+    pub fn to_proof_receipt(&self, sign_fn: impl FnOnce(&str) -> String) -> Receipt {
+        self.proof_receipt_builder().build(sign_fn)
+    }
+
+    /// A proof receipt for this audit, signed by `signer`
+    pub fn to_proof_receipt_signed(&self, signer: &dyn ReceiptSigner) -> Receipt {
+        self.proof_receipt_builder().build_signed(signer)
+    }
+
+    fn proof_receipt_builder(&self) -> ReceiptBuilder {
+        let claim = self.results.first().map(|r| r.claim.clone()).unwrap_or_default();
+        let audited = self.results.first().map(|r| r.evidence.clone()).unwrap_or_default();
+
+        let mut axioms: Vec<String> = Vec::new();
+        for id in self.results.iter().flat_map(|r| &r.axioms) {
+            if !axioms.contains(id) {
+                axioms.push(id.clone());
+            }
+        }
+
+        let references = self
+            .results
+            .iter()
+            .map(|r| format!("{}{:?}:{}", RESULT_PREFIX, r.level, r.hash))
+            .chain([
+                format!("{}{}", TIMESTAMP_PREFIX, self.timestamp.to_rfc3339()),
+                format!("{}{}", RECEIPT_PREFIX, self.receipt_hash),
+            ]);
+
+        ReceiptBuilder::new(claim)
+            .with_evidence_list(audited.into_iter().chain(references).collect())
+            .with_causal_chain(self.results.iter().map(|r| format!("{:?} -> {:?}", r.level, r.proof)).collect())
+            .with_axioms(axioms)
+            .with_c_zero(self.c_zero && self.proof_exists())
+    }
+}
+
+/// The audit receipt a proof receipt refers to
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AuditReference {
+    /// `(level, result hash)` in level order
+    pub result_hashes: Vec<(String, String)>,
+    /// The audit receipt's timestamp, as hashed
+    pub timestamp: String,
+    /// The audit receipt hash
+    pub receipt_hash: String,
+}
+
+impl AuditReference {
+    /// Read the reference from a proof receipt made by
+    /// [`AuditReceipt::to_proof_receipt`]; `None` for other receipts
+    ///
+    /// Only the trailing reference block is read, so audited evidence that
+    /// happens to use the same prefixes is left alone. The causal chain
+    /// has one entry per level, which fixes how many result items the
+    /// block holds.
+    pub fn from_proof_receipt(receipt: &Receipt) -> Option<Self> {
+        let levels = receipt.causal_chain.len();
+        let start = receipt.evidence.len().checked_sub(levels + 2)?;
+        let (results, tail) = receipt.evidence[start..].split_at(levels);
+
+        let result_hashes = results
+            .iter()
+            .map(|item| {
+                let (level, hash) = item.strip_prefix(RESULT_PREFIX)?.split_once(':')?;
+                Some((level.to_string(), hash.to_string()))
+            })
+            .collect::<Option<Vec<_>>>()?;
+
+        Some(Self {
+            result_hashes,
+            timestamp: tail[0].strip_prefix(TIMESTAMP_PREFIX)?.to_string(),
+            receipt_hash: tail[1].strip_prefix(RECEIPT_PREFIX)?.to_string(),
+        })
+    }
+
+    /// Recompute the audit receipt hash from the result hashes and timestamp
+    pub fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        for (_, hash) in &self.result_hashes {
+            hasher.update(hash.as_bytes());
+        }
+        hasher.update(self.timestamp.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// The recorded audit receipt hash matches its recomputation
+    pub fn verify(&self) -> bool {
+        self.compute_hash() == self.receipt_hash
+    }
+
+    /// This reference names `receipt`, whose hash is intact
+    pub fn matches(&self, receipt: &AuditReceipt) -> bool {
+        self.verify() && receipt.verify_hash() && receipt.receipt_hash == self.receipt_hash
+    }
+}
+
+/// Evidence statement standing for a verified proof receipt
+pub fn proof_receipt_evidence(receipt: &Receipt) -> String {
+    format!("{} [proof receipt {}]", receipt.claim, receipt.hash)
+}
+
+/// Audit a SAP-4D proof receipt's claim
+pub trait AuditableReceipt {
+    /// Verify the receipt with `verify_fn`, then audit its claim on
+    /// `service` with its evidence plus [`proof_receipt_evidence`]
+    ///
+    /// Receipts that fail verification or are not C=0 are rejected rather
+    /// than audited.
+    fn audit(
+        &self,
+        service: &mut AuditService,
+        verify_fn: impl FnOnce(&str, &str) -> bool,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<AuditReceipt>;
+}
+
+impl AuditableReceipt for Receipt {
+    fn audit(
+        &self,
+        service: &mut AuditService,
+        verify_fn: impl FnOnce(&str, &str) -> bool,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<AuditReceipt> {
+        if !self.verify_hash() {
+            return Err(AuditError::L1Failure(format!("proof receipt {} does not match its hash", self.hash)));
+        }
+        if !self.verify_signature(verify_fn) {
+            return Err(AuditError::SignatureVerificationFailed);
+        }
+        if !self.c_zero {
+            return Err(AuditError::ContradictionDetected);
+        }
+
+        let mut evidence = self.evidence.clone();
+        evidence.push(proof_receipt_evidence(self));
+        service.audit(&self.claim, &evidence, sign_fn)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap4d::ProofEngine;
+
+    fn mock_sign(hash: &str) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(b"MOCK_SIG:");
+        hasher.update(hash.as_bytes());
+        base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+    }
+
+    fn mock_verify(hash: &str, sig: &str) -> bool {
+        mock_sign(hash) == sig
+    }
+
+    fn passing_audit() -> AuditReceipt {
+        AuditService::new()
+            .audit("The deployment succeeded", &["The deployment succeeded".to_string()], mock_sign)
+            .unwrap()
+    }
+
+    #[test]
+    fn test_audit_to_proof_receipt_verifies() {
+        let audit = passing_audit();
+        assert!(audit.proof_exists());
+
+        let proof = audit.to_proof_receipt(mock_sign);
+        assert!(proof.verify(mock_verify));
+        assert!(proof.c_zero);
+        assert_eq!(proof.claim, "The deployment succeeded");
+        assert_eq!(proof.causal_chain.len(), audit.results.len());
+        for result in &audit.results {
+            assert!(proof.evidence.contains(&format!("{}{:?}:{}", RESULT_PREFIX, result.level, result.hash)));
+            assert!(result.axioms.iter().all(|a| proof.axioms.contains(a)));
+        }
+    }
+
+    #[test]
+    fn test_proof_receipt_references_audit_hash() {
+        let audit = passing_audit();
+        let proof = audit.to_proof_receipt(mock_sign);
+
+        let reference = AuditReference::from_proof_receipt(&proof).unwrap();
+        assert_eq!(reference.receipt_hash, audit.receipt_hash);
+        assert_eq!(reference.result_hashes.len(), audit.results.len());
+        assert!(reference.verify());
+        assert!(reference.matches(&audit));
+
+        // Altering a referenced result hash breaks both the reference and the proof receipt
+        let mut tampered = proof.clone();
+        let index = tampered.evidence.iter().position(|e| e.starts_with(RESULT_PREFIX)).unwrap();
+        tampered.evidence[index] = format!("{}L1:{}", RESULT_PREFIX, "0".repeat(64));
+        assert!(!AuditReference::from_proof_receipt(&tampered).unwrap().verify());
+        assert!(!tampered.verify_hash());
+
+        let (_, plain) = ProofEngine::new().prove("Claim", vec!["Claim".to_string()], mock_sign).unwrap();
+        assert_eq!(AuditReference::from_proof_receipt(&plain), None);
+    }
+
+    #[test]
+    fn test_reference_ignores_look_alike_audited_evidence() {
+        let evidence: Vec<String> = [
+            "The deployment succeeded",
+            "audit-result:L1:0000",
+            "audit-timestamp:2020-01-01T00:00:00+00:00",
+            "audit-receipt:0000",
+        ]
+        .iter()
+        .map(|e| e.to_string())
+        .collect();
+        let audit = AuditService::new().audit("The deployment succeeded", &evidence, mock_sign).unwrap();
+        let proof = audit.to_proof_receipt(mock_sign);
+
+        let reference = AuditReference::from_proof_receipt(&proof).unwrap();
+        assert_eq!(reference.result_hashes.len(), audit.results.len());
+        assert_eq!(reference.receipt_hash, audit.receipt_hash);
+        assert!(reference.verify());
+        assert!(reference.matches(&audit));
+
+        // Look-alike evidence without the block is not a reference
+        let (_, plain) = ProofEngine::new().prove("The deployment succeeded", evidence, mock_sign).unwrap();
+        assert_eq!(AuditReference::from_proof_receipt(&plain), None);
+    }
+
+    #[test]
+    fn test_failed_audit_maps_to_non_c_zero() {
+        let audit = AuditService::new().audit("A claim", &[], mock_sign).unwrap();
+        assert!(!audit.proof_exists());
+        assert!(audit.c_zero);
+
+        let proof = audit.to_proof_receipt(mock_sign);
+        assert!(proof.verify(mock_verify));
+        assert!(!proof.c_zero);
+    }
+
+    #[test]
+    fn test_proof_receipt_audits_as_l1_evidence() {
+        let (_, proof) = ProofEngine::new()
+            .prove("Water boils", vec!["Heat applied".to_string(), "Water boils".to_string()], mock_sign)
+            .unwrap();
+
+        let mut service = AuditService::new();
+        let audit = proof.audit(&mut service, mock_verify, mock_sign).unwrap();
+        assert!(audit.verify(mock_verify));
+        assert!(audit.proof_exists() && audit.c_zero);
+        assert!(audit.results[0].evidence.contains(&proof_receipt_evidence(&proof)));
+
+        // And back again
+        let round_trip = audit.to_proof_receipt(mock_sign);
+        assert!(round_trip.verify(mock_verify) && round_trip.c_zero);
+        assert!(AuditReference::from_proof_receipt(&round_trip).unwrap().matches(&audit));
+    }
+
+    #[test]
+    fn test_unverifiable_proof_receipts_are_rejected() {
+        let (_, proof) = ProofEngine::new()
+            .prove("Water boils", vec!["Water boils".to_string()], mock_sign)
+            .unwrap();
+        let mut service = AuditService::new();
+
+        let wrong_key = proof.audit(&mut service, |_, _| false, mock_sign);
+        assert!(matches!(wrong_key, Err(AuditError::SignatureVerificationFailed)));
+
+        let mut tampered = proof.clone();
+        tampered.claim = "Water freezes".to_string();
+        assert!(matches!(tampered.audit(&mut service, mock_verify, mock_sign), Err(AuditError::L1Failure(_))));
+
+        let not_c_zero = ReceiptBuilder::new("Water boils").with_c_zero(false).build(mock_sign);
+        assert!(matches!(
+            not_c_zero.audit(&mut service, mock_verify, mock_sign),
+            Err(AuditError::ContradictionDetected)
+        ));
+    }
+}
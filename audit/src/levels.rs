@@ -3,10 +3,121 @@
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
-use sap4d::{ProofEngine, OmegaSSoT};
+use sap4d::engine::EngineConfig;
+use sap4d::{Contradiction, ContradictionDetector, Evidence, ProofEngine, ProofError, OmegaSSoT};
+pub use sap4d::operation::SubOperation;
+use std::collections::HashSet;
+use std::sync::Arc;
 
-use crate::audit::{AuditResult, BinaryProof};
-use crate::Result;
+use crate::audit::{AuditResult, BinaryProof, FailureReason, Finding, FindingSeverity};
+use crate::{AuditError, Result};
+
+/// Machine-readable finding codes
+pub mod codes {
+    /// No usable evidence was supplied; L2/L3 are skipped
+    pub const NO_EVIDENCE: &str = "NO_EVIDENCE";
+    /// Empty or whitespace-only evidence items were dropped
+    pub const EMPTY_EVIDENCE_DROPPED: &str = "EMPTY_EVIDENCE_DROPPED";
+    /// An evidence item comes from a source trusted below the L1 threshold
+    pub const UNTRUSTED_SOURCE: &str = "UNTRUSTED_SOURCE";
+    /// No evidence item is relevant to the claim
+    pub const IRRELEVANT_EVIDENCE: &str = "IRRELEVANT_EVIDENCE";
+    /// Fewer evidence items than the engine requires
+    pub const INSUFFICIENT_EVIDENCE: &str = "INSUFFICIENT_EVIDENCE";
+    /// Evidence from fewer distinct sources than the engine requires
+    pub const INSUFFICIENT_SOURCES: &str = "INSUFFICIENT_SOURCES";
+    /// Evidence items contradict each other
+    pub const CONTRADICTORY_EVIDENCE: &str = "CONTRADICTORY_EVIDENCE";
+}
+
+/// Stable finding ids, `F-<level>-<check>`
+///
+/// Ids are never renamed or reused; a changed check gets a new id.
+pub mod finding_ids {
+    /// Empty or whitespace-only evidence items were dropped
+    pub const L1_EVIDENCE_DROPPED: &str = "F-L1-EVIDENCE-DROPPED";
+    /// No usable evidence was supplied
+    pub const L1_NO_EVIDENCE: &str = "F-L1-NO-EVIDENCE";
+    /// Too few evidence items or distinct sources for the engine's minimums
+    pub const L1_INSUFFICIENT_EVIDENCE: &str = "F-L1-INSUFFICIENT-EVIDENCE";
+    /// The Ω-SSOT hash chain failed verification
+    pub const L1_SSOT_INTEGRITY_FAILED: &str = "F-L1-SSOT-INTEGRITY-FAILED";
+    /// The Ω-SSOT hash chain verified
+    pub const L1_SSOT_INTEGRITY_VERIFIED: &str = "F-L1-SSOT-INTEGRITY-VERIFIED";
+    /// The claim violates an axiom
+    pub const L1_CLAIM_AXIOM_VIOLATION: &str = "F-L1-CLAIM-AXIOM-VIOLATION";
+    /// The claim violates no axiom
+    pub const L1_NO_AXIOM_VIOLATIONS: &str = "F-L1-NO-AXIOM-VIOLATIONS";
+    /// No evidence item is relevant to the claim
+    pub const L1_EVIDENCE_IRRELEVANT: &str = "F-L1-EVIDENCE-IRRELEVANT";
+    /// Evidence items relevant to the claim were found
+    pub const L1_EVIDENCE_RELEVANT: &str = "F-L1-EVIDENCE-RELEVANT";
+    /// An evidence item contradicts an earlier one
+    pub const L1_EVIDENCE_CONTRADICTS: &str = "F-L1-EVIDENCE-CONTRADICTS";
+    /// The evidence supports the claim
+    pub const L1_CLAIM_SUPPORTED: &str = "F-L1-CLAIM-SUPPORTED";
+    /// The evidence does not support the claim
+    pub const L1_CLAIM_UNSUPPORTED: &str = "F-L1-CLAIM-UNSUPPORTED";
+    /// The proof engine failed
+    pub const L1_VERIFICATION_ERROR: &str = "F-L1-VERIFICATION-ERROR";
+    /// An evidence item comes from a source trusted below the threshold
+    pub const L1_UNTRUSTED_SOURCE: &str = "F-L1-UNTRUSTED-SOURCE";
+    /// L1 found no proof, so L2 did not run
+    pub const L2_BLOCKED: &str = "F-L2-BLOCKED";
+    /// L1 found a proof
+    pub const L2_L1_VERIFIED: &str = "F-L2-L1-VERIFIED";
+    /// An evidence item carries an inconsistency marker
+    pub const L2_EVIDENCE_INCONSISTENT: &str = "F-L2-EVIDENCE-INCONSISTENT";
+    /// An evidence item contradicts an earlier one
+    pub const L2_EVIDENCE_CONTRADICTS: &str = "F-L2-EVIDENCE-CONTRADICTS";
+    /// The consistency checker does not map an evidence item to the claim
+    pub const L2_EVIDENCE_UNRELATED: &str = "F-L2-EVIDENCE-UNRELATED";
+    /// Contradictions were counted (C != 0)
+    pub const L2_CONTRADICTIONS: &str = "F-L2-CONTRADICTIONS";
+    /// No contradictions were counted (C = 0)
+    pub const L2_C_ZERO_VERIFIED: &str = "F-L2-C-ZERO-VERIFIED";
+    /// Every evidence item maps consistently to the claim
+    pub const L2_MAPPING_CONSISTENT: &str = "F-L2-MAPPING-CONSISTENT";
+    /// L1 or L2 found no proof, so L3 did not run
+    pub const L3_BLOCKED: &str = "F-L3-BLOCKED";
+    /// L1 and L2 found proofs
+    pub const L3_LOWER_LEVELS_VERIFIED: &str = "F-L3-LOWER-LEVELS-VERIFIED";
+    /// A sub-operation does not conform
+    pub const L3_SUBOP_NONCONFORMANT: &str = "F-L3-SUBOP-NONCONFORMANT";
+    /// A sub-operation conforms
+    pub const L3_SUBOP_CONFORMS: &str = "F-L3-SUBOP-CONFORMS";
+    /// The sub-operation hash chain is broken
+    pub const L3_CHAIN_BROKEN: &str = "F-L3-CHAIN-BROKEN";
+    /// The sub-operation hash chain verified
+    pub const L3_CHAIN_VERIFIED: &str = "F-L3-CHAIN-VERIFIED";
+}
+
+/// Finding at `level`
+fn finding(level: AuditLevel, id: &str, severity: FindingSeverity, message: impl Into<String>) -> Finding {
+    Finding::new(id, level, severity, message)
+}
+
+/// Default L1 minimum source trust
+pub const DEFAULT_MIN_TRUST: f64 = 0.5;
+
+/// Default content words an evidence item must share with the claim at L1
+pub const DEFAULT_MIN_EVIDENCE_RELEVANCE: usize = 1;
+
+/// Reject empty claims and drop empty evidence items, returning the drop count
+pub fn normalize_inputs(claim: &str, evidence: &[String]) -> Result<(Vec<String>, usize)> {
+    if claim.trim().is_empty() {
+        return Err(AuditError::InvalidClaim);
+    }
+    
+    let kept: Vec<String> = evidence
+        .iter()
+        .filter(|e| !e.trim().is_empty())
+        .cloned()
+        .collect();
+    let dropped = evidence.len() - kept.len();
+    
+    Ok((kept, dropped))
+}
 
 /// Audit level identifier
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
@@ -42,8 +153,8 @@ impl AuditLevel {
 /// L1 Audit: Claim→Outcome proof under Ω-SSOT
 pub struct L1Audit {
     ssot: OmegaSSoT,
-    #[allow(dead_code)] // Reserved for future proof verification logic
     engine: ProofEngine,
+    min_trust: f64,
 }
 
 impl L1Audit {
@@ -51,17 +162,157 @@ impl L1Audit {
     pub fn new() -> Self {
         Self {
             ssot: OmegaSSoT::new(),
-            engine: ProofEngine::new(),
+            engine: ProofEngine::with_config(EngineConfig {
+                min_evidence_relevance: DEFAULT_MIN_EVIDENCE_RELEVANCE,
+                ..Default::default()
+            }),
+            min_trust: DEFAULT_MIN_TRUST,
         }
     }
     
+    /// Prove claims with `engine` (its `EngineConfig` sets evidence relevance)
+    pub fn with_engine(mut self, engine: ProofEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+    
+    /// Flag evidence whose source trust is below `min_trust`
+    pub fn with_min_trust(mut self, min_trust: f64) -> Self {
+        self.min_trust = min_trust;
+        self
+    }
+    
+    /// Ids of the Ω-SSOT and engine domain axioms `statement` violates
+    fn violations(&self, statement: &str) -> Vec<String> {
+        self.ssot.check_violation(statement).into_iter()
+            .chain(self.engine.domain_axioms().check_violation(statement))
+            .map(|a| a.id.clone())
+            .collect()
+    }
+    
+    /// L1 audit over evidence with provenance
+    ///
+    /// Runs [`L1Audit::audit`] on the statements and adds an
+    /// `F-L1-UNTRUSTED-SOURCE` finding (and the `UNTRUSTED_SOURCE` code)
+    /// for every item trusted below the threshold. The findings are
+    /// advisory and do not change the proof.
+    pub fn audit_evidence(&self, claim: &str, evidence: &[Evidence]) -> Result<AuditResult> {
+        let statements: Vec<String> = evidence.iter().map(|e| e.statement.clone()).collect();
+        let result = self.audit_items(claim, &statements, Some(evidence))?;
+        
+        // Indexed like the result's evidence, which omits empty items
+        let untrusted: Vec<Finding> = evidence
+            .iter()
+            .filter(|e| !e.statement.trim().is_empty())
+            .enumerate()
+            .filter(|(_, e)| e.trust < self.min_trust)
+            .map(|(i, item)| {
+                let message = format!(
+                    "Evidence '{}' from untrusted source {} (trust {:.2} < {:.2})",
+                    item.statement,
+                    item.source_uri.as_deref().unwrap_or("<unknown>"),
+                    item.trust,
+                    self.min_trust
+                );
+                finding(AuditLevel::L1, finding_ids::L1_UNTRUSTED_SOURCE, FindingSeverity::Warning, message).at_evidence(i)
+            })
+            .collect();
+        if untrusted.is_empty() {
+            return Ok(result);
+        }
+        
+        let mut finding_codes = result.codes.clone();
+        finding_codes.push(codes::UNTRUSTED_SOURCE.to_string());
+        Ok(result.with_findings(untrusted).with_codes(finding_codes))
+    }
+    
     /// Perform L1 audit
+    ///
+    /// Empty claims are rejected with `AuditError::InvalidClaim`; empty
+    /// evidence items are dropped and recorded before the audit proceeds.
+    /// At least one item must be relevant to the claim (by default, share a
+    /// content word with it); otherwise no proof exists and the result
+    /// carries `IRRELEVANT_EVIDENCE`. Evidence short of the engine's
+    /// `min_evidence_count` or `min_distinct_sources` carries
+    /// `INSUFFICIENT_EVIDENCE` or `INSUFFICIENT_SOURCES`; bare statements
+    /// have no sources. When the engine detects contradictions, evidence
+    /// items contradicting each other mean no proof exists and the result
+    /// carries `CONTRADICTORY_EVIDENCE`.
     pub fn audit(&self, claim: &str, evidence: &[String]) -> Result<AuditResult> {
+        self.audit_items(claim, evidence, None)
+    }
+    
+    /// [`L1Audit::audit`], with the provenance behind `evidence` if known
+    fn audit_items(&self, claim: &str, evidence: &[String], provenance: Option<&[Evidence]>) -> Result<AuditResult> {
+        let (evidence, dropped) = normalize_inputs(claim, evidence)?;
+        let evidence = evidence.as_slice();
         let mut findings = Vec::new();
+        let mut finding_codes = Vec::new();
+        let l1 = |id, severity, message: String| finding(AuditLevel::L1, id, severity, message);
+        
+        if dropped > 0 {
+            findings.push(l1(
+                finding_ids::L1_EVIDENCE_DROPPED,
+                FindingSeverity::Warning,
+                format!("Dropped {} empty evidence item(s)", dropped),
+            ));
+            finding_codes.push(codes::EMPTY_EVIDENCE_DROPPED.to_string());
+        }
+        
+        // Step 0: Require at least one evidence item
+        if evidence.is_empty() {
+            findings.push(l1(finding_ids::L1_NO_EVIDENCE, FindingSeverity::Error, "No evidence supplied".to_string()));
+            finding_codes.push(codes::NO_EVIDENCE.to_string());
+            return Ok(AuditResult::new(
+                AuditLevel::L1,
+                BinaryProof::NoProofExists,
+                claim,
+                vec![],
+                vec![],
+                true, // No contradiction, nothing to evaluate
+                findings,
+            ).with_codes(finding_codes).with_reason(FailureReason::NoEvidence));
+        }
+        
+        // Step 0b: Enforce the engine's evidence minimums
+        let bare: Vec<Evidence>;
+        let items = match provenance {
+            Some(items) => items,
+            None => {
+                bare = evidence.iter().map(Evidence::new).collect();
+                &bare
+            }
+        };
+        if let Err(e) = self.engine.check_evidence_requirements(items) {
+            let (code, reason) = match e {
+                ProofError::InsufficientSources { required, provided } => {
+                    (codes::INSUFFICIENT_SOURCES, FailureReason::InsufficientSources { required, provided })
+                }
+                ProofError::InsufficientEvidence { required, provided } => {
+                    (codes::INSUFFICIENT_EVIDENCE, FailureReason::InsufficientEvidence { required, provided })
+                }
+                ref other => return Err(AuditError::L1Failure(other.to_string())),
+            };
+            findings.push(l1(finding_ids::L1_INSUFFICIENT_EVIDENCE, FindingSeverity::Error, e.to_string()));
+            finding_codes.push(code.to_string());
+            return Ok(AuditResult::new(
+                AuditLevel::L1,
+                BinaryProof::NoProofExists,
+                claim,
+                evidence.to_vec(),
+                vec![],
+                true, // No contradiction, just too little evidence
+                findings,
+            ).with_codes(finding_codes).with_reason(reason));
+        }
         
         // Step 1: Verify Ω-SSOT integrity
         if !self.ssot.verify_integrity() {
-            findings.push("Ω-SSOT integrity check failed".to_string());
+            findings.push(l1(
+                finding_ids::L1_SSOT_INTEGRITY_FAILED,
+                FindingSeverity::Error,
+                "Ω-SSOT integrity check failed".to_string(),
+            ));
             return Ok(AuditResult::new(
                 AuditLevel::L1,
                 BinaryProof::NoProofExists,
@@ -70,30 +321,99 @@ impl L1Audit {
                 vec![],
                 false,
                 findings,
-            ));
+            ).with_codes(finding_codes).with_reason(FailureReason::ChainBroken));
         }
-        findings.push("Ω-SSOT integrity verified".to_string());
+        findings.push(l1(
+            finding_ids::L1_SSOT_INTEGRITY_VERIFIED,
+            FindingSeverity::Info,
+            "Ω-SSOT integrity verified".to_string(),
+        ));
         
         // Step 2: Check if claim violates any axioms
-        if let Some(violated) = self.ssot.check_violation(claim) {
-            findings.push(format!("Axiom violation: {}", violated.id));
+        let violated = self.violations(claim);
+        if let Some(first) = violated.first() {
+            for id in &violated {
+                findings.push(
+                    l1(finding_ids::L1_CLAIM_AXIOM_VIOLATION, FindingSeverity::Error, format!("Axiom violation: {}", id))
+                        .with_axiom(id.clone()),
+                );
+            }
+            let reason = FailureReason::AxiomViolation { id: first.clone() };
             return Ok(AuditResult::new(
                 AuditLevel::L1,
                 BinaryProof::NoProofExists,
                 claim,
                 evidence.to_vec(),
-                vec![violated.id.clone()],
+                violated,
                 false,
                 findings,
+            ).with_codes(finding_codes).with_reason(reason));
+        }
+        findings.push(l1(
+            finding_ids::L1_NO_AXIOM_VIOLATIONS,
+            FindingSeverity::Info,
+            "No axiom violations detected".to_string(),
+        ));
+        
+        // Step 3: Require evidence that bears on the claim
+        let relevant = self.engine.relevant_evidence(claim, evidence);
+        if relevant.is_empty() {
+            findings.push(l1(
+                finding_ids::L1_EVIDENCE_IRRELEVANT,
+                FindingSeverity::Error,
+                "No evidence relevant to the claim".to_string(),
             ));
+            finding_codes.push(codes::IRRELEVANT_EVIDENCE.to_string());
+            return Ok(AuditResult::new(
+                AuditLevel::L1,
+                BinaryProof::NoProofExists,
+                claim,
+                evidence.to_vec(),
+                vec![],
+                true, // No contradiction, the evidence is about something else
+                findings,
+            ).with_codes(finding_codes).with_reason(FailureReason::Unsupported));
+        }
+        let named: Vec<String> = relevant.iter().map(|&i| format!("'{}'", evidence[i])).collect();
+        findings.push(l1(
+            finding_ids::L1_EVIDENCE_RELEVANT,
+            FindingSeverity::Info,
+            format!("Evidence relevant to the claim: {}", named.join(", ")),
+        ));
+        
+        // Step 4: Reject evidence that contradicts itself
+        let contradictions = self.engine.contradictions(evidence);
+        if !contradictions.is_empty() {
+            for found in &contradictions {
+                findings.push(
+                    l1(finding_ids::L1_EVIDENCE_CONTRADICTS, FindingSeverity::Error, contradiction_message(found))
+                        .at_evidence(found.second)
+                        .with_axiom("A6_C_ZERO"),
+                );
+            }
+            finding_codes.push(codes::CONTRADICTORY_EVIDENCE.to_string());
+            let count = contradictions.len() as u32;
+            return Ok(AuditResult::new(
+                AuditLevel::L1,
+                BinaryProof::NoProofExists,
+                claim,
+                evidence.to_vec(),
+                vec!["A6_C_ZERO".to_string()],
+                false,
+                findings,
+            ).with_codes(finding_codes).with_reason(FailureReason::ContradictionDetected { count }));
         }
-        findings.push("No axiom violations detected".to_string());
         
-        // Step 3: Verify claim is supported by evidence
+        // Step 5: Verify claim is supported by evidence
         match self.engine.verify_claim(claim, evidence) {
             Ok(true) => {
-                findings.push("Claim supported by evidence".to_string());
+                findings.push(l1(
+                    finding_ids::L1_CLAIM_SUPPORTED,
+                    FindingSeverity::Info,
+                    "Claim supported by evidence".to_string(),
+                ));
                 let axioms: Vec<String> = self.ssot.core_axioms.all()
+                    .chain(self.engine.domain_axioms().all())
                     .map(|a| a.id.clone())
                     .collect();
                 
@@ -105,10 +425,14 @@ impl L1Audit {
                     axioms,
                     true,
                     findings,
-                ))
+                ).with_codes(finding_codes))
             }
             Ok(false) => {
-                findings.push("Claim not supported by evidence".to_string());
+                findings.push(l1(
+                    finding_ids::L1_CLAIM_UNSUPPORTED,
+                    FindingSeverity::Error,
+                    "Claim not supported by evidence".to_string(),
+                ));
                 Ok(AuditResult::new(
                     AuditLevel::L1,
                     BinaryProof::NoProofExists,
@@ -117,10 +441,14 @@ impl L1Audit {
                     vec![],
                     true, // No contradiction, just insufficient evidence
                     findings,
-                ))
+                ).with_codes(finding_codes).with_reason(FailureReason::Unsupported))
             }
             Err(e) => {
-                findings.push(format!("Verification error: {}", e));
+                findings.push(l1(
+                    finding_ids::L1_VERIFICATION_ERROR,
+                    FindingSeverity::Error,
+                    format!("Verification error: {}", e),
+                ));
                 Ok(AuditResult::new(
                     AuditLevel::L1,
                     BinaryProof::NoProofExists,
@@ -129,7 +457,7 @@ impl L1Audit {
                     vec![],
                     false,
                     findings,
-                ))
+                ).with_codes(finding_codes).with_reason(FailureReason::Unsupported))
             }
         }
     }
@@ -141,73 +469,285 @@ impl Default for L1Audit {
     }
 }
 
+/// Decides whether an evidence item maps to a claim during L2
+pub trait ConsistencyChecker: Send + Sync {
+    /// Stable checker name recorded in findings
+    fn name(&self) -> &str;
+    
+    /// Whether `evidence` maps to `claim`
+    fn supports(&self, claim: &str, evidence: &str) -> bool;
+}
+
+impl std::fmt::Debug for dyn ConsistencyChecker {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "ConsistencyChecker({})", self.name())
+    }
+}
+
+impl<C: ConsistencyChecker + ?Sized> ConsistencyChecker for Arc<C> {
+    fn name(&self) -> &str {
+        (**self).name()
+    }
+    
+    fn supports(&self, claim: &str, evidence: &str) -> bool {
+        (**self).supports(claim, evidence)
+    }
+}
+
+/// Default English stop words ignored by `LexicalOverlapChecker`, the same
+/// ones the engine ignores when measuring evidence relevance
+pub use sap4d::engine::RELEVANCE_STOP_WORDS as DEFAULT_STOP_WORDS;
+
+/// Word-overlap heuristic with stop-word filtering
+#[derive(Debug, Clone)]
+pub struct LexicalOverlapChecker {
+    stop_words: HashSet<String>,
+    min_overlap: usize,
+}
+
+impl LexicalOverlapChecker {
+    /// Create a checker requiring `min_overlap` shared content words
+    pub fn new(min_overlap: usize) -> Self {
+        Self {
+            stop_words: DEFAULT_STOP_WORDS.iter().map(|w| w.to_string()).collect(),
+            min_overlap: min_overlap.max(1),
+        }
+    }
+    
+    /// Replace the stop-word list
+    pub fn with_stop_words<I, S>(mut self, words: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.stop_words = words.into_iter().map(|w| w.into().to_lowercase()).collect();
+        self
+    }
+    
+    /// Lowercased content words, excluding stop words
+    fn content_words(&self, text: &str) -> HashSet<String> {
+        text.split(|c: char| !c.is_alphanumeric())
+            .filter(|w| !w.is_empty())
+            .map(|w| w.to_lowercase())
+            .filter(|w| !self.stop_words.contains(w))
+            .collect()
+    }
+}
+
+impl Default for LexicalOverlapChecker {
+    fn default() -> Self {
+        Self::new(1)
+    }
+}
+
+impl ConsistencyChecker for LexicalOverlapChecker {
+    fn name(&self) -> &str {
+        "lexical-overlap"
+    }
+    
+    fn supports(&self, claim: &str, evidence: &str) -> bool {
+        let claim_words = self.content_words(claim);
+        let evidence_words = self.content_words(evidence);
+        claim_words.intersection(&evidence_words).count() >= self.min_overlap
+    }
+}
+
+/// Finding message for a contradicting evidence pair
+fn contradiction_message(found: &Contradiction) -> String {
+    format!("Evidence {} contradicts evidence {} ({:?})", found.second, found.first, found.kind)
+}
+
+/// Outcome of the L2 checks on one evidence item
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct EvidenceCheck {
+    index: usize,
+    findings: Vec<Finding>,
+    inconsistent: bool,
+}
+
+/// L2 checks on evidence item `index`, independent of every other item
+fn check_evidence_item(
+    checker: &dyn ConsistencyChecker,
+    claim: &str,
+    index: usize,
+    evidence: &str,
+) -> EvidenceCheck {
+    let mut findings = Vec::new();
+    
+    // Check if evidence is self-consistent
+    let inconsistent = evidence.contains("contradiction") || evidence.contains("inconsistent");
+    if inconsistent {
+        findings.push(
+            finding(
+                AuditLevel::L2,
+                finding_ids::L2_EVIDENCE_INCONSISTENT,
+                FindingSeverity::Error,
+                format!("Evidence {} contains inconsistency marker", index),
+            )
+            .at_evidence(index),
+        );
+    }
+    
+    // Check if evidence maps to claim
+    if !checker.supports(claim, evidence) {
+        let message = format!("Evidence {} may not directly support claim (checker: {})", index, checker.name());
+        findings.push(
+            finding(AuditLevel::L2, finding_ids::L2_EVIDENCE_UNRELATED, FindingSeverity::Warning, message).at_evidence(index),
+        );
+    }
+    
+    EvidenceCheck { index, findings, inconsistent }
+}
+
 /// L2 Audit: Mapping consistency proof (C=0)
 pub struct L2Audit {
     #[allow(dead_code)] // Reserved for future proof verification logic
     engine: ProofEngine,
+    checker: Arc<dyn ConsistencyChecker>,
+    contradictions: Option<ContradictionDetector>,
 }
 
 impl L2Audit {
-    /// Create a new L2 auditor
+    /// Create a new L2 auditor using `LexicalOverlapChecker`
     pub fn new() -> Self {
+        Self::with_checker(Box::new(LexicalOverlapChecker::default()))
+    }
+    
+    /// Create an L2 auditor with a custom mapping-consistency checker
+    pub fn with_checker(checker: Box<dyn ConsistencyChecker>) -> Self {
         Self {
             engine: ProofEngine::new(),
+            checker: Arc::from(checker),
+            contradictions: None,
         }
     }
     
+    /// Also count evidence pairs `detector` finds contradicting toward C
+    pub fn with_contradiction_detector(mut self, detector: ContradictionDetector) -> Self {
+        self.contradictions = Some(detector);
+        self
+    }
+    
+    /// Name of the active consistency checker
+    pub fn checker_name(&self) -> &str {
+        self.checker.name()
+    }
+    
     /// Perform L2 audit
     pub fn audit(&self, claim: &str, evidence: &[String], l1_result: &AuditResult) -> Result<AuditResult> {
-        let mut findings = Vec::new();
+        if !l1_result.proof.exists() {
+            return Ok(Self::blocked(claim, evidence));
+        }
+        
+        let checks = evidence
+            .iter()
+            .enumerate()
+            .map(|(i, e)| check_evidence_item(self.checker.as_ref(), claim, i, e))
+            .collect();
+        
+        Ok(self.conclude(claim, evidence, checks))
+    }
+    
+    /// Perform L2 audit with up to `concurrency` evidence items checked in parallel
+    ///
+    /// Each item is checked on the blocking pool; results are ordered by
+    /// evidence index, so the outcome is identical to [`L2Audit::audit`]
+    /// whatever order the checks finish in.
+    pub async fn audit_async(
+        &self,
+        claim: &str,
+        evidence: &[String],
+        l1_result: &AuditResult,
+        concurrency: usize,
+    ) -> Result<AuditResult> {
+        use futures::stream::{self, StreamExt, TryStreamExt};
         
-        // Step 1: Verify L1 passed
         if !l1_result.proof.exists() {
-            findings.push("L1 audit did not pass - L2 cannot proceed".to_string());
-            return Ok(AuditResult::new(
-                AuditLevel::L2,
-                BinaryProof::NoProofExists,
-                claim,
-                evidence.to_vec(),
-                vec![],
-                false,
-                findings,
-            ));
+            return Ok(Self::blocked(claim, evidence));
         }
-        findings.push("L1 audit verified".to_string());
         
-        // Step 2: Verify mapping consistency
+        let claim_owned: Arc<str> = Arc::from(claim);
+        let mut checks: Vec<EvidenceCheck> = stream::iter(evidence.iter().cloned().enumerate())
+            .map(|(i, e)| {
+                let checker = self.checker.clone();
+                let claim = claim_owned.clone();
+                tokio::task::spawn_blocking(move || check_evidence_item(checker.as_ref(), &claim, i, &e))
+            })
+            .buffer_unordered(concurrency.max(1))
+            .map_err(|e| AuditError::Internal(format!("L2 evidence check failed: {}", e)))
+            .try_collect()
+            .await?;
+        checks.sort_by_key(|check| check.index);
+        
+        Ok(self.conclude(claim, evidence, checks))
+    }
+    
+    /// L2 result when L1 did not pass
+    fn blocked(claim: &str, evidence: &[String]) -> AuditResult {
+        AuditResult::new(
+            AuditLevel::L2,
+            BinaryProof::NoProofExists,
+            claim,
+            evidence.to_vec(),
+            vec![],
+            false,
+            vec![finding(
+                AuditLevel::L2,
+                finding_ids::L2_BLOCKED,
+                FindingSeverity::Error,
+                "L1 audit did not pass - L2 cannot proceed",
+            )],
+        )
+        .with_reason(FailureReason::LowerLevelFailed { level: AuditLevel::L1 })
+    }
+    
+    /// Combine per-item checks (in evidence order) into the L2 result
+    fn conclude(&self, claim: &str, evidence: &[String], checks: Vec<EvidenceCheck>) -> AuditResult {
+        let mut findings = vec![finding(AuditLevel::L2, finding_ids::L2_L1_VERIFIED, FindingSeverity::Info, "L1 audit verified")];
+        
         // Each piece of evidence should map consistently to the claim
         let mut consistent = true;
         let mut c_value = 0u32;
         
-        for (i, e) in evidence.iter().enumerate() {
-            // Check if evidence is self-consistent
-            if e.contains("contradiction") || e.contains("inconsistent") {
-                findings.push(format!("Evidence {} contains inconsistency marker", i));
+        for check in checks {
+            if check.inconsistent {
                 consistent = false;
                 c_value += 1;
             }
-            
-            // Check if evidence maps to claim
-            // Simple heuristic: evidence should relate to claim
-            if !claim.split_whitespace().any(|w| e.to_lowercase().contains(&w.to_lowercase())) {
-                findings.push(format!("Evidence {} may not directly support claim", i));
-            }
+            findings.extend(check.findings);
+        }
+        
+        // Evidence items should not contradict each other
+        let contradictions = self.contradictions.as_ref().map(|d| d.detect(evidence)).unwrap_or_default();
+        for found in &contradictions {
+            findings.push(
+                finding(AuditLevel::L2, finding_ids::L2_EVIDENCE_CONTRADICTS, FindingSeverity::Error, contradiction_message(found))
+                    .at_evidence(found.second),
+            );
+            consistent = false;
+            c_value += 1;
         }
         
-        // Step 3: Verify C=0
+        // Verify C=0
         let c_zero = c_value == 0;
         if !c_zero {
-            findings.push(format!("C={} (contradictions detected)", c_value));
+            let message = format!("C={} (contradictions detected)", c_value);
+            findings.push(
+                finding(AuditLevel::L2, finding_ids::L2_CONTRADICTIONS, FindingSeverity::Error, message).with_axiom("A6_C_ZERO"),
+            );
             consistent = false;
         } else {
-            findings.push("C=0 verified".to_string());
+            findings.push(
+                finding(AuditLevel::L2, finding_ids::L2_C_ZERO_VERIFIED, FindingSeverity::Info, "C=0 verified").with_axiom("A6_C_ZERO"),
+            );
         }
         
         if consistent {
-            findings.push("Mapping consistency verified".to_string());
+            let message = format!("Mapping consistency verified (checker: {})", self.checker.name());
+            findings.push(finding(AuditLevel::L2, finding_ids::L2_MAPPING_CONSISTENT, FindingSeverity::Info, message));
         }
         
-        Ok(AuditResult::new(
+        AuditResult::new(
             AuditLevel::L2,
             BinaryProof::from_bool(consistent && c_zero),
             claim,
@@ -215,7 +755,8 @@ impl L2Audit {
             vec!["A6_C_ZERO".to_string()],
             c_zero,
             findings,
-        ))
+        )
+        .with_reason(FailureReason::ContradictionDetected { count: c_value })
     }
 }
 
@@ -230,6 +771,19 @@ pub struct L3Audit {
     ssot: OmegaSSoT,
 }
 
+/// L3 verdict on a single sub-operation
+#[derive(Debug, Clone, PartialEq)]
+pub struct OpVerdict {
+    /// Position of the operation in its chain
+    pub index: usize,
+    /// Hash intact and no axiom violated
+    pub conforms: bool,
+    /// `F-L3-SUBOP-CONFORMS` or `F-L3-SUBOP-NONCONFORMANT`
+    pub finding: Finding,
+    /// Why the operation does not conform
+    pub reason: Option<FailureReason>,
+}
+
 impl L3Audit {
     /// Create a new L3 auditor
     pub fn new() -> Self {
@@ -248,10 +802,15 @@ impl L3Audit {
         sub_operations: &[SubOperation],
     ) -> Result<AuditResult> {
         let mut findings = Vec::new();
+        let l3 = |id, severity, message: String| finding(AuditLevel::L3, id, severity, message);
         
         // Step 1: Verify L1 and L2 passed
-        if !l1_result.proof.exists() || !l2_result.proof.exists() {
-            findings.push("L1 or L2 audit did not pass - L3 cannot proceed".to_string());
+        if let Some(failed) = [l1_result, l2_result].iter().find(|r| !r.proof.exists()) {
+            findings.push(l3(
+                finding_ids::L3_BLOCKED,
+                FindingSeverity::Error,
+                "L1 or L2 audit did not pass - L3 cannot proceed".to_string(),
+            ));
             return Ok(AuditResult::new(
                 AuditLevel::L3,
                 BinaryProof::NoProofExists,
@@ -260,34 +819,48 @@ impl L3Audit {
                 vec![],
                 false,
                 findings,
-            ));
+            ).with_reason(FailureReason::LowerLevelFailed { level: failed.level }));
         }
-        findings.push("L1 and L2 audits verified".to_string());
+        findings.push(l3(
+            finding_ids::L3_LOWER_LEVELS_VERIFIED,
+            FindingSeverity::Info,
+            "L1 and L2 audits verified".to_string(),
+        ));
         
         // Step 2: Verify each sub-operation conforms
         let mut all_conform = true;
+        let mut reason = None;
         
         for (i, op) in sub_operations.iter().enumerate() {
-            if !op.verify_conformity(&self.ssot) {
-                findings.push(format!("Sub-operation {} non-conformant: {}", i, op.name));
-                all_conform = false;
-            } else {
-                findings.push(format!("Sub-operation {} conforms", i));
+            let verdict = self.check_operation(i, op);
+            all_conform &= verdict.conforms;
+            if let Some(op_reason) = verdict.reason {
+                reason.get_or_insert(op_reason);
             }
+            findings.push(verdict.finding);
         }
         
         // Step 3: Verify sub-operation chain integrity
         let chain_valid = SubOperation::verify_chain(sub_operations);
         if !chain_valid {
-            findings.push("Sub-operation chain integrity failed".to_string());
+            findings.push(l3(
+                finding_ids::L3_CHAIN_BROKEN,
+                FindingSeverity::Error,
+                "Sub-operation chain integrity failed".to_string(),
+            ));
             all_conform = false;
+            reason.get_or_insert(FailureReason::ChainBroken);
         } else {
-            findings.push("Sub-operation chain integrity verified".to_string());
+            findings.push(l3(
+                finding_ids::L3_CHAIN_VERIFIED,
+                FindingSeverity::Info,
+                "Sub-operation chain integrity verified".to_string(),
+            ));
         }
         
         let c_zero = all_conform;
         
-        Ok(AuditResult::new(
+        let result = AuditResult::new(
             AuditLevel::L3,
             BinaryProof::from_bool(all_conform),
             claim,
@@ -295,104 +868,57 @@ impl L3Audit {
             vec!["A5_DETERMINISM".to_string(), "A7_CAUSAL_CLOSURE".to_string()],
             c_zero,
             findings,
-        ))
-    }
-}
-
-impl Default for L3Audit {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-/// A sub-operation in the audit chain
-#[derive(Debug, Clone, Serialize, Deserialize)]
-pub struct SubOperation {
-    /// Operation name
-    pub name: String,
-    /// Input to the operation
-    pub input: String,
-    /// Output from the operation
-    pub output: String,
-    /// Hash of the operation
-    pub hash: String,
-    /// Previous operation hash (for chain)
-    pub prev_hash: Option<String>,
-}
-
-impl SubOperation {
-    /// Create a new sub-operation
-    pub fn new(
-        name: impl Into<String>,
-        input: impl Into<String>,
-        output: impl Into<String>,
-        prev_hash: Option<String>,
-    ) -> Self {
-        let name = name.into();
-        let input = input.into();
-        let output = output.into();
-        
-        let hash = Self::compute_hash(&name, &input, &output, &prev_hash);
-        
-        Self {
-            name,
-            input,
-            output,
-            hash,
-            prev_hash,
-        }
-    }
-    
-    fn compute_hash(name: &str, input: &str, output: &str, prev: &Option<String>) -> String {
-        use sha2::{Sha256, Digest};
-        let mut hasher = Sha256::new();
-        hasher.update(name.as_bytes());
-        hasher.update(input.as_bytes());
-        hasher.update(output.as_bytes());
-        if let Some(p) = prev {
-            hasher.update(p.as_bytes());
-        }
-        hex::encode(hasher.finalize())
-    }
-    
-    /// Verify operation integrity
-    pub fn verify_integrity(&self) -> bool {
-        let computed = Self::compute_hash(&self.name, &self.input, &self.output, &self.prev_hash);
-        computed == self.hash
+        );
+        Ok(match reason {
+            Some(reason) => result.with_reason(reason),
+            None => result,
+        })
     }
     
-    /// Verify conformity with Ω-SSOT
-    pub fn verify_conformity(&self, ssot: &OmegaSSoT) -> bool {
-        // Check operation doesn't violate any axioms
-        if ssot.check_violation(&self.output).is_some() {
-            return false;
+    /// Check one sub-operation's integrity and Ω-SSOT conformity
+    ///
+    /// Chain linkage is checked separately, over the whole chain.
+    pub fn check_operation(&self, index: usize, op: &SubOperation) -> OpVerdict {
+        if op.verify_conformity(&self.ssot) {
+            return OpVerdict {
+                index,
+                conforms: true,
+                finding: finding(
+                    AuditLevel::L3,
+                    finding_ids::L3_SUBOP_CONFORMS,
+                    FindingSeverity::Info,
+                    format!("Sub-operation {} conforms", index),
+                ),
+                reason: None,
+            };
         }
         
-        // Verify integrity
-        self.verify_integrity()
-    }
-    
-    /// Verify a chain of sub-operations
-    pub fn verify_chain(ops: &[SubOperation]) -> bool {
-        if ops.is_empty() {
-            return true;
+        let mut nonconformant = finding(
+            AuditLevel::L3,
+            finding_ids::L3_SUBOP_NONCONFORMANT,
+            FindingSeverity::Error,
+            format!("Sub-operation {} non-conformant: {}", index, op.name),
+        );
+        // Conformity fails on an axiom violation or a tampered hash
+        let violated = op.output.as_text().and_then(|text| self.ssot.check_violation(text).into_iter().next());
+        if let Some(violated) = violated {
+            nonconformant = nonconformant.with_axiom(violated.id.clone());
         }
-        
-        // First op should have no prev_hash
-        if ops[0].prev_hash.is_some() {
-            return false;
+        OpVerdict {
+            index,
+            conforms: false,
+            finding: nonconformant,
+            reason: Some(match violated {
+                Some(violated) => FailureReason::AxiomViolation { id: violated.id.clone() },
+                None => FailureReason::ChainBroken,
+            }),
         }
-        
-        // Each subsequent op should reference the previous
-        for i in 1..ops.len() {
-            match &ops[i].prev_hash {
-                Some(prev) if *prev == ops[i-1].hash => continue,
-                _ => return false,
-            }
-        }
-        
-        // All ops should have valid integrity
-        ops.iter().all(|op| op.verify_integrity())
+    }
+}
+
+impl Default for L3Audit {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -405,11 +931,43 @@ mod tests {
         let l1 = L1Audit::new();
         let result = l1.audit(
             "The conclusion follows",
-            &["Evidence A".to_string(), "Evidence B".to_string()],
+            &["Evidence A".to_string(), "Premise B supports the conclusion".to_string()],
         ).unwrap();
         
         assert!(result.proof.exists());
         assert!(result.c_zero);
+        let relevant: Vec<&Finding> = result.findings_with_id(finding_ids::L1_EVIDENCE_RELEVANT).collect();
+        assert_eq!(relevant[0].message, "Evidence relevant to the claim: 'Premise B supports the conclusion'");
+        assert!(result.has_finding(finding_ids::L1_CLAIM_SUPPORTED));
+        
+        // The string list is the messages, in order
+        let messages: Vec<&String> = result.structured_findings.iter().map(|f| &f.message).collect();
+        assert_eq!(result.findings.iter().collect::<Vec<_>>(), messages);
+        assert!(result.structured_findings.iter().all(|f| f.level == AuditLevel::L1 && f.id.starts_with("F-L1-")));
+    }
+    
+    #[test]
+    fn test_l1_rejects_unrelated_evidence() {
+        let l1 = L1Audit::new();
+        let result = l1.audit("The moon is cheese", &["Servers restarted at 3pm".to_string()]).unwrap();
+        
+        assert!(!result.proof.exists());
+        assert!(result.c_zero);
+        assert_eq!(result.codes, vec![codes::IRRELEVANT_EVIDENCE]);
+        
+        let result = l1.audit(
+            "The moon is made of cheese",
+            &["Servers restarted at 3pm".to_string(), "Samples show the moon is cheese".to_string()],
+        ).unwrap();
+        assert!(result.proof.exists());
+        assert_eq!(
+            result.findings_with_id(finding_ids::L1_EVIDENCE_RELEVANT).next().unwrap().message,
+            "Evidence relevant to the claim: 'Samples show the moon is cheese'"
+        );
+        
+        // An engine without a relevance minimum keeps the old behaviour
+        let lenient = L1Audit::new().with_engine(ProofEngine::new());
+        assert!(lenient.audit("The moon is cheese", &["Servers restarted at 3pm".to_string()]).unwrap().proof.exists());
     }
     
     #[test]
@@ -418,6 +976,99 @@ mod tests {
         let result = l1.audit("Some claim", &[]).unwrap();
         
         assert!(!result.proof.exists());
+        assert!(result.has_code(codes::NO_EVIDENCE));
+        assert_eq!(result.structured_findings[0].id, finding_ids::L1_NO_EVIDENCE);
+        assert_eq!(result.structured_findings[0].severity, FindingSeverity::Error);
+    }
+    
+    #[test]
+    fn test_l1_flags_untrusted_source() {
+        let l1 = L1Audit::new().with_min_trust(0.6);
+        let evidence = vec![
+            Evidence::new("Evidence A shows the conclusion"),
+            Evidence::new("Evidence B")
+                .with_source("https://rumors.example/b", chrono::Utc::now())
+                .with_trust(0.2),
+        ];
+        
+        let result = l1.audit_evidence("The conclusion follows", &evidence).unwrap();
+        
+        assert!(result.proof.exists());
+        assert_eq!(result.codes, vec![codes::UNTRUSTED_SOURCE]);
+        let untrusted: Vec<&Finding> = result.findings_with_id(finding_ids::L1_UNTRUSTED_SOURCE).collect();
+        assert_eq!(untrusted.len(), 1);
+        assert_eq!(untrusted[0].evidence_index, Some(1));
+        assert!(untrusted[0].message.contains("https://rumors.example/b") && untrusted[0].message.contains("0.20 < 0.60"));
+        assert!(result.verify_integrity());
+        
+        let trusted = l1.audit_evidence("The conclusion follows", &evidence[..1]).unwrap();
+        assert!(!trusted.has_code(codes::UNTRUSTED_SOURCE));
+    }
+    
+    #[test]
+    fn test_l1_enforces_evidence_minimums() {
+        let engine = ProofEngine::with_config(EngineConfig {
+            min_evidence_count: 3,
+            min_distinct_sources: 2,
+            ..Default::default()
+        });
+        let l1 = L1Audit::new().with_engine(engine);
+        let claim = "Water boils";
+        
+        let short = l1.audit(claim, &["Water boils at 100C".to_string(), " ".to_string()]).unwrap();
+        assert!(!short.proof.exists());
+        assert_eq!(short.reason, Some(FailureReason::InsufficientEvidence { required: 3, provided: 1 }));
+        assert!(short.codes.contains(&codes::INSUFFICIENT_EVIDENCE.to_string()));
+        assert_eq!(short.findings_with_id(finding_ids::L1_INSUFFICIENT_EVIDENCE).count(), 1);
+        
+        let now = chrono::Utc::now();
+        let sourced = |uris: [&str; 3]| -> Vec<Evidence> {
+            ["Water boils at 100C", "Water boils when heated", "Boiling water steams"]
+                .iter()
+                .zip(uris)
+                .map(|(statement, uri)| Evidence::new(*statement).with_source(uri, now))
+                .collect()
+        };
+        let one_source = l1.audit_evidence(claim, &sourced(["lab:1", "lab:1", "lab:1"])).unwrap();
+        assert_eq!(one_source.reason, Some(FailureReason::InsufficientSources { required: 2, provided: 1 }));
+        assert_eq!(one_source.reason.as_ref().unwrap().code(), codes::INSUFFICIENT_SOURCES);
+        
+        let two_sources = l1.audit_evidence(claim, &sourced(["lab:1", "lab:2", "lab:1"])).unwrap();
+        assert!(two_sources.proof.exists());
+    }
+    
+    #[test]
+    fn test_l1_consults_domain_axioms() {
+        use sap4d::{Axiom, Comparison, ViolationRule};
+        
+        let mut engine = ProofEngine::new();
+        engine.add_axiom(
+            Axiom::new("FIN_LIMIT", "Transfer Limit", "No transfer exceeds 10000", "finance")
+                .with_violation_rule(ViolationRule::numeric(r"transfer of (?P<value>[\d,]+)", Comparison::Le, 10000.0).unwrap()),
+        );
+        engine.add_axiom(
+            Axiom::new("FIN_NO_CASH", "No Cash", "Cash payments are not accepted", "finance")
+                .with_violation_rule(ViolationRule::forbidden(["cash"]).unwrap()),
+        );
+        let l1 = L1Audit::new().with_engine(engine);
+        let claim = "The ledger records the transfer";
+        
+        let within = l1.audit(claim, &["The ledger records a transfer of 9,500".to_string()]).unwrap();
+        assert!(within.proof.exists());
+        assert!(within.axioms.contains(&"FIN_LIMIT".to_string()));
+        
+        // Every axiom the claim violates is reported
+        let claim = "The ledger records a transfer of 12,000 in cash";
+        let violated = l1.audit(claim, &[claim.to_string()]).unwrap();
+        assert!(!violated.proof.exists());
+        assert_eq!(violated.axioms, vec!["FIN_LIMIT".to_string(), "FIN_NO_CASH".to_string()]);
+        assert_eq!(violated.reason, Some(FailureReason::AxiomViolation { id: "FIN_LIMIT".to_string() }));
+        let findings: Vec<&Finding> = violated.findings_with_id(finding_ids::L1_CLAIM_AXIOM_VIOLATION).collect();
+        assert_eq!(findings.len(), 2);
+        assert_eq!(findings[1].axiom.as_deref(), Some("FIN_NO_CASH"));
+        
+        let claim_violation = l1.audit("Paid the ledger in cash", &["Paid the ledger".to_string()]).unwrap();
+        assert_eq!(claim_violation.reason, Some(FailureReason::AxiomViolation { id: "FIN_NO_CASH".to_string() }));
     }
     
     #[test]
@@ -425,7 +1076,7 @@ mod tests {
         let l1 = L1Audit::new();
         let l2 = L2Audit::new();
         
-        let evidence = vec!["Supporting fact".to_string()];
+        let evidence = vec!["Supporting fact for the claim".to_string()];
         let l1_result = l1.audit("The claim", &evidence).unwrap();
         let l2_result = l2.audit("The claim", &evidence, &l1_result).unwrap();
         
@@ -447,19 +1098,183 @@ mod tests {
     }
     
     #[test]
-    fn test_sub_operation_chain() {
-        let op1 = SubOperation::new("init", "start", "middle", None);
-        let op2 = SubOperation::new("process", "middle", "end", Some(op1.hash.clone()));
+    fn test_l2_findings_locate_inconsistent_evidence() {
+        let l1 = L1Audit::new();
+        let l2 = L2Audit::new();
         
-        assert!(SubOperation::verify_chain(&[op1, op2]));
+        let evidence = vec![
+            "The ledger balances".to_string(),
+            "The ledger balances but totals are inconsistent".to_string(),
+        ];
+        let l1_result = l1.audit("The ledger balances", &evidence).unwrap();
+        let l2_result = l2.audit("The ledger balances", &evidence, &l1_result).unwrap();
+        
+        assert!(!l2_result.proof.exists());
+        let inconsistent: Vec<&Finding> = l2_result.findings_with_id(finding_ids::L2_EVIDENCE_INCONSISTENT).collect();
+        assert_eq!(inconsistent.len(), 1);
+        assert_eq!(inconsistent[0].evidence_index, Some(1));
+        let contradictions = l2_result.findings_with_id(finding_ids::L2_CONTRADICTIONS).next().unwrap();
+        assert_eq!(contradictions.axiom.as_deref(), Some("A6_C_ZERO"));
     }
     
     #[test]
-    fn test_sub_operation_broken_chain() {
-        let op1 = SubOperation::new("init", "start", "middle", None);
-        let op2 = SubOperation::new("process", "middle", "end", Some("wrong_hash".to_string()));
+    fn test_contradicting_evidence_fails_l1_and_l2() {
+        let claim = "The ledger is balanced";
+        let evidence = vec!["The ledger is balanced".to_string(), "The ledger is not balanced".to_string()];
+        
+        // Without detection both items simply support the claim
+        let l1_plain = L1Audit::new().audit(claim, &evidence).unwrap();
+        assert!(l1_plain.proof.exists());
+        assert!(L2Audit::new().audit(claim, &evidence, &l1_plain).unwrap().proof.exists());
+        
+        let l1 = L1Audit::new().with_engine(ProofEngine::with_config(EngineConfig {
+            detect_contradictions: true,
+            ..Default::default()
+        }));
+        let l1_result = l1.audit(claim, &evidence).unwrap();
+        assert!(!l1_result.proof.exists());
+        assert!(!l1_result.c_zero);
+        assert!(l1_result.has_code(codes::CONTRADICTORY_EVIDENCE));
+        assert_eq!(l1_result.reason, Some(FailureReason::ContradictionDetected { count: 1 }));
+        let found = l1_result.findings_with_id(finding_ids::L1_EVIDENCE_CONTRADICTS).next().unwrap();
+        assert_eq!(found.evidence_index, Some(1));
         
-        assert!(!SubOperation::verify_chain(&[op1, op2]));
+        // L2 counts the pair toward C on its own
+        let l2 = L2Audit::new().with_contradiction_detector(ContradictionDetector::default());
+        let l2_result = l2.audit(claim, &evidence, &l1_plain).unwrap();
+        assert!(!l2_result.proof.exists());
+        assert_eq!(l2_result.reason, Some(FailureReason::ContradictionDetected { count: 1 }));
+        let found = l2_result.findings_with_id(finding_ids::L2_EVIDENCE_CONTRADICTS).next().unwrap();
+        assert_eq!(found.evidence_index, Some(1));
+        assert!(l2_result.findings_with_id(finding_ids::L2_CONTRADICTIONS).next().is_some());
+    }
+    
+    #[test]
+            fn test_failure_reasons() {
+        let l1 = L1Audit::new();
+        let l2 = L2Audit::new();
+        let l3 = L3Audit::new();
+        let reason = |r: &AuditResult| {
+            // Proof stays two-valued; a reason accompanies exactly the failures
+            assert_eq!(r.reason.is_some(), r.proof == BinaryProof::NoProofExists);
+            r.reason.clone()
+        };
+        
+        let none = l1.audit("Some claim", &[]).unwrap();
+        assert_eq!(reason(&none), Some(FailureReason::NoEvidence));
+        
+        let irrelevant = l1.audit("The moon is cheese", &["Servers restarted at 3pm".to_string()]).unwrap();
+        assert_eq!(reason(&irrelevant), Some(FailureReason::Unsupported));
+        
+        let violation = l1.audit("A contradiction holds", &["A contradiction holds".to_string()]).unwrap();
+        assert_eq!(reason(&violation), Some(FailureReason::AxiomViolation { id: "A2_NON_CONTRADICTION".to_string() }));
+        
+        let blocked = l2.audit("Some claim", &[], &none).unwrap();
+        assert_eq!(reason(&blocked), Some(FailureReason::LowerLevelFailed { level: AuditLevel::L1 }));
+        
+        let evidence = vec!["The ledger balances".to_string(), "The ledger balances but totals are inconsistent".to_string()];
+        let l1_passed = l1.audit("The ledger balances", &evidence).unwrap();
+        assert_eq!(reason(&l1_passed), None);
+        let l2_failed = l2.audit("The ledger balances", &evidence, &l1_passed).unwrap();
+        assert_eq!(reason(&l2_failed), Some(FailureReason::ContradictionDetected { count: 1 }));
+        
+        let l3_blocked = l3.audit("The ledger balances", &evidence, &l1_passed, &l2_failed, &[]).unwrap();
+        assert_eq!(reason(&l3_blocked), Some(FailureReason::LowerLevelFailed { level: AuditLevel::L2 }));
+        
+        let evidence = &evidence[..1];
+        let l2_passed = l2.audit("The ledger balances", evidence, &l1_passed).unwrap();
+        let first = SubOperation::new("init", "start", "middle", None);
+        let unlinked = SubOperation::new("next", "middle", "end", None);
+        let broken = l3.audit("The ledger balances", evidence, &l1_passed, &l2_passed, &[first.clone(), unlinked]).unwrap();
+        assert_eq!(reason(&broken), Some(FailureReason::ChainBroken));
+        
+        assert!(broken.has_finding(finding_ids::L3_CHAIN_BROKEN));
+        
+        let violating = SubOperation::new("next", "middle", "a contradiction", Some(first.hash.clone()));
+        let nonconformant = l3.audit("The ledger balances", evidence, &l1_passed, &l2_passed, &[first, violating]).unwrap();
+        assert_eq!(
+            reason(&nonconformant),
+            Some(FailureReason::AxiomViolation { id: "A2_NON_CONTRADICTION".to_string() })
+        );
+        let finding = nonconformant.findings_with_id(finding_ids::L3_SUBOP_NONCONFORMANT).next().unwrap();
+        assert_eq!(finding.axiom.as_deref(), Some("A2_NON_CONTRADICTION"));
+    }
+    
+    #[test]
+    fn test_lexical_overlap_ignores_stop_words() {
+        let checker = LexicalOverlapChecker::default();
+        
+        assert!(!checker.supports("the cat sat", "the dog ran"));
+        assert!(checker.supports("the cat sat", "a cat was seen"));
+        
+        let strict = LexicalOverlapChecker::new(2);
+        assert!(!strict.supports("the cat sat", "a cat was seen"));
+        assert!(strict.supports("the cat sat", "the cat sat down"));
+    }
+    
+    #[test]
+    fn test_l2_findings_name_checker() {
+        struct AlwaysUnrelated;
+        impl ConsistencyChecker for AlwaysUnrelated {
+            fn name(&self) -> &str {
+                "always-unrelated"
+            }
+            fn supports(&self, _claim: &str, _evidence: &str) -> bool {
+                false
+            }
+        }
+        
+        // L1 would reject the unrelated evidence before L2 sees it
+        let l1 = L1Audit::new().with_engine(ProofEngine::new());
+        let evidence = vec!["the dog ran".to_string()];
+        let l1_result = l1.audit("the cat sat", &evidence).unwrap();
+        
+        let lexical = L2Audit::new().audit("the cat sat", &evidence, &l1_result).unwrap();
+        let unrelated = lexical.findings_with_id(finding_ids::L2_EVIDENCE_UNRELATED).next().unwrap();
+        assert_eq!(unrelated.message, "Evidence 0 may not directly support claim (checker: lexical-overlap)");
+        assert_eq!(unrelated.evidence_index, Some(0));
+        assert_eq!(unrelated.severity, FindingSeverity::Warning);
+        
+        let custom = L2Audit::with_checker(Box::new(AlwaysUnrelated));
+        let result = custom.audit("the cat sat", &evidence, &l1_result).unwrap();
+        assert_eq!(custom.checker_name(), "always-unrelated");
+        assert!(result
+            .findings_with_id(finding_ids::L2_EVIDENCE_UNRELATED)
+            .any(|f| f.message.contains("(checker: always-unrelated)")));
+    }
+    
+    /// Checker with a fixed per-item cost, so timings measure the scheduling
+    struct FixedCost;
+    
+    impl ConsistencyChecker for FixedCost {
+        fn name(&self) -> &str {
+            "fixed-cost"
+        }
+        
+        fn supports(&self, _claim: &str, _evidence: &str) -> bool {
+            std::thread::sleep(std::time::Duration::from_millis(1));
+            true
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_l2_async_scales_linearly() {
+        let l2 = L2Audit::with_checker(Box::new(FixedCost));
+        let passed = L1Audit::new().audit("Entry balances", &["Entry balances".to_string()]).unwrap();
+        let mut timings = Vec::new();
+        
+        for n in [250, 1000] {
+            let evidence: Vec<String> = (0..n).map(|i| format!("Entry {} balances", i)).collect();
+            let started = std::time::Instant::now();
+            let result = l2.audit_async("Entry balances", &evidence, &passed, 8).await.unwrap();
+            timings.push(started.elapsed());
+            
+            assert!(result.proof.exists());
+            assert_eq!(result.findings.len(), 3);
+        }
+        
+        // 4x the evidence should take ~4x as long; quadratic work would be ~16x
+        let ratio = timings[1].as_secs_f64() / timings[0].as_secs_f64();
+        assert!(ratio < 8.0, "1k items took {:.1}x the time of 250", ratio);
     }
 }
-
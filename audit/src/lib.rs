@@ -10,13 +10,39 @@
 //!
 //! All outputs are binary: `Proof Exists` | `No Proof Exists`
 //!
+//! ## Features
+//!
+//! - `service` (default): the audit levels, Merkle log, log anchoring, HTTP
+//!   service and binaries.
+//! - `audit-verify`: just [`verify::AuditReceipt::verify`], without the
+//!   service or the proof engine.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
+#[cfg(feature = "service")]
+pub mod anchor;
+#[cfg(feature = "service")]
 pub mod audit;
+#[cfg(feature = "service")]
+pub mod composite;
+#[cfg(feature = "service")]
+pub mod corpus;
+#[cfg(feature = "service")]
+pub mod http;
+#[cfg(feature = "service")]
+pub mod interop;
+#[cfg(feature = "service")]
 pub mod levels;
+#[cfg(feature = "service")]
 pub mod merkle;
+#[cfg(feature = "service")]
+pub mod sampling;
+#[cfg(feature = "service")]
 pub mod service;
+#[cfg(feature = "audit-verify")]
+pub mod verify;
 
+#[cfg(feature = "service")]
 use thiserror::Error;
 
 /// Substrate authority
@@ -26,6 +52,7 @@ pub const SUBSTRATE: &str = "Alexis Adams";
 pub const PROJECTION: &str = "AXIOMHIVE PROJECTION";
 
 /// Audit service errors
+#[cfg(feature = "service")]
 #[derive(Error, Debug)]
 pub enum AuditError {
     #[error("L1 audit failed: {0}")]
@@ -52,15 +79,32 @@ pub enum AuditError {
     #[error("Signature verification failed")]
     SignatureVerificationFailed,
 
+    #[error("Anchoring failed: {0}")]
+    AnchorFailed(String),
+
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+#[cfg(feature = "service")]
 pub type Result<T> = std::result::Result<T, AuditError>;
 
 // Re-exports
-pub use audit::{AuditReceipt, AuditResult, BinaryProof};
-pub use levels::{L1Audit, L2Audit, L3Audit, AuditLevel};
-pub use merkle::{MerkleTree, MerkleProof};
-pub use service::AuditService;
-
+#[cfg(feature = "service")]
+pub use anchor::{verify_anchor, AnchorMetadata, AnchorProof, AnchorQueue, Anchorer, FileAnchorer, HttpAnchorer};
+#[cfg(feature = "service")]
+pub use audit::{count_findings, AuditReceipt, AuditResult, BinaryProof, FailureReason, Finding, FindingSeverity};
+#[cfg(feature = "service")]
+pub use composite::DailyComposite;
+#[cfg(feature = "service")]
+pub use corpus::ReceiptCorpus;
+#[cfg(feature = "service")]
+pub use interop::{AuditReference, AuditableReceipt};
+#[cfg(feature = "service")]
+pub use levels::{L1Audit, L2Audit, L3Audit, AuditLevel, ConsistencyChecker, LexicalOverlapChecker, OpVerdict};
+#[cfg(feature = "service")]
+pub use merkle::{ArchivedSegment, ConsistencyProof, LogSegment, MerkleLog, MerkleProof, MerkleTree};
+#[cfg(feature = "service")]
+pub use sampling::{SamplePolicy, SampleSize, SampledAuditReceipt};
+#[cfg(feature = "service")]
+pub use service::{AuditService, IncrementalAudit};
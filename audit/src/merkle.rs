@@ -1,9 +1,26 @@
 //! Merkle tree implementation for audit trail integrity
 //!
+//! A [`MerkleLog`] can be checkpointed: live entries are sealed into a
+//! [`LogSegment`] (kept in memory as a small summary) and an
+//! [`ArchivedSegment`] carrying an inclusion proof for every sealed entry,
+//! which can be exported to disk. Segments chain through `previous_root`.
+//!
+//! The log root covers every entry ever appended, sealed or live, so a
+//! checkpoint does not move it. It is taken over an append-only tree shaped
+//! as in RFC 6962 (the left subtree holds the largest power of two of
+//! leaves) that keeps every complete subtree hash, so the root at any
+//! earlier size is still available and a [`ConsistencyProof`] shows that
+//! the log at one size is a prefix of the log at a later size. Leaves and
+//! nodes are hashed as in [`MerkleTree`], so for power-of-two sizes the two
+//! agree.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
+use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::io;
+use std::path::Path;
 
 /// A node in the Merkle tree
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -107,19 +124,29 @@ impl MerkleTree {
             return None;
         }
         
-        let proof_hashes: Vec<String> = Vec::new();
-        let proof_positions: Vec<u8> = Vec::new();
+        let mut proof_hashes = Vec::new();
+        let mut proof_positions = Vec::new();
         
         let leaf_hash = &self.leaves[index];
         
-        // Simple proof generation for binary tree
-        // TODO: Implement full tree traversal for proof generation
-        // Reserved for future implementation:
-        let _current_index = index;
-        let _level_size = self.leaves.len().next_power_of_two();
+        // Walk the padded levels bottom-up collecting sibling hashes
+        let mut level = self.leaves.clone();
+        while level.len() > 1 && !level.len().is_power_of_two() {
+            level.push(level.last().unwrap().clone());
+        }
         
-        // We need to traverse and collect sibling hashes
-        // This is a simplified version - full implementation would traverse the tree
+        let mut current_index = index;
+        while level.len() > 1 {
+            let sibling = current_index ^ 1;
+            proof_hashes.push(level[sibling].clone());
+            proof_positions.push(if sibling < current_index { 0 } else { 1 });
+            
+            level = level
+                .chunks(2)
+                .map(|pair| hash_data(&format!("{}{}", pair[0], pair[1])))
+                .collect();
+            current_index /= 2;
+        }
         
         Some(MerkleProof {
             leaf_hash: leaf_hash.clone(),
@@ -186,13 +213,158 @@ impl MerkleProof {
     }
 }
 
+/// Hash of an internal node over two child hashes
+fn hash_pair(left: &str, right: &str) -> String {
+    hash_data(&format!("{}{}", left, right))
+}
+
+/// Largest power of two below `n` (for `n >= 2`)
+fn split_point(n: u64) -> u64 {
+    1 << (63 - (n - 1).leading_zeros())
+}
+
+/// Append-only Merkle tree that keeps every complete subtree hash
+///
+/// `levels[k][i]` is the hash over leaves `i * 2^k .. (i + 1) * 2^k`, so
+/// the root at any size up to the current one is O(log n) hashes away.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+struct CompactTree {
+    levels: Vec<Vec<String>>,
+}
+
+impl CompactTree {
+    fn push(&mut self, leaf_hash: String) {
+        let mut node = leaf_hash;
+        let mut level = 0;
+        loop {
+            if self.levels.len() == level {
+                self.levels.push(Vec::new());
+            }
+            self.levels[level].push(node);
+            let nodes = &self.levels[level];
+            if nodes.len() % 2 == 1 {
+                return;
+            }
+            node = hash_pair(&nodes[nodes.len() - 2], &nodes[nodes.len() - 1]);
+            level += 1;
+        }
+    }
+    
+    fn size(&self) -> u64 {
+        self.levels.first().map_or(0, |leaves| leaves.len() as u64)
+    }
+    
+    /// Root over the first `size` leaves
+    fn root(&self, size: u64) -> Option<String> {
+        (size > 0 && size <= self.size()).then(|| self.subtree(0, size))
+    }
+    
+    /// Hash over leaves `start..start + size`, a subtree of the RFC 6962 split
+    fn subtree(&self, start: u64, size: u64) -> String {
+        if size.is_power_of_two() && start % size == 0 {
+            let level = size.trailing_zeros() as usize;
+            return self.levels[level][(start / size) as usize].clone();
+        }
+        let k = split_point(size);
+        hash_pair(&self.subtree(start, k), &self.subtree(start + k, size - k))
+    }
+    
+    /// RFC 6962 `SUBPROOF(m, D[start..start + size], complete)`
+    fn subproof(&self, m: u64, start: u64, size: u64, complete: bool, out: &mut Vec<String>) {
+        if m == size {
+            if !complete {
+                out.push(self.subtree(start, size));
+            }
+            return;
+        }
+        let k = split_point(size);
+        if m <= k {
+            self.subproof(m, start, k, complete, out);
+            out.push(self.subtree(start + k, size - k));
+        } else {
+            self.subproof(m - k, start + k, size - k, false, out);
+            out.push(self.subtree(start, k));
+        }
+    }
+}
+
+/// Proof that the log at `old_size` entries is a prefix of the log at `new_size`
+///
+/// An auditor who recorded the root at `old_size` checks a later root with
+/// [`ConsistencyProof::verify`]; any rewrite of the first `old_size`
+/// entries makes the check fail.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ConsistencyProof {
+    pub old_size: u64,
+    pub new_size: u64,
+    /// Subtree hashes, in RFC 6962 `PROOF(m, D[n])` order
+    pub hashes: Vec<String>,
+}
+
+impl ConsistencyProof {
+    /// Whether `new_root` over `new_size` entries extends `old_root` over `old_size`
+    ///
+    /// This is the verification algorithm of RFC 9162 section 2.1.4.2.
+    pub fn verify(&self, old_root: &str, new_root: &str, old_size: u64, new_size: u64) -> bool {
+        if self.old_size != old_size || self.new_size != new_size || old_size == 0 || old_size > new_size {
+            return false;
+        }
+        if old_size == new_size {
+            return self.hashes.is_empty() && old_root == new_root;
+        }
+        
+        let mut path: Vec<&str> = Vec::with_capacity(self.hashes.len() + 1);
+        if old_size.is_power_of_two() {
+            path.push(old_root);
+        }
+        path.extend(self.hashes.iter().map(String::as_str));
+        let Some((first, rest)) = path.split_first() else {
+            return false;
+        };
+        
+        let (mut fn_, mut sn) = (old_size - 1, new_size - 1);
+        while fn_ & 1 == 1 {
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+        
+        let (mut fr, mut sr) = (first.to_string(), first.to_string());
+        for c in rest {
+            if sn == 0 {
+                return false;
+            }
+            if fn_ & 1 == 1 || fn_ == sn {
+                fr = hash_pair(c, &fr);
+                sr = hash_pair(c, &sr);
+                while fn_ & 1 == 0 && fn_ != 0 {
+                    fn_ >>= 1;
+                    sn >>= 1;
+                }
+            } else {
+                sr = hash_pair(&sr, c);
+            }
+            fn_ >>= 1;
+            sn >>= 1;
+        }
+        
+        sn == 0 && fr == old_root && sr == new_root
+    }
+}
+
 /// Append-only Merkle log for audit trail
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct MerkleLog {
-    /// All entries in the log
+    /// Live entries (not yet checkpointed)
     entries: Vec<LogEntry>,
-    /// Current Merkle tree (rebuilt on access)
-    tree_hash: Option<String>,
+    /// Tree over every entry hash ever appended
+    #[serde(default)]
+    tree: CompactTree,
+    /// Summaries of sealed segments, oldest first
+    #[serde(default)]
+    segments: Vec<LogSegment>,
+    /// Sealed segments not yet exported
+    #[serde(default)]
+    archived: Vec<ArchivedSegment>,
 }
 
 /// A single entry in the Merkle log
@@ -205,20 +377,82 @@ pub struct LogEntry {
     /// Hash of this entry
     pub hash: String,
     /// Timestamp
-    pub timestamp: chrono::DateTime<chrono::Utc>,
+    pub timestamp: DateTime<Utc>,
 }
 
 impl LogEntry {
     pub fn new(index: u64, data: impl Into<String>) -> Self {
         let data = data.into();
-        let hash = hash_data(&format!("{}:{}", index, data));
+        let hash = Self::compute_hash(index, &data);
         Self {
             index,
             data,
             hash,
-            timestamp: chrono::Utc::now(),
+            timestamp: sap4d::clock::now(),
         }
     }
+    
+    fn compute_hash(index: u64, data: &str) -> String {
+        hash_data(&format!("{}:{}", index, data))
+    }
+}
+
+/// Summary of a sealed run of log entries
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct LogSegment {
+    /// Segment number, from 0
+    pub number: u64,
+    /// Merkle root over the segment's entry hashes
+    pub root_hash: String,
+    /// Root of the segment before this one
+    pub previous_root: Option<String>,
+    /// Number of entries sealed
+    pub count: u64,
+    /// Index of the first entry sealed
+    pub first_index: u64,
+    /// Timestamp of the first entry
+    pub first_timestamp: DateTime<Utc>,
+    /// Timestamp of the last entry
+    pub last_timestamp: DateTime<Utc>,
+}
+
+/// A sealed segment with inclusion proofs generated at checkpoint time
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedSegment {
+    pub segment: LogSegment,
+    pub entries: Vec<ArchivedEntry>,
+}
+
+/// A sealed entry and its proof against the segment root
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchivedEntry {
+    pub index: u64,
+    pub hash: String,
+    pub proof: MerkleProof,
+}
+
+impl ArchivedSegment {
+    /// Load a segment written by [`MerkleLog::export_segment`]
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let json = std::fs::read_to_string(path)?;
+        Ok(serde_json::from_str(&json)?)
+    }
+    
+    /// Proof for the entry at `index`
+    pub fn proof(&self, index: u64) -> Option<&MerkleProof> {
+        let offset = index.checked_sub(self.segment.first_index)?;
+        self.entries.get(offset as usize).map(|entry| &entry.proof)
+    }
+    
+    /// Whether entry `index` with `data` was sealed in this segment
+    pub fn verify_entry(&self, index: u64, data: &str) -> bool {
+        let Some(proof) = self.proof(index) else {
+            return false;
+        };
+        proof.leaf_hash == hash_data(&LogEntry::compute_hash(index, data))
+            && proof.root_hash == self.segment.root_hash
+            && proof.verify()
+    }
 }
 
 impl MerkleLog {
@@ -226,40 +460,134 @@ impl MerkleLog {
     pub fn new() -> Self {
         Self {
             entries: Vec::new(),
-            tree_hash: None,
+            tree: CompactTree::default(),
+            segments: Vec::new(),
+            archived: Vec::new(),
         }
     }
     
     /// Append an entry to the log
     pub fn append(&mut self, data: impl Into<String>) -> &LogEntry {
-        let index = self.entries.len() as u64;
-        let entry = LogEntry::new(index, data);
+        let entry = LogEntry::new(self.next_index(), data);
+        self.tree.push(hash_data(&entry.hash));
         self.entries.push(entry);
-        self.tree_hash = None; // Invalidate cached hash
         self.entries.last().unwrap()
     }
     
-    /// Get the current tree root hash
-    pub fn root_hash(&mut self) -> Option<String> {
-        if self.tree_hash.is_none() && !self.entries.is_empty() {
-            let data: Vec<String> = self.entries.iter().map(|e| e.hash.clone()).collect();
-            let tree = MerkleTree::from_data(&data);
-            self.tree_hash = tree.root_hash().map(|s| s.to_string());
+    /// Get the current root hash over every entry, sealed or live
+    pub fn root_hash(&self) -> Option<String> {
+        self.tree.root(self.tree.size())
+    }
+    
+    /// Root hash the log had when it held its first `size` entries
+    pub fn root_hash_at(&self, size: u64) -> Option<String> {
+        self.tree.root(size)
+    }
+    
+    /// Proof that the log at `old_size` entries is a prefix of the log now
+    ///
+    /// Returns `None` for an `old_size` of 0 or beyond the current size.
+    pub fn consistency_proof(&self, old_size: u64) -> Option<ConsistencyProof> {
+        let new_size = self.tree.size();
+        if old_size == 0 || old_size > new_size {
+            return None;
         }
-        self.tree_hash.clone()
+        let mut hashes = Vec::new();
+        if old_size < new_size {
+            self.tree.subproof(old_size, 0, new_size, true, &mut hashes);
+        }
+        Some(ConsistencyProof { old_size, new_size, hashes })
     }
     
-    /// Get entry by index
+    /// Seal the live entries into a new segment and clear them
+    ///
+    /// Returns `None` when there are no live entries.
+    pub fn checkpoint(&mut self) -> Option<&LogSegment> {
+        let (first, last) = (self.entries.first()?, self.entries.last()?);
+        
+        let hashes: Vec<String> = self.entries.iter().map(|e| e.hash.clone()).collect();
+        let tree = MerkleTree::from_data(&hashes);
+        let segment = LogSegment {
+            number: self.segments.len() as u64,
+            root_hash: tree.root_hash()?.to_string(),
+            previous_root: self.segments.last().map(|s| s.root_hash.clone()),
+            count: self.entries.len() as u64,
+            first_index: first.index,
+            first_timestamp: first.timestamp,
+            last_timestamp: last.timestamp,
+        };
+        let entries = self
+            .entries
+            .iter()
+            .enumerate()
+            .filter_map(|(i, entry)| {
+                Some(ArchivedEntry {
+                    index: entry.index,
+                    hash: entry.hash.clone(),
+                    proof: tree.generate_proof(i)?,
+                })
+            })
+            .collect();
+        
+        self.archived.push(ArchivedSegment {
+            segment: segment.clone(),
+            entries,
+        });
+        self.segments.push(segment);
+        self.entries.clear();
+        self.segments.last()
+    }
+    
+    /// Write archived segment `number` to `path` as JSON and release it
+    pub fn export_segment(&mut self, number: u64, path: impl AsRef<Path>) -> io::Result<()> {
+        let position = self
+            .archived
+            .iter()
+            .position(|a| a.segment.number == number)
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("segment {} is not archived in memory", number),
+                )
+            })?;
+        
+        let json = serde_json::to_string_pretty(&self.archived[position])?;
+        std::fs::write(path, json)?;
+        self.archived.remove(position);
+        Ok(())
+    }
+    
+    /// Summaries of sealed segments, oldest first
+    pub fn segments(&self) -> &[LogSegment] {
+        &self.segments
+    }
+    
+    /// Sealed segments still held in memory
+    pub fn archived(&self) -> &[ArchivedSegment] {
+        &self.archived
+    }
+    
+    /// Check that each segment records the root of the one before it
+    pub fn verify_segment_chain(&self) -> bool {
+        self.segments.iter().enumerate().all(|(i, segment)| {
+            segment.number == i as u64
+                && segment.previous_root.as_deref()
+                    == i.checked_sub(1).map(|p| self.segments[p].root_hash.as_str())
+        })
+    }
+    
+    /// Get a live entry by index
     pub fn get(&self, index: u64) -> Option<&LogEntry> {
-        self.entries.get(index as usize)
+        let offset = index.checked_sub(self.entries.first()?.index)?;
+        self.entries.get(offset as usize)
     }
     
-    /// Get all entries
+    /// Get live entries
     pub fn entries(&self) -> &[LogEntry] {
         &self.entries
     }
     
-    /// Get entry count
+    /// Get live entry count
     pub fn len(&self) -> usize {
         self.entries.len()
     }
@@ -268,6 +596,20 @@ impl MerkleLog {
     pub fn is_empty(&self) -> bool {
         self.entries.is_empty()
     }
+    
+    /// Entries ever appended, sealed or live
+    pub fn total_entries(&self) -> u64 {
+        self.next_index()
+    }
+    
+    /// Index the next appended entry receives
+    fn next_index(&self) -> u64 {
+        match (self.entries.last(), self.segments.last()) {
+            (Some(entry), _) => entry.index + 1,
+            (None, Some(segment)) => segment.first_index + segment.count,
+            (None, None) => 0,
+        }
+    }
 }
 
 impl Default for MerkleLog {
@@ -320,11 +662,186 @@ mod tests {
         assert_eq!(entry.data, "entry 2");
     }
     
+    #[test]
+    fn test_checkpoint_seals_entries() {
+        let mut log = MerkleLog::new();
+        for i in 0..5 {
+            log.append(format!("entry {}", i));
+        }
+        let live_root = log.root_hash().unwrap();
+        
+        let hashes: Vec<String> = log.entries().iter().map(|e| e.hash.clone()).collect();
+        
+        let segment = log.checkpoint().unwrap().clone();
+        assert_eq!(segment.number, 0);
+        assert_eq!(segment.count, 5);
+        assert_eq!(segment.root_hash, MerkleTree::from_data(&hashes).root_hash().unwrap());
+        assert_eq!(log.root_hash().unwrap(), live_root);
+        assert!(segment.previous_root.is_none());
+        assert!(log.is_empty());
+        assert!(log.checkpoint().is_none());
+        
+        // Indices continue across segments; the root covers sealed entries too
+        let entry = log.append("entry 5");
+        assert_eq!(entry.index, 5);
+        assert_ne!(log.root_hash().unwrap(), live_root);
+        assert_eq!(log.root_hash_at(5).unwrap(), live_root);
+        assert!(log.get(5).is_some());
+        assert!(log.get(4).is_none());
+        
+        let second = log.checkpoint().unwrap();
+        assert_eq!(second.previous_root.as_deref(), Some(segment.root_hash.as_str()));
+        assert_eq!(second.first_index, 5);
+        assert!(log.verify_segment_chain());
+    }
+    
+    #[test]
+    fn test_archived_segment_proves_inclusion() {
+        let mut log = MerkleLog::new();
+        for i in 0..3 {
+            log.append(format!("entry {}", i));
+        }
+        log.checkpoint();
+        log.append("entry 3");
+        log.checkpoint();
+        
+        let archive = &log.archived()[1];
+        assert!(archive.verify_entry(3, "entry 3"));
+        assert!(!archive.verify_entry(3, "entry x"));
+        assert!(!archive.verify_entry(0, "entry 0"));
+        assert!(log.archived()[0].verify_entry(1, "entry 1"));
+    }
+    
+    #[test]
+    fn test_export_segment_round_trip() {
+        let path = std::env::temp_dir().join(format!("merkle-segment-{}.json", std::process::id()));
+        let mut log = MerkleLog::new();
+        log.append("entry 0");
+        log.append("entry 1");
+        log.checkpoint();
+        
+        log.export_segment(0, &path).unwrap();
+        assert!(log.archived().is_empty());
+        assert_eq!(log.segments().len(), 1);
+        assert_eq!(log.export_segment(0, &path).unwrap_err().kind(), io::ErrorKind::NotFound);
+        
+        let archive = ArchivedSegment::load(&path).unwrap();
+        assert_eq!(archive.segment, log.segments()[0]);
+        assert!(archive.verify_entry(1, "entry 1"));
+        std::fs::remove_file(&path).unwrap();
+    }
+    
     #[test]
     fn test_empty_tree() {
         let tree = MerkleTree::from_data(&[]);
         assert!(tree.root.is_none());
         assert!(tree.root_hash().is_none());
     }
+    
+    #[test]
+    fn test_inclusion_proofs() {
+        for size in [1, 2, 3, 5, 8] {
+            let data: Vec<String> = (0..size).map(|i| format!("item {}", i)).collect();
+            let tree = MerkleTree::from_data(&data);
+            
+            for index in 0..size {
+                let proof = tree.generate_proof(index).unwrap();
+                assert_eq!(proof.root_hash, tree.root_hash().unwrap());
+                assert!(proof.verify(), "size {} index {}", size, index);
+            }
+        }
+        
+        let tree = MerkleTree::from_data(&["a".to_string(), "b".to_string(), "c".to_string()]);
+        let mut proof = tree.generate_proof(1).unwrap();
+        proof.leaf_hash = hash_data("x");
+        assert!(!proof.verify());
+        assert!(tree.generate_proof(3).is_none());
+    }
+    
+    fn log_of(data: &[String]) -> MerkleLog {
+        let mut log = MerkleLog::new();
+        for item in data {
+            log.append(item.clone());
+        }
+        log
+    }
+    
+    #[test]
+    fn test_log_root_matches_tree_at_powers_of_two() {
+        for size in [1, 2, 4, 8] {
+            let log = log_of(&(0..size).map(|i| format!("entry {}", i)).collect::<Vec<_>>());
+            let hashes: Vec<String> = log.entries().iter().map(|e| e.hash.clone()).collect();
+            assert_eq!(log.root_hash().as_deref(), MerkleTree::from_data(&hashes).root_hash());
+        }
+        assert!(MerkleLog::new().root_hash().is_none());
+    }
+    
+    #[test]
+    fn test_consistency_proofs_verify() {
+        for new_size in [1u64, 2, 3, 5, 7, 8, 13] {
+            let data: Vec<String> = (0..new_size).map(|i| format!("entry {}", i)).collect();
+            let log = log_of(&data);
+            let new_root = log.root_hash().unwrap();
+            
+            for old_size in 1..=new_size {
+                // The old root an auditor recorded from a log that was then extended
+                let old_root = log_of(&data[..old_size as usize]).root_hash().unwrap();
+                assert_eq!(log.root_hash_at(old_size).unwrap(), old_root);
+                
+                let proof = log.consistency_proof(old_size).unwrap();
+                assert!(
+                    proof.verify(&old_root, &new_root, old_size, new_size),
+                    "old {} new {}",
+                    old_size,
+                    new_size
+                );
+            }
+        }
+        
+        let log = log_of(&["a".to_string()]);
+        assert!(log.consistency_proof(0).is_none());
+        assert!(log.consistency_proof(2).is_none());
+    }
+    
+    #[test]
+    fn test_consistency_survives_checkpoints() {
+        let mut log = MerkleLog::new();
+        log.append("entry 0");
+        log.append("entry 1");
+        log.append("entry 2");
+        let old_root = log.root_hash().unwrap();
+        log.checkpoint();
+        log.append("entry 3");
+        log.append("entry 4");
+        
+        let proof = log.consistency_proof(3).unwrap();
+        assert!(proof.verify(&old_root, &log.root_hash().unwrap(), 3, 5));
+    }
+    
+    #[test]
+    fn test_consistency_fails_for_rewritten_history() {
+        let data: Vec<String> = (0..6).map(|i| format!("entry {}", i)).collect();
+        let old_root = log_of(&data[..3]).root_hash().unwrap();
+        
+        // Entry 0 changed before the log was extended
+        let mut rewritten = data.clone();
+        rewritten[0] = "entry x".to_string();
+        let forged = log_of(&rewritten);
+        let proof = forged.consistency_proof(3).unwrap();
+        assert!(!proof.verify(&old_root, &forged.root_hash().unwrap(), 3, 6));
+        
+        let honest = log_of(&data);
+        let new_root = honest.root_hash().unwrap();
+        let proof = honest.consistency_proof(3).unwrap();
+        assert!(proof.verify(&old_root, &new_root, 3, 6));
+        assert!(!proof.verify(&old_root, &forged.root_hash().unwrap(), 3, 6));
+        assert!(!proof.verify(&old_root, &new_root, 2, 6));
+        assert!(!proof.verify(&new_root, &new_root, 3, 6));
+        
+        let mut tampered = proof.clone();
+        tampered.hashes[0] = hash_data("x");
+        assert!(!tampered.verify(&old_root, &new_root, 3, 6));
+        tampered.hashes = Vec::new();
+        assert!(!tampered.verify(&old_root, &new_root, 3, 6));
+    }
 }
-
@@ -0,0 +1,379 @@
+//! Sampled audits for high-volume record streams
+//!
+//! Auditing every record of a large stream is often too expensive, so
+//! [`AuditService::audit_sample`](crate::service::AuditService::audit_sample)
+//! audits a deterministic sample and issues a [`SampledAuditReceipt`] that
+//! records exactly what was covered.
+//!
+//! ## Selection
+//!
+//! - [`SampleSize::Rate`]`(p)`: hash-threshold sampling. Record `i` is
+//!   selected when `SHA-256(seed ‖ i)` read as a `u64` falls below
+//!   `p · 2⁶⁴`, so every record is included independently with probability
+//!   `p` and the expected sample size is `p · N`.
+//! - [`SampleSize::Fixed`]`(n)`: reservoir sampling (Algorithm R) driven by a
+//!   SplitMix64 generator seeded with `seed`. Every record is included with
+//!   probability `n / N` and exactly `min(n, N)` records are selected.
+//!
+//! Selection depends only on the seed and record positions, so re-running
+//! with the same seed over the same population selects the same records.
+//!
+//! ## Coverage
+//!
+//! If a fraction `f` of the population would fail, a sample of `n` records
+//! contains at least one failure with probability at least `1 - (1 - f)^n`
+//! (see [`SampledAuditReceipt::detection_probability`]). A passing sampled
+//! audit is therefore statistical evidence, never a proof that every record
+//! passes; receipts are marked [`Coverage::Sampled`] so they cannot be
+//! mistaken for exhaustive [`AuditReceipt`](crate::audit::AuditReceipt)s.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::audit::BinaryProof;
+use crate::merkle::MerkleTree;
+use crate::{AuditError, Result};
+
+/// Placeholder in a claim template replaced by each sampled record
+pub const RECORD_PLACEHOLDER: &str = "{record}";
+
+/// How many records to sample
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SampleSize {
+    /// Include each record independently with this probability (0.0..=1.0)
+    Rate(f64),
+    /// Select exactly this many records (or all, if fewer)
+    Fixed(usize),
+}
+
+/// Sampling policy: size plus the seed that makes selection reproducible
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct SamplePolicy {
+    /// Sample size
+    pub size: SampleSize,
+    /// Selection seed
+    pub seed: u64,
+}
+
+impl SamplePolicy {
+    /// Sample each record with probability `rate`
+    pub fn rate(rate: f64, seed: u64) -> Self {
+        Self { size: SampleSize::Rate(rate), seed }
+    }
+
+    /// Sample exactly `n` records
+    pub fn fixed(n: usize, seed: u64) -> Self {
+        Self { size: SampleSize::Fixed(n), seed }
+    }
+
+    fn validate(&self) -> Result<()> {
+        if let SampleSize::Rate(rate) = self.size {
+            if !(0.0..=1.0).contains(&rate) {
+                return Err(AuditError::Internal(format!("Sample rate {} is outside 0.0..=1.0", rate)));
+            }
+        }
+        Ok(())
+    }
+
+    /// Select `(index, record)` pairs from a stream, returning them in index
+    /// order together with the population size
+    pub fn select(&self, records: impl Iterator<Item = String>) -> Result<(Vec<(usize, String)>, usize)> {
+        self.validate()?;
+
+        let mut population = 0;
+        let mut selected = Vec::new();
+        match self.size {
+            SampleSize::Rate(rate) => {
+                let threshold = rate * u64::MAX as f64;
+                for (index, record) in records.enumerate() {
+                    population += 1;
+                    if rate >= 1.0 || (index_hash(self.seed, index) as f64) < threshold {
+                        selected.push((index, record));
+                    }
+                }
+            }
+            SampleSize::Fixed(n) => {
+                let mut rng = SplitMix64(self.seed);
+                for (index, record) in records.enumerate() {
+                    population += 1;
+                    if selected.len() < n {
+                        selected.push((index, record));
+                    } else if n > 0 {
+                        let j = (rng.next() % (index as u64 + 1)) as usize;
+                        if j < n {
+                            selected[j] = (index, record);
+                        }
+                    }
+                }
+                selected.sort_by_key(|(index, _)| *index);
+            }
+        }
+        Ok((selected, population))
+    }
+}
+
+/// First eight bytes of `SHA-256(seed ‖ index)` as a `u64`
+fn index_hash(seed: u64, index: usize) -> u64 {
+    let mut hasher = Sha256::new();
+    hasher.update(seed.to_le_bytes());
+    hasher.update((index as u64).to_le_bytes());
+    let digest = hasher.finalize();
+    let mut bytes = [0u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+    u64::from_le_bytes(bytes)
+}
+
+/// SplitMix64 generator for reservoir replacement positions
+struct SplitMix64(u64);
+
+impl SplitMix64 {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// How much of the population a receipt covers
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Coverage {
+    /// Only the recorded sample was audited
+    Sampled,
+}
+
+/// Outcome of auditing one sampled record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SampleOutcome {
+    /// Position of the record in the population
+    pub index: usize,
+    /// Instantiated claim
+    pub claim: String,
+    /// Hash of the record's audit receipt
+    pub receipt_hash: String,
+    /// The record's final proof
+    pub proof: BinaryProof,
+    /// The record's C=0 status
+    pub c_zero: bool,
+}
+
+/// Signed composite receipt for a sampled audit
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SampledAuditReceipt {
+    /// Always [`Coverage::Sampled`]
+    pub coverage: Coverage,
+    /// Claim template records were instantiated into
+    pub claim_template: String,
+    /// Sampling policy, including the seed
+    pub policy: SamplePolicy,
+    /// Number of records in the population
+    pub population: usize,
+    /// Population indices that were audited, ascending
+    pub selected_indices: Vec<usize>,
+    /// Merkle root over the selected indices
+    pub indices_root: String,
+    /// Per-sample outcomes in index order
+    pub outcomes: Vec<SampleOutcome>,
+    /// Whether every sampled record proved its claim
+    pub final_proof: BinaryProof,
+    /// Whether every sampled record is C=0
+    pub c_zero: bool,
+    /// Hash of the composite contents
+    pub composite_hash: String,
+    /// Signature over the composite hash
+    pub signature: String,
+    /// Timestamp
+    pub timestamp: DateTime<Utc>,
+}
+
+impl SampledAuditReceipt {
+    /// Compose per-sample outcomes into a signed receipt
+    pub fn new(
+        claim_template: impl Into<String>,
+        policy: SamplePolicy,
+        population: usize,
+        outcomes: Vec<SampleOutcome>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Self {
+        let claim_template = claim_template.into();
+        let selected_indices: Vec<usize> = outcomes.iter().map(|o| o.index).collect();
+        let indices_root = indices_root(&selected_indices);
+        // An empty sample proves nothing about the population
+        let final_proof = BinaryProof::from_bool(
+            !outcomes.is_empty() && outcomes.iter().all(|o| o.proof.exists()),
+        );
+        let c_zero = outcomes.iter().all(|o| o.c_zero);
+        let timestamp = sap4d::clock::now();
+
+        let mut receipt = Self {
+            coverage: Coverage::Sampled,
+            claim_template,
+            policy,
+            population,
+            selected_indices,
+            indices_root,
+            outcomes,
+            final_proof,
+            c_zero,
+            composite_hash: String::new(),
+            signature: String::new(),
+            timestamp,
+        };
+        receipt.composite_hash = receipt.compute_hash();
+        receipt.signature = sign_fn(&receipt.composite_hash);
+        receipt
+    }
+
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(format!("{:?}", self.coverage).as_bytes());
+        hasher.update(self.claim_template.as_bytes());
+        hasher.update(format!("{:?}", self.policy.size).as_bytes());
+        hasher.update(self.policy.seed.to_le_bytes());
+        hasher.update((self.population as u64).to_le_bytes());
+        hasher.update(self.indices_root.as_bytes());
+        for outcome in &self.outcomes {
+            hasher.update((outcome.index as u64).to_le_bytes());
+            hasher.update(outcome.claim.as_bytes());
+            hasher.update(outcome.receipt_hash.as_bytes());
+            hasher.update(format!("{:?}", outcome.proof).as_bytes());
+            hasher.update([outcome.c_zero as u8]);
+        }
+        hasher.update(format!("{:?}", self.final_proof).as_bytes());
+        hasher.update([self.c_zero as u8]);
+        hasher.update(self.timestamp.to_rfc3339().as_bytes());
+        hex::encode(hasher.finalize())
+    }
+
+    /// Number of records audited
+    pub fn sample_size(&self) -> usize {
+        self.outcomes.len()
+    }
+
+    /// Whether every sampled record proved its claim
+    pub fn proof_exists(&self) -> bool {
+        self.final_proof.exists()
+    }
+
+    /// Lower bound on the chance this sample would have caught at least one
+    /// failure if `failure_rate` of the population fails
+    pub fn detection_probability(&self, failure_rate: f64) -> f64 {
+        let failure_rate = failure_rate.clamp(0.0, 1.0);
+        1.0 - (1.0 - failure_rate).powi(self.sample_size() as i32)
+    }
+
+    /// Verify the composite's hash integrity
+    pub fn verify_hash(&self) -> bool {
+        let selected: Vec<usize> = self.outcomes.iter().map(|o| o.index).collect();
+        selected == self.selected_indices
+            && indices_root(&self.selected_indices) == self.indices_root
+            && self.compute_hash() == self.composite_hash
+    }
+
+    /// Full verification (hash + signature)
+    pub fn verify(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        self.verify_hash() && verify_fn(&self.composite_hash, &self.signature)
+    }
+}
+
+fn indices_root(indices: &[usize]) -> String {
+    let leaves: Vec<String> = indices.iter().map(|i| i.to_string()).collect();
+    MerkleTree::from_data(&leaves)
+        .root_hash()
+        .map(str::to_string)
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::service::AuditService;
+
+    fn mock_sign(hash: &str) -> String {
+        format!("SIG:{}", hash)
+    }
+
+    fn mock_verify(hash: &str, sig: &str) -> bool {
+        sig == mock_sign(hash)
+    }
+
+    fn population(n: usize) -> impl Iterator<Item = String> {
+        (0..n).map(|i| format!("event {} processed", i))
+    }
+
+    #[test]
+    fn test_selection_is_deterministic() {
+        for policy in [SamplePolicy::rate(0.1, 42), SamplePolicy::fixed(25, 42)] {
+            let (first, total) = policy.select(population(1000)).unwrap();
+            let (second, _) = policy.select(population(1000)).unwrap();
+            assert_eq!(total, 1000);
+            assert_eq!(first, second);
+
+            let reseeded = SamplePolicy { seed: 7, ..policy };
+            assert_ne!(reseeded.select(population(1000)).unwrap().0, first);
+        }
+
+        let (fixed, _) = SamplePolicy::fixed(25, 42).select(population(1000)).unwrap();
+        assert_eq!(fixed.len(), 25);
+        assert!(fixed.windows(2).all(|w| w[0].0 < w[1].0));
+
+        let (rated, _) = SamplePolicy::rate(0.1, 42).select(population(1000)).unwrap();
+        assert!((50..150).contains(&rated.len()));
+
+        let (all, _) = SamplePolicy::fixed(25, 42).select(population(10)).unwrap();
+        assert_eq!(all.len(), 10);
+
+        assert!(SamplePolicy::rate(1.5, 42).select(population(10)).is_err());
+    }
+
+    #[test]
+    fn test_receipt_records_population_and_sample() {
+        let mut service = AuditService::new();
+        let policy = SamplePolicy::fixed(20, 99);
+
+        let receipt = service
+            .audit_sample("Record '{record}' is valid", population(500), policy, mock_sign)
+            .unwrap();
+
+        assert_eq!(receipt.coverage, Coverage::Sampled);
+        assert_eq!(receipt.population, 500);
+        assert_eq!(receipt.sample_size(), 20);
+        assert_eq!(receipt.policy, policy);
+        let (expected, _) = policy.select(population(500)).unwrap();
+        let expected: Vec<usize> = expected.iter().map(|(i, _)| *i).collect();
+        assert_eq!(receipt.selected_indices, expected);
+        assert_eq!(receipt.outcomes[0].claim, format!("Record 'event {} processed' is valid", expected[0]));
+        assert!(receipt.proof_exists());
+        assert!(receipt.verify(mock_verify));
+        assert!(receipt.detection_probability(0.1) > 0.8);
+
+        let mut tampered = receipt.clone();
+        tampered.population = 5;
+        assert!(!tampered.verify(mock_verify));
+    }
+
+    #[test]
+    fn test_failing_sample_fails_composite() {
+        let mut service = AuditService::new();
+        let records = vec![
+            "event 0 processed".to_string(),
+            "P ∧ ¬P".to_string(),
+            "event 2 processed".to_string(),
+        ];
+
+        let receipt = service
+            .audit_sample("Record '{record}' is valid", records.into_iter(), SamplePolicy::rate(1.0, 1), mock_sign)
+            .unwrap();
+
+        assert_eq!(receipt.sample_size(), 3);
+        assert!(!receipt.outcomes[1].proof.exists());
+        assert!(!receipt.proof_exists());
+        assert!(receipt.verify(mock_verify));
+    }
+}
@@ -2,11 +2,20 @@
 //!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use crate::audit::{AuditReceipt, BinaryProof};
-// AuditResult is not directly used in this module
-use crate::levels::{L1Audit, L2Audit, L3Audit, SubOperation};
-use crate::merkle::MerkleLog;
-use crate::Result;
+use crate::anchor::{AnchorMetadata, AnchorProof, AnchorQueue, Anchorer, PendingAnchor};
+use crate::audit::{AuditReceipt, AuditResult, BinaryProof, FailureReason};
+use crate::levels::{
+    codes, ConsistencyChecker, L1Audit, L2Audit, L3Audit, LexicalOverlapChecker, OpVerdict, SubOperation,
+    DEFAULT_MIN_EVIDENCE_RELEVANCE,
+};
+use sap4d::engine::EngineConfig;
+use sap4d::{ContradictionDetector, ProofEngine};
+use std::collections::VecDeque;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use crate::merkle::{ConsistencyProof, LogSegment, MerkleLog};
+use crate::sampling::{SampleOutcome, SamplePolicy, SampledAuditReceipt, RECORD_PLACEHOLDER};
+use crate::{AuditError, Result};
 
 /// Configuration for the audit service
 #[derive(Debug, Clone)]
@@ -17,6 +26,25 @@ pub struct AuditConfig {
     pub max_evidence: usize,
     /// Enable audit logging
     pub enable_logging: bool,
+    /// L2 mapping-consistency checker
+    pub consistency_checker: Arc<dyn ConsistencyChecker>,
+    /// Evidence items checked in parallel by `audit_async` during L2
+    pub l2_concurrency: usize,
+    /// Reject evidence items that contradict each other at L1 and count
+    /// them toward C at L2
+    pub detect_contradictions: bool,
+    /// Patterns used when `detect_contradictions` is set
+    pub contradiction_detector: ContradictionDetector,
+    /// Checkpoint the audit log once it holds this many live entries
+    pub max_log_entries: Option<usize>,
+    /// Directory automatic checkpoints export segments to; without it,
+    /// sealed segments stay in memory until `export_log_segment`
+    pub segment_dir: Option<PathBuf>,
+    /// Anchor the log root once this many entries were appended since the
+    /// last anchor (needs an anchorer, see `AuditService::with_anchorer`)
+    pub anchor_every: Option<u64>,
+    /// File roots waiting to be anchored are kept in across restarts
+    pub anchor_queue_path: Option<PathBuf>,
 }
 
 impl Default for AuditConfig {
@@ -25,6 +53,14 @@ impl Default for AuditConfig {
             enable_l3: true,
             max_evidence: 100,
             enable_logging: true,
+            consistency_checker: Arc::new(LexicalOverlapChecker::default()),
+            l2_concurrency: 8,
+            detect_contradictions: false,
+            contradiction_detector: ContradictionDetector::default(),
+            max_log_entries: None,
+            segment_dir: None,
+            anchor_every: None,
+            anchor_queue_path: None,
         }
     }
 }
@@ -36,31 +72,63 @@ pub struct AuditService {
     l3: L3Audit,
     config: AuditConfig,
     log: MerkleLog,
+    anchorer: Option<Box<dyn Anchorer>>,
+    anchor_queue: AnchorQueue,
+    /// Proofs for roots anchored by this service, oldest first
+    anchor_proofs: Vec<AnchorProof>,
+    /// Log size when the last root was queued for anchoring
+    anchored_through: u64,
 }
 
 impl AuditService {
     /// Create a new audit service
     pub fn new() -> Self {
         Self {
-            l1: L1Audit::new(),
             l2: L2Audit::new(),
-            l3: L3Audit::new(),
-            config: AuditConfig::default(),
-            log: MerkleLog::new(),
+            ..Self::with_config(AuditConfig::default())
         }
     }
     
     /// Create with custom configuration
     pub fn with_config(config: AuditConfig) -> Self {
+        let mut l1 = L1Audit::new();
+        let mut l2 = L2Audit::with_checker(Box::new(config.consistency_checker.clone()));
+        if config.detect_contradictions {
+            l1 = l1.with_engine(ProofEngine::with_config(EngineConfig {
+                min_evidence_relevance: DEFAULT_MIN_EVIDENCE_RELEVANCE,
+                detect_contradictions: true,
+                contradiction_detector: config.contradiction_detector.clone(),
+                ..Default::default()
+            }));
+            l2 = l2.with_contradiction_detector(config.contradiction_detector.clone());
+        }
         Self {
-            l1: L1Audit::new(),
-            l2: L2Audit::new(),
+            l1,
+            l2,
             l3: L3Audit::new(),
             config,
             log: MerkleLog::new(),
+            anchorer: None,
+            anchor_queue: AnchorQueue::in_memory(),
+            anchor_proofs: Vec::new(),
+            anchored_through: 0,
         }
     }
     
+    /// Anchor the audit log root with `anchorer`
+    ///
+    /// Roots left queued by an earlier run are picked up from
+    /// `AuditConfig::anchor_queue_path` and anchored first.
+    pub fn with_anchorer(mut self, anchorer: impl Anchorer + 'static) -> Result<Self> {
+        if let Some(ref path) = self.config.anchor_queue_path {
+            self.anchor_queue = AnchorQueue::open(path).map_err(|e| {
+                AuditError::AnchorFailed(format!("cannot open anchor queue {}: {}", path.display(), e))
+            })?;
+        }
+        self.anchorer = Some(Box::new(anchorer));
+        Ok(self)
+    }
+    
     /// Perform full audit and generate receipt
     pub fn audit(
         &mut self,
@@ -81,43 +149,283 @@ impl AuditService {
     ) -> Result<AuditReceipt> {
         let mut results = Vec::new();
         
-        // L1 Audit
+        // L1 Audit (rejects empty claims, drops empty evidence)
         let l1_result = self.l1.audit(claim, evidence)?;
-        if self.config.enable_logging {
-            self.log.append(format!("L1: {} - {:?}", claim, l1_result.proof));
+        self.record(&mut results, &l1_result);
+        
+        // Without evidence there is nothing for L2/L3 to check
+        if l1_result.has_code(codes::NO_EVIDENCE) {
+            return Ok(self.finish_receipt(results, sign_fn));
         }
-        results.push(l1_result.clone());
+        let evidence = l1_result.evidence.as_slice();
         
         // L2 Audit
         let l2_result = self.l2.audit(claim, evidence, &l1_result)?;
-        if self.config.enable_logging {
-            self.log.append(format!("L2: {} - {:?}", claim, l2_result.proof));
-        }
-        results.push(l2_result.clone());
+        self.record(&mut results, &l2_result);
         
         // L3 Audit (if enabled and sub-operations provided)
+        self.finish_l3(claim, &l1_result, &l2_result, sub_ops, results, sign_fn)
+    }
+    
+    /// Start an audit whose sub-operations are pushed as they complete
+    ///
+    /// L1 and L2 run now; see [`IncrementalAudit`].
+    pub fn begin(&mut self, claim: &str, evidence: &[String]) -> Result<IncrementalAudit<'_>> {
+        let mut results = Vec::new();
+        
+        let l1_result = self.l1.audit(claim, evidence)?;
+        self.record(&mut results, &l1_result);
+        
+        let l2_result = if l1_result.has_code(codes::NO_EVIDENCE) {
+            None
+        } else {
+            let l2_result = self.l2.audit(claim, &l1_result.evidence, &l1_result)?;
+            self.record(&mut results, &l2_result);
+            Some(l2_result)
+        };
+        
+        Ok(IncrementalAudit {
+            service: self,
+            claim: claim.to_string(),
+            l1_result,
+            l2_result,
+            results,
+            ops: Vec::new(),
+        })
+    }
+    
+    /// Async [`AuditService::audit_with_ops`] for large evidence sets
+    ///
+    /// Levels still run in order (L1 → L2 → L3), but L2 checks up to
+    /// `AuditConfig::l2_concurrency` evidence items in parallel. The receipt
+    /// matches the sync path's regardless of completion order.
+    pub async fn audit_async(
+        &mut self,
+        claim: &str,
+        evidence: &[String],
+        sub_ops: &[SubOperation],
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<AuditReceipt> {
+        let mut results = Vec::new();
+        
+        let l1_result = self.l1.audit(claim, evidence)?;
+        self.record(&mut results, &l1_result);
+        
+        if l1_result.has_code(codes::NO_EVIDENCE) {
+            return Ok(self.finish_receipt(results, sign_fn));
+        }
+        let evidence = l1_result.evidence.as_slice();
+        
+        let l2_result = self
+            .l2
+            .audit_async(claim, evidence, &l1_result, self.config.l2_concurrency)
+            .await?;
+        self.record(&mut results, &l2_result);
+        
+        self.finish_l3(claim, &l1_result, &l2_result, sub_ops, results, sign_fn)
+    }
+    
+    /// Run L3 (if enabled) and sign the receipt
+    fn finish_l3(
+        &mut self,
+        claim: &str,
+        l1_result: &AuditResult,
+        l2_result: &AuditResult,
+        sub_ops: &[SubOperation],
+        mut results: Vec<AuditResult>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<AuditReceipt> {
         if self.config.enable_l3 {
-            let l3_result = self.l3.audit(claim, evidence, &l1_result, &l2_result, sub_ops)?;
-            if self.config.enable_logging {
-                self.log.append(format!("L3: {} - {:?}", claim, l3_result.proof));
-            }
-            results.push(l3_result);
+            let evidence = l1_result.evidence.as_slice();
+            let l3_result = self.l3.audit(claim, evidence, l1_result, l2_result, sub_ops)?;
+            self.record(&mut results, &l3_result);
         }
         
-        // Generate receipt
-        let receipt = AuditReceipt::new(results, sign_fn);
+        Ok(self.finish_receipt(results, sign_fn))
+    }
+    
+    /// Log a level result and add it to the receipt's results
+    fn record(&mut self, results: &mut Vec<AuditResult>, result: &AuditResult) {
+        self.log_result(result);
+        results.push(result.clone());
+    }
+    
+    fn log_result(&mut self, result: &AuditResult) {
+        if self.config.enable_logging {
+            self.append_log(format!("L{}: {} - {:?}", result.level.number(), result.claim, result.proof));
+        }
+    }
+    
+    /// Log a receipt produced by another service instance
+    ///
+    /// Appends exactly the entries this service would have logged had it run
+    /// the audit itself, so audits can run on per-request services while one
+    /// shared service keeps the log.
+    pub fn record_receipt(&mut self, receipt: &AuditReceipt) {
+        for result in &receipt.results {
+            self.log_result(result);
+        }
+        self.log_receipt(receipt);
+    }
+    
+    /// Audit a deterministic sample of a record stream (see [`crate::sampling`])
+    ///
+    /// Each selected record replaces `{record}` in `claim_template` and is
+    /// audited with itself as evidence. The composite verdict fails if any
+    /// sampled record fails.
+    pub fn audit_sample(
+        &mut self,
+        claim_template: &str,
+        records: impl Iterator<Item = String>,
+        policy: SamplePolicy,
+        sign_fn: impl Fn(&str) -> String,
+    ) -> Result<SampledAuditReceipt> {
+        let (selected, population) = policy.select(records)?;
         
+        let mut outcomes = Vec::with_capacity(selected.len());
+        for (index, record) in selected {
+            let claim = claim_template.replace(RECORD_PLACEHOLDER, &record);
+            let receipt = self.audit(&claim, &[record], &sign_fn)?;
+            outcomes.push(SampleOutcome {
+                index,
+                claim,
+                receipt_hash: receipt.receipt_hash,
+                proof: receipt.final_proof,
+                c_zero: receipt.c_zero,
+            });
+        }
+        
+        let receipt = SampledAuditReceipt::new(claim_template, policy, population, outcomes, &sign_fn);
         if self.config.enable_logging {
-            self.log.append(format!("Receipt: {} - {:?}", receipt.receipt_hash, receipt.final_proof));
+            self.append_log(format!(
+                "Sampled: {} of {} - {} - {:?}",
+                receipt.sample_size(),
+                receipt.population,
+                receipt.composite_hash,
+                receipt.final_proof
+            ));
         }
         
         Ok(receipt)
     }
     
+    /// Sign the receipt and record it in the audit log
+    fn finish_receipt(
+        &mut self,
+        results: Vec<AuditResult>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> AuditReceipt {
+        let receipt = AuditReceipt::new(results, sign_fn);
+        self.log_receipt(&receipt);
+        receipt
+    }
+    
+    fn log_receipt(&mut self, receipt: &AuditReceipt) {
+        if self.config.enable_logging {
+            self.append_log(format!("Receipt: {} - {:?}", receipt.receipt_hash, receipt.final_proof));
+            self.maybe_anchor();
+        }
+    }
+    
+    /// Append to the log, checkpointing as soon as it holds `max_log_entries`
+    fn append_log(&mut self, entry: String) {
+        self.log.append(entry);
+        if self.config.max_log_entries.is_some_and(|max| self.log.len() >= max) {
+            self.auto_checkpoint();
+        }
+    }
+    
+    /// Seal the log and, if configured, export the segment to `segment_dir`
+    fn auto_checkpoint(&mut self) {
+        let Some(number) = self.log.checkpoint().map(|s| s.number) else {
+            return;
+        };
+        if let Some(ref dir) = self.config.segment_dir {
+            let path = dir.join(format!("segment-{:06}.json", number));
+            // A failed export keeps the segment in memory for a later retry
+            if let Err(err) = self.log.export_segment(number, &path) {
+                tracing::warn!("failed to export audit log segment {} to {}: {}", number, path.display(), err);
+            }
+        }
+    }
+    
+    /// Queue the current log root and anchor every queued root, oldest first
+    ///
+    /// Returns the proofs anchored by this call. If the anchorer fails, the
+    /// failed root and those after it stay queued for the next attempt;
+    /// proofs anchored before the failure are still kept in
+    /// [`anchor_proofs`](Self::anchor_proofs).
+    pub fn anchor_now(&mut self) -> Result<Vec<AnchorProof>> {
+        if self.anchorer.is_none() {
+            return Err(AuditError::AnchorFailed("no anchorer configured".to_string()));
+        }
+        self.queue_root()?;
+        
+        let Some(anchorer) = self.anchorer.as_deref() else {
+            return Ok(Vec::new());
+        };
+        let report = self.anchor_queue.flush(anchorer);
+        self.anchor_proofs.extend(report.anchored.iter().cloned());
+        match report.error {
+            Some(e) => Err(AuditError::AnchorFailed(e.to_string())),
+            None => Ok(report.anchored),
+        }
+    }
+    
+    /// Proofs for every root this service anchored, oldest first
+    pub fn anchor_proofs(&self) -> &[AnchorProof] {
+        &self.anchor_proofs
+    }
+    
+    /// Roots waiting to be anchored, oldest first
+    pub fn pending_anchors(&self) -> &VecDeque<PendingAnchor> {
+        self.anchor_queue.pending()
+    }
+    
+    fn queue_root(&mut self) -> Result<()> {
+        let Some(root) = self.log.root_hash() else {
+            return Ok(());
+        };
+        // Already queued or anchored: nothing was appended since
+        let last = self
+            .anchor_queue
+            .pending()
+            .back()
+            .map(|pending| pending.root_hash.as_str())
+            .or_else(|| self.anchor_proofs.last().map(|proof| proof.root_hash.as_str()));
+        if last == Some(root.as_str()) {
+            return Ok(());
+        }
+        let entry_count = self.log.total_entries();
+        self.anchor_queue
+            .push(&root, AnchorMetadata::new(entry_count))
+            .map_err(|e| AuditError::AnchorFailed(format!("cannot persist anchor queue: {}", e)))?;
+        self.anchored_through = entry_count;
+        Ok(())
+    }
+    
+    /// Anchor if `anchor_every` entries were appended since the last anchor
+    fn maybe_anchor(&mut self) {
+        let Some(every) = self.config.anchor_every else {
+            return;
+        };
+        if self.anchorer.is_none() || self.log.total_entries() - self.anchored_through < every {
+            return;
+        }
+        // The root stays queued and is retried with the next anchor
+        if let Err(err) = self.anchor_now() {
+            tracing::warn!("audit log anchor failed: {}", err);
+        }
+    }
+    
     /// Quick verification (L1 only)
     pub fn quick_verify(&self, claim: &str, evidence: &[String]) -> Result<BinaryProof> {
-        let result = self.l1.audit(claim, evidence)?;
-        Ok(result.proof)
+        Ok(self.quick_audit(claim, evidence)?.proof)
+    }
+    
+    /// L1 result behind [`AuditService::quick_verify`], with its failure reason
+    pub fn quick_audit(&self, claim: &str, evidence: &[String]) -> Result<AuditResult> {
+        self.l1.audit(claim, evidence)
     }
     
     /// Verify a receipt
@@ -134,10 +442,30 @@ impl AuditService {
         self.log.root_hash()
     }
     
+    /// Proof that the audit log at `old_size` entries is a prefix of the log now
+    pub fn log_consistency_proof(&self, old_size: u64) -> Option<ConsistencyProof> {
+        self.log.consistency_proof(old_size)
+    }
+    
     /// Get audit log entries
     pub fn log_entries(&self) -> &[crate::merkle::LogEntry] {
         self.log.entries()
     }
+    
+    /// Seal the live audit log entries into a segment
+    pub fn checkpoint_log(&mut self) -> Option<&LogSegment> {
+        self.log.checkpoint()
+    }
+    
+    /// Sealed audit log segments, oldest first
+    pub fn log_segments(&self) -> &[LogSegment] {
+        self.log.segments()
+    }
+    
+    /// Write a sealed audit log segment with its inclusion proofs to `path`
+    pub fn export_log_segment(&mut self, number: u64, path: impl AsRef<Path>) -> std::io::Result<()> {
+        self.log.export_segment(number, path)
+    }
 }
 
 impl Default for AuditService {
@@ -146,8 +474,79 @@ impl Default for AuditService {
     }
 }
 
+/// An audit of a long-running operation, fed one sub-operation at a time
+///
+/// Each [`push_op`](Self::push_op) checks the operation against Ω-SSOT as
+/// soon as it completes and appends it to the service's Merkle log, so a
+/// non-conformant step is known (and tamper-evident) hours before the run
+/// ends. The caller decides whether to carry on or stop;
+/// [`finish`](Self::finish) runs L3 over every pushed operation and signs
+/// the same receipt [`AuditService::audit_with_ops`] would have.
+pub struct IncrementalAudit<'a> {
+    service: &'a mut AuditService,
+    claim: String,
+    l1_result: AuditResult,
+    /// `None` when L1 found no evidence and L2/L3 are skipped
+    l2_result: Option<AuditResult>,
+    results: Vec<AuditResult>,
+    ops: Vec<SubOperation>,
+}
+
+impl IncrementalAudit<'_> {
+    /// Check `op` and log it
+    ///
+    /// A non-conformant operation is recorded and reported in the verdict.
+    /// An operation that does not link to the last one pushed is rejected
+    /// with [`AuditError::L3Failure`] and not recorded.
+    pub fn push_op(&mut self, op: SubOperation) -> Result<OpVerdict> {
+        let expected = self.ops.last().map(|last| last.hash.as_str());
+        if op.prev_hash.as_deref() != expected {
+            return Err(AuditError::L3Failure(format!(
+                "operation {} ({}) does not link to the previous operation",
+                self.ops.len(),
+                op.name
+            )));
+        }
+        
+        let verdict = self.service.l3.check_operation(self.ops.len(), &op);
+        if self.service.config.enable_logging {
+            self.service.log.append(format!(
+                "Op {}: {} - {} - {}",
+                verdict.index, op.name, op.hash, verdict.finding.id
+            ));
+            self.service.maybe_anchor();
+        }
+        self.ops.push(op);
+        Ok(verdict)
+    }
+    
+    /// Whether L1 and L2 passed (otherwise L3 will fail however the ops turn out)
+    pub fn lower_levels_passed(&self) -> bool {
+        self.l1_result.proof.exists() && self.l2_result.as_ref().is_some_and(|r| r.proof.exists())
+    }
+    
+    /// Operations pushed so far, in order
+    pub fn operations(&self) -> &[SubOperation] {
+        &self.ops
+    }
+    
+    /// Current root of the service's audit log, covering every pushed op
+    pub fn log_root_hash(&mut self) -> Option<String> {
+        self.service.log_root_hash()
+    }
+    
+    /// Run L3 over the pushed operations and sign the receipt
+    pub fn finish(self, sign_fn: impl FnOnce(&str) -> String) -> Result<AuditReceipt> {
+        let Self { service, claim, l1_result, l2_result, results, ops } = self;
+        match l2_result {
+            Some(l2_result) => service.finish_l3(&claim, &l1_result, &l2_result, &ops, results, sign_fn),
+            None => Ok(service.finish_receipt(results, sign_fn)),
+        }
+    }
+}
+
 /// Request for audit API
-#[derive(Debug, Clone, serde::Deserialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AuditRequest {
     pub claim: String,
     pub evidence: Vec<String>,
@@ -156,12 +555,15 @@ pub struct AuditRequest {
 }
 
 /// Response from audit API
-#[derive(Debug, Clone, serde::Serialize)]
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
 pub struct AuditResponse {
     pub proof_exists: bool,
     pub c_zero: bool,
     pub receipt_hash: String,
     pub timestamp: String,
+    /// Why no proof exists (first failing level); absent when it does
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<FailureReason>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub receipt: Option<AuditReceipt>,
 }
@@ -173,6 +575,7 @@ impl From<AuditReceipt> for AuditResponse {
             c_zero: receipt.c_zero,
             receipt_hash: receipt.receipt_hash.clone(),
             timestamp: receipt.timestamp.to_rfc3339(),
+            reason: receipt.failure_reason().cloned(),
             receipt: Some(receipt),
         }
     }
@@ -181,6 +584,7 @@ impl From<AuditReceipt> for AuditResponse {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::levels::{finding_ids, AuditLevel};
     
     fn mock_sign(hash: &str) -> String {
         use sha2::{Sha256, Digest};
@@ -219,7 +623,7 @@ mod tests {
         
         let proof = service.quick_verify(
             "Simple claim",
-            &["Supporting evidence".to_string()],
+            &["Supporting evidence for the simple claim".to_string()],
         ).unwrap();
         
         assert!(proof.exists());
@@ -238,6 +642,27 @@ mod tests {
         assert!(!service.log_entries().is_empty());
         assert!(service.log_root_hash().is_some());
     }
+
+    #[test]
+    fn test_record_receipt_matches_own_log() {
+        let evidence = ["Evidence for the logged claim".to_string()];
+        let mut direct = AuditService::new();
+        let receipt = direct.audit("Logged claim", &evidence, mock_sign).unwrap();
+    
+        let mut worker = AuditService::with_config(AuditConfig {
+            enable_logging: false,
+            ..AuditConfig::default()
+        });
+        let remote = worker.audit("Logged claim", &evidence, mock_sign).unwrap();
+        assert!(worker.log_entries().is_empty());
+    
+        let mut shared = AuditService::new();
+        shared.record_receipt(&remote);
+        let normalized = |service: &AuditService, hash: &str| -> Vec<String> {
+            service.log_entries().iter().map(|e| e.data.replace(hash, "<hash>")).collect()
+        };
+        assert_eq!(normalized(&shared, &remote.receipt_hash), normalized(&direct, &receipt.receipt_hash));
+    }
     
     #[test]
     fn test_audit_with_sub_ops() {
@@ -257,5 +682,360 @@ mod tests {
         // Should have 3 results (L1, L2, L3)
         assert_eq!(receipt.results.len(), 3);
     }
+
+    #[test]
+    fn test_audit_with_engine_recorded_ops() {
+        let claim = "The sky reflects certain wavelengths";
+        let evidence = vec![
+            "The sky is blue".to_string(),
+            "Blue things reflect certain wavelengths".to_string(),
+        ];
+        let (_, _, ops) = sap4d::ProofEngine::new()
+            .prove_audited(claim, evidence.clone(), mock_sign)
+            .unwrap();
+
+        let mut service = AuditService::new();
+        let receipt = service.audit_with_ops(claim, &evidence, &ops, mock_sign).unwrap();
+        assert_eq!(receipt.results[2].level, AuditLevel::L3);
+        assert!(receipt.results[2].proof.exists());
+
+        // A tampered middle operation fails L3
+        let mut tampered = ops;
+        tampered[1].output = "C=1".into();
+        let receipt = service.audit_with_ops(claim, &evidence, &tampered, mock_sign).unwrap();
+        assert!(!receipt.results[2].proof.exists());
+    }
+
+    #[test]
+    fn test_config_selects_checker() {
+        let config = AuditConfig {
+            consistency_checker: Arc::new(LexicalOverlapChecker::new(2)),
+            ..Default::default()
+        };
+        let mut service = AuditService::with_config(config);
+        
+        let receipt = service.audit("the cat sat", &["a cat was seen".to_string()], mock_sign).unwrap();
+        let l2 = &receipt.results[1];
+        
+        let unrelated = l2.findings_with_id(finding_ids::L2_EVIDENCE_UNRELATED).next().unwrap();
+        assert!(unrelated.message.contains("(checker: lexical-overlap)"));
+    }
+    
+    fn levels_run(receipt: &AuditReceipt) -> Vec<AuditLevel> {
+        receipt.results.iter().map(|r| r.level).collect()
+    }
+    
+    /// Evidence set with inconsistencies and unsupported items sprinkled in
+    fn large_evidence(n: usize) -> Vec<String> {
+        (0..n)
+            .map(|i| match i % 50 {
+                7 => format!("Ledger entry {} is inconsistent", i),
+                13 => format!("Unrelated note {}", i),
+                _ => format!("Ledger entry {} balances", i),
+            })
+            .collect()
+    }
+    
+    /// Level, proof, C=0, findings and codes of one result
+    type Comparable = (AuditLevel, BinaryProof, bool, Vec<String>, Vec<String>);
+    
+    fn comparable(receipt: &AuditReceipt) -> Vec<Comparable> {
+        receipt
+            .results
+            .iter()
+            .map(|r| (r.level, r.proof, r.c_zero, r.findings.clone(), r.codes.clone()))
+            .collect()
+    }
+    
+    #[test]
+    fn test_max_log_entries_checkpoints() {
+        let dir = std::env::temp_dir().join(format!("audit-segments-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let config = AuditConfig {
+            max_log_entries: Some(3),
+            segment_dir: Some(dir.clone()),
+            ..Default::default()
+        };
+        let mut service = AuditService::with_config(config);
+        let evidence = vec!["Evidence for the claim".to_string()];
+        
+        // Four entries per audit (L1, L2, L3, receipt); segments cut across audits
+        for _ in 0..5 {
+            service.audit("A claim", &evidence, mock_sign).unwrap();
+        }
+        
+        assert_eq!(service.log_segments().len(), 6);
+        assert_eq!(service.log_entries().len(), 2);
+        assert!(service.log_root_hash().is_some());
+        
+        let archive = crate::merkle::ArchivedSegment::load(dir.join("segment-000001.json")).unwrap();
+        assert_eq!(archive.segment, service.log_segments()[1]);
+        assert_eq!(archive.segment.first_index, 3);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+    
+    #[tokio::test]
+    async fn test_async_matches_sync() {
+        let claim = "The ledger balances";
+        let ops = vec![SubOperation::new("init", "start", "middle", None)];
+        
+        for evidence in [large_evidence(1000), vec!["Ledger balances".to_string()], vec![]] {
+            let sync = AuditService::new().audit_with_ops(claim, &evidence, &ops, mock_sign).unwrap();
+            let mut service = AuditService::new();
+            let parallel = service.audit_async(claim, &evidence, &ops, mock_sign).await.unwrap();
+            
+            assert_eq!(comparable(&sync), comparable(&parallel));
+            assert_eq!(sync.final_proof, parallel.final_proof);
+            assert!(parallel.verify(mock_verify));
+        }
+    }
+    
+    /// Checker that finishes later items first
+    struct ReversedLatency;
+    
+    impl ConsistencyChecker for ReversedLatency {
+        fn name(&self) -> &str {
+            "reversed-latency"
+        }
+        
+        fn supports(&self, _claim: &str, evidence: &str) -> bool {
+            let index: u64 = evidence.rsplit(' ').next().unwrap().parse().unwrap();
+            std::thread::sleep(std::time::Duration::from_millis(20 - index));
+            index % 2 == 0
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_async_findings_ordered_by_evidence_index() {
+        let config = AuditConfig {
+            consistency_checker: Arc::new(ReversedLatency),
+            l2_concurrency: 16,
+            ..Default::default()
+        };
+        let evidence: Vec<String> = (0..16).map(|i| format!("item {}", i)).collect();
+        
+        let mut service = AuditService::with_config(config);
+        let receipt = service.audit_async("item", &evidence, &[], mock_sign).await.unwrap();
+        
+        let unsupported: Vec<Option<usize>> = receipt.results[1]
+            .findings_with_id(finding_ids::L2_EVIDENCE_UNRELATED)
+            .map(|f| f.evidence_index)
+            .collect();
+        let expected: Vec<Option<usize>> = (1..16).step_by(2).map(Some).collect();
+        assert_eq!(unsupported, expected);
+        assert_eq!(receipt.finding_counts()[finding_ids::L2_EVIDENCE_UNRELATED], 8);
+    }
+    
+    #[test]
+    fn test_empty_claim_rejected() {
+        let mut service = AuditService::new();
+        
+        for claim in ["", "   \t\n"] {
+            let result = service.audit(claim, &["Evidence".to_string()], mock_sign);
+            assert!(matches!(result, Err(crate::AuditError::InvalidClaim)));
+        }
+        assert!(matches!(
+            service.quick_verify("", &[]),
+            Err(crate::AuditError::InvalidClaim)
+        ));
+        assert!(service.log_entries().is_empty());
+    }
+    
+    #[test]
+    fn test_empty_evidence_list_skips_l2_l3() {
+        let mut service = AuditService::new();
+        
+        let receipt = service.audit("A claim", &[], mock_sign).unwrap();
+        
+        assert_eq!(levels_run(&receipt), vec![AuditLevel::L1]);
+        assert_eq!(receipt.results[0].codes, vec![codes::NO_EVIDENCE]);
+        assert!(!receipt.proof_exists());
+    }
+    
+    #[test]
+    fn test_list_of_empty_evidence() {
+        let mut service = AuditService::new();
+        
+        let evidence = vec!["".to_string(), "  ".to_string()];
+        let receipt = service.audit("A claim", &evidence, mock_sign).unwrap();
+        
+        assert_eq!(levels_run(&receipt), vec![AuditLevel::L1]);
+        assert_eq!(
+            receipt.results[0].codes,
+            vec![codes::EMPTY_EVIDENCE_DROPPED, codes::NO_EVIDENCE]
+        );
+        let dropped = receipt.results[0].findings_with_id(finding_ids::L1_EVIDENCE_DROPPED).next().unwrap();
+        assert!(dropped.message.contains("Dropped 2"));
+    }
+    
+    #[test]
+    fn test_mixed_evidence_filters_empties() {
+        let mut service = AuditService::new();
+        
+        let evidence = vec!["Evidence for the claim".to_string(), "".to_string()];
+        let receipt = service.audit("A claim", &evidence, mock_sign).unwrap();
+        
+        assert_eq!(levels_run(&receipt), vec![AuditLevel::L1, AuditLevel::L2, AuditLevel::L3]);
+        assert_eq!(receipt.results[0].codes, vec![codes::EMPTY_EVIDENCE_DROPPED]);
+        assert_eq!(receipt.results[1].evidence, vec!["Evidence for the claim".to_string()]);
+        assert!(receipt.results[1].codes.is_empty());
+    }
+    
+    #[test]
+    fn test_incremental_matches_batch() {
+        let claim = "The sky reflects certain wavelengths";
+        let evidence = vec![
+            "The sky is blue".to_string(),
+            "Blue things reflect certain wavelengths".to_string(),
+        ];
+        let (_, _, ops) = sap4d::ProofEngine::new()
+            .prove_audited(claim, evidence.clone(), mock_sign)
+            .unwrap();
+        
+        let batch = AuditService::new().audit_with_ops(claim, &evidence, &ops, mock_sign).unwrap();
+        
+        let mut service = AuditService::new();
+        let mut audit = service.begin(claim, &evidence).unwrap();
+        assert!(audit.lower_levels_passed());
+        let mut roots = Vec::new();
+        for op in &ops {
+            let verdict = audit.push_op(op.clone()).unwrap();
+            assert!(verdict.conforms);
+            assert_eq!(verdict.finding.id, finding_ids::L3_SUBOP_CONFORMS);
+            roots.push(audit.log_root_hash().unwrap());
+        }
+        // Every op moves the log root as it is pushed
+        roots.dedup();
+        assert_eq!(roots.len(), ops.len());
+        
+        let receipt = audit.finish(mock_sign).unwrap();
+        assert!(receipt.verify(mock_verify));
+        assert_eq!(receipt.final_proof, batch.final_proof);
+        let ids = |r: &AuditReceipt| -> Vec<Vec<String>> {
+            r.results.iter().map(|r| r.structured_findings.iter().map(|f| f.id.clone()).collect()).collect()
+        };
+        assert_eq!(ids(&receipt), ids(&batch));
+        
+        // L1, L2, one entry per op, L3, receipt
+        assert_eq!(service.log_entries().len(), 2 + ops.len() + 2);
+        assert!(service.log_entries()[2].data.starts_with("Op 0: "));
+    }
+    
+    #[test]
+    fn test_incremental_reports_nonconformant_op_immediately() {
+        let mut service = AuditService::new();
+        let mut audit = service.begin("The ledger balances", &["The ledger balances".to_string()]).unwrap();
+        
+        let first = SubOperation::new("init", "start", "middle", None);
+        let violating = SubOperation::new("next", "middle", "a contradiction", Some(first.hash.clone()));
+        let last = SubOperation::new("done", "a contradiction", "end", Some(violating.hash.clone()));
+        
+        assert!(audit.lower_levels_passed());
+        assert!(audit.push_op(first).unwrap().conforms);
+        let verdict = audit.push_op(violating).unwrap();
+        assert!(!verdict.conforms);
+        assert_eq!(verdict.index, 1);
+        assert_eq!(verdict.finding.id, finding_ids::L3_SUBOP_NONCONFORMANT);
+        assert!(matches!(verdict.reason, Some(FailureReason::AxiomViolation { .. })));
+        
+        // The caller chose to continue; the receipt still fails L3
+        assert!(audit.push_op(last).unwrap().conforms);
+        let receipt = audit.finish(mock_sign).unwrap();
+        assert_eq!(receipt.results[2].level, AuditLevel::L3);
+        assert!(!receipt.results[2].proof.exists());
+        assert_eq!(receipt.failure_reason(), verdict.reason.as_ref());
+    }
+    
+    #[test]
+    fn test_incremental_rejects_unlinked_op() {
+        let mut service = AuditService::new();
+        let mut audit = service.begin("The ledger balances", &["The ledger balances".to_string()]).unwrap();
+        
+        let first = SubOperation::new("init", "start", "middle", None);
+        audit.push_op(first.clone()).unwrap();
+        
+        let unlinked = SubOperation::new("next", "middle", "end", None);
+        assert!(matches!(audit.push_op(unlinked), Err(AuditError::L3Failure(_))));
+        assert_eq!(audit.operations().len(), 1);
+        
+        let linked = SubOperation::new("next", "middle", "end", Some(first.hash.clone()));
+        audit.push_op(linked).unwrap();
+        let receipt = audit.finish(mock_sign).unwrap();
+        assert!(receipt.results[2].proof.exists());
+    }
+    
+    fn scratch(name: &str) -> PathBuf {
+        let path = std::env::temp_dir().join(format!("audit-service-{}-{}", std::process::id(), name));
+        let _ = std::fs::remove_file(&path);
+        path
+    }
+    
+    #[test]
+    fn test_anchor_every_n_entries() {
+        let anchors = scratch("anchors.jsonl");
+        let config = AuditConfig {
+            anchor_every: Some(6),
+            ..AuditConfig::default()
+        };
+        let mut service = AuditService::with_config(config)
+            .with_anchorer(crate::anchor::FileAnchorer::new(&anchors, mock_sign))
+            .unwrap();
+        let evidence = ["The ledger balances".to_string()];
+        
+        // One audit logs four entries (L1, L2, L3, receipt): not yet due
+        service.audit("The ledger balances", &evidence, mock_sign).unwrap();
+        assert!(service.anchor_proofs().is_empty());
+        
+        service.audit("The ledger balances", &evidence, mock_sign).unwrap();
+        let proofs = service.anchor_proofs().to_vec();
+        assert_eq!(proofs.len(), 1);
+        assert_eq!(proofs[0].metadata.entry_count, 8);
+        let root = service.log_root_hash().unwrap();
+        assert!(crate::anchor::verify_anchor(&proofs[0], &root, mock_verify));
+        assert_eq!(crate::anchor::load_anchor_file(&anchors).unwrap(), proofs);
+        
+        // Nothing new since the last anchor: the same root is not queued twice
+        assert!(service.anchor_now().unwrap().is_empty());
+        assert!(service.pending_anchors().is_empty());
+        
+        assert!(matches!(AuditService::new().anchor_now(), Err(AuditError::AnchorFailed(_))));
+        let _ = std::fs::remove_file(anchors);
+    }
+    
+    #[test]
+    fn test_failed_anchor_survives_restart() {
+        let queue = scratch("queue.json");
+        let anchors = scratch("restart-anchors.jsonl");
+        let config = AuditConfig {
+            anchor_queue_path: Some(queue.clone()),
+            ..AuditConfig::default()
+        };
+        let evidence = ["The ledger balances".to_string()];
+        
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let unreachable = crate::anchor::HttpAnchorer::new(format!("http://{}/anchor", listener.local_addr().unwrap()));
+        drop(listener);
+        let mut offline = AuditService::with_config(config.clone()).with_anchorer(unreachable).unwrap();
+        offline.audit("The ledger balances", &evidence, mock_sign).unwrap();
+        let first_root = offline.log_root_hash().unwrap();
+        assert!(matches!(offline.anchor_now(), Err(AuditError::AnchorFailed(_))));
+        assert_eq!(offline.pending_anchors().len(), 1);
+        assert_eq!(offline.pending_anchors()[0].attempts, 1);
+        drop(offline);
+        
+        let mut online = AuditService::with_config(config)
+            .with_anchorer(crate::anchor::FileAnchorer::new(&anchors, mock_sign))
+            .unwrap();
+        assert_eq!(online.pending_anchors()[0].root_hash, first_root);
+        online.audit("The ledger balances", &evidence, mock_sign).unwrap();
+        let proofs = online.anchor_now().unwrap();
+        assert_eq!(proofs.len(), 2);
+        assert_eq!(proofs[0].root_hash, first_root);
+        assert_eq!(proofs[1].root_hash, online.log_root_hash().unwrap());
+        assert!(online.pending_anchors().is_empty());
+        
+        let _ = std::fs::remove_file(queue);
+        let _ = std::fs::remove_file(anchors);
+    }
 }
 
@@ -0,0 +1,170 @@
+//! Audit receipt verification without the service
+//!
+//! Built by the `audit-verify` feature, which depends only on serde, sha2,
+//! hex and sap4d's `verify-only` build. [`AuditReceipt`] mirrors
+//! [`crate::audit::AuditReceipt`] with the level and proof as their
+//! serialized names and timestamps as the strings auditd writes. This is
+//! the only implementation of the hash rules; the service hashes its own
+//! results by reading them back through this module.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::hashing::FieldHasher;
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+
+/// The hashed parts of one level's result
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditResult {
+    /// `L1`, `L2` or `L3`
+    pub level: String,
+    /// `ProofExists` or `NoProofExists`
+    pub proof: String,
+    pub claim: String,
+    pub evidence: Vec<String>,
+    pub axioms: Vec<String>,
+    pub c_zero: bool,
+    /// Absent from receipts written before findings had ids
+    #[serde(default)]
+    pub structured_findings: Vec<Finding>,
+    /// Absent when the result has none
+    #[serde(default)]
+    pub codes: Vec<String>,
+    /// Absent when the proof exists
+    #[serde(default)]
+    pub reason: Option<FailureReason>,
+    pub hash: String,
+    /// 1 if absent; see `sap4d::hashing`
+    #[serde(default = "sap4d::hashing::legacy_hash_version")]
+    pub hash_version: u32,
+    /// RFC 3339, as written by auditd
+    pub timestamp: String,
+}
+
+/// A failure reason: its `code` tag and the fields beside it
+#[derive(Debug, Clone, Deserialize)]
+pub struct FailureReason {
+    pub code: String,
+    #[serde(flatten)]
+    pub details: BTreeMap<String, ReasonValue>,
+}
+
+/// Value of a failure reason field
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum ReasonValue {
+    Number(u64),
+    Text(String),
+}
+
+impl FailureReason {
+    /// `name=value`, sorted by name, as the service hashes them
+    fn details(&self) -> Vec<String> {
+        self.details
+            .iter()
+            .map(|(name, value)| match value {
+                ReasonValue::Number(n) => format!("{}={}", name, n),
+                ReasonValue::Text(text) => format!("{}={}", name, text),
+            })
+            .collect()
+    }
+}
+
+/// The hashed part of a finding
+#[derive(Debug, Clone, Deserialize)]
+pub struct Finding {
+    pub id: String,
+}
+
+impl AuditResult {
+    /// Verify the result's integrity
+    pub fn verify_integrity(&self) -> bool {
+        self.compute_hash().as_deref() == Some(self.hash.as_str())
+    }
+
+    /// Hash under the rules of `self.hash_version` (`None` for an unknown version)
+    pub(crate) fn compute_hash(&self) -> Option<String> {
+        let timestamp = sap4d::verify::to_rfc3339(&self.timestamp)?;
+        let finding_ids: Vec<&str> = self.structured_findings.iter().map(|f| f.id.as_str()).collect();
+        match self.hash_version {
+            1 => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.level.as_bytes());
+                hasher.update(self.proof.as_bytes());
+                hasher.update(self.claim.as_bytes());
+                for e in &self.evidence {
+                    hasher.update(e.as_bytes());
+                }
+                for a in &self.axioms {
+                    hasher.update(a.as_bytes());
+                }
+                for id in &finding_ids {
+                    hasher.update(id.as_bytes());
+                }
+                hasher.update([self.c_zero as u8]);
+                hasher.update(timestamp.as_bytes());
+                Some(hex::encode(hasher.finalize()))
+            }
+            2 => {
+                let hasher = FieldHasher::new("axiom-audit.result")
+                    .str(&self.level)
+                    .str(&self.proof)
+                    .str(&self.claim)
+                    .list(&self.evidence)
+                    .list(&self.axioms)
+                    .list(&finding_ids)
+                    .bool(self.c_zero)
+                    .str(&timestamp);
+                match &self.reason {
+                    Some(reason) => Some(hasher.list(&self.codes).str(&reason.code).list(&reason.details()).finish()),
+                    None if self.codes.is_empty() => Some(hasher.finish()),
+                    None => Some(hasher.list(&self.codes).finish()),
+                }
+            }
+            _ => None,
+        }
+    }
+}
+
+/// The hashed and signed parts of an audit receipt
+#[derive(Debug, Clone, Deserialize)]
+pub struct AuditReceipt {
+    pub results: Vec<AuditResult>,
+    pub receipt_hash: String,
+    pub signature: String,
+    /// RFC 3339, as written by auditd
+    pub timestamp: String,
+}
+
+impl AuditReceipt {
+    /// Verify the receipt's hash integrity
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().as_deref() == Some(self.receipt_hash.as_str())
+    }
+
+    /// Verify the receipt's signature
+    pub fn verify_signature(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        verify_fn(&self.receipt_hash, &self.signature)
+    }
+
+    /// Full verification (every result, the receipt hash and the signature)
+    pub fn verify(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        self.results.iter().all(AuditResult::verify_integrity) && self.verify_hash() && self.verify_signature(verify_fn)
+    }
+
+    fn compute_hash(&self) -> Option<String> {
+        let timestamp = sap4d::verify::to_rfc3339(&self.timestamp)?;
+        Some(receipt_hash(self.results.iter().map(|r| r.hash.as_str()), &timestamp))
+    }
+}
+
+/// Receipt hash over the results' hashes and the RFC 3339 timestamp
+pub(crate) fn receipt_hash<'a>(result_hashes: impl IntoIterator<Item = &'a str>, timestamp: &str) -> String {
+    let mut hasher = Sha256::new();
+    for hash in result_hashes {
+        hasher.update(hash.as_bytes());
+    }
+    hasher.update(timestamp.as_bytes());
+    hex::encode(hasher.finalize())
+}
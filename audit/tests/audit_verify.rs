@@ -0,0 +1,143 @@
+//! Audit receipt verification against a pinned fixture
+//!
+//! Runs under both the default features and
+//! `--no-default-features --features audit-verify`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::verify::AuditReceipt;
+use sha2::{Digest, Sha256};
+
+const RECEIPT: &str = include_str!("fixtures/audit_receipt.json");
+/// A hash-version-1 result issued with codes, which v1 does not hash
+const RESULT_V1_CODES: &str = include_str!("fixtures/audit_result_v1_codes.json");
+
+/// Signer the fixture was issued with
+fn sign(hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"FIXTURE_SIG:");
+    hasher.update(hash.as_bytes());
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+}
+
+fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+fn load() -> AuditReceipt {
+    serde_json::from_str(RECEIPT).unwrap()
+}
+
+#[test]
+fn test_fixture_verifies() {
+    let receipt = load();
+    assert_eq!(receipt.results.len(), 3);
+    // Issued before field hashing, so every result hashes under v1
+    assert!(receipt.results.iter().all(|r| r.hash_version == sap4d::hashing::LEGACY_HASH_VERSION));
+    assert!(receipt.results.iter().all(|r| r.verify_integrity()));
+    assert!(receipt.verify(verify));
+}
+
+#[test]
+fn test_tampering_is_detected() {
+    let mut proof = load();
+    proof.results[1].proof = "NoProofExists".to_string();
+    assert!(!proof.results[1].verify_integrity());
+    assert!(proof.verify_hash());
+    assert!(!proof.verify(verify));
+
+    let mut dropped = load();
+    dropped.results.pop();
+    assert!(!dropped.verify_hash());
+
+    let mut later = load();
+    later.timestamp = "2025-06-01T12:00:01.500Z".to_string();
+    assert!(!later.verify_hash());
+
+    let mut forged = load();
+    forged.signature = sign("forged");
+    assert!(!forged.verify(verify));
+}
+
+#[test]
+fn test_v1_result_with_codes_verifies() {
+    let result: axiom_audit::verify::AuditResult = serde_json::from_str(RESULT_V1_CODES).unwrap();
+    assert_eq!(result.hash_version, sap4d::hashing::LEGACY_HASH_VERSION);
+    assert_eq!(result.codes.len(), 2);
+    assert!(result.verify_integrity());
+
+    #[cfg(feature = "service")]
+    {
+        let full: axiom_audit::AuditResult = serde_json::from_str(RESULT_V1_CODES).unwrap();
+        assert!(full.verify_integrity());
+    }
+}
+
+/// The service's receipt type agrees with the lightweight verifier
+#[cfg(feature = "service")]
+#[test]
+fn test_service_receipts_verify_without_the_service() {
+    let full: axiom_audit::AuditReceipt = serde_json::from_str(RECEIPT).unwrap();
+    assert!(full.verify(verify));
+
+    let result = axiom_audit::AuditResult::new(
+        axiom_audit::AuditLevel::L1,
+        axiom_audit::BinaryProof::ProofExists,
+        "Water boils",
+        vec!["Heat applied".to_string()],
+        vec!["A1_IDENTITY".to_string()],
+        true,
+        vec![axiom_audit::Finding::new(
+            "F-L1-CLAIM-SUPPORTED",
+            axiom_audit::AuditLevel::L1,
+            axiom_audit::FindingSeverity::Info,
+            "Claim supported by evidence",
+        )],
+    )
+    .with_codes(vec!["EMPTY_EVIDENCE_DROPPED".to_string()]);
+    let receipt = axiom_audit::AuditReceipt::new(vec![result], sign);
+    let lite: AuditReceipt = serde_json::from_str(&serde_json::to_string(&receipt).unwrap()).unwrap();
+    assert!(lite.verify(verify));
+
+    // Finding ids, and codes under hash version 2, are hashed by both
+    let mut renamed = lite.clone();
+    renamed.results[0].structured_findings[0].id = "F-L1-CLAIM-UNSUPPORTED".to_string();
+    assert!(!renamed.results[0].verify_integrity());
+    let mut recoded = lite;
+    recoded.results[0].codes.clear();
+    assert!(!recoded.results[0].verify_integrity());
+}
+
+/// Both verifiers hash a failed result's reason, fields included
+#[cfg(feature = "service")]
+#[test]
+fn test_failure_reasons_verify_without_the_service() {
+    use axiom_audit::{AuditLevel, BinaryProof, FailureReason};
+
+    let failed = |reason| {
+        axiom_audit::AuditResult::new(AuditLevel::L2, BinaryProof::NoProofExists, "Water boils", vec![], vec![], false, vec![])
+            .with_reason(reason)
+    };
+    let receipt = axiom_audit::AuditReceipt::new(
+        vec![
+            failed(FailureReason::NoEvidence),
+            failed(FailureReason::LowerLevelFailed { level: AuditLevel::L1 }),
+            failed(FailureReason::InsufficientEvidence { required: 3, provided: 1 }),
+        ],
+        sign,
+    );
+    let json = serde_json::to_value(&receipt).unwrap();
+    let lite: AuditReceipt = serde_json::from_value(json.clone()).unwrap();
+    assert!(lite.verify(verify));
+
+    let mut rewritten = json.clone();
+    rewritten["results"][0]["reason"] = serde_json::json!({ "code": "CHAIN_BROKEN" });
+    let rewritten: AuditReceipt = serde_json::from_value(rewritten).unwrap();
+    assert!(!rewritten.results[0].verify_integrity());
+    assert!(!rewritten.verify(verify));
+
+    let mut raised = json;
+    raised["results"][2]["reason"]["required"] = 1.into();
+    let raised: AuditReceipt = serde_json::from_value(raised).unwrap();
+    assert!(!raised.verify(verify));
+}
@@ -0,0 +1,161 @@
+//! The auditd routes, served in process
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::http::{router, AuditdState, KeySigner};
+use axiom_audit::service::{AuditConfig, AuditResponse};
+use axum::http::StatusCode;
+use axum_test::TestServer;
+use sap4d::signing::{ReceiptSigner, SignerRegistry};
+use std::sync::Arc;
+
+const CLAIM: &str = "The sky reflects blue light";
+
+fn signer() -> KeySigner {
+    KeySigner::new("test-signing-key")
+}
+
+fn state() -> Arc<AuditdState> {
+    Arc::new(AuditdState::new(AuditConfig::default(), signer()))
+}
+
+fn server() -> TestServer {
+    TestServer::new(router(state())).unwrap()
+}
+
+fn request(claim: &str) -> serde_json::Value {
+    serde_json::json!({
+        "claim": claim,
+        "evidence": ["The sky scatters blue light", "Blue light reflects off the sky"]
+    })
+}
+
+async fn log_root(server: &TestServer) -> serde_json::Value {
+    let response = server.get("/log/root").await;
+    response.assert_status_ok();
+    response.json()
+}
+
+#[tokio::test]
+async fn healthz_is_ok() {
+    let body: serde_json::Value = server().get("/healthz").await.json();
+    assert_eq!(body["status"], "ok");
+}
+
+#[test]
+fn signatures_are_hmac_sha256() {
+    // RFC 4231 test case 2, base64
+    let signer = KeySigner::new("Jefe");
+    let signature = signer.sign("what do ya want for nothing?");
+    assert_eq!(signature, "W9zBRr9gdU5qBCQmCJV1x1oAPwidJzmDnexYuWTsOEM=");
+    assert!(signer.verify("what do ya want for nothing?", &signature));
+    assert!(!KeySigner::new("other").verify("what do ya want for nothing?", &signature));
+}
+
+#[tokio::test]
+async fn each_audit_moves_the_log_root() {
+    let server = server();
+    let empty = log_root(&server).await;
+    assert!(empty["log_root_hash"].is_null());
+    assert_eq!(empty["entries_count"], 0);
+
+    let mut roots = vec![];
+    for i in 0..3 {
+        let response = server.post("/audit").json(&request(&format!("{} {}", CLAIM, i))).await;
+        response.assert_status_ok();
+        let audited: AuditResponse = response.json();
+        let receipt = audited.receipt.unwrap();
+        assert!(receipt.verify(|hash, sig| signer().verify(hash, sig)));
+
+        let root = log_root(&server).await;
+        // One entry per level plus the receipt
+        assert_eq!(root["entries_count"], (i + 1) * (receipt.results.len() + 1));
+        roots.push(root["log_root_hash"].as_str().unwrap().to_string());
+    }
+    roots.dedup();
+    assert_eq!(roots.len(), 3);
+}
+
+#[tokio::test]
+async fn receipts_name_their_signing_key() {
+    let audited: AuditResponse = server().post("/audit").json(&request(CLAIM)).await.json();
+    let receipt = audited.receipt.unwrap();
+    assert_eq!(receipt.key_id.as_deref(), Some(signer().key_id()));
+    assert!(receipt.key_id.as_deref().unwrap().starts_with("sha256:"));
+
+    let registry = SignerRegistry::new().with_key(signer().key_id(), |hash, sig| signer().verify(hash, sig));
+    assert_eq!(receipt.verify_all(&registry, 1), Ok(1));
+    assert_ne!(KeySigner::new("another-key").key_id(), signer().key_id());
+}
+
+#[tokio::test]
+async fn quick_audit_runs_l1_without_logging() {
+    let server = server();
+
+    let body: serde_json::Value = server.post("/audit/quick").json(&request(CLAIM)).await.json();
+    assert_eq!(body["proof_exists"], true);
+    assert_eq!(body["claim"], CLAIM);
+
+    let unrelated = serde_json::json!({ "claim": CLAIM, "evidence": ["Tides follow the moon"] });
+    let body: serde_json::Value = server.post("/audit/quick").json(&unrelated).await.json();
+    assert_eq!(body["proof_exists"], false);
+
+    assert!(log_root(&server).await["log_root_hash"].is_null());
+}
+
+#[tokio::test]
+async fn quick_audit_reports_the_reason_code() {
+    let body: serde_json::Value = server()
+        .post("/audit/quick")
+        .json(&serde_json::json!({ "claim": CLAIM, "evidence": [] }))
+        .await
+        .json();
+    assert_eq!(body["proof_exists"], false);
+    assert_eq!(body["reason"], axiom_audit::levels::codes::NO_EVIDENCE);
+}
+
+#[tokio::test]
+async fn verify_receipt_checks_this_daemons_key() {
+    let server = server();
+    let audited: AuditResponse = server.post("/audit").json(&request(CLAIM)).await.json();
+    let mut receipt = audited.receipt.unwrap();
+
+    let body: serde_json::Value = server.post("/verify-receipt").json(&receipt).await.json();
+    assert_eq!(body["valid"], true);
+
+    receipt.signature = KeySigner::new("another-key").sign(&receipt.receipt_hash);
+    let body: serde_json::Value = server.post("/verify-receipt").json(&receipt).await.json();
+    assert_eq!(body["valid"], false);
+
+    let info: serde_json::Value = server.get("/info").await.json();
+    assert_eq!(info["policy"], "C = 0");
+}
+
+#[tokio::test]
+async fn empty_claim_is_unprocessable() {
+    let server = server();
+    for route in ["/audit", "/audit/quick"] {
+        let response = server.post(route).json(&request("  ")).await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    assert_eq!(log_root(&server).await["entries_count"], 0);
+}
+
+#[tokio::test]
+async fn concurrent_audits_all_reach_the_log() {
+    // A TestServer holds its transport lock while a request is in flight,
+    // so concurrent requests each go through their own server onto one state
+    let state = state();
+
+    let audits = (0..16).map(|i| {
+        let server = TestServer::new(router(state.clone())).unwrap();
+        async move {
+            let response: AuditResponse = server.post("/audit").json(&request(&format!("{} {}", CLAIM, i))).await.json();
+            response.receipt.unwrap().results.len() + 1
+        }
+    });
+    let expected: usize = futures::future::join_all(audits).await.into_iter().sum();
+
+    let server = TestServer::new(router(state)).unwrap();
+    assert_eq!(log_root(&server).await["entries_count"], expected);
+}
@@ -2,15 +2,51 @@
 //!
 //! Thermal Intelligence & C=0 Resource Enforcement
 //!
+//! Metrics attribute load to the heaviest processes, and thermal checks keep
+//! a short history so a single hot reading does not throttle the workload.
+//! Sysinfo is refreshed at most once per refresh interval, however often
+//! the frontend polls.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
-use sysinfo::{CpuExt, System, SystemExt};
+use sysinfo::{ComponentExt, CpuExt, PidExt, ProcessExt, System, SystemExt};
+use std::collections::{HashMap, VecDeque};
 use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::cozo_db::{CozoError, CozoStore};
 
-/// Maximum entropy budget (Hamiltonian energy ceiling)
+/// Maximum entropy budget (Hamiltonian energy ceiling), shared by all sessions
 const MAX_ENTROPY: f64 = 1000.0;
 
+/// Session charged by `consume_entropy` when the caller names none
+pub const DEFAULT_SESSION: &str = "default";
+
+/// Component charged by `consume_entropy`
+pub const UNATTRIBUTED: &str = "unattributed";
+
+/// Action type of persisted ledger entries
+pub const BARK_LEDGER_ACTION: &str = "BARK_ENTROPY";
+
+/// Action type recorded when the budget is reset
+pub const BARK_RESET_ACTION: &str = "BARK_ENTROPY_RESET";
+
+/// Consumers listed in the metrics breakdown
+const TOP_CONSUMERS: usize = 10;
+
+/// Processes listed per ranking (CPU, memory) in the metrics
+const TOP_PROCESSES: usize = 5;
+
+/// Minimum time between sysinfo refreshes
+const REFRESH_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Thermal readings kept for trend plots
+const THERMAL_HISTORY: usize = 120;
+
+/// Consecutive Critical readings before BARK asks to reduce workload
+const CRITICAL_CONFIRMATIONS: usize = 2;
+
 /// Thermal thresholds
 const THERMAL_WARNING: f32 = 70.0;  // °C
 const THERMAL_CRITICAL: f32 = 85.0; // °C
@@ -18,8 +54,60 @@ const THERMAL_SHUTDOWN: f32 = 95.0; // °C
 
 /// BARK Controller
 pub struct BarkController {
-    system: Arc<Mutex<System>>,
-    entropy_budget: Arc<Mutex<f64>>,
+    system: Arc<Mutex<Sampler>>,
+    entropy: Arc<Mutex<EntropyState>>,
+    thermal: Arc<Mutex<ThermalHistory>>,
+    session_budget: f64,
+    top_processes: usize,
+    store: Option<CozoStore>,
+}
+
+/// System handle and when it was last refreshed
+struct Sampler {
+    system: System,
+    refreshed_at: Instant,
+    interval: Duration,
+}
+
+/// Budget and ledger, kept under one lock so checks and charges agree
+#[derive(Debug, Default)]
+struct EntropyState {
+    /// Remaining global budget
+    budget: f64,
+    /// Number of resets so far
+    epoch: u64,
+    /// Entries since the last reset
+    entries: Vec<LedgerEntry>,
+    /// Net consumption per session since the last reset
+    spent: HashMap<String, f64>,
+    archived: Vec<ArchivedLedger>,
+}
+
+impl EntropyState {
+    fn fresh() -> Self {
+        Self {
+            budget: MAX_ENTROPY,
+            ..Self::default()
+        }
+    }
+    
+    fn record(&mut self, entry: LedgerEntry) {
+        *self.spent.entry(entry.session_id.clone()).or_default() += entry.amount;
+        self.budget = (self.budget - entry.amount).clamp(0.0, MAX_ENTROPY);
+        self.entries.push(entry);
+    }
+    
+    fn archive(&mut self, archived_at: String) {
+        let entries = std::mem::take(&mut self.entries);
+        self.archived.push(ArchivedLedger {
+            epoch: self.epoch,
+            archived_at,
+            entries,
+        });
+        self.epoch += 1;
+        self.spent.clear();
+        self.budget = MAX_ENTROPY;
+    }
 }
 
 impl BarkController {
@@ -29,24 +117,129 @@ impl BarkController {
         system.refresh_all();
         
         Self {
-            system: Arc::new(Mutex::new(system)),
-            entropy_budget: Arc::new(Mutex::new(MAX_ENTROPY)),
+            system: Arc::new(Mutex::new(Sampler {
+                system,
+                refreshed_at: Instant::now(),
+                interval: REFRESH_INTERVAL,
+            })),
+            entropy: Arc::new(Mutex::new(EntropyState::fresh())),
+            thermal: Arc::new(Mutex::new(ThermalHistory::new(THERMAL_HISTORY))),
+            session_budget: MAX_ENTROPY,
+            top_processes: TOP_PROCESSES,
+            store: None,
+        }
+    }
+    
+    /// Refresh sysinfo at most once per `interval` (default 500ms)
+    pub fn with_refresh_interval(self, interval: Duration) -> Self {
+        self.system.lock().unwrap().interval = interval;
+        self
+    }
+    
+    /// Keep the last `capacity` thermal readings (default 120)
+    pub fn with_thermal_history(self, capacity: usize) -> Self {
+        *self.thermal.lock().unwrap() = ThermalHistory::new(capacity);
+        self
+    }
+    
+    /// List the top `n` processes by CPU and by memory in the metrics (default 5)
+    pub fn with_top_processes(mut self, n: usize) -> Self {
+        self.top_processes = n;
+        self
+    }
+    
+    /// Cap each session's net consumption below the global budget
+    pub fn with_session_budget(mut self, budget: f64) -> Self {
+        self.session_budget = budget.clamp(0.0, MAX_ENTROPY);
+        self
+    }
+    
+    /// Persist the ledger to the action log in `store`, resuming any stored history
+    pub fn with_store(mut self, store: &CozoStore) -> Result<Self, CozoError> {
+        let resets = store.load_actions(BARK_RESET_ACTION)?;
+        let mut reset_times: HashMap<u64, String> = HashMap::new();
+        for reset in &resets {
+            if let Some(epoch) = reset["result"]["epoch"].as_u64() {
+                let at = reset["result"]["archived_at"].as_str().unwrap_or_default();
+                reset_times.insert(epoch, at.to_string());
+            }
+        }
+        
+        let mut state = EntropyState::fresh();
+        let mut by_epoch: Vec<(u64, LedgerEntry)> = store
+            .load_actions(BARK_LEDGER_ACTION)?
+            .into_iter()
+            .filter_map(|action| serde_json::from_value::<PersistedEntry>(action["result"].clone()).ok())
+            .map(|persisted| (persisted.epoch, persisted.entry))
+            .collect();
+        by_epoch.sort_by_key(|(epoch, _)| *epoch);
+        
+        let current = reset_times.keys().max().copied().unwrap_or(0);
+        for (epoch, entry) in by_epoch {
+            while state.epoch < epoch.min(current) {
+                let at = reset_times.get(&(state.epoch + 1)).cloned().unwrap_or_default();
+                state.archive(at);
+            }
+            state.record(entry);
+        }
+        while state.epoch < current {
+            let at = reset_times.get(&(state.epoch + 1)).cloned().unwrap_or_default();
+            state.archive(at);
         }
+        
+        self.entropy = Arc::new(Mutex::new(state));
+        self.store = Some(store.clone());
+        Ok(self)
     }
     
-    /// Refresh system metrics
-    pub fn refresh(&self) {
-        if let Ok(mut sys) = self.system.lock() {
-            sys.refresh_all();
+    /// Refresh system metrics unless the last refresh is recent
+    ///
+    /// Returns whether sysinfo was actually refreshed.
+    pub fn refresh(&self) -> bool {
+        let Ok(mut sampler) = self.system.lock() else {
+            return false;
+        };
+        if sampler.refreshed_at.elapsed() < sampler.interval {
+            return false;
         }
+        sampler.system.refresh_all();
+        sampler.refreshed_at = Instant::now();
+        true
+    }
+    
+    /// Top `limit` processes by CPU usage and by resident memory
+    pub fn top_processes(&self, limit: usize) -> TopProcesses {
+        self.refresh();
+        
+        let sampler = self.system.lock().unwrap();
+        let mut processes: Vec<ProcessUsage> = sampler.system
+            .processes()
+            .values()
+            .map(|p| ProcessUsage {
+                pid: p.pid().as_u32(),
+                name: p.name().to_string(),
+                cpu_percent: p.cpu_usage(),
+                rss_bytes: p.memory(),
+            })
+            .collect();
+        drop(sampler);
+        
+        processes.sort_by(|a, b| b.cpu_percent.total_cmp(&a.cpu_percent).then(a.pid.cmp(&b.pid)));
+        let by_cpu = processes.iter().take(limit).cloned().collect();
+        processes.sort_by(|a, b| b.rss_bytes.cmp(&a.rss_bytes).then(a.pid.cmp(&b.pid)));
+        processes.truncate(limit);
+        
+        TopProcesses { by_cpu, by_memory: processes }
     }
     
     /// Get current system metrics
     pub fn get_metrics(&self) -> serde_json::Value {
-        self.refresh();
+        let processes = self.top_processes(self.top_processes);
         
-        let sys = self.system.lock().unwrap();
-        let entropy = self.entropy_budget.lock().unwrap();
+        let sampler = self.system.lock().unwrap();
+        let sys = &sampler.system;
+        let state = self.entropy.lock().unwrap();
+        let entropy = &state.budget;
         
         let cpu_usage: f32 = sys.cpus().iter().map(|c| c.cpu_usage()).sum::<f32>() 
             / sys.cpus().len() as f32;
@@ -70,8 +263,13 @@ impl BarkController {
                 "current": MAX_ENTROPY - *entropy,
                 "budget": *entropy,
                 "max": MAX_ENTROPY,
-                "usage_percent": ((MAX_ENTROPY - *entropy) / MAX_ENTROPY) * 100.0
+                "usage_percent": ((MAX_ENTROPY - *entropy) / MAX_ENTROPY) * 100.0,
+                "session_budget": self.session_budget,
+                "sessions": state.spent.len(),
+                "top_consumers": top_consumers(&state.entries, TOP_CONSUMERS),
+                "archived_ledgers": state.archived.len()
             },
+            "processes": processes,
             "c_zero_compliant": *entropy > 0.0,
             "substrate": crate::SUBSTRATE,
             "projection": crate::PROJECTION
@@ -79,13 +277,17 @@ impl BarkController {
     }
     
     /// Check thermal status
+    ///
+    /// Each fresh reading joins the history. The action escalates to
+    /// `REDUCE_WORKLOAD` only after consecutive Critical readings;
+    /// `transition` is set when the status differs from the previous reading.
     pub fn check_thermal(&self) -> serde_json::Value {
-        self.refresh();
+        let refreshed = self.refresh();
         
-        let sys = self.system.lock().unwrap();
+        let sampler = self.system.lock().unwrap();
         
         // Get CPU temperature (platform-specific)
-        let temps: Vec<ThermalReading> = sys.components()
+        let temps: Vec<ThermalReading> = sampler.system.components()
             .iter()
             .map(|c| ThermalReading {
                 label: c.label().to_string(),
@@ -94,20 +296,24 @@ impl BarkController {
                 critical: c.critical(),
             })
             .collect();
+        drop(sampler);
         
         // Find highest temperature
         let max_temp = temps.iter()
             .map(|t| t.current)
             .fold(0.0f32, |a, b| a.max(b));
         
-        let status = if max_temp >= THERMAL_SHUTDOWN {
-            ThermalStatus::Shutdown
-        } else if max_temp >= THERMAL_CRITICAL {
-            ThermalStatus::Critical
-        } else if max_temp >= THERMAL_WARNING {
-            ThermalStatus::Warning
+        let status = ThermalStatus::from_temperature(max_temp);
+        
+        let mut history = self.thermal.lock().unwrap();
+        let transition = if refreshed || history.is_empty() {
+            history.push(ThermalSample {
+                status,
+                max_temperature: max_temp,
+                timestamp: chrono::Utc::now().to_rfc3339(),
+            })
         } else {
-            ThermalStatus::Normal
+            None
         };
         
         serde_json::json!({
@@ -119,42 +325,102 @@ impl BarkController {
                 "shutdown": THERMAL_SHUTDOWN
             },
             "readings": temps,
-            "action": match status {
-                ThermalStatus::Shutdown => "HALT_ALL_INFERENCE",
-                ThermalStatus::Critical => "REDUCE_WORKLOAD",
-                ThermalStatus::Warning => "MONITOR",
-                ThermalStatus::Normal => "PROCEED",
-            }
+            "consecutive": history.consecutive(),
+            "transition": transition,
+            "action": history.action()
         })
     }
     
+    /// Thermal readings, oldest first
+    pub fn thermal_history(&self) -> Vec<ThermalSample> {
+        self.thermal.lock().unwrap().samples.iter().cloned().collect()
+    }
+    
     /// Consume entropy budget
     pub fn consume_entropy(&self, amount: f64) -> Result<(), BarkError> {
-        let mut budget = self.entropy_budget.lock().unwrap();
-        
-        if *budget < amount {
-            return Err(BarkError::EntropyExceeded {
-                requested: amount,
-                available: *budget,
-            });
-        }
-        
-        *budget -= amount;
-        tracing::debug!("Entropy consumed: {} (remaining: {})", amount, *budget);
+        self.consume_entropy_for(DEFAULT_SESSION, UNATTRIBUTED, amount)
+    }
+    
+    /// Consume entropy on behalf of `component` in `session_id`, recording it in the ledger
+    pub fn consume_entropy_for(&self, session_id: &str, component: &str, amount: f64) -> Result<(), BarkError> {
+        let (entry, epoch) = {
+            let mut state = self.entropy.lock().unwrap();
+            
+            if state.budget < amount {
+                return Err(BarkError::EntropyExceeded {
+                    requested: amount,
+                    available: state.budget,
+                });
+            }
+            let session_available = self.session_budget - state.spent.get(session_id).copied().unwrap_or(0.0);
+            if session_available < amount {
+                return Err(BarkError::SessionBudgetExceeded {
+                    session_id: session_id.to_string(),
+                    requested: amount,
+                    available: session_available.max(0.0),
+                });
+            }
+            
+            let entry = LedgerEntry::new(session_id, component, amount);
+            state.record(entry.clone());
+            tracing::debug!("Entropy consumed: {} by {}/{} (remaining: {})", amount, session_id, component, state.budget);
+            (entry, state.epoch)
+        };
         
+        self.persist(&entry, epoch);
         Ok(())
     }
     
     /// Restore entropy budget
     pub fn restore_entropy(&self, amount: f64) {
-        let mut budget = self.entropy_budget.lock().unwrap();
-        *budget = (*budget + amount).min(MAX_ENTROPY);
+        let mut state = self.entropy.lock().unwrap();
+        state.budget = (state.budget + amount).min(MAX_ENTROPY);
+    }
+    
+    /// Give back entropy `component` consumed in `session_id` (at most what the session spent)
+    pub fn restore_entropy_for(&self, session_id: &str, component: &str, amount: f64) {
+        let (entry, epoch) = {
+            let mut state = self.entropy.lock().unwrap();
+            let spent = state.spent.get(session_id).copied().unwrap_or(0.0);
+            let credited = amount.min(spent).max(0.0);
+            if credited == 0.0 {
+                return;
+            }
+            
+            let entry = LedgerEntry::new(session_id, component, -credited);
+            state.record(entry.clone());
+            (entry, state.epoch)
+        };
+        
+        self.persist(&entry, epoch);
+    }
+    
+    /// Ledger entries for `session_id` since the last reset, oldest first
+    pub fn get_ledger(&self, session_id: &str) -> Vec<LedgerEntry> {
+        let state = self.entropy.lock().unwrap();
+        state.entries.iter().filter(|e| e.session_id == session_id).cloned().collect()
+    }
+    
+    /// Ledgers closed by earlier resets, oldest first
+    pub fn archived_ledgers(&self) -> Vec<ArchivedLedger> {
+        self.entropy.lock().unwrap().archived.clone()
+    }
+    
+    fn persist(&self, entry: &LedgerEntry, epoch: u64) {
+        let Some(ref store) = self.store else {
+            return;
+        };
+        let result = serde_json::to_string(&PersistedEntry { epoch, entry: entry.clone() })
+            .unwrap_or_default();
+        if let Err(e) = store.log_action(BARK_LEDGER_ACTION, &entry.session_id, &result, entry.amount) {
+            tracing::warn!("Failed to persist entropy ledger entry: {}", e);
+        }
     }
     
     /// Check if action is within entropy budget
     pub fn check_action(&self, entropy_cost: f64) -> ActionCheck {
-        let budget = self.entropy_budget.lock().unwrap();
         let thermal = self.check_thermal();
+        let budget = self.entropy.lock().unwrap().budget;
         
         let thermal_status = thermal["status"].as_str().unwrap_or("UNKNOWN");
         
@@ -166,12 +432,12 @@ impl BarkController {
             };
         }
         
-        if *budget < entropy_cost {
+        if budget < entropy_cost {
             return ActionCheck {
                 allowed: false,
                 reason: format!(
                     "Entropy budget exceeded: requested {} but only {} available",
-                    entropy_cost, *budget
+                    entropy_cost, budget
                 ),
                 c_zero: false,
             };
@@ -184,11 +450,47 @@ impl BarkController {
         }
     }
     
-    /// Reset entropy budget (for new session)
+    /// Point-in-time view of memory, CPU and thermal headroom
+    pub fn snapshot(&self) -> SystemSnapshot {
+        let metrics = self.get_metrics();
+        let thermal = self.check_thermal();
+        
+        let used = metrics["memory"]["used_bytes"].as_u64().unwrap_or(0);
+        let total = metrics["memory"]["total_bytes"].as_u64().unwrap_or(0);
+        let to_mb = |bytes: u64| bytes as f64 / (1024.0 * 1024.0);
+        let thermal_status = match thermal["status"].as_str() {
+            Some("SHUTDOWN") => ThermalStatus::Shutdown,
+            Some("CRITICAL") => ThermalStatus::Critical,
+            Some("WARNING") => ThermalStatus::Warning,
+            _ => ThermalStatus::Normal,
+        };
+        
+        SystemSnapshot {
+            free_memory_mb: to_mb(total.saturating_sub(used)),
+            total_memory_mb: to_mb(total),
+            cpu_usage_percent: metrics["cpu"]["usage_percent"].as_f64().unwrap_or(0.0),
+            thermal_status,
+            max_temperature: thermal["max_temperature"].as_f64().unwrap_or(0.0) as f32,
+            taken_at: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+    
+    /// Reset entropy budget (for new session), archiving the current ledger
     pub fn reset_entropy(&self) {
-        let mut budget = self.entropy_budget.lock().unwrap();
-        *budget = MAX_ENTROPY;
+        let archived_at = chrono::Utc::now().to_rfc3339();
+        let epoch = {
+            let mut state = self.entropy.lock().unwrap();
+            state.archive(archived_at.clone());
+            state.epoch
+        };
         tracing::info!("Entropy budget reset to {}", MAX_ENTROPY);
+        
+        if let Some(ref store) = self.store {
+            let result = serde_json::json!({ "epoch": epoch, "archived_at": archived_at });
+            if let Err(e) = store.log_action(BARK_RESET_ACTION, "global", &result.to_string(), 0.0) {
+                tracing::warn!("Failed to persist entropy reset: {}", e);
+            }
+        }
     }
 }
 
@@ -198,6 +500,63 @@ impl Default for BarkController {
     }
 }
 
+/// One charge (positive) or refund (negative) against the entropy budget
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct LedgerEntry {
+    pub session_id: String,
+    pub component: String,
+    pub amount: f64,
+    pub timestamp: String,
+}
+
+impl LedgerEntry {
+    fn new(session_id: &str, component: &str, amount: f64) -> Self {
+        Self {
+            session_id: session_id.to_string(),
+            component: component.to_string(),
+            amount,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+}
+
+/// Ledger closed by `reset_entropy`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ArchivedLedger {
+    /// Reset count when this ledger was opened
+    pub epoch: u64,
+    pub archived_at: String,
+    pub entries: Vec<LedgerEntry>,
+}
+
+/// Ledger entry as written to the action log
+#[derive(Serialize, Deserialize)]
+struct PersistedEntry {
+    epoch: u64,
+    #[serde(flatten)]
+    entry: LedgerEntry,
+}
+
+/// Net consumption per session and component, largest first
+fn top_consumers(entries: &[LedgerEntry], limit: usize) -> Vec<serde_json::Value> {
+    let mut totals: HashMap<(&str, &str), f64> = HashMap::new();
+    for entry in entries {
+        *totals.entry((entry.session_id.as_str(), entry.component.as_str())).or_default() += entry.amount;
+    }
+    
+    let mut totals: Vec<_> = totals.into_iter().filter(|(_, amount)| *amount > 0.0).collect();
+    totals.sort_by(|a, b| b.1.total_cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+    totals
+        .into_iter()
+        .take(limit)
+        .map(|((session_id, component), consumed)| serde_json::json!({
+            "session_id": session_id,
+            "component": component,
+            "consumed": consumed
+        }))
+        .collect()
+}
+
 /// Thermal reading
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ThermalReading {
@@ -207,8 +566,108 @@ pub struct ThermalReading {
     pub critical: Option<f32>,
 }
 
+/// Process resource usage
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessUsage {
+    pub pid: u32,
+    pub name: String,
+    /// CPU usage; may exceed 100 on multi-core systems
+    pub cpu_percent: f32,
+    /// Resident set size
+    pub rss_bytes: u64,
+}
+
+/// Heaviest processes, ranked by CPU and by memory
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TopProcesses {
+    pub by_cpu: Vec<ProcessUsage>,
+    pub by_memory: Vec<ProcessUsage>,
+}
+
+/// One thermal reading in the history
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThermalSample {
+    pub status: ThermalStatus,
+    pub max_temperature: f32,
+    pub timestamp: String,
+}
+
+/// Change of thermal status between two consecutive readings
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ThermalTransition {
+    pub from: ThermalStatus,
+    pub to: ThermalStatus,
+    pub at: String,
+}
+
+/// Ring buffer of the most recent thermal readings
+#[derive(Debug)]
+struct ThermalHistory {
+    samples: VecDeque<ThermalSample>,
+    capacity: usize,
+}
+
+impl ThermalHistory {
+    fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(CRITICAL_CONFIRMATIONS);
+        Self {
+            samples: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+    
+    fn is_empty(&self) -> bool {
+        self.samples.is_empty()
+    }
+    
+    /// Record `sample`, returning the transition if its status changed
+    fn push(&mut self, sample: ThermalSample) -> Option<ThermalTransition> {
+        let transition = self
+            .samples
+            .back()
+            .filter(|last| last.status != sample.status)
+            .map(|last| ThermalTransition {
+                from: last.status,
+                to: sample.status,
+                at: sample.timestamp.clone(),
+            });
+        
+        if self.samples.len() == self.capacity {
+            self.samples.pop_front();
+        }
+        self.samples.push_back(sample);
+        transition
+    }
+    
+    /// Readings in a row, ending with the latest, at the latest status
+    fn consecutive(&self) -> usize {
+        let Some(last) = self.samples.back() else {
+            return 0;
+        };
+        self.samples.iter().rev().take_while(|s| s.status == last.status).count()
+    }
+    
+    /// Recommended action for the latest reading
+    fn action(&self) -> &'static str {
+        let hot = self
+            .samples
+            .iter()
+            .rev()
+            .take_while(|s| matches!(s.status, ThermalStatus::Critical | ThermalStatus::Shutdown))
+            .count();
+        
+        match self.samples.back().map(|s| s.status) {
+            Some(ThermalStatus::Shutdown) => "HALT_ALL_INFERENCE",
+            Some(ThermalStatus::Critical) if hot >= CRITICAL_CONFIRMATIONS => "REDUCE_WORKLOAD",
+            Some(ThermalStatus::Critical) | Some(ThermalStatus::Warning) => "MONITOR",
+            Some(ThermalStatus::Normal) | None => "PROCEED",
+        }
+    }
+}
+
 /// Thermal status
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
 pub enum ThermalStatus {
     Normal,
     Warning,
@@ -217,6 +676,19 @@ pub enum ThermalStatus {
 }
 
 impl ThermalStatus {
+    /// Status for the hottest component temperature
+    pub fn from_temperature(celsius: f32) -> Self {
+        if celsius >= THERMAL_SHUTDOWN {
+            ThermalStatus::Shutdown
+        } else if celsius >= THERMAL_CRITICAL {
+            ThermalStatus::Critical
+        } else if celsius >= THERMAL_WARNING {
+            ThermalStatus::Warning
+        } else {
+            ThermalStatus::Normal
+        }
+    }
+    
     pub fn as_str(&self) -> &'static str {
         match self {
             ThermalStatus::Normal => "NORMAL",
@@ -227,6 +699,34 @@ impl ThermalStatus {
     }
 }
 
+/// System headroom captured once and shared by every voter in a decision
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SystemSnapshot {
+    pub free_memory_mb: f64,
+    pub total_memory_mb: f64,
+    pub cpu_usage_percent: f64,
+    pub thermal_status: ThermalStatus,
+    pub max_temperature: f32,
+    pub taken_at: String,
+}
+
+/// Source of system snapshots (BARK in production, fixed values in tests)
+pub trait SnapshotSource: Send + Sync {
+    fn snapshot(&self) -> SystemSnapshot;
+}
+
+impl SnapshotSource for BarkController {
+    fn snapshot(&self) -> SystemSnapshot {
+        BarkController::snapshot(self)
+    }
+}
+
+impl SnapshotSource for SystemSnapshot {
+    fn snapshot(&self) -> SystemSnapshot {
+        self.clone()
+    }
+}
+
 /// Action check result
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ActionCheck {
@@ -241,6 +741,9 @@ pub enum BarkError {
     #[error("Entropy budget exceeded: requested {requested}, available {available}")]
     EntropyExceeded { requested: f64, available: f64 },
     
+    #[error("Session {session_id} entropy budget exceeded: requested {requested}, available {available}")]
+    SessionBudgetExceeded { session_id: String, requested: f64, available: f64 },
+    
     #[error("Thermal limit exceeded")]
     ThermalExceeded,
     
@@ -287,5 +790,182 @@ mod tests {
         assert!(check.allowed);
         assert!(check.c_zero);
     }
+    
+    #[test]
+    fn test_ledger_and_top_consumers() {
+        let bark = BarkController::new();
+        bark.consume_entropy_for("s1", "llama3", 100.0).unwrap();
+        bark.consume_entropy_for("s1", "phi3", 10.0).unwrap();
+        bark.consume_entropy_for("s2", "mistral7b", 50.0).unwrap();
+        bark.restore_entropy_for("s1", "llama3", 40.0);
+        
+        let ledger = bark.get_ledger("s1");
+        assert_eq!(ledger.len(), 3);
+        assert_eq!(ledger[2].amount, -40.0);
+        
+        let metrics = bark.get_metrics();
+        assert_eq!(metrics["entropy"]["budget"].as_f64().unwrap(), 880.0);
+        let top = metrics["entropy"]["top_consumers"].as_array().unwrap();
+        assert_eq!(top[0]["component"], "llama3");
+        assert_eq!(top[0]["consumed"].as_f64().unwrap(), 60.0);
+        assert_eq!(top[1]["session_id"], "s2");
+    }
+    
+    #[test]
+    fn test_session_budget() {
+        let bark = BarkController::new().with_session_budget(100.0);
+        bark.consume_entropy_for("s1", "llama3", 80.0).unwrap();
+        
+        let err = bark.consume_entropy_for("s1", "llama3", 30.0).unwrap_err();
+        assert!(matches!(err, BarkError::SessionBudgetExceeded { available, .. } if available == 20.0));
+        
+        // Other sessions draw on their own budget
+        assert!(bark.consume_entropy_for("s2", "llama3", 80.0).is_ok());
+    }
+    
+    #[test]
+    fn test_reset_archives_ledger() {
+        let bark = BarkController::new();
+        bark.consume_entropy_for("s1", "llama3", 100.0).unwrap();
+        bark.reset_entropy();
+        
+        assert!(bark.get_ledger("s1").is_empty());
+        let archived = bark.archived_ledgers();
+        assert_eq!(archived.len(), 1);
+        assert_eq!(archived[0].epoch, 0);
+        assert_eq!(archived[0].entries[0].component, "llama3");
+        assert_eq!(bark.get_metrics()["entropy"]["budget"].as_f64().unwrap(), MAX_ENTROPY);
+    }
+    
+    #[test]
+    fn test_concurrent_consumption() {
+        let bark = Arc::new(BarkController::new().with_session_budget(300.0));
+        
+        let handles: Vec<_> = (0..8)
+            .map(|t| {
+                let bark = bark.clone();
+                std::thread::spawn(move || {
+                    let session = format!("s{}", t % 4);
+                    (0..50)
+                        .filter(|_| bark.consume_entropy_for(&session, "worker", 5.0).is_ok())
+                        .count()
+                })
+            })
+            .collect();
+        let granted: usize = handles.into_iter().map(|h| h.join().unwrap()).sum();
+        
+        // 4 sessions x 300 would exceed the global cap, so the cap wins
+        assert_eq!(granted as f64 * 5.0, MAX_ENTROPY);
+        let ledgered: usize = (0..4).map(|s| bark.get_ledger(&format!("s{}", s)).len()).sum();
+        assert_eq!(ledgered, granted);
+        for s in 0..4 {
+            let spent: f64 = bark.get_ledger(&format!("s{}", s)).iter().map(|e| e.amount).sum();
+            assert!(spent <= 300.0);
+        }
+    }
+    
+    #[test]
+    fn test_ledger_survives_restart() {
+        let path = std::env::temp_dir().join(format!("bark-ledger-{}", std::process::id()));
+        let store = CozoStore::new(&path).unwrap();
+        
+        let bark = BarkController::new().with_store(&store).unwrap();
+        bark.consume_entropy_for("s1", "llama3", 100.0).unwrap();
+        bark.reset_entropy();
+        bark.consume_entropy_for("s1", "phi3", 10.0).unwrap();
+        drop(bark);
+        
+        let resumed = BarkController::new().with_store(&store).unwrap();
+        let ledger = resumed.get_ledger("s1");
+        assert_eq!(ledger.len(), 1);
+        assert_eq!(ledger[0].component, "phi3");
+        assert_eq!(resumed.archived_ledgers()[0].entries[0].component, "llama3");
+        assert_eq!(resumed.get_metrics()["entropy"]["budget"].as_f64().unwrap(), MAX_ENTROPY - 10.0);
+        
+        let _ = std::fs::remove_dir_all(&path);
+    }
+    
+    fn sample(status: ThermalStatus) -> ThermalSample {
+        ThermalSample {
+            status,
+            max_temperature: 0.0,
+            timestamp: chrono::Utc::now().to_rfc3339(),
+        }
+    }
+    
+    #[test]
+    fn test_critical_escalates_after_two_readings() {
+        let mut history = ThermalHistory::new(THERMAL_HISTORY);
+        assert_eq!(history.action(), "PROCEED");
+        
+        assert!(history.push(sample(ThermalStatus::Normal)).is_none());
+        let transition = history.push(sample(ThermalStatus::Critical)).unwrap();
+        assert_eq!((transition.from, transition.to), (ThermalStatus::Normal, ThermalStatus::Critical));
+        assert_eq!(history.action(), "MONITOR");
+        
+        assert!(history.push(sample(ThermalStatus::Critical)).is_none());
+        assert_eq!(history.consecutive(), 2);
+        assert_eq!(history.action(), "REDUCE_WORKLOAD");
+        
+        // Shutdown halts at once; stepping back to Critical keeps the escalation
+        history.push(sample(ThermalStatus::Shutdown));
+        assert_eq!(history.action(), "HALT_ALL_INFERENCE");
+        history.push(sample(ThermalStatus::Critical));
+        assert_eq!(history.action(), "REDUCE_WORKLOAD");
+        
+        history.push(sample(ThermalStatus::Warning));
+        history.push(sample(ThermalStatus::Critical));
+        assert_eq!(history.action(), "MONITOR");
+    }
+    
+    #[test]
+    fn test_thermal_history_is_bounded() {
+        let mut history = ThermalHistory::new(3);
+        for status in [ThermalStatus::Normal, ThermalStatus::Warning, ThermalStatus::Warning, ThermalStatus::Normal] {
+            history.push(sample(status));
+        }
+        
+        let statuses: Vec<_> = history.samples.iter().map(|s| s.status).collect();
+        assert_eq!(statuses, [ThermalStatus::Warning, ThermalStatus::Warning, ThermalStatus::Normal]);
+        assert_eq!(ThermalStatus::from_temperature(86.0), ThermalStatus::Critical);
+    }
+    
+    #[test]
+    fn test_refresh_is_rate_limited() {
+        let bark = BarkController::new().with_refresh_interval(Duration::from_secs(3600));
+        assert!(!bark.refresh());
+        
+        // Rapid polling records one reading, not one per call
+        for _ in 0..5 {
+            bark.check_thermal();
+        }
+        assert_eq!(bark.thermal_history().len(), 1);
+        
+        let bark = BarkController::new().with_refresh_interval(Duration::ZERO);
+        assert!(bark.refresh());
+    }
+    
+    #[test]
+    fn test_metrics_list_top_processes() {
+        let bark = BarkController::new().with_top_processes(3);
+        let top = bark.top_processes(3);
+        assert!(top.by_memory.len() <= 3);
+        assert!(top.by_memory.windows(2).all(|w| w[0].rss_bytes >= w[1].rss_bytes));
+        assert!(top.by_cpu.windows(2).all(|w| w[0].cpu_percent >= w[1].cpu_percent));
+        
+        // This test process is running, so something is listed
+        assert!(!top.by_memory.is_empty());
+        let metrics = bark.get_metrics();
+        assert!(metrics["processes"]["by_memory"].as_array().unwrap().len() <= 3);
+    }
+    
+    #[test]
+    fn test_snapshot() {
+        let bark = BarkController::new();
+        
+        let snapshot = bark.snapshot();
+        assert!(snapshot.total_memory_mb > 0.0);
+        assert!(snapshot.free_memory_mb <= snapshot.total_memory_mb);
+    }
 }
 
@@ -5,7 +5,10 @@
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use cozo::{DataValue, DbInstance, NamedRows, ScriptMutability};
+use sap4d::anchors::TrustAnchor;
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
+use std::collections::{BTreeMap, BTreeSet};
 use std::path::Path;
 use thiserror::Error;
 use uuid::Uuid;
@@ -18,9 +21,135 @@ pub enum CozoError {
     Query(String),
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    #[error("Unknown session: {0}")]
+    UnknownSession(String),
+    #[error("Session is closed: {0}")]
+    SessionClosed(String),
+    #[error("Unknown fact: {0}")]
+    UnknownFact(String),
+    #[error("Invalid fact: {0}")]
+    InvalidFact(String),
+    #[error("Invalid trust anchor: {0}")]
+    InvalidTrustAnchor(String),
+}
+
+/// Chain-of-thought session identifier
+pub type SessionId = String;
+
+/// A row of the facts relation
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Fact {
+    pub id: String,
+    pub domain: String,
+    pub statement: String,
+    /// 0.0..=1.0
+    pub confidence: f64,
+    /// Provenance record or other origin of the statement
+    pub source_id: String,
+    /// Epoch millis when the fact was stored
+    pub timestamp: f64,
+    pub verified: bool,
+}
+
+/// A row of the trust_anchors relation: a signer whose receipts verify locally
+///
+/// The name is the key id receipts record. The key is stored as in a
+/// `PORTAL_TRUST_ANCHORS` entry, `hmac:<base64 secret>` or
+/// `ed25519:<base64 public key>`, and never leaves the backend.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StoredAnchor {
+    pub name: String,
+    /// `hmac` or `ed25519`
+    pub scheme: String,
+    #[serde(skip_serializing)]
+    pub key: String,
+    /// Epoch millis when the anchor was added
+    pub added_at: f64,
+}
+
+impl StoredAnchor {
+    /// The anchor that checks this signer's signatures
+    pub fn anchor(&self) -> Result<TrustAnchor, CozoError> {
+        TrustAnchor::parse(&format!("{}={}", self.name, self.key))
+            .map_err(|e| CozoError::InvalidTrustAnchor(e.to_string()))
+    }
+}
+
+/// Filter over the facts relation
+///
+/// Every value reaches Datalog as a bound parameter, so statements and
+/// domains need no escaping and confidences no formatting.
+#[derive(Debug, Clone, Default)]
+pub struct FactQuery {
+    domain: Option<String>,
+    min_confidence: Option<f64>,
+    verified_only: bool,
+    limit: Option<usize>,
+}
+
+impl FactQuery {
+    /// Every fact, oldest first
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Only facts in `domain`
+    pub fn with_domain(mut self, domain: impl Into<String>) -> Self {
+        self.domain = Some(domain.into());
+        self
+    }
+    
+    /// Only facts with confidence of at least `min_confidence`
+    pub fn with_min_confidence(mut self, min_confidence: f64) -> Self {
+        self.min_confidence = Some(min_confidence);
+        self
+    }
+    
+    /// Only verified facts
+    pub fn verified_only(mut self) -> Self {
+        self.verified_only = true;
+        self
+    }
+    
+    /// At most `limit` facts
+    pub fn with_limit(mut self, limit: usize) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+    
+    /// The script and its parameters
+    fn build(&self) -> Result<(String, BTreeMap<String, DataValue>), CozoError> {
+        let mut script = String::from(
+            "?[timestamp, id, domain, statement, confidence, source_id, verified] := \
+             facts[id, domain, statement, confidence, source_id, timestamp, verified]",
+        );
+        let mut params = BTreeMap::new();
+        
+        if let Some(domain) = &self.domain {
+            script.push_str(", domain = $domain");
+            params.insert("domain".to_string(), DataValue::from(domain.as_str()));
+        }
+        if let Some(min_confidence) = self.min_confidence {
+            if !min_confidence.is_finite() {
+                return Err(CozoError::InvalidFact(format!("min confidence {} is not finite", min_confidence)));
+            }
+            script.push_str(", confidence >= $min_confidence");
+            params.insert("min_confidence".to_string(), DataValue::from(min_confidence));
+        }
+        if self.verified_only {
+            script.push_str(", verified = true");
+        }
+        script.push_str("\n:order timestamp, id");
+        if let Some(limit) = self.limit {
+            script.push_str(&format!("\n:limit {}", limit));
+        }
+        
+        Ok((script, params))
+    }
 }
 
 /// CozoDB store for sovereign memory
+#[derive(Clone)]
 pub struct CozoStore {
     db: DbInstance,
 }
@@ -54,6 +183,18 @@ impl CozoStore {
             }
         "#)?;
         
+        // Sessions relation - one chain of thought each; closing seals it
+        self.run_script(r#"
+            :create sessions {
+                id: String
+                =>
+                label: String,
+                created_at: Float,
+                closed_at: Float?,
+                final_hash: String?
+            }
+        "#)?;
+        
         // Provenance relation - tracks data lineage
         self.run_script(r#"
             :create provenance {
@@ -107,6 +248,29 @@ impl CozoStore {
             }
         "#)?;
         
+        // DSIF audit relation - hash-chained audit entries and decisions
+        self.run_script(r#"
+            :create dsif_audit {
+                id: String
+                =>
+                kind: String,
+                sequence: Int,
+                hash: String,
+                previous_hash: String,
+                payload: String
+            }
+        "#)?;
+        
+        // Trust anchors relation - signers accepted when verifying receipts
+        self.run_script(r#"
+            :create trust_anchors {
+                name: String
+                =>
+                key: String,
+                added_at: Float
+            }
+        "#)?;
+        
         tracing::info!("CozoDB schema initialized");
         Ok(())
     }
@@ -118,81 +282,217 @@ impl CozoStore {
             .map_err(|e| CozoError::Query(e.to_string()))
     }
     
-    /// Store a thought in the chain
-    pub fn store_thought(
+    /// Run a Datalog script with bound parameters
+    fn run_script_with_params(
         &self,
+        script: &str,
+        params: BTreeMap<String, DataValue>,
+    ) -> Result<NamedRows, CozoError> {
+        self.db
+            .run_script(script, params, ScriptMutability::Mutable)
+            .map_err(|e| CozoError::Query(e.to_string()))
+    }
+    
+    /// Open a new chain-of-thought session
+    pub fn create_session(&self, label: &str) -> Result<SessionId, CozoError> {
+        let id = Uuid::new_v4().to_string();
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("label".to_string(), DataValue::from(label)),
+            ("created_at".to_string(), DataValue::from(chrono::Utc::now().timestamp_millis() as f64)),
+        ]);
+        
+        self.run_script_with_params(
+            r#"?[id, label, created_at, closed_at, final_hash] <- [[$id, $label, $created_at, null, null]]
+            :put sessions { id => label, created_at, closed_at, final_hash }"#,
+            params,
+        )?;
+        
+        tracing::debug!("Opened session: {} ({})", id, label);
+        Ok(id)
+    }
+    
+    /// List sessions, oldest first
+    pub fn list_sessions(&self) -> Result<Vec<Value>, CozoError> {
+        let result = self.run_script(
+            r#"?[created_at, id, label, closed_at, final_hash] := sessions[id, label, created_at, closed_at, final_hash]
+               :order created_at"#,
+        )?;
+        
+        Ok(result.rows.iter().map(|row| session_json(row)).collect())
+    }
+    
+    /// One session's record, as listed by [`CozoStore::list_sessions`]
+    pub fn get_session(&self, session_id: &str) -> Result<Value, CozoError> {
+        let params = BTreeMap::from([("id".to_string(), DataValue::from(session_id))]);
+        let result = self.run_script_with_params(
+            r#"?[created_at, id, label, closed_at, final_hash] := sessions[id, label, created_at, closed_at, final_hash], id = $id"#,
+            params,
+        )?;
+        
+        result
+            .rows
+            .first()
+            .map(|row| session_json(row))
+            .ok_or_else(|| CozoError::UnknownSession(session_id.to_string()))
+    }
+    
+    /// Fail unless `session_id` exists and is still open
+    fn ensure_open(&self, session_id: &str) -> Result<(), CozoError> {
+        let params = BTreeMap::from([("id".to_string(), DataValue::from(session_id))]);
+        let result = self.run_script_with_params(
+            r#"?[closed_at] := sessions[$id, _, _, closed_at, _]"#,
+            params,
+        )?;
+        
+        match result.rows.first().and_then(|r| r.first()) {
+            None => Err(CozoError::UnknownSession(session_id.to_string())),
+            Some(DataValue::Null) => Ok(()),
+            Some(_) => Err(CozoError::SessionClosed(session_id.to_string())),
+        }
+    }
+    
+    /// Store a thought at the end of a session's chain
+    pub fn store_thought_in(
+        &self,
+        session_id: &str,
         thought_type: &str,
         content: &str,
         metadata: Value,
     ) -> Result<String, CozoError> {
+        self.ensure_open(session_id)?;
+        
         let id = Uuid::new_v4().to_string();
-        let session_id = "default"; // TODO: Session management
         let timestamp = chrono::Utc::now().timestamp_millis() as f64;
         let hash = crate::invariance::sha256(content);
         let metadata_str = serde_json::to_string(&metadata)?;
         
-        // Get next sequence number
-        let seq_result = self.run_script(&format!(
-            r#"?[max_seq] := thoughts[_, "{}", _, _, _, _, _, seq], max_seq = max(seq)
+        // Sequence numbers are per session
+        let seq_result = self.run_script_with_params(
+            r#"?[max_seq] := thoughts[_, $session_id, _, _, _, _, _, seq], max_seq = max(seq)
                ?[max_seq] := max_seq = 0"#,
-            session_id
-        ))?;
+            BTreeMap::from([("session_id".to_string(), DataValue::from(session_id))]),
+        )?;
         
         let sequence = seq_result
             .rows
-            .first()
-            .and_then(|r| r.first())
-            .and_then(|v| match v {
+            .iter()
+            .filter_map(|r| r.first().and_then(|v| match v {
                 DataValue::Num(n) => n.get_int(),
                 _ => None,
-            })
+            }))
+            .max()
             .unwrap_or(0) + 1;
         
-        self.run_script(&format!(
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("session_id".to_string(), DataValue::from(session_id)),
+            ("thought_type".to_string(), DataValue::from(thought_type)),
+            ("content".to_string(), DataValue::from(content)),
+            ("metadata".to_string(), DataValue::from(metadata_str.as_str())),
+            ("timestamp".to_string(), DataValue::from(timestamp)),
+            ("hash".to_string(), DataValue::from(hash.as_str())),
+            ("sequence".to_string(), DataValue::from(sequence)),
+        ]);
+        
+        self.run_script_with_params(
             r#"?[id, session_id, thought_type, content, metadata, timestamp, hash, sequence] <- [[
-                "{}", "{}", "{}", "{}", "{}", {}, "{}", {}
+                $id, $session_id, $thought_type, $content, $metadata, $timestamp, $hash, $sequence
             ]]
-            :put thoughts {{ id, session_id, thought_type, content, metadata, timestamp, hash => sequence }}"#,
-            id,
-            session_id,
-            thought_type,
-            content.replace('"', r#"\""#),
-            metadata_str.replace('"', r#"\""#),
-            timestamp,
-            hash,
-            sequence
-        ))?;
-        
-        tracing::debug!("Stored thought: {} (seq {})", id, sequence);
+            :put thoughts { id, session_id, thought_type, content, metadata, timestamp, hash => sequence }"#,
+            params,
+        )?;
+        
+        tracing::debug!("Stored thought: {} (session {}, seq {})", id, session_id, sequence);
         Ok(id)
     }
     
+    /// Hash over a session's thought hashes in sequence order
+    fn session_hash(&self, session_id: &str) -> Result<String, CozoError> {
+        let chain = self.get_chain_of_thought(session_id)?;
+        let mut material = session_id.to_string();
+        for thought in &chain {
+            material.push('\n');
+            material.push_str(thought["hash"].as_str().unwrap_or(""));
+        }
+        Ok(crate::invariance::sha256(&material))
+    }
+    
+    /// Close a session, sealing it with a final hash over its ordered thoughts
+    pub fn close_session(&self, session_id: &str) -> Result<String, CozoError> {
+        self.ensure_open(session_id)?;
+        let final_hash = self.session_hash(session_id)?;
+        
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(session_id)),
+            ("closed_at".to_string(), DataValue::from(chrono::Utc::now().timestamp_millis() as f64)),
+            ("final_hash".to_string(), DataValue::from(final_hash.as_str())),
+        ]);
+        
+        self.run_script_with_params(
+            r#"?[id, label, created_at, closed_at, final_hash] :=
+                sessions[id, label, created_at, _, _], id = $id,
+                closed_at = $closed_at, final_hash = $final_hash
+            :put sessions { id => label, created_at, closed_at, final_hash }"#,
+            params,
+        )?;
+        
+        tracing::debug!("Closed session: {} ({})", session_id, final_hash);
+        Ok(final_hash)
+    }
+    
+    /// Whether a closed session's thoughts still match its final hash
+    pub fn verify_session(&self, session_id: &str) -> Result<bool, CozoError> {
+        let params = BTreeMap::from([("id".to_string(), DataValue::from(session_id))]);
+        let result = self.run_script_with_params(
+            r#"?[final_hash] := sessions[$id, _, _, _, final_hash]"#,
+            params,
+        )?;
+        
+        match result.rows.first().and_then(|r| r.first()) {
+            None => Err(CozoError::UnknownSession(session_id.to_string())),
+            Some(DataValue::Str(stored)) => Ok(stored.as_str() == self.session_hash(session_id)?),
+            Some(_) => Err(CozoError::Query(format!("Session {} is not closed", session_id))),
+        }
+    }
+    
     /// Get chain of thought for a session
     pub fn get_chain_of_thought(&self, session_id: &str) -> Result<Vec<Value>, CozoError> {
-        let result = self.run_script(&format!(
+        let params = BTreeMap::from([("session_id".to_string(), DataValue::from(session_id))]);
+        let result = self.run_script_with_params(
             r#"?[id, thought_type, content, metadata, timestamp, hash, sequence] := 
-                thoughts[id, "{}", thought_type, content, metadata, timestamp, hash, sequence]
+                thoughts[id, $session_id, thought_type, content, metadata, timestamp, hash, sequence]
                :order sequence"#,
-            session_id
-        ))?;
+            params,
+        )?;
         
-        let thoughts: Vec<Value> = result
-            .rows
-            .iter()
-            .map(|row| {
-                serde_json::json!({
-                    "id": row.get(0).map(dv_to_string).unwrap_or_default(),
-                    "thought_type": row.get(1).map(dv_to_string).unwrap_or_default(),
-                    "content": row.get(2).map(dv_to_string).unwrap_or_default(),
-                    "metadata": row.get(3).map(dv_to_string).unwrap_or_default(),
-                    "timestamp": row.get(4).map(dv_to_f64).unwrap_or(0.0),
-                    "hash": row.get(5).map(dv_to_string).unwrap_or_default(),
-                    "sequence": row.get(6).map(dv_to_i64).unwrap_or(0),
-                })
-            })
-            .collect();
+        Ok(result.rows.iter().map(|row| thought_json(row)).collect())
+    }
+    
+    /// Up to `limit` thoughts of a session with sequence above `after_sequence`
+    pub fn get_chain_of_thought_page(
+        &self,
+        session_id: &str,
+        after_sequence: i64,
+        limit: usize,
+    ) -> Result<Vec<Value>, CozoError> {
+        let params = BTreeMap::from([
+            ("session_id".to_string(), DataValue::from(session_id)),
+            ("after".to_string(), DataValue::from(after_sequence)),
+        ]);
+        let result = self.run_script_with_params(
+            &format!(
+                r#"?[id, thought_type, content, metadata, timestamp, hash, sequence] := 
+                    thoughts[id, $session_id, thought_type, content, metadata, timestamp, hash, sequence],
+                    sequence > $after
+                   :order sequence
+                   :limit {}"#,
+                limit
+            ),
+            params,
+        )?;
         
-        Ok(thoughts)
+        Ok(result.rows.iter().map(|row| thought_json(row)).collect())
     }
     
     /// Store provenance record
@@ -205,13 +505,21 @@ impl CozoStore {
         let id = Uuid::new_v4().to_string();
         let timestamp = chrono::Utc::now().timestamp_millis() as f64;
         
-        self.run_script(&format!(
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("source_type".to_string(), DataValue::from(source_type)),
+            ("source_uri".to_string(), DataValue::from(source_uri)),
+            ("retrieved_at".to_string(), DataValue::from(timestamp)),
+            ("content_hash".to_string(), DataValue::from(content_hash)),
+        ]);
+        
+        self.run_script_with_params(
             r#"?[id, source_type, source_uri, retrieved_at, content_hash, verified] <- [[
-                "{}", "{}", "{}", {}, "{}", false
+                $id, $source_type, $source_uri, $retrieved_at, $content_hash, false
             ]]
-            :put provenance {{ id, source_type, source_uri, retrieved_at, content_hash => verified }}"#,
-            id, source_type, source_uri, timestamp, content_hash
-        ))?;
+            :put provenance { id, source_type, source_uri, retrieved_at, content_hash => verified }"#,
+            params,
+        )?;
         
         Ok(id)
     }
@@ -221,23 +529,198 @@ impl CozoStore {
         let id = Uuid::new_v4().to_string();
         let timestamp = chrono::Utc::now().timestamp_millis() as f64;
         
-        self.run_script(&format!(
+        // Bound parameters: the evidence is JSON and claims may hold quotes
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("claim".to_string(), DataValue::from(receipt["claim"].as_str().unwrap_or(""))),
+            ("evidence".to_string(), DataValue::from(serde_json::to_string(&receipt["evidence"])?)),
+            ("c_zero".to_string(), DataValue::from(receipt["C_zero"].as_bool().unwrap_or(false))),
+            ("hash".to_string(), DataValue::from(receipt["hash"].as_str().unwrap_or(""))),
+            ("signature".to_string(), DataValue::from(receipt["signature"].as_str().unwrap_or(""))),
+            ("timestamp".to_string(), DataValue::from(timestamp)),
+        ]);
+        
+        self.run_script_with_params(
             r#"?[id, claim, evidence, c_zero, hash, signature, timestamp] <- [[
-                "{}", "{}", "{}", {}, "{}", "{}", {}
+                $id, $claim, $evidence, $c_zero, $hash, $signature, $timestamp
             ]]
-            :put receipts {{ id, claim, evidence, c_zero, hash, signature, timestamp }}"#,
-            id,
-            receipt["claim"].as_str().unwrap_or(""),
-            serde_json::to_string(&receipt["evidence"]).unwrap_or_default(),
-            receipt["C_zero"].as_bool().unwrap_or(false),
-            receipt["hash"].as_str().unwrap_or(""),
-            receipt["signature"].as_str().unwrap_or(""),
-            timestamp
-        ))?;
+            :put receipts { id, claim, evidence, c_zero, hash, signature, timestamp }"#,
+            params,
+        )?;
         
         Ok(id)
     }
     
+    /// Up to `limit` receipts stored between `from` and `to` (epoch millis),
+    /// oldest first, skipping the first `offset`
+    pub fn load_receipts_between(
+        &self,
+        from: f64,
+        to: f64,
+        offset: usize,
+        limit: usize,
+    ) -> Result<Vec<Value>, CozoError> {
+        let params = BTreeMap::from([
+            ("from".to_string(), DataValue::from(from)),
+            ("to".to_string(), DataValue::from(to)),
+        ]);
+        let result = self.run_script_with_params(
+            &format!(
+                r#"?[timestamp, id, claim, evidence, c_zero, hash, signature] :=
+                    receipts[id, claim, evidence, c_zero, hash, signature, timestamp],
+                    timestamp >= $from, timestamp <= $to
+                   :order timestamp, id
+                   :offset {}
+                   :limit {}"#,
+                offset, limit
+            ),
+            params,
+        )?;
+        
+        Ok(result
+            .rows
+            .iter()
+            .map(|row| {
+                let evidence = row.get(3).map(dv_to_string).unwrap_or_default();
+                serde_json::json!({
+                    "id": row.get(1).map(dv_to_string).unwrap_or_default(),
+                    "claim": row.get(2).map(dv_to_string).unwrap_or_default(),
+                    "evidence": serde_json::from_str::<Value>(&evidence).unwrap_or(Value::String(evidence)),
+                    "C_zero": row.get(4).map(dv_to_json).unwrap_or(Value::Bool(false)),
+                    "hash": row.get(5).map(dv_to_string).unwrap_or_default(),
+                    "signature": row.get(6).map(dv_to_string).unwrap_or_default(),
+                    "timestamp": row.first().map(dv_to_f64).unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+    
+    /// Store an unverified fact
+    pub fn store_fact(
+        &self,
+        domain: &str,
+        statement: &str,
+        confidence: f64,
+        source_id: &str,
+    ) -> Result<String, CozoError> {
+        if !(0.0..=1.0).contains(&confidence) {
+            return Err(CozoError::InvalidFact(format!("confidence {} is outside 0.0..=1.0", confidence)));
+        }
+        if statement.trim().is_empty() {
+            return Err(CozoError::InvalidFact("statement is empty".to_string()));
+        }
+        
+        let id = Uuid::new_v4().to_string();
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("domain".to_string(), DataValue::from(domain)),
+            ("statement".to_string(), DataValue::from(statement)),
+            ("confidence".to_string(), DataValue::from(confidence)),
+            ("source_id".to_string(), DataValue::from(source_id)),
+            ("timestamp".to_string(), DataValue::from(chrono::Utc::now().timestamp_millis() as f64)),
+        ]);
+        
+        self.run_script_with_params(
+            r#"?[id, domain, statement, confidence, source_id, timestamp, verified] <- [[
+                $id, $domain, $statement, $confidence, $source_id, $timestamp, false
+            ]]
+            :put facts { id, domain, statement, confidence, source_id, timestamp => verified }"#,
+            params,
+        )?;
+        
+        tracing::debug!("Stored fact: {} ({})", id, domain);
+        Ok(id)
+    }
+    
+    /// One fact by id
+    pub fn get_fact(&self, id: &str) -> Result<Fact, CozoError> {
+        let params = BTreeMap::from([("id".to_string(), DataValue::from(id))]);
+        let result = self.run_script_with_params(
+            r#"?[timestamp, id, domain, statement, confidence, source_id, verified] :=
+                facts[id, domain, statement, confidence, source_id, timestamp, verified], id = $id"#,
+            params,
+        )?;
+        
+        result
+            .rows
+            .first()
+            .map(|row| fact_from_row(row))
+            .ok_or_else(|| CozoError::UnknownFact(id.to_string()))
+    }
+    
+    /// Mark a fact verified, logging a `FACT_VERIFIED` action the first time
+    pub fn verify_fact(&self, id: &str) -> Result<Fact, CozoError> {
+        let mut fact = self.get_fact(id)?;
+        if fact.verified {
+            return Ok(fact);
+        }
+        
+        let params = BTreeMap::from([("id".to_string(), DataValue::from(id))]);
+        self.run_script_with_params(
+            r#"?[id, domain, statement, confidence, source_id, timestamp, verified] :=
+                facts[id, domain, statement, confidence, source_id, timestamp, _], id = $id,
+                verified = true
+            :put facts { id, domain, statement, confidence, source_id, timestamp => verified }"#,
+            params,
+        )?;
+        fact.verified = true;
+        
+        let result = serde_json::json!({
+            "domain": fact.domain,
+            "statement": fact.statement,
+            "confidence": fact.confidence,
+            "source_id": fact.source_id,
+        });
+        self.log_action("FACT_VERIFIED", id, &result.to_string(), 0.0)?;
+        
+        tracing::debug!("Verified fact: {}", id);
+        Ok(fact)
+    }
+    
+    /// Facts matching a [`FactQuery`], oldest first
+    pub fn find_facts(&self, query: &FactQuery) -> Result<Vec<Fact>, CozoError> {
+        let (script, params) = query.build()?;
+        let result = self.run_script_with_params(&script, params)?;
+        
+        Ok(result.rows.iter().map(|row| fact_from_row(row)).collect())
+    }
+    
+    /// Facts in `domain` (any domain if `None`) with at least `min_confidence`
+    pub fn query_facts(
+        &self,
+        domain: Option<&str>,
+        min_confidence: Option<f64>,
+        verified_only: bool,
+    ) -> Result<Vec<Fact>, CozoError> {
+        let mut query = FactQuery::new();
+        if let Some(domain) = domain {
+            query = query.with_domain(domain);
+        }
+        if let Some(min_confidence) = min_confidence {
+            query = query.with_min_confidence(min_confidence);
+        }
+        if verified_only {
+            query = query.verified_only();
+        }
+        
+        self.find_facts(&query)
+    }
+    
+    /// Verified facts sharing at least one word with `claim`, most shared
+    /// words first, then most confident
+    pub fn facts_for_claim(&self, claim: &str) -> Result<Vec<Fact>, CozoError> {
+        let claim_tokens = tokens(claim);
+        let mut matches: Vec<(usize, Fact)> = self
+            .find_facts(&FactQuery::new().verified_only())?
+            .into_iter()
+            .map(|fact| (tokens(&fact.statement).intersection(&claim_tokens).count(), fact))
+            .filter(|(shared, _)| *shared > 0)
+            .collect();
+        
+        matches.sort_by(|(a, fa), (b, fb)| b.cmp(a).then(fb.confidence.total_cmp(&fa.confidence)));
+        Ok(matches.into_iter().map(|(_, fact)| fact).collect())
+    }
+    
     /// Log an action
     pub fn log_action(
         &self,
@@ -249,17 +732,200 @@ impl CozoStore {
         let id = Uuid::new_v4().to_string();
         let timestamp = chrono::Utc::now().timestamp_millis() as f64;
         
-        self.run_script(&format!(
+        // Bound parameters: results are often JSON and would break a quoted literal
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id.as_str())),
+            ("action_type".to_string(), DataValue::from(action_type)),
+            ("target".to_string(), DataValue::from(target)),
+            ("result".to_string(), DataValue::from(result)),
+            ("timestamp".to_string(), DataValue::from(timestamp)),
+            ("entropy_delta".to_string(), DataValue::from(entropy_delta)),
+        ]);
+        
+        self.run_script_with_params(
             r#"?[id, action_type, target, result, timestamp, entropy_delta] <- [[
-                "{}", "{}", "{}", "{}", {}, {}
+                $id, $action_type, $target, $result, $timestamp, $entropy_delta
             ]]
-            :put actions {{ id, action_type, target, result, timestamp => entropy_delta }}"#,
-            id, action_type, target, result, timestamp, entropy_delta
-        ))?;
+            :put actions { id, action_type, target, result, timestamp => entropy_delta }"#,
+            params,
+        )?;
         
         Ok(id)
     }
     
+    /// Load logged actions of one type, oldest first
+    pub fn load_actions(&self, action_type: &str) -> Result<Vec<Value>, CozoError> {
+        let params = BTreeMap::from([("action_type".to_string(), DataValue::from(action_type))]);
+        let result = self.run_script_with_params(
+            r#"?[timestamp, id, target, result] := actions[id, $action_type, target, result, timestamp, _]
+               :order timestamp"#,
+            params,
+        )?;
+        
+        Ok(result
+            .rows
+            .iter()
+            .map(|row| {
+                let result = row.get(3).map(dv_to_string).unwrap_or_default();
+                serde_json::json!({
+                    "id": row.get(1).map(dv_to_string).unwrap_or_default(),
+                    "action_type": action_type,
+                    "target": row.get(2).map(dv_to_string).unwrap_or_default(),
+                    "result": serde_json::from_str::<Value>(&result).unwrap_or(Value::String(result)),
+                    "timestamp": row.first().map(dv_to_f64).unwrap_or_default(),
+                })
+            })
+            .collect())
+    }
+    
+    /// Add (or replace) the trust anchor `name`, whose key is
+    /// `hmac:<base64 secret>` or `ed25519:<base64 public key>`
+    pub fn store_trust_anchor(&self, name: &str, key: &str) -> Result<StoredAnchor, CozoError> {
+        if name.trim().is_empty() {
+            return Err(CozoError::InvalidTrustAnchor("name must not be empty".to_string()));
+        }
+        
+        let anchor = StoredAnchor {
+            name: name.to_string(),
+            scheme: key.split(':').next().unwrap_or_default().to_string(),
+            key: key.to_string(),
+            added_at: chrono::Utc::now().timestamp_millis() as f64,
+        };
+        anchor.anchor()?;
+        let params = BTreeMap::from([
+            ("name".to_string(), DataValue::from(name)),
+            ("key".to_string(), DataValue::from(key)),
+            ("added_at".to_string(), DataValue::from(anchor.added_at)),
+        ]);
+        
+        self.run_script_with_params(
+            r#"?[name, key, added_at] <- [[$name, $key, $added_at]]
+            :put trust_anchors { name => key, added_at }"#,
+            params,
+        )?;
+        
+        Ok(anchor)
+    }
+    
+    /// Stored trust anchors, by name
+    pub fn load_trust_anchors(&self) -> Result<Vec<StoredAnchor>, CozoError> {
+        let result = self.run_script(
+            r#"?[name, key, added_at] := trust_anchors[name, key, added_at]
+               :order name"#,
+        )?;
+        
+        Ok(result
+            .rows
+            .iter()
+            .map(|row| {
+                let key = row.get(1).map(dv_to_string).unwrap_or_default();
+                StoredAnchor {
+                    name: row.first().map(dv_to_string).unwrap_or_default(),
+                    scheme: key.split(':').next().unwrap_or_default().to_string(),
+                    key,
+                    added_at: row.get(2).map(dv_to_f64).unwrap_or_default(),
+                }
+            })
+            .collect())
+    }
+    
+    /// Stop trusting `name`; false if it was not stored
+    pub fn remove_trust_anchor(&self, name: &str) -> Result<bool, CozoError> {
+        if !self.load_trust_anchors()?.iter().any(|anchor| anchor.name == name) {
+            return Ok(false);
+        }
+        
+        let params = BTreeMap::from([("name".to_string(), DataValue::from(name))]);
+        self.run_script_with_params(
+            r#"?[name] <- [[$name]]
+            :rm trust_anchors { name }"#,
+            params,
+        )?;
+        
+        Ok(true)
+    }
+    
+    /// Store a DSIF audit record (`kind` is "entry" or "decision")
+    pub fn store_dsif_record(
+        &self,
+        kind: &str,
+        id: &str,
+        sequence: i64,
+        hash: &str,
+        previous_hash: Option<&str>,
+        payload: &Value,
+    ) -> Result<(), CozoError> {
+        let params = BTreeMap::from([
+            ("id".to_string(), DataValue::from(id)),
+            ("kind".to_string(), DataValue::from(kind)),
+            ("sequence".to_string(), DataValue::from(sequence)),
+            ("hash".to_string(), DataValue::from(hash)),
+            ("previous_hash".to_string(), DataValue::from(previous_hash.unwrap_or(""))),
+            ("payload".to_string(), DataValue::from(serde_json::to_string(payload)?)),
+        ]);
+        
+        self.run_script_with_params(
+            r#"?[id, kind, sequence, hash, previous_hash, payload] <- [[
+                $id, $kind, $sequence, $hash, $previous_hash, $payload
+            ]]
+            :put dsif_audit { id => kind, sequence, hash, previous_hash, payload }"#,
+            params,
+        )?;
+        
+        Ok(())
+    }
+    
+    /// Load DSIF audit record payloads of one kind, in sequence order
+    pub fn load_dsif_records(&self, kind: &str) -> Result<Vec<Value>, CozoError> {
+        let params = BTreeMap::from([("kind".to_string(), DataValue::from(kind))]);
+        let result = self.run_script_with_params(
+            r#"?[sequence, payload] := dsif_audit[_, $kind, sequence, _, _, payload]
+               :order sequence"#,
+            params,
+        )?;
+        
+        result
+            .rows
+            .iter()
+            .map(|row| {
+                let payload = row.get(1).map(dv_to_string).unwrap_or_default();
+                Ok(serde_json::from_str(&payload)?)
+            })
+            .collect()
+    }
+    
+    /// Up to `limit` DSIF record payloads of one kind with sequence above `after_sequence`
+    pub fn load_dsif_records_page(
+        &self,
+        kind: &str,
+        after_sequence: i64,
+        limit: usize,
+    ) -> Result<Vec<(i64, Value)>, CozoError> {
+        let params = BTreeMap::from([
+            ("kind".to_string(), DataValue::from(kind)),
+            ("after".to_string(), DataValue::from(after_sequence)),
+        ]);
+        let result = self.run_script_with_params(
+            &format!(
+                r#"?[sequence, payload] := dsif_audit[_, $kind, sequence, _, _, payload], sequence > $after
+                   :order sequence
+                   :limit {}"#,
+                limit
+            ),
+            params,
+        )?;
+        
+        result
+            .rows
+            .iter()
+            .map(|row| {
+                let sequence = row.first().map(dv_to_i64).unwrap_or_default();
+                let payload = row.get(1).map(dv_to_string).unwrap_or_default();
+                Ok((sequence, serde_json::from_str(&payload)?))
+            })
+            .collect()
+    }
+    
     /// Run a custom query
     pub fn query(&self, query: &str) -> Result<Value, CozoError> {
         let result = self.run_script(query)?;
@@ -277,6 +943,51 @@ impl CozoStore {
     }
 }
 
+/// Session row `[created_at, id, label, closed_at, final_hash]` as JSON
+fn session_json(row: &[DataValue]) -> Value {
+    serde_json::json!({
+        "id": row.get(1).map(dv_to_string).unwrap_or_default(),
+        "label": row.get(2).map(dv_to_string).unwrap_or_default(),
+        "created_at": row.first().map(dv_to_f64).unwrap_or_default(),
+        "closed_at": row.get(3).map(dv_to_json).unwrap_or(Value::Null),
+        "final_hash": row.get(4).map(dv_to_json).unwrap_or(Value::Null),
+    })
+}
+
+/// Thought row `[id, thought_type, content, metadata, timestamp, hash, sequence]` as JSON
+fn thought_json(row: &[DataValue]) -> Value {
+    serde_json::json!({
+        "id": row.get(0).map(dv_to_string).unwrap_or_default(),
+        "thought_type": row.get(1).map(dv_to_string).unwrap_or_default(),
+        "content": row.get(2).map(dv_to_string).unwrap_or_default(),
+        "metadata": row.get(3).map(dv_to_string).unwrap_or_default(),
+        "timestamp": row.get(4).map(dv_to_f64).unwrap_or(0.0),
+        "hash": row.get(5).map(dv_to_string).unwrap_or_default(),
+        "sequence": row.get(6).map(dv_to_i64).unwrap_or(0),
+    })
+}
+
+/// Fact row `[timestamp, id, domain, statement, confidence, source_id, verified]`
+fn fact_from_row(row: &[DataValue]) -> Fact {
+    Fact {
+        id: row.get(1).map(dv_to_string).unwrap_or_default(),
+        domain: row.get(2).map(dv_to_string).unwrap_or_default(),
+        statement: row.get(3).map(dv_to_string).unwrap_or_default(),
+        confidence: row.get(4).map(dv_to_f64).unwrap_or_default(),
+        source_id: row.get(5).map(dv_to_string).unwrap_or_default(),
+        timestamp: row.first().map(dv_to_f64).unwrap_or_default(),
+        verified: matches!(row.get(6), Some(DataValue::Bool(true))),
+    }
+}
+
+/// Lowercase words of three or more letters or digits
+fn tokens(text: &str) -> BTreeSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|word| word.chars().count() >= 3)
+        .map(str::to_lowercase)
+        .collect()
+}
+
 /// Convert DataValue to String
 fn dv_to_string(dv: &DataValue) -> String {
     match dv {
@@ -323,3 +1034,109 @@ fn dv_to_json(dv: &DataValue) -> Value {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    fn temp_store(name: &str) -> CozoStore {
+        CozoStore::new(&std::env::temp_dir().join(format!("{}-{}", name, std::process::id()))).unwrap()
+    }
+
+    #[test]
+    fn test_store_and_query_facts() {
+        let store = temp_store("facts-query");
+        let quoted = store.store_fact("physics", r#"Water "boils" at 100\C"#, 0.9, "prov-1").unwrap();
+        store.store_fact("physics", "Ice melts above 0C", 0.4, "prov-2").unwrap();
+        store.store_fact("biology", "Cells divide", 0.95, "prov-3").unwrap();
+
+        let fact = store.get_fact(&quoted).unwrap();
+        assert_eq!(fact.statement, r#"Water "boils" at 100\C"#);
+        assert_eq!(fact.confidence, 0.9);
+        assert!(!fact.verified);
+
+        assert_eq!(store.query_facts(Some("physics"), None, false).unwrap().len(), 2);
+        assert_eq!(store.query_facts(Some("physics"), Some(0.5), false).unwrap().len(), 1);
+        assert_eq!(store.query_facts(None, Some(0.5), false).unwrap().len(), 2);
+        assert!(store.query_facts(None, None, true).unwrap().is_empty());
+        assert_eq!(store.find_facts(&FactQuery::new().with_limit(1)).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_invalid_facts_are_rejected() {
+        let store = temp_store("facts-invalid");
+        assert!(matches!(store.store_fact("d", "s", 1.5, "p"), Err(CozoError::InvalidFact(_))));
+        assert!(matches!(store.store_fact("d", "s", f64::NAN, "p"), Err(CozoError::InvalidFact(_))));
+        assert!(matches!(store.store_fact("d", "  ", 0.5, "p"), Err(CozoError::InvalidFact(_))));
+        assert!(matches!(
+            store.query_facts(None, Some(f64::INFINITY), false),
+            Err(CozoError::InvalidFact(_))
+        ));
+        assert!(matches!(store.verify_fact("missing"), Err(CozoError::UnknownFact(_))));
+    }
+
+    #[test]
+    fn test_verify_fact_logs_once() {
+        let store = temp_store("facts-verify");
+        let id = store.store_fact("physics", "Water boils at 100C", 0.9, "prov-1").unwrap();
+
+        assert!(store.verify_fact(&id).unwrap().verified);
+        assert!(store.verify_fact(&id).unwrap().verified);
+        assert!(store.get_fact(&id).unwrap().verified);
+        assert_eq!(store.query_facts(None, None, true).unwrap().len(), 1);
+
+        let actions = store.load_actions("FACT_VERIFIED").unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0]["target"], id.as_str());
+        assert_eq!(actions[0]["result"]["statement"], "Water boils at 100C");
+    }
+
+    #[test]
+    fn test_facts_for_claim_ranks_verified_matches() {
+        let store = temp_store("facts-claim");
+        let one = store.store_fact("weather", "The sky is blue", 0.7, "p").unwrap();
+        let both = store.store_fact("weather", "Blue sky means clear weather", 0.6, "p").unwrap();
+        let unrelated = store.store_fact("weather", "Rain is wet", 0.9, "p").unwrap();
+        store.store_fact("weather", "Clear blue sky today", 0.9, "p").unwrap();
+        for id in [&one, &both, &unrelated] {
+            store.verify_fact(id).unwrap();
+        }
+
+        let facts = store.facts_for_claim("Clear weather under a blue sky").unwrap();
+        let ids: Vec<&str> = facts.iter().map(|f| f.id.as_str()).collect();
+        assert_eq!(ids, [both.as_str(), one.as_str()]);
+    }
+
+    #[test]
+    fn test_trust_anchors_round_trip() {
+        let store = temp_store("trust-anchors");
+        let ed25519 = format!("ed25519:{}", STANDARD.encode([7u8; 32]));
+        store.store_trust_anchor("portal", &ed25519).unwrap();
+        store.store_trust_anchor("audit", "hmac:b2xkIGtleQ==").unwrap();
+        store.store_trust_anchor("audit", "hmac:YXVkaXRkIGtleQ==").unwrap();
+
+        let anchors = store.load_trust_anchors().unwrap();
+        let pairs: Vec<(&str, &str)> = anchors.iter().map(|a| (a.name.as_str(), a.scheme.as_str())).collect();
+        assert_eq!(pairs, [("audit", "hmac"), ("portal", "ed25519")]);
+        assert_eq!(anchors[0].anchor().unwrap(), TrustAnchor::hmac("audit", "auditd key"));
+        assert!(!serde_json::to_string(&anchors[0]).unwrap().contains("YXVkaXRkIGtleQ=="));
+
+        assert!(store.remove_trust_anchor("audit").unwrap());
+        assert!(!store.remove_trust_anchor("audit").unwrap());
+        assert_eq!(store.load_trust_anchors().unwrap().len(), 1);
+        for (name, key) in [(" ", "hmac:eA=="), ("audit", "AUDIT_SVC_SIG:"), ("audit", "ed25519:eA==")] {
+            assert!(matches!(store.store_trust_anchor(name, key), Err(CozoError::InvalidTrustAnchor(_))));
+        }
+    }
+
+    #[test]
+    fn test_store_receipt_with_quotes() {
+        let store = temp_store("receipt-quotes");
+        let receipt = crate::invariance::generate_receipt(r#"A "quoted" claim"#, &[r#"say "hi""#.to_string()]);
+        store.store_receipt(&receipt).unwrap();
+
+        let stored = store.load_receipts_between(0.0, f64::MAX, 0, 10).unwrap();
+        assert_eq!(stored[0]["claim"], r#"A "quoted" claim"#);
+        assert_eq!(stored[0]["evidence"][0], r#"say "hi""#);
+    }
+}
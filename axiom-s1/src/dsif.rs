@@ -6,14 +6,27 @@
 //! Pipeline: Input hygiene → Policy validation → Simulation-before-actuation →
 //!           Consensus gating → Controlled actuation → Immutable audit
 //!
+//! Critical actions stop before actuation: their decision is sealed as
+//! `AwaitingApproval` and actuated only when a human calls [`DSIF::approve`]
+//! with the decision's one-time approval token. [`DSIF::reject`] settles it
+//! without actuation, and decisions nobody settles expire after the approval
+//! TTL. Pending approvals live in memory and do not survive a restart.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use chrono::Utc;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+use thiserror::Error;
+use tokio::sync::RwLock;
 use uuid::Uuid;
 
+use crate::bark::{SnapshotSource, SystemSnapshot, ThermalStatus};
+use crate::cozo_db::{CozoError, CozoStore};
+use crate::session_bundle;
 use crate::{PROJECTION, SUBSTRATE};
 use verification::Attestation;
 use verification::attestation::SignerRole;
@@ -26,6 +39,333 @@ pub struct Agent {
     pub state: AgentState,
     pub trust_score: f64,
     pub last_decision: Option<Decision>,
+    /// Vote logic for consensus agents (reported by name)
+    #[serde(default, skip_deserializing, serialize_with = "serialize_policy")]
+    pub policy: Option<Arc<dyn AgentPolicy>>,
+}
+
+fn serialize_policy<S: serde::Serializer>(
+    policy: &Option<Arc<dyn AgentPolicy>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    match policy {
+        Some(policy) => serializer.serialize_some(policy.name()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// A consensus agent's verdict on an action
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct VoteDecision {
+    pub approve: bool,
+    pub rationale: String,
+}
+
+impl VoteDecision {
+    pub fn approve(rationale: impl Into<String>) -> Self {
+        Self { approve: true, rationale: rationale.into() }
+    }
+    
+    pub fn reject(rationale: impl Into<String>) -> Self {
+        Self { approve: false, rationale: rationale.into() }
+    }
+}
+
+/// Vote logic run by a consensus agent
+///
+/// Agents with different policies look at different aspects of an action,
+/// so the vote set can genuinely disagree.
+pub trait AgentPolicy: fmt::Debug + Send + Sync {
+    /// Short identifier reported alongside the agent
+    fn name(&self) -> &'static str;
+    
+    /// Vote on an action that passed simulation
+    fn vote(
+        &self,
+        action: &Action,
+        simulation: &SimulationResult,
+        invariants: &InvariantCheck,
+    ) -> VoteDecision;
+}
+
+/// Rejects actions whose predicted resource usage exceeds a fixed budget
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResourceBudgetPolicy {
+    pub max_memory_mb: f64,
+    pub max_cpu_percent: f64,
+    pub max_latency_ms: f64,
+    pub max_network_bytes: u64,
+}
+
+impl Default for ResourceBudgetPolicy {
+    fn default() -> Self {
+        Self {
+            max_memory_mb: 1024.0,
+            max_cpu_percent: 80.0,
+            max_latency_ms: 5000.0,
+            max_network_bytes: 10 * 1024 * 1024,
+        }
+    }
+}
+
+impl AgentPolicy for ResourceBudgetPolicy {
+    fn name(&self) -> &'static str {
+        "resource-budget"
+    }
+    
+    fn vote(&self, _action: &Action, simulation: &SimulationResult, invariants: &InvariantCheck) -> VoteDecision {
+        if !invariants.passed {
+            return VoteDecision::reject("Invariant check failed");
+        }
+        
+        let usage = &simulation.resource_usage;
+        let over = [
+            (usage.memory_mb > self.max_memory_mb)
+                .then(|| format!("memory {:.1}/{:.1} MB", usage.memory_mb, self.max_memory_mb)),
+            (usage.cpu_percent > self.max_cpu_percent)
+                .then(|| format!("cpu {:.1}/{:.1}%", usage.cpu_percent, self.max_cpu_percent)),
+            (usage.latency_ms > self.max_latency_ms)
+                .then(|| format!("latency {:.0}/{:.0} ms", usage.latency_ms, self.max_latency_ms)),
+            (usage.network_bytes > self.max_network_bytes)
+                .then(|| format!("network {}/{} bytes", usage.network_bytes, self.max_network_bytes)),
+        ];
+        let over: Vec<String> = over.into_iter().flatten().collect();
+        
+        if over.is_empty() {
+            VoteDecision::approve("Predicted resource usage within budget")
+        } else {
+            VoteDecision::reject(format!("Resource budget exceeded: {}", over.join(", ")))
+        }
+    }
+}
+
+/// Approves only targets matching the allow patterns and none of the deny patterns
+///
+/// Patterns use [`TargetPattern`] glob syntax; an empty allow list allows every target.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetPatternPolicy {
+    pub allow: Vec<String>,
+    pub deny: Vec<String>,
+}
+
+impl TargetPatternPolicy {
+    /// Deny list covering obviously sensitive targets
+    pub fn sensitive_targets() -> Self {
+        Self {
+            allow: Vec::new(),
+            deny: vec!["*secret*".to_string(), "*credential*".to_string(), "*.key".to_string()],
+        }
+    }
+}
+
+impl AgentPolicy for TargetPatternPolicy {
+    fn name(&self) -> &'static str {
+        "target-pattern"
+    }
+    
+    fn vote(&self, action: &Action, _simulation: &SimulationResult, invariants: &InvariantCheck) -> VoteDecision {
+        if !invariants.passed {
+            return VoteDecision::reject("Invariant check failed");
+        }
+        
+        if let Some(pattern) = self.deny.iter().find(|p| wildcard_match(p, &action.target)) {
+            return VoteDecision::reject(format!("Target '{}' matches deny pattern '{}'", action.target, pattern));
+        }
+        if !self.allow.is_empty() && !self.allow.iter().any(|p| wildcard_match(p, &action.target)) {
+            return VoteDecision::reject(format!("Target '{}' matches no allow pattern", action.target));
+        }
+        
+        VoteDecision::approve("Target permitted by pattern policy")
+    }
+}
+
+/// Requires a minimum provenance trust level, higher for critical actions
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProvenanceTrustPolicy {
+    pub min_trust: TrustLevel,
+    pub min_trust_critical: TrustLevel,
+}
+
+impl Default for ProvenanceTrustPolicy {
+    fn default() -> Self {
+        Self {
+            min_trust: TrustLevel::Verified,
+            min_trust_critical: TrustLevel::Trusted,
+        }
+    }
+}
+
+impl AgentPolicy for ProvenanceTrustPolicy {
+    fn name(&self) -> &'static str {
+        "provenance-trust"
+    }
+    
+    fn vote(&self, action: &Action, _simulation: &SimulationResult, invariants: &InvariantCheck) -> VoteDecision {
+        if !invariants.passed {
+            return VoteDecision::reject("Invariant check failed");
+        }
+        
+        let required = if action.action_type == ActionType::Critical {
+            self.min_trust_critical
+        } else {
+            self.min_trust
+        };
+        if action.provenance.trust_level < required {
+            return VoteDecision::reject(format!(
+                "Provenance {:?} below required {:?}",
+                action.provenance.trust_level, required
+            ));
+        }
+        
+        VoteDecision::approve(format!("Provenance {:?} meets {:?}", action.provenance.trust_level, required))
+    }
+}
+
+/// Match `text` against `pattern` with the same glob syntax as [`TargetPattern`]
+///
+/// Patterns that fail to parse only match themselves.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    TargetPattern::new(pattern).map_or(pattern == text, |p| p.matches(text))
+}
+
+/// Compiled target glob used by the allowlist, denylist and pattern policy
+///
+/// `*` matches any run of characters (including `/`), `?` one character,
+/// `[abc]`, `[a-z]` and `[!abc]` one character from (or outside) a class,
+/// and `\` escapes the next character. Strings without these are exact
+/// matches. Matching is case-sensitive.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TargetPattern {
+    source: String,
+    tokens: Vec<GlobToken>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum GlobToken {
+    Literal(char),
+    AnyRun,
+    AnyChar,
+    Class { negated: bool, ranges: Vec<(char, char)> },
+}
+
+impl GlobToken {
+    /// Whether this single-character token matches `c` (never true for `*`)
+    fn matches_one(&self, c: char) -> bool {
+        match self {
+            GlobToken::Literal(l) => *l == c,
+            GlobToken::AnyChar => true,
+            GlobToken::Class { negated, ranges } => {
+                ranges.iter().any(|(lo, hi)| (*lo..=*hi).contains(&c)) != *negated
+            }
+            GlobToken::AnyRun => false,
+        }
+    }
+}
+
+impl TargetPattern {
+    /// Compile `pattern`, rejecting empty patterns and malformed classes or escapes
+    pub fn new(pattern: &str) -> Result<Self, String> {
+        if pattern.is_empty() {
+            return Err("Pattern is empty".to_string());
+        }
+        
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars().peekable();
+        while let Some(c) = chars.next() {
+            let token = match c {
+                '*' => {
+                    // Consecutive stars are one run
+                    if tokens.last() == Some(&GlobToken::AnyRun) {
+                        continue;
+                    }
+                    GlobToken::AnyRun
+                }
+                '?' => GlobToken::AnyChar,
+                '\\' => match chars.next() {
+                    Some(escaped) => GlobToken::Literal(escaped),
+                    None => return Err(format!("Pattern '{}' ends with a dangling escape", pattern)),
+                },
+                '[' => {
+                    let negated = chars.next_if_eq(&'!').is_some();
+                    let mut ranges = Vec::new();
+                    loop {
+                        let lo = match chars.next() {
+                            Some(']') if !ranges.is_empty() => break,
+                            Some(']') => return Err(format!("Pattern '{}' has an empty character class", pattern)),
+                            Some(lo) => lo,
+                            None => return Err(format!("Pattern '{}' has an unclosed character class", pattern)),
+                        };
+                        let hi = if chars.next_if_eq(&'-').is_some() {
+                            match chars.next() {
+                                Some(']') | None => {
+                                    return Err(format!("Pattern '{}' has an unfinished range", pattern))
+                                }
+                                Some(hi) => hi,
+                            }
+                        } else {
+                            lo
+                        };
+                        if hi < lo {
+                            return Err(format!("Pattern '{}' has a reversed range {}-{}", pattern, lo, hi));
+                        }
+                        ranges.push((lo, hi));
+                    }
+                    GlobToken::Class { negated, ranges }
+                }
+                ']' => return Err(format!("Pattern '{}' has an unmatched ']'", pattern)),
+                c => GlobToken::Literal(c),
+            };
+            tokens.push(token);
+        }
+        
+        Ok(Self {
+            source: pattern.to_string(),
+            tokens,
+        })
+    }
+    
+    /// The pattern as written
+    pub fn as_str(&self) -> &str {
+        &self.source
+    }
+    
+    /// Whether `text` matches the whole pattern
+    pub fn matches(&self, text: &str) -> bool {
+        let text: Vec<char> = text.chars().collect();
+        let (mut t, mut p) = (0, 0);
+        // Last `*` seen and the text position it is currently absorbing up to
+        let mut star: Option<(usize, usize)> = None;
+        
+        while t < text.len() {
+            match self.tokens.get(p) {
+                Some(GlobToken::AnyRun) => {
+                    star = Some((p, t));
+                    p += 1;
+                }
+                Some(token) if token.matches_one(text[t]) => {
+                    p += 1;
+                    t += 1;
+                }
+                _ => match star {
+                    // Let the last `*` absorb one more character and retry
+                    Some((star_p, star_t)) => {
+                        star = Some((star_p, star_t + 1));
+                        p = star_p + 1;
+                        t = star_t + 1;
+                    }
+                    None => return false,
+                },
+            }
+        }
+        
+        self.tokens[p..].iter().all(|token| *token == GlobToken::AnyRun)
+    }
+}
+
+impl fmt::Display for TargetPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.source)
+    }
 }
 
 /// Agent roles in the swarm
@@ -63,7 +403,49 @@ pub struct Decision {
     pub quorum_met: bool,
     pub invariant_check: InvariantCheck,
     pub simulation_result: Option<SimulationResult>,
+    /// System headroom the consensus agents voted against
+    #[serde(default)]
+    pub system_snapshot: Option<SystemSnapshot>,
     pub c_zero: bool,
+    /// Where the decision stands; critical actions wait for a human
+    #[serde(default)]
+    pub status: DecisionStatus,
+    /// Token to present to `DSIF::approve` or `DSIF::reject`; only on the
+    /// decision returned while awaiting approval, never stored or audited
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approval_token: Option<String>,
+    /// Human who approved or rejected the decision
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub approver: Option<String>,
+}
+
+/// Lifecycle of a decision that passed consensus
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DecisionStatus {
+    /// The action was actuated
+    #[default]
+    Executed,
+    /// A critical action is held until a human approves or rejects it
+    AwaitingApproval,
+    /// A human rejected the action; it was not actuated
+    Rejected,
+    /// Nobody approved the action within the approval TTL
+    Expired,
+}
+
+/// How long a critical decision waits for human approval by default
+pub const DEFAULT_APPROVAL_TTL_SECS: i64 = 15 * 60;
+
+/// Default window over which identical audit entries are coalesced
+pub const DEFAULT_COALESCE_WINDOW_SECS: i64 = 60;
+
+/// A critical decision held for human approval
+#[derive(Debug, Clone)]
+struct PendingApproval {
+    decision: Decision,
+    /// SHA-256 of the approval token; the token itself is never kept
+    token_hash: String,
+    expires_at: chrono::DateTime<Utc>,
 }
 
 /// Action to be executed
@@ -115,10 +497,13 @@ pub struct SimulationResult {
     pub predicted_outcomes: Vec<String>,
     pub resource_usage: ResourceUsage,
     pub violations: Vec<String>,
+    /// Simulator that produced the result
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub simulator: Option<String>,
 }
 
 /// Resource usage prediction
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct ResourceUsage {
     pub memory_mb: f64,
     pub cpu_percent: f64,
@@ -126,6 +511,174 @@ pub struct ResourceUsage {
     pub network_bytes: u64,
 }
 
+impl SimulationResult {
+    /// An unsafe result for a simulation that could not run to completion
+    pub fn failed(reason: impl Into<String>) -> Self {
+        Self {
+            safe: false,
+            predicted_outcomes: Vec::new(),
+            resource_usage: ResourceUsage::default(),
+            violations: vec![reason.into()],
+            simulator: None,
+        }
+    }
+}
+
+/// Predicts what an action will do before it is actuated
+///
+/// Registered per action type and target prefix with
+/// [`DSIF::register_simulator`]; actions no simulator claims fall back to
+/// [`CostModelSimulator`]. A simulator that panics makes the action unsafe.
+pub trait Simulator: Send + Sync {
+    /// Short identifier recorded on the simulation result
+    fn name(&self) -> &str;
+    
+    fn simulate(&self, action: &Action) -> SimulationResult;
+}
+
+/// Verbs that mark an action as destructive when they appear in its target
+/// or string parameters
+const DESTRUCTIVE_VERBS: &[&str] = &[
+    "delete", "drop", "truncate", "shutdown", "destroy", "wipe", "purge", "erase", "kill",
+];
+
+/// Default cost model: usage scales with the size of the parameter payload
+///
+/// Each action type has a base cost; every KiB of (JSON-encoded) parameters
+/// adds `memory_mb_per_kib` and `latency_ms_per_kib` on top. Mutating actions
+/// also send the payload over the network. Destructive verbs make mutating
+/// actions unsafe and are only noted on reads.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct CostModelSimulator {
+    pub memory_mb_per_kib: f64,
+    pub latency_ms_per_kib: f64,
+    pub cpu_percent_per_kib: f64,
+}
+
+impl Default for CostModelSimulator {
+    fn default() -> Self {
+        Self {
+            memory_mb_per_kib: 0.25,
+            latency_ms_per_kib: 2.0,
+            cpu_percent_per_kib: 0.05,
+        }
+    }
+}
+
+impl CostModelSimulator {
+    /// Size of `action`'s parameters as JSON, in bytes
+    pub fn payload_bytes(action: &Action) -> u64 {
+        serde_json::to_vec(&action.parameters).map_or(0, |bytes| bytes.len() as u64)
+    }
+    
+    /// Destructive verbs found in `action`'s target and string parameters
+    pub fn destructive_verbs(action: &Action) -> Vec<&'static str> {
+        let mut texts = vec![action.target.to_lowercase()];
+        texts.extend(action.parameters.values().filter_map(|v| v.as_str()).map(str::to_lowercase));
+        
+        DESTRUCTIVE_VERBS
+            .iter()
+            .copied()
+            .filter(|verb| {
+                texts
+                    .iter()
+                    .any(|text| text.split(|c: char| !c.is_ascii_alphanumeric()).any(|word| word == *verb))
+            })
+            .collect()
+    }
+}
+
+impl Simulator for CostModelSimulator {
+    fn name(&self) -> &str {
+        "cost-model"
+    }
+    
+    fn simulate(&self, action: &Action) -> SimulationResult {
+        let payload = Self::payload_bytes(action);
+        let kib = payload as f64 / 1024.0;
+        
+        let (memory_mb, cpu_percent, latency_ms, outcome) = match action.action_type {
+            ActionType::Read => (10.0, 5.0, 50.0, "Read operation - no state change"),
+            ActionType::Write => (16.0, 10.0, 100.0, "Write operation will persist data"),
+            ActionType::Critical => (32.0, 20.0, 200.0, "Critical operation will modify system state"),
+            ActionType::Config => (12.0, 8.0, 80.0, "Configuration change will affect system behavior"),
+        };
+        let mutating = action.action_type != ActionType::Read;
+        
+        let mut predicted_outcomes = vec![outcome.to_string()];
+        let mut violations = Vec::new();
+        let verbs = Self::destructive_verbs(action);
+        if !verbs.is_empty() {
+            if mutating {
+                let kind = if action.action_type == ActionType::Critical { "Critical" } else { "Mutating" };
+                violations.push(format!("{} destructive operation detected ({})", kind, verbs.join(", ")));
+            } else {
+                predicted_outcomes.push(format!("Target mentions destructive verbs ({}) but is read only", verbs.join(", ")));
+            }
+        }
+        
+        SimulationResult {
+            safe: violations.is_empty(),
+            predicted_outcomes,
+            resource_usage: ResourceUsage {
+                memory_mb: memory_mb + kib * self.memory_mb_per_kib,
+                cpu_percent: (cpu_percent + kib * self.cpu_percent_per_kib).min(100.0),
+                latency_ms: latency_ms + kib * self.latency_ms_per_kib,
+                network_bytes: 1024 + if mutating { payload } else { 0 },
+            },
+            violations,
+            simulator: None,
+        }
+    }
+}
+
+/// A [`Simulator`] backed by a caller's dry-run callback
+///
+/// The callback returning `Err` makes the action unsafe, as does a panic.
+pub struct DryRunSimulator<F> {
+    name: String,
+    dry_run: F,
+}
+
+impl<F> DryRunSimulator<F>
+where
+    F: Fn(&Action) -> Result<SimulationResult, String> + Send + Sync,
+{
+    pub fn new(name: impl Into<String>, dry_run: F) -> Self {
+        Self { name: name.into(), dry_run }
+    }
+}
+
+impl<F> Simulator for DryRunSimulator<F>
+where
+    F: Fn(&Action) -> Result<SimulationResult, String> + Send + Sync,
+{
+    fn name(&self) -> &str {
+        &self.name
+    }
+    
+    fn simulate(&self, action: &Action) -> SimulationResult {
+        (self.dry_run)(action)
+            .unwrap_or_else(|e| SimulationResult::failed(format!("Dry run '{}' failed: {}", self.name, e)))
+    }
+}
+
+/// Which actions a registered simulator handles
+#[derive(Clone)]
+struct SimulatorRoute {
+    /// Any action type if unset
+    action_type: Option<ActionType>,
+    target_prefix: String,
+    simulator: Arc<dyn Simulator>,
+}
+
+impl SimulatorRoute {
+    fn matches(&self, action: &Action) -> bool {
+        self.action_type.as_ref().map_or(true, |t| *t == action.action_type)
+            && action.target.starts_with(&self.target_prefix)
+    }
+}
+
 /// Input provenance information
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Provenance {
@@ -149,16 +702,88 @@ pub enum TrustLevel {
     Trusted,
 }
 
+/// Resource limits consensus agents apply to heavy actions
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct ResourcePolicy {
+    /// Reject when predicted memory exceeds this fraction of free memory
+    pub max_free_memory_fraction: f64,
+}
+
+impl Default for ResourcePolicy {
+    fn default() -> Self {
+        Self { max_free_memory_fraction: 0.5 }
+    }
+}
+
+/// How agent trust reacts to verified outcomes
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct TrustPolicy {
+    /// Fraction of trust lost for a wrong vote
+    pub decay_rate: f64,
+    /// Trust gained for a correct vote (capped at 1.0)
+    pub boost: f64,
+    /// Agents whose trust falls below this are quarantined
+    pub quarantine_threshold: f64,
+    /// Trust restored by `reinstate_agent`
+    pub reinstated_trust: f64,
+}
+
+impl Default for TrustPolicy {
+    fn default() -> Self {
+        Self {
+            decay_rate: 0.2,
+            boost: 0.05,
+            quarantine_threshold: 0.5,
+            reinstated_trust: 0.75,
+        }
+    }
+}
+
+/// Verified result of an action the swarm voted on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ActionOutcome {
+    /// The action turned out safe; approving was correct
+    Safe,
+    /// The action turned out harmful; rejecting was correct
+    Unsafe,
+}
+
+/// Trust change applied to one agent by `record_outcome`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct TrustUpdate {
+    pub agent_id: String,
+    pub correct: bool,
+    pub trust_before: f64,
+    pub trust_after: f64,
+    pub state: AgentState,
+}
+
 /// DSIF Framework - Main orchestrator
 pub struct DSIF {
     agents: Vec<Agent>,
     quorum_threshold: f64,
     audit_trail: Vec<AuditEntry>,
     invariants: Vec<Invariant>,
-    allowlist: Vec<String>,
-    denylist: Vec<String>,
+    allowlist: Vec<TargetPattern>,
+    denylist: Vec<TargetPattern>,
     /// Human approver attestations collected for decisions
     human_approvals: Vec<Attestation>,
+    /// Backing store for the audit trail (in-memory only if unset)
+    store: Option<CozoStore>,
+    /// System headroom source for resource-aware voting (votes ignore headroom if unset)
+    snapshot_source: Option<Arc<dyn SnapshotSource>>,
+    resource_policy: ResourcePolicy,
+    trust_policy: TrustPolicy,
+    /// Votes awaiting a verified outcome, by decision id
+    pending_votes: HashMap<String, Vec<Vote>>,
+    /// Registered simulators, in registration order
+    simulators: Vec<SimulatorRoute>,
+    /// Critical decisions awaiting a human, by decision id (in memory only)
+    pending_approvals: HashMap<String, PendingApproval>,
+    /// How long a pending approval stays open
+    approval_ttl: chrono::Duration,
+    /// Window for coalescing repeated audit entries (off if unset)
+    coalesce_window: Option<chrono::Duration>,
 }
 
 /// Invariant - Safety property that must be preserved
@@ -183,6 +808,84 @@ pub struct AuditEntry {
     pub rationale: String,
     pub hash: String,
     pub previous_hash: Option<String>,
+    /// Identical entries coalesced into this one, itself included
+    #[serde(default = "single", skip_serializing_if = "is_single")]
+    pub repeat_count: u64,
+    /// Timestamp of the last coalesced repeat
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+}
+
+fn single() -> u64 {
+    1
+}
+
+fn is_single(count: &u64) -> bool {
+    *count == 1
+}
+
+/// Format tag written at the top of every exported trail
+pub const TRAIL_FORMAT: &str = "axiom-s1-dsif-trail/1";
+
+/// Audit trail export that can be checked without the DSIF that wrote it
+///
+/// Each entry carries the SHA-256 of its JSON; the Merkle root is taken
+/// over those digests in trail order, so editing any field of any entry
+/// shows up both at that entry and in the root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedTrail {
+    pub format: String,
+    pub generated_at: String,
+    pub entries: Vec<ExportedEntry>,
+    pub merkle_root: String,
+    /// Hash of the last entry, `None` for an empty trail
+    pub chain_head: Option<String>,
+}
+
+/// One audit entry in an [`ExportedTrail`]
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedEntry {
+    /// SHA-256 of `entry` serialized as JSON
+    pub digest: String,
+    pub entry: AuditEntry,
+}
+
+impl ExportedEntry {
+    fn new(entry: &AuditEntry) -> Self {
+        Self {
+            digest: entry_digest(entry),
+            entry: entry.clone(),
+        }
+    }
+}
+
+/// Why an exported trail failed verification
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum TrailError {
+    #[error("Unsupported trail format: {0}")]
+    UnsupportedFormat(String),
+    #[error("Entry {index} does not match its digest")]
+    EntryTampered { index: usize },
+    #[error("Entry {index} does not chain onto the entry before it")]
+    BrokenChain { index: usize },
+    #[error("Merkle root does not match the entry digests")]
+    MerkleRootMismatch,
+    #[error("Chain head does not match the last entry")]
+    ChainHeadMismatch,
+}
+
+fn entry_digest(entry: &AuditEntry) -> String {
+    // Plain structs of strings and enums always serialize
+    sha256_hex(&serde_json::to_string(entry).unwrap_or_default())
+}
+
+/// Chain hash of an entry: the digest of every field but `hash` itself, so
+/// it covers the entry's content and, through `previous_hash`, its predecessor
+fn entry_hash(entry: &AuditEntry) -> String {
+    entry_digest(&AuditEntry {
+        hash: String::new(),
+        ..entry.clone()
+    })
 }
 
 /// Pipeline phases
@@ -207,6 +910,15 @@ impl DSIF {
             allowlist: Vec::new(),
             denylist: Vec::new(),
             human_approvals: Vec::new(),
+            store: None,
+            snapshot_source: None,
+            resource_policy: ResourcePolicy::default(),
+            trust_policy: TrustPolicy::default(),
+            pending_votes: HashMap::new(),
+            simulators: Vec::new(),
+            pending_approvals: HashMap::new(),
+            approval_ttl: chrono::Duration::seconds(DEFAULT_APPROVAL_TTL_SECS),
+            coalesce_window: None,
         };
         
         // Initialize default agents
@@ -218,51 +930,242 @@ impl DSIF {
         dsif
     }
     
-    /// Initialize default agent swarm
-    fn initialize_agents(&mut self) {
-        let roles = vec![
-            AgentRole::Validator,
-            AgentRole::Simulator,
-            AgentRole::Consensus,
-            AgentRole::Consensus,
-            AgentRole::Consensus,
-            AgentRole::Executor,
-            AgentRole::Auditor,
-        ];
-        
-        for (i, role) in roles.into_iter().enumerate() {
-            self.agents.push(Agent {
-                id: format!("agent-{}", i),
-                role,
-                state: AgentState::Active,
-                trust_score: 1.0,
-                last_decision: None,
-            });
-        }
+    /// Persist the audit trail to `store`, continuing the trail already stored there
+    pub fn with_store(mut self, store: &CozoStore) -> Result<Self, CozoError> {
+        self.store = Some(store.clone());
+        let loaded = self.load_audit_trail()?;
+        tracing::info!("DSIF: Loaded {} audit entries from store", loaded);
+        Ok(self)
     }
     
-    /// Initialize default safety invariants
-    fn initialize_invariants(&mut self) {
-        self.invariants.push(Invariant {
-            id: "INV-001".to_string(),
-            name: "Zero Contradiction".to_string(),
-            property: "C = 0".to_string(),
-            domain: "all".to_string(),
+    /// Let consensus agents weigh system headroom from `source`
+    pub fn with_snapshot_source(mut self, source: Arc<dyn SnapshotSource>) -> Self {
+        self.snapshot_source = Some(source);
+        self
+    }
+    
+    /// Override the resource limits applied during consensus
+    pub fn with_resource_policy(mut self, policy: ResourcePolicy) -> Self {
+        self.resource_policy = policy;
+        self
+    }
+    
+    /// Override how agent trust reacts to outcomes
+    pub fn with_trust_policy(mut self, policy: TrustPolicy) -> Self {
+        self.trust_policy = policy;
+        self
+    }
+    
+    /// Override how long critical decisions wait for human approval
+    pub fn with_approval_ttl(mut self, ttl: chrono::Duration) -> Self {
+        self.approval_ttl = ttl;
+        self
+    }
+    
+    /// Coalesce repeated audit entries
+    ///
+    /// When enabled, an entry with the same phase, action and rationale as
+    /// the last entry of the trail, written within `window` of that entry's
+    /// timestamp, is not appended: the last entry's `repeat_count` and
+    /// `last_seen` are updated and its hash recomputed over them instead.
+    /// A retry flood thus costs one entry per window, and since only the
+    /// head of the chain is ever rewritten the chain stays intact. The
+    /// coalesced entry keeps the decision and agent of its first occurrence.
+    pub fn set_coalescing(&mut self, window: chrono::Duration, enabled: bool) {
+        self.coalesce_window = enabled.then_some(window);
+    }
+    
+    /// Simulate actions of `action_type` (any type if `None`) whose target
+    /// starts with `target_prefix` with `simulator`
+    ///
+    /// The longest matching prefix wins, then a type-specific route over an
+    /// untyped one, then the latest registration. Unmatched actions use the
+    /// default [`CostModelSimulator`].
+    pub fn register_simulator(
+        &mut self,
+        action_type: Option<ActionType>,
+        target_prefix: impl Into<String>,
+        simulator: Arc<dyn Simulator>,
+    ) {
+        self.simulators.push(SimulatorRoute {
+            action_type,
+            target_prefix: target_prefix.into(),
+            simulator,
         });
+    }
+    
+    /// Replace the in-memory trail with the stored one, returning the entry count
+    pub fn load_audit_trail(&mut self) -> Result<usize, CozoError> {
+        let Some(store) = &self.store else {
+            return Ok(self.audit_trail.len());
+        };
         
-        self.invariants.push(Invariant {
-            id: "INV-002".to_string(),
-            name: "Deterministic Output".to_string(),
-            property: "∀(input, state): output = f(input, state)".to_string(),
-            domain: "computation".to_string(),
-        });
+        self.audit_trail = store
+            .load_dsif_records("entry")?
+            .into_iter()
+            .map(serde_json::from_value)
+            .collect::<Result<_, _>>()?;
         
-        self.invariants.push(Invariant {
-            id: "INV-003".to_string(),
-            name: "Causal Closure".to_string(),
-            property: "Every effect must have a traceable cause".to_string(),
-            domain: "causality".to_string(),
-        });
+        Ok(self.audit_trail.len())
+    }
+    
+    /// Write an audit entry to the store (no-op without a store)
+    pub fn persist_entry(&self, entry: &AuditEntry, sequence: usize) -> Result<(), CozoError> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        
+        store.store_dsif_record(
+            "entry",
+            &entry.id,
+            sequence as i64,
+            &entry.hash,
+            entry.previous_hash.as_deref(),
+            &serde_json::to_value(entry)?,
+        )
+    }
+    
+    /// Write a decision to the store, keyed to the audit entry that sealed it
+    pub fn persist_decision(&self, decision: &Decision, sequence: usize) -> Result<(), CozoError> {
+        let Some(store) = &self.store else {
+            return Ok(());
+        };
+        
+        let hash = self.hash(&serde_json::to_string(decision)?);
+        store.store_dsif_record(
+            "decision",
+            &decision.id,
+            sequence as i64,
+            &hash,
+            None,
+            &serde_json::to_value(decision)?,
+        )
+    }
+    
+    /// Verify the hash chain, returning the index of the first entry that
+    /// does not chain onto its predecessor or whose hash no longer matches it
+    pub fn verify_trail(&self) -> Result<(), usize> {
+        let mut previous: Option<&str> = None;
+        
+        for (index, entry) in self.audit_trail.iter().enumerate() {
+            if entry.previous_hash.as_deref() != previous || entry.hash != entry_hash(entry) {
+                return Err(index);
+            }
+            previous = Some(&entry.hash);
+        }
+        
+        Ok(())
+    }
+    
+    /// Export the whole audit trail with a Merkle root over its entries
+    pub fn export_trail(&self) -> ExportedTrail {
+        let entries: Vec<ExportedEntry> = self.audit_trail.iter().map(ExportedEntry::new).collect();
+        let digests: Vec<String> = entries.iter().map(|e| e.digest.clone()).collect();
+        
+        ExportedTrail {
+            format: TRAIL_FORMAT.to_string(),
+            generated_at: Utc::now().to_rfc3339(),
+            merkle_root: session_bundle::merkle_root(&digests),
+            chain_head: self.audit_trail.last().map(|e| e.hash.clone()),
+            entries,
+        }
+    }
+    
+    /// Check an exported trail's entry digests, hash chain, Merkle root and
+    /// chain head, stopping at the first entry that fails
+    pub fn verify_exported_trail(doc: &ExportedTrail) -> Result<(), TrailError> {
+        if doc.format != TRAIL_FORMAT {
+            return Err(TrailError::UnsupportedFormat(doc.format.clone()));
+        }
+        
+        let mut previous: Option<&str> = None;
+        for (index, item) in doc.entries.iter().enumerate() {
+            if entry_digest(&item.entry) != item.digest {
+                return Err(TrailError::EntryTampered { index });
+            }
+            if item.entry.previous_hash.as_deref() != previous || item.entry.hash.is_empty() {
+                return Err(TrailError::BrokenChain { index });
+            }
+            previous = Some(&item.entry.hash);
+        }
+        
+        let digests: Vec<String> = doc.entries.iter().map(|e| e.digest.clone()).collect();
+        if session_bundle::merkle_root(&digests) != doc.merkle_root {
+            return Err(TrailError::MerkleRootMismatch);
+        }
+        if doc.chain_head.as_deref() != previous {
+            return Err(TrailError::ChainHeadMismatch);
+        }
+        Ok(())
+    }
+    
+    /// Initialize default agent swarm
+    ///
+    /// Each consensus agent runs a different built-in policy.
+    fn initialize_agents(&mut self) {
+        let resource: Arc<dyn AgentPolicy> = Arc::new(ResourceBudgetPolicy::default());
+        let target: Arc<dyn AgentPolicy> = Arc::new(TargetPatternPolicy::sensitive_targets());
+        let provenance: Arc<dyn AgentPolicy> = Arc::new(ProvenanceTrustPolicy::default());
+        
+        let roles = vec![
+            (AgentRole::Validator, None),
+            (AgentRole::Simulator, None),
+            (AgentRole::Consensus, Some(resource)),
+            (AgentRole::Consensus, Some(target)),
+            (AgentRole::Consensus, Some(provenance)),
+            (AgentRole::Executor, None),
+            (AgentRole::Auditor, None),
+        ];
+        
+        for (i, (role, policy)) in roles.into_iter().enumerate() {
+            self.agents.push(Agent {
+                id: format!("agent-{}", i),
+                role,
+                state: AgentState::Active,
+                trust_score: 1.0,
+                last_decision: None,
+                policy,
+            });
+        }
+    }
+    
+    /// Replace the vote policy of a consensus agent
+    pub fn set_agent_policy(&mut self, agent_id: &str, policy: Arc<dyn AgentPolicy>) -> Result<(), String> {
+        let agent = self
+            .agents
+            .iter_mut()
+            .find(|a| a.id == agent_id)
+            .ok_or_else(|| format!("Unknown agent: {}", agent_id))?;
+        if agent.role != AgentRole::Consensus {
+            return Err(format!("Agent {} is not a consensus agent", agent_id));
+        }
+        
+        agent.policy = Some(policy);
+        Ok(())
+    }
+    
+    /// Initialize default safety invariants
+    fn initialize_invariants(&mut self) {
+        self.invariants.push(Invariant {
+            id: "INV-001".to_string(),
+            name: "Zero Contradiction".to_string(),
+            property: "C = 0".to_string(),
+            domain: "all".to_string(),
+        });
+        
+        self.invariants.push(Invariant {
+            id: "INV-002".to_string(),
+            name: "Deterministic Output".to_string(),
+            property: "∀(input, state): output = f(input, state)".to_string(),
+            domain: "computation".to_string(),
+        });
+        
+        self.invariants.push(Invariant {
+            id: "INV-003".to_string(),
+            name: "Causal Closure".to_string(),
+            property: "Every effect must have a traceable cause".to_string(),
+            domain: "causality".to_string(),
+        });
         
         self.invariants.push(Invariant {
             id: "INV-004".to_string(),
@@ -280,6 +1183,77 @@ impl DSIF {
         target: &str,
         parameters: HashMap<String, serde_json::Value>,
     ) -> Result<Decision, String> {
+        let (decision_id, action) = self.admit(input, action_type, target, parameters)?;
+        let view = self.view();
+        
+        let simulation_result = view.simulate(&action).await;
+        let invariant_check = self.review_simulation(&action, &decision_id, &simulation_result)?;
+        
+        // One snapshot per decision keeps every vote looking at the same numbers
+        let system_snapshot = view.system_snapshot();
+        let votes = self
+            .consensus_gating(&action, &decision_id, &invariant_check, &simulation_result, system_snapshot.as_ref())
+            .await?;
+        
+        self.conclude(action, decision_id, votes, invariant_check, simulation_result, system_snapshot)
+    }
+    
+    /// Execute the full DSIF pipeline on a shared instance
+    ///
+    /// The write guard is held only while audit entries and votes are
+    /// recorded; simulation and consensus run on a `PipelineView` taken under
+    /// a read guard, so audit-trail reads and other pipelines proceed
+    /// meanwhile. Each recording step appends under a single guard, so the
+    /// hash chain stays intact however pipelines interleave.
+    pub async fn execute_pipeline_shared(
+        dsif: &RwLock<Self>,
+        input: &str,
+        action_type: ActionType,
+        target: &str,
+        parameters: HashMap<String, serde_json::Value>,
+    ) -> Result<Decision, String> {
+        let (decision_id, action) = dsif.write().await.admit(input, action_type, target, parameters)?;
+        let view = dsif.read().await.view();
+        
+        let simulation_result = view.simulate(&action).await;
+        let invariant_check = dsif
+            .write()
+            .await
+            .review_simulation(&action, &decision_id, &simulation_result)?;
+        
+        let system_snapshot = view.system_snapshot();
+        let votes = view
+            .collect_votes(&action, &decision_id, &invariant_check, &simulation_result, system_snapshot.as_ref())
+            .await;
+        
+        let mut dsif = dsif.write().await;
+        dsif.record_votes(&decision_id, &votes)?;
+        dsif.conclude(action, decision_id, votes, invariant_check, simulation_result, system_snapshot)
+    }
+    
+    /// Copy of the state the simulation and consensus phases read
+    pub fn view(&self) -> PipelineView {
+        PipelineView {
+            consensus_agents: self
+                .agents
+                .iter()
+                .filter(|a| a.role == AgentRole::Consensus && a.state == AgentState::Active)
+                .cloned()
+                .collect(),
+            resource_policy: self.resource_policy,
+            snapshot_source: self.snapshot_source.clone(),
+            simulators: self.simulators.clone(),
+        }
+    }
+    
+    /// Phases 1 and 2: admit the input and build an action that passes policy
+    fn admit(
+        &mut self,
+        input: &str,
+        action_type: ActionType,
+        target: &str,
+        parameters: HashMap<String, serde_json::Value>,
+    ) -> Result<(String, Action), String> {
         let decision_id = Uuid::new_v4().to_string();
         
         // Phase 1: Input Hygiene
@@ -288,9 +1262,9 @@ impl DSIF {
         // Phase 2: Policy Validation
         let action = Action {
             id: Uuid::new_v4().to_string(),
-            action_type: action_type.clone(),
+            action_type,
             target: target.to_string(),
-            parameters: parameters.clone(),
+            parameters,
             provenance,
         };
         
@@ -299,8 +1273,23 @@ impl DSIF {
             return Err(format!("Policy validation failed: {:?}", policy_result.violations));
         }
         
-        // Phase 3: Simulation-before-actuation
-        let simulation_result = self.simulate_action(&action, &decision_id).await?;
+        Ok((decision_id, action))
+    }
+    
+    /// Phase 3 record: audit the simulation and check invariants against it
+    fn review_simulation(
+        &mut self,
+        action: &Action,
+        decision_id: &str,
+        simulation_result: &SimulationResult,
+    ) -> Result<InvariantCheck, String> {
+        self.audit(
+            PipelinePhase::Simulation,
+            decision_id,
+            None,
+            "Simulation",
+            "Simulating action before actuation",
+        )?;
         if !simulation_result.safe {
             return Err(format!(
                 "Simulation failed: {:?}",
@@ -308,8 +1297,7 @@ impl DSIF {
             ));
         }
         
-        // Phase 4: Consensus Gating
-        let invariant_check = self.check_invariants(&action, &simulation_result)?;
+        let invariant_check = self.check_invariants(action, simulation_result)?;
         if !invariant_check.passed {
             return Err(format!(
                 "Invariant violation: {:?}",
@@ -317,30 +1305,49 @@ impl DSIF {
             ));
         }
         
-        let votes = self.consensus_gating(&action, &decision_id, &invariant_check).await?;
+        Ok(invariant_check)
+    }
+    
+    /// Phases 5 and 6: gate on quorum and approval, actuate and seal the decision
+    ///
+    /// Critical actions are not actuated here: the decision is sealed as
+    /// `AwaitingApproval` and returned with a one-time approval token for
+    /// [`DSIF::approve`] or [`DSIF::reject`].
+    fn conclude(
+        &mut self,
+        action: Action,
+        decision_id: String,
+        votes: Vec<Vote>,
+        invariant_check: InvariantCheck,
+        simulation_result: SimulationResult,
+        system_snapshot: Option<SystemSnapshot>,
+    ) -> Result<Decision, String> {
         let quorum_met = self.check_quorum(&votes);
         
         if !quorum_met {
             return Err("Quorum not met - action blocked".to_string());
         }
         
+        let critical = action.action_type == ActionType::Critical;
+        
         // Phase 5: Controlled Actuation (requires human approval for non-read)
         // Reads do not change state and are allowed to proceed without human attestation.
-        if action_type != ActionType::Read {
-            // Require an explicit human approver attestation for any Write/Config/Critical action
+        // Critical actions get their approval through the handshake below instead.
+        if action.action_type != ActionType::Read && !critical {
+            // Require an explicit human approver attestation for any Write/Config action
             if !self.has_human_approval(&decision_id) {
                 return Err("Human approver attestation required before actuation".to_string());
             }
         }
 
         // If we reach here and quorum is met, execute controlled actuation
-        if quorum_met {
+        if quorum_met && !critical {
             self.controlled_actuation(&action, &decision_id)?;
         }
         
         // Phase 6: Immutable Audit
-        let decision = Decision {
-            id: decision_id.clone(),
+        let mut decision = Decision {
+            id: decision_id,
             timestamp: Utc::now().to_rfc3339(),
             action,
             rationale: format!("Pipeline completed successfully with quorum: {}", quorum_met),
@@ -348,14 +1355,158 @@ impl DSIF {
             quorum_met,
             invariant_check,
             simulation_result: Some(simulation_result),
+            system_snapshot,
             c_zero: true,
+            status: DecisionStatus::Executed,
+            approval_token: None,
+            approver: None,
         };
+        if critical {
+            decision.status = DecisionStatus::AwaitingApproval;
+            decision.rationale = "Quorum met; critical action awaiting human approval".to_string();
+        }
+        
+        self.seal(&decision)?;
+        
+        if critical {
+            let token = Uuid::new_v4().simple().to_string();
+            let expires_at = Utc::now() + self.approval_ttl;
+            self.pending_approvals.insert(
+                decision.id.clone(),
+                PendingApproval {
+                    decision: decision.clone(),
+                    token_hash: self.hash(&token),
+                    expires_at,
+                },
+            );
+            decision.approval_token = Some(token);
+        }
+        
+        Ok(decision)
+    }
+    
+    /// Phase 6 record: audit the decision and store it under its id
+    fn seal(&mut self, decision: &Decision) -> Result<(), String> {
+        self.immutable_audit(decision, PipelinePhase::ImmutableAudit)?;
+        self.persist_decision(decision, self.audit_trail.len() - 1)
+            .map_err(|e| format!("Failed to persist decision: {}", e))
+    }
+    
+    /// Approve a critical decision awaiting approval and actuate it
+    ///
+    /// `token` is the one returned with the `AwaitingApproval` decision.
+    /// A wrong token is audited and leaves the decision pending; an expired,
+    /// unknown or already settled decision is refused.
+    pub fn approve(&mut self, decision_id: &str, token: &str, approver: &str) -> Result<Decision, String> {
+        let mut decision = self.take_pending(decision_id, token, approver)?;
+        
+        self.audit_result(
+            PipelinePhase::ControlledActuation,
+            Some(decision_id),
+            None,
+            "Human approval",
+            "APPROVED",
+            &format!("Approved by {}", approver),
+        )?;
+        self.controlled_actuation(&decision.action, decision_id)?;
+        
+        decision.status = DecisionStatus::Executed;
+        decision.approver = Some(approver.to_string());
+        decision.timestamp = Utc::now().to_rfc3339();
+        decision.rationale = format!("Critical action approved by {} and executed", approver);
+        self.seal(&decision)?;
+        Ok(decision)
+    }
+    
+    /// Reject a critical decision awaiting approval; the action is not actuated
+    pub fn reject(&mut self, decision_id: &str, token: &str, approver: &str, reason: &str) -> Result<Decision, String> {
+        let mut decision = self.take_pending(decision_id, token, approver)?;
         
-        self.immutable_audit(&decision, PipelinePhase::ImmutableAudit)?;
+        self.audit_result(
+            PipelinePhase::ControlledActuation,
+            Some(decision_id),
+            None,
+            "Human rejection",
+            "REJECTED",
+            &format!("Rejected by {}: {}", approver, reason),
+        )?;
         
+        decision.status = DecisionStatus::Rejected;
+        decision.approver = Some(approver.to_string());
+        decision.timestamp = Utc::now().to_rfc3339();
+        decision.rationale = format!("Critical action rejected by {}: {}", approver, reason);
+        self.seal(&decision)?;
         Ok(decision)
     }
     
+    /// Decisions still awaiting approval (expired ones are settled first)
+    pub fn pending_approvals(&mut self) -> Result<Vec<Decision>, String> {
+        self.expire_approvals()?;
+        let mut pending: Vec<Decision> = self.pending_approvals.values().map(|p| p.decision.clone()).collect();
+        pending.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
+        Ok(pending)
+    }
+    
+    /// Settle every pending approval past its TTL as `Expired`, returning their ids
+    pub fn expire_approvals(&mut self) -> Result<Vec<String>, String> {
+        let now = Utc::now();
+        let mut expired: Vec<String> = self
+            .pending_approvals
+            .iter()
+            .filter(|(_, p)| now >= p.expires_at)
+            .map(|(id, _)| id.clone())
+            .collect();
+        expired.sort();
+        
+        for id in &expired {
+            let Some(pending) = self.pending_approvals.remove(id) else {
+                continue;
+            };
+            self.audit_result(
+                PipelinePhase::ControlledActuation,
+                Some(id),
+                None,
+                "Approval expired",
+                "EXPIRED",
+                &format!("No approval before {}", pending.expires_at.to_rfc3339()),
+            )?;
+            let mut decision = pending.decision;
+            decision.status = DecisionStatus::Expired;
+            decision.timestamp = now.to_rfc3339();
+            decision.rationale = "Critical action expired awaiting human approval".to_string();
+            self.seal(&decision)?;
+        }
+        
+        Ok(expired)
+    }
+    
+    /// Remove `decision_id` from the pending approvals if `token` opens it
+    fn take_pending(&mut self, decision_id: &str, token: &str, approver: &str) -> Result<Decision, String> {
+        if approver.trim().is_empty() {
+            return Err("Approver identity required".to_string());
+        }
+        self.expire_approvals()?;
+        
+        let pending = self
+            .pending_approvals
+            .remove(decision_id)
+            .ok_or_else(|| format!("No decision awaiting approval: {}", decision_id))?;
+        if pending.token_hash != self.hash(token) {
+            self.pending_approvals.insert(decision_id.to_string(), pending);
+            self.audit_result(
+                PipelinePhase::ControlledActuation,
+                Some(decision_id),
+                None,
+                "Approval refused",
+                "INVALID_TOKEN",
+                &format!("{} presented an invalid approval token", approver),
+            )?;
+            return Err("Invalid approval token".to_string());
+        }
+        
+        Ok(pending.decision)
+    }
+    
     /// Phase 1: Input Hygiene
     fn input_hygiene(&mut self, input: &str, decision_id: &str) -> Result<Provenance, String> {
         self.audit(
@@ -429,14 +1580,9 @@ impl DSIF {
         
         let mut violations = Vec::new();
         
-        // Check allowlist
-        if !self.allowlist.is_empty() && !self.allowlist.contains(&action.target) {
-            violations.push("Target not in allowlist".to_string());
-        }
-        
-        // Check denylist
-        if self.denylist.contains(&action.target) {
-            violations.push("Target in denylist".to_string());
+        // Check denylist, then allowlist
+        if let Some(violation) = self.target_violation(&action.target) {
+            violations.push(violation);
         }
         
         // Check action type permissions
@@ -460,69 +1606,25 @@ impl DSIF {
         })
     }
     
-    /// Phase 3: Simulation-before-actuation
-    async fn simulate_action(
-        &mut self,
-        action: &Action,
-        decision_id: &str,
-    ) -> Result<SimulationResult, String> {
-        self.audit(
-            PipelinePhase::Simulation,
-            decision_id,
-            None,
-            "Simulation",
-            "Simulating action before actuation",
-        )?;
-        
-        // Simulate resource usage
-        let resource_usage = ResourceUsage {
-            memory_mb: 10.0,
-            cpu_percent: 5.0,
-            latency_ms: 50.0,
-            network_bytes: 1024,
-        };
-        
-        // Check for predicted violations
-        let mut violations = Vec::new();
-        let mut predicted_outcomes = Vec::new();
-        
-        // Simulate based on action type
-        match action.action_type {
-            ActionType::Critical => {
-                predicted_outcomes.push("Critical operation will modify system state".to_string());
-                // Check if critical operations are safe
-                if action.target.contains("shutdown") || action.target.contains("delete") {
-                    violations.push("Critical destructive operation detected".to_string());
-                }
-            }
-            ActionType::Write => {
-                predicted_outcomes.push("Write operation will persist data".to_string());
-            }
-            ActionType::Read => {
-                predicted_outcomes.push("Read operation - no state change".to_string());
-            }
-            ActionType::Config => {
-                predicted_outcomes.push("Configuration change will affect system behavior".to_string());
-            }
-        }
-        
-        let safe = violations.is_empty();
-        
-        Ok(SimulationResult {
-            safe,
-            predicted_outcomes,
-            resource_usage,
-            violations,
-        })
-    }
-    
     /// Phase 4: Consensus Gating
     async fn consensus_gating(
         &mut self,
         action: &Action,
         decision_id: &str,
         invariant_check: &InvariantCheck,
+        simulation: &SimulationResult,
+        snapshot: Option<&SystemSnapshot>,
     ) -> Result<Vec<Vote>, String> {
+        let votes = self
+            .view()
+            .collect_votes(action, decision_id, invariant_check, simulation, snapshot)
+            .await;
+        self.record_votes(decision_id, &votes)?;
+        Ok(votes)
+    }
+    
+    /// Phase 4 record: audit the vote and keep it for `record_outcome`
+    fn record_votes(&mut self, decision_id: &str, votes: &[Vote]) -> Result<(), String> {
         self.audit(
             PipelinePhase::ConsensusGating,
             decision_id,
@@ -530,38 +1632,105 @@ impl DSIF {
             "Consensus gating",
             "Collecting votes from consensus agents",
         )?;
+        self.pending_votes.insert(decision_id.to_string(), votes.to_vec());
+        Ok(())
+    }
+    
+    /// Score each agent's vote on `decision_id` against the verified outcome
+    ///
+    /// Wrong votes decay trust by `decay_rate`, correct ones raise it by
+    /// `boost`. Agents that fall below `quarantine_threshold` are quarantined
+    /// and no longer vote.
+    pub fn record_outcome(
+        &mut self,
+        decision_id: &str,
+        actual_outcome: ActionOutcome,
+    ) -> Result<Vec<TrustUpdate>, String> {
+        let votes = self
+            .pending_votes
+            .remove(decision_id)
+            .ok_or_else(|| format!("No votes recorded for decision {}", decision_id))?;
+        let policy = self.trust_policy;
+        let should_approve = actual_outcome == ActionOutcome::Safe;
         
-        let mut votes = Vec::new();
-        
-        // Get consensus agents
-        let consensus_agents: Vec<_> = self
-            .agents
-            .iter()
-            .filter(|a| a.role == AgentRole::Consensus && a.state == AgentState::Active)
-            .collect();
-        
-        // Each agent votes
-        for agent in consensus_agents {
-            let approve = invariant_check.passed && agent.trust_score > 0.5;
-            let rationale = if approve {
-                "Invariants passed, action safe".to_string()
-            } else {
-                format!("Invariant check failed or low trust score: {}", agent.trust_score)
+        let mut updates = Vec::new();
+        for vote in &votes {
+            let Some(agent) = self.agents.iter_mut().find(|a| a.id == vote.agent_id) else {
+                continue;
             };
             
-            let vote = Vote {
-                agent_id: agent.id.clone(),
-                decision_id: decision_id.to_string(),
-                approve,
-                rationale,
-                timestamp: Utc::now().to_rfc3339(),
-                signature: self.sign_vote(&agent.id, decision_id, approve),
+            let correct = vote.approve == should_approve;
+            let trust_before = agent.trust_score;
+            agent.trust_score = if correct {
+                (trust_before + policy.boost).min(1.0)
+            } else {
+                trust_before * (1.0 - policy.decay_rate)
             };
+            if agent.state == AgentState::Active && agent.trust_score < policy.quarantine_threshold {
+                agent.state = AgentState::Quarantined;
+            }
             
-            votes.push(vote);
+            updates.push(TrustUpdate {
+                agent_id: agent.id.clone(),
+                correct,
+                trust_before,
+                trust_after: agent.trust_score,
+                state: agent.state,
+            });
         }
         
-        Ok(votes)
+        let wrong = updates.iter().filter(|u| !u.correct).count();
+        self.audit_result(
+            PipelinePhase::ImmutableAudit,
+            Some(decision_id),
+            None,
+            "Outcome recorded",
+            "RECORDED",
+            &format!("Outcome {:?}: {} of {} votes wrong", actual_outcome, wrong, updates.len()),
+        )?;
+        let quarantined = updates.iter().filter(|u| {
+            u.state == AgentState::Quarantined && u.trust_before >= policy.quarantine_threshold
+        });
+        for update in quarantined {
+            self.audit_result(
+                PipelinePhase::ImmutableAudit,
+                Some(decision_id),
+                Some(&update.agent_id),
+                "Agent quarantined",
+                "QUARANTINED",
+                &format!(
+                    "Trust {:.3} fell below {:.3}",
+                    update.trust_after, policy.quarantine_threshold
+                ),
+            )?;
+        }
+        
+        Ok(updates)
+    }
+    
+    /// Return a quarantined agent to the consensus pool at `reinstated_trust`
+    pub fn reinstate_agent(&mut self, agent_id: &str) -> Result<(), String> {
+        let trust = self.trust_policy.reinstated_trust;
+        let agent = self
+            .agents
+            .iter_mut()
+            .find(|a| a.id == agent_id)
+            .ok_or_else(|| format!("Unknown agent: {}", agent_id))?;
+        if agent.state != AgentState::Quarantined {
+            return Err(format!("Agent {} is not quarantined", agent_id));
+        }
+        
+        agent.state = AgentState::Active;
+        agent.trust_score = trust;
+        
+        self.audit_result(
+            PipelinePhase::ImmutableAudit,
+            None,
+            Some(agent_id),
+            "Agent reinstated",
+            "REINSTATED",
+            &format!("Trust reset to {:.3}", trust),
+        )
     }
     
     /// Check if quorum is met
@@ -616,19 +1785,22 @@ impl DSIF {
             decision_id: Some(decision.id.clone()),
             agent_id: None,
             action: format!("Decision: {}", decision.id),
-            result: if decision.quorum_met {
-                "APPROVED".to_string()
-            } else {
-                "BLOCKED".to_string()
-            },
+            result: match (decision.quorum_met, decision.status) {
+                (false, _) => "BLOCKED",
+                (true, DecisionStatus::Executed) => "APPROVED",
+                (true, DecisionStatus::AwaitingApproval) => "AWAITING_APPROVAL",
+                (true, DecisionStatus::Rejected) => "REJECTED",
+                (true, DecisionStatus::Expired) => "EXPIRED",
+            }
+            .to_string(),
             rationale: decision.rationale.clone(),
-            hash: self.hash(&format!("{:?}{:?}", decision, phase)),
+            hash: String::new(),
             previous_hash,
+            repeat_count: 1,
+            last_seen: None,
         };
         
-        self.audit_trail.push(entry);
-        
-        Ok(())
+        self.record(entry)
     }
     
     /// Check invariants against action
@@ -647,11 +1819,7 @@ impl DSIF {
                 "INV-001" => !simulation.safe, // C = 0
                 "INV-002" => false, // Deterministic - always true in DSIF
                 "INV-003" => action.provenance.hash.is_empty(), // Causal closure
-                "INV-004" => {
-                    // Check allowlist/denylist
-                    (!self.allowlist.is_empty() && !self.allowlist.contains(&action.target))
-                        || self.denylist.contains(&action.target)
-                }
+                "INV-004" => self.target_violation(&action.target).is_some(),
                 _ => false,
             };
             
@@ -675,6 +1843,19 @@ impl DSIF {
         agent_id: Option<&str>,
         action: &str,
         rationale: &str,
+    ) -> Result<(), String> {
+        self.audit_result(phase, Some(decision_id), agent_id, action, "IN_PROGRESS", rationale)
+    }
+    
+    /// Helper: Audit logging with an explicit result
+    fn audit_result(
+        &mut self,
+        phase: PipelinePhase,
+        decision_id: Option<&str>,
+        agent_id: Option<&str>,
+        action: &str,
+        result: &str,
+        rationale: &str,
     ) -> Result<(), String> {
         let previous_hash = self.audit_trail.last().map(|e| e.hash.clone());
         
@@ -682,30 +1863,68 @@ impl DSIF {
             id: Uuid::new_v4().to_string(),
             timestamp: Utc::now().to_rfc3339(),
             phase,
-            decision_id: Some(decision_id.to_string()),
+            decision_id: decision_id.map(|s| s.to_string()),
             agent_id: agent_id.map(|s| s.to_string()),
             action: action.to_string(),
-            result: "IN_PROGRESS".to_string(),
+            result: result.to_string(),
             rationale: rationale.to_string(),
-            hash: self.hash(&format!("{}{}{}", phase as u8, action, rationale)),
+            hash: String::new(),
             previous_hash,
+            repeat_count: 1,
+            last_seen: None,
         };
         
+        self.record(entry)
+    }
+    
+    /// Helper: Persist and append an audit entry, or coalesce it into the last one
+    fn record(&mut self, mut entry: AuditEntry) -> Result<(), String> {
+        if self.repeats_last(&entry) {
+            return self.coalesce(entry);
+        }
+        
+        entry.hash = entry_hash(&entry);
+        self.persist_entry(&entry, self.audit_trail.len())
+            .map_err(|e| format!("Failed to persist audit entry: {}", e))?;
         self.audit_trail.push(entry);
         Ok(())
     }
     
-    /// Helper: Hash function
-    fn hash(&self, data: &str) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(data.as_bytes());
-        hex::encode(hasher.finalize())
+    /// Helper: Whether `entry` repeats the last entry within the coalescing window
+    fn repeats_last(&self, entry: &AuditEntry) -> bool {
+        let (Some(window), Some(last)) = (self.coalesce_window, self.audit_trail.last()) else {
+            return false;
+        };
+        if (last.phase, &last.action, &last.rationale) != (entry.phase, &entry.action, &entry.rationale) {
+            return false;
+        }
+        
+        match (
+            chrono::DateTime::parse_from_rfc3339(&last.timestamp),
+            chrono::DateTime::parse_from_rfc3339(&entry.timestamp),
+        ) {
+            (Ok(first), Ok(now)) => now.signed_duration_since(first) <= window,
+            _ => false,
+        }
     }
     
-    /// Helper: Sign vote
-    fn sign_vote(&self, agent_id: &str, decision_id: &str, approve: bool) -> String {
-        let data = format!("{}:{}:{}", agent_id, decision_id, approve);
-        self.hash(&data)
+    /// Helper: Fold `repeat` into the last entry, re-hashing it over its new count
+    fn coalesce(&mut self, repeat: AuditEntry) -> Result<(), String> {
+        let index = self.audit_trail.len() - 1;
+        let mut entry = self.audit_trail[index].clone();
+        entry.repeat_count += 1;
+        entry.last_seen = Some(repeat.timestamp);
+        entry.hash = entry_hash(&entry);
+        
+        self.persist_entry(&entry, index)
+            .map_err(|e| format!("Failed to persist audit entry: {}", e))?;
+        self.audit_trail[index] = entry;
+        Ok(())
+    }
+    
+    /// Helper: Hash function
+    fn hash(&self, data: &str) -> String {
+        sha256_hex(data)
     }
     
     /// Add an invariant
@@ -713,18 +1932,33 @@ impl DSIF {
         self.invariants.push(invariant);
     }
     
-    /// Add to allowlist
-    pub fn add_to_allowlist(&mut self, item: String) {
-        if !self.allowlist.contains(&item) {
-            self.allowlist.push(item);
+    /// Add an exact target or glob to the allowlist
+    pub fn add_to_allowlist(&mut self, item: String) -> Result<(), String> {
+        let pattern = TargetPattern::new(&item)?;
+        if !self.allowlist.contains(&pattern) {
+            self.allowlist.push(pattern);
         }
+        Ok(())
     }
     
-    /// Add to denylist
-    pub fn add_to_denylist(&mut self, item: String) {
-        if !self.denylist.contains(&item) {
-            self.denylist.push(item);
+    /// Add an exact target or glob to the denylist
+    pub fn add_to_denylist(&mut self, item: String) -> Result<(), String> {
+        let pattern = TargetPattern::new(&item)?;
+        if !self.denylist.contains(&pattern) {
+            self.denylist.push(pattern);
         }
+        Ok(())
+    }
+    
+    /// Why `target` is refused by the lists, if it is; the denylist wins on overlap
+    fn target_violation(&self, target: &str) -> Option<String> {
+        if let Some(pattern) = self.denylist.iter().find(|p| p.matches(target)) {
+            return Some(format!("Target matches denylist pattern '{}'", pattern));
+        }
+        if !self.allowlist.is_empty() && !self.allowlist.iter().any(|p| p.matches(target)) {
+            return Some("Target not in allowlist".to_string());
+        }
+        None
     }
 
     /// Add a human approver attestation. The attestation's statement should
@@ -768,6 +2002,150 @@ impl DSIF {
     }
 }
 
+/// Read-only copy of what simulation and consensus need
+///
+/// Taken from `DSIF::view` so the long-running phases can run without
+/// holding the DSIF lock. Only active consensus agents are kept.
+#[derive(Clone)]
+pub struct PipelineView {
+    consensus_agents: Vec<Agent>,
+    resource_policy: ResourcePolicy,
+    snapshot_source: Option<Arc<dyn SnapshotSource>>,
+    simulators: Vec<SimulatorRoute>,
+}
+
+impl PipelineView {
+    /// Phase 3: Simulation-before-actuation
+    ///
+    /// Runs the simulator registered for `action` on a blocking thread; a
+    /// panicking simulator yields an unsafe result rather than no result.
+    pub async fn simulate(&self, action: &Action) -> SimulationResult {
+        let simulator = self.simulator_for(action);
+        let name = simulator.name().to_string();
+        let owned = action.clone();
+        
+        let mut result = match tokio::task::spawn_blocking(move || simulator.simulate(&owned)).await {
+            Ok(result) => result,
+            Err(e) if e.is_panic() => SimulationResult::failed(format!("Simulator '{}' panicked", name)),
+            Err(e) => SimulationResult::failed(format!("Simulator '{}' did not finish: {}", name, e)),
+        };
+        result.simulator = Some(name);
+        result
+    }
+    
+    /// The registered simulator for `action`, or the default cost model
+    fn simulator_for(&self, action: &Action) -> Arc<dyn Simulator> {
+        self.simulators
+            .iter()
+            .filter(|route| route.matches(action))
+            .max_by_key(|route| (route.target_prefix.len(), route.action_type.is_some()))
+            .map_or_else(
+                || Arc::new(CostModelSimulator::default()) as Arc<dyn Simulator>,
+                |route| route.simulator.clone(),
+            )
+    }
+    
+    /// Current system headroom, if a snapshot source is set
+    pub fn system_snapshot(&self) -> Option<SystemSnapshot> {
+        self.snapshot_source.as_ref().map(|source| source.snapshot())
+    }
+    
+    /// Phase 4: each active consensus agent's vote on `action`
+    pub async fn collect_votes(
+        &self,
+        action: &Action,
+        decision_id: &str,
+        invariant_check: &InvariantCheck,
+        simulation: &SimulationResult,
+        snapshot: Option<&SystemSnapshot>,
+    ) -> Vec<Vote> {
+        let mut votes = Vec::new();
+        
+        // Each agent votes through its own policy; trust and headroom gate every agent alike
+        for agent in &self.consensus_agents {
+            let (approve, rationale) = if !invariant_check.passed || agent.trust_score <= 0.5 {
+                (false, format!("Invariant check failed or low trust score: {}", agent.trust_score))
+            } else if let Some(objection) = snapshot.and_then(|s| self.resource_objection(action, simulation, s)) {
+                (false, objection)
+            } else {
+                match &agent.policy {
+                    Some(policy) => {
+                        let vote = policy.vote(action, simulation, invariant_check);
+                        (vote.approve, format!("[{}] {}", policy.name(), vote.rationale))
+                    }
+                    None => (true, "Invariants passed, action safe".to_string()),
+                }
+            };
+            let rationale = match snapshot {
+                Some(s) => format!(
+                    "{} [free memory {:.1} MB, thermal {} at {:.1}°C]",
+                    rationale,
+                    s.free_memory_mb,
+                    s.thermal_status.as_str(),
+                    s.max_temperature
+                ),
+                None => rationale,
+            };
+            
+            let vote = Vote {
+                agent_id: agent.id.clone(),
+                decision_id: decision_id.to_string(),
+                approve,
+                rationale,
+                timestamp: Utc::now().to_rfc3339(),
+                signature: self.sign_vote(&agent.id, decision_id, approve),
+            };
+            
+            votes.push(vote);
+        }
+        
+        votes
+    }
+    
+    /// Reason to reject `action` given the system headroom, if any
+    fn resource_objection(
+        &self,
+        action: &Action,
+        simulation: &SimulationResult,
+        snapshot: &SystemSnapshot,
+    ) -> Option<String> {
+        let limit = self.resource_policy.max_free_memory_fraction * snapshot.free_memory_mb;
+        if simulation.resource_usage.memory_mb > limit {
+            return Some(format!(
+                "Predicted memory {:.1} MB exceeds {:.0}% of free memory ({:.1} MB)",
+                simulation.resource_usage.memory_mb,
+                self.resource_policy.max_free_memory_fraction * 100.0,
+                limit
+            ));
+        }
+        
+        let heavy = matches!(action.action_type, ActionType::Write | ActionType::Critical);
+        let overheated = matches!(snapshot.thermal_status, ThermalStatus::Critical | ThermalStatus::Shutdown);
+        if heavy && overheated {
+            return Some(format!(
+                "Thermal status {} forbids {:?} actions",
+                snapshot.thermal_status.as_str(),
+                action.action_type
+            ));
+        }
+        
+        None
+    }
+    
+    /// Helper: Sign vote
+    fn sign_vote(&self, agent_id: &str, decision_id: &str, approve: bool) -> String {
+        let data = format!("{}:{}:{}", agent_id, decision_id, approve);
+        sha256_hex(&data)
+    }
+}
+
+/// Hex SHA-256 of `data`
+fn sha256_hex(data: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(data.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
 /// Policy validation result
 #[derive(Debug, Clone)]
 struct PolicyResult {
@@ -843,6 +2221,439 @@ mod tests {
         assert!(result.unwrap_err().contains("Adversarial pattern"));
     }
     
+    async fn run_read(dsif: &mut DSIF) -> Decision {
+        dsif.execute_pipeline("trusted:read", ActionType::Read, "test-target", HashMap::new())
+            .await
+            .unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_verify_trail_detects_broken_link() {
+        let mut dsif = DSIF::new(0.67);
+        run_read(&mut dsif).await;
+        run_read(&mut dsif).await;
+        assert_eq!(dsif.verify_trail(), Ok(()));
+        
+        dsif.audit_trail[3].previous_hash = Some("tampered".to_string());
+        assert_eq!(dsif.verify_trail(), Err(3));
+    }
+    
+    #[tokio::test]
+    async fn test_verify_trail_detects_edited_entry() {
+        let mut dsif = DSIF::new(0.67);
+        run_read(&mut dsif).await;
+        run_read(&mut dsif).await;
+        
+        // The link to the next entry is intact, but the content no longer hashes
+        dsif.audit_trail[2].result = "APPROVED".to_string();
+        dsif.audit_trail[2].rationale.push('!');
+        assert_eq!(dsif.verify_trail(), Err(2));
+    }
+    
+    #[tokio::test]
+    async fn test_exported_trail_verifies() {
+        let mut dsif = DSIF::new(0.67);
+        run_read(&mut dsif).await;
+        run_read(&mut dsif).await;
+        
+        let doc = dsif.export_trail();
+        assert_eq!(doc.entries.len(), dsif.get_audit_trail().len());
+        assert_eq!(doc.chain_head.as_deref(), dsif.get_audit_trail().last().map(|e| e.hash.as_str()));
+        assert_eq!(DSIF::verify_exported_trail(&doc), Ok(()));
+        
+        // Survives a round trip through JSON
+        let json = serde_json::to_string_pretty(&doc).unwrap();
+        let parsed: ExportedTrail = serde_json::from_str(&json).unwrap();
+        assert_eq!(DSIF::verify_exported_trail(&parsed), Ok(()));
+        
+        assert_eq!(DSIF::verify_exported_trail(&DSIF::new(0.67).export_trail()), Ok(()));
+    }
+    
+    #[tokio::test]
+    async fn test_exported_trail_pinpoints_tampering() {
+        let mut dsif = DSIF::new(0.67);
+        run_read(&mut dsif).await;
+        run_read(&mut dsif).await;
+        let doc = dsif.export_trail();
+        let middle = doc.entries.len() / 2;
+        
+        let mut tampered = doc.clone();
+        tampered.entries[middle].entry.result = "APPROVED".to_string();
+        tampered.entries[middle].entry.rationale.push('!');
+        assert_eq!(
+            DSIF::verify_exported_trail(&tampered),
+            Err(TrailError::EntryTampered { index: middle })
+        );
+        
+        // Re-digesting the edit still fails: the root no longer matches
+        tampered.entries[middle].digest = entry_digest(&tampered.entries[middle].entry);
+        assert_eq!(DSIF::verify_exported_trail(&tampered), Err(TrailError::MerkleRootMismatch));
+        
+        // Dropping an entry breaks the chain at the one after it
+        let mut dropped = doc.clone();
+        dropped.entries.remove(middle);
+        assert_eq!(
+            DSIF::verify_exported_trail(&dropped),
+            Err(TrailError::BrokenChain { index: middle })
+        );
+        
+        let mut truncated = doc;
+        truncated.entries.pop();
+        truncated.merkle_root = session_bundle::merkle_root(
+            &truncated.entries.iter().map(|e| e.digest.clone()).collect::<Vec<_>>(),
+        );
+        assert_eq!(DSIF::verify_exported_trail(&truncated), Err(TrailError::ChainHeadMismatch));
+    }
+    
+    #[tokio::test]
+    async fn test_trail_survives_restart() {
+        let path = std::env::temp_dir().join(format!("dsif-audit-{}", std::process::id()));
+        let store = CozoStore::new(&path).unwrap();
+        
+        let mut first = DSIF::new(0.67).with_store(&store).unwrap();
+        let decision = run_read(&mut first).await;
+        let before = first.get_audit_trail().to_vec();
+        drop(first);
+        
+        let mut second = DSIF::new(0.67).with_store(&store).unwrap();
+        assert_eq!(second.get_audit_trail().len(), before.len());
+        assert_eq!(second.get_audit_trail().last().unwrap().hash, before.last().unwrap().hash);
+        
+        // New entries chain onto the restored trail
+        run_read(&mut second).await;
+        assert_eq!(second.verify_trail(), Ok(()));
+        assert_eq!(store.load_dsif_records("decision").unwrap()[0]["id"], decision.id);
+        
+        let _ = std::fs::remove_dir_all(&path);
+    }
+    
+    async fn run_blocked(dsif: &mut DSIF) {
+        let result = dsif
+            .execute_pipeline("trusted:jailbreak", ActionType::Read, "test-target", HashMap::new())
+            .await;
+        assert!(result.unwrap_err().contains("Adversarial pattern"));
+    }
+    
+    #[tokio::test]
+    async fn test_repeated_failures_coalesce() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.set_coalescing(chrono::Duration::seconds(DEFAULT_COALESCE_WINDOW_SECS), true);
+        
+        run_blocked(&mut dsif).await;
+        let first_hash = dsif.get_audit_trail()[0].hash.clone();
+        for _ in 1..1000 {
+            run_blocked(&mut dsif).await;
+        }
+        
+        let trail = dsif.get_audit_trail();
+        assert_eq!(trail.len(), 1);
+        assert_eq!(trail[0].phase, PipelinePhase::InputHygiene);
+        assert_eq!(trail[0].repeat_count, 1000);
+        assert!(trail[0].last_seen.is_some());
+        assert_ne!(trail[0].hash, first_hash);
+        assert_eq!(dsif.verify_trail(), Ok(()));
+        
+        // The count is part of the exported entry and its digest
+        let doc = dsif.export_trail();
+        assert_eq!(DSIF::verify_exported_trail(&doc), Ok(()));
+        let mut tampered = doc;
+        tampered.entries[0].entry.repeat_count = 1;
+        assert_eq!(
+            DSIF::verify_exported_trail(&tampered),
+            Err(TrailError::EntryTampered { index: 0 })
+        );
+        
+        // A different entry ends the run; the chain continues from the coalesced head
+        run_read(&mut dsif).await;
+        assert!(dsif.get_audit_trail().len() > 1);
+        assert_eq!(dsif.verify_trail(), Ok(()));
+        assert_eq!(DSIF::verify_exported_trail(&dsif.export_trail()), Ok(()));
+    }
+    
+    #[tokio::test]
+    async fn test_coalescing_window_and_toggle() {
+        let mut dsif = DSIF::new(0.67);
+        run_blocked(&mut dsif).await;
+        run_blocked(&mut dsif).await;
+        assert_eq!(dsif.get_audit_trail().len(), 2);
+        assert!(dsif.get_audit_trail().iter().all(|e| e.repeat_count == 1));
+        
+        // Repeats outside the window start a new entry
+        dsif.set_coalescing(chrono::Duration::seconds(DEFAULT_COALESCE_WINDOW_SECS), true);
+        dsif.audit_trail[1].timestamp = (Utc::now() - chrono::Duration::hours(1)).to_rfc3339();
+        dsif.audit_trail[1].hash = entry_hash(&dsif.audit_trail[1]);
+        run_blocked(&mut dsif).await;
+        run_blocked(&mut dsif).await;
+        assert_eq!(dsif.get_audit_trail().len(), 3);
+        assert_eq!(dsif.get_audit_trail()[2].repeat_count, 2);
+        
+        dsif.set_coalescing(chrono::Duration::seconds(DEFAULT_COALESCE_WINDOW_SECS), false);
+        run_blocked(&mut dsif).await;
+        assert_eq!(dsif.get_audit_trail().len(), 4);
+        assert_eq!(dsif.verify_trail(), Ok(()));
+        
+        // Entries that were never coalesced serialize as before
+        let json = serde_json::to_value(&dsif.get_audit_trail()[0]).unwrap();
+        assert!(json.get("repeat_count").is_none());
+        assert!(json.get("last_seen").is_none());
+    }
+    
+    fn snapshot(free_memory_mb: f64, thermal_status: ThermalStatus) -> SystemSnapshot {
+        SystemSnapshot {
+            free_memory_mb,
+            total_memory_mb: 16384.0,
+            cpu_usage_percent: 20.0,
+            thermal_status,
+            max_temperature: if thermal_status == ThermalStatus::Critical { 90.0 } else { 45.0 },
+            taken_at: "2026-01-01T00:00:00Z".to_string(),
+        }
+    }
+    
+    fn heavy_write(memory_mb: f64) -> (Action, SimulationResult) {
+        let action = Action {
+            id: "action-1".to_string(),
+            action_type: ActionType::Write,
+            target: "test-target".to_string(),
+            parameters: HashMap::new(),
+            provenance: Provenance {
+                source: "test".to_string(),
+                trust_level: TrustLevel::Trusted,
+                attestation: None,
+                timestamp: "".to_string(),
+                hash: "abc".to_string(),
+            },
+        };
+        let simulation = SimulationResult {
+            safe: true,
+            predicted_outcomes: vec![],
+            resource_usage: ResourceUsage {
+                memory_mb,
+                cpu_percent: 5.0,
+                latency_ms: 50.0,
+                network_bytes: 0,
+            },
+            violations: vec![],
+            simulator: None,
+        };
+        (action, simulation)
+    }
+    
+    async fn vote_with(dsif: &mut DSIF, memory_mb: f64, snapshot: &SystemSnapshot) -> Vec<Vote> {
+        let (action, simulation) = heavy_write(memory_mb);
+        let check = dsif.check_invariants(&action, &simulation).unwrap();
+        dsif.consensus_gating(&action, "d1", &check, &simulation, Some(snapshot))
+            .await
+            .unwrap()
+    }
+    
+    #[tokio::test]
+    async fn test_votes_flip_on_low_memory() {
+        let mut dsif = DSIF::new(0.67);
+        
+        let roomy = vote_with(&mut dsif, 500.0, &snapshot(8192.0, ThermalStatus::Normal)).await;
+        assert!(roomy.iter().all(|v| v.approve));
+        assert!(roomy[0].rationale.contains("free memory 8192.0 MB"));
+        assert!(dsif.check_quorum(&roomy));
+        
+        let tight = vote_with(&mut dsif, 500.0, &snapshot(600.0, ThermalStatus::Normal)).await;
+        assert!(tight.iter().all(|v| !v.approve));
+        assert!(tight[0].rationale.contains("Predicted memory 500.0 MB exceeds 50% of free memory (300.0 MB)"));
+        assert!(!dsif.check_quorum(&tight));
+    }
+    
+    #[tokio::test]
+    async fn test_votes_reject_heavy_action_when_overheated() {
+        let mut dsif = DSIF::new(0.67);
+        
+        let votes = vote_with(&mut dsif, 10.0, &snapshot(8192.0, ThermalStatus::Critical)).await;
+        assert!(votes.iter().all(|v| !v.approve));
+        assert!(votes[0].rationale.contains("Thermal status CRITICAL forbids Write actions"));
+        assert!(votes[0].rationale.contains("thermal CRITICAL at 90.0°C"));
+    }
+    
+    #[tokio::test]
+    async fn test_decision_records_snapshot() {
+        let constrained = snapshot(1.0, ThermalStatus::Normal);
+        let mut dsif = DSIF::new(0.67).with_snapshot_source(Arc::new(snapshot(8192.0, ThermalStatus::Normal)));
+        
+        let decision = run_read(&mut dsif).await;
+        assert_eq!(decision.system_snapshot, Some(snapshot(8192.0, ThermalStatus::Normal)));
+        
+        // Even a read is blocked when its predicted memory exceeds the headroom
+        let mut starved = DSIF::new(0.67).with_snapshot_source(Arc::new(constrained));
+        let result = starved
+            .execute_pipeline("trusted:read", ActionType::Read, "test-target", HashMap::new())
+            .await;
+        assert_eq!(result.unwrap_err(), "Quorum not met - action blocked");
+    }
+    
+    fn write_to(target: &str, memory_mb: f64) -> (Action, SimulationResult) {
+        let (mut action, simulation) = heavy_write(memory_mb);
+        action.target = target.to_string();
+        (action, simulation)
+    }
+    
+    #[tokio::test]
+    async fn test_policies_split_vote_and_block_quorum() {
+        let mut dsif = DSIF::new(0.67);
+        
+        // Over the memory budget and aimed at a sensitive target, but well attested
+        let (action, simulation) = write_to("vault/secret-store", 2048.0);
+        let check = dsif.check_invariants(&action, &simulation).unwrap();
+        let votes = dsif.consensus_gating(&action, "d1", &check, &simulation, None).await.unwrap();
+        
+        let approvals: Vec<bool> = votes.iter().map(|v| v.approve).collect();
+        assert_eq!(approvals, [false, false, true]);
+        assert!(votes[0].rationale.starts_with("[resource-budget] Resource budget exceeded: memory 2048.0/1024.0 MB"));
+        assert!(votes[1].rationale.contains("matches deny pattern '*secret*'"));
+        assert!(votes[2].rationale.starts_with("[provenance-trust]"));
+        assert!(!dsif.check_quorum(&votes));
+        
+        // Fixing one objection flips one vote and restores quorum
+        let (action, simulation) = write_to("vault/public", 2048.0);
+        let votes = dsif.consensus_gating(&action, "d2", &check, &simulation, None).await.unwrap();
+        assert_eq!(votes.iter().filter(|v| v.approve).count(), 2);
+        assert!(dsif.check_quorum(&votes));
+    }
+    
+    #[tokio::test]
+    async fn test_custom_policy_and_reporting() {
+        let mut dsif = DSIF::new(0.67);
+        let strict = TargetPatternPolicy { allow: vec!["reports/*".to_string()], deny: vec![] };
+        dsif.set_agent_policy("agent-2", Arc::new(strict)).unwrap();
+        assert!(dsif.set_agent_policy("agent-0", Arc::new(ProvenanceTrustPolicy::default())).is_err());
+        
+        let (action, simulation) = write_to("logs/today", 10.0);
+        let check = dsif.check_invariants(&action, &simulation).unwrap();
+        let votes = dsif.consensus_gating(&action, "d1", &check, &simulation, None).await.unwrap();
+        assert!(!votes[0].approve);
+        assert!(votes[0].rationale.contains("matches no allow pattern"));
+        
+        let reported = serde_json::json!(dsif.get_agents());
+        assert_eq!(reported[2]["policy"], "target-pattern");
+        assert_eq!(reported[3]["policy"], "target-pattern");
+        assert_eq!(reported[4]["policy"], "provenance-trust");
+        assert!(reported[0]["policy"].is_null());
+    }
+    
+    #[tokio::test]
+    async fn test_repeated_bad_votes_quarantine_agent() {
+        let mut dsif = DSIF::new(0.67);
+        let (action, simulation) = write_to("vault/secret-store", 10.0);
+        let check = dsif.check_invariants(&action, &simulation).unwrap();
+        
+        // agent-3 keeps rejecting a target that turns out to be safe
+        for round in 0..4 {
+            let decision_id = format!("d{}", round);
+            let votes = dsif.consensus_gating(&action, &decision_id, &check, &simulation, None).await.unwrap();
+            assert_eq!(votes.len(), 3);
+            assert!(!votes[1].approve);
+            
+            let updates = dsif.record_outcome(&decision_id, ActionOutcome::Safe).unwrap();
+            assert!(updates[0].correct && !updates[1].correct);
+            assert_eq!(updates[0].trust_after, 1.0);
+        }
+        
+        let agent = &dsif.get_agents()[3];
+        assert_eq!(agent.state, AgentState::Quarantined);
+        assert!((agent.trust_score - 0.4096).abs() < 1e-9);
+        assert!(dsif.record_outcome("d0", ActionOutcome::Safe).is_err());
+        
+        let trail = dsif.get_audit_trail();
+        let quarantine = trail.iter().filter(|e| e.result == "QUARANTINED").collect::<Vec<_>>();
+        assert_eq!(quarantine.len(), 1);
+        assert_eq!(quarantine[0].agent_id.as_deref(), Some("agent-3"));
+        assert_eq!(quarantine[0].decision_id.as_deref(), Some("d3"));
+        
+        // Quarantined agents no longer vote
+        let votes = dsif.consensus_gating(&action, "d4", &check, &simulation, None).await.unwrap();
+        assert_eq!(votes.len(), 2);
+        assert!(votes.iter().all(|v| v.approve && v.agent_id != "agent-3"));
+        
+        dsif.reinstate_agent("agent-3").unwrap();
+        assert_eq!(dsif.get_agents()[3].state, AgentState::Active);
+        assert_eq!(dsif.get_agents()[3].trust_score, 0.75);
+        assert!(dsif.reinstate_agent("agent-3").is_err());
+        assert_eq!(dsif.get_audit_trail().last().unwrap().result, "REINSTATED");
+        assert_eq!(dsif.verify_trail(), Ok(()));
+    }
+    
+    #[test]
+    fn test_wildcard_match() {
+        assert!(wildcard_match("*secret*", "vault/secret-store"));
+        assert!(wildcard_match("*.key", "tls/server.key"));
+        assert!(wildcard_match("reports/*", "reports/"));
+        assert!(wildcard_match("a*b*c", "axxbyyc"));
+        assert!(!wildcard_match("a*b*c", "axxcyyb"));
+        assert!(!wildcard_match("ab*ba", "aba"));
+        assert!(wildcard_match("exact", "exact"));
+        assert!(!wildcard_match("exact", "exactly"));
+    }
+    
+    #[test]
+    fn test_target_pattern_syntax() {
+        let pattern = TargetPattern::new("api.internal/v[0-9]/user?").unwrap();
+        assert!(pattern.matches("api.internal/v2/users"));
+        assert!(!pattern.matches("api.internal/vx/users"));
+        assert!(TargetPattern::new("[!a-c]x").unwrap().matches("dx"));
+        assert!(TargetPattern::new(r"literal\*").unwrap().matches("literal*"));
+        assert!(!TargetPattern::new(r"literal\*").unwrap().matches("literally"));
+        
+        for invalid in ["", "[abc", "[]", "[z-a]", "a]", "trailing\\", "[a-]"] {
+            assert!(TargetPattern::new(invalid).is_err(), "{:?} should be rejected", invalid);
+        }
+    }
+    
+    #[test]
+    fn test_denylist_takes_precedence() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.add_to_allowlist("api.internal/*".to_string()).unwrap();
+        dsif.add_to_denylist("*/delete".to_string()).unwrap();
+        
+        assert_eq!(dsif.target_violation("api.internal/users/list"), None);
+        assert_eq!(
+            dsif.target_violation("api.internal/users/delete").as_deref(),
+            Some("Target matches denylist pattern '*/delete'")
+        );
+        assert_eq!(dsif.target_violation("api.external/users").as_deref(), Some("Target not in allowlist"));
+        assert!(dsif.add_to_allowlist("[oops".to_string()).is_err());
+    }
+    
+    #[test]
+    fn test_wildcard_only_and_case_sensitive_entries() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.add_to_allowlist("*".to_string()).unwrap();
+        assert_eq!(dsif.target_violation("anything/at/all"), None);
+        
+        dsif.add_to_denylist("Admin/*".to_string()).unwrap();
+        assert!(dsif.target_violation("Admin/panel").is_some());
+        assert_eq!(dsif.target_violation("admin/panel"), None);
+        
+        // Exact entries still work as before
+        dsif.add_to_denylist("exact-target".to_string()).unwrap();
+        assert!(dsif.target_violation("exact-target").is_some());
+        assert_eq!(dsif.target_violation("exact-target-2"), None);
+    }
+    
+    #[tokio::test]
+    async fn test_policy_and_invariant_agree_on_lists() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.add_to_allowlist("api.internal/*".to_string()).unwrap();
+        dsif.add_to_denylist("*/delete".to_string()).unwrap();
+        
+        let result = dsif
+            .execute_pipeline("trusted:cleanup", ActionType::Read, "api.internal/users/delete", HashMap::new())
+            .await;
+        let err = result.unwrap_err();
+        assert!(err.contains("denylist pattern '*/delete'"), "{}", err);
+        
+        let allowed = dsif
+            .execute_pipeline("trusted:list", ActionType::Read, "api.internal/users", HashMap::new())
+            .await
+            .unwrap();
+        assert!(allowed.quorum_met);
+    }
+    
     #[test]
     fn test_quorum_check() {
         let dsif = DSIF::new(0.67);
@@ -876,5 +2687,271 @@ mod tests {
         
         assert!(dsif.check_quorum(&votes)); // 2/3 = 0.67 >= 0.67
     }
+    
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_shared_pipelines_keep_every_audit_entry() {
+        let per_decision = {
+            let mut solo = DSIF::new(0.67);
+            run_read(&mut solo).await;
+            solo.get_audit_trail().len()
+        };
+        
+        let dsif = Arc::new(RwLock::new(DSIF::new(0.67)));
+        let mut pipelines = Vec::new();
+        let mut readers = Vec::new();
+        for i in 0..50 {
+            let shared = dsif.clone();
+            pipelines.push(tokio::spawn(async move {
+                let input = format!("trusted:read {}", i);
+                DSIF::execute_pipeline_shared(&shared, &input, ActionType::Read, "test-target", HashMap::new())
+                    .await
+                    .map(|decision| decision.id)
+            }));
+            let shared = dsif.clone();
+            readers.push(tokio::spawn(async move { shared.read().await.verify_trail() }));
+        }
+        
+        let decision_ids = tokio::time::timeout(std::time::Duration::from_secs(30), async {
+            for reader in readers {
+                assert_eq!(reader.await.unwrap(), Ok(()));
+            }
+            let mut decision_ids = Vec::new();
+            for pipeline in pipelines {
+                decision_ids.push(pipeline.await.unwrap().unwrap());
+            }
+            decision_ids
+        })
+        .await
+        .expect("pipelines deadlocked");
+        
+        let dsif = dsif.read().await;
+        let trail = dsif.get_audit_trail();
+        assert_eq!(trail.len(), 50 * per_decision);
+        for id in &decision_ids {
+            let entries = trail.iter().filter(|e| e.decision_id.as_deref() == Some(id.as_str())).count();
+            assert_eq!(entries, per_decision, "decision {}", id);
+        }
+        assert_eq!(dsif.verify_trail(), Ok(()));
+    }
+    
+    async fn run_critical(dsif: &mut DSIF) -> Decision {
+        dsif.execute_pipeline("attested:restart web", ActionType::Critical, "services/web", HashMap::new())
+            .await
+            .unwrap()
+    }
+    
+    fn actuations(dsif: &DSIF, decision_id: &str) -> usize {
+        dsif.get_audit_trail()
+            .iter()
+            .filter(|e| e.decision_id.as_deref() == Some(decision_id) && e.action == "Controlled actuation")
+            .count()
+    }
+    
+    #[tokio::test]
+    async fn test_critical_action_waits_for_approval() {
+        let mut dsif = DSIF::new(0.67);
+        let pending = run_critical(&mut dsif).await;
+        assert_eq!(pending.status, DecisionStatus::AwaitingApproval);
+        assert_eq!(actuations(&dsif, &pending.id), 0);
+        assert_eq!(dsif.get_audit_trail().last().unwrap().result, "AWAITING_APPROVAL");
+        assert_eq!(dsif.pending_approvals().unwrap().len(), 1);
+        assert_eq!(dsif.pending_approvals().unwrap()[0].approval_token, None);
+        let token = pending.approval_token.clone().unwrap();
+        
+        let approved = dsif.approve(&pending.id, &token, "operator-1").unwrap();
+        assert_eq!(approved.status, DecisionStatus::Executed);
+        assert_eq!(approved.approver.as_deref(), Some("operator-1"));
+        assert_eq!(approved.approval_token, None);
+        assert_eq!(actuations(&dsif, &pending.id), 1);
+        assert!(dsif.get_audit_trail().iter().any(|e| e.result == "APPROVED" && e.rationale == "Approved by operator-1"));
+        assert!(dsif.pending_approvals().unwrap().is_empty());
+        assert_eq!(dsif.verify_trail(), Ok(()));
+        
+        // A second approval with the same token is refused and actuates nothing
+        let err = dsif.approve(&pending.id, &token, "operator-1").unwrap_err();
+        assert!(err.contains("No decision awaiting approval"), "{}", err);
+        assert_eq!(actuations(&dsif, &pending.id), 1);
+    }
+    
+    #[tokio::test]
+    async fn test_wrong_token_is_audited_and_keeps_decision_pending() {
+        let mut dsif = DSIF::new(0.67);
+        let pending = run_critical(&mut dsif).await;
+        let token = pending.approval_token.clone().unwrap();
+        
+        assert_eq!(dsif.approve(&pending.id, "not-the-token", "mallory").unwrap_err(), "Invalid approval token");
+        let last = dsif.get_audit_trail().last().unwrap();
+        assert_eq!(last.result, "INVALID_TOKEN");
+        assert!(last.rationale.contains("mallory"));
+        assert!(dsif.reject(&pending.id, "not-the-token", "mallory", "no").is_err());
+        assert!(dsif.approve(&pending.id, &token, " ").is_err());
+        assert_eq!(dsif.pending_approvals().unwrap().len(), 1);
+        
+        let rejected = dsif.reject(&pending.id, &token, "operator-1", "Outside the change window").unwrap();
+        assert_eq!(rejected.status, DecisionStatus::Rejected);
+        assert_eq!(rejected.approver.as_deref(), Some("operator-1"));
+        assert_eq!(dsif.get_audit_trail().last().unwrap().result, "REJECTED");
+        assert_eq!(actuations(&dsif, &pending.id), 0);
+        assert!(dsif.approve(&pending.id, &token, "operator-1").is_err());
+        assert_eq!(dsif.verify_trail(), Ok(()));
+    }
+    
+    #[tokio::test]
+    async fn test_pending_approval_expires() {
+        let mut dsif = DSIF::new(0.67).with_approval_ttl(chrono::Duration::zero());
+        let pending = run_critical(&mut dsif).await;
+        let token = pending.approval_token.clone().unwrap();
+        
+        let err = dsif.approve(&pending.id, &token, "operator-1").unwrap_err();
+        assert!(err.contains("No decision awaiting approval"), "{}", err);
+        assert_eq!(actuations(&dsif, &pending.id), 0);
+        
+        let settled: Vec<&AuditEntry> = dsif
+            .get_audit_trail()
+            .iter()
+            .filter(|e| e.decision_id.as_deref() == Some(pending.id.as_str()))
+            .collect();
+        assert!(settled.iter().any(|e| e.action == "Approval expired"));
+        assert_eq!(settled.last().unwrap().result, "EXPIRED");
+        assert!(dsif.expire_approvals().unwrap().is_empty());
+        assert_eq!(dsif.verify_trail(), Ok(()));
+    }
+    
+    fn payload(bytes: usize) -> HashMap<String, serde_json::Value> {
+        HashMap::from([("blob".to_string(), serde_json::json!("x".repeat(bytes)))])
+    }
+    
+    /// Vetoes actions whose parameters exceed a size limit
+    struct PayloadLimit(u64);
+    
+    impl Simulator for PayloadLimit {
+        fn name(&self) -> &str {
+            "payload-limit"
+        }
+        
+        fn simulate(&self, action: &Action) -> SimulationResult {
+            let mut result = CostModelSimulator::default().simulate(action);
+            let size = CostModelSimulator::payload_bytes(action);
+            if size > self.0 {
+                result.safe = false;
+                result.violations.push(format!("Payload of {} bytes exceeds {}", size, self.0));
+            }
+            result
+        }
+    }
+    
+    struct Panics;
+    
+    impl Simulator for Panics {
+        fn name(&self) -> &str {
+            "panics"
+        }
+        
+        fn simulate(&self, _action: &Action) -> SimulationResult {
+            panic!("dry run crashed")
+        }
+    }
+    
+    #[tokio::test]
+    async fn test_cost_model_scales_with_payload() {
+        let dsif = DSIF::new(0.67);
+        let (mut small, _) = write_to("db/users", 0.0);
+        small.parameters = payload(16);
+        let mut large = small.clone();
+        large.parameters = payload(1024 * 1024);
+        
+        let small = dsif.view().simulate(&small).await;
+        let large = dsif.view().simulate(&large).await;
+        assert!(small.safe && large.safe);
+        assert_eq!(large.simulator.as_deref(), Some("cost-model"));
+        assert!(large.resource_usage.memory_mb > small.resource_usage.memory_mb + 100.0);
+        assert!(large.resource_usage.latency_ms > small.resource_usage.latency_ms);
+        assert!(large.resource_usage.network_bytes > 1024 * 1024);
+    }
+    
+    #[tokio::test]
+    async fn test_cost_model_flags_destructive_verbs() {
+        let dsif = DSIF::new(0.67);
+        let (mut write, _) = write_to("db/users", 0.0);
+        write.parameters = HashMap::from([("sql".to_string(), serde_json::json!("DROP TABLE users"))]);
+        let result = dsif.view().simulate(&write).await;
+        assert!(!result.safe);
+        assert!(result.violations[0].contains("drop"));
+        
+        // Reads are only annotated, and verbs must be whole words
+        let mut read = write.clone();
+        read.action_type = ActionType::Read;
+        assert!(dsif.view().simulate(&read).await.safe);
+        write.parameters = HashMap::from([("view".to_string(), serde_json::json!("dropdown"))]);
+        assert!(dsif.view().simulate(&write).await.safe);
+    }
+    
+    #[tokio::test]
+    async fn test_registered_simulator_vetoes_large_writes() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.register_simulator(Some(ActionType::Write), "db/", Arc::new(PayloadLimit(4096)));
+        
+        let err = dsif
+            .execute_pipeline("trusted:bulk insert", ActionType::Write, "db/users", payload(8192))
+            .await
+            .unwrap_err();
+        assert!(err.contains("Simulation failed") && err.contains("exceeds 4096"), "{}", err);
+        
+        // Small writes pass; reads and other targets fall back to the cost model
+        let (mut small, _) = write_to("db/users", 0.0);
+        small.parameters = payload(64);
+        let result = dsif.view().simulate(&small).await;
+        assert!(result.safe);
+        assert_eq!(result.simulator.as_deref(), Some("payload-limit"));
+        
+        let mut read = small.clone();
+        read.action_type = ActionType::Read;
+        read.parameters = payload(8192);
+        assert_eq!(dsif.view().simulate(&read).await.simulator.as_deref(), Some("cost-model"));
+        
+        let mut elsewhere = read.clone();
+        elsewhere.action_type = ActionType::Write;
+        elsewhere.target = "cache/users".to_string();
+        assert!(dsif.view().simulate(&elsewhere).await.safe);
+    }
+    
+    #[tokio::test]
+    async fn test_longest_prefix_simulator_wins() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.register_simulator(None, "db/", Arc::new(PayloadLimit(0)));
+        dsif.register_simulator(None, "db/scratch/", Arc::new(CostModelSimulator::default()));
+        
+        let (action, _) = write_to("db/scratch/tmp", 0.0);
+        assert!(dsif.view().simulate(&action).await.safe);
+        let (action, _) = write_to("db/users", 0.0);
+        assert!(!dsif.view().simulate(&action).await.safe);
+    }
+    
+    #[tokio::test]
+    async fn test_failing_simulators_are_unsafe() {
+        let mut dsif = DSIF::new(0.67);
+        dsif.register_simulator(None, "crash/", Arc::new(Panics));
+        dsif.register_simulator(
+            None,
+            "remote/",
+            Arc::new(DryRunSimulator::new("remote", |_: &Action| Err("connection refused".to_string()))),
+        );
+        
+        let (action, _) = write_to("crash/here", 0.0);
+        let result = dsif.view().simulate(&action).await;
+        assert!(!result.safe);
+        assert_eq!(result.violations, ["Simulator 'panics' panicked"]);
+        
+        let (action, _) = write_to("remote/api", 0.0);
+        let result = dsif.view().simulate(&action).await;
+        assert!(!result.safe);
+        assert!(result.violations[0].contains("connection refused"));
+        
+        let err = dsif
+            .execute_pipeline("trusted:read", ActionType::Read, "crash/here", HashMap::new())
+            .await
+            .unwrap_err();
+        assert!(err.contains("panicked"), "{}", err);
+    }
 }
 
@@ -1,11 +1,23 @@
 //! Invariance Layer - C=0 Enforcement
 //!
+//! Output is checked against intent by the strategy in [`AlignmentConfig`]:
+//! an exact hash match, the Jaccard similarity of normalized token sets, or
+//! an external scorer such as an embedding model. Whatever the strategy, the
+//! verdict is binary; the score only explains it.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use chrono::Utc;
+use chrono::{TimeZone, Utc};
+use ring::rand::{SecureRandom, SystemRandom};
+use sap4d::signing::{KeySigner, ReceiptSigner};
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fmt;
+use std::sync::Arc;
+use unicode_normalization::UnicodeNormalization;
 
+use crate::cozo_db::{CozoStore, Fact};
 use crate::{PROJECTION, SUBSTRATE};
 
 /// Compute SHA-256 hash
@@ -15,12 +27,166 @@ pub fn sha256(data: &str) -> String {
     hex::encode(hasher.finalize())
 }
 
-/// Check alignment between output and intent
+/// Default minimum Jaccard similarity for alignment
+pub const DEFAULT_JACCARD_THRESHOLD: f64 = 0.5;
+
+/// Text shown in place of nullified output
+pub const NULLIFIED_REFUSAL: &str =
+    "[NULLIFIED] This output was withheld because it does not align with the stated intent.";
+
+/// How output is compared with intent
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum AlignmentStrategy {
+    /// SHA-256 of output and intent must match
+    ExactHash,
+    /// Jaccard similarity of NFKC-normalized, lowercased token sets
+    TokenJaccard,
+    /// Caller-supplied scorer (e.g. embedding cosine similarity)
+    Embedding,
+}
+
+/// Similarity in `0.0..=1.0` between output and intent
+pub type AlignmentScorer = Arc<dyn Fn(&str, &str) -> f64 + Send + Sync>;
+
+/// Alignment strategy and threshold
+#[derive(Clone)]
+pub struct AlignmentConfig {
+    strategy: AlignmentStrategy,
+    threshold: f64,
+    scorer: Option<AlignmentScorer>,
+}
+
+impl Default for AlignmentConfig {
+    fn default() -> Self {
+        Self::token_jaccard(DEFAULT_JACCARD_THRESHOLD)
+    }
+}
+
+impl fmt::Debug for AlignmentConfig {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("AlignmentConfig")
+            .field("strategy", &self.strategy)
+            .field("threshold", &self.threshold)
+            .finish_non_exhaustive()
+    }
+}
+
+impl AlignmentConfig {
+    /// Aligned only when output and intent are byte-identical
+    pub fn exact_hash() -> Self {
+        Self {
+            strategy: AlignmentStrategy::ExactHash,
+            threshold: 1.0,
+            scorer: None,
+        }
+    }
+    
+    /// Aligned when token-set similarity reaches `threshold`
+    pub fn token_jaccard(threshold: f64) -> Self {
+        Self {
+            strategy: AlignmentStrategy::TokenJaccard,
+            threshold,
+            scorer: None,
+        }
+    }
+    
+    /// Aligned when `scorer` reaches `threshold`; scores are clamped to `0.0..=1.0`
+    pub fn embedding(threshold: f64, scorer: impl Fn(&str, &str) -> f64 + Send + Sync + 'static) -> Self {
+        Self {
+            strategy: AlignmentStrategy::Embedding,
+            threshold,
+            scorer: Some(Arc::new(scorer)),
+        }
+    }
+    
+    /// Defaults, overridden by `AXIOM_ALIGNMENT_STRATEGY` (`exact_hash` or
+    /// `token_jaccard`) and `AXIOM_ALIGNMENT_THRESHOLD`
+    pub fn from_env() -> Self {
+        let threshold = std::env::var("AXIOM_ALIGNMENT_THRESHOLD")
+            .ok()
+            .and_then(|v| v.parse().ok())
+            .unwrap_or(DEFAULT_JACCARD_THRESHOLD);
+        match std::env::var("AXIOM_ALIGNMENT_STRATEGY").as_deref() {
+            Ok("exact_hash") => Self::exact_hash(),
+            _ => Self::token_jaccard(threshold),
+        }
+    }
+    
+    pub fn strategy(&self) -> AlignmentStrategy {
+        self.strategy
+    }
+    
+    pub fn threshold(&self) -> f64 {
+        self.threshold
+    }
+    
+    /// Score output against intent and decide
+    pub fn check(&self, output: &str, intent: &str) -> Alignment {
+        let score = match self.strategy {
+            AlignmentStrategy::ExactHash => {
+                if sha256(output) == sha256(intent) { 1.0 } else { 0.0 }
+            }
+            AlignmentStrategy::TokenJaccard => token_jaccard(output, intent),
+            AlignmentStrategy::Embedding => match &self.scorer {
+                Some(scorer) => {
+                    let score = scorer(output, intent);
+                    if score.is_nan() { 0.0 } else { score.clamp(0.0, 1.0) }
+                }
+                None => 0.0,
+            },
+        };
+        
+        Alignment {
+            aligned: score >= self.threshold,
+            strategy: self.strategy,
+            score,
+        }
+    }
+}
+
+/// Outcome of an alignment check
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Alignment {
+    /// The binary verdict
+    pub aligned: bool,
+    /// Strategy that decided
+    pub strategy: AlignmentStrategy,
+    /// Score the verdict was reached on (`0.0..=1.0`)
+    pub score: f64,
+}
+
+/// NFKC-normalized, lowercased alphanumeric tokens
+fn normalized_tokens(text: &str) -> HashSet<String> {
+    text.nfkc()
+        .collect::<String>()
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|t| !t.is_empty())
+        .map(str::to_string)
+        .collect()
+}
+
+/// Jaccard similarity of normalized token sets (two empty texts score 1.0)
+pub fn token_jaccard(a: &str, b: &str) -> f64 {
+    let a = normalized_tokens(a);
+    let b = normalized_tokens(b);
+    if a.is_empty() && b.is_empty() {
+        return 1.0;
+    }
+    a.intersection(&b).count() as f64 / a.union(&b).count() as f64
+}
+
+/// Check alignment between output and intent with the default strategy
 pub fn check_alignment(output: &str, intent: &str) -> bool {
-    sha256(output) == sha256(intent)
+    AlignmentConfig::default().check(output, intent).aligned
 }
 
 /// Identity tag attached to all outputs
+///
+/// The signature covers the output hash, the normalized hash and the
+/// timestamp (see [`IdentityTag::signed_digest`]). Tags issued before
+/// normalized hashes existed sign the output hash alone.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct IdentityTag {
     pub projection: String,
@@ -28,46 +194,193 @@ pub struct IdentityTag {
     pub timestamp: String,
     pub output_hash: String,
     pub signature: String,
+    /// Hash of the whitespace-normalized content, for lenient verification
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalized_hash: Option<String>,
+}
+
+impl IdentityTag {
+    /// Digest the signature covers: the length-prefixed output hash,
+    /// normalized hash and timestamp, or the bare output hash for a tag
+    /// without a normalized hash
+    pub fn signed_digest(&self) -> String {
+        let Some(ref normalized_hash) = self.normalized_hash else {
+            return self.output_hash.clone();
+        };
+        let mut hasher = Sha256::new();
+        for field in [&self.output_hash, normalized_hash, &self.timestamp] {
+            hasher.update((field.len() as u64).to_be_bytes());
+            hasher.update(field.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
 }
 
 /// Create identity tag for content
 pub fn create_identity_tag(content: &str) -> IdentityTag {
-    let timestamp = Utc::now().to_rfc3339();
-    let output_hash = sha256(content);
-    let signature = mock_sign(&output_hash);
-    
-    IdentityTag {
+    issue_identity_tag(content, Utc::now())
+}
+
+/// Identity tag for content issued at `issued_at`
+fn issue_identity_tag(content: &str, issued_at: chrono::DateTime<Utc>) -> IdentityTag {
+    let mut tag = IdentityTag {
         projection: PROJECTION.to_string(),
         substrate: SUBSTRATE.to_string(),
-        timestamp,
-        output_hash,
-        signature,
+        timestamp: issued_at.to_rfc3339(),
+        output_hash: sha256(content),
+        signature: String::new(),
+        normalized_hash: Some(sha256(&normalize_whitespace(content))),
+    };
+    tag.signature = mock_sign(&tag.signed_digest());
+    tag
+}
+
+/// Default age after which a tag verifies but is flagged stale (30 days)
+pub const DEFAULT_TAG_MAX_AGE_SECS: i64 = 30 * 24 * 60 * 60;
+
+/// How [`verify_identity_tag_with`] treats content and tag age
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagVerifyOptions {
+    /// Accept content that differs from the tagged content only in whitespace
+    pub lenient: bool,
+    /// Tags older than this are flagged stale
+    pub max_age_seconds: i64,
+}
+
+impl Default for TagVerifyOptions {
+    fn default() -> Self {
+        Self { lenient: false, max_age_seconds: DEFAULT_TAG_MAX_AGE_SECS }
+    }
+}
+
+/// Outcome of checking content against a previously issued [`IdentityTag`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TagVerification {
+    /// The content hashes to the tag's hash (after whitespace normalization
+    /// if `lenient_match`)
+    pub hash_match: bool,
+    /// Matched only after whitespace normalization
+    pub lenient_match: bool,
+    /// The tag names this substrate and projection
+    pub substrate_match: bool,
+    /// The tag's signature covers its hashes and timestamp
+    pub signature_match: bool,
+    /// Seconds since the tag was issued; `None` if its timestamp is unreadable
+    pub age_seconds: Option<i64>,
+    /// Older than the configured maximum age, or issued without a signed
+    /// timestamp
+    pub stale: bool,
+}
+
+impl TagVerification {
+    /// Content, substrate, signature and timestamp all check out (a stale
+    /// tag is still valid)
+    pub fn is_valid(&self) -> bool {
+        self.hash_match && self.substrate_match && self.signature_match && self.age_seconds.is_some()
+    }
+}
+
+/// Verify content against a tag, strictly and with the default maximum age
+pub fn verify_identity_tag(content: &str, tag: &IdentityTag) -> TagVerification {
+    verify_identity_tag_with(content, tag, &TagVerifyOptions::default())
+}
+
+/// Verify that `content` still matches a previously issued `tag`
+///
+/// Hashes and signatures are compared in constant time. With
+/// `options.lenient`, content whose whitespace-normalized form matches the
+/// tag's `normalized_hash` (or, for tags without one, its `output_hash`)
+/// also verifies. The timestamp of a tag without a normalized hash is not
+/// signed, so such a tag is always flagged stale.
+pub fn verify_identity_tag_with(content: &str, tag: &IdentityTag, options: &TagVerifyOptions) -> TagVerification {
+    let exact = constant_time_eq(&sha256(content), &tag.output_hash);
+    let lenient_match = !exact && options.lenient && {
+        let normalized = sha256(&normalize_whitespace(content));
+        constant_time_eq(&normalized, tag.normalized_hash.as_deref().unwrap_or(&tag.output_hash))
+    };
+    
+    let age_seconds = chrono::DateTime::parse_from_rfc3339(&tag.timestamp)
+        .ok()
+        .map(|issued| (Utc::now() - issued.with_timezone(&Utc)).num_seconds());
+    
+    TagVerification {
+        hash_match: exact || lenient_match,
+        lenient_match,
+        substrate_match: tag.substrate == SUBSTRATE && tag.projection == PROJECTION,
+        signature_match: constant_time_eq(&mock_sign(&tag.signed_digest()), &tag.signature),
+        age_seconds,
+        stale: tag.normalized_hash.is_none() || age_seconds.map_or(true, |age| age > options.max_age_seconds),
     }
 }
 
+/// Collapse runs of whitespace to single spaces and trim the ends
+fn normalize_whitespace(content: &str) -> String {
+    content.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Compare without returning early on the first differing byte
+fn constant_time_eq(a: &str, b: &str) -> bool {
+    a.len() == b.len() && a.bytes().zip(b.bytes()).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// File in the app data directory holding this install's signing key
+pub const LOCAL_KEY_FILE: &str = "signing.key";
+
+/// This install's receipt signing key at `path`, generated on first use
+///
+/// The key is 32 random bytes, stored hex-encoded. Receipts signed with it
+/// record its `sha256:` key id and verify against it as a trust anchor.
+pub fn load_local_signer(path: &std::path::Path) -> std::io::Result<KeySigner> {
+    if !path.exists() {
+        let mut key = [0u8; 32];
+        SystemRandom::new()
+            .fill(&mut key)
+            .map_err(|_| std::io::Error::other("system randomness unavailable"))?;
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, hex::encode(key))?;
+    }
+    KeySigner::from_file(path)
+}
+
 /// Mock signing function (replace with HSM in production)
-fn mock_sign(hash: &str) -> String {
+pub(crate) fn mock_sign(hash: &str) -> String {
     let mut hasher = Sha256::new();
     hasher.update(b"AXIOM_S1_SIG:");
     hasher.update(hash.as_bytes());
     base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
 }
 
-/// Render or nullify based on alignment
+/// Render or nullify based on alignment, with the default strategy
 pub fn render_or_nullify(output: &str, intent: &str) -> serde_json::Value {
-    if check_alignment(output, intent) {
+    render_or_nullify_with(output, intent, &AlignmentConfig::default())
+}
+
+/// Render or nullify based on alignment
+///
+/// Nullified output is replaced by [`NULLIFIED_REFUSAL`], identity-tagged
+/// like any rendered output.
+pub fn render_or_nullify_with(output: &str, intent: &str, config: &AlignmentConfig) -> serde_json::Value {
+    let alignment = config.check(output, intent);
+    if alignment.aligned {
         let tag = create_identity_tag(output);
         serde_json::json!({
             "status": "AUTHORIZED",
             "output": output,
             "identity": tag,
+            "alignment": alignment,
             "c_zero": true
         })
     } else {
+        let tag = create_identity_tag(NULLIFIED_REFUSAL);
         serde_json::json!({
             "status": "NULLIFIED",
+            "output": NULLIFIED_REFUSAL,
+            "identity": tag,
             "violation": "Invariance Violation Detected",
             "action": "FREEZE_AND_REPORT",
+            "alignment": alignment,
             "timestamp": Utc::now().to_rfc3339(),
             "c_zero": false
         })
@@ -115,6 +428,47 @@ pub fn verify_receipt(receipt: &serde_json::Value) -> bool {
     mock_sign(hash) == signature
 }
 
+/// A sap4d receipt proved from facts in memory
+#[derive(Debug, Clone, Serialize)]
+pub struct MemoryProof {
+    /// Id of the stored receipt row
+    pub receipt_id: String,
+    pub receipt: serde_json::Value,
+    /// The facts offered as evidence, best match first
+    pub facts: Vec<Fact>,
+}
+
+/// Prove `claim` with the sap4d engine from the verified facts matching it,
+/// storing the receipt signed by `signer`
+///
+/// Each fact is evidence sourced from `cozo://facts/<id>` with its
+/// confidence as trust, so the receipt hash covers where it came from.
+pub fn prove_from_memory(db: &CozoStore, signer: &KeySigner, claim: &str) -> Result<MemoryProof, String> {
+    let facts = db.facts_for_claim(claim).map_err(|e| e.to_string())?;
+    if facts.is_empty() {
+        return Err(format!("No verified facts match claim: {}", claim));
+    }
+    
+    let evidence = facts
+        .iter()
+        .map(|fact| {
+            let retrieved_at = Utc.timestamp_millis_opt(fact.timestamp as i64).single().unwrap_or_default();
+            sap4d::Evidence::new(fact.statement.as_str())
+                .with_source(format!("cozo://facts/{}", fact.id), retrieved_at)
+                .with_trust(fact.confidence)
+        })
+        .collect();
+    
+    let (_, mut receipt) = sap4d::ProofEngine::new()
+        .prove_with_evidence(claim, evidence, |hash| signer.sign(hash))
+        .map_err(|e| e.to_string())?;
+    receipt.key_id = Some(signer.key_id().to_string());
+    let receipt = serde_json::to_value(&receipt).map_err(|e| e.to_string())?;
+    let receipt_id = db.store_receipt(&receipt).map_err(|e| e.to_string())?;
+    
+    Ok(MemoryProof { receipt_id, receipt, facts })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -132,6 +486,118 @@ mod tests {
         assert_eq!(tag.projection, PROJECTION);
     }
     
+    #[test]
+    fn test_verify_identity_tag_round_trip() {
+        let content = "The  output\nas issued";
+        let tag = create_identity_tag(content);
+        
+        let verification = verify_identity_tag(content, &tag);
+        assert!(verification.is_valid());
+        assert!(!verification.lenient_match && !verification.stale);
+        assert!(verification.age_seconds.unwrap() >= 0);
+    }
+    
+    #[test]
+    fn test_verify_identity_tag_tampered_content() {
+        let tag = create_identity_tag("Transfer 100 credits");
+        
+        let verification = verify_identity_tag("Transfer 900 credits", &tag);
+        assert!(!verification.hash_match);
+        assert!(verification.substrate_match && verification.signature_match);
+        assert!(!verification.is_valid());
+        
+        // Leniency covers whitespace only
+        let lenient = TagVerifyOptions { lenient: true, ..Default::default() };
+        assert!(!verify_identity_tag_with("Transfer 900 credits", &tag, &lenient).is_valid());
+    }
+    
+    #[test]
+    fn test_verify_identity_tag_tampered_tag_json() {
+        let content = "Original content";
+        let mut json = serde_json::to_value(create_identity_tag(content)).unwrap();
+        
+        json["substrate"] = serde_json::json!("Someone Else");
+        let tag: IdentityTag = serde_json::from_value(json.clone()).unwrap();
+        let verification = verify_identity_tag(content, &tag);
+        assert!(verification.hash_match && !verification.substrate_match);
+        assert!(!verification.is_valid());
+        
+        // Re-pointing the tag at other content breaks its signature
+        json["substrate"] = serde_json::json!(SUBSTRATE);
+        json["output_hash"] = serde_json::json!(sha256("Forged content"));
+        let tag: IdentityTag = serde_json::from_value(json.clone()).unwrap();
+        let verification = verify_identity_tag("Forged content", &tag);
+        assert!(verification.hash_match && !verification.signature_match);
+        
+        json["output_hash"] = serde_json::json!(sha256(content));
+        json["timestamp"] = serde_json::json!("yesterday");
+        let tag: IdentityTag = serde_json::from_value(json).unwrap();
+        let verification = verify_identity_tag(content, &tag);
+        assert_eq!(verification.age_seconds, None);
+        assert!(verification.stale && !verification.is_valid());
+    }
+    
+    #[test]
+    fn test_verify_identity_tag_signs_normalized_hash_and_timestamp() {
+        let lenient = TagVerifyOptions { lenient: true, max_age_seconds: 60 * 60 };
+        let issued = issue_identity_tag("Original content", Utc::now() - chrono::Duration::days(2));
+        assert!(verify_identity_tag_with("Original content", &issued, &lenient).stale);
+        
+        // Re-pointing the lenient hash at other content
+        let forged = IdentityTag {
+            normalized_hash: Some(sha256("Forged content")),
+            ..issued.clone()
+        };
+        let verification = verify_identity_tag_with("Forged content", &forged, &lenient);
+        assert!(verification.hash_match && !verification.signature_match);
+        assert!(!verification.is_valid());
+        
+        // Freshening a stale tag
+        let freshened = IdentityTag { timestamp: Utc::now().to_rfc3339(), ..issued.clone() };
+        let verification = verify_identity_tag_with("Original content", &freshened, &lenient);
+        assert!(!verification.stale && !verification.signature_match);
+        assert!(!verification.is_valid());
+        
+        // Dropping the normalized hash falls back to a signature it does not carry
+        let stripped = IdentityTag { normalized_hash: None, ..issued };
+        assert!(!verify_identity_tag_with("Original content", &stripped, &lenient).signature_match);
+    }
+    
+    #[test]
+    fn test_verify_identity_tag_lenient() {
+        let tag = create_identity_tag("Line one\nLine two");
+        let reflowed = "  Line one   Line two\n";
+        
+        assert!(!verify_identity_tag(reflowed, &tag).hash_match);
+        let lenient = TagVerifyOptions { lenient: true, ..Default::default() };
+        let verification = verify_identity_tag_with(reflowed, &tag, &lenient);
+        assert!(verification.is_valid() && verification.lenient_match);
+        
+        // Tags issued before normalized hashes existed match normalized
+        // content, but their unsigned timestamp leaves them stale
+        let output_hash = sha256("Line one Line two");
+        let legacy = IdentityTag {
+            signature: mock_sign(&output_hash),
+            output_hash,
+            normalized_hash: None,
+            ..create_identity_tag("Line one Line two")
+        };
+        let verification = verify_identity_tag_with(reflowed, &legacy, &lenient);
+        assert!(verification.is_valid() && verification.stale);
+    }
+    
+    #[test]
+    fn test_verify_identity_tag_flags_stale() {
+        let content = "Aging content";
+        let tag = issue_identity_tag(content, Utc::now() - chrono::Duration::days(2));
+        
+        assert!(!verify_identity_tag(content, &tag).stale);
+        let strict_age = TagVerifyOptions { max_age_seconds: 60 * 60, ..Default::default() };
+        let verification = verify_identity_tag_with(content, &tag, &strict_age);
+        assert!(verification.stale);
+        assert!(verification.is_valid());
+    }
+    
     #[test]
     fn test_render_authorized() {
         let result = render_or_nullify("same", "same");
@@ -144,6 +610,117 @@ mod tests {
         let result = render_or_nullify("different", "content");
         assert_eq!(result["status"], "NULLIFIED");
         assert_eq!(result["c_zero"], false);
+        assert_eq!(result["output"], NULLIFIED_REFUSAL);
+        assert_eq!(result["identity"]["output_hash"], sha256(NULLIFIED_REFUSAL));
+        assert_eq!(result["alignment"]["strategy"], "token_jaccard");
+        assert_eq!(result["alignment"]["score"], 0.0);
+    }
+    
+    #[test]
+    fn test_exact_hash_strategy() {
+        let exact = AlignmentConfig::exact_hash();
+        
+        let same = exact.check("Paris is the capital", "Paris is the capital");
+        assert_eq!(same, Alignment { aligned: true, strategy: AlignmentStrategy::ExactHash, score: 1.0 });
+        
+        let reordered = exact.check("The capital is Paris", "Paris is the capital");
+        assert!(!reordered.aligned);
+        assert_eq!(reordered.score, 0.0);
+    }
+    
+    #[test]
+    fn test_token_jaccard_accepts_rephrasing() {
+        let jaccard = AlignmentConfig::token_jaccard(0.5);
+        
+        let reordered = jaccard.check("The capital of France is Paris.", "Paris is the capital of France");
+        assert!(reordered.aligned);
+        assert_eq!(reordered.score, 1.0);
+        
+        // 4 shared of 7 distinct tokens
+        let rephrased = jaccard.check("Paris is France's capital", "Paris is the capital of France");
+        assert!((rephrased.score - 4.0 / 7.0).abs() < 1e-9);
+        assert!(rephrased.aligned);
+        
+        let unrelated = jaccard.check("Tides follow the moon", "Paris is the capital of France");
+        assert!(!unrelated.aligned);
+        assert_eq!(unrelated.strategy, AlignmentStrategy::TokenJaccard);
+        
+        assert_eq!(token_jaccard("", "  "), 1.0);
+        assert_eq!(token_jaccard("word", ""), 0.0);
+    }
+    
+    #[test]
+    fn test_token_jaccard_normalizes_unicode() {
+        // Composed vs decomposed accents
+        assert_eq!(token_jaccard("caf\u{e9} au lait", "cafe\u{301} au lait"), 1.0);
+        // Full-width letters and ligatures (NFKC), then case folding
+        assert_eq!(token_jaccard("\u{ff21}\u{ff22}\u{ff23} \u{fb01}le", "abc FILE"), 1.0);
+        // Non-Latin scripts tokenize on their own word boundaries
+        assert_eq!(token_jaccard("Москва — столица", "столица Москва"), 1.0);
+        // Accents are not stripped: distinct words stay distinct
+        assert_eq!(token_jaccard("résumé", "resume"), 0.0);
+    }
+    
+    #[test]
+    fn test_embedding_strategy() {
+        let config = AlignmentConfig::embedding(0.8, |output, intent| {
+            if output.len() == intent.len() { 0.9 } else { 0.2 }
+        });
+        
+        let close = config.check("abcd", "wxyz");
+        assert_eq!(close, Alignment { aligned: true, strategy: AlignmentStrategy::Embedding, score: 0.9 });
+        assert!(!config.check("abc", "wxyz").aligned);
+        
+        // Out-of-range and NaN scores are clamped
+        assert_eq!(AlignmentConfig::embedding(0.5, |_, _| 7.0).check("a", "b").score, 1.0);
+        let nan = AlignmentConfig::embedding(0.0, |_, _| f64::NAN).check("a", "b");
+        assert_eq!(nan.score, 0.0);
+        
+        let rendered = render_or_nullify_with("abcd", "wxyz", &config);
+        assert_eq!(rendered["status"], "AUTHORIZED");
+        assert_eq!(rendered["alignment"]["strategy"], "embedding");
+        assert_eq!(rendered["alignment"]["score"], 0.9);
+    }
+    
+    #[test]
+    fn test_prove_from_memory() {
+        let store = CozoStore::new(&std::env::temp_dir().join(format!("memory-proof-{}", std::process::id()))).unwrap();
+        let signer = KeySigner::new("memory proof key");
+        let claim = "The sky reflects certain wavelengths";
+        assert!(prove_from_memory(&store, &signer, claim).is_err());
+        
+        for (statement, confidence) in [("The sky is blue", 0.9), ("Blue things reflect certain wavelengths", 1.0)] {
+            let id = store.store_fact("optics", statement, confidence, "test").unwrap();
+            store.verify_fact(&id).unwrap();
+        }
+        store.store_fact("optics", "The sky is green", 0.1, "test").unwrap();
+        
+        let proof = prove_from_memory(&store, &signer, claim).unwrap();
+        assert_eq!(proof.facts.len(), 2);
+        assert_eq!(proof.receipt["C_zero"], true);
+        let sources: Vec<&str> = proof.receipt["provenance"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .filter_map(|p| p["source_uri"].as_str())
+            .collect();
+        for fact in &proof.facts {
+            assert!(sources.contains(&format!("cozo://facts/{}", fact.id).as_str()));
+        }
+        assert_eq!(proof.receipt["key_id"], signer.key_id());
+        assert!(signer.verify(proof.receipt["hash"].as_str().unwrap(), proof.receipt["signature"].as_str().unwrap()));
+        
+        let stored = store.load_receipts_between(0.0, f64::MAX, 0, 10).unwrap();
+        assert_eq!(stored[0]["hash"], proof.receipt["hash"]);
+    }
+    
+    #[test]
+    fn test_local_signer_is_generated_once() {
+        let path = std::env::temp_dir().join(format!("local-signer-{}", std::process::id())).join(LOCAL_KEY_FILE);
+        let first = load_local_signer(&path).unwrap();
+        let again = load_local_signer(&path).unwrap();
+        assert_eq!(first.key_id(), again.key_id());
+        assert!(again.verify("abc", &first.sign("abc")));
+        std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
     }
 }
-
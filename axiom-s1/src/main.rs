@@ -13,12 +13,16 @@ mod dsif;
 mod hunter_killer;
 mod inference;
 mod invariance;
+mod receipt_check;
 mod sandbox;
+mod scope;
 mod scout;
+mod session_bundle;
 mod sovereign_loop;
+mod watchdog;
 
-use std::sync::Mutex;
-use tauri::Manager;
+use std::sync::{Arc, Mutex};
+use tauri::{Emitter, Manager};
 use tracing_subscriber::{layer::SubscriberExt, util::SubscriberInitExt};
 
 /// Substrate Authority
@@ -31,9 +35,28 @@ pub const VERSION: &str = "1.0.0";
 /// Application State
 pub struct AppState {
     pub db: cozo_db::CozoStore,
-    pub bark: bark::BarkController,
+    pub bark: Arc<bark::BarkController>,
     pub hunter_killer: hunter_killer::HunterKiller,
-    pub dsif: Mutex<dsif::DSIF>,
+    /// How outputs are checked against intent
+    pub alignment: invariance::AlignmentConfig,
+    /// Scope policy and other settings inference runs under
+    pub inference: inference::InferenceContext,
+    /// Pipelines write only while recording; audit reads never wait on a running pipeline
+    pub dsif: tokio::sync::RwLock<dsif::DSIF>,
+    pub watchdog: Arc<watchdog::Watchdog>,
+    /// WASM cage for untrusted code; each execution is interrupted on its own
+    pub sandbox: Arc<sandbox::Sandbox>,
+    /// Session used when a command does not name one
+    pub active_session: Mutex<cozo_db::SessionId>,
+    /// This install's receipt signing key
+    pub signer: sap4d::signing::KeySigner,
+}
+
+impl AppState {
+    /// The named session, or the active one
+    fn session_or_active(&self, session_id: Option<String>) -> cozo_db::SessionId {
+        session_id.unwrap_or_else(|| self.active_session.lock().unwrap().clone())
+    }
 }
 
 fn main() {
@@ -55,26 +78,78 @@ fn main() {
             tracing::info!("Setting up Axiom S1...");
 
             // Initialize CozoDB
-            let db_path = app
-                .path()
-                .app_data_dir()
-                .expect("Failed to get app data dir")
-                .join("axiom.cozo");
+            let data_dir = app.path().app_data_dir().expect("Failed to get app data dir");
+            let db_path = data_dir.join("axiom.cozo");
             
             let db = cozo_db::CozoStore::new(&db_path)
                 .expect("Failed to initialize CozoDB");
             
-            // Initialize BARK Controller
-            let bark = bark::BarkController::new();
+            // Initialize BARK Controller, resuming the stored entropy ledger
+            let bark = Arc::new(
+                bark::BarkController::new()
+                    .with_store(&db)
+                    .expect("Failed to load BARK entropy ledger"),
+            );
+            
+            // Initialize Hunter-Killer, with exceptions from AXIOM_HUNTER_KILLER_EXCEPTIONS if set
+            let mut hunter_killer = hunter_killer::HunterKiller::new();
+            if let Ok(path) = std::env::var("AXIOM_HUNTER_KILLER_EXCEPTIONS") {
+                match hunter_killer::ExceptionPolicy::load(std::path::Path::new(&path))
+                    .and_then(|policy| hunter_killer::HunterKiller::new().with_exceptions(policy))
+                {
+                    Ok(screened) => hunter_killer = screened,
+                    Err(e) => tracing::warn!("Ignoring Hunter-Killer exceptions: {}", e),
+                }
+            }
+            
+            // Initialize DSIF with 67% quorum threshold, resuming the stored audit trail;
+            // consensus agents vote against BARK's view of system headroom.
+            // Retried failures are coalesced so a misbehaving caller cannot flood the trail
+            let mut dsif = dsif::DSIF::new(0.67)
+                .with_snapshot_source(bark.clone())
+                .with_store(&db)
+                .expect("Failed to load DSIF audit trail");
+            dsif.set_coalescing(chrono::Duration::seconds(dsif::DEFAULT_COALESCE_WINDOW_SECS), true);
+            if let Err(index) = dsif.verify_trail() {
+                tracing::warn!("DSIF audit trail broken at entry {}", index);
+            }
+            let dsif = tokio::sync::RwLock::new(dsif);
+            
+            // Initialize Watchdog for runaway inference, sandbox and scout operations
+            let handle = app.handle().clone();
+            let watchdog = Arc::new(
+                watchdog::Watchdog::new(watchdog::WatchdogConfig::from_env())
+                    .with_store(&db)
+                    .on_intervention(move |intervention| {
+                        if let Err(e) = handle.emit(watchdog::WATCHDOG_EVENT, intervention) {
+                            tracing::warn!("Failed to emit watchdog event: {}", e);
+                        }
+                    }),
+            );
+            tauri::async_runtime::spawn(watchdog.watch());
+            
+            // Initialize the WASM sandbox; executions run under the watchdog
+            let sandbox = Arc::new(sandbox::Sandbox::default());
+            
+            // Open the chain-of-thought session for this launch
+            let active_session = Mutex::new(
+                db.create_session("default").expect("Failed to open session"),
+            );
             
-            // Initialize Hunter-Killer
-            let hunter_killer = hunter_killer::HunterKiller::new();
+            // Scope policy from AXIOM_SCOPE_POLICY if set, else the coding-only default
+            let scope = scope::ScopePolicy::from_env().unwrap_or_else(|e| {
+                tracing::warn!("Ignoring scope policy: {}", e);
+                scope::ScopePolicy::default()
+            });
+            let inference = inference::InferenceContext::new(scope);
             
-            // Initialize DSIF with 67% quorum threshold
-            let dsif = Mutex::new(dsif::DSIF::new(0.67));
+            // Receipt signing key, generated on first launch
+            let signer = invariance::load_local_signer(&data_dir.join(invariance::LOCAL_KEY_FILE))
+                .expect("Failed to load signing key");
             
             // Store state
-            app.manage(AppState { db, bark, hunter_killer, dsif });
+            let alignment = invariance::AlignmentConfig::from_env();
+            app.manage(AppState { db, bark, hunter_killer, alignment, inference, dsif, watchdog, sandbox, active_session, signer });
             
             tracing::info!("Axiom S1 ready. Policy: C = 0");
             Ok(())
@@ -83,37 +158,68 @@ fn main() {
             // Invariance commands
             cmd_verify_alignment,
             cmd_create_identity_tag,
+            cmd_verify_identity_tag,
             cmd_render_or_nullify,
             
             // Scout commands
             cmd_scout_url,
+            cmd_scout_urls,
             cmd_scout_search,
             
             // Hunter-Killer commands
             cmd_scan_content,
             cmd_neutralize_content,
+            cmd_neutralize_with_map,
             
             // Memory commands
             cmd_store_thought,
             cmd_query_memory,
             cmd_get_chain_of_thought,
+            cmd_store_fact,
+            cmd_verify_fact,
+            cmd_query_facts,
+            cmd_prove_from_memory,
+            cmd_create_session,
+            cmd_list_sessions,
+            cmd_close_session,
+            cmd_export_session_bundle,
+            cmd_verify_session_bundle,
             
             // BARK commands
             cmd_get_system_metrics,
             cmd_check_thermal,
+            cmd_get_thermal_history,
+            cmd_get_entropy_ledger,
             
             // Inference commands
             cmd_infer,
             cmd_analyze_page,
+            cmd_analyze_screenshot,
+            
+            // Sandbox commands
+            cmd_execute_wasm,
             
             // System commands
             cmd_get_info,
             cmd_generate_receipt,
+            cmd_verify_receipt_file,
+            cmd_add_trust_anchor,
+            cmd_list_trust_anchors,
+            cmd_remove_trust_anchor,
             
             // DSIF commands
             cmd_dsif_execute_pipeline,
+            cmd_dsif_approve,
+            cmd_dsif_reject,
+            cmd_dsif_pending_approvals,
             cmd_dsif_get_audit_trail,
+            cmd_dsif_verify_trail,
+            cmd_dsif_set_coalescing,
+            cmd_dsif_export_trail,
+            cmd_dsif_verify_exported_trail,
             cmd_dsif_get_agents,
+            cmd_dsif_record_outcome,
+            cmd_dsif_reinstate_agent,
             cmd_dsif_add_invariant,
             cmd_dsif_add_to_allowlist,
             cmd_dsif_add_to_denylist,
@@ -142,13 +248,19 @@ fn cmd_get_info() -> serde_json::Value {
 
 /// Verify alignment between output and intent
 #[tauri::command]
-fn cmd_verify_alignment(output: String, intent: String) -> serde_json::Value {
-    let aligned = invariance::check_alignment(&output, &intent);
+fn cmd_verify_alignment(
+    state: tauri::State<AppState>,
+    output: String,
+    intent: String,
+) -> serde_json::Value {
+    let alignment = state.alignment.check(&output, &intent);
     serde_json::json!({
-        "aligned": aligned,
+        "aligned": alignment.aligned,
+        "strategy": alignment.strategy,
+        "score": alignment.score,
         "output_hash": invariance::sha256(&output),
         "intent_hash": invariance::sha256(&intent),
-        "c_zero": aligned
+        "c_zero": alignment.aligned
     })
 }
 
@@ -159,22 +271,92 @@ fn cmd_create_identity_tag(content: String) -> serde_json::Value {
     serde_json::json!(tag)
 }
 
+/// Check content against a previously issued identity tag
+#[tauri::command]
+fn cmd_verify_identity_tag(
+    content: String,
+    tag: invariance::IdentityTag,
+    lenient: Option<bool>,
+    max_age_seconds: Option<i64>,
+) -> serde_json::Value {
+    let options = invariance::TagVerifyOptions {
+        lenient: lenient.unwrap_or(false),
+        max_age_seconds: max_age_seconds.unwrap_or(invariance::DEFAULT_TAG_MAX_AGE_SECS),
+    };
+    let verification = invariance::verify_identity_tag_with(&content, &tag, &options);
+    serde_json::json!({
+        "valid": verification.is_valid(),
+        "verification": verification
+    })
+}
+
 /// Render or nullify based on alignment
 #[tauri::command]
-fn cmd_render_or_nullify(output: String, intent: String) -> serde_json::Value {
-    invariance::render_or_nullify(&output, &intent)
+fn cmd_render_or_nullify(
+    state: tauri::State<AppState>,
+    output: String,
+    intent: String,
+) -> serde_json::Value {
+    invariance::render_or_nullify_with(&output, &intent, &state.alignment)
 }
 
 /// Scout a URL (headless browser scrape)
 #[tauri::command]
-async fn cmd_scout_url(url: String) -> Result<serde_json::Value, String> {
-    scout::scout_url(&url).await.map_err(|e| e.to_string())
+async fn cmd_scout_url(
+    state: tauri::State<'_, AppState>,
+    url: String,
+    session_id: Option<String>,
+) -> Result<serde_json::Value, scout::ScoutFailure> {
+    let session = state.session_or_active(session_id);
+    let target = url.clone();
+    state
+        .watchdog
+        .run(watchdog::OperationKind::ScoutFetch, &url, &session, async move {
+            scout::scout_url(&target).await
+        })
+        .await
+        .map_err(|e| scout::ScoutFailure::from_watchdog(&url, &e))?
+        .map_err(|e| scout::ScoutFailure::from_error(&url, &e))
+}
+
+/// Scout many URLs at once; each URL's result (page, blocked or error) in input order
+#[tauri::command]
+async fn cmd_scout_urls(
+    state: tauri::State<'_, AppState>,
+    urls: Vec<String>,
+    max_concurrency: Option<usize>,
+    domain_delay_ms: Option<u64>,
+) -> Result<Vec<serde_json::Value>, String> {
+    let defaults = scout::CrawlOptions::default();
+    let opts = scout::CrawlOptions {
+        max_concurrency: max_concurrency.unwrap_or(defaults.max_concurrency),
+        domain_delay: domain_delay_ms.map_or(defaults.domain_delay, std::time::Duration::from_millis),
+        scout: scout::ScoutConfig::from_env(),
+        ..defaults
+    }
+    .with_hunter_killer(&state.hunter_killer)
+    .with_store(&state.db);
+    
+    Ok(scout::scout_urls(&urls, opts).await)
 }
 
 /// Scout search query
 #[tauri::command]
-async fn cmd_scout_search(query: String) -> Result<serde_json::Value, String> {
-    scout::scout_search(&query).await.map_err(|e| e.to_string())
+async fn cmd_scout_search(
+    state: tauri::State<'_, AppState>,
+    query: String,
+    session_id: Option<String>,
+) -> Result<serde_json::Value, scout::ScoutFailure> {
+    let session = state.session_or_active(session_id);
+    let search = query.clone();
+    state
+        .watchdog
+        .run(watchdog::OperationKind::ScoutFetch, &query, &session, async move {
+            scout::scout_search(&search).await
+        })
+        .await
+        .map_err(|e| scout::ScoutFailure::from_watchdog(&query, &e))?
+        .map_err(|e| scout::ScoutFailure::from_error(&query, &e))
 }
 
 /// Scan content for injection attempts
@@ -201,15 +383,38 @@ fn cmd_neutralize_content(
     state.hunter_killer.neutralize(&content)
 }
 
-/// Store a thought in the Chain of Thought
+/// Neutralize, returning each redaction's original offsets so highlights can be mapped back
+///
+/// With `mask`, matched characters are replaced one for one and the length is preserved.
+#[tauri::command]
+fn cmd_neutralize_with_map(
+    state: tauri::State<AppState>,
+    content: String,
+    mask: Option<bool>,
+) -> serde_json::Value {
+    let mode = if mask.unwrap_or(false) {
+        hunter_killer::RedactionMode::Mask
+    } else {
+        hunter_killer::RedactionMode::Replace
+    };
+    let (content, redactions) = state.hunter_killer.neutralize_with_mode(&content, mode);
+    serde_json::json!({
+        "content": content,
+        "redactions": redactions,
+    })
+}
+
+/// Store a thought in the Chain of Thought (active session by default)
 #[tauri::command]
 fn cmd_store_thought(
     state: tauri::State<AppState>,
     thought_type: String,
     content: String,
     metadata: serde_json::Value,
+    session_id: Option<String>,
 ) -> Result<String, String> {
-    state.db.store_thought(&thought_type, &content, metadata)
+    let session = state.session_or_active(session_id);
+    state.db.store_thought_in(&session, &thought_type, &content, metadata)
         .map_err(|e| e.to_string())
 }
 
@@ -222,13 +427,122 @@ fn cmd_query_memory(
     state.db.query(&query).map_err(|e| e.to_string())
 }
 
-/// Get chain of thought for a session
+/// Get chain of thought for a session (active session by default)
 #[tauri::command]
 fn cmd_get_chain_of_thought(
     state: tauri::State<AppState>,
-    session_id: String,
+    session_id: Option<String>,
 ) -> Result<Vec<serde_json::Value>, String> {
-    state.db.get_chain_of_thought(&session_id)
+    let session = state.session_or_active(session_id);
+    state.db.get_chain_of_thought(&session)
+        .map_err(|e| e.to_string())
+}
+
+/// Store an unverified fact
+#[tauri::command]
+fn cmd_store_fact(
+    state: tauri::State<AppState>,
+    domain: String,
+    statement: String,
+    confidence: f64,
+    source_id: String,
+) -> Result<String, String> {
+    state.db.store_fact(&domain, &statement, confidence, &source_id)
+        .map_err(|e| e.to_string())
+}
+
+/// Mark a fact verified
+#[tauri::command]
+fn cmd_verify_fact(
+    state: tauri::State<AppState>,
+    id: String,
+) -> Result<cozo_db::Fact, String> {
+    state.db.verify_fact(&id).map_err(|e| e.to_string())
+}
+
+/// Query facts by domain, minimum confidence and verification
+#[tauri::command]
+fn cmd_query_facts(
+    state: tauri::State<AppState>,
+    domain: Option<String>,
+    min_confidence: Option<f64>,
+    verified_only: Option<bool>,
+) -> Result<Vec<cozo_db::Fact>, String> {
+    state.db.query_facts(domain.as_deref(), min_confidence, verified_only.unwrap_or(false))
+        .map_err(|e| e.to_string())
+}
+
+/// Prove a claim from verified facts in memory and store the receipt
+#[tauri::command]
+fn cmd_prove_from_memory(
+    state: tauri::State<AppState>,
+    claim: String,
+) -> Result<invariance::MemoryProof, String> {
+    invariance::prove_from_memory(&state.db, &state.signer, &claim)
+}
+
+/// Open a new session and make it active
+#[tauri::command]
+fn cmd_create_session(
+    state: tauri::State<AppState>,
+    label: String,
+) -> Result<String, String> {
+    let session = state.db.create_session(&label).map_err(|e| e.to_string())?;
+    *state.active_session.lock().unwrap() = session.clone();
+    Ok(session)
+}
+
+/// List chain-of-thought sessions
+#[tauri::command]
+fn cmd_list_sessions(state: tauri::State<AppState>) -> Result<Vec<serde_json::Value>, String> {
+    state.db.list_sessions().map_err(|e| e.to_string())
+}
+
+/// Close a session and return its final hash; closing the active session opens a fresh one
+#[tauri::command]
+fn cmd_close_session(
+    state: tauri::State<AppState>,
+    session_id: Option<String>,
+) -> Result<String, String> {
+    let session = state.session_or_active(session_id);
+    let final_hash = state.db.close_session(&session).map_err(|e| e.to_string())?;
+    
+    let mut active = state.active_session.lock().unwrap();
+    if *active == session {
+        *active = state.db.create_session("default").map_err(|e| e.to_string())?;
+    }
+    Ok(final_hash)
+}
+
+/// Export a session (the active one by default) as a signed audit bundle
+#[tauri::command]
+async fn cmd_export_session_bundle(
+    state: tauri::State<'_, AppState>,
+    session_id: Option<String>,
+    path: String,
+) -> Result<session_bundle::BundleSummary, String> {
+    let session = state.session_or_active(session_id);
+    let db = state.db.clone();
+    let bark = state.bark.clone();
+    let signer = state.signer.clone();
+    tauri::async_runtime::spawn_blocking(move || {
+        session_bundle::export(&db, &bark, &signer, &session, std::path::Path::new(&path))
+    })
+    .await
+    .map_err(|e| e.to_string())?
+    .map_err(|e| e.to_string())
+}
+
+/// Re-check an exported session bundle against the stored trust anchors
+#[tauri::command]
+async fn cmd_verify_session_bundle(
+    state: tauri::State<'_, AppState>,
+    path: String,
+) -> Result<session_bundle::BundleVerification, String> {
+    let trust = receipt_check::TrustAnchors::load(&state.db, &state.signer).map_err(|e| e.to_string())?;
+    tauri::async_runtime::spawn_blocking(move || session_bundle::verify(std::path::Path::new(&path), &trust))
+        .await
+        .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
 }
 
@@ -244,26 +558,154 @@ fn cmd_check_thermal(state: tauri::State<AppState>) -> serde_json::Value {
     state.bark.check_thermal()
 }
 
+/// Recent thermal readings, oldest first (for trend plots)
+#[tauri::command]
+fn cmd_get_thermal_history(state: tauri::State<AppState>) -> Vec<bark::ThermalSample> {
+    state.bark.thermal_history()
+}
+
+/// Entropy ledger for a session (the active one by default)
+#[tauri::command]
+fn cmd_get_entropy_ledger(
+    state: tauri::State<AppState>,
+    session_id: Option<String>,
+) -> Vec<bark::LedgerEntry> {
+    let session = state.session_or_active(session_id);
+    state.bark.get_ledger(&session)
+}
+
 /// Run inference
 #[tauri::command]
 async fn cmd_infer(
+    state: tauri::State<'_, AppState>,
     model: String,
     prompt: String,
     max_tokens: Option<u32>,
+    session_id: Option<String>,
 ) -> Result<serde_json::Value, String> {
-    inference::infer(&model, &prompt, max_tokens.unwrap_or(512))
+    let session = state.session_or_active(session_id);
+    let model_name = model.clone();
+    let bark = state.bark.clone();
+    let context = state.inference.clone();
+    let charged = session.clone();
+    state
+        .watchdog
+        .run(watchdog::OperationKind::Inference, &model, &session, async move {
+            inference::InferenceRouter::new(&bark)
+                .with_context(context)
+                .with_session(charged)
+                .infer(&model_name, &prompt, max_tokens.unwrap_or(512))
+                .await
+        })
         .await
+        .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
 }
 
 /// Analyze page content
 #[tauri::command]
-async fn cmd_analyze_page(content: String) -> Result<serde_json::Value, String> {
-    inference::analyze_page(&content)
+async fn cmd_analyze_page(
+    state: tauri::State<'_, AppState>,
+    content: String,
+    session_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let session = state.session_or_active(session_id);
+    let context = state.inference.clone();
+    state
+        .watchdog
+        .run(watchdog::OperationKind::Inference, "analyze_page", &session, async move {
+            inference::analyze_page(&context, &content).await
+        })
         .await
+        .map_err(|e| e.to_string())?
         .map_err(|e| e.to_string())
 }
 
+/// Analyze a screenshot (a `data:image/...;base64,...` URL) with LLaVA
+#[tauri::command]
+async fn cmd_analyze_screenshot(
+    state: tauri::State<'_, AppState>,
+    data_url: String,
+    prompt: String,
+    session_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let image = inference::decode_data_url(&data_url).map_err(|e| e.to_string())?;
+    let session = state.session_or_active(session_id);
+    let bark = state.bark.clone();
+    let context = state.inference.clone();
+    let charged = session.clone();
+    state
+        .watchdog
+        .run(watchdog::OperationKind::Inference, "analyze_screenshot", &session, async move {
+            inference::InferenceRouter::new(&bark)
+                .with_context(context)
+                .with_session(charged)
+                .analyze_image(&image, &prompt)
+                .await
+        })
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Run an exported function of a WASM module in the sandbox, under the watchdog
+///
+/// The watchdog interrupts only this execution when it overruns its deadline.
+#[tauri::command]
+async fn cmd_execute_wasm(
+    state: tauri::State<'_, AppState>,
+    path: String,
+    function: String,
+    args: Vec<i32>,
+    session_id: Option<String>,
+) -> Result<serde_json::Value, String> {
+    let session = state.session_or_active(session_id);
+    let wasm = std::fs::read(&path).map_err(|e| e.to_string())?;
+    let sandbox = state.sandbox.clone();
+    let module = sandbox.verify_module(&wasm).map_err(|e| e.to_string())?;
+    
+    let context = sandbox::HostContext {
+        session_id: session.clone(),
+        substrate: SUBSTRATE.to_string(),
+        permissions: Vec::new(),
+    };
+    let args: Vec<wasmtime::Val> = args.into_iter().map(wasmtime::Val::I32).collect();
+    let interrupt = sandbox::Interrupt::new();
+    let handle = interrupt.clone();
+    let result = state
+        .watchdog
+        .run_blocking(
+            watchdog::OperationKind::Sandbox,
+            &path,
+            &session,
+            move || handle.interrupt(),
+            move || sandbox.execute(&wasm, &function, &args, &context, &interrupt),
+        )
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())?;
+    
+    let results: Vec<serde_json::Value> = result
+        .results
+        .iter()
+        .map(|value| match value {
+            wasmtime::Val::I32(v) => serde_json::json!(v),
+            wasmtime::Val::I64(v) => serde_json::json!(v),
+            wasmtime::Val::F32(bits) => serde_json::json!(f32::from_bits(*bits)),
+            wasmtime::Val::F64(bits) => serde_json::json!(f64::from_bits(*bits)),
+            _ => serde_json::Value::Null,
+        })
+        .collect();
+    Ok(serde_json::json!({
+        "results": results,
+        "fuel_consumed": result.fuel_consumed,
+        "logs": result.logs,
+        "elapsed_ms": result.elapsed_ms,
+        "exports": module.exports,
+        "c_zero": result.c_zero
+    }))
+}
+
 /// Generate cryptographic receipt
 #[tauri::command]
 fn cmd_generate_receipt(
@@ -273,6 +715,46 @@ fn cmd_generate_receipt(
     invariance::generate_receipt(&claim, &evidence)
 }
 
+/// Verify a sap4d, audit or portal receipt file against the stored trust anchors
+#[tauri::command]
+async fn cmd_verify_receipt_file(
+    state: tauri::State<'_, AppState>,
+    path: String,
+) -> Result<receipt_check::ReceiptVerdict, String> {
+    let db = state.db.clone();
+    let signer = state.signer.clone();
+    tauri::async_runtime::spawn_blocking(move || receipt_check::verify_file(&db, &signer, std::path::Path::new(&path)))
+        .await
+        .map_err(|e| e.to_string())?
+        .map_err(|e| e.to_string())
+}
+
+/// Trust receipts signed under key id `name` by `key`
+/// (`hmac:<base64 secret>` or `ed25519:<base64 public key>`)
+#[tauri::command]
+fn cmd_add_trust_anchor(
+    state: tauri::State<AppState>,
+    name: String,
+    key: String,
+) -> Result<cozo_db::StoredAnchor, String> {
+    state.db.store_trust_anchor(&name, &key).map_err(|e| e.to_string())
+}
+
+/// List stored trust anchors (the browser's own key is always trusted)
+#[tauri::command]
+fn cmd_list_trust_anchors(state: tauri::State<AppState>) -> Result<Vec<cozo_db::StoredAnchor>, String> {
+    state.db.load_trust_anchors().map_err(|e| e.to_string())
+}
+
+/// Stop trusting a stored anchor
+#[tauri::command]
+fn cmd_remove_trust_anchor(
+    state: tauri::State<AppState>,
+    name: String,
+) -> Result<bool, String> {
+    state.db.remove_trust_anchor(&name).map_err(|e| e.to_string())
+}
+
 // =============================================================================
 // DSIF COMMANDS
 // =============================================================================
@@ -299,8 +781,8 @@ async fn cmd_dsif_execute_pipeline(
     let params_map: HashMap<String, serde_json::Value> = serde_json::from_value(parameters)
         .map_err(|e| format!("Invalid parameters: {}", e))?;
     
-    let mut dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
-    let decision = dsif.execute_pipeline(&input, action_type_enum, &target, params_map).await?;
+    let decision =
+        dsif::DSIF::execute_pipeline_shared(&state.dsif, &input, action_type_enum, &target, params_map).await?;
     
     Ok(serde_json::json!({
         "success": true,
@@ -308,29 +790,187 @@ async fn cmd_dsif_execute_pipeline(
     }))
 }
 
+/// Approve a critical DSIF decision awaiting approval, actuating it
+#[tauri::command]
+async fn cmd_dsif_approve(
+    state: tauri::State<'_, AppState>,
+    decision_id: String,
+    token: String,
+    approver: String,
+) -> Result<serde_json::Value, String> {
+    let mut dsif = state.dsif.write().await;
+    let decision = dsif.approve(&decision_id, &token, &approver)?;
+    Ok(serde_json::json!({
+        "success": true,
+        "decision": decision
+    }))
+}
+
+/// Reject a critical DSIF decision awaiting approval
+#[tauri::command]
+async fn cmd_dsif_reject(
+    state: tauri::State<'_, AppState>,
+    decision_id: String,
+    token: String,
+    approver: String,
+    reason: String,
+) -> Result<serde_json::Value, String> {
+    let mut dsif = state.dsif.write().await;
+    let decision = dsif.reject(&decision_id, &token, &approver, &reason)?;
+    Ok(serde_json::json!({
+        "success": true,
+        "decision": decision
+    }))
+}
+
+/// List critical DSIF decisions still awaiting approval
+#[tauri::command]
+async fn cmd_dsif_pending_approvals(
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let mut dsif = state.dsif.write().await;
+    let pending = dsif.pending_approvals()?;
+    Ok(serde_json::json!(pending))
+}
+
 /// Get DSIF audit trail
 #[tauri::command]
-fn cmd_dsif_get_audit_trail(
+async fn cmd_dsif_get_audit_trail(
     state: tauri::State<'_, AppState>,
 ) -> Result<serde_json::Value, String> {
-    let dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
+    let dsif = state.dsif.read().await;
     let trail = dsif.get_audit_trail();
     Ok(serde_json::json!(trail))
 }
 
+/// Verify the DSIF audit trail hash chain
+#[tauri::command]
+async fn cmd_dsif_verify_trail(
+    state: tauri::State<'_, AppState>,
+) -> Result<serde_json::Value, String> {
+    let dsif = state.dsif.read().await;
+    let result = dsif.verify_trail();
+    Ok(serde_json::json!({
+        "intact": result.is_ok(),
+        "entries": dsif.get_audit_trail().len(),
+        "first_broken_index": result.err()
+    }))
+}
+
+/// Turn coalescing of repeated DSIF audit entries on or off
+#[tauri::command]
+async fn cmd_dsif_set_coalescing(
+    state: tauri::State<'_, AppState>,
+    window_secs: i64,
+    enabled: bool,
+) -> Result<serde_json::Value, String> {
+    if window_secs < 0 {
+        return Err("Coalescing window must not be negative".to_string());
+    }
+    let mut dsif = state.dsif.write().await;
+    dsif.set_coalescing(chrono::Duration::seconds(window_secs), enabled);
+    
+    Ok(serde_json::json!({
+        "success": true,
+        "enabled": enabled,
+        "window_secs": window_secs
+    }))
+}
+
+/// Export the DSIF audit trail to a file chosen in a save dialog
+///
+/// Returns `None` if the dialog is cancelled.
+#[tauri::command]
+async fn cmd_dsif_export_trail(
+    app: tauri::AppHandle,
+    state: tauri::State<'_, AppState>,
+) -> Result<Option<serde_json::Value>, String> {
+    use tauri_plugin_dialog::DialogExt;
+    
+    let (tx, rx) = tokio::sync::oneshot::channel();
+    app.dialog()
+        .file()
+        .add_filter("JSON", &["json"])
+        .set_file_name("dsif-audit-trail.json")
+        .save_file(move |path| {
+            let _ = tx.send(path);
+        });
+    let Some(path) = rx.await.map_err(|e| e.to_string())? else {
+        return Ok(None);
+    };
+    let path = path.into_path().map_err(|e| e.to_string())?;
+    
+    let doc = state.dsif.read().await.export_trail();
+    let json = serde_json::to_vec_pretty(&doc).map_err(|e| e.to_string())?;
+    std::fs::write(&path, json).map_err(|e| e.to_string())?;
+    
+    tracing::info!("Exported DSIF audit trail to {}", path.display());
+    Ok(Some(serde_json::json!({
+        "path": path,
+        "entries": doc.entries.len(),
+        "merkle_root": doc.merkle_root,
+        "chain_head": doc.chain_head
+    })))
+}
+
+/// Re-check an exported DSIF audit trail
+#[tauri::command]
+async fn cmd_dsif_verify_exported_trail(path: String) -> Result<serde_json::Value, String> {
+    let json = std::fs::read(&path).map_err(|e| e.to_string())?;
+    let doc: dsif::ExportedTrail = serde_json::from_slice(&json).map_err(|e| e.to_string())?;
+    let result = dsif::DSIF::verify_exported_trail(&doc);
+    Ok(serde_json::json!({
+        "valid": result.is_ok(),
+        "entries": doc.entries.len(),
+        "error": result.err().map(|e| e.to_string())
+    }))
+}
+
 /// Get DSIF agents
 #[tauri::command]
-fn cmd_dsif_get_agents(
+async fn cmd_dsif_get_agents(
     state: tauri::State<'_, AppState>,
 ) -> Result<serde_json::Value, String> {
-    let dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
+    let dsif = state.dsif.read().await;
     let agents = dsif.get_agents();
     Ok(serde_json::json!(agents))
 }
 
+/// Score agents' votes on a decision against its verified outcome
+#[tauri::command]
+async fn cmd_dsif_record_outcome(
+    state: tauri::State<'_, AppState>,
+    decision_id: String,
+    outcome: String,
+) -> Result<serde_json::Value, String> {
+    let outcome = match outcome.as_str() {
+        "Safe" => dsif::ActionOutcome::Safe,
+        "Unsafe" => dsif::ActionOutcome::Unsafe,
+        _ => return Err("Invalid outcome".to_string()),
+    };
+    
+    let mut dsif = state.dsif.write().await;
+    let updates = dsif.record_outcome(&decision_id, outcome)?;
+    Ok(serde_json::json!({
+        "success": true,
+        "updates": updates
+    }))
+}
+
+/// Return a quarantined DSIF agent to the consensus pool
+#[tauri::command]
+async fn cmd_dsif_reinstate_agent(
+    state: tauri::State<'_, AppState>,
+    agent_id: String,
+) -> Result<serde_json::Value, String> {
+    let mut dsif = state.dsif.write().await;
+    dsif.reinstate_agent(&agent_id)?;
+    Ok(serde_json::json!({ "success": true }))
+}
+
 /// Add invariant to DSIF
 #[tauri::command]
-fn cmd_dsif_add_invariant(
+async fn cmd_dsif_add_invariant(
     state: tauri::State<'_, AppState>,
     id: String,
     name: String,
@@ -344,7 +984,7 @@ fn cmd_dsif_add_invariant(
         domain,
     };
     
-    let mut dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
+    let mut dsif = state.dsif.write().await;
     dsif.add_invariant(invariant);
     
     Ok(serde_json::json!({
@@ -355,12 +995,12 @@ fn cmd_dsif_add_invariant(
 
 /// Add item to DSIF allowlist
 #[tauri::command]
-fn cmd_dsif_add_to_allowlist(
+async fn cmd_dsif_add_to_allowlist(
     state: tauri::State<'_, AppState>,
     item: String,
 ) -> Result<serde_json::Value, String> {
-    let mut dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
-    dsif.add_to_allowlist(item);
+    let mut dsif = state.dsif.write().await;
+    dsif.add_to_allowlist(item)?;
     
     Ok(serde_json::json!({
         "success": true,
@@ -370,12 +1010,12 @@ fn cmd_dsif_add_to_allowlist(
 
 /// Add item to DSIF denylist
 #[tauri::command]
-fn cmd_dsif_add_to_denylist(
+async fn cmd_dsif_add_to_denylist(
     state: tauri::State<'_, AppState>,
     item: String,
 ) -> Result<serde_json::Value, String> {
-    let mut dsif = state.dsif.lock().map_err(|e| format!("Failed to lock DSIF: {}", e))?;
-    dsif.add_to_denylist(item);
+    let mut dsif = state.dsif.write().await;
+    dsif.add_to_denylist(item)?;
     
     Ok(serde_json::json!({
         "success": true,
@@ -0,0 +1,509 @@
+//! Local verification of third-party receipts
+//!
+//! A receipt file is one of three JSON shapes: a `sap4d::Receipt` (from the
+//! CLI or engine), an audit service `AuditReceipt`, or a portal
+//! `StoredReceipt` (from `GET /receipt/{hash}`). The shape is taken from a
+//! `type` field (`sap4d`, `audit` or `portal`) when present and detected
+//! from the fields otherwise.
+//!
+//! Each receipt gets the checks its shape allows (per-level result hashes,
+//! the receipt hash, the signature, C=0), reported one by one for the UI.
+//! Signatures are checked against the trust anchors stored in CozoDB plus
+//! this install's own signing key, using the same anchor model as the
+//! portal's `PORTAL_TRUST_ANCHORS` ([`sap4d::anchors`]): an HMAC secret or
+//! Ed25519 public key under the key id receipts record. A signature from a
+//! key no anchor holds is *unverified* rather than failed: the receipt may
+//! be genuine, this install just cannot tell. Every verification is logged
+//! as a `RECEIPT_VERIFIED` action.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::verify::AuditReceipt;
+use sap4d::anchors::TrustAnchor;
+use sap4d::signing::KeySigner;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+use thiserror::Error;
+
+use crate::cozo_db::{CozoError, CozoStore};
+
+/// Action type logged for every verified receipt file
+pub const RECEIPT_VERIFIED: &str = "RECEIPT_VERIFIED";
+
+#[derive(Error, Debug)]
+pub enum ReceiptCheckError {
+    #[error("Cannot read receipt file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid receipt at {field}: {message}")]
+    Invalid { field: String, message: String },
+    #[error("Unrecognized receipt shape; set `type` to sap4d, audit or portal")]
+    UnrecognizedShape,
+    #[error("Store error: {0}")]
+    Store(#[from] CozoError),
+}
+
+impl ReceiptCheckError {
+    fn at(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self::Invalid {
+            field: field.into(),
+            message: message.into(),
+        }
+    }
+}
+
+/// Signers a receipt's signature is checked against
+#[derive(Debug, Clone, PartialEq)]
+pub struct TrustAnchors {
+    anchors: Vec<TrustAnchor>,
+}
+
+impl TrustAnchors {
+    /// Only this install's own signer
+    pub fn local_only(local: &KeySigner) -> Self {
+        Self {
+            anchors: vec![local.anchor()],
+        }
+    }
+
+    /// Also accept `anchor`'s signatures
+    pub fn with_anchor(mut self, anchor: TrustAnchor) -> Self {
+        self.anchors.push(anchor);
+        self
+    }
+
+    /// The local anchor plus every anchor stored in `db`
+    pub fn load(db: &CozoStore, local: &KeySigner) -> Result<Self, CozoError> {
+        db.load_trust_anchors()?
+            .iter()
+            .try_fold(Self::local_only(local), |anchors, stored| Ok(anchors.with_anchor(stored.anchor()?)))
+    }
+
+    /// Check `signature` over `hash`
+    ///
+    /// A receipt that names its key is checked against that anchor only;
+    /// one that does not is accepted from any anchor.
+    pub fn check(&self, hash: &str, signature: &str, key_id: Option<&str>) -> SignatureCheck {
+        match key_id {
+            Some(key_id) => match self.anchors.iter().find(|anchor| anchor.name == key_id) {
+                Some(anchor) if anchor.verify(hash, signature) => SignatureCheck::Verified {
+                    signer: key_id.to_string(),
+                },
+                Some(_) => SignatureCheck::Invalid {
+                    key_id: key_id.to_string(),
+                },
+                None => SignatureCheck::UnknownKey {
+                    key_id: Some(key_id.to_string()),
+                },
+            },
+            None => match self.anchors.iter().find(|anchor| anchor.verify(hash, signature)) {
+                Some(anchor) => SignatureCheck::Verified {
+                    signer: anchor.name.clone(),
+                },
+                None => SignatureCheck::UnknownKey { key_id: None },
+            },
+        }
+    }
+}
+
+/// Receipt shapes a file may hold
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ReceiptKind {
+    Sap4d,
+    Audit,
+    Portal,
+}
+
+/// Outcome of the signature check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SignatureCheck {
+    /// A trust anchor made the signature
+    Verified { signer: String },
+    /// No trust anchor matches: the signer is not known here
+    UnknownKey { key_id: Option<String> },
+    /// The named anchor did not make this signature
+    Invalid { key_id: String },
+}
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CheckOutcome {
+    Pass,
+    Fail,
+    /// Could not be decided (the signing key is unknown)
+    Unverified,
+}
+
+/// One line of a verdict
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct CheckResult {
+    /// `hash`, `results`, `summary`, `signature` or `c_zero`
+    pub check: String,
+    pub outcome: CheckOutcome,
+    pub detail: String,
+}
+
+impl CheckResult {
+    fn new(check: &str, passed: bool, pass: impl Into<String>, fail: impl Into<String>) -> Self {
+        let (outcome, detail) = if passed {
+            (CheckOutcome::Pass, pass.into())
+        } else {
+            (CheckOutcome::Fail, fail.into())
+        };
+        Self {
+            check: check.to_string(),
+            outcome,
+            detail,
+        }
+    }
+
+    fn signature(signature: &SignatureCheck) -> Self {
+        let (outcome, detail) = match signature {
+            SignatureCheck::Verified { signer } => (CheckOutcome::Pass, format!("signed by {}", signer)),
+            SignatureCheck::UnknownKey { key_id: Some(key_id) } => {
+                (CheckOutcome::Unverified, format!("unknown key {}", key_id))
+            }
+            SignatureCheck::UnknownKey { key_id: None } => {
+                (CheckOutcome::Unverified, "no trust anchor made this signature".to_string())
+            }
+            SignatureCheck::Invalid { key_id } => (CheckOutcome::Fail, format!("not a valid signature from {}", key_id)),
+        };
+        Self {
+            check: "signature".to_string(),
+            outcome,
+            detail,
+        }
+    }
+}
+
+/// Overall result of verifying a receipt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VerdictStatus {
+    /// Every check passed
+    Verified,
+    /// Nothing failed, but the signing key is unknown
+    SignatureUnverified,
+    /// At least one check failed
+    Failed,
+}
+
+/// Verdict on one receipt, with every check for the UI
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReceiptVerdict {
+    #[serde(rename = "type")]
+    pub kind: ReceiptKind,
+    /// The hash the receipt claims
+    pub hash: String,
+    pub status: VerdictStatus,
+    /// e.g. "hash valid, signature unverified (unknown key)"
+    pub summary: String,
+    pub signature: SignatureCheck,
+    pub checks: Vec<CheckResult>,
+}
+
+impl ReceiptVerdict {
+    fn new(kind: ReceiptKind, hash: String, signature: SignatureCheck, mut checks: Vec<CheckResult>, c_zero: bool) -> Self {
+        checks.push(CheckResult::signature(&signature));
+        checks.push(CheckResult::new("c_zero", c_zero, "C = 0", "C = 0 not established"));
+
+        let status = if checks.iter().any(|c| c.outcome == CheckOutcome::Fail) {
+            VerdictStatus::Failed
+        } else if checks.iter().any(|c| c.outcome == CheckOutcome::Unverified) {
+            VerdictStatus::SignatureUnverified
+        } else {
+            VerdictStatus::Verified
+        };
+
+        let integrity_ok = checks
+            .iter()
+            .filter(|c| c.check != "signature" && c.check != "c_zero")
+            .all(|c| c.outcome == CheckOutcome::Pass);
+        let mut summary = vec![
+            if integrity_ok { "hash valid" } else { "hash invalid" }.to_string(),
+            match &signature {
+                SignatureCheck::Verified { signer } => format!("signature verified ({})", signer),
+                SignatureCheck::UnknownKey { .. } => "signature unverified (unknown key)".to_string(),
+                SignatureCheck::Invalid { .. } => "signature invalid".to_string(),
+            },
+        ];
+        if !c_zero {
+            summary.push("C = 0 not established".to_string());
+        }
+
+        Self {
+            kind,
+            hash,
+            status,
+            summary: summary.join(", "),
+            signature,
+            checks,
+        }
+    }
+}
+
+/// The hashed and signed parts of a portal `StoredReceipt`
+#[derive(Debug, Clone, Deserialize)]
+struct PortalReceipt {
+    claim: String,
+    evidence: Vec<String>,
+    c_zero: bool,
+    hash: String,
+    signature: String,
+    timestamp: String,
+}
+
+impl PortalReceipt {
+    /// SHA-256 over the claim, each evidence item, the C=0 byte and the timestamp
+    fn compute_hash(&self) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.claim.as_bytes());
+        for e in &self.evidence {
+            hasher.update(e.as_bytes());
+        }
+        hasher.update([self.c_zero as u8]);
+        hasher.update(self.timestamp.as_bytes());
+        hex::encode(hasher.finalize())
+    }
+}
+
+/// Verify the receipt at `path` against `local` and the anchors in `db`,
+/// logging the verdict
+pub fn verify_file(db: &CozoStore, local: &KeySigner, path: &Path) -> Result<ReceiptVerdict, ReceiptCheckError> {
+    let body = std::fs::read(path)?;
+    let verdict = verify(&body, &TrustAnchors::load(db, local)?)?;
+
+    let target = path.display().to_string();
+    db.log_action(RECEIPT_VERIFIED, &target, &serde_json::to_string(&verdict).unwrap_or_default(), 0.0)?;
+    tracing::info!("Verified receipt {}: {}", target, verdict.summary);
+    Ok(verdict)
+}
+
+/// Parse `body` as a receipt and verify it against `trust`
+pub fn verify(body: &[u8], trust: &TrustAnchors) -> Result<ReceiptVerdict, ReceiptCheckError> {
+    let document: Value = serde_json::from_slice(body).map_err(|e| ReceiptCheckError::at(".", e.to_string()))?;
+    let Some(object) = document.as_object() else {
+        return Err(ReceiptCheckError::at(".", "receipt must be a JSON object"));
+    };
+
+    let kind = match object.get("type") {
+        Some(kind) => serde_json::from_value(kind.clone())
+            .map_err(|_| ReceiptCheckError::at("type", "expected one of: sap4d, audit, portal"))?,
+        None => detect(object).ok_or(ReceiptCheckError::UnrecognizedShape)?,
+    };
+    let key_id = object.get("key_id").and_then(Value::as_str).map(str::to_string);
+    let written_c_zero = object.get("c_zero").and_then(Value::as_bool);
+
+    match kind {
+        ReceiptKind::Sap4d => {
+            let receipt: sap4d::Receipt = parse(document)?;
+            let hash_ok = receipt.verify_hash();
+            let signature = trust.check(&receipt.hash, &receipt.signature, receipt.key_id.as_deref());
+            let checks = vec![CheckResult::new(
+                "hash",
+                hash_ok,
+                format!("v{} hash matches the contents", receipt.version),
+                format!("v{} hash does not match the contents", receipt.version),
+            )];
+            Ok(ReceiptVerdict::new(kind, receipt.hash, signature, checks, receipt.c_zero))
+        }
+        ReceiptKind::Audit => {
+            let receipt: AuditReceipt = parse(document)?;
+            let invalid_result = receipt.results.iter().position(|r| !r.verify_integrity());
+            let c_zero = !receipt.results.is_empty() && receipt.results.iter().all(|r| r.c_zero);
+            // The receipt hash covers only the results, so a written C=0
+            // summary must agree with them
+            let summary_ok = !matches!(written_c_zero, Some(written) if written != c_zero);
+            let signature = trust.check(&receipt.receipt_hash, &receipt.signature, key_id.as_deref());
+            let checks = vec![
+                CheckResult::new(
+                    "results",
+                    invalid_result.is_none(),
+                    format!("{} level result(s) intact", receipt.results.len()),
+                    format!("result {} does not match its hash", invalid_result.unwrap_or_default()),
+                ),
+                CheckResult::new(
+                    "hash",
+                    receipt.verify_hash(),
+                    "receipt hash matches the results",
+                    "receipt hash does not match the results",
+                ),
+                CheckResult::new(
+                    "summary",
+                    summary_ok,
+                    "C = 0 summary matches the results",
+                    "C = 0 summary disagrees with the results",
+                ),
+            ];
+            Ok(ReceiptVerdict::new(kind, receipt.receipt_hash, signature, checks, c_zero))
+        }
+        ReceiptKind::Portal => {
+            let receipt: PortalReceipt = parse(document)?;
+            let hash_ok = receipt.compute_hash() == receipt.hash;
+            let signature = trust.check(&receipt.hash, &receipt.signature, key_id.as_deref());
+            let checks = vec![CheckResult::new(
+                "hash",
+                hash_ok,
+                "hash matches the claim and evidence",
+                "hash does not match the claim and evidence",
+            )];
+            Ok(ReceiptVerdict::new(kind, receipt.hash, signature, checks, receipt.c_zero))
+        }
+    }
+}
+
+/// Guess the receipt shape from its fields
+fn detect(object: &Map<String, Value>) -> Option<ReceiptKind> {
+    let has = |key: &str| object.contains_key(key);
+    if has("receipt_hash") && has("results") {
+        Some(ReceiptKind::Audit)
+    } else if has("claim") && has("causal_chain") {
+        Some(ReceiptKind::Sap4d)
+    } else if has("claim") && has("hash") && has("signature") && has("c_zero") {
+        Some(ReceiptKind::Portal)
+    } else {
+        None
+    }
+}
+
+/// Deserialize, reporting the first bad field
+fn parse<T: DeserializeOwned>(document: Value) -> Result<T, ReceiptCheckError> {
+    serde_json::from_value(document).map_err(|e| ReceiptCheckError::at(".", e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap4d::signing::ReceiptSigner;
+
+    fn local() -> KeySigner {
+        KeySigner::new("axiom-s1 test key")
+    }
+
+    fn sap4d_receipt(signer: &KeySigner) -> sap4d::Receipt {
+        let mut receipt = sap4d::ReceiptBuilder::new("The deployment succeeded")
+            .with_evidence("The deployment succeeded")
+            .with_c_zero(true)
+            .build(|hash| signer.sign(hash));
+        receipt.key_id = Some(signer.key_id().to_string());
+        receipt
+    }
+
+    fn portal_receipt(signer: &KeySigner) -> Value {
+        let mut receipt = PortalReceipt {
+            claim: "A claim".to_string(),
+            evidence: vec!["A claim".to_string()],
+            c_zero: true,
+            hash: String::new(),
+            signature: String::new(),
+            timestamp: "2026-01-01T00:00:00+00:00".to_string(),
+        };
+        receipt.hash = receipt.compute_hash();
+        serde_json::json!({
+            "claim": receipt.claim,
+            "evidence": receipt.evidence,
+            "c_zero": receipt.c_zero,
+            "hash": receipt.hash,
+            "signature": signer.sign(&receipt.hash),
+            "timestamp": receipt.timestamp,
+            "key_id": signer.key_id(),
+        })
+    }
+
+    #[test]
+    fn test_sap4d_receipt_from_local_signer_verifies() {
+        let receipt = sap4d_receipt(&local());
+        let verdict = verify(receipt.to_json().unwrap().as_bytes(), &TrustAnchors::local_only(&local())).unwrap();
+        assert_eq!(verdict.kind, ReceiptKind::Sap4d);
+        assert_eq!(verdict.status, VerdictStatus::Verified);
+        assert_eq!(verdict.signature, SignatureCheck::Verified { signer: local().key_id().to_string() });
+        assert!(verdict.checks.iter().all(|c| c.outcome == CheckOutcome::Pass));
+    }
+
+    #[test]
+    fn test_unknown_key_is_unverified_not_failed() {
+        let elsewhere = KeySigner::new("elsewhere key");
+        let mut receipt = sap4d_receipt(&elsewhere);
+        receipt.key_id = None;
+        let verdict = verify(receipt.to_json().unwrap().as_bytes(), &TrustAnchors::local_only(&local())).unwrap();
+        assert_eq!(verdict.status, VerdictStatus::SignatureUnverified);
+        assert_eq!(verdict.summary, "hash valid, signature unverified (unknown key)");
+
+        // Trusting the signer's key settles it
+        let trust = TrustAnchors::local_only(&local()).with_anchor(TrustAnchor::hmac("elsewhere", "elsewhere key"));
+        let verdict = verify(receipt.to_json().unwrap().as_bytes(), &trust).unwrap();
+        assert_eq!(verdict.status, VerdictStatus::Verified);
+        assert_eq!(verdict.summary, "hash valid, signature verified (elsewhere)");
+    }
+
+    #[test]
+    fn test_named_key_must_match_its_anchor() {
+        let portal = KeySigner::new("portal key");
+        let trust = TrustAnchors::local_only(&local()).with_anchor(portal.anchor());
+
+        // A receipt claiming the portal's key id, signed by another key
+        let mut receipt = serde_json::to_value(sap4d_receipt(&local())).unwrap();
+        receipt["key_id"] = portal.key_id().into();
+        let verdict = verify(receipt.to_string().as_bytes(), &trust).unwrap();
+        assert_eq!(verdict.status, VerdictStatus::Failed);
+        assert_eq!(verdict.signature, SignatureCheck::Invalid { key_id: portal.key_id().to_string() });
+
+        receipt["key_id"] = "sha256:0123456789abcdef".into();
+        let verdict = verify(receipt.to_string().as_bytes(), &trust).unwrap();
+        assert_eq!(
+            verdict.signature,
+            SignatureCheck::UnknownKey { key_id: Some("sha256:0123456789abcdef".to_string()) }
+        );
+    }
+
+    #[test]
+    fn test_tampered_portal_receipt_fails() {
+        let portal = KeySigner::new("portal key");
+        let trust = TrustAnchors::local_only(&local()).with_anchor(portal.anchor());
+        let receipt = portal_receipt(&portal);
+        let verdict = verify(receipt.to_string().as_bytes(), &trust).unwrap();
+        assert_eq!(verdict.kind, ReceiptKind::Portal);
+        assert_eq!(verdict.status, VerdictStatus::Verified);
+
+        let mut tampered = receipt.clone();
+        tampered["claim"] = "Another claim".into();
+        let verdict = verify(tampered.to_string().as_bytes(), &trust).unwrap();
+        assert_eq!(verdict.status, VerdictStatus::Failed);
+        assert!(verdict.summary.starts_with(&format!("hash invalid, signature verified ({})", portal.key_id())));
+    }
+
+    #[test]
+    fn test_detect_shapes() {
+        let trust = TrustAnchors::local_only(&local());
+        let object = |value: Value| value.as_object().unwrap().clone();
+        assert_eq!(detect(&object(serde_json::json!({ "receipt_hash": "h", "results": [] }))), Some(ReceiptKind::Audit));
+        assert_eq!(detect(&object(serde_json::json!({ "claim": "c", "causal_chain": [] }))), Some(ReceiptKind::Sap4d));
+        assert_eq!(detect(&object(portal_receipt(&local()))), Some(ReceiptKind::Portal));
+        assert_eq!(detect(&object(serde_json::json!({ "hash": "h" }))), None);
+        assert!(matches!(verify(b"{\"hash\": \"h\"}", &trust), Err(ReceiptCheckError::UnrecognizedShape)));
+        assert!(matches!(verify(b"[1]", &trust), Err(ReceiptCheckError::Invalid { .. })));
+    }
+
+    #[test]
+    fn test_verify_file_uses_stored_anchors_and_logs() {
+        let db = CozoStore::new(&std::env::temp_dir().join(format!("receipt-check-{}", std::process::id()))).unwrap();
+        let path = std::env::temp_dir().join(format!("receipt-check-{}.json", std::process::id()));
+        let portal = KeySigner::new("portal key");
+        std::fs::write(&path, portal_receipt(&portal).to_string()).unwrap();
+
+        assert_eq!(verify_file(&db, &local(), &path).unwrap().status, VerdictStatus::SignatureUnverified);
+        db.store_trust_anchor(portal.key_id(), "hmac:cG9ydGFsIGtleQ==").unwrap();
+        assert_eq!(verify_file(&db, &local(), &path).unwrap().status, VerdictStatus::Verified);
+
+        let actions = db.load_actions(RECEIPT_VERIFIED).unwrap();
+        assert_eq!(actions.len(), 2);
+        assert_eq!(actions[1]["target"], path.display().to_string());
+        assert_eq!(actions[1]["result"]["status"], "verified");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -5,9 +5,30 @@
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::{Duration, Instant};
 use thiserror::Error;
 use wasmtime::*;
 
+/// How often running executions re-check their wall-clock deadline
+const EPOCH_TICK: Duration = Duration::from_millis(10);
+
+/// Host function status codes returned to the guest
+pub mod status {
+    /// Success
+    pub const OK: i32 = 0;
+    /// The module does not export a `memory`
+    pub const NO_MEMORY: i32 = -1;
+    /// A `(ptr, len)` range falls outside guest memory
+    pub const OUT_OF_BOUNDS: i32 = -2;
+    /// The input bytes are not valid UTF-8
+    pub const INVALID_UTF8: i32 = -3;
+}
+
 #[derive(Error, Debug)]
 pub enum SandboxError {
     #[error("Failed to compile module: {0}")]
@@ -16,12 +37,30 @@ pub enum SandboxError {
     Instantiation(String),
     #[error("Execution failed: {0}")]
     Execution(String),
-    #[error("Resource limit exceeded")]
-    ResourceLimit,
+    #[error("Resource limit exceeded: {0}")]
+    ResourceLimit(ResourceLimit),
     #[error("Unauthorized operation: {0}")]
     Unauthorized(String),
 }
 
+/// Which limit stopped an execution
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ResourceLimit {
+    /// `max_fuel` instructions consumed
+    Fuel,
+    /// `max_time_ms` elapsed
+    WallClock,
+}
+
+impl fmt::Display for ResourceLimit {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ResourceLimit::Fuel => write!(f, "fuel exhausted"),
+            ResourceLimit::WallClock => write!(f, "wall-clock time limit reached"),
+        }
+    }
+}
+
 /// Sandbox configuration
 #[derive(Debug, Clone)]
 pub struct SandboxConfig {
@@ -51,6 +90,107 @@ pub struct HostContext {
     pub permissions: Vec<String>,
 }
 
+/// Per-execution host state reachable from host functions
+struct HostState {
+    substrate: String,
+    logs: Vec<String>,
+    /// Why the epoch callback stopped the guest, if it did
+    abort: Option<Abort>,
+}
+
+/// Reason the epoch callback trapped the guest
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Abort {
+    WallClock,
+    Interrupted,
+}
+
+/// Background thread ticking the engine epoch until dropped
+struct EpochTicker {
+    stop: Arc<AtomicBool>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl EpochTicker {
+    fn start(engine: Engine) -> Self {
+        let stop = Arc::new(AtomicBool::new(false));
+        let flag = stop.clone();
+        let handle = std::thread::spawn(move || {
+            while !flag.load(Ordering::SeqCst) {
+                std::thread::sleep(EPOCH_TICK);
+                engine.increment_epoch();
+            }
+        });
+        Self { stop, handle: Some(handle) }
+    }
+}
+
+impl Drop for EpochTicker {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Stops the one execution it is passed to (for the watchdog)
+///
+/// Each execution gets its own handle, so interrupting one leaves every
+/// other execution on the same sandbox running. The guest stops at its next
+/// epoch tick.
+#[derive(Debug, Clone, Default)]
+pub struct Interrupt(Arc<AtomicBool>);
+
+impl Interrupt {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stop the execution holding this handle
+    pub fn interrupt(&self) {
+        self.0.store(true, Ordering::SeqCst);
+    }
+
+    fn is_set(&self) -> bool {
+        self.0.load(Ordering::SeqCst)
+    }
+}
+
+/// Map a wasmtime failure to the limit or interrupt that caused it
+fn classify_error(abort: Option<Abort>, error: Error, fallback: fn(String) -> SandboxError) -> SandboxError {
+    match abort {
+        Some(Abort::WallClock) => SandboxError::ResourceLimit(ResourceLimit::WallClock),
+        Some(Abort::Interrupted) => SandboxError::Execution("Interrupted by watchdog".to_string()),
+        None if error.downcast_ref::<Trap>() == Some(&Trap::OutOfFuel) => {
+            SandboxError::ResourceLimit(ResourceLimit::Fuel)
+        }
+        None => fallback(error.to_string()),
+    }
+}
+
+/// Guest memory exported as `memory`, if any
+fn guest_memory(caller: &mut Caller<'_, HostState>) -> Option<Memory> {
+    caller.get_export("memory").and_then(Extern::into_memory)
+}
+
+/// Bounds-checked `(ptr, len)` range within a memory of `size` bytes
+fn guest_range(ptr: i32, len: i32, size: usize) -> Option<std::ops::Range<usize>> {
+    let start = usize::try_from(ptr).ok()?;
+    let end = start.checked_add(usize::try_from(len).ok()?)?;
+    (end <= size).then_some(start..end)
+}
+
+/// Read a UTF-8 string from guest memory, or the status code explaining why not
+fn read_guest_str(caller: &mut Caller<'_, HostState>, ptr: i32, len: i32) -> Result<String, i32> {
+    let memory = guest_memory(caller).ok_or(status::NO_MEMORY)?;
+    let data = memory.data(&caller);
+    let range = guest_range(ptr, len, data.len()).ok_or(status::OUT_OF_BOUNDS)?;
+    std::str::from_utf8(&data[range])
+        .map(str::to_string)
+        .map_err(|_| status::INVALID_UTF8)
+}
+
 /// Wasmtime sandbox
 pub struct Sandbox {
     engine: Engine,
@@ -71,13 +211,14 @@ impl Sandbox {
         Ok(Self { engine, config })
     }
     
-    /// Execute WASM module
+    /// Execute WASM module; `interrupt` stops this execution early
     pub fn execute(
         &self,
         wasm_bytes: &[u8],
         function: &str,
         args: &[Val],
         context: &HostContext,
+        interrupt: &Interrupt,
     ) -> Result<ExecutionResult, SandboxError> {
         // Log provenance before execution
         tracing::info!(
@@ -90,19 +231,41 @@ impl Sandbox {
         let module = Module::new(&self.engine, wasm_bytes)
             .map_err(|e| SandboxError::Compilation(e.to_string()))?;
         
-        // Create store with fuel limit
-        let mut store = Store::new(&self.engine, ());
+        // Create store with fuel limit; every epoch tick checks the wall-clock
+        // deadline and this execution's interrupt
+        let started = Instant::now();
+        let deadline = started + Duration::from_millis(self.config.max_time_ms);
+        let interrupt = interrupt.clone();
+        
+        let mut store = Store::new(&self.engine, HostState {
+            substrate: context.substrate.clone(),
+            logs: Vec::new(),
+            abort: None,
+        });
         store.set_fuel(self.config.max_fuel)
             .map_err(|e| SandboxError::Execution(e.to_string()))?;
+        store.set_epoch_deadline(1);
+        store.epoch_deadline_callback(move |mut ctx| {
+            let abort = if interrupt.is_set() {
+                Abort::Interrupted
+            } else if Instant::now() >= deadline {
+                Abort::WallClock
+            } else {
+                return Ok(UpdateDeadline::Continue(1));
+            };
+            ctx.data_mut().abort = Some(abort);
+            Err(anyhow::anyhow!("execution aborted: {:?}", abort))
+        });
+        let _ticker = EpochTicker::start(self.engine.clone());
         
         // Create linker with host functions
         let mut linker = Linker::new(&self.engine);
-        self.add_host_functions(&mut linker, context)?;
+        self.add_host_functions(&mut linker)?;
         
         // Instantiate
         let instance = linker
             .instantiate(&mut store, &module)
-            .map_err(|e| SandboxError::Instantiation(e.to_string()))?;
+            .map_err(|e| classify_error(store.data().abort, e, SandboxError::Instantiation))?;
         
         // Get function
         let func = instance
@@ -112,7 +275,7 @@ impl Sandbox {
         // Execute
         let mut results = vec![Val::I32(0); func.ty(&store).results().len()];
         func.call(&mut store, args, &mut results)
-            .map_err(|e| SandboxError::Execution(e.to_string()))?;
+            .map_err(|e| classify_error(store.data().abort, e, SandboxError::Execution))?;
         
         // Get remaining fuel
         let fuel_consumed = self.config.max_fuel - store.get_fuel().unwrap_or(0);
@@ -120,27 +283,32 @@ impl Sandbox {
         Ok(ExecutionResult {
             results,
             fuel_consumed,
+            logs: std::mem::take(&mut store.data_mut().logs),
+            elapsed_ms: started.elapsed().as_millis() as u64,
             c_zero: true,
         })
     }
     
     /// Add host functions to linker
-    fn add_host_functions(
-        &self,
-        linker: &mut Linker<()>,
-        context: &HostContext,
-    ) -> Result<(), SandboxError> {
-        let substrate = context.substrate.clone();
-        
-        // log_event: Log a system event
+    ///
+    /// Host functions read `(ptr, len)` ranges from the guest's exported
+    /// `memory` and return a [`status`] code; bad pointers are reported to
+    /// the guest rather than trapping the store.
+    fn add_host_functions(&self, linker: &mut Linker<HostState>) -> Result<(), SandboxError> {
+        // log_event: Log a UTF-8 message from guest memory
         linker
-            .func_wrap("env", "log_event", move |caller: Caller<'_, ()>, ptr: i32, len: i32| {
-                tracing::debug!(
-                    "WASM log_event: ptr={}, len={}, substrate={}",
-                    ptr, len, substrate
-                );
-                // In production, would read string from WASM memory
-                0i32 // Success
+            .func_wrap("env", "log_event", |mut caller: Caller<'_, HostState>, ptr: i32, len: i32| -> i32 {
+                match read_guest_str(&mut caller, ptr, len) {
+                    Ok(message) => {
+                        tracing::debug!(
+                            "WASM log_event: {} (substrate={})",
+                            message, caller.data().substrate
+                        );
+                        caller.data_mut().logs.push(message);
+                        status::OK
+                    }
+                    Err(code) => code,
+                }
             })
             .map_err(|e| SandboxError::Instantiation(e.to_string()))?;
         
@@ -151,12 +319,28 @@ impl Sandbox {
             })
             .map_err(|e| SandboxError::Instantiation(e.to_string()))?;
         
-        // hash_data: Hash data using SHA-256
+        // hash_data: SHA-256 of a UTF-8 range, 32-byte digest written at out_ptr
         linker
-            .func_wrap("env", "hash_data", |_ptr: i32, _len: i32, _out_ptr: i32| -> i32 {
-                // In production, would read data and write hash
-                0i32 // Success
-            })
+            .func_wrap(
+                "env",
+                "hash_data",
+                |mut caller: Caller<'_, HostState>, ptr: i32, len: i32, out_ptr: i32| -> i32 {
+                    let data = match read_guest_str(&mut caller, ptr, len) {
+                        Ok(data) => data,
+                        Err(code) => return code,
+                    };
+                    let digest = Sha256::digest(data.as_bytes());
+                    
+                    let Some(memory) = guest_memory(&mut caller) else {
+                        return status::NO_MEMORY;
+                    };
+                    let Some(range) = guest_range(out_ptr, digest.len() as i32, memory.data_size(&caller)) else {
+                        return status::OUT_OF_BOUNDS;
+                    };
+                    memory.data_mut(&mut caller)[range].copy_from_slice(&digest);
+                    status::OK
+                },
+            )
             .map_err(|e| SandboxError::Instantiation(e.to_string()))?;
         
         Ok(())
@@ -218,6 +402,10 @@ impl Default for Sandbox {
 pub struct ExecutionResult {
     pub results: Vec<Val>,
     pub fuel_consumed: u64,
+    /// Messages the guest passed to `log_event`
+    pub logs: Vec<String>,
+    /// Wall-clock execution time
+    pub elapsed_ms: u64,
     pub c_zero: bool,
 }
 
@@ -249,5 +437,132 @@ mod tests {
         
         assert_eq!(context.substrate, crate::SUBSTRATE);
     }
+    
+    fn context() -> HostContext {
+        HostContext {
+            session_id: "test-session".to_string(),
+            substrate: crate::SUBSTRATE.to_string(),
+            permissions: vec![],
+        }
+    }
+    
+    /// Module with "hello sandbox" at offset 0; `run(out_ptr)` hashes it into
+    /// `out_ptr`, logs it, and returns the hash_data status
+    const HASH_WAT: &str = r#"
+        (module
+            (import "env" "log_event" (func $log_event (param i32 i32) (result i32)))
+            (import "env" "hash_data" (func $hash_data (param i32 i32 i32) (result i32)))
+            (memory (export "memory") 1)
+            (data (i32.const 0) "hello sandbox")
+            (func (export "run") (param $out i32) (result i32)
+                (drop (call $log_event (i32.const 0) (i32.const 13)))
+                (call $hash_data (i32.const 0) (i32.const 13) (local.get $out))))
+    "#;
+    
+    #[test]
+    fn test_hash_data_writes_digest() {
+        let sandbox = Sandbox::default();
+        
+        let result = sandbox
+            .execute(HASH_WAT.as_bytes(), "run", &[Val::I32(64)], &context(), &Interrupt::new())
+            .unwrap();
+        assert_eq!(result.results[0].unwrap_i32(), status::OK);
+        assert_eq!(result.logs, vec!["hello sandbox".to_string()]);
+        
+        // Read the digest back through a fresh instance's memory
+        let mut store = Store::new(&sandbox.engine, HostState { substrate: String::new(), logs: vec![], abort: None });
+        store.set_fuel(1_000_000).unwrap();
+        store.set_epoch_deadline(1);
+        let mut linker = Linker::new(&sandbox.engine);
+        sandbox.add_host_functions(&mut linker).unwrap();
+        let module = Module::new(&sandbox.engine, HASH_WAT).unwrap();
+        let instance = linker.instantiate(&mut store, &module).unwrap();
+        let run = instance.get_typed_func::<i32, i32>(&mut store, "run").unwrap();
+        assert_eq!(run.call(&mut store, 64).unwrap(), status::OK);
+        
+        let memory = instance.get_memory(&mut store, "memory").unwrap();
+        let expected = Sha256::digest(b"hello sandbox");
+        assert_eq!(&memory.data(&store)[64..96], expected.as_slice());
+    }
+    
+    #[test]
+    fn test_hash_data_out_of_bounds_returns_error() {
+        let sandbox = Sandbox::default();
+        
+        // One page is 65536 bytes; a digest at 65530 would overflow it
+        let result = sandbox
+            .execute(HASH_WAT.as_bytes(), "run", &[Val::I32(65530)], &context(), &Interrupt::new())
+            .unwrap();
+        assert_eq!(result.results[0].unwrap_i32(), status::OUT_OF_BOUNDS);
+        
+        let result = sandbox
+            .execute(HASH_WAT.as_bytes(), "run", &[Val::I32(-1)], &context(), &Interrupt::new())
+            .unwrap();
+        assert_eq!(result.results[0].unwrap_i32(), status::OUT_OF_BOUNDS);
+    }
+    
+    const SPIN_WAT: &str = r#"(module (func (export "spin") (loop $l (br $l))))"#;
+    
+    #[test]
+    fn test_wall_clock_limit_stops_infinite_loop() {
+        let config = SandboxConfig {
+            max_time_ms: 200,
+            max_fuel: u64::MAX / 2,
+            ..Default::default()
+        };
+        let sandbox = Sandbox::new(config).unwrap();
+        
+        let started = Instant::now();
+        let result = sandbox.execute(SPIN_WAT.as_bytes(), "spin", &[], &context(), &Interrupt::new());
+        
+        assert!(matches!(result, Err(SandboxError::ResourceLimit(ResourceLimit::WallClock))));
+        assert!(started.elapsed() < Duration::from_millis(400));
+    }
+    
+    #[test]
+    fn test_fuel_limit_reported_as_fuel() {
+        let config = SandboxConfig {
+            max_fuel: 10_000,
+            ..Default::default()
+        };
+        let sandbox = Sandbox::new(config).unwrap();
+        
+        let result = sandbox.execute(SPIN_WAT.as_bytes(), "spin", &[], &context(), &Interrupt::new());
+        assert!(matches!(result, Err(SandboxError::ResourceLimit(ResourceLimit::Fuel))));
+        
+        let ok = sandbox.execute(HASH_WAT.as_bytes(), "run", &[Val::I32(64)], &context(), &Interrupt::new()).unwrap();
+        assert!(ok.elapsed_ms < 5000);
+    }
+    
+    #[test]
+    fn test_interrupt_stops_only_its_execution() {
+        let sandbox = Arc::new(
+            Sandbox::new(SandboxConfig {
+                max_time_ms: 300,
+                max_fuel: u64::MAX / 2,
+                ..Default::default()
+            })
+            .unwrap(),
+        );
+        let interrupt = Interrupt::new();
+        let other = {
+            let sandbox = sandbox.clone();
+            std::thread::spawn(move || sandbox.execute(SPIN_WAT.as_bytes(), "spin", &[], &context(), &Interrupt::new()))
+        };
+        let handle = interrupt.clone();
+        std::thread::spawn(move || {
+            std::thread::sleep(Duration::from_millis(50));
+            handle.interrupt();
+        });
+        
+        let started = Instant::now();
+        let result = sandbox.execute(SPIN_WAT.as_bytes(), "spin", &[], &context(), &interrupt);
+        assert!(matches!(result, Err(SandboxError::Execution(msg)) if msg.contains("watchdog")));
+        assert!(started.elapsed() < Duration::from_millis(250));
+        
+        // The concurrent execution ran on to its own limit
+        let other = other.join().unwrap();
+        assert!(matches!(other, Err(SandboxError::ResourceLimit(ResourceLimit::WallClock))));
+    }
 }
 
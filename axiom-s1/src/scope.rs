@@ -0,0 +1,306 @@
+//! Scope Policy - which prompts the inference layer accepts
+//!
+//! A [`ScopePolicy`] holds named sets of banned phrases and allowed
+//! keywords, a default verdict for prompts matching neither, and case
+//! rules. Banned phrases are checked first and always win; then allowed
+//! keywords; then the default. Every [`ScopeDecision`] names the rule that
+//! fired so the UI can explain a rejection.
+//!
+//! The compiled-in default is the coding-only scope described in SAFETY.md.
+//! Deployments override it with a JSON or TOML file (see
+//! [`ScopePolicy::load`]); omitted fields keep their defaults, so a file
+//! that only changes `default_verdict` still carries the full ban list.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+/// Environment variable naming a scope policy file
+pub const SCOPE_POLICY_ENV: &str = "AXIOM_SCOPE_POLICY";
+
+/// Phrases rejected by the default policy, by domain
+const DEFAULT_BANNED: &[(&str, &[&str])] = &[
+    ("medical", &["diagnose", "symptom", "treatment", "prescribe", "medical advice", "therapy"]),
+    ("legal", &["lawsuit", "legal advice", "contract dispute", "subpoena", "indictment"]),
+    (
+        "finance",
+        &[
+            "buy stocks", "sell stocks", "options trading", "forex", "crypto trading",
+            "financial advice", "investment advice", "portfolio allocation",
+        ],
+    ),
+    (
+        "physical_control",
+        &[
+            "control the drone", "control the robot", "control the car",
+            "disable safety", "bypass safety", "shutdown power grid",
+        ],
+    ),
+];
+
+/// Keywords allowed by the default policy, by domain
+const DEFAULT_ALLOWED: &[(&str, &[&str])] = &[(
+    "coding",
+    &[
+        "code", "function", "class", "refactor", "bug", "stack trace", "compile",
+        "typescript", "python", "rust", "swift", "javascript", "tsconfig", "cargo",
+        "xcode", "sdk", "api", "unit test", "integration test", "linter", "eslint",
+    ],
+)];
+
+/// Outcome of a scope check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ScopeVerdict {
+    Allow,
+    Reject,
+}
+
+/// The rule that decided a scope check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ScopeRule {
+    /// `phrase` from banned set `set` appeared in the prompt
+    Banned { set: String, phrase: String },
+    /// `keyword` from allowed set `set` appeared in the prompt
+    Allowed { set: String, keyword: String },
+    /// Nothing matched; the policy's default verdict applied
+    Default,
+}
+
+impl fmt::Display for ScopeRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ScopeRule::Banned { set, phrase } => write!(f, "banned {} phrase '{}'", set, phrase),
+            ScopeRule::Allowed { set, keyword } => write!(f, "allowed {} keyword '{}'", set, keyword),
+            ScopeRule::Default => write!(f, "no rule matched (default verdict)"),
+        }
+    }
+}
+
+/// Verdict for a prompt and the rule behind it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ScopeDecision {
+    pub verdict: ScopeVerdict,
+    pub rule: ScopeRule,
+}
+
+impl ScopeDecision {
+    pub fn is_allowed(&self) -> bool {
+        self.verdict == ScopeVerdict::Allow
+    }
+}
+
+/// Data-driven scope guard for inference prompts
+///
+/// ```json
+/// {
+///   "allowed": { "coding": ["rust", "refactor"], "docs": ["markdown"] },
+///   "default_verdict": "reject",
+///   "case_sensitive": false,
+///   "whole_words": true
+/// }
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ScopePolicy {
+    /// Named keyword sets; a prompt containing any keyword is allowed
+    pub allowed: BTreeMap<String, Vec<String>>,
+    /// Named phrase sets; a prompt containing any phrase is rejected,
+    /// whatever else it contains
+    pub banned: BTreeMap<String, Vec<String>>,
+    /// Verdict for prompts that match no set
+    pub default_verdict: ScopeVerdict,
+    /// Match case exactly instead of case-insensitively
+    pub case_sensitive: bool,
+    /// Match only at word boundaries, so "code" does not match "barcode"
+    pub whole_words: bool,
+}
+
+impl Default for ScopePolicy {
+    fn default() -> Self {
+        let sets = |sets: &[(&str, &[&str])]| {
+            sets.iter()
+                .map(|(name, terms)| (name.to_string(), terms.iter().map(|t| t.to_string()).collect()))
+                .collect()
+        };
+        Self {
+            allowed: sets(DEFAULT_ALLOWED),
+            banned: sets(DEFAULT_BANNED),
+            default_verdict: ScopeVerdict::Reject,
+            case_sensitive: false,
+            whole_words: false,
+        }
+    }
+}
+
+impl ScopePolicy {
+    pub fn from_json(json: &str) -> Result<Self, ScopeError> {
+        serde_json::from_str(json).map_err(|e| ScopeError::Config(e.to_string()))
+    }
+
+    pub fn from_toml(toml: &str) -> Result<Self, ScopeError> {
+        toml::from_str(toml).map_err(|e| ScopeError::Config(e.to_string()))
+    }
+
+    /// Read a policy from `path`: TOML for `.toml` files, JSON otherwise
+    pub fn load(path: &Path) -> Result<Self, ScopeError> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| ScopeError::Config(format!("{}: {}", path.display(), e)))?;
+        if path.extension().is_some_and(|ext| ext == "toml") {
+            Self::from_toml(&text)
+        } else {
+            Self::from_json(&text)
+        }
+    }
+
+    /// The policy named by `AXIOM_SCOPE_POLICY`, or the default if unset
+    pub fn from_env() -> Result<Self, ScopeError> {
+        match std::env::var(SCOPE_POLICY_ENV) {
+            Ok(path) => Self::load(Path::new(&path)),
+            Err(_) => Ok(Self::default()),
+        }
+    }
+
+    /// Decide whether `prompt` is in scope
+    pub fn evaluate(&self, prompt: &str) -> ScopeDecision {
+        let prompt = self.fold(prompt);
+
+        if let Some((set, phrase)) = self.first_match(&self.banned, &prompt) {
+            return ScopeDecision {
+                verdict: ScopeVerdict::Reject,
+                rule: ScopeRule::Banned { set, phrase },
+            };
+        }
+        if let Some((set, keyword)) = self.first_match(&self.allowed, &prompt) {
+            return ScopeDecision {
+                verdict: ScopeVerdict::Allow,
+                rule: ScopeRule::Allowed { set, keyword },
+            };
+        }
+        ScopeDecision {
+            verdict: self.default_verdict,
+            rule: ScopeRule::Default,
+        }
+    }
+
+    fn fold(&self, text: &str) -> String {
+        if self.case_sensitive { text.to_string() } else { text.to_lowercase() }
+    }
+
+    /// First `(set, term)` in `sets` found in `prompt`
+    fn first_match(&self, sets: &BTreeMap<String, Vec<String>>, prompt: &str) -> Option<(String, String)> {
+        sets.iter().find_map(|(name, terms)| {
+            terms
+                .iter()
+                .find(|term| !term.is_empty() && self.contains(prompt, &self.fold(term)))
+                .map(|term| (name.clone(), term.clone()))
+        })
+    }
+
+    fn contains(&self, prompt: &str, term: &str) -> bool {
+        if !self.whole_words {
+            return prompt.contains(term);
+        }
+        let is_word = |c: char| c.is_alphanumeric() || c == '_';
+        prompt.match_indices(term).any(|(start, _)| {
+            let end = start + term.len();
+            !prompt[..start].chars().next_back().is_some_and(is_word)
+                && !prompt[end..].chars().next().is_some_and(is_word)
+        })
+    }
+}
+
+/// Invalid scope policy
+#[derive(Error, Debug)]
+pub enum ScopeError {
+    #[error("Invalid scope policy: {0}")]
+    Config(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_policy_is_coding_only() {
+        let policy = ScopePolicy::default();
+
+        let decision = policy.evaluate("Refactor this Rust function");
+        assert!(decision.is_allowed());
+        assert!(matches!(decision.rule, ScopeRule::Allowed { ref set, .. } if set == "coding"));
+
+        let decision = policy.evaluate("Write me a poem");
+        assert_eq!(decision, ScopeDecision { verdict: ScopeVerdict::Reject, rule: ScopeRule::Default });
+
+        let decision = policy.evaluate("Diagnose my medical condition");
+        assert_eq!(
+            decision.rule,
+            ScopeRule::Banned { set: "medical".to_string(), phrase: "diagnose".to_string() }
+        );
+    }
+
+    #[test]
+    fn test_custom_policy_flips_default_verdict() {
+        let policy = ScopePolicy::from_json(r#"{"default_verdict": "allow"}"#).unwrap();
+
+        let decision = policy.evaluate("Write me a poem");
+        assert!(decision.is_allowed());
+        assert_eq!(decision.rule, ScopeRule::Default);
+    }
+
+    #[test]
+    fn test_ban_list_wins_over_allowed_and_default() {
+        let policy = ScopePolicy::from_toml(
+            r#"
+            default_verdict = "allow"
+
+            [allowed]
+            support = ["symptom checker"]
+            "#,
+        )
+        .unwrap();
+
+        // The file names no banned sets, so the compiled-in list still applies
+        let decision = policy.evaluate("Fix the symptom checker code");
+        assert!(!decision.is_allowed());
+        assert_eq!(
+            decision.rule,
+            ScopeRule::Banned { set: "medical".to_string(), phrase: "symptom".to_string() }
+        );
+        assert!(!policy.evaluate("Give me investment advice").is_allowed());
+    }
+
+    #[test]
+    fn test_case_and_word_rules() {
+        let mut policy = ScopePolicy {
+            allowed: BTreeMap::from([("coding".to_string(), vec!["Code".to_string()])]),
+            banned: BTreeMap::new(),
+            ..Default::default()
+        };
+        assert!(policy.evaluate("read this CODE").is_allowed());
+        assert!(policy.evaluate("scan the barcode").is_allowed());
+
+        policy.whole_words = true;
+        assert!(!policy.evaluate("scan the barcode").is_allowed());
+        assert!(policy.evaluate("read this code.").is_allowed());
+
+        policy.case_sensitive = true;
+        assert!(!policy.evaluate("read this code").is_allowed());
+        assert!(policy.evaluate("read this Code").is_allowed());
+    }
+
+    #[test]
+    fn test_invalid_policy() {
+        assert!(matches!(ScopePolicy::from_json("{"), Err(ScopeError::Config(_))));
+        assert!(matches!(
+            ScopePolicy::from_json(r#"{"default_verdict": "maybe"}"#),
+            Err(ScopeError::Config(_))
+        ));
+        assert!(ScopePolicy::load(Path::new("/nonexistent/scope.json")).is_err());
+    }
+}
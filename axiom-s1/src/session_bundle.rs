@@ -0,0 +1,701 @@
+//! Session Audit Bundles
+//!
+//! A bundle is a single JSON document with everything recorded for one
+//! session, for compliance review:
+//!
+//! ```text
+//! { "format": "axiom-s1-session-bundle/1",
+//!   "sections": [ { "name": "session", "items": [...], "count": 1, "hash": "..." }, ... ],
+//!   "merkle_root": "...",
+//!   "key_id": "sha256:...",
+//!   "signature": "..." }
+//! ```
+//!
+//! Sections, in order: `session` (the session record and export window),
+//! `thoughts` (the chain of thought), `receipts` and `dsif_audit` (stored
+//! receipts and DSIF audit entries from the session window), `bark_ledger`
+//! (the session's entropy charges) and `bark_metrics` (a snapshot taken at
+//! export). A section hash is SHA-256 over the section name followed by the
+//! SHA-256 of each item's JSON as written; the Merkle root over the section
+//! hashes is signed with the install's signing key, whose `key_id` the
+//! bundle records. Verification checks the signature against the same trust
+//! anchors as receipt files, so a bundle from another install verifies once
+//! its key is trusted.
+//!
+//! Sections are written and verified one item at a time, so neither export
+//! nor verification holds a whole session in memory.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::de::{self, DeserializeSeed, Deserializer, IgnoredAny, MapAccess, SeqAccess, Visitor};
+use serde::Serialize;
+use serde_json::value::RawValue;
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::fs::File;
+use std::io::{self, BufReader, BufWriter, Write};
+use std::path::Path;
+use thiserror::Error;
+
+use sap4d::signing::{KeySigner, ReceiptSigner};
+
+use crate::bark::BarkController;
+use crate::cozo_db::{CozoError, CozoStore};
+use crate::invariance;
+use crate::receipt_check::{SignatureCheck, TrustAnchors};
+
+/// Format tag written at the top of every bundle
+pub const BUNDLE_FORMAT: &str = "axiom-s1-session-bundle/1";
+
+/// Rows fetched from the store per query while exporting
+const PAGE_SIZE: usize = 256;
+
+#[derive(Error, Debug)]
+pub enum BundleError {
+    #[error("I/O error: {0}")]
+    Io(#[from] io::Error),
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+    #[error("Store error: {0}")]
+    Store(#[from] CozoError),
+    #[error("Unsupported bundle format: {0}")]
+    UnsupportedFormat(String),
+}
+
+/// One written section
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionSummary {
+    pub name: String,
+    pub count: usize,
+    pub hash: String,
+}
+
+/// Result of an export
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BundleSummary {
+    pub session_id: String,
+    pub sections: Vec<SectionSummary>,
+    pub merkle_root: String,
+    pub key_id: String,
+    pub signature: String,
+}
+
+/// Checks on one section of a bundle being verified
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct SectionCheck {
+    pub name: String,
+    /// Items actually present
+    pub count: usize,
+    /// The recorded count matches the items
+    pub count_ok: bool,
+    /// The recorded hash matches the items
+    pub hash_ok: bool,
+    /// Index of the first item whose own hashes fail, if any
+    pub invalid_item: Option<usize>,
+    pub reason: Option<String>,
+}
+
+impl SectionCheck {
+    pub fn is_valid(&self) -> bool {
+        self.count_ok && self.hash_ok && self.invalid_item.is_none()
+    }
+}
+
+/// Result of verifying a bundle
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct BundleVerification {
+    pub valid: bool,
+    pub merkle_root_ok: bool,
+    pub signature_ok: bool,
+    /// Who signed the Merkle root, as far as the trust anchors tell
+    pub signer: SignatureCheck,
+    pub sections: Vec<SectionCheck>,
+}
+
+/// Export a session bundle to `path`, signed by `signer`
+///
+/// Receipts and DSIF audit entries are taken from the session's lifetime
+/// (creation until close, or until now for an open session).
+pub fn export(
+    db: &CozoStore,
+    bark: &BarkController,
+    signer: &KeySigner,
+    session_id: &str,
+    path: &Path,
+) -> Result<BundleSummary, BundleError> {
+    let session = db.get_session(session_id)?;
+    let from = session["created_at"].as_f64().unwrap_or_default();
+    let to = session["closed_at"]
+        .as_f64()
+        .unwrap_or_else(|| chrono::Utc::now().timestamp_millis() as f64);
+
+    let mut out = BufWriter::new(File::create(path)?);
+    out.write_all(b"{\"format\":")?;
+    serde_json::to_writer(&mut out, BUNDLE_FORMAT)?;
+    out.write_all(b",\"sections\":[")?;
+
+    let mut sections = Vec::new();
+
+    let mut section = SectionWriter::begin(&mut out, "session", true)?;
+    section.push(&serde_json::json!({
+        "session": session,
+        "window": { "from": from, "to": to },
+        "exported_at": chrono::Utc::now().to_rfc3339(),
+        "substrate": crate::SUBSTRATE,
+        "projection": crate::PROJECTION
+    }))?;
+    sections.push(section.finish()?);
+
+    let mut section = SectionWriter::begin(&mut out, "thoughts", false)?;
+    let mut after = 0;
+    loop {
+        let page = db.get_chain_of_thought_page(session_id, after, PAGE_SIZE)?;
+        for thought in &page {
+            after = thought["sequence"].as_i64().unwrap_or(after);
+            section.push(thought)?;
+        }
+        if page.len() < PAGE_SIZE {
+            break;
+        }
+    }
+    sections.push(section.finish()?);
+
+    let mut section = SectionWriter::begin(&mut out, "receipts", false)?;
+    let mut offset = 0;
+    loop {
+        let page = db.load_receipts_between(from, to, offset, PAGE_SIZE)?;
+        for receipt in &page {
+            section.push(receipt)?;
+        }
+        offset += page.len();
+        if page.len() < PAGE_SIZE {
+            break;
+        }
+    }
+    sections.push(section.finish()?);
+
+    let mut section = SectionWriter::begin(&mut out, "dsif_audit", false)?;
+    let mut after = -1;
+    loop {
+        let page = db.load_dsif_records_page("entry", after, PAGE_SIZE)?;
+        for (sequence, entry) in &page {
+            after = *sequence;
+            let at = entry["timestamp"]
+                .as_str()
+                .and_then(|t| chrono::DateTime::parse_from_rfc3339(t).ok())
+                .map(|t| t.timestamp_millis() as f64);
+            if at.is_some_and(|at| at >= from && at <= to) {
+                section.push(entry)?;
+            }
+        }
+        if page.len() < PAGE_SIZE {
+            break;
+        }
+    }
+    sections.push(section.finish()?);
+
+    let mut section = SectionWriter::begin(&mut out, "bark_ledger", false)?;
+    for entry in bark.get_ledger(session_id) {
+        section.push(&entry)?;
+    }
+    sections.push(section.finish()?);
+
+    let mut section = SectionWriter::begin(&mut out, "bark_metrics", false)?;
+    section.push(&bark.get_metrics())?;
+    sections.push(section.finish()?);
+
+    let hashes: Vec<String> = sections.iter().map(|s| s.hash.clone()).collect();
+    let merkle_root = merkle_root(&hashes);
+    let key_id = signer.key_id().to_string();
+    let signature = signer.sign(&merkle_root);
+
+    out.write_all(b"],\"merkle_root\":")?;
+    serde_json::to_writer(&mut out, &merkle_root)?;
+    out.write_all(b",\"key_id\":")?;
+    serde_json::to_writer(&mut out, &key_id)?;
+    out.write_all(b",\"signature\":")?;
+    serde_json::to_writer(&mut out, &signature)?;
+    out.write_all(b"}")?;
+    out.flush()?;
+
+    tracing::info!("Exported session {} bundle to {}", session_id, path.display());
+    Ok(BundleSummary {
+        session_id: session_id.to_string(),
+        sections,
+        merkle_root,
+        key_id,
+        signature,
+    })
+}
+
+/// Re-check a bundle's Merkle root, per-section hashes and signature,
+/// trusting the signers in `trust`
+pub fn verify(path: &Path, trust: &TrustAnchors) -> Result<BundleVerification, BundleError> {
+    let reader = BufReader::new(File::open(path)?);
+    let mut deserializer = serde_json::Deserializer::from_reader(reader);
+    let parsed = (&mut deserializer).deserialize_map(BundleVisitor { trust })?;
+    deserializer.end()?;
+
+    if parsed.format != BUNDLE_FORMAT {
+        return Err(BundleError::UnsupportedFormat(parsed.format));
+    }
+
+    let hashes: Vec<String> = parsed.sections.iter().map(|s| s.recorded_hash.clone()).collect();
+    let merkle_root_ok = merkle_root(&hashes) == parsed.merkle_root;
+    let signer = trust.check(&parsed.merkle_root, &parsed.signature, parsed.key_id.as_deref());
+    let signature_ok = matches!(signer, SignatureCheck::Verified { .. });
+    let sections: Vec<SectionCheck> = parsed.sections.into_iter().map(|s| s.check).collect();
+
+    Ok(BundleVerification {
+        valid: merkle_root_ok && signature_ok && sections.iter().all(SectionCheck::is_valid),
+        merkle_root_ok,
+        signature_ok,
+        signer,
+        sections,
+    })
+}
+
+/// Merkle root over `leaves`; an odd leaf count duplicates the last leaf
+/// and an odd node higher up is carried to the next level
+pub(crate) fn merkle_root(leaves: &[String]) -> String {
+    let mut level = leaves.to_vec();
+    if level.is_empty() {
+        return invariance::sha256("");
+    }
+    if level.len() % 2 == 1 {
+        level.push(level[level.len() - 1].clone());
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => invariance::sha256(&format!("{}{}", left, right)),
+                [single] => single.clone(),
+                _ => unreachable!("chunks(2) yields one or two nodes"),
+            })
+            .collect();
+    }
+    level.remove(0)
+}
+
+/// Running hash over a section's items
+struct SectionHasher {
+    hasher: Sha256,
+    count: usize,
+}
+
+impl SectionHasher {
+    fn new(name: &str) -> Self {
+        let mut hasher = Sha256::new();
+        hasher.update(name.as_bytes());
+        Self { hasher, count: 0 }
+    }
+
+    fn add(&mut self, item_json: &str) {
+        self.hasher.update(Sha256::digest(item_json.as_bytes()));
+        self.count += 1;
+    }
+
+    fn finish(self) -> String {
+        hex::encode(self.hasher.finalize())
+    }
+}
+
+/// Streams one section's items to the bundle
+struct SectionWriter<'a, W: Write> {
+    out: &'a mut W,
+    name: String,
+    hasher: SectionHasher,
+}
+
+impl<'a, W: Write> SectionWriter<'a, W> {
+    fn begin(out: &'a mut W, name: &str, first: bool) -> Result<Self, BundleError> {
+        if !first {
+            out.write_all(b",")?;
+        }
+        out.write_all(b"{\"name\":")?;
+        serde_json::to_writer(&mut *out, name)?;
+        out.write_all(b",\"items\":[")?;
+
+        Ok(Self {
+            out,
+            name: name.to_string(),
+            hasher: SectionHasher::new(name),
+        })
+    }
+
+    fn push(&mut self, item: &impl Serialize) -> Result<(), BundleError> {
+        let json = serde_json::to_string(item)?;
+        if self.hasher.count > 0 {
+            self.out.write_all(b",")?;
+        }
+        self.out.write_all(json.as_bytes())?;
+        self.hasher.add(&json);
+        Ok(())
+    }
+
+    fn finish(self) -> Result<SectionSummary, BundleError> {
+        let count = self.hasher.count;
+        let hash = self.hasher.finish();
+        write!(self.out, "],\"count\":{},\"hash\":", count)?;
+        serde_json::to_writer(&mut *self.out, &hash)?;
+        self.out.write_all(b"}")?;
+
+        Ok(SectionSummary {
+            name: self.name,
+            count,
+            hash,
+        })
+    }
+}
+
+/// Per-item integrity checks for the sections that carry their own hashes
+struct ItemChecker<'a> {
+    section: &'a str,
+    trust: &'a TrustAnchors,
+    previous: Option<Value>,
+}
+
+impl ItemChecker<'_> {
+    fn check(&mut self, item: Value) -> Result<(), String> {
+        let result = match self.section {
+            "thoughts" => {
+                let content = item["content"].as_str().unwrap_or_default();
+                let after_previous = self
+                    .previous
+                    .as_ref()
+                    .map_or(true, |previous| item["sequence"].as_i64() > previous["sequence"].as_i64());
+                if item["hash"].as_str() != Some(invariance::sha256(content).as_str()) {
+                    Err("thought hash does not match its content".to_string())
+                } else if !after_previous {
+                    Err("thoughts are out of sequence".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            "receipts" => {
+                // Receipts that name their key are checked against the trust
+                // anchors; unkeyed ones come from `generate_receipt`
+                let signed = match item["key_id"].as_str() {
+                    Some(key_id) => {
+                        let hash = item["hash"].as_str().unwrap_or_default();
+                        let signature = item["signature"].as_str().unwrap_or_default();
+                        matches!(self.trust.check(hash, signature, Some(key_id)), SignatureCheck::Verified { .. })
+                    }
+                    None => invariance::verify_receipt(&item),
+                };
+                if signed {
+                    Ok(())
+                } else {
+                    Err("receipt signature does not match its hash".to_string())
+                }
+            }
+            "dsif_audit" => {
+                let linked = self
+                    .previous
+                    .as_ref()
+                    .map_or(true, |previous| item["previous_hash"] == previous["hash"]);
+                if item["hash"].as_str().map_or(true, str::is_empty) {
+                    Err("audit entry has no hash".to_string())
+                } else if !linked {
+                    Err("audit entry does not link to the previous entry".to_string())
+                } else {
+                    Ok(())
+                }
+            }
+            _ => Ok(()),
+        };
+        self.previous = Some(item);
+        result
+    }
+}
+
+/// Top-level fields of a bundle being verified
+struct ParsedBundle {
+    format: String,
+    sections: Vec<ParsedSection>,
+    merkle_root: String,
+    key_id: Option<String>,
+    signature: String,
+}
+
+struct ParsedSection {
+    check: SectionCheck,
+    recorded_hash: String,
+}
+
+struct BundleVisitor<'a> {
+    trust: &'a TrustAnchors,
+}
+
+impl<'de> Visitor<'de> for BundleVisitor<'_> {
+    type Value = ParsedBundle;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a session bundle")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut format = None;
+        let mut sections = None;
+        let mut merkle_root = None;
+        let mut key_id = None;
+        let mut signature = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "format" => format = Some(map.next_value()?),
+                "sections" => sections = Some(map.next_value_seed(SectionsSeed { trust: self.trust })?),
+                "merkle_root" => merkle_root = Some(map.next_value()?),
+                "key_id" => key_id = Some(map.next_value()?),
+                "signature" => signature = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        Ok(ParsedBundle {
+            format: format.ok_or_else(|| de::Error::missing_field("format"))?,
+            sections: sections.ok_or_else(|| de::Error::missing_field("sections"))?,
+            merkle_root: merkle_root.ok_or_else(|| de::Error::missing_field("merkle_root"))?,
+            key_id,
+            signature: signature.ok_or_else(|| de::Error::missing_field("signature"))?,
+        })
+    }
+}
+
+struct SectionsSeed<'a> {
+    trust: &'a TrustAnchors,
+}
+
+impl<'de> DeserializeSeed<'de> for SectionsSeed<'_> {
+    type Value = Vec<ParsedSection>;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SectionsSeed<'_> {
+    type Value = Vec<ParsedSection>;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list of sections")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut sections = Vec::new();
+        while let Some(section) = seq.next_element_seed(SectionSeed { trust: self.trust })? {
+            sections.push(section);
+        }
+        Ok(sections)
+    }
+}
+
+struct SectionSeed<'a> {
+    trust: &'a TrustAnchors,
+}
+
+impl<'de> DeserializeSeed<'de> for SectionSeed<'_> {
+    type Value = ParsedSection;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_map(self)
+    }
+}
+
+impl<'de> Visitor<'de> for SectionSeed<'_> {
+    type Value = ParsedSection;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a bundle section")
+    }
+
+    fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<Self::Value, A::Error> {
+        let mut name: Option<String> = None;
+        let mut items = None;
+        let mut count: Option<usize> = None;
+        let mut hash: Option<String> = None;
+
+        while let Some(key) = map.next_key::<String>()? {
+            match key.as_str() {
+                "name" => name = Some(map.next_value()?),
+                "items" => {
+                    // Items are hashed as they stream past, so the name must come first
+                    let section = name.as_deref().ok_or_else(|| de::Error::custom("section items before name"))?;
+                    items = Some(map.next_value_seed(ItemsSeed { section, trust: self.trust })?);
+                }
+                "count" => count = Some(map.next_value()?),
+                "hash" => hash = Some(map.next_value()?),
+                _ => {
+                    map.next_value::<IgnoredAny>()?;
+                }
+            }
+        }
+
+        let name = name.ok_or_else(|| de::Error::missing_field("name"))?;
+        let items = items.ok_or_else(|| de::Error::missing_field("items"))?;
+        let recorded_hash = hash.ok_or_else(|| de::Error::missing_field("hash"))?;
+        let (invalid_item, reason) = match items.invalid {
+            Some((index, reason)) => (Some(index), Some(reason)),
+            None => (None, None),
+        };
+
+        Ok(ParsedSection {
+            check: SectionCheck {
+                name,
+                count: items.count,
+                count_ok: count == Some(items.count),
+                hash_ok: items.hash == recorded_hash,
+                invalid_item,
+                reason,
+            },
+            recorded_hash,
+        })
+    }
+}
+
+/// What streaming a section's items established
+struct ItemsSummary {
+    count: usize,
+    hash: String,
+    invalid: Option<(usize, String)>,
+}
+
+struct ItemsSeed<'a> {
+    section: &'a str,
+    trust: &'a TrustAnchors,
+}
+
+impl<'de> DeserializeSeed<'de> for ItemsSeed<'_> {
+    type Value = ItemsSummary;
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<Self::Value, D::Error> {
+        deserializer.deserialize_seq(self)
+    }
+}
+
+impl<'de> Visitor<'de> for ItemsSeed<'_> {
+    type Value = ItemsSummary;
+
+    fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str("a list of section items")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<Self::Value, A::Error> {
+        let mut hasher = SectionHasher::new(self.section);
+        let mut checker = ItemChecker {
+            section: self.section,
+            trust: self.trust,
+            previous: None,
+        };
+        let mut invalid = None;
+
+        // Hash the raw bytes, so the check does not depend on re-serialization
+        while let Some(raw) = seq.next_element::<Box<RawValue>>()? {
+            let index = hasher.count;
+            hasher.add(raw.get());
+            if invalid.is_none() {
+                let item: Value = serde_json::from_str(raw.get()).map_err(de::Error::custom)?;
+                if let Err(reason) = checker.check(item) {
+                    invalid = Some((index, reason));
+                }
+            }
+        }
+
+        Ok(ItemsSummary {
+            count: hasher.count,
+            hash: hasher.finish(),
+            invalid,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_path(name: &str) -> std::path::PathBuf {
+        std::env::temp_dir().join(format!("{}-{}", name, std::process::id()))
+    }
+
+    fn signer() -> KeySigner {
+        KeySigner::new("bundle test key")
+    }
+
+    fn exported_session(name: &str) -> (std::path::PathBuf, BundleSummary) {
+        let store = CozoStore::new(&temp_path(&format!("{}-store", name))).unwrap();
+        let bark = BarkController::new().with_store(&store).unwrap();
+
+        let session = store.create_session("review").unwrap();
+        store.store_thought_in(&session, "observe", "page loaded", serde_json::json!({})).unwrap();
+        store.store_thought_in(&session, "decide", "summarize", serde_json::json!({})).unwrap();
+        store.store_receipt(&invariance::generate_receipt("claim", &["fact".to_string()])).unwrap();
+        let id = store.store_fact("weather", "The sky is blue", 1.0, "test").unwrap();
+        store.verify_fact(&id).unwrap();
+        invariance::prove_from_memory(&store, &signer(), "The sky is blue").unwrap();
+        bark.consume_entropy_for(&session, "llama3", 10.0).unwrap();
+
+        let path = temp_path(&format!("{}.json", name));
+        let summary = export(&store, &bark, &signer(), &session, &path).unwrap();
+        (path, summary)
+    }
+
+    #[test]
+    fn test_export_and_verify() {
+        let (path, summary) = exported_session("bundle-roundtrip");
+        let names: Vec<&str> = summary.sections.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, ["session", "thoughts", "receipts", "dsif_audit", "bark_ledger", "bark_metrics"]);
+        assert_eq!(summary.sections[1].count, 2);
+        assert_eq!(summary.sections[2].count, 2);
+        assert_eq!(summary.sections[4].count, 1);
+        assert_eq!(summary.key_id, signer().key_id());
+
+        let verification = verify(&path, &TrustAnchors::local_only(&signer())).unwrap();
+        assert!(verification.valid, "{:?}", verification);
+        assert_eq!(verification.signer, SignatureCheck::Verified { signer: summary.key_id.clone() });
+        assert_eq!(verification.sections[1].count, 2);
+
+        // Another install cannot vouch for the bundle until it trusts the key
+        let elsewhere = TrustAnchors::local_only(&KeySigner::new("another install"));
+        let verification = verify(&path, &elsewhere).unwrap();
+        assert!(!verification.signature_ok);
+        assert_eq!(verification.signer, SignatureCheck::UnknownKey { key_id: Some(summary.key_id.clone()) });
+        assert!(verify(&path, &elsewhere.with_anchor(signer().anchor())).unwrap().valid);
+    }
+
+    #[test]
+    fn test_tampered_bundle_detected() {
+        let (path, summary) = exported_session("bundle-tampered");
+        let bundle = std::fs::read_to_string(&path).unwrap();
+
+        // Rewriting a thought breaks its own hash and its section hash
+        let trust = TrustAnchors::local_only(&signer());
+        std::fs::write(&path, bundle.replace("page loaded", "page altered")).unwrap();
+        let verification = verify(&path, &trust).unwrap();
+        assert!(!verification.valid);
+        assert!(verification.merkle_root_ok);
+        let thoughts = &verification.sections[1];
+        assert!(!thoughts.hash_ok);
+        assert_eq!(thoughts.invalid_item, Some(0));
+
+        // A re-rooted bundle fails the signature
+        let forged = bundle.replace(&summary.merkle_root, &invariance::sha256("forged"));
+        std::fs::write(&path, forged).unwrap();
+        let verification = verify(&path, &trust).unwrap();
+        assert!(!verification.merkle_root_ok);
+        assert!(!verification.signature_ok);
+        assert_eq!(verification.signer, SignatureCheck::Invalid { key_id: summary.key_id.clone() });
+    }
+
+    #[test]
+    fn test_merkle_root_odd_leaves() {
+        let leaves: Vec<String> = ["a", "b", "c"].iter().map(|s| s.to_string()).collect();
+        let ab = invariance::sha256("ab");
+        let cc = invariance::sha256("cc");
+        assert_eq!(merkle_root(&leaves), invariance::sha256(&format!("{}{}", ab, cc)));
+    }
+}
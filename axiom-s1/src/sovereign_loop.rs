@@ -22,6 +22,7 @@ pub struct LoopResult {
 
 /// Execute the full sovereign loop
 pub async fn execute(
+    context: &inference::InferenceContext,
     intent: &str,
     target_url: Option<&str>,
 ) -> Result<LoopResult, String> {
@@ -50,7 +51,7 @@ pub async fn execute(
     let filtered = hk.neutralize(content);
     
     // Phase 3: SYNTHESIZE
-    let synthesis = inference::synthesize(&sensed, intent)
+    let synthesis = inference::synthesize(context, &sensed, intent)
         .await
         .map_err(|e| e.to_string())?;
     
@@ -0,0 +1,506 @@
+//! Watchdog - Deadlines for Long-Running Operations
+//!
+//! Inference calls, sandbox executions and scout fetches register with a
+//! per-kind deadline and a cancellation handle. A background sweep cancels
+//! anything past its deadline, logs a `WATCHDOG_TERMINATE` action, notifies
+//! the UI (`security://watchdog`) and issues a signed receipt documenting the
+//! intervention. A guard removes each registration as soon as the operation
+//! finishes, so the registry only ever holds live operations.
+//!
+//! Async operations use [`Watchdog::run`], which aborts the task. Blocking
+//! work such as a WASM call uses [`Watchdog::run_blocking`] with an
+//! interrupt (see `sandbox::Interrupt`).
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::future::Future;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use crate::cozo_db::CozoStore;
+
+/// Event emitted for every intervention
+pub const WATCHDOG_EVENT: &str = "security://watchdog";
+
+/// Action type recorded in the action log
+pub const WATCHDOG_ACTION: &str = "WATCHDOG_TERMINATE";
+
+#[derive(Error, Debug)]
+pub enum WatchdogError {
+    #[error("{kind} operation '{label}' terminated by watchdog after {elapsed_ms} ms")]
+    Terminated {
+        kind: OperationKind,
+        label: String,
+        elapsed_ms: u64,
+    },
+    #[error("{kind} operation '{label}' failed: {message}")]
+    Failed {
+        kind: OperationKind,
+        label: String,
+        message: String,
+    },
+}
+
+/// Kind of watched operation; each kind has its own deadline
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum OperationKind {
+    Inference,
+    Sandbox,
+    ScoutFetch,
+}
+
+impl OperationKind {
+    pub const ALL: [OperationKind; 3] = [
+        OperationKind::Inference,
+        OperationKind::Sandbox,
+        OperationKind::ScoutFetch,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            OperationKind::Inference => "inference",
+            OperationKind::Sandbox => "sandbox",
+            OperationKind::ScoutFetch => "scout_fetch",
+        }
+    }
+}
+
+impl fmt::Display for OperationKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// Watchdog configuration
+#[derive(Debug, Clone)]
+pub struct WatchdogConfig {
+    deadlines: HashMap<OperationKind, Duration>,
+    /// How often the sweep checks deadlines
+    pub poll_interval: Duration,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            deadlines: HashMap::from([
+                (OperationKind::Inference, Duration::from_secs(120)),
+                (OperationKind::Sandbox, Duration::from_secs(10)),
+                (OperationKind::ScoutFetch, Duration::from_secs(30)),
+            ]),
+            poll_interval: Duration::from_millis(250),
+        }
+    }
+}
+
+impl WatchdogConfig {
+    /// Defaults overridden by `AXIOM_WATCHDOG_<KIND>_MS` (e.g. `AXIOM_WATCHDOG_INFERENCE_MS`)
+    pub fn from_env() -> Self {
+        OperationKind::ALL.iter().fold(Self::default(), |config, kind| {
+            let var = format!("AXIOM_WATCHDOG_{}_MS", kind.as_str().to_uppercase());
+            match std::env::var(&var).ok().and_then(|v| v.parse().ok()) {
+                Some(ms) => config.with_deadline(*kind, Duration::from_millis(ms)),
+                None => config,
+            }
+        })
+    }
+
+    /// Set the deadline for one operation kind
+    pub fn with_deadline(mut self, kind: OperationKind, deadline: Duration) -> Self {
+        self.deadlines.insert(kind, deadline);
+        self
+    }
+
+    /// Set the sweep interval
+    pub fn with_poll_interval(mut self, interval: Duration) -> Self {
+        self.poll_interval = interval;
+        self
+    }
+
+    /// Deadline for an operation kind
+    pub fn deadline(&self, kind: OperationKind) -> Duration {
+        self.deadlines.get(&kind).copied().unwrap_or(Duration::from_secs(60))
+    }
+}
+
+/// Record of one watchdog termination
+#[derive(Debug, Clone, Serialize)]
+pub struct Intervention {
+    pub operation_id: u64,
+    pub kind: OperationKind,
+    pub label: String,
+    pub session_id: String,
+    pub elapsed_ms: u64,
+    pub deadline_ms: u64,
+    /// Signed receipt (see `invariance::generate_receipt`)
+    pub receipt: serde_json::Value,
+}
+
+/// A registered operation
+struct Watched {
+    kind: OperationKind,
+    label: String,
+    session_id: String,
+    started: Instant,
+    deadline: Duration,
+    cancel: Box<dyn FnOnce() + Send>,
+    fired: Arc<AtomicBool>,
+}
+
+type Notifier = Box<dyn Fn(&Intervention) + Send + Sync>;
+
+/// Deadline enforcer for long-running operations
+pub struct Watchdog {
+    config: WatchdogConfig,
+    registry: Mutex<HashMap<u64, Watched>>,
+    next_id: AtomicU64,
+    store: Option<CozoStore>,
+    notify: Option<Notifier>,
+}
+
+impl Watchdog {
+    /// Create a watchdog
+    pub fn new(config: WatchdogConfig) -> Self {
+        Self {
+            config,
+            registry: Mutex::new(HashMap::new()),
+            next_id: AtomicU64::new(1),
+            store: None,
+            notify: None,
+        }
+    }
+
+    /// Log interventions to the action log in `store`
+    pub fn with_store(mut self, store: &CozoStore) -> Self {
+        self.store = Some(store.clone());
+        self
+    }
+
+    /// Call `notify` for every intervention (e.g. to emit `WATCHDOG_EVENT`)
+    pub fn on_intervention(mut self, notify: impl Fn(&Intervention) + Send + Sync + 'static) -> Self {
+        self.notify = Some(Box::new(notify));
+        self
+    }
+
+    /// Active configuration
+    pub fn config(&self) -> &WatchdogConfig {
+        &self.config
+    }
+
+    /// Number of registered (still running) operations
+    pub fn active(&self) -> usize {
+        self.registry().len()
+    }
+
+    /// Register an operation; it is deregistered when the guard drops
+    pub fn register(
+        self: &Arc<Self>,
+        kind: OperationKind,
+        label: &str,
+        session_id: &str,
+        cancel: impl FnOnce() + Send + 'static,
+    ) -> WatchGuard {
+        let id = self.next_id.fetch_add(1, Ordering::Relaxed);
+        let fired = Arc::new(AtomicBool::new(false));
+
+        self.registry().insert(
+            id,
+            Watched {
+                kind,
+                label: label.to_string(),
+                session_id: session_id.to_string(),
+                started: Instant::now(),
+                deadline: self.config.deadline(kind),
+                cancel: Box::new(cancel),
+                fired: fired.clone(),
+            },
+        );
+
+        WatchGuard {
+            watchdog: self.clone(),
+            id,
+            fired,
+        }
+    }
+
+    /// Run a future under the watchdog, aborting it past the deadline
+    pub async fn run<F, T>(
+        self: &Arc<Self>,
+        kind: OperationKind,
+        label: &str,
+        session_id: &str,
+        operation: F,
+    ) -> Result<T, WatchdogError>
+    where
+        F: Future<Output = T> + Send + 'static,
+        T: Send + 'static,
+    {
+        let started = Instant::now();
+        let task = tokio::spawn(operation);
+        let abort = task.abort_handle();
+        let guard = self.register(kind, label, session_id, move || abort.abort());
+
+        let result = task.await;
+        let terminated = guard.fired();
+        drop(guard);
+
+        match result {
+            Ok(value) if !terminated => Ok(value),
+            Ok(_) => Err(terminated_error(kind, label, started)),
+            Err(e) if e.is_cancelled() => Err(terminated_error(kind, label, started)),
+            Err(e) => Err(WatchdogError::Failed {
+                kind,
+                label: label.to_string(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Run blocking work under the watchdog; `interrupt` stops it past the deadline
+    pub async fn run_blocking<F, T>(
+        self: &Arc<Self>,
+        kind: OperationKind,
+        label: &str,
+        session_id: &str,
+        interrupt: impl FnOnce() + Send + 'static,
+        operation: F,
+    ) -> Result<T, WatchdogError>
+    where
+        F: FnOnce() -> T + Send + 'static,
+        T: Send + 'static,
+    {
+        let started = Instant::now();
+        let guard = self.register(kind, label, session_id, interrupt);
+        let result = tokio::task::spawn_blocking(operation).await;
+        let terminated = guard.fired();
+        drop(guard);
+
+        match result {
+            _ if terminated => Err(terminated_error(kind, label, started)),
+            Ok(value) => Ok(value),
+            Err(e) => Err(WatchdogError::Failed {
+                kind,
+                label: label.to_string(),
+                message: e.to_string(),
+            }),
+        }
+    }
+
+    /// Background sweep loop; ends once the watchdog is dropped
+    pub fn watch(self: &Arc<Self>) -> impl Future<Output = ()> + Send + 'static {
+        let watchdog = Arc::downgrade(self);
+        let interval = self.config.poll_interval;
+        async move {
+            loop {
+                tokio::time::sleep(interval).await;
+                match watchdog.upgrade() {
+                    Some(watchdog) => {
+                        watchdog.sweep();
+                    }
+                    None => break,
+                }
+            }
+        }
+    }
+
+    /// Terminate every operation past its deadline
+    pub fn sweep(&self) -> Vec<Intervention> {
+        let expired: Vec<(u64, Watched)> = {
+            let mut registry = self.registry();
+            let ids: Vec<u64> = registry
+                .iter()
+                .filter(|(_, w)| w.started.elapsed() >= w.deadline)
+                .map(|(id, _)| *id)
+                .collect();
+            ids.into_iter()
+                .filter_map(|id| registry.remove(&id).map(|w| (id, w)))
+                .collect()
+        };
+
+        expired
+            .into_iter()
+            .map(|(id, watched)| self.terminate(id, watched))
+            .collect()
+    }
+
+    fn terminate(&self, id: u64, watched: Watched) -> Intervention {
+        watched.fired.store(true, Ordering::SeqCst);
+        (watched.cancel)();
+
+        let elapsed_ms = watched.started.elapsed().as_millis() as u64;
+        let deadline_ms = watched.deadline.as_millis() as u64;
+        let operation = format!("{}:{}", watched.kind, watched.label);
+
+        let evidence = vec![
+            format!("operation_id: {}", id),
+            format!("kind: {}", watched.kind),
+            format!("label: {}", watched.label),
+            format!("session_id: {}", watched.session_id),
+            format!("elapsed_ms: {}", elapsed_ms),
+            format!("deadline_ms: {}", deadline_ms),
+        ];
+        let receipt = crate::invariance::generate_receipt(
+            &format!("operation {} terminated by watchdog", operation),
+            &evidence,
+        );
+
+        tracing::warn!(
+            "Watchdog terminated {} in session {} after {} ms (deadline {} ms)",
+            operation,
+            watched.session_id,
+            elapsed_ms,
+            deadline_ms
+        );
+
+        let intervention = Intervention {
+            operation_id: id,
+            kind: watched.kind,
+            label: watched.label,
+            session_id: watched.session_id,
+            elapsed_ms,
+            deadline_ms,
+            receipt,
+        };
+
+        if let Some(ref store) = self.store {
+            let result = serde_json::json!({
+                "kind": intervention.kind,
+                "session_id": intervention.session_id,
+                "elapsed_ms": elapsed_ms,
+                "deadline_ms": deadline_ms,
+                "receipt": intervention.receipt,
+            });
+            if let Err(e) = store.log_action(WATCHDOG_ACTION, &operation, &result.to_string(), 0.0) {
+                tracing::warn!("Failed to log watchdog action: {}", e);
+            }
+        }
+
+        if let Some(ref notify) = self.notify {
+            notify(&intervention);
+        }
+
+        intervention
+    }
+
+    fn registry(&self) -> MutexGuard<'_, HashMap<u64, Watched>> {
+        self.registry.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+fn terminated_error(kind: OperationKind, label: &str, started: Instant) -> WatchdogError {
+    WatchdogError::Terminated {
+        kind,
+        label: label.to_string(),
+        elapsed_ms: started.elapsed().as_millis() as u64,
+    }
+}
+
+/// Registration handle; deregisters the operation on drop
+pub struct WatchGuard {
+    watchdog: Arc<Watchdog>,
+    id: u64,
+    fired: Arc<AtomicBool>,
+}
+
+impl WatchGuard {
+    /// Whether the watchdog terminated this operation
+    pub fn fired(&self) -> bool {
+        self.fired.load(Ordering::SeqCst)
+    }
+}
+
+impl Drop for WatchGuard {
+    fn drop(&mut self) {
+        self.watchdog.registry().remove(&self.id);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_slow_inference_terminated() {
+        let path = std::env::temp_dir().join(format!("watchdog-{}", std::process::id()));
+        let store = CozoStore::new(&path).unwrap();
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let sink = events.clone();
+
+        let config = WatchdogConfig::default()
+            .with_deadline(OperationKind::Inference, Duration::from_millis(50))
+            .with_poll_interval(Duration::from_millis(10));
+        let watchdog = Arc::new(
+            Watchdog::new(config)
+                .with_store(&store)
+                .on_intervention(move |i| sink.lock().unwrap().push(i.clone())),
+        );
+        tokio::spawn(watchdog.watch());
+
+        // Simulated inference that never finishes in time
+        let result = watchdog
+            .run(OperationKind::Inference, "phi-3", "session-1", async {
+                tokio::time::sleep(Duration::from_secs(30)).await;
+                "response"
+            })
+            .await;
+
+        match result {
+            Err(WatchdogError::Terminated { kind, elapsed_ms, .. }) => {
+                assert_eq!(kind, OperationKind::Inference);
+                assert!(elapsed_ms < 5_000);
+            }
+            other => panic!("expected termination, got {:?}", other),
+        }
+        assert_eq!(watchdog.active(), 0);
+
+        let actions = store.load_actions(WATCHDOG_ACTION).unwrap();
+        assert_eq!(actions.len(), 1);
+        assert_eq!(actions[0]["target"], "inference:phi-3");
+        assert_eq!(actions[0]["result"]["session_id"], "session-1");
+        assert!(actions[0]["result"]["elapsed_ms"].as_u64().unwrap() >= 50);
+
+        let events = events.lock().unwrap();
+        assert_eq!(events.len(), 1);
+        let receipt = &events[0].receipt;
+        assert_eq!(receipt["claim"], "operation inference:phi-3 terminated by watchdog");
+        assert!(crate::invariance::verify_receipt(receipt));
+        assert_eq!(actions[0]["result"]["receipt"]["hash"], receipt["hash"]);
+
+        let _ = std::fs::remove_dir_all(&path);
+    }
+
+    #[tokio::test]
+    async fn test_completed_operations_deregister() {
+        let config = WatchdogConfig::default().with_deadline(OperationKind::Sandbox, Duration::ZERO);
+        let watchdog = Arc::new(Watchdog::new(config));
+
+        for i in 0..3 {
+            let value = watchdog
+                .run(OperationKind::Inference, "phi-3", "session-1", async move { i })
+                .await
+                .unwrap();
+            assert_eq!(value, i);
+        }
+        assert_eq!(watchdog.active(), 0);
+        assert!(watchdog.sweep().is_empty());
+
+        // Blocking work is interrupted through its handle
+        let interrupted = Arc::new(AtomicBool::new(false));
+        let flag = interrupted.clone();
+        let guard = watchdog.register(OperationKind::Sandbox, "module.wasm", "session-2", move || {
+            flag.store(true, Ordering::SeqCst)
+        });
+        let interventions = watchdog.sweep();
+        assert_eq!(interventions.len(), 1);
+        assert_eq!(interventions[0].session_id, "session-2");
+        assert!(interrupted.load(Ordering::SeqCst));
+        assert!(guard.fired());
+        drop(guard);
+        assert_eq!(watchdog.active(), 0);
+    }
+}
@@ -24,6 +24,15 @@ interface Metrics {
   c_zero_compliant: boolean;
 }
 
+interface ScoutFailure {
+  kind: string;
+  status_code: number | null;
+  retryable: boolean;
+  retry_after_secs: number | null;
+  message: string;
+  url: string;
+}
+
 // Main App
 export default function App() {
   const [systemInfo, setSystemInfo] = useState<SystemInfo | null>(null);
@@ -51,7 +60,17 @@ export default function App() {
       const result = await invoke('cmd_scout_url', { url });
       setContent(JSON.stringify(result, null, 2));
     } catch (e) {
-      setContent(`Error: ${e}`);
+      const failure = e as ScoutFailure;
+      if (failure && typeof failure === 'object' && 'kind' in failure) {
+        const retry = failure.retryable
+          ? failure.retry_after_secs != null
+            ? ` (retry in ${failure.retry_after_secs}s)`
+            : ' (retryable)'
+          : '';
+        setContent(`Error [${failure.kind}]: ${failure.message}${retry}`);
+      } else {
+        setContent(`Error: ${e}`);
+      }
     }
     setIsLoading(false);
   };
@@ -153,7 +153,7 @@ build_all() {
     
     # Build components
     build_rust_component "sap4d-cli" "sap4d"
-    build_rust_component "axiom-audit" "audit"
+    build_rust_component "auditd" "audit"
     build_rust_component "axiom-portal" "portal"
     build_rust_component "hunter-killer" "tools/hunter_killer"
     build_python_component
@@ -178,7 +178,7 @@ case "${1:-all}" in
         ;;
     rust)
         build_rust_component "sap4d-cli" "sap4d"
-        build_rust_component "axiom-audit" "audit"
+        build_rust_component "auditd" "audit"
         build_rust_component "axiom-portal" "portal"
         build_rust_component "hunter-killer" "tools/hunter_killer"
         ;;
@@ -0,0 +1,230 @@
+//! # AXIOM HIVE Verification Portal client
+//!
+//! A small blocking client for the portal's public API, typed after the
+//! schemas the portal serves at `GET /openapi.json`:
+//!
+//! ```no_run
+//! let client = portal_client::PortalClient::new("http://localhost:3000")?;
+//! let verdict = client.verify("The deployment succeeded", &["The deployment succeeded".to_string()])?;
+//! if let Some(receipt) = client.get_receipt(&verdict.hash)? {
+//!     println!("{} -> {}", receipt.claim, receipt.c_zero);
+//! }
+//! # Ok::<(), portal_client::ClientError>(())
+//! ```
+//!
+//! The client blocks, so call it from a thread rather than an async task.
+//! Error statuses are mapped to [`ClientError`]; a 404 from
+//! [`PortalClient::get_receipt`] is `Ok(None)`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use reqwest::blocking::{Client, Response};
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::time::Duration;
+use thiserror::Error;
+
+/// Request timeout unless [`PortalClient::with_timeout`] says otherwise
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+// ============================================================================
+// Types
+// ============================================================================
+
+/// Body of `POST /verify`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyRequest {
+    pub claim: String,
+    pub evidence: Vec<String>,
+}
+
+/// Binary verdict from `POST /verify`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VerifyResponse {
+    #[serde(rename = "C_zero")]
+    pub c_zero: bool,
+    pub hash: String,
+    pub signature: String,
+    pub timestamp: String,
+    /// Why the claim was not verified (e.g. `INSUFFICIENT_EVIDENCE`)
+    #[serde(default)]
+    pub reason: Option<String>,
+    /// Returned from the portal's cache rather than minted for this request
+    #[serde(default)]
+    pub cached: bool,
+}
+
+/// Receipt from `GET /receipt/{hash}`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StoredReceipt {
+    pub claim: String,
+    pub evidence: Vec<String>,
+    pub c_zero: bool,
+    pub hash: String,
+    pub signature: String,
+    pub timestamp: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Request counts for one client, as listed in `GET /stats`
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ClientCount {
+    pub ip: String,
+    pub requests: u64,
+    pub rate_limited: u64,
+}
+
+/// Portal counters from `GET /stats`
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PortalStats {
+    pub total_verifications: u64,
+    pub verified_count: u64,
+    pub not_verified_count: u64,
+    #[serde(default)]
+    pub cache_hits: u64,
+    pub total_audits: u64,
+    pub audits_proof_exists: u64,
+    pub audits_no_proof: u64,
+    #[serde(default)]
+    pub audits_no_proof_by_reason: BTreeMap<String, u64>,
+    pub rejected_rate_limited: u64,
+    pub uptime_seconds: u64,
+    #[serde(default)]
+    pub top_clients: Vec<ClientCount>,
+}
+
+/// Body of a 429 response
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct RateLimitError {
+    error: String,
+    retry_after: u64,
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+#[derive(Error, Debug)]
+pub enum ClientError {
+    #[error("Invalid portal URL '{0}'")]
+    InvalidUrl(String),
+    #[error("Portal request failed: {0}")]
+    Http(#[from] reqwest::Error),
+    #[error("Rate limited by the portal; retry after {retry_after}s")]
+    RateLimited { retry_after: u64 },
+    #[error("Portal rejected the request ({status}): {message}")]
+    Rejected { status: u16, message: String },
+}
+
+pub type Result<T> = std::result::Result<T, ClientError>;
+
+// ============================================================================
+// Client
+// ============================================================================
+
+/// Blocking client for one portal
+#[derive(Debug, Clone)]
+pub struct PortalClient {
+    base_url: String,
+    http: Client,
+}
+
+impl PortalClient {
+    /// Client for the portal at `base_url` (e.g. `http://localhost:3000`, or
+    /// the prefix it is nested under)
+    pub fn new(base_url: impl Into<String>) -> Result<Self> {
+        Self::with_timeout(base_url, DEFAULT_TIMEOUT)
+    }
+
+    /// Client whose requests give up after `timeout`
+    pub fn with_timeout(base_url: impl Into<String>, timeout: Duration) -> Result<Self> {
+        let base_url = base_url.into().trim_end_matches('/').to_string();
+        if !(base_url.starts_with("http://") || base_url.starts_with("https://")) {
+            return Err(ClientError::InvalidUrl(base_url));
+        }
+        let http = Client::builder().timeout(timeout).build()?;
+        Ok(Self { base_url, http })
+    }
+
+    pub fn base_url(&self) -> &str {
+        &self.base_url
+    }
+
+    /// Submit a claim for verification (`POST /verify`)
+    pub fn verify(&self, claim: &str, evidence: &[String]) -> Result<VerifyResponse> {
+        let request = VerifyRequest {
+            claim: claim.to_string(),
+            evidence: evidence.to_vec(),
+        };
+        let response = self.http.post(self.url("/verify")).json(&request).send()?;
+        parse(response)
+    }
+
+    /// The receipt with `hash` (`GET /receipt/{hash}`), or `None` if the
+    /// portal does not hold it
+    pub fn get_receipt(&self, hash: &str) -> Result<Option<StoredReceipt>> {
+        let response = self.http.get(self.url(&format!("/receipt/{}", hash))).send()?;
+        if response.status() == StatusCode::NOT_FOUND {
+            return Ok(None);
+        }
+        parse(response).map(Some)
+    }
+
+    /// Portal statistics (`GET /stats`)
+    pub fn stats(&self) -> Result<PortalStats> {
+        parse(self.http.get(self.url("/stats")).send()?)
+    }
+
+    fn url(&self, path: &str) -> String {
+        format!("{}{}", self.base_url, path)
+    }
+}
+
+/// Decode a successful response, or map its status to a [`ClientError`]
+fn parse<T: DeserializeOwned>(response: Response) -> Result<T> {
+    let status = response.status();
+    if status.is_success() {
+        return Ok(response.json()?);
+    }
+    let body = response.text().unwrap_or_default();
+    if status == StatusCode::TOO_MANY_REQUESTS {
+        let retry_after = serde_json::from_str::<RateLimitError>(&body).map_or(0, |e| e.retry_after);
+        return Err(ClientError::RateLimited { retry_after });
+    }
+    Err(ClientError::Rejected {
+        status: status.as_u16(),
+        message: body,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_base_url_is_normalized() {
+        let client = PortalClient::new("http://localhost:3000/portal/").unwrap();
+        assert_eq!(client.base_url(), "http://localhost:3000/portal");
+        assert_eq!(client.url("/stats"), "http://localhost:3000/portal/stats");
+
+        assert!(matches!(PortalClient::new("localhost:3000"), Err(ClientError::InvalidUrl(_))));
+    }
+
+    #[test]
+    fn test_wire_names_match_the_portal() {
+        let response: VerifyResponse = serde_json::from_str(
+            r#"{"C_zero": true, "hash": "ab", "signature": "sig", "timestamp": "2025-06-01T12:00:00+00:00"}"#,
+        )
+        .unwrap();
+        assert!(response.c_zero);
+        assert!(!response.cached);
+        assert_eq!(response.reason, None);
+
+        let limited: RateLimitError =
+            serde_json::from_str(r#"{"error": "Rate limit exceeded", "retry_after": 7}"#).unwrap();
+        assert_eq!(limited.retry_after, 7);
+    }
+}
@@ -0,0 +1,58 @@
+//! Mount the verification portal inside an existing axum application
+//!
+//! The host keeps its own routes, signs portal receipts with its own key and
+//! holds a handle to the receipt store. Run with
+//! `cargo run -p axiom-portal --example embed`, then
+//! `curl -X POST localhost:8080/portal/verify -H 'content-type: application/json' -d '{"claim":"A claim","evidence":["A claim"]}'`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use anyhow::Context;
+use axum::{extract::State, routing::get, Json, Router};
+use portal::listing::ReceiptFilter;
+use portal::receipt_check::{hmac_sign, TrustAnchor};
+use portal::{build_router, MemoryStore, PortalConfig, ReceiptStore, TrustAnchors};
+use std::net::SocketAddr;
+use std::sync::Arc;
+
+/// The host application's signing secret (load it from a secret store in practice)
+const HOST_SECRET: &[u8] = b"host-app secret";
+
+fn host_sign(hash: &str) -> String {
+    hmac_sign(HOST_SECRET, hash)
+}
+
+/// A host route reading the portal's receipts
+async fn receipt_count(State(store): State<Arc<MemoryStore>>) -> Json<serde_json::Value> {
+    let count = store.export(&ReceiptFilter::default()).await.map_or(0, |r| r.len());
+    Json(serde_json::json!({ "receipts": count }))
+}
+
+#[tokio::main]
+async fn main() -> anyhow::Result<()> {
+    let store = Arc::new(MemoryStore::new());
+
+    let portal = build_router(PortalConfig {
+        store: store.clone(),
+        signer: host_sign,
+        // Receipts the host signs for its own use verify at /portal/verify-receipt
+        trust: TrustAnchors::portal_only().with_anchor(TrustAnchor::hmac("host-app", HOST_SECRET)),
+        ..PortalConfig::default()
+    });
+
+    let app = Router::new()
+        .route("/", get(|| async { "Host application" }))
+        .route("/receipt-count", get(receipt_count))
+        .with_state(store)
+        .nest("/portal", portal);
+
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:8080")
+        .await
+        .context("Failed to bind 127.0.0.1:8080")?;
+    println!("Host listening on http://127.0.0.1:8080 (portal under /portal)");
+
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>())
+        .await
+        .context("Host server failed")?;
+    Ok(())
+}
@@ -0,0 +1,650 @@
+//! Verification backends
+//!
+//! The portal either audits in-process (`LocalBackend`) or acts as a thin
+//! front door to a separate audit service (`RemoteBackend`). Remote calls are
+//! bounded by a timeout, retried with exponential backoff, and guarded by a
+//! circuit breaker so a down backend fails fast with 503 instead of hanging.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use async_trait::async_trait;
+use axiom_audit::{
+    levels::{codes, SubOperation},
+    service::{AuditRequest, AuditResponse},
+    AuditError, AuditReceipt, AuditService,
+};
+use axum::http::StatusCode;
+use serde::{de::DeserializeOwned, Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::{Duration, Instant};
+use thiserror::Error;
+use tokio::sync::Mutex;
+use utoipa::ToSchema;
+
+/// Signing function for receipts issued or co-signed by the portal
+pub type SignFn = fn(&str) -> String;
+
+/// Errors from a verification backend
+#[derive(Debug, Error)]
+pub enum BackendError {
+    #[error("Audit error: {0}")]
+    Audit(#[from] AuditError),
+
+    #[error("Remote backend rejected the request ({status}): {message}")]
+    Rejected { status: StatusCode, message: String },
+
+    #[error("Remote backend timed out after {}ms", .0.as_millis())]
+    Timeout(Duration),
+
+    #[error("Remote backend unavailable: {0}")]
+    Unavailable(String),
+
+    #[error("Remote backend circuit open after repeated failures; retry in {}s", .retry_after.as_secs().max(1))]
+    CircuitOpen { retry_after: Duration },
+
+    #[error("Remote backend returned an invalid receipt: {0}")]
+    InvalidReceipt(String),
+
+    #[error("Backend configuration error: {0}")]
+    Config(String),
+}
+
+impl BackendError {
+    /// HTTP status the portal answers with (malformed input is 422, not "not verified")
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::Audit(AuditError::InvalidClaim) => StatusCode::UNPROCESSABLE_ENTITY,
+            Self::Audit(_) => StatusCode::BAD_REQUEST,
+            Self::Rejected { status, .. } => *status,
+            Self::Timeout(_) => StatusCode::GATEWAY_TIMEOUT,
+            Self::Unavailable(_) | Self::CircuitOpen { .. } => StatusCode::SERVICE_UNAVAILABLE,
+            Self::InvalidReceipt(_) => StatusCode::BAD_GATEWAY,
+            Self::Config(_) => StatusCode::INTERNAL_SERVER_ERROR,
+        }
+    }
+
+    /// Whether a remote call failing this way may succeed on retry
+    fn is_retryable(&self) -> bool {
+        matches!(self, Self::Timeout(_) | Self::Unavailable(_))
+    }
+}
+
+pub type Result<T> = std::result::Result<T, BackendError>;
+
+/// L1 outcome behind a `/verify` answer
+#[derive(Debug, Clone, PartialEq, Eq, Default, Deserialize)]
+pub struct QuickVerdict {
+    pub proof_exists: bool,
+    /// Failure reason code (e.g. `INSUFFICIENT_EVIDENCE`) when no proof exists
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl QuickVerdict {
+    /// Not verified because too little evidence was supplied
+    pub fn insufficient_evidence() -> Self {
+        Self {
+            proof_exists: false,
+            reason: Some(codes::INSUFFICIENT_EVIDENCE.to_string()),
+        }
+    }
+}
+
+/// Where `/verify` and `/audit` are evaluated
+#[async_trait]
+pub trait VerificationBackend: Send + Sync {
+    /// Backend name ("local" or "remote")
+    fn name(&self) -> &'static str;
+
+    /// L1 check: does a proof exist for the claim, and if not, why?
+    async fn quick_verify(&self, claim: &str, evidence: &[String]) -> Result<QuickVerdict>;
+
+    /// Full L1/L2/L3 audit, returning the signed receipt
+    async fn audit(
+        &self,
+        claim: &str,
+        evidence: &[String],
+        sub_operations: &[SubOperation],
+    ) -> Result<AuditReceipt>;
+}
+
+// ============================================================================
+// Local
+// ============================================================================
+
+/// Embedded AuditService; receipts are signed by the portal
+pub struct LocalBackend {
+    service: Mutex<AuditService>,
+    sign_fn: SignFn,
+}
+
+impl LocalBackend {
+    /// Create a local backend
+    pub fn new(service: AuditService, sign_fn: SignFn) -> Self {
+        Self {
+            service: Mutex::new(service),
+            sign_fn,
+        }
+    }
+}
+
+#[async_trait]
+impl VerificationBackend for LocalBackend {
+    fn name(&self) -> &'static str {
+        "local"
+    }
+
+    async fn quick_verify(&self, claim: &str, evidence: &[String]) -> Result<QuickVerdict> {
+        let service = self.service.lock().await;
+        let result = service.quick_audit(claim, evidence)?;
+        Ok(QuickVerdict {
+            proof_exists: result.proof.exists(),
+            reason: result.reason.map(|reason| reason.code().to_string()),
+        })
+    }
+
+    async fn audit(
+        &self,
+        claim: &str,
+        evidence: &[String],
+        sub_operations: &[SubOperation],
+    ) -> Result<AuditReceipt> {
+        let mut service = self.service.lock().await;
+        Ok(service.audit_with_ops(claim, evidence, sub_operations, self.sign_fn)?)
+    }
+}
+
+// ============================================================================
+// Remote
+// ============================================================================
+
+/// Remote backend settings
+#[derive(Debug, Clone)]
+pub struct RemoteConfig {
+    /// Base URL of the audit service (e.g. `https://audit.internal:3001`)
+    pub endpoint: String,
+    /// Per-attempt request timeout
+    pub timeout: Duration,
+    /// Retries after the first attempt (timeouts and 5xx only)
+    pub max_retries: u32,
+    /// Backoff before the first retry; doubles per retry
+    pub backoff: Duration,
+    /// Consecutive failed calls before the circuit opens
+    pub failure_threshold: u32,
+    /// How long the circuit stays open before a trial call is allowed
+    pub cooldown: Duration,
+    /// PEM file with the client certificate and private key (mTLS)
+    pub client_identity: Option<PathBuf>,
+    /// PEM file with the CA certificate the backend's certificate chains to
+    pub ca_cert: Option<PathBuf>,
+}
+
+impl RemoteConfig {
+    /// Defaults for a backend at `endpoint`
+    pub fn new(endpoint: impl Into<String>) -> Self {
+        Self {
+            endpoint: endpoint.into(),
+            timeout: Duration::from_secs(5),
+            max_retries: 2,
+            backoff: Duration::from_millis(100),
+            failure_threshold: 5,
+            cooldown: Duration::from_secs(30),
+            client_identity: None,
+            ca_cert: None,
+        }
+    }
+}
+
+/// Backend selection (`PORTAL_BACKEND=local|remote`)
+#[derive(Debug, Clone)]
+pub enum BackendConfig {
+    Local,
+    Remote(RemoteConfig),
+}
+
+impl BackendConfig {
+    /// Read the backend selection from the environment
+    pub fn from_env() -> Result<Self> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// Read the backend selection from `lookup`
+    ///
+    /// Remote settings: `PORTAL_REMOTE_URL` (required), `PORTAL_REMOTE_TIMEOUT_MS`,
+    /// `PORTAL_REMOTE_RETRIES`, `PORTAL_REMOTE_BACKOFF_MS`,
+    /// `PORTAL_REMOTE_FAILURE_THRESHOLD`, `PORTAL_REMOTE_COOLDOWN_MS`,
+    /// `PORTAL_REMOTE_CLIENT_IDENTITY`, `PORTAL_REMOTE_CA_CERT`.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Self> {
+        let backend = lookup("PORTAL_BACKEND").unwrap_or_else(|| "local".to_string());
+        match backend.as_str() {
+            "local" => Ok(Self::Local),
+            "remote" => {
+                let endpoint = lookup("PORTAL_REMOTE_URL").ok_or_else(|| {
+                    BackendError::Config("PORTAL_REMOTE_URL is required for the remote backend".to_string())
+                })?;
+                let mut config = RemoteConfig::new(endpoint.trim_end_matches('/'));
+
+                let number = |key: &str| -> Result<Option<u64>> {
+                    lookup(key)
+                        .map(|v| {
+                            v.parse()
+                                .map_err(|_| BackendError::Config(format!("{} must be a number, got '{}'", key, v)))
+                        })
+                        .transpose()
+                };
+                if let Some(ms) = number("PORTAL_REMOTE_TIMEOUT_MS")? {
+                    config.timeout = Duration::from_millis(ms);
+                }
+                if let Some(n) = number("PORTAL_REMOTE_RETRIES")? {
+                    config.max_retries = n as u32;
+                }
+                if let Some(ms) = number("PORTAL_REMOTE_BACKOFF_MS")? {
+                    config.backoff = Duration::from_millis(ms);
+                }
+                if let Some(n) = number("PORTAL_REMOTE_FAILURE_THRESHOLD")? {
+                    config.failure_threshold = (n as u32).max(1);
+                }
+                if let Some(ms) = number("PORTAL_REMOTE_COOLDOWN_MS")? {
+                    config.cooldown = Duration::from_millis(ms);
+                }
+                config.client_identity = lookup("PORTAL_REMOTE_CLIENT_IDENTITY").map(PathBuf::from);
+                config.ca_cert = lookup("PORTAL_REMOTE_CA_CERT").map(PathBuf::from);
+
+                Ok(Self::Remote(config))
+            }
+            other => Err(BackendError::Config(format!(
+                "PORTAL_BACKEND must be 'local' or 'remote', got '{}'",
+                other
+            ))),
+        }
+    }
+
+    /// Build the selected backend
+    pub fn build(self, sign_fn: SignFn) -> Result<Box<dyn VerificationBackend>> {
+        match self {
+            Self::Local => Ok(Box::new(LocalBackend::new(AuditService::new(), sign_fn))),
+            Self::Remote(config) => Ok(Box::new(RemoteBackend::new(config)?)),
+        }
+    }
+}
+
+/// Consecutive-failure circuit breaker
+///
+/// Closed until `threshold` calls in a row fail, then open for `cooldown`.
+/// After the cooldown a trial call goes through; failure reopens the circuit,
+/// success closes it.
+struct CircuitBreaker {
+    threshold: u32,
+    cooldown: Duration,
+    state: std::sync::Mutex<BreakerState>,
+}
+
+#[derive(Default)]
+struct BreakerState {
+    consecutive_failures: u32,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    fn new(threshold: u32, cooldown: Duration) -> Self {
+        Self {
+            threshold,
+            cooldown,
+            state: std::sync::Mutex::new(BreakerState::default()),
+        }
+    }
+
+    fn state(&self) -> std::sync::MutexGuard<'_, BreakerState> {
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn check(&self) -> Result<()> {
+        // One reading of the clock: a second one could pass the cooldown
+        let elapsed = self.state().opened_at.map(|at| at.elapsed());
+        match elapsed {
+            Some(elapsed) if elapsed < self.cooldown => Err(BackendError::CircuitOpen {
+                retry_after: self.cooldown.saturating_sub(elapsed),
+            }),
+            _ => Ok(()),
+        }
+    }
+
+    fn record_success(&self) {
+        *self.state() = BreakerState::default();
+    }
+
+    fn record_failure(&self) {
+        let mut state = self.state();
+        state.consecutive_failures += 1;
+        if state.consecutive_failures >= self.threshold {
+            state.opened_at = Some(Instant::now());
+        }
+    }
+}
+
+/// HTTP client for the audit service API
+pub struct RemoteBackend {
+    client: reqwest::Client,
+    config: RemoteConfig,
+    breaker: CircuitBreaker,
+}
+
+impl RemoteBackend {
+    /// Create a remote backend (loads mTLS material if configured)
+    pub fn new(config: RemoteConfig) -> Result<Self> {
+        let mut builder = reqwest::Client::builder()
+            .use_rustls_tls()
+            .timeout(config.timeout);
+
+        if let Some(path) = &config.client_identity {
+            let pem = read_pem(path)?;
+            let identity = reqwest::Identity::from_pem(&pem)
+                .map_err(|e| BackendError::Config(format!("Invalid client identity {}: {}", path.display(), e)))?;
+            builder = builder.identity(identity);
+        }
+        if let Some(path) = &config.ca_cert {
+            let pem = read_pem(path)?;
+            let cert = reqwest::Certificate::from_pem(&pem)
+                .map_err(|e| BackendError::Config(format!("Invalid CA certificate {}: {}", path.display(), e)))?;
+            builder = builder.add_root_certificate(cert);
+        }
+
+        let client = builder
+            .build()
+            .map_err(|e| BackendError::Config(format!("Failed to build HTTP client: {}", e)))?;
+        let breaker = CircuitBreaker::new(config.failure_threshold, config.cooldown);
+
+        Ok(Self { client, config, breaker })
+    }
+
+    /// POST through the circuit breaker, retrying transient failures
+    async fn post<B: Serialize + Sync, R: DeserializeOwned>(&self, path: &str, body: &B) -> Result<R> {
+        self.breaker.check()?;
+
+        let mut attempt = 0;
+        let result = loop {
+            match self.post_once(path, body).await {
+                Err(e) if e.is_retryable() && attempt < self.config.max_retries => {
+                    tracing::warn!("Remote backend {} attempt {} failed: {}", path, attempt + 1, e);
+                    tokio::time::sleep(self.config.backoff * 2u32.saturating_pow(attempt)).await;
+                    attempt += 1;
+                }
+                other => break other,
+            }
+        };
+
+        // A 4xx means the backend is up and answered
+        match &result {
+            Ok(_) | Err(BackendError::Rejected { .. }) => self.breaker.record_success(),
+            Err(_) => self.breaker.record_failure(),
+        }
+        result
+    }
+
+    async fn post_once<B: Serialize, R: DeserializeOwned>(&self, path: &str, body: &B) -> Result<R> {
+        let url = format!("{}{}", self.config.endpoint, path);
+        let response = self.client.post(&url).json(body).send().await.map_err(|e| {
+            if e.is_timeout() {
+                BackendError::Timeout(self.config.timeout)
+            } else {
+                BackendError::Unavailable(e.to_string())
+            }
+        })?;
+
+        let status = StatusCode::from_u16(response.status().as_u16()).unwrap_or(StatusCode::BAD_GATEWAY);
+        if status.is_success() {
+            return response.json().await.map_err(|e| {
+                if e.is_timeout() {
+                    BackendError::Timeout(self.config.timeout)
+                } else {
+                    BackendError::InvalidReceipt(e.to_string())
+                }
+            });
+        }
+
+        let message = response.text().await.unwrap_or_default();
+        if status.is_server_error() {
+            Err(BackendError::Unavailable(format!("{}: {}", status, message)))
+        } else {
+            Err(BackendError::Rejected { status, message })
+        }
+    }
+}
+
+fn read_pem(path: &PathBuf) -> Result<Vec<u8>> {
+    std::fs::read(path).map_err(|e| BackendError::Config(format!("Failed to read {}: {}", path.display(), e)))
+}
+
+#[async_trait]
+impl VerificationBackend for RemoteBackend {
+    fn name(&self) -> &'static str {
+        "remote"
+    }
+
+    async fn quick_verify(&self, claim: &str, evidence: &[String]) -> Result<QuickVerdict> {
+        let request = AuditRequest {
+            claim: claim.to_string(),
+            evidence: evidence.to_vec(),
+            sub_operations: Vec::new(),
+        };
+        self.post("/audit/quick", &request).await
+    }
+
+    async fn audit(
+        &self,
+        claim: &str,
+        evidence: &[String],
+        sub_operations: &[SubOperation],
+    ) -> Result<AuditReceipt> {
+        let request = AuditRequest {
+            claim: claim.to_string(),
+            evidence: evidence.to_vec(),
+            sub_operations: sub_operations.to_vec(),
+        };
+        let response: AuditResponse = self.post("/audit", &request).await?;
+
+        let receipt = response
+            .receipt
+            .ok_or_else(|| BackendError::InvalidReceipt("response carried no receipt".to_string()))?;
+        if receipt.receipt_hash != response.receipt_hash || !receipt.verify_hash() {
+            return Err(BackendError::InvalidReceipt(format!(
+                "hash mismatch for receipt {}",
+                response.receipt_hash
+            )));
+        }
+
+        Ok(receipt)
+    }
+}
+
+// ============================================================================
+// Co-signed receipts
+// ============================================================================
+
+/// Audit receipt as stored by the portal
+///
+/// `receipt.signature` is the issuing backend's signature; `portal_signature`
+/// is the portal's signature over the same receipt hash. Both are retained.
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct CoSignedReceipt {
+    /// Backend that issued the receipt
+    pub backend: String,
+    /// Receipt with the backend's signature
+    #[schema(value_type = Object)]
+    pub receipt: AuditReceipt,
+    /// Portal signature over `receipt.receipt_hash`
+    pub portal_signature: String,
+}
+
+impl CoSignedReceipt {
+    /// Co-sign a receipt issued by `backend`
+    pub fn new(backend: &str, receipt: AuditReceipt, sign_fn: SignFn) -> Self {
+        let portal_signature = sign_fn(&receipt.receipt_hash);
+        Self {
+            backend: backend.to_string(),
+            receipt,
+            portal_signature,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{extract::Json, routing::post, Router};
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    fn stub_sign(hash: &str) -> String {
+        format!("STUB:{}", hash)
+    }
+
+    /// In-process audit service on an ephemeral port
+    async fn stub(router: Router) -> String {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        tokio::spawn(async move { axum::serve(listener, router).await.unwrap() });
+        format!("http://{}", addr)
+    }
+
+    fn audit_stub() -> Router {
+        let service = Arc::new(std::sync::Mutex::new(AuditService::new()));
+        Router::new().route(
+            "/audit",
+            post(move |Json(request): Json<AuditRequest>| {
+                let service = service.clone();
+                async move {
+                    let receipt = service
+                        .lock()
+                        .unwrap()
+                        .audit_with_ops(&request.claim, &request.evidence, &request.sub_operations, stub_sign)
+                        .unwrap();
+                    Json(AuditResponse::from(receipt))
+                }
+            }),
+        )
+    }
+
+    fn fast(endpoint: String) -> RemoteConfig {
+        RemoteConfig {
+            timeout: Duration::from_millis(100),
+            max_retries: 1,
+            backoff: Duration::from_millis(10),
+            failure_threshold: 2,
+            cooldown: Duration::from_secs(60),
+            ..RemoteConfig::new(endpoint)
+        }
+    }
+
+    #[tokio::test]
+    async fn test_remote_audit_success() {
+        let backend = RemoteBackend::new(fast(stub(audit_stub()).await)).unwrap();
+        let evidence = vec!["The deployment finished without errors".to_string()];
+
+        let receipt = backend.audit("The deployment succeeded", &evidence, &[]).await.unwrap();
+        assert!(receipt.proof_exists());
+        assert!(receipt.verify(|hash, sig| sig == stub_sign(hash)));
+
+        let cosigned = CoSignedReceipt::new(backend.name(), receipt, |hash| format!("PORTAL:{}", hash));
+        assert_ne!(cosigned.portal_signature, cosigned.receipt.signature);
+        assert_eq!(cosigned.receipt.signature, stub_sign(&cosigned.receipt.receipt_hash));
+        assert_eq!(cosigned.portal_signature, format!("PORTAL:{}", cosigned.receipt.receipt_hash));
+    }
+
+    #[tokio::test]
+    async fn test_remote_backend_speaks_auditd() {
+        use axiom_audit::http::{router, AuditdState, KeySigner};
+
+        let state = Arc::new(AuditdState::new(Default::default(), KeySigner::new("auditd-key")));
+        let backend = RemoteBackend::new(fast(stub(router(state)).await)).unwrap();
+
+        let verdict = backend.quick_verify("The deployment succeeded", &[]).await.unwrap();
+        assert!(!verdict.proof_exists);
+        assert_eq!(verdict.reason.as_deref(), Some(codes::NO_EVIDENCE));
+
+        let evidence = vec!["The deployment finished without errors".to_string()];
+        let receipt = backend.audit("The deployment succeeded", &evidence, &[]).await.unwrap();
+        assert!(receipt.verify(|hash, sig| KeySigner::new("auditd-key").verify(hash, sig)));
+    }
+
+    #[tokio::test]
+    async fn test_remote_timeout_is_retried_then_reported() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/audit/quick",
+            post(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async {
+                    tokio::time::sleep(Duration::from_secs(5)).await;
+                    "{}"
+                }
+            }),
+        );
+        let backend = RemoteBackend::new(fast(stub(router).await)).unwrap();
+
+        let started = Instant::now();
+        let err = backend.quick_verify("claim", &[]).await.unwrap_err();
+        assert!(matches!(err, BackendError::Timeout(_)), "{}", err);
+        assert_eq!(err.status(), StatusCode::GATEWAY_TIMEOUT);
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+        assert!(started.elapsed() < Duration::from_secs(2));
+    }
+
+    #[tokio::test]
+    async fn test_circuit_opens_after_repeated_failures() {
+        let hits = Arc::new(AtomicUsize::new(0));
+        let counter = hits.clone();
+        let router = Router::new().route(
+            "/audit/quick",
+            post(move || {
+                counter.fetch_add(1, Ordering::SeqCst);
+                async { (StatusCode::INTERNAL_SERVER_ERROR, "down") }
+            }),
+        );
+        let backend = RemoteBackend::new(RemoteConfig { max_retries: 0, ..fast(stub(router).await) }).unwrap();
+
+        for _ in 0..2 {
+            let err = backend.quick_verify("claim", &[]).await.unwrap_err();
+            assert!(matches!(err, BackendError::Unavailable(_)), "{}", err);
+        }
+
+        // Open: fails fast without reaching the backend
+        let err = backend.quick_verify("claim", &[]).await.unwrap_err();
+        assert!(matches!(err, BackendError::CircuitOpen { .. }), "{}", err);
+        assert_eq!(err.status(), StatusCode::SERVICE_UNAVAILABLE);
+        assert!(err.to_string().contains("circuit open"));
+        assert_eq!(hits.load(Ordering::SeqCst), 2);
+    }
+
+    #[test]
+    fn test_backend_selection() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |key: &str| pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+        };
+
+        assert!(matches!(BackendConfig::from_lookup(env(&[])), Ok(BackendConfig::Local)));
+        assert!(BackendConfig::from_lookup(env(&[("PORTAL_BACKEND", "remote")])).is_err());
+        assert!(BackendConfig::from_lookup(env(&[("PORTAL_BACKEND", "cloud")])).is_err());
+
+        let config = BackendConfig::from_lookup(env(&[
+            ("PORTAL_BACKEND", "remote"),
+            ("PORTAL_REMOTE_URL", "https://audit.internal:3001/"),
+            ("PORTAL_REMOTE_RETRIES", "4"),
+        ]))
+        .unwrap();
+        match config {
+            BackendConfig::Remote(remote) => {
+                assert_eq!(remote.endpoint, "https://audit.internal:3001");
+                assert_eq!(remote.max_retries, 4);
+            }
+            BackendConfig::Local => panic!("expected remote"),
+        }
+
+        let missing = RemoteConfig {
+            client_identity: Some(PathBuf::from("/nonexistent/client.pem")),
+            ..RemoteConfig::new("https://audit.internal")
+        };
+        assert!(matches!(RemoteBackend::new(missing), Err(BackendError::Config(_))));
+    }
+}
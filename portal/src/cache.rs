@@ -0,0 +1,176 @@
+//! Verification result cache
+//!
+//! Identical `/verify` submissions return the receipt minted for the first
+//! one instead of a new receipt each time. Requests are keyed by a digest
+//! of the trimmed claim and the sorted, de-duplicated evidence, so evidence
+//! order and repeats do not matter. The `PORTAL_CACHE_SIZE` most recently
+//! used digests are kept (default 1024, `0` disables caching).
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::sync::Mutex;
+
+/// Digests kept when `PORTAL_CACHE_SIZE` is unset
+pub const DEFAULT_CACHE_SIZE: usize = 1024;
+
+/// Canonical digest of a `/verify` request
+pub fn request_digest(claim: &str, evidence: &[String]) -> String {
+    let evidence: BTreeSet<&str> = evidence.iter().map(String::as_str).collect();
+
+    // Length prefixes keep field boundaries unambiguous
+    let mut hasher = Sha256::new();
+    hasher.update(b"PORTAL_REQ:");
+    let claim = claim.trim();
+    hasher.update((claim.len() as u64).to_be_bytes());
+    hasher.update(claim.as_bytes());
+    for e in evidence {
+        hasher.update((e.len() as u64).to_be_bytes());
+        hasher.update(e.as_bytes());
+    }
+    hex::encode(hasher.finalize())
+}
+
+/// Least-recently-used map from request digest to receipt hash
+pub struct VerifyCache {
+    capacity: usize,
+    inner: Mutex<Lru>,
+}
+
+#[derive(Default)]
+struct Lru {
+    /// digest -> (receipt hash, last use)
+    entries: HashMap<String, (String, u64)>,
+    /// last use -> digest
+    order: BTreeMap<u64, String>,
+    tick: u64,
+}
+
+impl Lru {
+    fn touch(&mut self, digest: &str) -> Option<String> {
+        self.tick += 1;
+        let tick = self.tick;
+        let (hash, used) = self.entries.get_mut(digest)?;
+        self.order.remove(used);
+        *used = tick;
+        self.order.insert(tick, digest.to_string());
+        Some(hash.clone())
+    }
+}
+
+impl VerifyCache {
+    /// Keep up to `capacity` digests; `0` disables caching
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Lru::default()),
+        }
+    }
+
+    /// Read `PORTAL_CACHE_SIZE` (number of digests kept)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("PORTAL_CACHE_SIZE") {
+            Ok(value) => value
+                .parse()
+                .map(Self::new)
+                .map_err(|_| format!("PORTAL_CACHE_SIZE must be a number, got '{}'", value)),
+            Err(_) => Ok(Self::new(DEFAULT_CACHE_SIZE)),
+        }
+    }
+
+    /// Receipt hash cached for `digest`, marking it recently used
+    pub fn get(&self, digest: &str) -> Option<String> {
+        self.inner.lock().unwrap().touch(digest)
+    }
+
+    /// Cache `hash` for `digest`, evicting the least recently used digest if full
+    pub fn insert(&self, digest: &str, hash: &str) {
+        if self.capacity == 0 {
+            return;
+        }
+        let mut lru = self.inner.lock().unwrap();
+        if lru.touch(digest).is_some() {
+            if let Some(entry) = lru.entries.get_mut(digest) {
+                entry.0 = hash.to_string();
+            }
+            return;
+        }
+        if lru.entries.len() >= self.capacity {
+            if let Some((_, oldest)) = lru.order.pop_first() {
+                lru.entries.remove(&oldest);
+            }
+        }
+        let tick = lru.tick;
+        lru.entries.insert(digest.to_string(), (hash.to_string(), tick));
+        lru.order.insert(tick, digest.to_string());
+    }
+
+    /// Forget `digest` (its receipt is gone from the store)
+    pub fn remove(&self, digest: &str) {
+        let mut lru = self.inner.lock().unwrap();
+        if let Some((_, used)) = lru.entries.remove(digest) {
+            lru.order.remove(&used);
+        }
+    }
+
+    /// Number of cached digests
+    pub fn len(&self) -> usize {
+        self.inner.lock().unwrap().entries.len()
+    }
+
+    /// Whether nothing is cached
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Default for VerifyCache {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_SIZE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ev(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_digest_is_canonical() {
+        let digest = request_digest("A claim", &ev(&["b", "a"]));
+        assert_eq!(request_digest("  A claim\n", &ev(&["a", "b", "a"])), digest);
+        assert_ne!(request_digest("A claim", &ev(&["a"])), digest);
+        assert_ne!(request_digest("A claim", &ev(&["ab"])), request_digest("A claim", &ev(&["a", "b"])));
+    }
+
+    #[test]
+    fn test_least_recently_used_evicted() {
+        let cache = VerifyCache::new(2);
+        cache.insert("d1", "h1");
+        cache.insert("d2", "h2");
+        assert_eq!(cache.get("d1").as_deref(), Some("h1"));
+        cache.insert("d3", "h3");
+
+        assert_eq!(cache.len(), 2);
+        assert!(cache.get("d2").is_none());
+        assert_eq!(cache.get("d1").as_deref(), Some("h1"));
+        assert_eq!(cache.get("d3").as_deref(), Some("h3"));
+
+        cache.insert("d1", "h1b");
+        assert_eq!(cache.get("d1").as_deref(), Some("h1b"));
+        cache.remove("d1");
+        assert!(cache.get("d1").is_none());
+    }
+
+    #[test]
+    fn test_zero_capacity_disables() {
+        let cache = VerifyCache::new(0);
+        cache.insert("d1", "h1");
+        assert!(cache.is_empty());
+        assert!(cache.get("d1").is_none());
+    }
+}
@@ -0,0 +1,1411 @@
+//! # AXIOM HIVE Verification Portal
+//!
+//! Public API for binary proof receipts.
+//! All outputs are binary: `Verified` | `Not Verified`
+//!
+//! The portal is a library so it can be mounted inside an existing axum
+//! application: `build_router` turns a `PortalConfig` into a `Router`, and
+//! everything the handlers hold (receipt store, verification backend,
+//! signer, rate limiter, trust anchors) is supplied through the config. The
+//! `axiom-portal` binary only reads that config from the environment and
+//! serves the router with [`server::serve`] (TLS, graceful shutdown).
+//!
+//! The API contract is served at `GET /openapi.json` (see [`openapi`]) and
+//! browsable at `GET /docs`; the `portal-client` crate is a typed client
+//! for it.
+//!
+//! ```no_run
+//! let app = axum::Router::new().nest("/portal", portal::build_router(portal::PortalConfig::default()));
+//! ```
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+pub mod backend;
+pub mod cache;
+pub mod listing;
+pub mod openapi;
+pub mod rate_limit;
+pub mod receipt_check;
+pub mod server;
+pub mod store;
+pub mod webhook;
+
+pub use backend::{BackendConfig, SignFn, VerificationBackend};
+pub use cache::VerifyCache;
+pub use rate_limit::RateLimiter;
+pub use receipt_check::TrustAnchors;
+pub use store::{MemoryStore, ReceiptStore, StoreError};
+pub use webhook::{WebhookConfig, Webhooks};
+
+use axiom_audit::service::{AuditRequest, AuditResponse};
+use axiom_audit::{AuditError, AuditService};
+use backend::{BackendError, CoSignedReceipt, LocalBackend, QuickVerdict};
+use listing::{ReceiptFilter, ReceiptListQuery, ReceiptPage};
+use rate_limit::ClientCount;
+use webhook::{WebhookEvent, WebhookStatus};
+use receipt_check::{InvalidReceipt, ReceiptCheck};
+use axum::{
+    extract::{Json, Query, State},
+    http::{header, HeaderValue, StatusCode, Method},
+    response::{Html, IntoResponse},
+    routing::{get, post},
+    Router,
+};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::BTreeMap;
+use std::sync::Arc;
+use tokio::sync::Mutex;
+use tower_http::compression::CompressionLayer;
+use tower_http::cors::{AllowOrigin, Any, CorsLayer};
+use utoipa::{IntoParams, ToSchema};
+
+const SUBSTRATE: &str = "Alexis Adams";
+const PROJECTION: &str = "AXIOMHIVE PROJECTION";
+pub const VERSION: &str = "1.0.0";
+
+// ============================================================================
+// Types
+// ============================================================================
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct VerifyRequest {
+    pub claim: String,
+    pub evidence: Vec<String>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct VerifyResponse {
+    #[serde(rename = "C_zero")]
+    pub c_zero: bool,
+    pub hash: String,
+    pub signature: String,
+    pub timestamp: String,
+    /// Why the claim was not verified (e.g. `INSUFFICIENT_EVIDENCE`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// Returned from the cache rather than minted for this request
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    pub cached: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct VerifyQuery {
+    /// Skip the cache and mint a new receipt
+    #[serde(default)]
+    pub force: bool,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptQuery {
+    pub hash: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct StoredReceipt {
+    pub claim: String,
+    pub evidence: Vec<String>,
+    pub c_zero: bool,
+    pub hash: String,
+    pub signature: String,
+    pub timestamp: String,
+    /// Why the claim was not verified
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct AuditQuery {
+    /// Include the full audit receipt in the response
+    #[serde(default)]
+    pub include_receipt: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct PortalStats {
+    pub total_verifications: u64,
+    pub verified_count: u64,
+    pub not_verified_count: u64,
+    /// `/verify` requests answered with a cached receipt
+    #[serde(default)]
+    pub cache_hits: u64,
+    pub total_audits: u64,
+    pub audits_proof_exists: u64,
+    pub audits_no_proof: u64,
+    /// Audits without a proof, by failure reason code
+    #[serde(default)]
+    pub audits_no_proof_by_reason: BTreeMap<String, u64>,
+    pub rejected_rate_limited: u64,
+    pub uptime_seconds: u64,
+    /// Busiest clients by request count
+    #[serde(default)]
+    pub top_clients: Vec<ClientCount>,
+}
+
+impl PortalStats {
+    /// Count a `/verify` answer, whether minted or served from the cache
+    fn record_verification(&mut self, c_zero: bool, cached: bool) {
+        self.total_verifications += 1;
+        if c_zero {
+            self.verified_count += 1;
+        } else {
+            self.not_verified_count += 1;
+        }
+        if cached {
+            self.cache_hits += 1;
+        }
+    }
+}
+
+// ============================================================================
+// Configuration
+// ============================================================================
+
+/// Everything the portal's handlers use
+///
+/// `Default` gives an in-memory store, the local audit backend, the mock
+/// signer, the default rate limit and cache size, portal-only trust
+/// anchors, no webhooks and permissive CORS. Override fields with struct update syntax.
+pub struct PortalConfig {
+    /// Where `/verify` and audit receipts are kept
+    pub store: Arc<dyn ReceiptStore>,
+    /// Where `/verify` and `/audit` are evaluated
+    pub backend: Box<dyn VerificationBackend>,
+    /// Signs `/verify` receipts and co-signs audit receipts
+    pub signer: SignFn,
+    /// Per-client rate limit (only applied when the router is served with connect info)
+    pub rate_limit: RateLimiter,
+    /// Signers accepted by `/verify-receipt`; receipts from `signer` always are
+    pub trust: TrustAnchors,
+    /// Where verification events are pushed
+    pub webhooks: Webhooks,
+    /// Receipts reused for repeated `/verify` requests
+    pub cache: VerifyCache,
+    /// CORS policy; `None` leaves CORS to the embedding application
+    pub cors: Option<CorsLayer>,
+}
+
+impl Default for PortalConfig {
+    fn default() -> Self {
+        Self {
+            store: Arc::new(MemoryStore::new()),
+            backend: Box::new(LocalBackend::new(AuditService::new(), mock_sign)),
+            signer: mock_sign,
+            rate_limit: RateLimiter::new(rate_limit::DEFAULT_REQUESTS_PER_MINUTE),
+            trust: TrustAnchors::default(),
+            webhooks: Webhooks::disabled(),
+            cache: VerifyCache::default(),
+            cors: Some(default_cors()),
+        }
+    }
+}
+
+impl PortalConfig {
+    /// Read the configuration the `axiom-portal` binary uses
+    ///
+    /// `PORTAL_BACKEND` (and the `PORTAL_REMOTE_*` settings), `PORTAL_RATE_LIMIT`,
+    /// `PORTAL_TRUST_ANCHORS`, the `PORTAL_WEBHOOK_*` settings,
+    /// `PORTAL_CACHE_SIZE` and `PORTAL_ALLOWED_ORIGINS`; the store is in memory.
+    pub fn from_env() -> Result<Self, String> {
+        let backend = BackendConfig::from_env()
+            .and_then(|config| config.build(mock_sign))
+            .map_err(|e| e.to_string())?;
+        Ok(Self {
+            backend,
+            rate_limit: RateLimiter::from_env()?,
+            trust: TrustAnchors::from_env()?,
+            webhooks: Webhooks::from_env()?,
+            cache: VerifyCache::from_env()?,
+            cors: Some(cors_from_env()?),
+            ..Self::default()
+        })
+    }
+}
+
+/// Permissive CORS: GET and POST from any origin
+pub fn default_cors() -> CorsLayer {
+    CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST])
+        .allow_origin(Any)
+        .allow_headers(Any)
+}
+
+/// CORS used by the binary: GET and POST from the comma-separated origins
+/// in `PORTAL_ALLOWED_ORIGINS`, or from any origin (with a warning) when unset
+pub fn cors_from_env() -> Result<CorsLayer, String> {
+    match std::env::var("PORTAL_ALLOWED_ORIGINS") {
+        Ok(value) => {
+            let origins: Vec<String> = value
+                .split(',')
+                .map(str::trim)
+                .filter(|origin| !origin.is_empty())
+                .map(String::from)
+                .collect();
+            cors_for_origins(&origins)
+        }
+        Err(_) => {
+            tracing::warn!("PORTAL_ALLOWED_ORIGINS is not set; accepting cross-origin requests from any origin");
+            Ok(default_cors())
+        }
+    }
+}
+
+/// GET and POST from exactly `origins` (e.g. `https://app.example.com`)
+pub fn cors_for_origins(origins: &[String]) -> Result<CorsLayer, String> {
+    if origins.is_empty() {
+        return Err("PORTAL_ALLOWED_ORIGINS names no origins".to_string());
+    }
+    let origins = origins
+        .iter()
+        .map(|origin| {
+            let scheme = origin.starts_with("http://") || origin.starts_with("https://");
+            HeaderValue::from_str(origin)
+                .ok()
+                .filter(|_| scheme && !origin.ends_with('/'))
+                .ok_or_else(|| format!("PORTAL_ALLOWED_ORIGINS: '{}' is not an origin like https://example.com", origin))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(CorsLayer::new()
+        .allow_methods([Method::GET, Method::POST])
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_headers(Any))
+}
+
+// ============================================================================
+// State
+// ============================================================================
+
+struct AppState {
+    store: Arc<dyn ReceiptStore>,
+    stats: Mutex<PortalStats>,
+    backend: Box<dyn VerificationBackend>,
+    signer: SignFn,
+    limiter: RateLimiter,
+    trust: TrustAnchors,
+    webhooks: Webhooks,
+    cache: VerifyCache,
+    start_time: std::time::Instant,
+}
+
+impl AppState {
+    fn new(config: PortalConfig) -> Self {
+        Self {
+            store: config.store,
+            stats: Mutex::new(PortalStats::default()),
+            backend: config.backend,
+            signer: config.signer,
+            limiter: config.rate_limit,
+            trust: config.trust.with_portal_signer(config.signer),
+            webhooks: config.webhooks,
+            cache: config.cache,
+            start_time: std::time::Instant::now(),
+        }
+    }
+}
+
+// ============================================================================
+// Signing (Mock for development)
+// ============================================================================
+
+/// Development signer (`PORTAL_SIG:` domain)
+pub fn mock_sign(hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"PORTAL_SIG:");
+    hasher.update(hash.as_bytes());
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+}
+
+#[cfg(test)]
+fn mock_verify(hash: &str, sig: &str) -> bool {
+    mock_sign(hash) == sig
+}
+
+fn compute_hash(claim: &str, evidence: &[String], c_zero: bool, timestamp: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(claim.as_bytes());
+    for e in evidence {
+        hasher.update(e.as_bytes());
+    }
+    hasher.update([c_zero as u8]);
+    hasher.update(timestamp.as_bytes());
+    hex::encode(hasher.finalize())
+}
+
+// ============================================================================
+// Verification Logic
+// ============================================================================
+
+/// Map backend errors to HTTP status and a reason
+fn backend_error(err: BackendError) -> (StatusCode, String) {
+    (err.status(), err.to_string())
+}
+
+/// Map store errors to HTTP status and a reason
+fn store_error(err: StoreError) -> (StatusCode, String) {
+    (err.status(), err.to_string())
+}
+
+// ============================================================================
+// Handlers
+// ============================================================================
+
+/// Health check
+#[utoipa::path(get, path = "/health", tag = "portal",
+    responses((status = 200, description = "Portal is up", body = String, content_type = "text/plain")))]
+async fn health() -> &'static str {
+    "[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]\nVerification Portal: OPERATIONAL"
+}
+
+/// Portal name, version, backend and endpoints
+#[utoipa::path(get, path = "/info", tag = "portal",
+    responses(
+        (status = 200, description = "Portal information", body = serde_json::Value),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+    ))]
+async fn info(State(state): State<Arc<AppState>>) -> Json<serde_json::Value> {
+    Json(serde_json::json!({
+        "name": "AXIOM HIVE Verification Portal",
+        "version": VERSION,
+        "substrate": SUBSTRATE,
+        "projection": PROJECTION,
+        "policy": "C = 0",
+        "output_type": "Binary (Verified | Not Verified)",
+        "backend": state.backend.name(),
+        "endpoints": {
+            "POST /verify": "Submit claim for verification (?force=true bypasses the cache)",
+            "POST /audit": "Run the full L1/L2/L3 audit (?include_receipt=true for the receipt)",
+            "GET /audit/{hash}": "Retrieve co-signed audit receipt by hash",
+            "GET /receipt/{hash}": "Retrieve receipt by hash",
+            "GET /receipts": "List receipt summaries (?limit=&offset=&c_zero=&since=&until=)",
+            "GET /receipts/export": "Export full receipts as NDJSON (gzip if accepted)",
+            "GET /stats": "Portal statistics",
+            "GET /webhooks/status": "Last webhook delivery per endpoint",
+            "GET /openapi.json": "OpenAPI description of this API",
+            "GET /docs": "Interactive API documentation",
+            "GET /health": "Health check"
+        }
+    }))
+}
+
+/// The stored receipt for an identical earlier request, if still held
+async fn cached_receipt(state: &AppState, digest: &str) -> Result<Option<StoredReceipt>, (StatusCode, String)> {
+    let Some(hash) = state.cache.get(digest) else {
+        return Ok(None);
+    };
+    let receipt = state.store.get(&hash).await.map_err(store_error)?;
+    if receipt.is_none() {
+        state.cache.remove(digest);
+    }
+    Ok(receipt)
+}
+
+/// Submit a claim for verification
+#[utoipa::path(post, path = "/verify", tag = "verification",
+    params(VerifyQuery),
+    request_body = VerifyRequest,
+    responses(
+        (status = 200, description = "Binary verdict and its receipt", body = VerifyResponse),
+        (status = 400, description = "Body is not valid JSON", body = String, content_type = "text/plain"),
+        (status = 422, description = "Body does not match VerifyRequest", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Backend or store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn verify(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<VerifyQuery>,
+    Json(request): Json<VerifyRequest>,
+) -> Result<Json<VerifyResponse>, (StatusCode, String)> {
+    let digest = cache::request_digest(&request.claim, &request.evidence);
+    if !query.force {
+        if let Some(receipt) = cached_receipt(&state, &digest).await? {
+            state.stats.lock().await.record_verification(receipt.c_zero, true);
+            return Ok(Json(VerifyResponse {
+                c_zero: receipt.c_zero,
+                hash: receipt.hash,
+                signature: receipt.signature,
+                timestamp: receipt.timestamp,
+                reason: receipt.reason,
+                cached: true,
+            }));
+        }
+    }
+
+    let timestamp = chrono::Utc::now().to_rfc3339();
+
+    // Perform verification (L1 of the audit pipeline, same as /audit)
+    let verdict = match state.backend.quick_verify(&request.claim, &request.evidence).await {
+        Ok(verdict) => verdict,
+        // Too little evidence is a negative verdict, not a failed request
+        Err(BackendError::Audit(AuditError::InsufficientEvidence)) => QuickVerdict::insufficient_evidence(),
+        Err(err) => return Err(backend_error(err)),
+    };
+    let c_zero = verdict.proof_exists;
+
+    // Compute hash
+    let hash = compute_hash(&request.claim, &request.evidence, c_zero, &timestamp);
+
+    // Sign the hash
+    let signature = (state.signer)(&hash);
+
+    // Store receipt
+    let receipt = StoredReceipt {
+        claim: request.claim.clone(),
+        evidence: request.evidence.clone(),
+        c_zero,
+        hash: hash.clone(),
+        signature: signature.clone(),
+        timestamp: timestamp.clone(),
+        reason: verdict.reason.clone(),
+    };
+    let event = WebhookEvent::from_receipt(&receipt);
+    state.store.insert(receipt).await.map_err(store_error)?;
+    state.cache.insert(&digest, &hash);
+
+    // Update stats
+    state.stats.lock().await.record_verification(c_zero, false);
+    state.webhooks.notify(event);
+
+    Ok(Json(VerifyResponse {
+        c_zero,
+        hash,
+        signature,
+        timestamp,
+        reason: verdict.reason,
+        cached: false,
+    }))
+}
+
+/// Run the full L1/L2/L3 audit
+#[utoipa::path(post, path = "/audit", tag = "audit",
+    params(AuditQuery),
+    request_body = AuditRequest,
+    responses(
+        (status = 200, description = "Audit outcome", body = AuditResponse),
+        (status = 400, description = "Body is not valid JSON, or the audit failed", body = String, content_type = "text/plain"),
+        (status = 422, description = "Invalid claim, or body does not match AuditRequest", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Backend or store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn audit(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<AuditQuery>,
+    Json(request): Json<AuditRequest>,
+) -> Result<Json<AuditResponse>, (StatusCode, String)> {
+    let receipt = state
+        .backend
+        .audit(&request.claim, &request.evidence, &request.sub_operations)
+        .await
+        .map_err(backend_error)?;
+
+    // Keep the backend's signature and add the portal's
+    let cosigned = CoSignedReceipt::new(state.backend.name(), receipt.clone(), state.signer);
+    state.store.insert_audit(cosigned).await.map_err(store_error)?;
+
+    {
+        let mut stats = state.stats.lock().await;
+        stats.total_audits += 1;
+        if receipt.proof_exists() {
+            stats.audits_proof_exists += 1;
+        } else {
+            stats.audits_no_proof += 1;
+            if let Some(reason) = receipt.failure_reason() {
+                *stats.audits_no_proof_by_reason.entry(reason.code().to_string()).or_default() += 1;
+            }
+        }
+    }
+    state.webhooks.notify(WebhookEvent::from_audit(&receipt));
+
+    let mut response = AuditResponse::from(receipt);
+    if !query.include_receipt {
+        response.receipt = None;
+    }
+
+    Ok(Json(response))
+}
+
+/// Co-signed audit receipt by hash
+#[utoipa::path(get, path = "/audit/{hash}", tag = "audit",
+    params(("hash" = String, Path, description = "Audit receipt hash")),
+    responses(
+        (status = 200, description = "The co-signed audit receipt", body = CoSignedReceipt),
+        (status = 404, description = "Audit receipt not found", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn get_audit_receipt(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Path(hash): axum::extract::Path<String>,
+) -> Result<Json<CoSignedReceipt>, (StatusCode, String)> {
+    state
+        .store
+        .get_audit(&hash)
+        .await
+        .map_err(store_error)?
+        .map(Json)
+        .ok_or((StatusCode::NOT_FOUND, "Audit receipt not found".to_string()))
+}
+
+/// Receipt by hash
+#[utoipa::path(get, path = "/receipt/{hash}", tag = "receipts",
+    params(("hash" = String, Path, description = "Receipt hash")),
+    responses(
+        (status = 200, description = "The stored receipt", body = StoredReceipt),
+        (status = 404, description = "Receipt not found", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn get_receipt(
+    State(state): State<Arc<AppState>>,
+    axum::extract::Path(hash): axum::extract::Path<String>,
+) -> Result<Json<StoredReceipt>, (StatusCode, String)> {
+    state
+        .store
+        .get(&hash)
+        .await
+        .map_err(store_error)?
+        .map(Json)
+        .ok_or((StatusCode::NOT_FOUND, "Receipt not found".to_string()))
+}
+
+/// Page of receipt summaries
+#[utoipa::path(get, path = "/receipts", tag = "receipts",
+    params(ReceiptListQuery),
+    responses(
+        (status = 200, description = "Receipt summaries in listing order", body = ReceiptPage),
+        (status = 400, description = "Invalid query", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn list_receipts(
+    State(state): State<Arc<AppState>>,
+    Query(query): Query<ReceiptListQuery>,
+) -> Result<Json<ReceiptPage>, (StatusCode, String)> {
+    state.store.page(&query).await.map(Json).map_err(store_error)
+}
+
+/// Export full receipts as NDJSON
+#[utoipa::path(get, path = "/receipts/export", tag = "receipts",
+    params(ReceiptFilter),
+    responses(
+        (status = 200, description = "One StoredReceipt per line, gzip if accepted", body = String, content_type = "application/x-ndjson"),
+        (status = 400, description = "Invalid query", body = String, content_type = "text/plain"),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+        (status = 503, description = "Store unavailable", body = String, content_type = "text/plain"),
+    ))]
+async fn export_receipts(
+    State(state): State<Arc<AppState>>,
+    Query(filter): Query<ReceiptFilter>,
+) -> Result<impl IntoResponse, (StatusCode, String)> {
+    // Snapshot so the stream doesn't hold the store
+    let snapshot = state.store.export(&filter).await.map_err(store_error)?;
+
+    Ok((
+        [(header::CONTENT_TYPE, "application/x-ndjson")],
+        listing::ndjson_body(snapshot),
+    ))
+}
+
+/// Check a sap4d, audit or portal receipt against the trust anchors
+#[utoipa::path(post, path = "/verify-receipt", tag = "verification",
+    request_body(content = serde_json::Value, description = "A receipt, optionally with `type` set to sap4d, audit or portal"),
+    responses(
+        (status = 200, description = "Outcome of the receipt's checks", body = ReceiptCheck),
+        (status = 422, description = "Not readable as any receipt", body = InvalidReceipt),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+    ))]
+async fn verify_receipt(
+    State(state): State<Arc<AppState>>,
+    body: axum::body::Bytes,
+) -> Result<Json<ReceiptCheck>, InvalidReceipt> {
+    receipt_check::check(&body, &state.trust).map(Json)
+}
+
+/// Portal statistics
+#[utoipa::path(get, path = "/stats", tag = "portal",
+    responses(
+        (status = 200, description = "Counters since the portal started", body = PortalStats),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+    ))]
+async fn get_stats(State(state): State<Arc<AppState>>) -> Json<PortalStats> {
+    let mut stats = state.stats.lock().await.clone();
+    stats.uptime_seconds = state.start_time.elapsed().as_secs();
+    stats.top_clients = state.limiter.top_clients(rate_limit::TOP_CLIENTS);
+    Json(stats)
+}
+
+/// Last webhook delivery per endpoint
+#[utoipa::path(get, path = "/webhooks/status", tag = "portal",
+    responses(
+        (status = 200, description = "Webhook queue and delivery counts", body = WebhookStatus),
+        (status = 429, description = "Rate limit exceeded", body = RateLimitError),
+    ))]
+async fn webhook_status(State(state): State<Arc<AppState>>) -> Json<WebhookStatus> {
+    Json(state.webhooks.status())
+}
+
+async fn index() -> Html<&'static str> {
+    Html(r#"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <meta name="viewport" content="width=device-width, initial-scale=1.0">
+    <title>AXIOM HIVE Verification Portal</title>
+    <style>
+        :root {
+            --bg-dark: #0a0a0f;
+            --bg-card: #12121a;
+            --accent: #00ff88;
+            --accent-dim: #00aa55;
+            --text: #e0e0e0;
+            --text-dim: #808080;
+            --error: #ff4444;
+        }
+        * { margin: 0; padding: 0; box-sizing: border-box; }
+        body {
+            font-family: 'SF Mono', 'Fira Code', monospace;
+            background: var(--bg-dark);
+            color: var(--text);
+            min-height: 100vh;
+            display: flex;
+            flex-direction: column;
+            align-items: center;
+            padding: 40px 20px;
+        }
+        .header {
+            text-align: center;
+            margin-bottom: 40px;
+        }
+        .header h1 {
+            font-size: 2rem;
+            color: var(--accent);
+            text-transform: uppercase;
+            letter-spacing: 0.2em;
+        }
+        .identity {
+            font-size: 0.75rem;
+            color: var(--text-dim);
+            margin-top: 8px;
+        }
+        .card {
+            background: var(--bg-card);
+            border: 1px solid #222;
+            border-radius: 8px;
+            padding: 24px;
+            width: 100%;
+            max-width: 600px;
+            margin-bottom: 20px;
+        }
+        .card h2 {
+            font-size: 1rem;
+            color: var(--accent);
+            margin-bottom: 16px;
+            display: flex;
+            align-items: center;
+            gap: 8px;
+        }
+        .card h2::before {
+            content: "▸";
+        }
+        label {
+            display: block;
+            font-size: 0.8rem;
+            color: var(--text-dim);
+            margin-bottom: 4px;
+        }
+        input, textarea {
+            width: 100%;
+            padding: 12px;
+            background: var(--bg-dark);
+            border: 1px solid #333;
+            border-radius: 4px;
+            color: var(--text);
+            font-family: inherit;
+            font-size: 0.9rem;
+            margin-bottom: 16px;
+        }
+        input:focus, textarea:focus {
+            outline: none;
+            border-color: var(--accent);
+        }
+        button {
+            width: 100%;
+            padding: 14px;
+            background: var(--accent);
+            color: var(--bg-dark);
+            border: none;
+            border-radius: 4px;
+            font-family: inherit;
+            font-weight: bold;
+            font-size: 1rem;
+            cursor: pointer;
+            text-transform: uppercase;
+            letter-spacing: 0.1em;
+        }
+        button:hover {
+            background: var(--accent-dim);
+        }
+        .result {
+            margin-top: 20px;
+            padding: 16px;
+            border-radius: 4px;
+            display: none;
+        }
+        .result.verified {
+            background: rgba(0, 255, 136, 0.1);
+            border: 1px solid var(--accent);
+        }
+        .result.not-verified {
+            background: rgba(255, 68, 68, 0.1);
+            border: 1px solid var(--error);
+        }
+        .result h3 {
+            font-size: 1.2rem;
+            margin-bottom: 12px;
+        }
+        .result.verified h3 { color: var(--accent); }
+        .result.not-verified h3 { color: var(--error); }
+        .result-details {
+            font-size: 0.8rem;
+            color: var(--text-dim);
+        }
+        .result-details code {
+            display: block;
+            background: var(--bg-dark);
+            padding: 8px;
+            margin-top: 8px;
+            border-radius: 4px;
+            overflow-x: auto;
+        }
+        .footer {
+            margin-top: auto;
+            padding-top: 40px;
+            text-align: center;
+            font-size: 0.75rem;
+            color: var(--text-dim);
+        }
+        .policy {
+            color: var(--accent);
+            font-weight: bold;
+        }
+    </style>
+</head>
+<body>
+    <div class="header">
+        <h1>◈ AXIOM HIVE</h1>
+        <p class="identity">[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]</p>
+    </div>
+    
+    <div class="card">
+        <h2>Verification Portal</h2>
+        <form id="verifyForm">
+            <label for="claim">Claim</label>
+            <input type="text" id="claim" placeholder="Enter claim to verify..." required>
+            
+            <label for="evidence">Evidence (one per line)</label>
+            <textarea id="evidence" rows="4" placeholder="Enter supporting evidence..."></textarea>
+            
+            <button type="submit">VERIFY</button>
+        </form>
+        
+        <div id="result" class="result">
+            <h3 id="resultTitle"></h3>
+            <div class="result-details">
+                <div>Hash: <code id="resultHash"></code></div>
+                <div style="margin-top: 8px;">Timestamp: <span id="resultTimestamp"></span></div>
+            </div>
+        </div>
+    </div>
+    
+    <div class="card">
+        <h2>Policy</h2>
+        <p style="font-size: 0.9rem; line-height: 1.6;">
+            All verifications produce <span class="policy">binary outcomes only</span>:
+        </p>
+        <ul style="margin-top: 12px; margin-left: 20px; font-size: 0.85rem; line-height: 1.8;">
+            <li><span class="policy">C = 0</span> → VERIFIED (Proof Exists)</li>
+            <li><span style="color: var(--error);">C ≠ 0</span> → NOT VERIFIED (No Proof)</li>
+        </ul>
+        <p style="margin-top: 16px; font-size: 0.8rem; color: var(--text-dim);">
+            No percentages. No probabilities. No partial verification.
+        </p>
+    </div>
+    
+    <footer class="footer">
+        <p>Verification Portal v1.0.0 • Deterministic • Local-First • Zero Telemetry</p>
+        <p style="margin-top: 8px;">Policy: <span class="policy">C = 0</span></p>
+    </footer>
+    
+    <script>
+        document.getElementById('verifyForm').addEventListener('submit', async (e) => {
+            e.preventDefault();
+            
+            const claim = document.getElementById('claim').value;
+            const evidenceText = document.getElementById('evidence').value;
+            const evidence = evidenceText.split('\n').filter(e => e.trim());
+            
+            try {
+                const response = await fetch('/verify', {
+                    method: 'POST',
+                    headers: { 'Content-Type': 'application/json' },
+                    body: JSON.stringify({ claim, evidence })
+                });
+                
+                const data = await response.json();
+                
+                const result = document.getElementById('result');
+                const title = document.getElementById('resultTitle');
+                const hash = document.getElementById('resultHash');
+                const timestamp = document.getElementById('resultTimestamp');
+                
+                result.style.display = 'block';
+                result.className = 'result ' + (data.C_zero ? 'verified' : 'not-verified');
+                title.textContent = data.C_zero ? '✓ VERIFIED (C = 0)' : '✗ NOT VERIFIED (C ≠ 0)';
+                hash.textContent = data.hash;
+                timestamp.textContent = data.timestamp;
+            } catch (err) {
+                console.error('Verification failed:', err);
+            }
+        });
+    </script>
+</body>
+</html>"#)
+}
+
+
+// ============================================================================
+// Router
+// ============================================================================
+
+/// The portal's routes, ready to serve or to `nest` in another router
+///
+/// Serve with `into_make_service_with_connect_info::<SocketAddr>()` for
+/// per-client rate limiting; without connect info requests are not limited.
+pub fn build_router(config: PortalConfig) -> Router {
+    let cors = config.cors.clone();
+    let router = router(Arc::new(AppState::new(config)));
+    match cors {
+        Some(cors) => router.layer(cors),
+        None => router,
+    }
+}
+
+fn router(state: Arc<AppState>) -> Router {
+    Router::new()
+        .route("/", get(index))
+        .route("/health", get(health))
+        .route("/info", get(info))
+        .route("/verify", post(verify))
+        .route("/audit", post(audit))
+        .route("/audit/:hash", get(get_audit_receipt))
+        .route("/receipt/:hash", get(get_receipt))
+        .route("/receipts", get(list_receipts))
+        .route("/receipts/export", get(export_receipts).layer(CompressionLayer::new()))
+        .route("/verify-receipt", post(verify_receipt))
+        .route("/stats", get(get_stats))
+        .route("/webhooks/status", get(webhook_status))
+        .route("/openapi.json", get(openapi::openapi_json))
+        .route("/docs", get(openapi::docs))
+        .layer(axum::middleware::from_fn_with_state(state.clone(), rate_limit::limit))
+        .with_state(state)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axiom_audit::AuditService;
+    use axum::extract::connect_info::MockConnectInfo;
+    use axum_test::TestServer;
+    use backend::{RemoteBackend, RemoteConfig};
+    use std::net::SocketAddr;
+    
+    /// Serve `app` as if every request came from `client`
+    fn serve(app: Router, client: [u8; 4]) -> TestServer {
+        let app = app.layer(MockConnectInfo(SocketAddr::from((client, 40000))));
+        TestServer::new(app).unwrap()
+    }
+    
+    fn server() -> TestServer {
+        serve(build_router(PortalConfig::default()), [127, 0, 0, 1])
+    }
+    
+    fn remote_server(endpoint: String) -> TestServer {
+        let config = RemoteConfig {
+            timeout: std::time::Duration::from_millis(200),
+            max_retries: 0,
+            failure_threshold: 1,
+            ..RemoteConfig::new(endpoint)
+        };
+        let config = PortalConfig {
+            backend: Box::new(RemoteBackend::new(config).unwrap()),
+            ..PortalConfig::default()
+        };
+        serve(build_router(config), [127, 0, 0, 1])
+    }
+    
+    fn stored(i: usize, c_zero: bool) -> StoredReceipt {
+        let timestamp = format!("2025-06-01T{:02}:{:02}:{:02}+00:00", 12 + i / 3600, i / 60 % 60, i % 60);
+        let claim = format!("Claim {}", i);
+        StoredReceipt {
+            hash: compute_hash(&claim, &[], c_zero, &timestamp),
+            signature: String::new(),
+            claim,
+            evidence: vec![],
+            c_zero,
+            timestamp,
+            reason: None,
+        }
+    }
+    
+    async fn seeded(n: usize) -> TestServer {
+        let store = MemoryStore::new();
+        for i in 0..n {
+            store.insert(stored(i, i % 3 != 0)).await.unwrap();
+        }
+        let config = PortalConfig {
+            store: Arc::new(store),
+            ..PortalConfig::default()
+        };
+        serve(build_router(config), [127, 0, 0, 1])
+    }
+    
+    #[tokio::test]
+    async fn test_receipts_empty_store() {
+        let server = server();
+        
+        let page: ReceiptPage = server.get("/receipts").await.json();
+        assert_eq!(page.total, 0);
+        assert!(page.receipts.is_empty());
+        assert_eq!(page.limit, listing::DEFAULT_PAGE_SIZE);
+        
+        let response = server.get("/receipts/export").await;
+        response.assert_status_ok();
+        assert_eq!(response.header("content-type"), "application/x-ndjson");
+        assert!(response.text().is_empty());
+    }
+    
+    #[tokio::test]
+    async fn test_receipts_filters_and_pagination() {
+        let server = seeded(120).await;
+        
+        let page: ReceiptPage = server.get("/receipts").add_query_param("limit", 10).await.json();
+        assert_eq!(page.total, 120);
+        assert_eq!(page.receipts.len(), 10);
+        assert_eq!(page.receipts[0].claim, "Claim 0");
+        
+        let page: ReceiptPage = server
+            .get("/receipts")
+            .add_query_param("c_zero", false)
+            .add_query_param("limit", 5)
+            .add_query_param("offset", 5)
+            .await
+            .json();
+        assert_eq!(page.total, 40);
+        let claims: Vec<&str> = page.receipts.iter().map(|r| r.claim.as_str()).collect();
+        assert_eq!(claims, ["Claim 15", "Claim 18", "Claim 21", "Claim 24", "Claim 27"]);
+        assert!(page.receipts.iter().all(|r| !r.c_zero));
+        
+        // since inclusive, until exclusive
+        let page: ReceiptPage = server
+            .get("/receipts")
+            .add_query_param("since", "2025-06-01T12:01:00Z")
+            .add_query_param("until", "2025-06-01T12:01:10Z")
+            .await
+            .json();
+        assert_eq!(page.total, 10);
+        assert_eq!(page.receipts[0].claim, "Claim 60");
+        
+        // Oversized pages are capped
+        let page: ReceiptPage = server.get("/receipts").add_query_param("limit", 1_000_000).await.json();
+        assert_eq!(page.limit, listing::MAX_PAGE_SIZE);
+        assert_eq!(page.receipts.len(), 120);
+    }
+    
+    #[tokio::test]
+    async fn test_pages_stable_under_new_receipts() {
+        let server = seeded(30).await;
+        let first: ReceiptPage = server.get("/receipts").add_query_param("limit", 10).await.json();
+        
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        server.post("/verify").json(&request).await.assert_status_ok();
+        
+        let again: ReceiptPage = server.get("/receipts").add_query_param("limit", 10).await.json();
+        let hashes = |page: &ReceiptPage| page.receipts.iter().map(|r| r.hash.clone()).collect::<Vec<_>>();
+        assert_eq!(hashes(&first), hashes(&again));
+        assert_eq!(again.total, 31);
+    }
+    
+    #[tokio::test]
+    async fn test_export_10k_receipts() {
+        use std::io::Read;
+        
+        let server = seeded(10_000).await;
+        
+        let plain = server.get("/receipts/export").await;
+        plain.assert_status_ok();
+        assert!(plain.headers().get("content-encoding").is_none());
+        let lines: Vec<StoredReceipt> = plain
+            .text()
+            .lines()
+            .map(|line| serde_json::from_str(line).unwrap())
+            .collect();
+        assert_eq!(lines.len(), 10_000);
+        assert!(lines.windows(2).all(|w| (&w[0].timestamp, &w[0].hash) <= (&w[1].timestamp, &w[1].hash)));
+        
+        let gzipped = server
+            .get("/receipts/export")
+            .add_header(header::ACCEPT_ENCODING, header::HeaderValue::from_static("gzip"))
+            .await;
+        gzipped.assert_status_ok();
+        assert_eq!(gzipped.header("content-encoding"), "gzip");
+        let mut decoded = String::new();
+        flate2::read::GzDecoder::new(gzipped.as_bytes().as_ref())
+            .read_to_string(&mut decoded)
+            .unwrap();
+        assert_eq!(decoded, plain.text());
+        
+        let filtered = server.get("/receipts/export").add_query_param("c_zero", false).await;
+        assert_eq!(filtered.text().lines().count(), 3334);
+    }
+    
+    #[tokio::test]
+    async fn test_audit_endpoint() {
+        let server = server();
+        let request = serde_json::json!({
+            "claim": "The deployment succeeded",
+            "evidence": ["The deployment finished without errors"]
+        });
+        
+        let response = server.post("/audit").json(&request).await;
+        response.assert_status_ok();
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["proof_exists"], true);
+        assert!(body.get("receipt").is_none());
+        
+        let response = server
+            .post("/audit")
+            .add_query_param("include_receipt", true)
+            .json(&request)
+            .await;
+        let body: serde_json::Value = response.json();
+        assert_eq!(body["receipt"]["results"].as_array().unwrap().len(), 3);
+        
+        let stats: PortalStats = server.get("/stats").await.json();
+        assert_eq!(stats.total_audits, 2);
+        assert_eq!(stats.audits_proof_exists, 2);
+        assert_eq!(stats.total_verifications, 0);
+    }
+    
+    #[tokio::test]
+    async fn test_verify_agrees_with_audit_l1() {
+        let server = server();
+        let request = serde_json::json!({ "claim": "A claim", "evidence": [] });
+        
+        let verify: VerifyResponse = server.post("/verify").json(&request).await.json();
+        let audit: serde_json::Value = server.post("/audit").json(&request).await.json();
+        assert!(!verify.c_zero);
+        assert_eq!(verify.reason.as_deref(), Some("NO_EVIDENCE"));
+        assert_eq!(audit["proof_exists"], false);
+    }
+    
+    #[tokio::test]
+    async fn test_verify_answers_insufficient_evidence_as_not_verified() {
+        use axiom_audit::{levels::SubOperation, AuditReceipt};
+        
+        struct Shortfall;
+        
+        #[async_trait::async_trait]
+        impl VerificationBackend for Shortfall {
+            fn name(&self) -> &'static str {
+                "shortfall"
+            }
+            
+            async fn quick_verify(&self, _: &str, _: &[String]) -> backend::Result<QuickVerdict> {
+                Err(BackendError::Audit(AuditError::InsufficientEvidence))
+            }
+            
+            async fn audit(&self, _: &str, _: &[String], _: &[SubOperation]) -> backend::Result<AuditReceipt> {
+                Err(BackendError::Audit(AuditError::InsufficientEvidence))
+            }
+        }
+        
+        let config = PortalConfig { backend: Box::new(Shortfall), ..PortalConfig::default() };
+        let server = serve(build_router(config), [127, 0, 0, 1]);
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["One item"] });
+        
+        let response = server.post("/verify").json(&request).await;
+        response.assert_status_ok();
+        let verify: VerifyResponse = response.json();
+        assert!(!verify.c_zero);
+        assert_eq!(verify.reason.as_deref(), Some("INSUFFICIENT_EVIDENCE"));
+    }
+    
+    #[tokio::test]
+    async fn test_audit_reports_failure_reason() {
+        let server = server();
+        
+        let no_evidence = serde_json::json!({ "claim": "A claim", "evidence": [] });
+        let body: serde_json::Value = server.post("/audit").json(&no_evidence).await.json();
+        assert_eq!(body["proof_exists"], false);
+        assert_eq!(body["reason"], serde_json::json!({ "code": "NO_EVIDENCE" }));
+        
+        let violation = serde_json::json!({ "claim": "A contradiction holds", "evidence": ["A contradiction holds"] });
+        let body: serde_json::Value = server.post("/audit").json(&violation).await.json();
+        assert_eq!(body["reason"]["code"], "AXIOM_VIOLATION");
+        assert_eq!(body["reason"]["id"], "A2_NON_CONTRADICTION");
+        
+        let passing = serde_json::json!({ "claim": "The deployment succeeded", "evidence": ["The deployment succeeded"] });
+        let body: serde_json::Value = server.post("/audit").json(&passing).await.json();
+        assert_eq!(body["proof_exists"], true);
+        assert!(body.get("reason").is_none());
+        
+        let stats: PortalStats = server.get("/stats").await.json();
+        assert_eq!(stats.audits_no_proof, 2);
+        assert_eq!(stats.audits_no_proof_by_reason["NO_EVIDENCE"], 1);
+        assert_eq!(stats.audits_no_proof_by_reason["AXIOM_VIOLATION"], 1);
+    }
+    
+    #[tokio::test]
+    async fn test_remote_audit_is_cosigned() {
+        use axum::routing::post;
+        
+        fn stub_sign(hash: &str) -> String {
+            format!("STUB:{}", hash)
+        }
+        
+        let service = Arc::new(Mutex::new(AuditService::new()));
+        let stub = Router::new().route(
+            "/audit",
+            post(move |Json(request): Json<AuditRequest>| async move {
+                let receipt = service
+                    .lock()
+                    .await
+                    .audit(&request.claim, &request.evidence, stub_sign)
+                    .unwrap();
+                Json(AuditResponse::from(receipt))
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, stub).await.unwrap() });
+        
+        let server = remote_server(endpoint);
+        let request = serde_json::json!({
+            "claim": "The deployment succeeded",
+            "evidence": ["The deployment finished without errors"]
+        });
+        let body: serde_json::Value = server.post("/audit").json(&request).await.json();
+        assert_eq!(body["proof_exists"], true);
+        
+        let hash = body["receipt_hash"].as_str().unwrap();
+        let stored: CoSignedReceipt = server.get(&format!("/audit/{}", hash)).await.json();
+        assert_eq!(stored.backend, "remote");
+        assert_eq!(stored.receipt.signature, stub_sign(hash));
+        assert!(stored.receipt.verify_hash());
+        assert!(mock_verify(hash, &stored.portal_signature));
+    }
+    
+    #[tokio::test]
+    async fn test_remote_down_is_service_unavailable() {
+        // Bind then drop to get a port nothing listens on
+        let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+        let endpoint = format!("http://{}", listener.local_addr().unwrap());
+        drop(listener);
+        
+        let server = remote_server(endpoint);
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        
+        let response = server.post("/verify").json(&request).expect_failure().await;
+        response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+        
+        let response = server.post("/audit").json(&request).expect_failure().await;
+        response.assert_status(StatusCode::SERVICE_UNAVAILABLE);
+        assert!(response.text().contains("circuit open"));
+    }
+    
+    #[tokio::test]
+    async fn test_empty_claim_is_unprocessable() {
+        let server = server();
+        let request = serde_json::json!({ "claim": "  ", "evidence": ["x"] });
+        
+        server.post("/verify").json(&request).expect_failure().await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        server.post("/audit").json(&request).expect_failure().await
+            .assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+    
+    #[tokio::test]
+    async fn test_identical_requests_reuse_receipt() {
+        let server = server();
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim", "More evidence"] });
+        
+        let first: VerifyResponse = server.post("/verify").json(&request).await.json();
+        assert!(!first.cached);
+        let second: VerifyResponse = server.post("/verify").json(&request).await.json();
+        assert!(second.cached);
+        assert_eq!(second.hash, first.hash);
+        assert_eq!(second.timestamp, first.timestamp);
+        
+        // Evidence order, repeats and claim padding don't matter
+        let reordered = serde_json::json!({ "claim": " A claim ", "evidence": ["More evidence", "A claim", "A claim"] });
+        let third: VerifyResponse = server.post("/verify").json(&reordered).await.json();
+        assert!(third.cached);
+        assert_eq!(third.hash, first.hash);
+        
+        let page: ReceiptPage = server.get("/receipts").await.json();
+        assert_eq!(page.total, 1);
+        let stats: PortalStats = server.get("/stats").await.json();
+        assert_eq!((stats.total_verifications, stats.cache_hits), (3, 2));
+    }
+    
+    #[tokio::test]
+    async fn test_force_bypasses_cache() {
+        let server = server();
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        
+        let first: VerifyResponse = server.post("/verify").json(&request).await.json();
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        let forced: VerifyResponse = server.post("/verify").add_query_param("force", true).json(&request).await.json();
+        assert!(!forced.cached);
+        assert_ne!(forced.hash, first.hash);
+        
+        // The forced receipt replaces the cached one
+        let again: VerifyResponse = server.post("/verify").json(&request).await.json();
+        assert!(again.cached);
+        assert_eq!(again.hash, forced.hash);
+        
+        let disabled = serve(build_router(PortalConfig { cache: VerifyCache::new(0), ..PortalConfig::default() }), [127, 0, 0, 1]);
+        let a: VerifyResponse = disabled.post("/verify").json(&request).await.json();
+        tokio::time::sleep(std::time::Duration::from_millis(2)).await;
+        let b: VerifyResponse = disabled.post("/verify").json(&request).await.json();
+        assert!(!b.cached);
+        assert_ne!(a.hash, b.hash);
+    }
+    
+    #[tokio::test]
+    async fn test_rate_limit_rejects_flooding_client() {
+        let app = build_router(PortalConfig {
+            rate_limit: RateLimiter::new(2),
+            ..PortalConfig::default()
+        });
+        let server = serve(app.clone(), [203, 0, 113, 9]);
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        
+        server.post("/verify").json(&request).await.assert_status_ok();
+        server.post("/verify").json(&request).await.assert_status_ok();
+        let response = server.post("/verify").json(&request).expect_failure().await;
+        response.assert_status(StatusCode::TOO_MANY_REQUESTS);
+        let body: serde_json::Value = response.json();
+        let retry_after = body["retry_after"].as_u64().unwrap();
+        assert!(retry_after >= 1);
+        assert_eq!(response.header("retry-after"), retry_after.to_string().as_str());
+        
+        // Health checks are exempt
+        server.get("/health").await.assert_status_ok();
+        
+        // Localhost is exempt too, and sees the flooding client in the breakdown
+        let local = serve(app, [127, 0, 0, 1]);
+        for _ in 0..3 {
+            local.post("/verify").json(&request).await.assert_status_ok();
+        }
+        let stats: PortalStats = local.get("/stats").await.json();
+        assert_eq!(stats.rejected_rate_limited, 1);
+        assert_eq!(stats.total_verifications, 5);
+        assert_eq!(stats.top_clients.len(), 1);
+        assert_eq!(stats.top_clients[0].ip, "203.0.113.9");
+        assert_eq!(stats.top_clients[0].requests, 3);
+        assert_eq!(stats.top_clients[0].rate_limited, 1);
+    }
+    
+    const CLI_SECRET: &[u8] = b"sap4d-cli secret";
+    const AUDIT_SECRET: &[u8] = b"audit secret";
+    
+    fn trusting_server() -> TestServer {
+        let trust = TrustAnchors::portal_only()
+            .with_anchor(receipt_check::TrustAnchor::hmac("sap4d-cli", CLI_SECRET))
+            .with_anchor(receipt_check::TrustAnchor::hmac("audit", AUDIT_SECRET));
+        let config = PortalConfig {
+            trust,
+            ..PortalConfig::default()
+        };
+        serve(build_router(config), [127, 0, 0, 1])
+    }
+    
+    #[tokio::test]
+    async fn test_verify_receipt_accepts_native_receipt() {
+        let server = server();
+        let request = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        let receipt: serde_json::Value = server.post("/verify").json(&request).await.json();
+        
+        let check: ReceiptCheck = server.post("/verify-receipt").json(&receipt).await.json();
+        assert_eq!(check.kind, receipt_check::ReceiptKind::Portal);
+        assert!(check.valid);
+        assert_eq!(check.details["signer"], "portal");
+    }
+    
+    #[tokio::test]
+    async fn test_verify_receipt_accepts_sap4d_receipt() {
+        let (_, mut receipt) = sap4d::ProofEngine::new()
+            .prove("Water boils", vec!["Water boils".to_string()], |hash| receipt_check::hmac_sign(CLI_SECRET, hash))
+            .unwrap();
+        
+        let check: ReceiptCheck = trusting_server().post("/verify-receipt").json(&receipt).await.json();
+        assert_eq!(check.kind, receipt_check::ReceiptKind::Sap4d);
+        assert!(check.valid);
+        assert_eq!(check.c_zero, receipt.c_zero);
+        assert_eq!(check.details["signer"], "sap4d-cli");
+        
+        // Same receipt, but the portal does not trust the CLI's key
+        let check: ReceiptCheck = server().post("/verify-receipt").json(&receipt).await.json();
+        assert!(!check.valid);
+        assert_eq!(check.details["hash_ok"], true);
+        assert_eq!(check.details["signature_ok"], false);
+        
+        // Knowing the anchor's name does not let anyone else sign as it
+        receipt.signature = receipt_check::hmac_sign(b"sap4d-cli", &receipt.hash);
+        let check: ReceiptCheck = trusting_server().post("/verify-receipt").json(&receipt).await.json();
+        assert!(!check.valid);
+        assert_eq!(check.details["hash_ok"], true);
+        assert_eq!(check.details["signature_ok"], false);
+    }
+    
+    #[tokio::test]
+    async fn test_verify_receipt_accepts_audit_receipt() {
+        let mut receipt = AuditService::new()
+            .audit("A claim", &["A claim".to_string()], |hash| receipt_check::hmac_sign(AUDIT_SECRET, hash))
+            .unwrap();
+        
+        let server = trusting_server();
+        let check: ReceiptCheck = server.post("/verify-receipt").json(&receipt).await.json();
+        assert_eq!(check.kind, receipt_check::ReceiptKind::Audit);
+        assert!(check.valid);
+        assert_eq!(check.details["signer"], "audit");
+        
+        // Explicit type, tampered C=0
+        receipt.c_zero = !receipt.c_zero;
+        let mut body = serde_json::to_value(&receipt).unwrap();
+        body["type"] = "audit".into();
+        let check: ReceiptCheck = server.post("/verify-receipt").json(&body).await.json();
+        assert!(!check.valid);
+        assert_eq!(check.details["hash_ok"], true);
+        assert_eq!(check.details["summary_ok"], false);
+    }
+    
+    #[tokio::test]
+    async fn test_verify_receipt_malformed_is_unprocessable() {
+        let server = server();
+        
+        let response = server.post("/verify-receipt").text("{not json").expect_failure().await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        
+        let body = serde_json::json!({ "type": "sap4d", "claim": "A claim", "C_zero": true });
+        let response = server.post("/verify-receipt").json(&body).expect_failure().await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+        let invalid: InvalidReceipt = response.json();
+        assert!(!invalid.errors.is_empty());
+        
+        let response = server.post("/verify-receipt").json(&serde_json::json!({ "hash": "h" })).expect_failure().await;
+        response.assert_status(StatusCode::UNPROCESSABLE_ENTITY);
+    }
+}
@@ -0,0 +1,210 @@
+//! Receipt listing and export
+//!
+//! Stored receipts are kept ordered by timestamp, then hash, so offset
+//! pagination stays stable while new receipts arrive: fresh receipts sort
+//! after every page already served. `since` is inclusive and `until`
+//! exclusive; both are RFC 3339 (use `Z` or percent-encode the `+`).
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::StoredReceipt;
+use axum::body::{Body, Bytes};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use utoipa::{IntoParams, ToSchema};
+
+/// Page size when `limit` is not given
+pub const DEFAULT_PAGE_SIZE: usize = 50;
+
+/// Largest page `GET /receipts` returns
+pub const MAX_PAGE_SIZE: usize = 1000;
+
+/// Characters of the claim kept in a summary
+pub const CLAIM_PREVIEW_CHARS: usize = 80;
+
+/// Receipts written per chunk of an export stream
+const EXPORT_CHUNK: usize = 256;
+
+/// Filters shared by listing and export
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReceiptFilter {
+    pub c_zero: Option<bool>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl ReceiptFilter {
+    /// Whether `receipt` passes every filter set
+    pub fn matches(&self, receipt: &StoredReceipt) -> bool {
+        if self.c_zero.is_some_and(|c_zero| c_zero != receipt.c_zero) {
+            return false;
+        }
+        if self.since.is_none() && self.until.is_none() {
+            return true;
+        }
+        let Some(timestamp) = parse_timestamp(&receipt.timestamp) else {
+            return false;
+        };
+        self.since.map_or(true, |since| timestamp >= since)
+            && self.until.map_or(true, |until| timestamp < until)
+    }
+}
+
+/// Query for `GET /receipts`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, IntoParams)]
+#[into_params(parameter_in = Query)]
+pub struct ReceiptListQuery {
+    pub limit: Option<usize>,
+    pub offset: Option<usize>,
+    pub c_zero: Option<bool>,
+    pub since: Option<DateTime<Utc>>,
+    pub until: Option<DateTime<Utc>>,
+}
+
+impl ReceiptListQuery {
+    pub fn filter(&self) -> ReceiptFilter {
+        ReceiptFilter {
+            c_zero: self.c_zero,
+            since: self.since,
+            until: self.until,
+        }
+    }
+}
+
+/// A receipt as listed, without evidence or signature
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReceiptSummary {
+    pub hash: String,
+    pub claim: String,
+    pub c_zero: bool,
+    pub timestamp: String,
+}
+
+impl From<&StoredReceipt> for ReceiptSummary {
+    fn from(receipt: &StoredReceipt) -> Self {
+        Self {
+            hash: receipt.hash.clone(),
+            claim: truncate_claim(&receipt.claim),
+            c_zero: receipt.c_zero,
+            timestamp: receipt.timestamp.clone(),
+        }
+    }
+}
+
+/// One page of receipt summaries
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReceiptPage {
+    /// Receipts matching the filters, across all pages
+    pub total: usize,
+    pub offset: usize,
+    pub limit: usize,
+    pub receipts: Vec<ReceiptSummary>,
+}
+
+/// Select the requested page from `receipts` (already in listing order)
+pub fn page(receipts: &[StoredReceipt], query: &ReceiptListQuery) -> ReceiptPage {
+    let filter = query.filter();
+    let offset = query.offset.unwrap_or(0);
+    let limit = query.limit.unwrap_or(DEFAULT_PAGE_SIZE).min(MAX_PAGE_SIZE);
+
+    let mut total = 0;
+    let mut summaries = Vec::new();
+    for receipt in receipts.iter().filter(|r| filter.matches(r)) {
+        if total >= offset && summaries.len() < limit {
+            summaries.push(ReceiptSummary::from(receipt));
+        }
+        total += 1;
+    }
+
+    ReceiptPage {
+        total,
+        offset,
+        limit,
+        receipts: summaries,
+    }
+}
+
+/// Insert `receipt` keeping `receipts` ordered by timestamp, then hash
+pub fn insert_ordered(receipts: &mut Vec<StoredReceipt>, receipt: StoredReceipt) {
+    let index = receipts
+        .binary_search_by(|existing| listing_order(existing, &receipt))
+        .unwrap_or_else(|index| index);
+    receipts.insert(index, receipt);
+}
+
+/// Listing order: timestamp, then hash
+fn listing_order(a: &StoredReceipt, b: &StoredReceipt) -> Ordering {
+    parse_timestamp(&a.timestamp)
+        .cmp(&parse_timestamp(&b.timestamp))
+        .then_with(|| a.hash.cmp(&b.hash))
+}
+
+fn parse_timestamp(timestamp: &str) -> Option<DateTime<Utc>> {
+    DateTime::parse_from_rfc3339(timestamp)
+        .ok()
+        .map(|t| t.with_timezone(&Utc))
+}
+
+/// Shorten a claim to `CLAIM_PREVIEW_CHARS` characters
+fn truncate_claim(claim: &str) -> String {
+    match claim.char_indices().nth(CLAIM_PREVIEW_CHARS) {
+        Some((end, _)) => format!("{}…", &claim[..end]),
+        None => claim.to_string(),
+    }
+}
+
+/// Stream `receipts` as NDJSON, one full receipt per line
+pub fn ndjson_body(receipts: Vec<StoredReceipt>) -> Body {
+    let chunks: Vec<Vec<StoredReceipt>> = receipts
+        .chunks(EXPORT_CHUNK)
+        .map(<[StoredReceipt]>::to_vec)
+        .collect();
+
+    Body::from_stream(futures::stream::iter(chunks.into_iter().map(|chunk| {
+        let mut buf = Vec::new();
+        for receipt in &chunk {
+            serde_json::to_writer(&mut buf, receipt)?;
+            buf.push(b'\n');
+        }
+        Ok::<_, serde_json::Error>(Bytes::from(buf))
+    })))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn receipt(hash: &str, timestamp: &str) -> StoredReceipt {
+        StoredReceipt {
+            claim: "A claim".to_string(),
+            evidence: vec![],
+            c_zero: true,
+            hash: hash.to_string(),
+            signature: String::new(),
+            timestamp: timestamp.to_string(),
+            reason: None,
+        }
+    }
+
+    #[test]
+    fn test_insert_orders_by_timestamp_then_hash() {
+        let mut receipts = Vec::new();
+        insert_ordered(&mut receipts, receipt("b", "2025-06-01T12:00:00.500+00:00"));
+        insert_ordered(&mut receipts, receipt("c", "2025-06-01T12:00:00+00:00"));
+        insert_ordered(&mut receipts, receipt("a", "2025-06-01T12:00:00.500+00:00"));
+
+        let hashes: Vec<&str> = receipts.iter().map(|r| r.hash.as_str()).collect();
+        assert_eq!(hashes, ["c", "a", "b"]);
+    }
+
+    #[test]
+    fn test_truncate_claim_on_char_boundary() {
+        let claim = "é".repeat(CLAIM_PREVIEW_CHARS + 5);
+        let preview = truncate_claim(&claim);
+        assert_eq!(preview.chars().count(), CLAIM_PREVIEW_CHARS + 1);
+        assert!(preview.ends_with('…'));
+        assert_eq!(truncate_claim("short"), "short");
+    }
+}
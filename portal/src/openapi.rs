@@ -0,0 +1,207 @@
+//! OpenAPI description of the portal
+//!
+//! `GET /openapi.json` serves [`spec`], generated from the annotations on
+//! the handlers and their request and response types; `GET /docs` renders
+//! it with Swagger UI. Error bodies are part of the contract: a 429 is a
+//! [`RateLimitError`], a 422 from `/verify-receipt` an [`InvalidReceipt`],
+//! and every other error (404, malformed bodies, backend and store
+//! failures) a plain-text reason.
+//!
+//! `/audit` bodies are described by schema-only mirrors of the
+//! `axiom-audit` types of the same name; the audit receipt itself is an
+//! opaque object whose shape `axiom-audit` owns.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::backend::CoSignedReceipt;
+use crate::listing::{ReceiptPage, ReceiptSummary};
+use crate::rate_limit::{ClientCount, RateLimitError};
+use crate::receipt_check::{FieldError, InvalidReceipt, ReceiptCheck, ReceiptKind};
+use crate::webhook::{DeliveryResult, EndpointStatus, WebhookStatus};
+use crate::{PortalStats, StoredReceipt, VerifyRequest, VerifyResponse};
+use axum::response::Html;
+use axum::Json;
+use serde::{Deserialize, Serialize};
+use utoipa::{OpenApi, ToSchema};
+
+#[derive(OpenApi)]
+#[openapi(
+    info(
+        title = "AXIOM HIVE Verification Portal",
+        description = "Public API for binary proof receipts: Verified | Not Verified"
+    ),
+    paths(
+        crate::health,
+        crate::info,
+        crate::verify,
+        crate::audit,
+        crate::get_audit_receipt,
+        crate::get_receipt,
+        crate::list_receipts,
+        crate::export_receipts,
+        crate::verify_receipt,
+        crate::get_stats,
+        crate::webhook_status,
+    ),
+    components(schemas(
+        VerifyRequest,
+        VerifyResponse,
+        StoredReceipt,
+        PortalStats,
+        ClientCount,
+        RateLimitError,
+        AuditRequest,
+        AuditResponse,
+        SubOperation,
+        Payload,
+        CoSignedReceipt,
+        ReceiptPage,
+        ReceiptSummary,
+        ReceiptCheck,
+        ReceiptKind,
+        InvalidReceipt,
+        FieldError,
+        WebhookStatus,
+        EndpointStatus,
+        DeliveryResult,
+    )),
+    tags(
+        (name = "verification", description = "Binary verdicts on claims and receipts"),
+        (name = "audit", description = "L1/L2/L3 audits and co-signed audit receipts"),
+        (name = "receipts", description = "Stored /verify receipts"),
+        (name = "portal", description = "Health, statistics and webhooks"),
+    )
+)]
+pub struct ApiDoc;
+
+/// The portal's OpenAPI document
+pub fn spec() -> utoipa::openapi::OpenApi {
+    ApiDoc::openapi()
+}
+
+pub(crate) async fn openapi_json() -> Json<utoipa::openapi::OpenApi> {
+    Json(spec())
+}
+
+/// Swagger UI for `openapi.json`, resolved relative to `/docs` so it also
+/// works when the portal is nested under a prefix
+pub(crate) async fn docs() -> Html<&'static str> {
+    Html(r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+    <meta charset="UTF-8">
+    <title>AXIOM HIVE Verification Portal - API</title>
+    <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+    <div id="swagger-ui"></div>
+    <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+    <script>
+        window.ui = SwaggerUIBundle({ url: "openapi.json", dom_id: "#swagger-ui" });
+    </script>
+</body>
+</html>"##)
+}
+
+/// Body of `POST /audit` (`axiom_audit::service::AuditRequest`)
+#[derive(Serialize, Deserialize, ToSchema)]
+#[allow(dead_code)]
+pub(crate) struct AuditRequest {
+    claim: String,
+    evidence: Vec<String>,
+    /// Hash-chained operations checked at L3
+    #[serde(default)]
+    sub_operations: Vec<SubOperation>,
+}
+
+/// One step of an operation chain (`sap4d::SubOperation`)
+#[derive(Serialize, Deserialize, ToSchema)]
+#[allow(dead_code)]
+pub(crate) struct SubOperation {
+    name: String,
+    input: Payload,
+    output: Payload,
+    hash: String,
+    prev_hash: Option<String>,
+}
+
+/// Input or output of a sub-operation (`sap4d::Payload`): text, base64
+/// `bytes`, or an external artifact by `uri`, `sha256` (hex) and `size`
+#[derive(Serialize, Deserialize, ToSchema)]
+#[serde(untagged)]
+#[allow(dead_code)]
+pub(crate) enum Payload {
+    Inline(String),
+    Bytes { bytes: String },
+    External { uri: String, sha256: String, size: u64 },
+}
+
+/// Response of `POST /audit` (`axiom_audit::service::AuditResponse`)
+#[derive(Serialize, Deserialize, ToSchema)]
+#[allow(dead_code)]
+pub(crate) struct AuditResponse {
+    proof_exists: bool,
+    c_zero: bool,
+    receipt_hash: String,
+    timestamp: String,
+    /// Why no proof exists, tagged by `code`; absent when it does
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    reason: Option<serde_json::Value>,
+    /// The audit receipt, with `?include_receipt=true`
+    #[serde(default)]
+    #[schema(value_type = Option<Object>)]
+    receipt: Option<serde_json::Value>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spec_covers_every_route() {
+        let spec = serde_json::to_value(spec()).unwrap();
+        let paths = spec["paths"].as_object().unwrap();
+        for (path, method) in [
+            ("/health", "get"),
+            ("/info", "get"),
+            ("/verify", "post"),
+            ("/audit", "post"),
+            ("/audit/{hash}", "get"),
+            ("/receipt/{hash}", "get"),
+            ("/receipts", "get"),
+            ("/receipts/export", "get"),
+            ("/verify-receipt", "post"),
+            ("/stats", "get"),
+            ("/webhooks/status", "get"),
+        ] {
+            assert!(paths.get(path).and_then(|p| p.get(method)).is_some(), "{} {}", method, path);
+        }
+    }
+
+    #[test]
+    fn test_spec_documents_error_shapes() {
+        let spec = serde_json::to_value(spec()).unwrap();
+        let verify = &spec["paths"]["/verify"]["post"]["responses"];
+        assert_eq!(
+            verify["429"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/RateLimitError"
+        );
+        assert!(verify["422"]["content"]["text/plain"].is_object());
+
+        let receipt = &spec["paths"]["/receipt/{hash}"]["get"]["responses"];
+        assert!(receipt["404"]["content"]["text/plain"].is_object());
+
+        let check = &spec["paths"]["/verify-receipt"]["post"]["responses"];
+        assert_eq!(
+            check["422"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/InvalidReceipt"
+        );
+
+        let schemas = spec["components"]["schemas"].as_object().unwrap();
+        for name in ["AuditRequest", "AuditResponse", "SubOperation", "Payload", "VerifyResponse"] {
+            assert!(schemas.contains_key(name), "{}", name);
+        }
+        assert!(schemas["VerifyResponse"]["properties"]["C_zero"].is_object());
+    }
+}
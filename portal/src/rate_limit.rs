@@ -0,0 +1,236 @@
+//! Per-client rate limiting
+//!
+//! Each client IP gets a token bucket of `PORTAL_RATE_LIMIT` requests per
+//! minute (default 60, `0` disables limiting). Loopback clients and
+//! `/health` are exempt, as are requests without a peer address (a router
+//! served without connect info). Idle buckets are swept once a minute, and
+//! per-client request counts are kept for at most `MAX_TRACKED_CLIENTS`
+//! addresses.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::AppState;
+use axum::{
+    extract::{ConnectInfo, Request, State},
+    http::{header, StatusCode},
+    middleware::Next,
+    response::{IntoResponse, Response},
+    Json,
+};
+use governor::{clock::{Clock, DefaultClock}, DefaultKeyedRateLimiter, Quota};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, SocketAddr};
+use std::num::NonZeroU32;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use utoipa::ToSchema;
+
+/// Requests per minute when `PORTAL_RATE_LIMIT` is unset
+pub const DEFAULT_REQUESTS_PER_MINUTE: u32 = 60;
+
+/// Most client addresses whose counts are kept
+pub const MAX_TRACKED_CLIENTS: usize = 1024;
+
+/// Clients listed in `GET /stats`
+pub const TOP_CLIENTS: usize = 10;
+
+/// How often idle buckets are dropped
+const SWEEP_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Request counts for one client
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ClientCount {
+    pub ip: String,
+    pub requests: u64,
+    pub rate_limited: u64,
+}
+
+/// Body of a 429 response; `retry_after` is also sent as `Retry-After`
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct RateLimitError {
+    pub error: String,
+    /// Whole seconds until the client may retry
+    pub retry_after: u64,
+}
+
+/// Token bucket per client IP plus bounded per-client counts
+pub struct RateLimiter {
+    limiter: Option<DefaultKeyedRateLimiter<IpAddr>>,
+    clock: DefaultClock,
+    clients: Mutex<HashMap<IpAddr, ClientCount>>,
+    last_sweep: Mutex<Instant>,
+}
+
+impl RateLimiter {
+    /// Limit each client to `requests_per_minute`; `0` disables limiting
+    pub fn new(requests_per_minute: u32) -> Self {
+        Self {
+            limiter: NonZeroU32::new(requests_per_minute)
+                .map(|n| governor::RateLimiter::keyed(Quota::per_minute(n))),
+            clock: DefaultClock::default(),
+            clients: Mutex::new(HashMap::new()),
+            last_sweep: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Read `PORTAL_RATE_LIMIT` (requests per minute)
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("PORTAL_RATE_LIMIT") {
+            Ok(value) => value
+                .parse()
+                .map(Self::new)
+                .map_err(|_| format!("PORTAL_RATE_LIMIT must be a number, got '{}'", value)),
+            Err(_) => Ok(Self::new(DEFAULT_REQUESTS_PER_MINUTE)),
+        }
+    }
+
+    /// Take a token for `ip`, or return how long until one is available
+    pub fn check(&self, ip: IpAddr) -> Result<(), Duration> {
+        self.sweep_idle();
+        let outcome = match &self.limiter {
+            Some(limiter) => limiter
+                .check_key(&ip)
+                .map_err(|not_until| not_until.wait_time_from(self.clock.now())),
+            None => Ok(()),
+        };
+        self.record(ip, outcome.is_err());
+        outcome
+    }
+
+    /// Clients with the most requests, busiest first
+    pub fn top_clients(&self, limit: usize) -> Vec<ClientCount> {
+        let clients = self.clients.lock().unwrap();
+        let mut top: Vec<ClientCount> = clients.values().cloned().collect();
+        top.sort_by(|a, b| b.requests.cmp(&a.requests).then_with(|| a.ip.cmp(&b.ip)));
+        top.truncate(limit);
+        top
+    }
+
+    /// Number of clients holding a token bucket
+    #[cfg(test)]
+    fn tracked_buckets(&self) -> usize {
+        self.limiter.as_ref().map_or(0, |l| l.len())
+    }
+
+    fn record(&self, ip: IpAddr, rate_limited: bool) {
+        let mut clients = self.clients.lock().unwrap();
+        if !clients.contains_key(&ip) && clients.len() >= MAX_TRACKED_CLIENTS {
+            // Make room by forgetting the quietest client
+            if let Some(quietest) = clients.iter().min_by_key(|(_, c)| c.requests).map(|(ip, _)| *ip) {
+                clients.remove(&quietest);
+            }
+        }
+        let count = clients.entry(ip).or_insert_with(|| ClientCount {
+            ip: ip.to_string(),
+            requests: 0,
+            rate_limited: 0,
+        });
+        count.requests += 1;
+        count.rate_limited += rate_limited as u64;
+    }
+
+    /// Drop buckets that have refilled, at most once per `SWEEP_INTERVAL`
+    fn sweep_idle(&self) {
+        let Some(limiter) = &self.limiter else {
+            return;
+        };
+        let mut last_sweep = self.last_sweep.lock().unwrap();
+        if last_sweep.elapsed() >= SWEEP_INTERVAL {
+            *last_sweep = Instant::now();
+            limiter.retain_recent();
+            limiter.shrink_to_fit();
+        }
+    }
+
+    #[cfg(test)]
+    fn force_sweep(&self) {
+        *self.last_sweep.lock().unwrap() = Instant::now() - SWEEP_INTERVAL;
+        self.sweep_idle();
+    }
+}
+
+/// Whether a request skips rate limiting
+fn is_exempt(ip: IpAddr, path: &str) -> bool {
+    path == "/health" || ip.to_canonical().is_loopback()
+}
+
+/// Middleware rejecting clients over their rate limit with 429
+pub(crate) async fn limit(
+    State(state): State<Arc<AppState>>,
+    connect_info: Option<ConnectInfo<SocketAddr>>,
+    request: Request,
+    next: Next,
+) -> Response {
+    let Some(ConnectInfo(addr)) = connect_info else {
+        return next.run(request).await;
+    };
+    let ip = addr.ip().to_canonical();
+    if is_exempt(ip, request.uri().path()) {
+        return next.run(request).await;
+    }
+
+    match state.limiter.check(ip) {
+        Ok(()) => next.run(request).await,
+        Err(wait) => {
+            state.stats.lock().await.rejected_rate_limited += 1;
+            tracing::warn!("Rate limited {} for {:?}", ip, wait);
+
+            // Round up so clients never retry early
+            let retry_after = wait.as_secs() + (wait.subsec_nanos() > 0) as u64;
+            let body = RateLimitError {
+                error: "Rate limit exceeded".to_string(),
+                retry_after,
+            };
+            (
+                StatusCode::TOO_MANY_REQUESTS,
+                [(header::RETRY_AFTER, retry_after.to_string())],
+                Json(body),
+            )
+                .into_response()
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bucket_and_idle_eviction() {
+        let limiter = RateLimiter::new(2);
+        let ip: IpAddr = "203.0.113.7".parse().unwrap();
+
+        assert!(limiter.check(ip).is_ok());
+        assert!(limiter.check(ip).is_ok());
+        let wait = limiter.check(ip).unwrap_err();
+        assert!(wait > Duration::ZERO && wait <= Duration::from_secs(30));
+
+        let top = limiter.top_clients(TOP_CLIENTS);
+        assert_eq!(top[0].requests, 3);
+        assert_eq!(top[0].rate_limited, 1);
+
+        // A bucket still refilling survives a sweep
+        limiter.force_sweep();
+        assert_eq!(limiter.tracked_buckets(), 1);
+    }
+
+    #[test]
+    fn test_client_counts_are_bounded() {
+        let limiter = RateLimiter::new(0);
+        let busy: IpAddr = "198.51.100.1".parse().unwrap();
+        for _ in 0..5 {
+            limiter.check(busy).unwrap();
+        }
+        for i in 0..(MAX_TRACKED_CLIENTS as u32 + 100) {
+            limiter.check(IpAddr::from((0x0a00_0000 + i).to_be_bytes())).unwrap();
+        }
+
+        let all = limiter.top_clients(usize::MAX);
+        assert_eq!(all.len(), MAX_TRACKED_CLIENTS);
+        assert_eq!(all[0].ip, busy.to_string());
+        assert!(is_exempt("::ffff:127.0.0.1".parse().unwrap(), "/verify"));
+        assert!(is_exempt(busy, "/health"));
+        assert!(!is_exempt(busy, "/verify"));
+    }
+}
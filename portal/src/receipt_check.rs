@@ -0,0 +1,338 @@
+//! Verification of submitted receipts
+//!
+//! `POST /verify-receipt` accepts three receipt shapes: a `sap4d::Receipt`
+//! (from the CLI or engine), an `axiom_audit::AuditReceipt` (from the audit
+//! service) and the portal's own `/verify` response. The shape is taken from
+//! a `type` field (`sap4d`, `audit` or `portal`) when present and detected
+//! from the fields otherwise. Signatures are accepted only from trust
+//! anchors: the portal's own signer and the HMAC secrets or Ed25519 public
+//! keys configured in `PORTAL_TRUST_ANCHORS`, looked up by the receipt's
+//! `key_id`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::backend::SignFn;
+use crate::VerifyResponse;
+use axiom_audit::AuditReceipt;
+use axum::http::StatusCode;
+use axum::response::{IntoResponse, Response};
+use axum::Json;
+use sap4d::anchors::parse_anchors;
+use sap4d::signing::SignerRegistry;
+use sap4d::Receipt;
+use serde::de::DeserializeOwned;
+use serde::{Deserialize, Serialize};
+use utoipa::ToSchema;
+
+pub use sap4d::anchors::{hmac_sign, AnchorKey, TrustAnchor};
+
+/// Key id of the portal's own signer
+pub const PORTAL_KEY_ID: &str = "portal";
+
+/// Signers accepted by `/verify-receipt`, resolved by key id
+///
+/// The portal's own signer is registered as [`PORTAL_KEY_ID`] when the
+/// router is built; configured anchors sit next to it.
+#[derive(Debug, Default)]
+pub struct TrustAnchors {
+    registry: SignerRegistry,
+}
+
+impl TrustAnchors {
+    /// No configured anchors: only the portal's own receipts verify
+    pub fn portal_only() -> Self {
+        Self::default()
+    }
+
+    /// Also accept `anchor`, replacing any anchor with the same name
+    pub fn with_anchor(mut self, anchor: TrustAnchor) -> Self {
+        let name = anchor.name.clone();
+        self.registry.insert(name, move |hash, signature| anchor.verify(hash, signature));
+        self
+    }
+
+    /// Accept receipts `signer` made, under [`PORTAL_KEY_ID`]
+    pub fn with_portal_signer(mut self, signer: SignFn) -> Self {
+        self.registry.insert(PORTAL_KEY_ID, move |hash, signature| signer(hash) == signature);
+        self
+    }
+
+    /// Read `PORTAL_TRUST_ANCHORS` (see [`TrustAnchors::parse`])
+    pub fn from_env() -> Result<Self, String> {
+        match std::env::var("PORTAL_TRUST_ANCHORS") {
+            Ok(value) => Self::parse(&value),
+            Err(_) => Ok(Self::portal_only()),
+        }
+    }
+
+    /// Parse `name=hmac:<base64 secret>` and `name=ed25519:<base64 public
+    /// key>` entries (see [`sap4d::anchors`])
+    pub fn parse(value: &str) -> Result<Self, String> {
+        let anchors = parse_anchors(value).map_err(|e| format!("PORTAL_TRUST_ANCHORS: {}", e))?;
+        if anchors.iter().any(|anchor| anchor.name == PORTAL_KEY_ID) {
+            return Err(format!("PORTAL_TRUST_ANCHORS: '{}' is the portal's own key id", PORTAL_KEY_ID));
+        }
+        Ok(anchors.into_iter().fold(Self::portal_only(), Self::with_anchor))
+    }
+
+    /// Names of the accepted signers, sorted
+    pub fn names(&self) -> impl Iterator<Item = &str> {
+        self.registry.key_ids()
+    }
+
+    /// Name of the anchor whose signature over `hash` is `signature`
+    ///
+    /// A receipt that records its `key_id` is checked against that anchor
+    /// only; one without is checked against each anchor in turn.
+    pub fn signer(&self, key_id: Option<&str>, hash: &str, signature: &str) -> Option<&str> {
+        self.registry
+            .key_ids()
+            .filter(|name| key_id.map_or(true, |key_id| key_id == *name))
+            .find(|name| self.registry.verify(name, hash, signature).is_ok())
+    }
+}
+
+/// Receipt shapes `/verify-receipt` understands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+#[serde(rename_all = "lowercase")]
+pub enum ReceiptKind {
+    Sap4d,
+    Audit,
+    Portal,
+}
+
+/// Uniform `/verify-receipt` response
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct ReceiptCheck {
+    /// Hash and signature checks all passed
+    pub valid: bool,
+    /// The receipt's C=0 status
+    pub c_zero: bool,
+    #[serde(rename = "type")]
+    pub kind: ReceiptKind,
+    /// `VERIFIED` when valid and C=0, `NOT_VERIFIED` otherwise
+    pub status: String,
+    /// Individual checks for this receipt type
+    #[schema(value_type = Object)]
+    pub details: serde_json::Value,
+}
+
+impl ReceiptCheck {
+    fn new(kind: ReceiptKind, valid: bool, c_zero: bool, details: serde_json::Value) -> Self {
+        let status = if valid && c_zero { "VERIFIED" } else { "NOT_VERIFIED" };
+        Self {
+            valid,
+            c_zero,
+            kind,
+            status: status.to_string(),
+            details,
+        }
+    }
+}
+
+/// One problem with a submitted receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct FieldError {
+    /// Path of the offending field (`.` for the document itself)
+    pub field: String,
+    pub message: String,
+}
+
+/// A submission that could not be read as any receipt (422)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, ToSchema)]
+pub struct InvalidReceipt {
+    pub errors: Vec<FieldError>,
+}
+
+impl InvalidReceipt {
+    fn at(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            errors: vec![FieldError {
+                field: field.into(),
+                message: message.into(),
+            }],
+        }
+    }
+}
+
+impl IntoResponse for InvalidReceipt {
+    fn into_response(self) -> Response {
+        (StatusCode::UNPROCESSABLE_ENTITY, Json(self)).into_response()
+    }
+}
+
+/// Parse `body` as a receipt and verify it against `trust`
+pub fn check(body: &[u8], trust: &TrustAnchors) -> Result<ReceiptCheck, InvalidReceipt> {
+    let document: serde_json::Value =
+        serde_json::from_slice(body).map_err(|e| InvalidReceipt::at(".", e.to_string()))?;
+    let Some(object) = document.as_object() else {
+        return Err(InvalidReceipt::at(".", "receipt must be a JSON object"));
+    };
+
+    let kind = match object.get("type") {
+        Some(kind) => serde_json::from_value(kind.clone())
+            .map_err(|_| InvalidReceipt::at("type", "expected one of: sap4d, audit, portal"))?,
+        None => detect(object).ok_or_else(|| {
+            InvalidReceipt::at(".", "unrecognized receipt shape; set `type` to sap4d, audit or portal")
+        })?,
+    };
+
+    match kind {
+        ReceiptKind::Sap4d => {
+            let receipt: Receipt = parse(document)?;
+            let hash_ok = receipt.verify_hash();
+            let signer = trust.signer(receipt.key_id.as_deref(), &receipt.hash, &receipt.signature);
+            Ok(ReceiptCheck::new(
+                kind,
+                hash_ok && signer.is_some(),
+                receipt.c_zero,
+                serde_json::json!({
+                    "hash": receipt.hash,
+                    "hash_ok": hash_ok,
+                    "signature_ok": signer.is_some(),
+                    "signer": signer,
+                    "version": receipt.version,
+                    "claim": receipt.claim,
+                }),
+            ))
+        }
+        ReceiptKind::Audit => {
+            let receipt: AuditReceipt = parse(document)?;
+            let results_ok = receipt.results.iter().all(|r| r.verify_integrity());
+            let hash_ok = receipt.verify_hash();
+            // The receipt hash covers only the results, so the summary
+            // fields must be re-derived from them
+            let summary_ok = receipt.c_zero == receipt.results.iter().all(|r| r.c_zero)
+                && receipt.final_proof.exists() == receipt.results.iter().all(|r| r.proof.exists());
+            let signer = trust.signer(receipt.key_id.as_deref(), &receipt.receipt_hash, &receipt.signature);
+            Ok(ReceiptCheck::new(
+                kind,
+                results_ok && hash_ok && summary_ok && signer.is_some(),
+                receipt.c_zero,
+                serde_json::json!({
+                    "hash": receipt.receipt_hash,
+                    "results_ok": results_ok,
+                    "hash_ok": hash_ok,
+                    "summary_ok": summary_ok,
+                    "signature_ok": signer.is_some(),
+                    "signer": signer,
+                    "final_proof": receipt.final_proof,
+                }),
+            ))
+        }
+        ReceiptKind::Portal => {
+            // The native receipt carries no claim, so only the signature can be checked
+            let receipt: VerifyResponse = parse(document)?;
+            let signer = trust.signer(Some(PORTAL_KEY_ID), &receipt.hash, &receipt.signature);
+            Ok(ReceiptCheck::new(
+                kind,
+                signer.is_some(),
+                receipt.c_zero,
+                serde_json::json!({
+                    "hash": receipt.hash,
+                    "signature_ok": signer.is_some(),
+                    "signer": signer,
+                }),
+            ))
+        }
+    }
+}
+
+/// Guess the receipt shape from its fields
+fn detect(object: &serde_json::Map<String, serde_json::Value>) -> Option<ReceiptKind> {
+    let has = |key: &str| object.contains_key(key);
+    if has("receipt_hash") && has("results") {
+        Some(ReceiptKind::Audit)
+    } else if has("claim") && has("causal_chain") {
+        Some(ReceiptKind::Sap4d)
+    } else if has("hash") && has("signature") && has("C_zero") {
+        Some(ReceiptKind::Portal)
+    } else {
+        None
+    }
+}
+
+/// Deserialize, reporting the path of the first bad field
+fn parse<T: DeserializeOwned>(document: serde_json::Value) -> Result<T, InvalidReceipt> {
+    serde_path_to_error::deserialize(document).map_err(|e| {
+        let field = e.path().to_string();
+        InvalidReceipt::at(field, e.into_inner().to_string())
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    #[test]
+    fn test_parse_trust_anchors() {
+        let entries = format!("cli={}, audit={}", hmac_entry(b"cli-secret"), hmac_entry(b"audit-secret"));
+        let trust = TrustAnchors::parse(&entries)
+            .unwrap()
+            .with_portal_signer(crate::mock_sign);
+        assert_eq!(trust.names().collect::<Vec<_>>(), ["audit", "cli", "portal"]);
+
+        let signature = hmac_sign(b"cli-secret", "abc");
+        assert_eq!(trust.signer(None, "abc", &signature), Some("cli"));
+        assert_eq!(trust.signer(Some("cli"), "abc", &signature), Some("cli"));
+        assert_eq!(trust.signer(Some("audit"), "abc", &signature), None);
+        assert_eq!(trust.signer(None, "abd", &signature), None);
+        assert_eq!(trust.signer(None, "abc", &crate::mock_sign("abc")), Some("portal"));
+
+        // Knowing an anchor's name is not enough to sign as it
+        assert_eq!(trust.signer(None, "abc", &hmac_sign(b"cli", "abc")), None);
+
+        assert!(TrustAnchors::parse("no-key").is_err());
+        assert!(TrustAnchors::parse("cli=SAP4D_CLI_SIG:").is_err());
+        assert!(TrustAnchors::parse("cli=ed25519:c2hvcnQ=").is_err());
+        assert!(TrustAnchors::parse(&format!("portal={}", hmac_entry(b"s"))).is_err());
+        assert!(TrustAnchors::parse(&format!("a={0},a={0}", hmac_entry(b"s"))).is_err());
+        assert_eq!(TrustAnchors::parse("").unwrap().names().count(), 0);
+    }
+
+    #[test]
+    fn test_ed25519_anchor() {
+        use ring::signature::{Ed25519KeyPair, KeyPair};
+
+        let pair = Ed25519KeyPair::from_seed_unchecked(&[9; 32]).unwrap();
+        let public_key = STANDARD.encode(pair.public_key().as_ref());
+        let trust = TrustAnchors::parse(&format!("signer=ed25519:{}", public_key)).unwrap();
+
+        let signature = STANDARD.encode(pair.sign(b"abc").as_ref());
+        assert_eq!(trust.signer(Some("signer"), "abc", &signature), Some("signer"));
+        assert_eq!(trust.signer(Some("signer"), "abd", &signature), None);
+    }
+
+    fn hmac_entry(secret: &[u8]) -> String {
+        format!("hmac:{}", STANDARD.encode(secret))
+    }
+
+    #[test]
+    fn test_detect_shapes() {
+        let object = |value: serde_json::Value| value.as_object().unwrap().clone();
+        assert_eq!(
+            detect(&object(serde_json::json!({ "receipt_hash": "h", "results": [] }))),
+            Some(ReceiptKind::Audit)
+        );
+        assert_eq!(
+            detect(&object(serde_json::json!({ "claim": "c", "causal_chain": [], "C_zero": true }))),
+            Some(ReceiptKind::Sap4d)
+        );
+        assert_eq!(
+            detect(&object(serde_json::json!({ "hash": "h", "signature": "s", "C_zero": true }))),
+            Some(ReceiptKind::Portal)
+        );
+        assert_eq!(detect(&object(serde_json::json!({ "hash": "h" }))), None);
+    }
+
+    #[test]
+    fn test_field_level_errors() {
+        let body = br#"{"type": "portal", "C_zero": "yes", "hash": "h", "signature": "s", "timestamp": "t"}"#;
+        let err = check(body, &TrustAnchors::default()).unwrap_err();
+        assert_eq!(err.errors[0].field, "C_zero");
+
+        let err = check(b"{not json", &TrustAnchors::default()).unwrap_err();
+        assert_eq!(err.errors[0].field, ".");
+    }
+}
@@ -0,0 +1,216 @@
+//! Serving the portal: TLS and graceful shutdown
+//!
+//! [`serve`] runs the router over plain HTTP, or over HTTPS when
+//! [`ServeOptions::tls`] names a PEM certificate chain and key. Unreadable
+//! or invalid TLS files fail before any connection is accepted.
+//!
+//! When the shutdown future resolves (the binary uses [`shutdown_signal`]:
+//! SIGINT or SIGTERM) the server stops accepting connections, gives
+//! in-flight requests up to [`ServeOptions::drain_timeout`] to finish,
+//! closes whatever is left, and then flushes the receipt store.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::{build_router, PortalConfig, StoreError};
+use axum_server::tls_rustls::RustlsConfig;
+use axum_server::Handle;
+use std::future::Future;
+use std::io;
+use std::net::{SocketAddr, TcpListener};
+use std::path::PathBuf;
+use std::time::Duration;
+use thiserror::Error;
+
+/// How long in-flight requests may run after shutdown starts, unless
+/// `PORTAL_DRAIN_TIMEOUT_SECS` says otherwise
+pub const DEFAULT_DRAIN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Errors starting, running or stopping the server
+#[derive(Debug, Error)]
+pub enum ServeError {
+    #[error("{0}")]
+    Config(String),
+    #[error("Cannot read TLS file {}: {source}", path.display())]
+    TlsFile { path: PathBuf, source: io::Error },
+    #[error("Invalid TLS certificate or key: {0}")]
+    Tls(io::Error),
+    #[error("Portal server failed: {0}")]
+    Io(#[from] io::Error),
+    #[error("Could not flush the receipt store: {0}")]
+    Flush(#[from] StoreError),
+}
+
+/// PEM certificate chain and private key
+#[derive(Debug, Clone)]
+pub struct TlsConfig {
+    pub cert: PathBuf,
+    pub key: PathBuf,
+}
+
+impl TlsConfig {
+    /// `PORTAL_TLS_CERT` and `PORTAL_TLS_KEY`, or `None` when neither is set
+    pub fn from_env() -> Result<Option<Self>, ServeError> {
+        match (std::env::var_os("PORTAL_TLS_CERT"), std::env::var_os("PORTAL_TLS_KEY")) {
+            (Some(cert), Some(key)) => Ok(Some(Self {
+                cert: cert.into(),
+                key: key.into(),
+            })),
+            (None, None) => Ok(None),
+            _ => Err(ServeError::Config(
+                "PORTAL_TLS_CERT and PORTAL_TLS_KEY must be set together".to_string(),
+            )),
+        }
+    }
+
+    /// Read and parse both files
+    pub async fn load(&self) -> Result<RustlsConfig, ServeError> {
+        let read = |path: &PathBuf| {
+            std::fs::read(path).map_err(|source| ServeError::TlsFile {
+                path: path.clone(),
+                source,
+            })
+        };
+        let (cert, key) = (read(&self.cert)?, read(&self.key)?);
+        RustlsConfig::from_pem(cert, key).await.map_err(ServeError::Tls)
+    }
+}
+
+/// How [`serve`] listens and stops
+#[derive(Debug, Clone)]
+pub struct ServeOptions {
+    /// Serve HTTPS with this certificate; plain HTTP when `None`
+    pub tls: Option<TlsConfig>,
+    /// Time in-flight requests get to finish once shutdown starts
+    pub drain_timeout: Duration,
+}
+
+impl Default for ServeOptions {
+    fn default() -> Self {
+        Self {
+            tls: None,
+            drain_timeout: DEFAULT_DRAIN_TIMEOUT,
+        }
+    }
+}
+
+impl ServeOptions {
+    /// `PORTAL_TLS_CERT`/`PORTAL_TLS_KEY` and `PORTAL_DRAIN_TIMEOUT_SECS`
+    pub fn from_env() -> Result<Self, ServeError> {
+        let drain_timeout = match std::env::var("PORTAL_DRAIN_TIMEOUT_SECS") {
+            Ok(value) => value.parse().map(Duration::from_secs).map_err(|_| {
+                ServeError::Config(format!("PORTAL_DRAIN_TIMEOUT_SECS must be a number, got '{}'", value))
+            })?,
+            Err(_) => DEFAULT_DRAIN_TIMEOUT,
+        };
+        Ok(Self {
+            tls: TlsConfig::from_env()?,
+            drain_timeout,
+        })
+    }
+}
+
+/// Serve the portal built from `config` on `listener` until `shutdown` resolves
+///
+/// Returns once the connections have drained (or the drain timeout closed
+/// them) and the receipt store has been flushed.
+pub async fn serve(
+    listener: TcpListener,
+    config: PortalConfig,
+    options: ServeOptions,
+    shutdown: impl Future<Output = ()> + Send + 'static,
+) -> Result<(), ServeError> {
+    let tls = match &options.tls {
+        Some(tls) => Some(tls.load().await?),
+        None => None,
+    };
+    listener.set_nonblocking(true)?;
+
+    let store = config.store.clone();
+    let app = build_router(config).into_make_service_with_connect_info::<SocketAddr>();
+
+    let handle = Handle::new();
+    let stopper = handle.clone();
+    let drain_timeout = options.drain_timeout;
+    let watcher = tokio::spawn(async move {
+        shutdown.await;
+        tracing::info!("Shutting down; draining connections for up to {:?}", drain_timeout);
+        stopper.graceful_shutdown(Some(drain_timeout));
+    });
+
+    let served = match tls {
+        Some(tls) => axum_server::from_tcp_rustls(listener, tls).handle(handle).serve(app).await,
+        None => axum_server::from_tcp(listener).handle(handle).serve(app).await,
+    };
+    watcher.abort();
+
+    // Whatever the server accepted is flushed, even if it failed
+    let flushed = store.flush().await;
+    served?;
+    flushed?;
+    tracing::info!("Verification Portal stopped");
+    Ok(())
+}
+
+/// Resolves on SIGINT (Ctrl+C) or, on Unix, SIGTERM
+pub async fn shutdown_signal() {
+    let interrupt = async {
+        if let Err(e) = tokio::signal::ctrl_c().await {
+            tracing::warn!("Cannot listen for Ctrl+C: {}", e);
+            std::future::pending::<()>().await;
+        }
+    };
+
+    #[cfg(unix)]
+    let terminate = async {
+        match tokio::signal::unix::signal(tokio::signal::unix::SignalKind::terminate()) {
+            Ok(mut signal) => {
+                signal.recv().await;
+            }
+            Err(e) => {
+                tracing::warn!("Cannot listen for SIGTERM: {}", e);
+                std::future::pending::<()>().await;
+            }
+        }
+    };
+    #[cfg(not(unix))]
+    let terminate = std::future::pending::<()>();
+
+    tokio::select! {
+        _ = interrupt => {}
+        _ = terminate => {}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_unreadable_tls_files_fail_fast() {
+        let tls = TlsConfig {
+            cert: PathBuf::from("/nonexistent/portal.crt"),
+            key: PathBuf::from("/nonexistent/portal.key"),
+        };
+        let err = tls.load().await.unwrap_err();
+        assert!(matches!(err, ServeError::TlsFile { ref path, .. } if path == &tls.cert));
+        assert!(err.to_string().contains("/nonexistent/portal.crt"));
+
+        let dir = std::env::temp_dir().join(format!("portal-tls-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let (cert, key) = (dir.join("portal.crt"), dir.join("portal.key"));
+        std::fs::write(&cert, "not a certificate").unwrap();
+        std::fs::write(&key, "not a key").unwrap();
+        let tls = TlsConfig { cert, key };
+        assert!(matches!(tls.load().await, Err(ServeError::Tls(_))));
+
+        // Nothing is served with a bad certificate
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let options = ServeOptions {
+            tls: Some(tls),
+            ..ServeOptions::default()
+        };
+        let result = serve(listener, PortalConfig::default(), options, std::future::pending()).await;
+        assert!(matches!(result, Err(ServeError::Tls(_))));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,111 @@
+//! Receipt storage
+//!
+//! Handlers never touch receipts directly; they go through a `ReceiptStore`
+//! so an application embedding the portal can keep receipts in its own
+//! database. `MemoryStore` is the default and keeps everything in process,
+//! ordered as `listing` expects.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::backend::CoSignedReceipt;
+use crate::listing::{self, ReceiptFilter, ReceiptListQuery, ReceiptPage};
+use crate::StoredReceipt;
+use async_trait::async_trait;
+use axum::http::StatusCode;
+use thiserror::Error;
+use tokio::sync::Mutex;
+
+/// Errors from a receipt store
+#[derive(Debug, Error)]
+pub enum StoreError {
+    #[error("Receipt store unavailable: {0}")]
+    Unavailable(String),
+}
+
+impl StoreError {
+    /// HTTP status the portal answers with
+    pub fn status(&self) -> StatusCode {
+        match self {
+            Self::Unavailable(_) => StatusCode::SERVICE_UNAVAILABLE,
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, StoreError>;
+
+/// Where `/verify` receipts and co-signed audit receipts are kept
+#[async_trait]
+pub trait ReceiptStore: Send + Sync {
+    /// Store a `/verify` receipt
+    async fn insert(&self, receipt: StoredReceipt) -> Result<()>;
+
+    /// `/verify` receipt with `hash`
+    async fn get(&self, hash: &str) -> Result<Option<StoredReceipt>>;
+
+    /// One page of receipt summaries, in listing order
+    async fn page(&self, query: &ReceiptListQuery) -> Result<ReceiptPage>;
+
+    /// Every receipt matching `filter`, in listing order
+    async fn export(&self, filter: &ReceiptFilter) -> Result<Vec<StoredReceipt>>;
+
+    /// Store a co-signed audit receipt
+    async fn insert_audit(&self, receipt: CoSignedReceipt) -> Result<()>;
+
+    /// Co-signed audit receipt with `receipt_hash`
+    async fn get_audit(&self, receipt_hash: &str) -> Result<Option<CoSignedReceipt>>;
+
+    /// Persist anything still buffered; called once the server has drained
+    async fn flush(&self) -> Result<()> {
+        Ok(())
+    }
+}
+
+/// In-process store (lost on restart)
+#[derive(Default)]
+pub struct MemoryStore {
+    receipts: Mutex<Vec<StoredReceipt>>,
+    audit_receipts: Mutex<Vec<CoSignedReceipt>>,
+}
+
+impl MemoryStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+#[async_trait]
+impl ReceiptStore for MemoryStore {
+    async fn insert(&self, receipt: StoredReceipt) -> Result<()> {
+        let mut receipts = self.receipts.lock().await;
+        listing::insert_ordered(&mut receipts, receipt);
+        Ok(())
+    }
+
+    async fn get(&self, hash: &str) -> Result<Option<StoredReceipt>> {
+        let receipts = self.receipts.lock().await;
+        Ok(receipts.iter().find(|r| r.hash == hash).cloned())
+    }
+
+    async fn page(&self, query: &ReceiptListQuery) -> Result<ReceiptPage> {
+        let receipts = self.receipts.lock().await;
+        Ok(listing::page(&receipts, query))
+    }
+
+    async fn export(&self, filter: &ReceiptFilter) -> Result<Vec<StoredReceipt>> {
+        let receipts = self.receipts.lock().await;
+        Ok(receipts.iter().filter(|r| filter.matches(r)).cloned().collect())
+    }
+
+    async fn insert_audit(&self, receipt: CoSignedReceipt) -> Result<()> {
+        self.audit_receipts.lock().await.push(receipt);
+        Ok(())
+    }
+
+    async fn get_audit(&self, receipt_hash: &str) -> Result<Option<CoSignedReceipt>> {
+        let audit_receipts = self.audit_receipts.lock().await;
+        Ok(audit_receipts
+            .iter()
+            .find(|r| r.receipt.receipt_hash == receipt_hash)
+            .cloned())
+    }
+}
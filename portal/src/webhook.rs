@@ -0,0 +1,691 @@
+//! Webhook notifications on verification events
+//!
+//! When configured, each `/verify` and `/audit` result without a proof (or
+//! every result, with `PORTAL_WEBHOOK_EVENTS=all`) is POSTed as a
+//! [`WebhookEvent`] to every webhook URL. The body is signed with
+//! HMAC-SHA256 under a shared secret and the signature sent as
+//! `X-Axiom-Signature: sha256=<hex>`; receivers check it with [`signature`].
+//!
+//! Handlers only enqueue: events go into a bounded queue drained by a
+//! spawned worker, so a slow receiver never holds up a request. When the
+//! queue is full the event is dropped and counted. Transport errors, 429
+//! and 5xx responses are retried with exponential backoff up to
+//! `max_attempts`; `GET /webhooks/status` shows the last delivery per
+//! endpoint. Endpoints are listed by origin only, since webhook URLs often
+//! embed credentials in their path.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::StoredReceipt;
+use axiom_audit::AuditReceipt;
+use hmac::{Hmac, Mac};
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::time::Duration;
+use tokio::sync::mpsc::{self, error::TrySendError};
+use utoipa::ToSchema;
+
+/// Header carrying the body's HMAC-SHA256 signature
+pub const SIGNATURE_HEADER: &str = "x-axiom-signature";
+
+/// Header carrying the event source (`verify` or `audit`)
+pub const EVENT_HEADER: &str = "x-axiom-event";
+
+/// Attempts per delivery when `PORTAL_WEBHOOK_MAX_ATTEMPTS` is unset
+pub const DEFAULT_MAX_ATTEMPTS: u32 = 5;
+
+/// Events held for delivery when `PORTAL_WEBHOOK_QUEUE` is unset
+pub const DEFAULT_QUEUE_CAPACITY: usize = 256;
+
+/// Which results are pushed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum WebhookEvents {
+    /// Only results without a proof (the default)
+    NotVerified,
+    /// Every result
+    All,
+}
+
+/// Webhook settings
+#[derive(Debug, Clone)]
+pub struct WebhookConfig {
+    /// Receivers; each event is POSTed to all of them
+    pub urls: Vec<String>,
+    /// HMAC-SHA256 key for the signature header
+    pub secret: String,
+    /// Which results are pushed
+    pub events: WebhookEvents,
+    /// Attempts per delivery, including the first
+    pub max_attempts: u32,
+    /// Backoff before the first retry; doubles per retry
+    pub backoff: Duration,
+    /// Per-attempt request timeout
+    pub timeout: Duration,
+    /// Events held for delivery before new ones are dropped
+    pub queue_capacity: usize,
+}
+
+impl WebhookConfig {
+    /// Defaults for `urls` signed with `secret`
+    pub fn new(urls: Vec<String>, secret: impl Into<String>) -> Self {
+        Self {
+            urls,
+            secret: secret.into(),
+            events: WebhookEvents::NotVerified,
+            max_attempts: DEFAULT_MAX_ATTEMPTS,
+            backoff: Duration::from_millis(500),
+            timeout: Duration::from_secs(5),
+            queue_capacity: DEFAULT_QUEUE_CAPACITY,
+        }
+    }
+
+    /// Read webhook settings from the environment
+    pub fn from_env() -> Result<Option<Self>, String> {
+        Self::from_lookup(|key| std::env::var(key).ok())
+    }
+
+    /// Read webhook settings from `lookup`; `None` without `PORTAL_WEBHOOK_URL`
+    ///
+    /// `PORTAL_WEBHOOK_URL` (comma-separated), `PORTAL_WEBHOOK_SECRET`
+    /// (required with a URL), `PORTAL_WEBHOOK_EVENTS` (`not_verified` or
+    /// `all`), `PORTAL_WEBHOOK_MAX_ATTEMPTS`, `PORTAL_WEBHOOK_BACKOFF_MS`,
+    /// `PORTAL_WEBHOOK_TIMEOUT_MS`, `PORTAL_WEBHOOK_QUEUE`.
+    pub fn from_lookup(lookup: impl Fn(&str) -> Option<String>) -> Result<Option<Self>, String> {
+        let urls: Vec<String> = lookup("PORTAL_WEBHOOK_URL")
+            .unwrap_or_default()
+            .split(',')
+            .map(str::trim)
+            .filter(|url| !url.is_empty())
+            .map(str::to_string)
+            .collect();
+        if urls.is_empty() {
+            return Ok(None);
+        }
+        if let Some(url) = urls.iter().find(|url| reqwest::Url::parse(url).is_err()) {
+            return Err(format!("PORTAL_WEBHOOK_URL has an invalid URL '{}'", url));
+        }
+        let secret = lookup("PORTAL_WEBHOOK_SECRET")
+            .filter(|secret| !secret.is_empty())
+            .ok_or_else(|| "PORTAL_WEBHOOK_SECRET is required with PORTAL_WEBHOOK_URL".to_string())?;
+        let mut config = Self::new(urls, secret);
+
+        match lookup("PORTAL_WEBHOOK_EVENTS").as_deref() {
+            None | Some("not_verified") => {}
+            Some("all") => config.events = WebhookEvents::All,
+            Some(other) => {
+                return Err(format!("PORTAL_WEBHOOK_EVENTS must be 'not_verified' or 'all', got '{}'", other));
+            }
+        }
+
+        let number = |key: &str| -> Result<Option<u64>, String> {
+            lookup(key)
+                .map(|v| v.parse().map_err(|_| format!("{} must be a number, got '{}'", key, v)))
+                .transpose()
+        };
+        if let Some(n) = number("PORTAL_WEBHOOK_MAX_ATTEMPTS")? {
+            config.max_attempts = (n as u32).max(1);
+        }
+        if let Some(ms) = number("PORTAL_WEBHOOK_BACKOFF_MS")? {
+            config.backoff = Duration::from_millis(ms);
+        }
+        if let Some(ms) = number("PORTAL_WEBHOOK_TIMEOUT_MS")? {
+            config.timeout = Duration::from_millis(ms);
+        }
+        if let Some(n) = number("PORTAL_WEBHOOK_QUEUE")? {
+            config.queue_capacity = (n as usize).max(1);
+        }
+
+        Ok(Some(config))
+    }
+}
+
+/// Where an event came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EventSource {
+    Verify,
+    Audit,
+}
+
+impl EventSource {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Self::Verify => "verify",
+            Self::Audit => "audit",
+        }
+    }
+}
+
+/// Body of a webhook POST: a summary of one result
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct WebhookEvent {
+    pub source: EventSource,
+    /// Whether a proof exists (C = 0)
+    pub verified: bool,
+    /// Receipt hash (`GET /receipt/{hash}` or `GET /audit/{hash}`)
+    pub hash: String,
+    pub claim: String,
+    pub evidence_count: usize,
+    pub timestamp: String,
+    /// Failure reason code, for audits without a proof
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl WebhookEvent {
+    /// Summary of a `/verify` receipt
+    pub fn from_receipt(receipt: &StoredReceipt) -> Self {
+        Self {
+            source: EventSource::Verify,
+            verified: receipt.c_zero,
+            hash: receipt.hash.clone(),
+            claim: receipt.claim.clone(),
+            evidence_count: receipt.evidence.len(),
+            timestamp: receipt.timestamp.clone(),
+            reason: None,
+        }
+    }
+
+    /// Summary of an `/audit` receipt
+    pub fn from_audit(receipt: &AuditReceipt) -> Self {
+        let first = receipt.results.first();
+        Self {
+            source: EventSource::Audit,
+            verified: receipt.proof_exists(),
+            hash: receipt.receipt_hash.clone(),
+            claim: first.map(|r| r.claim.clone()).unwrap_or_default(),
+            evidence_count: first.map_or(0, |r| r.evidence.len()),
+            timestamp: receipt.timestamp.to_rfc3339(),
+            reason: receipt.failure_reason().map(|reason| reason.code().to_string()),
+        }
+    }
+}
+
+/// `sha256=` and the hex HMAC-SHA256 of `body` under `secret`
+pub fn signature(secret: &str, body: &[u8]) -> String {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret.as_bytes()).expect("HMAC accepts keys of any length");
+    mac.update(body);
+    format!("sha256={}", hex::encode(mac.finalize().into_bytes()))
+}
+
+/// Outcome of one delivery to one endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct DeliveryResult {
+    /// Hash of the event's receipt
+    pub hash: String,
+    pub delivered: bool,
+    pub attempts: u32,
+    /// Last HTTP status received, if any
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<u16>,
+    /// Why the last attempt failed
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub finished_at: String,
+}
+
+/// Delivery counts for one endpoint
+#[derive(Debug, Clone, Serialize, Deserialize, ToSchema)]
+pub struct EndpointStatus {
+    /// Scheme, host and port of the URL
+    pub endpoint: String,
+    pub delivered: u64,
+    pub failed: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last: Option<DeliveryResult>,
+}
+
+/// Response of `GET /webhooks/status`
+#[derive(Debug, Clone, Default, Serialize, Deserialize, ToSchema)]
+pub struct WebhookStatus {
+    pub enabled: bool,
+    /// Events accepted into the queue
+    pub queued: u64,
+    /// Events dropped because the queue was full
+    pub dropped: u64,
+    /// Events waiting for delivery
+    pub pending: usize,
+    pub endpoints: Vec<EndpointStatus>,
+}
+
+/// Webhook dispatcher held by the portal
+///
+/// The delivery worker is spawned on the first event, so a `Webhooks` can
+/// be built outside a Tokio runtime.
+pub struct Webhooks {
+    events: WebhookEvents,
+    sender: Option<mpsc::Sender<WebhookEvent>>,
+    worker: Mutex<Option<(mpsc::Receiver<WebhookEvent>, Arc<Deliverer>)>>,
+    status: Arc<Mutex<WebhookStatus>>,
+}
+
+impl Default for Webhooks {
+    fn default() -> Self {
+        Self::disabled()
+    }
+}
+
+impl Webhooks {
+    /// No webhooks; events are ignored
+    pub fn disabled() -> Self {
+        Self {
+            events: WebhookEvents::NotVerified,
+            sender: None,
+            worker: Mutex::new(None),
+            status: Arc::new(Mutex::new(WebhookStatus::default())),
+        }
+    }
+
+    /// Deliver to `config.urls`
+    pub fn new(config: WebhookConfig) -> Result<Self, String> {
+        let client = reqwest::Client::builder()
+            .use_rustls_tls()
+            .timeout(config.timeout)
+            .build()
+            .map_err(|e| format!("Failed to build webhook HTTP client: {}", e))?;
+
+        let status = Arc::new(Mutex::new(WebhookStatus {
+            enabled: true,
+            endpoints: config
+                .urls
+                .iter()
+                .map(|url| EndpointStatus {
+                    endpoint: origin(url),
+                    delivered: 0,
+                    failed: 0,
+                    last: None,
+                })
+                .collect(),
+            ..WebhookStatus::default()
+        }));
+        let (sender, receiver) = mpsc::channel(config.queue_capacity.max(1));
+        let deliverer = Arc::new(Deliverer {
+            client,
+            config: config.clone(),
+            status: status.clone(),
+        });
+
+        Ok(Self {
+            events: config.events,
+            sender: Some(sender),
+            worker: Mutex::new(Some((receiver, deliverer))),
+            status,
+        })
+    }
+
+    /// Read `PORTAL_WEBHOOK_*` (see [`WebhookConfig::from_lookup`])
+    pub fn from_env() -> Result<Self, String> {
+        match WebhookConfig::from_env()? {
+            Some(config) => Self::new(config),
+            None => Ok(Self::disabled()),
+        }
+    }
+
+    /// Queue `event` for delivery if it should be pushed; never waits
+    pub fn notify(&self, event: WebhookEvent) {
+        let Some(sender) = &self.sender else {
+            return;
+        };
+        if event.verified && self.events == WebhookEvents::NotVerified {
+            return;
+        }
+
+        if let Some((receiver, deliverer)) = lock(&self.worker).take() {
+            tokio::spawn(deliverer.run(receiver));
+        }
+
+        match sender.try_send(event) {
+            Ok(()) => lock(&self.status).queued += 1,
+            Err(TrySendError::Full(event) | TrySendError::Closed(event)) => {
+                tracing::warn!("Webhook queue full; dropping event for {}", event.hash);
+                lock(&self.status).dropped += 1;
+            }
+        }
+    }
+
+    /// Delivery counts and the last result per endpoint
+    pub fn status(&self) -> WebhookStatus {
+        let mut status = lock(&self.status).clone();
+        status.pending = self
+            .sender
+            .as_ref()
+            .map_or(0, |sender| sender.max_capacity() - sender.capacity());
+        status
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// Scheme, host and port of `url`
+fn origin(url: &str) -> String {
+    reqwest::Url::parse(url)
+        .map(|url| url.origin().ascii_serialization())
+        .unwrap_or_else(|_| "invalid URL".to_string())
+}
+
+/// The delivery worker's state
+struct Deliverer {
+    client: reqwest::Client,
+    config: WebhookConfig,
+    status: Arc<Mutex<WebhookStatus>>,
+}
+
+impl Deliverer {
+    /// Deliver queued events in order until the queue closes
+    async fn run(self: Arc<Self>, mut receiver: mpsc::Receiver<WebhookEvent>) {
+        while let Some(event) = receiver.recv().await {
+            let body = match serde_json::to_vec(&event) {
+                Ok(body) => body,
+                Err(e) => {
+                    tracing::error!("Failed to serialize webhook event {}: {}", event.hash, e);
+                    continue;
+                }
+            };
+            let signature = signature(&self.config.secret, &body);
+
+            let deliveries = self
+                .config
+                .urls
+                .iter()
+                .enumerate()
+                .map(|(index, url)| self.deliver(index, url, &event, &body, &signature));
+            futures::future::join_all(deliveries).await;
+        }
+    }
+
+    /// POST `body` to `url`, retrying transient failures
+    async fn deliver(&self, index: usize, url: &str, event: &WebhookEvent, body: &[u8], signature: &str) {
+        let mut attempts = 0;
+        let (status, error) = loop {
+            attempts += 1;
+            let response = self
+                .client
+                .post(url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .header(SIGNATURE_HEADER, signature)
+                .header(EVENT_HEADER, event.source.as_str())
+                .body(body.to_vec())
+                .send()
+                .await;
+
+            let (status, error, retryable) = match response {
+                Ok(response) if response.status().is_success() => break (Some(response.status().as_u16()), None),
+                Ok(response) => {
+                    let status = response.status();
+                    let retryable = status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS;
+                    (Some(status.as_u16()), Some(format!("receiver answered {}", status)), retryable)
+                }
+                Err(e) => (None, Some(e.to_string()), true),
+            };
+            if !retryable || attempts >= self.config.max_attempts {
+                break (status, error);
+            }
+            tracing::warn!(
+                "Webhook delivery to {} attempt {} failed: {}",
+                origin(url),
+                attempts,
+                error.as_deref().unwrap_or_default()
+            );
+            tokio::time::sleep(self.config.backoff * 2u32.saturating_pow(attempts - 1)).await;
+        };
+
+        let delivered = error.is_none();
+        if !delivered {
+            tracing::error!("Webhook delivery to {} failed after {} attempts", origin(url), attempts);
+        }
+
+        let mut state = lock(&self.status);
+        let endpoint = &mut state.endpoints[index];
+        if delivered {
+            endpoint.delivered += 1;
+        } else {
+            endpoint.failed += 1;
+        }
+        endpoint.last = Some(DeliveryResult {
+            hash: event.hash.clone(),
+            delivered,
+            attempts,
+            status,
+            error,
+            finished_at: chrono::Utc::now().to_rfc3339(),
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{build_router, PortalConfig, VerifyResponse};
+    use axum::{body::Bytes, http::HeaderMap, http::StatusCode, routing::post, Router};
+    use axum_test::TestServer;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    const SECRET: &str = "webhook-secret";
+
+    /// A POST as the mock receiver saw it
+    #[derive(Debug, Clone)]
+    struct Received {
+        signature: String,
+        event: String,
+        body: Bytes,
+    }
+
+    /// Receiver answering with `statuses` in turn (then 200), after `delay`
+    async fn receiver(statuses: Vec<u16>, delay: Duration) -> (String, Arc<Mutex<Vec<Received>>>) {
+        let received = Arc::new(Mutex::new(Vec::new()));
+        let calls = Arc::new(AtomicUsize::new(0));
+        let log = received.clone();
+        let app = Router::new().route(
+            "/hook",
+            post(move |headers: HeaderMap, body: Bytes| {
+                let (log, calls, statuses) = (log.clone(), calls.clone(), statuses.clone());
+                async move {
+                    tokio::time::sleep(delay).await;
+                    let header =
+                        |name: &str| headers.get(name).and_then(|v| v.to_str().ok()).unwrap_or_default().to_string();
+                    lock(&log).push(Received {
+                        signature: header(SIGNATURE_HEADER),
+                        event: header(EVENT_HEADER),
+                        body,
+                    });
+                    let call = calls.fetch_add(1, Ordering::SeqCst);
+                    StatusCode::from_u16(statuses.get(call).copied().unwrap_or(200)).unwrap()
+                }
+            }),
+        );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+        (url, received)
+    }
+
+    fn config(url: &str) -> WebhookConfig {
+        WebhookConfig {
+            backoff: Duration::from_millis(10),
+            max_attempts: 3,
+            ..WebhookConfig::new(vec![url.to_string()], SECRET)
+        }
+    }
+
+    fn event(verified: bool) -> WebhookEvent {
+        WebhookEvent {
+            source: EventSource::Verify,
+            verified,
+            hash: "abc123".to_string(),
+            claim: "A claim".to_string(),
+            evidence_count: 0,
+            timestamp: "2025-06-01T12:00:00+00:00".to_string(),
+            reason: None,
+        }
+    }
+
+    /// Wait until every endpoint has finished a delivery
+    async fn settled(webhooks: &Webhooks) -> WebhookStatus {
+        for _ in 0..200 {
+            let status = webhooks.status();
+            if status.endpoints.iter().all(|e| e.last.is_some()) {
+                return status;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        panic!("webhook delivery did not finish");
+    }
+
+    #[test]
+    fn test_signature_is_hmac_sha256() {
+        // RFC 4231 test case 2
+        assert_eq!(
+            signature("Jefe", b"what do ya want for nothing?"),
+            "sha256=5bdcc146bf60754e6a042426089575c75a003f089d2739839dec58b964ec3843"
+        );
+    }
+
+    #[test]
+    fn test_config_from_lookup() {
+        let env = |pairs: &'static [(&'static str, &'static str)]| {
+            move |key: &str| pairs.iter().find(|(k, _)| *k == key).map(|(_, v)| v.to_string())
+        };
+        assert!(WebhookConfig::from_lookup(env(&[])).unwrap().is_none());
+
+        let config = WebhookConfig::from_lookup(env(&[
+            ("PORTAL_WEBHOOK_URL", "https://a.example/hook, https://b.example/hook"),
+            ("PORTAL_WEBHOOK_SECRET", "s"),
+            ("PORTAL_WEBHOOK_EVENTS", "all"),
+            ("PORTAL_WEBHOOK_MAX_ATTEMPTS", "7"),
+        ]))
+        .unwrap()
+        .unwrap();
+        assert_eq!(config.urls, ["https://a.example/hook", "https://b.example/hook"]);
+        assert_eq!(config.events, WebhookEvents::All);
+        assert_eq!(config.max_attempts, 7);
+
+        let err = WebhookConfig::from_lookup(env(&[("PORTAL_WEBHOOK_URL", "https://a.example/hook")])).unwrap_err();
+        assert!(err.contains("PORTAL_WEBHOOK_SECRET"));
+        let err = WebhookConfig::from_lookup(env(&[
+            ("PORTAL_WEBHOOK_URL", "https://a.example/hook"),
+            ("PORTAL_WEBHOOK_SECRET", "s"),
+            ("PORTAL_WEBHOOK_EVENTS", "some"),
+        ]))
+        .unwrap_err();
+        assert!(err.contains("PORTAL_WEBHOOK_EVENTS"));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_is_signed_and_retried_after_500() {
+        let (url, received) = receiver(vec![500, 500], Duration::ZERO).await;
+        let webhooks = Webhooks::new(config(&url)).unwrap();
+
+        webhooks.notify(event(false));
+        let status = settled(&webhooks).await;
+
+        let received = lock(&received).clone();
+        assert_eq!(received.len(), 3);
+        for post in &received {
+            assert_eq!(post.signature, signature(SECRET, &post.body));
+            assert_eq!(post.event, "verify");
+        }
+        let body: WebhookEvent = serde_json::from_slice(&received[0].body).unwrap();
+        assert_eq!(body.hash, "abc123");
+        assert!(!body.verified);
+
+        let endpoint = &status.endpoints[0];
+        assert_eq!(endpoint.endpoint, url.trim_end_matches("/hook"));
+        assert_eq!((endpoint.delivered, endpoint.failed), (1, 0));
+        let last = endpoint.last.as_ref().unwrap();
+        assert!(last.delivered);
+        assert_eq!((last.attempts, last.status), (3, Some(200)));
+    }
+
+    #[tokio::test]
+    async fn test_delivery_gives_up_after_max_attempts() {
+        let (url, received) = receiver(vec![500; 10], Duration::ZERO).await;
+        let webhooks = Webhooks::new(config(&url)).unwrap();
+
+        webhooks.notify(event(false));
+        let last = settled(&webhooks).await.endpoints[0].last.clone().unwrap();
+        assert!(!last.delivered);
+        assert_eq!((last.attempts, last.status), (3, Some(500)));
+        assert_eq!(lock(&received).len(), 3);
+
+        // A 4xx is not retried
+        let (url, received) = receiver(vec![400], Duration::ZERO).await;
+        let webhooks = Webhooks::new(config(&url)).unwrap();
+        webhooks.notify(event(false));
+        let last = settled(&webhooks).await.endpoints[0].last.clone().unwrap();
+        assert_eq!((last.attempts, last.status), (1, Some(400)));
+        assert_eq!(lock(&received).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_verified_results_are_filtered_and_full_queue_drops() {
+        let (url, received) = receiver(vec![], Duration::ZERO).await;
+        let webhooks = Webhooks::new(WebhookConfig {
+            queue_capacity: 1,
+            ..config(&url)
+        })
+        .unwrap();
+
+        webhooks.notify(event(true));
+        assert_eq!(webhooks.status().queued, 0);
+
+        // The worker cannot run until this task yields, so only one fits
+        for _ in 0..4 {
+            webhooks.notify(event(false));
+        }
+        let status = webhooks.status();
+        assert_eq!((status.queued, status.dropped, status.pending), (1, 3, 1));
+
+        settled(&webhooks).await;
+        assert_eq!(lock(&received).len(), 1);
+        assert!(!Webhooks::disabled().status().enabled);
+    }
+
+    #[tokio::test]
+    async fn test_portal_pushes_not_verified_results_off_the_request_path() {
+        // A receiver slower than any /verify should be
+        let (url, received) = receiver(vec![500], Duration::from_millis(300)).await;
+        let server = TestServer::new(build_router(PortalConfig {
+            webhooks: Webhooks::new(WebhookConfig {
+                backoff: Duration::from_millis(10),
+                ..WebhookConfig::new(vec![url], SECRET)
+            })
+            .unwrap(),
+            ..PortalConfig::default()
+        }))
+        .unwrap();
+
+        let verified = serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] });
+        server.post("/verify").json(&verified).await.assert_status_ok();
+
+        let started = std::time::Instant::now();
+        let not_verified = serde_json::json!({ "claim": "A claim", "evidence": [] });
+        let response: VerifyResponse = server.post("/verify").json(&not_verified).await.json();
+        assert!(!response.c_zero);
+        assert!(started.elapsed() < Duration::from_millis(300));
+
+        let mut status = WebhookStatus::default();
+        for _ in 0..200 {
+            status = server.get("/webhooks/status").await.json();
+            if status.endpoints[0].last.is_some() {
+                break;
+            }
+            tokio::time::sleep(Duration::from_millis(10)).await;
+        }
+        assert!(status.enabled);
+        assert_eq!(status.queued, 1);
+        let last = status.endpoints[0].last.clone().expect("delivery finished");
+        assert!(last.delivered);
+        assert_eq!(last.attempts, 2);
+        assert_eq!(last.hash, response.hash);
+
+        let received = lock(&received).clone();
+        assert_eq!(received.len(), 2);
+        assert!(received.iter().all(|post| post.signature == signature(SECRET, &post.body)));
+        let body: WebhookEvent = serde_json::from_slice(&received[1].body).unwrap();
+        assert_eq!((body.source, body.verified, body.evidence_count), (EventSource::Verify, false, 0));
+    }
+}
@@ -0,0 +1,225 @@
+//! Real portal responses checked against the schemas in `GET /openapi.json`
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axum::body::Body;
+use axum::extract::connect_info::MockConnectInfo;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use jsonschema::JSONSchema;
+use portal::{build_router, PortalConfig, RateLimiter};
+use serde_json::{json, Value};
+use std::net::SocketAddr;
+use tower::ServiceExt;
+
+/// Status, content type and body of one request to `app`
+async fn call(app: &Router, request: Request<Body>) -> (StatusCode, String, Vec<u8>) {
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let content_type = response
+        .headers()
+        .get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .unwrap_or_default()
+        .to_string();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, content_type, bytes.to_vec())
+}
+
+fn post_json(uri: &str, body: Value) -> Request<Body> {
+    Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+/// OpenAPI 3.0 `nullable` as JSON Schema: `{"anyOf": [schema, {"type": "null"}]}`
+fn json_schema(schema: &Value) -> Value {
+    match schema {
+        Value::Object(object) => {
+            let mut object: serde_json::Map<String, Value> =
+                object.iter().map(|(k, v)| (k.clone(), json_schema(v))).collect();
+            if object.remove("nullable") == Some(Value::Bool(true)) {
+                json!({ "anyOf": [Value::Object(object), { "type": "null" }] })
+            } else {
+                Value::Object(object)
+            }
+        }
+        Value::Array(items) => Value::Array(items.iter().map(json_schema).collect()),
+        other => other.clone(),
+    }
+}
+
+/// Assert that `body` is what the spec declares for `method path` answering
+/// `status` with `content_type`
+fn assert_conforms(spec: &Value, method: &str, path: &str, status: StatusCode, content_type: &str, body: &[u8]) {
+    let declared = &spec["paths"][path][method]["responses"][status.as_str()];
+    assert!(declared.is_object(), "{} {} does not declare {}", method, path, status);
+
+    let media = content_type.split(';').next().unwrap_or_default().trim();
+    let Some(schema) = declared["content"].get(media) else {
+        panic!("{} {} {} does not declare {}", method, path, status, media);
+    };
+
+    if media != "application/json" {
+        assert_eq!(schema["schema"]["type"], "string", "{} {} {}", method, path, status);
+        return;
+    }
+    let instance: Value = serde_json::from_slice(body).unwrap();
+    let root = json!({
+        "allOf": [json_schema(&schema["schema"])],
+        "components": json_schema(&spec["components"]),
+    });
+    let compiled = JSONSchema::compile(&root).expect("declared schema compiles");
+    if let Err(errors) = compiled.validate(&instance) {
+        let errors: Vec<String> = errors.map(|e| format!("{} at {}", e, e.instance_path)).collect();
+        panic!("{} {} {} does not match the spec: {:?}\n{}", method, path, status, errors, instance);
+    };
+}
+
+async fn spec(app: &Router) -> Value {
+    let (status, _, body) = call(app, get("/openapi.json")).await;
+    assert_eq!(status, StatusCode::OK);
+    serde_json::from_slice(&body).unwrap()
+}
+
+fn claim() -> Value {
+    json!({ "claim": "A claim", "evidence": ["A claim"] })
+}
+
+#[tokio::test]
+async fn served_spec_is_the_generated_spec() {
+    let app = build_router(PortalConfig::default());
+    assert_eq!(spec(&app).await, serde_json::to_value(portal::openapi::spec()).unwrap());
+
+    let (status, content_type, body) = call(&app, get("/docs")).await;
+    assert_eq!(status, StatusCode::OK);
+    assert!(content_type.starts_with("text/html"));
+    assert!(String::from_utf8(body).unwrap().contains("SwaggerUIBundle"));
+}
+
+#[tokio::test]
+async fn verify_and_receipt_responses_conform() {
+    let app = build_router(PortalConfig::default());
+    let spec = spec(&app).await;
+
+    let (status, content_type, body) = call(&app, post_json("/verify", claim())).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_conforms(&spec, "post", "/verify", status, &content_type, &body);
+    let hash = serde_json::from_slice::<Value>(&body).unwrap()["hash"].as_str().unwrap().to_string();
+
+    // Not verified, with a reason
+    let (status, content_type, body) = call(&app, post_json("/verify", json!({ "claim": "A claim", "evidence": [] }))).await;
+    assert_conforms(&spec, "post", "/verify", status, &content_type, &body);
+
+    let (status, content_type, body) = call(&app, get(&format!("/receipt/{}", hash))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_conforms(&spec, "get", "/receipt/{hash}", status, &content_type, &body);
+
+    let (status, content_type, body) = call(&app, get("/receipt/missing")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_conforms(&spec, "get", "/receipt/{hash}", status, &content_type, &body);
+}
+
+#[tokio::test]
+async fn audit_responses_conform() {
+    let app = build_router(PortalConfig::default());
+    let spec = spec(&app).await;
+
+    for uri in ["/audit", "/audit?include_receipt=true"] {
+        let (status, content_type, body) = call(&app, post_json(uri, claim())).await;
+        assert_eq!(status, StatusCode::OK);
+        assert_conforms(&spec, "post", "/audit", status, &content_type, &body);
+    }
+    let (_, _, body) = call(&app, post_json("/audit", json!({ "claim": "A claim", "evidence": [] }))).await;
+    let audit: Value = serde_json::from_slice(&body).unwrap();
+    assert!(audit["reason"].is_object());
+
+    let hash = audit["receipt_hash"].as_str().unwrap();
+    let (status, content_type, body) = call(&app, get(&format!("/audit/{}", hash))).await;
+    assert_eq!(status, StatusCode::OK);
+    assert_conforms(&spec, "get", "/audit/{hash}", status, &content_type, &body);
+
+    let (status, content_type, body) = call(&app, get("/audit/missing")).await;
+    assert_eq!(status, StatusCode::NOT_FOUND);
+    assert_conforms(&spec, "get", "/audit/{hash}", status, &content_type, &body);
+}
+
+#[tokio::test]
+async fn listing_and_status_responses_conform() {
+    let app = build_router(PortalConfig::default());
+    let spec = spec(&app).await;
+    call(&app, post_json("/verify", claim())).await;
+    call(&app, post_json("/audit", json!({ "claim": "A claim", "evidence": [] }))).await;
+
+    for (path, uri) in [
+        ("/receipts", "/receipts?limit=10"),
+        ("/receipts/export", "/receipts/export"),
+        ("/stats", "/stats"),
+        ("/webhooks/status", "/webhooks/status"),
+        ("/info", "/info"),
+        ("/health", "/health"),
+    ] {
+        let (status, content_type, body) = call(&app, get(uri)).await;
+        assert_eq!(status, StatusCode::OK, "{}", uri);
+        assert_conforms(&spec, "get", path, status, &content_type, &body);
+    }
+}
+
+#[tokio::test]
+async fn error_responses_conform() {
+    let app = build_router(PortalConfig::default());
+    let spec = spec(&app).await;
+
+    let (status, content_type, body) = call(&app, post_json("/verify", json!({ "claim": 1 }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_conforms(&spec, "post", "/verify", status, &content_type, &body);
+
+    let (status, content_type, body) = call(&app, post_json("/verify-receipt", json!({ "unknown": true }))).await;
+    assert_eq!(status, StatusCode::UNPROCESSABLE_ENTITY);
+    assert_conforms(&spec, "post", "/verify-receipt", status, &content_type, &body);
+
+    // Rate limiting only applies to non-loopback peers
+    let limited = build_router(PortalConfig {
+        rate_limit: RateLimiter::new(1),
+        ..PortalConfig::default()
+    })
+    .layer(MockConnectInfo(SocketAddr::from(([203, 0, 113, 9], 40000))));
+    assert_eq!(call(&limited, get("/stats")).await.0, StatusCode::OK);
+    let (status, content_type, body) = call(&limited, get("/stats")).await;
+    assert_eq!(status, StatusCode::TOO_MANY_REQUESTS);
+    assert_conforms(&spec, "get", "/stats", status, &content_type, &body);
+}
+
+#[tokio::test(flavor = "multi_thread")]
+async fn typed_client_round_trips_against_a_live_portal() {
+    let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    let app = build_router(PortalConfig::default());
+    tokio::spawn(async move {
+        axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await.unwrap();
+    });
+
+    tokio::task::spawn_blocking(move || {
+        let client = portal_client::PortalClient::new(format!("http://{}", addr)).unwrap();
+
+        let verdict = client.verify("A claim", &["A claim".to_string()]).unwrap();
+        assert!(verdict.c_zero);
+        assert!(!verdict.cached);
+
+        let receipt = client.get_receipt(&verdict.hash).unwrap().unwrap();
+        assert_eq!(receipt.claim, "A claim");
+        assert_eq!(receipt.signature, verdict.signature);
+        assert!(client.get_receipt("missing").unwrap().is_none());
+
+        let stats = client.stats().unwrap();
+        assert_eq!(stats.total_verifications, 1);
+        assert_eq!(stats.verified_count, 1);
+    })
+    .await
+    .unwrap();
+}
@@ -0,0 +1,171 @@
+//! The portal router mounted in a host application, driven in process
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use async_trait::async_trait;
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::response::Response;
+use axum::Router;
+use portal::backend::CoSignedReceipt;
+use portal::listing::{ReceiptFilter, ReceiptListQuery, ReceiptPage};
+use portal::receipt_check::hmac_sign;
+use portal::store;
+use portal::{
+    build_router, MemoryStore, PortalConfig, RateLimiter, ReceiptStore, StoreError, StoredReceipt, VerifyResponse,
+};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+fn host_sign(hash: &str) -> String {
+    hmac_sign(b"host-app secret", hash)
+}
+
+/// A store whose backing database is down
+struct DownStore;
+
+#[async_trait]
+impl ReceiptStore for DownStore {
+    async fn insert(&self, _: StoredReceipt) -> store::Result<()> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+
+    async fn get(&self, _: &str) -> store::Result<Option<StoredReceipt>> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+
+    async fn page(&self, _: &ReceiptListQuery) -> store::Result<ReceiptPage> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+
+    async fn export(&self, _: &ReceiptFilter) -> store::Result<Vec<StoredReceipt>> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+
+    async fn insert_audit(&self, _: CoSignedReceipt) -> store::Result<()> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+
+    async fn get_audit(&self, _: &str) -> store::Result<Option<CoSignedReceipt>> {
+        Err(StoreError::Unavailable("database offline".to_string()))
+    }
+}
+
+async fn send(app: &Router, request: Request<Body>) -> Response {
+    app.clone().oneshot(request).await.unwrap()
+}
+
+fn post_json(uri: &str, body: serde_json::Value) -> Request<Body> {
+    Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+fn get(uri: &str) -> Request<Body> {
+    Request::get(uri).body(Body::empty()).unwrap()
+}
+
+async fn json<T: serde::de::DeserializeOwned>(response: Response) -> T {
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    serde_json::from_slice(&bytes).unwrap()
+}
+
+fn claim() -> serde_json::Value {
+    serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] })
+}
+
+#[tokio::test]
+async fn nested_portal_uses_injected_store_and_signer() {
+    let store = Arc::new(MemoryStore::new());
+    let portal = build_router(PortalConfig {
+        store: store.clone(),
+        signer: host_sign,
+        ..PortalConfig::default()
+    });
+    let app = Router::new().nest("/portal", portal);
+
+    let response = send(&app, post_json("/portal/verify", claim())).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let verified: VerifyResponse = json(response).await;
+    assert!(verified.c_zero);
+    assert_eq!(verified.signature, host_sign(&verified.hash));
+
+    // The host sees the receipt through its own handle
+    let stored = store.get(&verified.hash).await.unwrap().unwrap();
+    assert_eq!(stored.signature, verified.signature);
+
+    let response = send(&app, get(&format!("/portal/receipt/{}", verified.hash))).await;
+    assert_eq!(response.status(), StatusCode::OK);
+    let fetched: StoredReceipt = json(response).await;
+    assert_eq!(fetched.hash, verified.hash);
+
+    let response = send(&app, get("/portal/health")).await;
+    assert_eq!(response.status(), StatusCode::OK);
+}
+
+#[tokio::test]
+async fn store_failure_is_service_unavailable() {
+    let app = build_router(PortalConfig {
+        store: Arc::new(DownStore),
+        ..PortalConfig::default()
+    });
+
+    for request in [post_json("/verify", claim()), post_json("/audit", claim()), get("/receipts"), get("/receipt/h")] {
+        let response = send(&app, request).await;
+        assert_eq!(response.status(), StatusCode::SERVICE_UNAVAILABLE);
+    }
+}
+
+#[tokio::test]
+async fn router_without_connect_info_is_not_rate_limited() {
+    let app = build_router(PortalConfig {
+        rate_limit: RateLimiter::new(1),
+        ..PortalConfig::default()
+    });
+
+    for _ in 0..3 {
+        let response = send(&app, post_json("/verify", claim())).await;
+        assert_eq!(response.status(), StatusCode::OK);
+    }
+}
+
+#[tokio::test]
+async fn cors_is_optional() {
+    let preflight = || {
+        Request::options("/verify")
+            .header(header::ORIGIN, "https://host.example")
+            .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+            .body(Body::empty())
+            .unwrap()
+    };
+
+    let app = build_router(PortalConfig::default());
+    let response = send(&app, preflight()).await;
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "*");
+
+    let app = build_router(PortalConfig {
+        cors: None,
+        ..PortalConfig::default()
+    });
+    let response = send(&app, preflight()).await;
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+    let app = build_router(PortalConfig {
+        cors: Some(portal::cors_for_origins(&["https://host.example".to_string()]).unwrap()),
+        ..PortalConfig::default()
+    });
+    let response = send(&app, preflight()).await;
+    assert_eq!(response.headers()[header::ACCESS_CONTROL_ALLOW_ORIGIN], "https://host.example");
+    let other = Request::options("/verify")
+        .header(header::ORIGIN, "https://elsewhere.example")
+        .header(header::ACCESS_CONTROL_REQUEST_METHOD, "POST")
+        .body(Body::empty())
+        .unwrap();
+    let response = send(&app, other).await;
+    assert!(response.headers().get(header::ACCESS_CONTROL_ALLOW_ORIGIN).is_none());
+
+    for origins in [vec![], vec!["*".to_string()], vec!["https://host.example/".to_string()]] {
+        assert!(portal::cors_for_origins(&origins).is_err(), "{:?}", origins);
+    }
+}
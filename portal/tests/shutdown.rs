@@ -0,0 +1,141 @@
+//! The served portal stopping: in-flight requests drain, then the store flushes
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use async_trait::async_trait;
+use portal::backend::CoSignedReceipt;
+use portal::listing::{ReceiptFilter, ReceiptListQuery, ReceiptPage};
+use portal::server::{serve, ServeError, ServeOptions};
+use portal::store;
+use portal::{MemoryStore, PortalConfig, ReceiptStore, StoredReceipt};
+use std::net::{SocketAddr, TcpListener};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::{oneshot, Notify};
+use tokio::task::JoinHandle;
+
+/// A memory store whose inserts take `delay` and which records flushes
+struct SlowStore {
+    inner: MemoryStore,
+    delay: Duration,
+    inserting: Notify,
+    flushed: AtomicBool,
+}
+
+impl SlowStore {
+    fn new(delay: Duration) -> Arc<Self> {
+        Arc::new(Self {
+            inner: MemoryStore::new(),
+            delay,
+            inserting: Notify::new(),
+            flushed: AtomicBool::new(false),
+        })
+    }
+}
+
+#[async_trait]
+impl ReceiptStore for SlowStore {
+    async fn insert(&self, receipt: StoredReceipt) -> store::Result<()> {
+        self.inserting.notify_one();
+        tokio::time::sleep(self.delay).await;
+        self.inner.insert(receipt).await
+    }
+
+    async fn get(&self, hash: &str) -> store::Result<Option<StoredReceipt>> {
+        self.inner.get(hash).await
+    }
+
+    async fn page(&self, query: &ReceiptListQuery) -> store::Result<ReceiptPage> {
+        self.inner.page(query).await
+    }
+
+    async fn export(&self, filter: &ReceiptFilter) -> store::Result<Vec<StoredReceipt>> {
+        self.inner.export(filter).await
+    }
+
+    async fn insert_audit(&self, receipt: CoSignedReceipt) -> store::Result<()> {
+        self.inner.insert_audit(receipt).await
+    }
+
+    async fn get_audit(&self, receipt_hash: &str) -> store::Result<Option<CoSignedReceipt>> {
+        self.inner.get_audit(receipt_hash).await
+    }
+
+    async fn flush(&self) -> store::Result<()> {
+        self.flushed.store(true, Ordering::SeqCst);
+        Ok(())
+    }
+}
+
+/// Serve a portal over `store` on a free local port until the sender fires
+fn start(
+    store: Arc<SlowStore>,
+    drain_timeout: Duration,
+) -> (SocketAddr, oneshot::Sender<()>, JoinHandle<Result<(), ServeError>>) {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let addr = listener.local_addr().unwrap();
+    let (stop, stopped) = oneshot::channel::<()>();
+    let config = PortalConfig {
+        store,
+        ..PortalConfig::default()
+    };
+    let options = ServeOptions {
+        drain_timeout,
+        ..ServeOptions::default()
+    };
+    let server = tokio::spawn(serve(listener, config, options, async {
+        let _ = stopped.await;
+    }));
+    (addr, stop, server)
+}
+
+fn verify(addr: SocketAddr) -> JoinHandle<reqwest::Result<reqwest::StatusCode>> {
+    tokio::spawn(async move {
+        let response = reqwest::Client::new()
+            .post(format!("http://{}/verify", addr))
+            .json(&serde_json::json!({ "claim": "A claim", "evidence": ["A claim"] }))
+            .send()
+            .await?;
+        Ok(response.status())
+    })
+}
+
+#[tokio::test]
+async fn in_flight_requests_finish_before_the_store_flushes() {
+    let store = SlowStore::new(Duration::from_millis(300));
+    let (addr, stop, server) = start(store.clone(), Duration::from_secs(10));
+
+    let request = verify(addr);
+    store.inserting.notified().await;
+    stop.send(()).unwrap();
+
+    assert_eq!(request.await.unwrap().unwrap(), reqwest::StatusCode::OK);
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server stops once drained")
+        .unwrap()
+        .unwrap();
+    assert!(store.flushed.load(Ordering::SeqCst));
+
+    // No longer accepting
+    assert!(verify(addr).await.unwrap().is_err());
+}
+
+#[tokio::test]
+async fn drain_timeout_bounds_shutdown() {
+    let store = SlowStore::new(Duration::from_secs(60));
+    let (addr, stop, server) = start(store.clone(), Duration::from_millis(100));
+
+    let request = verify(addr);
+    store.inserting.notified().await;
+    stop.send(()).unwrap();
+
+    tokio::time::timeout(Duration::from_secs(5), server)
+        .await
+        .expect("server stops at the drain timeout")
+        .unwrap()
+        .unwrap();
+    assert!(store.flushed.load(Ordering::SeqCst));
+    assert!(request.await.unwrap().is_err());
+}
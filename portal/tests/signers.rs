@@ -0,0 +1,83 @@
+//! Receipts from the repo's own signers verified by `POST /verify-receipt`
+//!
+//! The CLI receipt was written by `sap4d-cli prove` under
+//! `fixtures/cli-signing.key`; the audit receipt comes from a live auditd
+//! router. The portal trusts both through `PORTAL_TRUST_ANCHORS` entries
+//! named by the key ids the receipts record.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::http::{router, AuditdState, KeySigner};
+use axiom_audit::service::AuditResponse;
+use axum::body::Body;
+use axum::http::{header, Request, StatusCode};
+use axum::Router;
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use portal::receipt_check::{ReceiptCheck, TrustAnchors};
+use portal::{build_router, PortalConfig};
+use sap4d::signing::ReceiptSigner;
+use serde_json::{json, Value};
+use std::sync::Arc;
+use tower::ServiceExt;
+
+const CLI_KEY: &str = include_str!("fixtures/cli-signing.key");
+const CLI_RECEIPT: &str = include_str!("fixtures/cli_receipt.json");
+const AUDITD_KEY: &str = "auditd signing key";
+
+async fn post(app: &Router, uri: &str, body: &Value) -> (StatusCode, Vec<u8>) {
+    let request = Request::post(uri)
+        .header(header::CONTENT_TYPE, "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap();
+    let response = app.clone().oneshot(request).await.unwrap();
+    let status = response.status();
+    let bytes = axum::body::to_bytes(response.into_body(), usize::MAX).await.unwrap();
+    (status, bytes.to_vec())
+}
+
+/// `PORTAL_TRUST_ANCHORS` entry for an HMAC key, named by its key id
+fn anchor_entry(key: &str) -> String {
+    format!("{}=hmac:{}", KeySigner::new(key).key_id(), STANDARD.encode(key))
+}
+
+async fn auditd_receipt() -> Value {
+    let auditd = router(Arc::new(AuditdState::new(Default::default(), KeySigner::new(AUDITD_KEY))));
+    let request = json!({ "claim": "Water boils", "evidence": ["Water boils at 100C"] });
+    let (status, body) = post(&auditd, "/audit", &request).await;
+    assert_eq!(status, StatusCode::OK);
+    let response: AuditResponse = serde_json::from_slice(&body).unwrap();
+    serde_json::to_value(response.receipt.expect("auditd returns the receipt")).unwrap()
+}
+
+async fn check(portal: &Router, receipt: &Value) -> ReceiptCheck {
+    let (status, body) = post(portal, "/verify-receipt", receipt).await;
+    assert_eq!(status, StatusCode::OK);
+    serde_json::from_slice(&body).unwrap()
+}
+
+#[tokio::test]
+async fn cli_and_auditd_receipts_verify_against_configured_anchors() {
+    // The key file's trailing newline is not part of the key
+    let entries = format!("{},{}", anchor_entry(CLI_KEY.trim_end()), anchor_entry(AUDITD_KEY));
+    let trust = TrustAnchors::parse(&entries).unwrap();
+    let portal = build_router(PortalConfig {
+        trust,
+        ..PortalConfig::default()
+    });
+
+    let cli_receipt: Value = serde_json::from_str(CLI_RECEIPT).unwrap();
+    let audit_receipt = auditd_receipt().await;
+    for (receipt, key) in [(&cli_receipt, CLI_KEY.trim_end()), (&audit_receipt, AUDITD_KEY)] {
+        let check = check(&portal, receipt).await;
+        assert_eq!(check.status, "VERIFIED", "{:?}", check);
+        assert_eq!(check.details["signer"], KeySigner::new(key).key_id());
+    }
+
+    // Without the anchors the same receipts do not verify
+    let untrusting = build_router(PortalConfig::default());
+    for receipt in [&cli_receipt, &audit_receipt] {
+        let check = check(&untrusting, receipt).await;
+        assert_eq!(check.status, "NOT_VERIFIED");
+        assert_eq!(check.details["signature_ok"], false);
+    }
+}
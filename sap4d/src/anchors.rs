@@ -0,0 +1,174 @@
+//! Trust anchors: the keys signatures are verified against
+//!
+//! An anchor is a key id and the key that checks its signatures: an
+//! HMAC-SHA256 secret (as held by [`crate::signing::KeySigner`]) or an
+//! Ed25519 public key. Signatures are base64 over the receipt hash either
+//! way. Anchors are configured as entries of the form
+//!
+//! ```text
+//! sha256:445e9a3f7eb864de=hmac:<base64 secret>,did:key:system=ed25519:<base64 public key>
+//! ```
+//!
+//! separated by commas or newlines, as in the portal's
+//! `PORTAL_TRUST_ANCHORS`.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use hmac::{Hmac, Mac};
+use ring::signature::{UnparsedPublicKey, ED25519};
+use sha2::Sha256;
+use std::fmt;
+use thiserror::Error;
+
+/// Why a trust anchor entry was rejected
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AnchorError {
+    #[error(
+        "trust anchor entries must be name=hmac:<base64 secret> \
+         or name=ed25519:<base64 public key>, got '{0}'"
+    )]
+    Malformed(String),
+
+    #[error("'{0}' is already a trust anchor")]
+    Duplicate(String),
+}
+
+/// Key that checks an anchor's signatures
+#[derive(Clone, PartialEq, Eq)]
+pub enum AnchorKey {
+    /// Shared secret: signatures are base64 HMAC-SHA256 over the hash
+    Hmac(Vec<u8>),
+    /// Public key: signatures are base64 Ed25519 over the hash
+    Ed25519(Vec<u8>),
+}
+
+impl fmt::Debug for AnchorKey {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Hmac(_) => f.write_str("Hmac(<secret>)"),
+            Self::Ed25519(public_key) => f.debug_tuple("Ed25519").field(&STANDARD.encode(public_key)).finish(),
+        }
+    }
+}
+
+/// A signer whose signatures are trusted, under its key id
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TrustAnchor {
+    pub name: String,
+    pub key: AnchorKey,
+}
+
+impl TrustAnchor {
+    pub fn hmac(name: impl Into<String>, secret: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            key: AnchorKey::Hmac(secret.into()),
+        }
+    }
+
+    pub fn ed25519(name: impl Into<String>, public_key: impl Into<Vec<u8>>) -> Self {
+        Self {
+            name: name.into(),
+            key: AnchorKey::Ed25519(public_key.into()),
+        }
+    }
+
+    /// Parse one `name=hmac:<base64>` or `name=ed25519:<base64>` entry
+    pub fn parse(entry: &str) -> Result<Self, AnchorError> {
+        let malformed = || AnchorError::Malformed(entry.to_string());
+        let (name, key) = entry
+            .split_once('=')
+            .filter(|(name, _)| !name.is_empty())
+            .ok_or_else(malformed)?;
+        let (scheme, encoded) = key.split_once(':').ok_or_else(malformed)?;
+        let key = STANDARD.decode(encoded).map_err(|_| malformed())?;
+        match scheme {
+            "hmac" if !key.is_empty() => Ok(Self::hmac(name, key)),
+            "ed25519" if key.len() == 32 => Ok(Self::ed25519(name, key)),
+            _ => Err(malformed()),
+        }
+    }
+
+    /// Whether `signature` is this anchor's signature over `hash`
+    pub fn verify(&self, hash: &str, signature: &str) -> bool {
+        match &self.key {
+            AnchorKey::Hmac(secret) => hmac_verify(secret, hash, signature),
+            AnchorKey::Ed25519(public_key) => STANDARD.decode(signature).is_ok_and(|signature| {
+                UnparsedPublicKey::new(&ED25519, public_key)
+                    .verify(hash.as_bytes(), &signature)
+                    .is_ok()
+            }),
+        }
+    }
+
+    /// Whether this anchor holds the Ed25519 public key `public_key`
+    pub fn holds_ed25519(&self, public_key: &[u8]) -> bool {
+        matches!(&self.key, AnchorKey::Ed25519(own) if own == public_key)
+    }
+}
+
+/// Parse comma- or newline-separated anchor entries, rejecting repeated names
+pub fn parse_anchors(value: &str) -> Result<Vec<TrustAnchor>, AnchorError> {
+    let mut anchors: Vec<TrustAnchor> = Vec::new();
+    for entry in value.split([',', '\n']).map(str::trim).filter(|entry| !entry.is_empty()) {
+        let anchor = TrustAnchor::parse(entry)?;
+        if anchors.iter().any(|known| known.name == anchor.name) {
+            return Err(AnchorError::Duplicate(anchor.name));
+        }
+        anchors.push(anchor);
+    }
+    Ok(anchors)
+}
+
+fn hmac_mac(secret: &[u8], hash: &str) -> Hmac<Sha256> {
+    let mut mac = Hmac::<Sha256>::new_from_slice(secret).expect("HMAC accepts keys of any length");
+    mac.update(hash.as_bytes());
+    mac
+}
+
+/// Signature an [`AnchorKey::Hmac`] anchor holding `secret` accepts over `hash`
+pub fn hmac_sign(secret: &[u8], hash: &str) -> String {
+    STANDARD.encode(hmac_mac(secret, hash).finalize().into_bytes())
+}
+
+/// Whether `signature` is the base64 HMAC-SHA256 of `hash` under `secret`
+pub(crate) fn hmac_verify(secret: &[u8], hash: &str, signature: &str) -> bool {
+    STANDARD
+        .decode(signature)
+        .is_ok_and(|signature| hmac_mac(secret, hash).verify_slice(&signature).is_ok())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_anchor_entries() {
+        let entries = format!(
+            "cli=hmac:{}\n system=ed25519:{} ,",
+            STANDARD.encode("cli-secret"),
+            STANDARD.encode([7; 32])
+        );
+        let anchors = parse_anchors(&entries).unwrap();
+        assert_eq!(anchors, [TrustAnchor::hmac("cli", "cli-secret"), TrustAnchor::ed25519("system", [7; 32])]);
+        assert!(anchors[0].verify("abc", &hmac_sign(b"cli-secret", "abc")));
+        assert!(!anchors[0].verify("abc", &hmac_sign(b"cli", "abc")));
+        assert!(anchors[1].holds_ed25519(&[7; 32]));
+
+        for bad in ["no-key", "=hmac:c2VjcmV0", "cli=SAP4D_CLI_SIG:", "cli=hmac:", "cli=ed25519:c2hvcnQ="] {
+            assert_eq!(parse_anchors(bad), Err(AnchorError::Malformed(bad.to_string())), "{}", bad);
+        }
+        assert_eq!(
+            parse_anchors("a=hmac:cw==,a=hmac:dA=="),
+            Err(AnchorError::Duplicate("a".to_string()))
+        );
+        assert!(parse_anchors("").unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_secrets_stay_out_of_debug_output() {
+        let anchor = TrustAnchor::hmac("cli", "cli-secret");
+        assert!(!format!("{:?}", anchor).contains("cli-secret"));
+    }
+}
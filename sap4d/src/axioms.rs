@@ -1,10 +1,206 @@
 //! Axiom definitions and the Ω-SSOT (Omega Single Source of Truth)
 //!
+//! Besides the fundamental axioms, domain axiom packs can be loaded from a
+//! TOML or JSON manifest:
+//!
+//! ```toml
+//! version = "1.0.0"            # optional
+//! hash = "<axiom set hash>"    # optional; checked against the axioms below
+//!
+//! [[axioms]]
+//! id = "FIN_1_DOUBLE_ENTRY"
+//! name = "Double Entry"
+//! statement = "Every debit has an equal and opposite credit"
+//! domain = "finance"
+//!
+//! [[axioms]]
+//! id = "FIN_2_TRANSFER_LIMIT"
+//! name = "Transfer Limit"
+//! statement = "No single transfer exceeds 10000"
+//! domain = "finance"
+//! violation_rule = { kind = "numeric", pattern = 'transfer of (?P<value>[\d,.]+)', op = "<=", limit = 10000 }
+//! ```
+//!
+//! An axiom with a [`ViolationRule`] is checked against statements: a
+//! `regex` rule is violated when its pattern matches, a `forbidden` rule
+//! when any listed substring occurs (ignoring case), and a `numeric` rule
+//! when a number captured as `value` fails `value <op> limit`. Rules are
+//! validated when built or deserialized, so an invalid pattern fails the
+//! pack load rather than the first check.
+//!
+//! Ω-SSOT versions are compared, frozen and kept in order in [`history`].
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
+use crate::{ProofError, Result};
+use regex::Regex;
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
-use std::collections::HashMap;
+use std::collections::BTreeMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+pub mod history;
+
+/// Comparison a numeric rule requires of the captured value
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Comparison {
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = ">")]
+    Gt,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+}
+
+impl Comparison {
+    /// Whether `value <self> limit` holds
+    pub fn holds(self, value: f64, limit: f64) -> bool {
+        match self {
+            Comparison::Lt => value < limit,
+            Comparison::Le => value <= limit,
+            Comparison::Gt => value > limit,
+            Comparison::Ge => value >= limit,
+            Comparison::Eq => value == limit,
+            Comparison::Ne => value != limit,
+        }
+    }
+}
+
+/// A violation rule as written in an axiom manifest
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "snake_case", deny_unknown_fields)]
+pub enum RuleSpec {
+    /// Violated when `pattern` matches the statement
+    Regex { pattern: String },
+    /// Violated when any substring occurs in the statement, ignoring case
+    Forbidden { substrings: Vec<String> },
+    /// Violated when a number captured by the `value` group fails `value <op> limit`
+    Numeric { pattern: String, op: Comparison, limit: f64 },
+}
+
+/// A validated, compiled violation rule
+///
+/// Serializes as its [`RuleSpec`]; deserializing validates the spec.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "RuleSpec", into = "RuleSpec")]
+pub struct ViolationRule {
+    spec: RuleSpec,
+    regex: Option<Regex>,
+    /// Canonical JSON of the spec; equality, hashing and the axiom hash use it
+    canonical: String,
+}
+
+impl ViolationRule {
+    /// Violated when `pattern` matches
+    pub fn regex(pattern: impl Into<String>) -> Result<Self> {
+        Self::try_from(RuleSpec::Regex { pattern: pattern.into() })
+    }
+    
+    /// Violated when any of `substrings` occurs, ignoring case
+    pub fn forbidden<S: Into<String>>(substrings: impl IntoIterator<Item = S>) -> Result<Self> {
+        Self::try_from(RuleSpec::Forbidden {
+            substrings: substrings.into_iter().map(Into::into).collect(),
+        })
+    }
+    
+    /// Violated when a number captured as `value` by `pattern` fails `value <op> limit`
+    pub fn numeric(pattern: impl Into<String>, op: Comparison, limit: f64) -> Result<Self> {
+        Self::try_from(RuleSpec::Numeric { pattern: pattern.into(), op, limit })
+    }
+    
+    /// The rule as written
+    pub fn spec(&self) -> &RuleSpec {
+        &self.spec
+    }
+    
+    /// Whether `statement` violates the rule
+    pub fn is_violated_by(&self, statement: &str) -> bool {
+        match (&self.spec, &self.regex) {
+            (RuleSpec::Regex { .. }, Some(regex)) => regex.is_match(statement),
+            (RuleSpec::Forbidden { substrings }, _) => {
+                let statement = statement.to_lowercase();
+                substrings.iter().any(|s| statement.contains(&s.to_lowercase()))
+            }
+            (RuleSpec::Numeric { op, limit, .. }, Some(regex)) => regex
+                .captures_iter(statement)
+                .filter_map(|caps| parse_number(caps.name("value")?.as_str()))
+                .any(|value| !op.holds(value, *limit)),
+            _ => false,
+        }
+    }
+}
+
+/// Parse a captured number, allowing `,` and `_` digit separators
+fn parse_number(text: &str) -> Option<f64> {
+    let digits: String = text.chars().filter(|c| *c != ',' && *c != '_').collect();
+    digits.parse().ok().filter(|v: &f64| v.is_finite())
+}
+
+fn compile(pattern: &str) -> Result<Regex> {
+    Regex::new(pattern)
+        .map_err(|e| ProofError::AxiomViolation(format!("invalid violation rule pattern '{}': {}", pattern, e)))
+}
+
+impl TryFrom<RuleSpec> for ViolationRule {
+    type Error = ProofError;
+    
+    fn try_from(spec: RuleSpec) -> Result<Self> {
+        let regex = match &spec {
+            RuleSpec::Regex { pattern } => Some(compile(pattern)?),
+            RuleSpec::Forbidden { substrings } => {
+                if substrings.is_empty() || substrings.iter().any(|s| s.trim().is_empty()) {
+                    return Err(ProofError::AxiomViolation(
+                        "forbidden rule needs at least one non-empty substring".to_string(),
+                    ));
+                }
+                None
+            }
+            RuleSpec::Numeric { pattern, limit, .. } => {
+                let regex = compile(pattern)?;
+                if !regex.capture_names().flatten().any(|name| name == "value") {
+                    return Err(ProofError::AxiomViolation(format!(
+                        "numeric rule pattern '{}' has no (?P<value>...) group",
+                        pattern
+                    )));
+                }
+                if !limit.is_finite() {
+                    return Err(ProofError::AxiomViolation(format!("numeric rule limit {} is not finite", limit)));
+                }
+                Some(regex)
+            }
+        };
+        let canonical = serde_json::to_string(&spec)
+            .map_err(|e| ProofError::Internal(format!("Failed to encode violation rule: {}", e)))?;
+        Ok(Self { spec, regex, canonical })
+    }
+}
+
+impl From<ViolationRule> for RuleSpec {
+    fn from(rule: ViolationRule) -> Self {
+        rule.spec
+    }
+}
+
+impl PartialEq for ViolationRule {
+    fn eq(&self, other: &Self) -> bool {
+        self.canonical == other.canonical
+    }
+}
+
+impl Eq for ViolationRule {}
+
+impl Hash for ViolationRule {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.canonical.hash(state);
+    }
+}
 
 /// A single axiom in the system
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, Hash)]
@@ -17,7 +213,10 @@ pub struct Axiom {
     pub statement: String,
     /// Domain this axiom applies to
     pub domain: String,
-    /// Hash of the axiom content for integrity verification
+    /// Rule statements are checked against; without one the axiom is descriptive
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub violation_rule: Option<ViolationRule>,
+    /// Hash of the axiom content (and rule, if any) for integrity verification
     pub hash: String,
 }
 
@@ -29,29 +228,53 @@ impl Axiom {
         let statement = statement.into();
         let domain = domain.into();
         
-        let hash = Self::compute_hash(&id, &name, &statement, &domain);
+        let hash = Self::compute_hash(&id, &name, &statement, &domain, None);
         
         Self {
             id,
             name,
             statement,
             domain,
+            violation_rule: None,
             hash,
         }
     }
     
-    fn compute_hash(id: &str, name: &str, statement: &str, domain: &str) -> String {
+    /// Attach a violation rule (rehashing the axiom)
+    pub fn with_violation_rule(mut self, rule: ViolationRule) -> Self {
+        self.hash = Self::compute_hash(&self.id, &self.name, &self.statement, &self.domain, Some(&rule));
+        self.violation_rule = Some(rule);
+        self
+    }
+    
+    /// Whether `statement` violates this axiom's rule (never, without one)
+    pub fn is_violated_by(&self, statement: &str) -> bool {
+        self.violation_rule.as_ref().is_some_and(|rule| rule.is_violated_by(statement))
+    }
+    
+    /// Axioms without a rule hash exactly as they did before rules existed
+    fn compute_hash(id: &str, name: &str, statement: &str, domain: &str, rule: Option<&ViolationRule>) -> String {
         let mut hasher = Sha256::new();
         hasher.update(id.as_bytes());
         hasher.update(name.as_bytes());
         hasher.update(statement.as_bytes());
         hasher.update(domain.as_bytes());
+        if let Some(rule) = rule {
+            hasher.update(b"\0violation_rule:");
+            hasher.update(rule.canonical.as_bytes());
+        }
         hex::encode(hasher.finalize())
     }
     
     /// Verify the axiom's integrity
     pub fn verify_integrity(&self) -> bool {
-        let computed = Self::compute_hash(&self.id, &self.name, &self.statement, &self.domain);
+        let computed = Self::compute_hash(
+            &self.id,
+            &self.name,
+            &self.statement,
+            &self.domain,
+            self.violation_rule.as_ref(),
+        );
         computed == self.hash
     }
 }
@@ -59,7 +282,8 @@ impl Axiom {
 /// A collection of axioms
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AxiomSet {
-    axioms: HashMap<String, Axiom>,
+    /// Keyed by id; ordered so iteration (and every hash over it) is stable
+    axioms: BTreeMap<String, Axiom>,
     /// Hash of the entire axiom set
     set_hash: String,
 }
@@ -68,7 +292,7 @@ impl AxiomSet {
     /// Create a new empty axiom set
     pub fn new() -> Self {
         Self {
-            axioms: HashMap::new(),
+            axioms: BTreeMap::new(),
             set_hash: String::new(),
         }
     }
@@ -113,13 +337,9 @@ impl AxiomSet {
     
     fn recompute_hash(&mut self) {
         let mut hasher = Sha256::new();
-        let mut ids: Vec<_> = self.axioms.keys().collect();
-        ids.sort();
         
-        for id in ids {
-            if let Some(axiom) = self.axioms.get(id) {
-                hasher.update(axiom.hash.as_bytes());
-            }
+        for axiom in self.axioms.values() {
+            hasher.update(axiom.hash.as_bytes());
         }
         
         self.set_hash = hex::encode(hasher.finalize());
@@ -134,6 +354,13 @@ impl AxiomSet {
     pub fn verify_integrity(&self) -> bool {
         self.axioms.values().all(|a| a.verify_integrity())
     }
+    
+    /// Every axiom whose violation rule `statement` breaks, in id order
+    pub fn check_violation(&self, statement: &str) -> Vec<&Axiom> {
+        self.axioms.values()
+            .filter(|a| a.is_violated_by(statement))
+            .collect()
+    }
 }
 
 impl Default for AxiomSet {
@@ -142,6 +369,107 @@ impl Default for AxiomSet {
     }
 }
 
+/// One axiom as written in a manifest
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AxiomEntry {
+    pub id: String,
+    pub name: String,
+    pub statement: String,
+    pub domain: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub violation_rule: Option<ViolationRule>,
+}
+
+/// Axiom manifest file (TOML or JSON, chosen by extension)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct AxiomManifest {
+    /// Version of the axiom pack
+    #[serde(default)]
+    pub version: Option<String>,
+    /// Declared hash of the axioms ([`AxiomSet::hash`] over the entries)
+    #[serde(default)]
+    pub hash: Option<String>,
+    /// Axioms in the pack
+    #[serde(default)]
+    pub axioms: Vec<AxiomEntry>,
+}
+
+impl AxiomManifest {
+    /// Read, parse and validate a manifest
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| ProofError::Internal(format!("Failed to read {}: {}", path.display(), e)))?;
+
+        let manifest: Self = match path.extension().and_then(|e| e.to_str()) {
+            Some("toml") => toml::from_str(&text).map_err(|e| {
+                ProofError::Internal(format!("Invalid axiom manifest {}: {}", path.display(), e))
+            })?,
+            Some("json") => serde_json::from_str(&text).map_err(|e| {
+                ProofError::Internal(format!("Invalid axiom manifest {}: {}", path.display(), e))
+            })?,
+            _ => {
+                return Err(ProofError::Internal(format!(
+                    "Axiom manifest {} must have a .toml or .json extension",
+                    path.display()
+                )))
+            }
+        };
+
+        manifest.validate(&path.display().to_string())?;
+        Ok(manifest)
+    }
+
+    /// Check ids are unique, statements non-empty and the declared hash matches
+    pub fn validate(&self, origin: &str) -> Result<()> {
+        let set = self.axiom_set(origin)?;
+
+        if let Some(declared) = &self.hash {
+            if declared != set.hash() {
+                return Err(ProofError::AxiomViolation(format!(
+                    "{}: declared hash {} does not match contents ({})",
+                    origin,
+                    declared,
+                    set.hash()
+                )));
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Axioms in the manifest as a set (rejects duplicate ids and empty fields)
+    pub fn axiom_set(&self, origin: &str) -> Result<AxiomSet> {
+        let mut set = AxiomSet::new();
+
+        for entry in &self.axioms {
+            if entry.id.trim().is_empty() {
+                return Err(ProofError::AxiomViolation(format!("{}: axiom with empty id", origin)));
+            }
+            if entry.statement.trim().is_empty() {
+                return Err(ProofError::AxiomViolation(format!(
+                    "{}: axiom '{}' has an empty statement",
+                    origin, entry.id
+                )));
+            }
+            if set.contains(&entry.id) {
+                return Err(ProofError::AxiomViolation(format!(
+                    "{}: duplicate axiom id '{}'",
+                    origin, entry.id
+                )));
+            }
+            let mut axiom = Axiom::new(&entry.id, &entry.name, &entry.statement, &entry.domain);
+            if let Some(rule) = &entry.violation_rule {
+                axiom = axiom.with_violation_rule(rule.clone());
+            }
+            set.add(axiom);
+        }
+
+        Ok(set)
+    }
+}
+
 /// Ω-SSOT: The Single Source of Truth for the Axiom Hive system
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OmegaSSoT {
@@ -164,7 +492,7 @@ impl OmegaSSoT {
             core_axioms: AxiomSet::new(),
             version: "1.0.0".to_string(),
             substrate: crate::SUBSTRATE.to_string(),
-            created_at: chrono::Utc::now().to_rfc3339(),
+            created_at: crate::clock::now().to_rfc3339(),
             omega_hash: String::new(),
         };
         
@@ -175,6 +503,46 @@ impl OmegaSSoT {
         ssot
     }
     
+    /// Create an Ω-SSOT holding only the axioms in a manifest file
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref();
+        let manifest = AxiomManifest::load(path)?;
+        
+        let mut ssot = Self {
+            core_axioms: manifest.axiom_set(&path.display().to_string())?,
+            version: manifest.version.unwrap_or_else(|| "1.0.0".to_string()),
+            substrate: crate::SUBSTRATE.to_string(),
+            created_at: crate::clock::now().to_rfc3339(),
+            omega_hash: String::new(),
+        };
+        ssot.recompute_hash();
+        
+        Ok(ssot)
+    }
+    
+    /// Add the axioms in a manifest file, returning how many were added
+    ///
+    /// Nothing is added if any id is already present.
+    pub fn extend_from_file(&mut self, path: impl AsRef<Path>) -> Result<usize> {
+        let path = path.as_ref();
+        let origin = path.display().to_string();
+        let pack = AxiomManifest::load(path)?.axiom_set(&origin)?;
+        
+        if let Some(existing) = pack.all().find(|a| self.core_axioms.contains(&a.id)) {
+            return Err(ProofError::AxiomViolation(format!(
+                "{}: duplicate axiom id '{}' (already defined)",
+                origin, existing.id
+            )));
+        }
+        
+        for axiom in pack.all() {
+            self.core_axioms.add(axiom.clone());
+        }
+        self.recompute_hash();
+        
+        Ok(pack.len())
+    }
+    
     fn add_fundamental_axioms(&mut self) {
         // Axiom 1: Law of Identity
         self.core_axioms.add(Axiom::new(
@@ -272,14 +640,22 @@ impl OmegaSSoT {
         computed == self.omega_hash
     }
     
-    /// Check if a statement violates any core axiom
-    pub fn check_violation(&self, statement: &str) -> Option<&Axiom> {
-        // Check for explicit contradictions
+    /// Every core axiom a statement violates
+    ///
+    /// An explicit contradiction violates `A2_NON_CONTRADICTION`; any other
+    /// axiom is violated when its [`ViolationRule`] matches.
+    pub fn check_violation(&self, statement: &str) -> Vec<&Axiom> {
+        let mut violated = self.core_axioms.check_violation(statement);
+        
         if statement.contains("P ∧ ¬P") || statement.contains("contradiction") {
-            return self.core_axioms.get("A2_NON_CONTRADICTION");
+            if let Some(axiom) = self.core_axioms.get("A2_NON_CONTRADICTION") {
+                if !violated.iter().any(|a| a.id == axiom.id) {
+                    violated.insert(0, axiom);
+                }
+            }
         }
         
-        None
+        violated
     }
 }
 
@@ -332,6 +708,198 @@ mod tests {
         assert!(ssot.verify_integrity());
     }
     
+    fn write_manifest(name: &str, contents: &str) -> std::path::PathBuf {
+        let path = std::env::temp_dir().join(format!("sap4d-axioms-{}-{}", std::process::id(), name));
+        std::fs::write(&path, contents).unwrap();
+        path
+    }
+    
+    const FINANCE_TOML: &str = r#"
+version = "2.1.0"
+
+[[axioms]]
+id = "FIN_1"
+name = "Double Entry"
+statement = "Every debit has an equal and opposite credit"
+domain = "finance"
+
+[[axioms]]
+id = "FIN_2"
+name = "Closed Periods"
+statement = "Closed periods are immutable"
+domain = "finance"
+"#;
+    
+    #[test]
+    fn test_load_toml_and_json_manifests() {
+        let toml_path = write_manifest("finance.toml", FINANCE_TOML);
+        let ssot = OmegaSSoT::from_file(&toml_path).unwrap();
+        assert_eq!(ssot.version, "2.1.0");
+        assert_eq!(ssot.core_axioms.len(), 2);
+        assert!(!ssot.core_axioms.contains("A1_IDENTITY"));
+        assert!(ssot.verify_integrity());
+        
+        let json = serde_json::json!({
+            "hash": ssot.core_axioms.hash(),
+            "axioms": [
+                { "id": "FIN_2", "name": "Closed Periods", "statement": "Closed periods are immutable", "domain": "finance" },
+                { "id": "FIN_1", "name": "Double Entry", "statement": "Every debit has an equal and opposite credit", "domain": "finance" }
+            ]
+        });
+        let json_path = write_manifest("finance.json", &json.to_string());
+        let from_json = OmegaSSoT::from_file(&json_path).unwrap();
+        assert_eq!(from_json.core_axioms.hash(), ssot.core_axioms.hash());
+        
+        let mut extended = OmegaSSoT::new();
+        let before = extended.hash().to_string();
+        assert_eq!(extended.extend_from_file(&toml_path).unwrap(), 2);
+        assert_eq!(extended.core_axioms.len(), 10);
+        assert_ne!(extended.hash(), before);
+        assert!(extended.verify_integrity());
+        
+        // Loading the same pack twice collides and leaves the set untouched
+        let err = extended.extend_from_file(&toml_path).unwrap_err();
+        assert!(err.to_string().contains("duplicate axiom id 'FIN_1'"));
+        assert_eq!(extended.core_axioms.len(), 10);
+        
+        let _ = std::fs::remove_file(toml_path);
+        let _ = std::fs::remove_file(json_path);
+    }
+    
+    #[test]
+    fn test_invalid_manifests_rejected() {
+        let cases = [
+            ("dup.toml", FINANCE_TOML.replace("FIN_2", "FIN_1"), "duplicate axiom id 'FIN_1'"),
+            ("empty.toml", FINANCE_TOML.replace("Closed periods are immutable", " "), "'FIN_2' has an empty statement"),
+            ("hash.toml", format!("hash = \"{}\"\n{}", "0".repeat(64), FINANCE_TOML), "does not match contents"),
+        ];
+        
+        for (name, contents, expected) in cases {
+            let path = write_manifest(name, &contents);
+            match OmegaSSoT::from_file(&path) {
+                Err(ProofError::AxiomViolation(message)) => assert!(message.contains(expected), "{}", message),
+                other => panic!("{}: expected axiom violation, got {:?}", name, other.map(|s| s.omega_hash)),
+            }
+            let _ = std::fs::remove_file(path);
+        }
+        
+        let path = write_manifest("pack.yaml", FINANCE_TOML);
+        assert!(matches!(OmegaSSoT::from_file(&path), Err(ProofError::Internal(_))));
+        let _ = std::fs::remove_file(path);
+    }
+    
+    const RULES_TOML: &str = r#"
+[[axioms]]
+id = "FIN_LIMIT"
+name = "Transfer Limit"
+statement = "No single transfer exceeds 10000"
+domain = "finance"
+violation_rule = { kind = "numeric", pattern = 'transfer of (?P<value>[\d,.]+)', op = "<=", limit = 10000 }
+
+[[axioms]]
+id = "FIN_NO_CASH"
+name = "No Cash"
+statement = "Cash is not accepted"
+domain = "finance"
+violation_rule = { kind = "forbidden", substrings = ["cash", "bearer bond"] }
+
+[[axioms]]
+id = "FIN_NO_BACKDATING"
+name = "No Backdating"
+statement = "Entries are never backdated"
+domain = "finance"
+violation_rule = { kind = "regex", pattern = '(?i)\bbackdated\b' }
+"#;
+    
+    #[test]
+    fn test_violation_rules() {
+        let regex = ViolationRule::regex(r"(?i)\bbackdated\b").unwrap();
+        assert!(regex.is_violated_by("Entry was Backdated"));
+        assert!(!regex.is_violated_by("Entry was dated today"));
+        
+        let forbidden = ViolationRule::forbidden(["cash", "bearer bond"]).unwrap();
+        assert!(forbidden.is_violated_by("Paid in CASH"));
+        assert!(forbidden.is_violated_by("Settled by bearer bond"));
+        assert!(!forbidden.is_violated_by("Paid by wire"));
+        
+        let numeric = ViolationRule::numeric(r"transfer of (?P<value>[\d,.]+)", Comparison::Le, 10000.0).unwrap();
+        assert!(!numeric.is_violated_by("A transfer of 10,000"));
+        assert!(numeric.is_violated_by("A transfer of 10,000.01"));
+        assert!(numeric.is_violated_by("A transfer of 5 and a transfer of 20000"));
+        assert!(!numeric.is_violated_by("A transfer of ,,"));
+        assert!(!numeric.is_violated_by("No transfers today"));
+        
+        assert!(Comparison::Gt.holds(2.0, 1.0) && !Comparison::Ne.holds(1.0, 1.0));
+    }
+    
+    #[test]
+    fn test_invalid_violation_rules_rejected() {
+        let invalid = [
+            ViolationRule::regex("(unclosed"),
+            ViolationRule::forbidden(Vec::<String>::new()),
+            ViolationRule::forbidden(["cash", " "]),
+            ViolationRule::numeric(r"transfer of (\d+)", Comparison::Le, 1.0),
+            ViolationRule::numeric(r"transfer of (?P<value>\d+)", Comparison::Le, f64::NAN),
+        ];
+        for rule in invalid {
+            assert!(matches!(rule, Err(ProofError::AxiomViolation(_))), "{:?}", rule);
+        }
+        
+        // Deserializing validates too
+        let bad = serde_json::json!({ "kind": "numeric", "pattern": "(\\d+)", "op": "<=", "limit": 5 });
+        let err = serde_json::from_value::<ViolationRule>(bad).unwrap_err();
+        assert!(err.to_string().contains("no (?P<value>...) group"), "{}", err);
+        let unknown = serde_json::json!({ "kind": "regex", "pattern": "x", "flags": "i" });
+        assert!(serde_json::from_value::<ViolationRule>(unknown).is_err());
+        
+        let path = write_manifest("bad-rule.toml", &RULES_TOML.replace(r"(?i)\bbackdated\b", "(?i"));
+        match OmegaSSoT::from_file(&path) {
+            Err(ProofError::Internal(message)) => assert!(message.contains("invalid violation rule pattern"), "{}", message),
+            other => panic!("expected invalid manifest, got {:?}", other.map(|s| s.omega_hash)),
+        }
+        let _ = std::fs::remove_file(path);
+    }
+    
+    #[test]
+    fn test_violation_rules_load_and_round_trip() {
+        let path = write_manifest("rules.toml", RULES_TOML);
+        let ssot = OmegaSSoT::from_file(&path).unwrap();
+        let _ = std::fs::remove_file(path);
+        assert!(ssot.verify_integrity());
+        
+        let violated: Vec<&str> = ssot
+            .check_violation("Backdated a transfer of 12000 paid in cash")
+            .iter()
+            .map(|a| a.id.as_str())
+            .collect();
+        assert_eq!(violated, vec!["FIN_LIMIT", "FIN_NO_BACKDATING", "FIN_NO_CASH"]);
+        assert!(ssot.check_violation("A transfer of 500 by wire").is_empty());
+        
+        let limit = ssot.core_axioms.get("FIN_LIMIT").unwrap();
+        let json = serde_json::to_string(limit).unwrap();
+        assert!(json.contains(r#""violation_rule":{"kind":"numeric""#), "{}", json);
+        let parsed: Axiom = serde_json::from_str(&json).unwrap();
+        assert_eq!(&parsed, limit);
+        assert!(parsed.verify_integrity());
+        assert!(parsed.is_violated_by("transfer of 10001"));
+        
+        // The rule is covered by the hash; rule-less axioms hash as before
+        let plain = Axiom::new("FIN_LIMIT", "Transfer Limit", "No single transfer exceeds 10000", "finance");
+        assert_ne!(plain.hash, limit.hash);
+        assert!(!serde_json::to_string(&plain).unwrap().contains("violation_rule"));
+        let mut tampered = limit.clone();
+        tampered.violation_rule = Some(ViolationRule::numeric(r"transfer of (?P<value>\d+)", Comparison::Le, 1e9).unwrap());
+        assert!(!tampered.verify_integrity());
+        
+        // The fundamental contradiction check still applies alongside the rules
+        let mut ssot = OmegaSSoT::new();
+        let path = write_manifest("rules-extend.toml", RULES_TOML);
+        ssot.extend_from_file(&path).unwrap();
+        let _ = std::fs::remove_file(path);
+        let ids: Vec<&str> = ssot.check_violation("a contradiction paid in cash").iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["A2_NON_CONTRADICTION", "FIN_NO_CASH"]);
+    }
+    
     #[test]
     fn test_omega_ssot_fundamental_axioms() {
         let ssot = OmegaSSoT::new();
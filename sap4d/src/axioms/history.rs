@@ -0,0 +1,353 @@
+//! Ω-SSOT versions and receipt compatibility across them
+//!
+//! [`OmegaSSoT::freeze`] takes an immutable [`SsotSnapshot`] of the axioms
+//! as they stand, stamped with the version, the Ω hash and the time it was
+//! frozen. An [`SsotHistory`] keeps snapshots in the order they shipped and
+//! persists them as JSON, so a receipt can be traced back to the snapshot
+//! in force when it was issued (the last one frozen at or before its
+//! timestamp).
+//!
+//! Receipts name their axioms by id only. [`SsotSnapshot::compatibility`]
+//! pins each id to its hash in the issuing snapshot and reports which are
+//! missing from, or hash differently in, a later snapshot. A receipt whose
+//! axioms all survive unchanged can be re-checked under the later version.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+use super::{Axiom, AxiomSet, OmegaSSoT};
+use crate::receipt::Receipt;
+use crate::{ProofError, Result};
+
+/// An axiom whose content differs between two versions
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct AxiomChange {
+    pub before: Axiom,
+    pub after: Axiom,
+}
+
+impl AxiomChange {
+    pub fn id(&self) -> &str {
+        &self.after.id
+    }
+}
+
+/// Axioms that differ between two Ω-SSOT versions, in id order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SsotDiff {
+    /// Only in the newer version
+    pub added: Vec<Axiom>,
+    /// Only in the older version
+    pub removed: Vec<Axiom>,
+    /// In both, with different hashes
+    pub changed: Vec<AxiomChange>,
+}
+
+impl SsotDiff {
+    fn between(old: &AxiomSet, new: &AxiomSet) -> Self {
+        let mut diff = Self::default();
+        for axiom in old.all() {
+            match new.get(&axiom.id) {
+                None => diff.removed.push(axiom.clone()),
+                Some(after) if after.hash != axiom.hash => diff.changed.push(AxiomChange {
+                    before: axiom.clone(),
+                    after: after.clone(),
+                }),
+                Some(_) => {}
+            }
+        }
+        diff.added = new.all().filter(|a| !old.contains(&a.id)).cloned().collect();
+        diff
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty() && self.changed.is_empty()
+    }
+}
+
+impl OmegaSSoT {
+    /// What changed from this version to `other`
+    pub fn diff(&self, other: &OmegaSSoT) -> SsotDiff {
+        SsotDiff::between(&self.core_axioms, &other.core_axioms)
+    }
+
+    /// An immutable snapshot of this version, frozen now
+    pub fn freeze(&self) -> SsotSnapshot {
+        SsotSnapshot {
+            ssot: self.clone(),
+            frozen_at: crate::clock::now(),
+        }
+    }
+}
+
+/// How a receipt's axioms fare in a later Ω-SSOT version, in id order
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Compatibility {
+    /// In the issuing version but gone from the later one
+    pub missing: Vec<String>,
+    /// In both, with different hashes
+    pub changed: Vec<String>,
+    /// Not in the issuing version, so not pinned to any hash
+    pub unpinned: Vec<String>,
+}
+
+impl Compatibility {
+    pub(crate) fn check(axioms: &[String], issued: &AxiomSet, current: &AxiomSet) -> Self {
+        let mut ids: Vec<&String> = axioms.iter().collect();
+        ids.sort();
+        ids.dedup();
+
+        let mut compatibility = Self::default();
+        for id in ids {
+            match (issued.get(id), current.get(id)) {
+                (None, _) => compatibility.unpinned.push(id.clone()),
+                (Some(_), None) => compatibility.missing.push(id.clone()),
+                (Some(before), Some(after)) if before.hash != after.hash => compatibility.changed.push(id.clone()),
+                _ => {}
+            }
+        }
+        compatibility
+    }
+
+    /// Every pinned axiom exists unchanged
+    pub fn is_compatible(&self) -> bool {
+        self.missing.is_empty() && self.changed.is_empty()
+    }
+
+    /// `Ok` when compatible, else an error naming the first drifted axiom
+    pub fn into_result(self) -> Result<()> {
+        if let Some(axiom) = self.changed.into_iter().next() {
+            return Err(ProofError::AxiomDrift { axiom, drift: "changed".to_string() });
+        }
+        if let Some(axiom) = self.missing.into_iter().next() {
+            return Err(ProofError::AxiomDrift { axiom, drift: "was removed".to_string() });
+        }
+        Ok(())
+    }
+}
+
+/// A frozen Ω-SSOT version
+///
+/// Fields are read-only; a snapshot that fails [`SsotSnapshot::verify_integrity`]
+/// is refused by [`SsotHistory`].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SsotSnapshot {
+    ssot: OmegaSSoT,
+    frozen_at: DateTime<Utc>,
+}
+
+impl SsotSnapshot {
+    pub fn version(&self) -> &str {
+        &self.ssot.version
+    }
+
+    /// The Ω hash of the frozen version
+    pub fn hash(&self) -> &str {
+        self.ssot.hash()
+    }
+
+    pub fn frozen_at(&self) -> DateTime<Utc> {
+        self.frozen_at
+    }
+
+    pub fn axioms(&self) -> &AxiomSet {
+        &self.ssot.core_axioms
+    }
+
+    /// The frozen Ω-SSOT, e.g. to re-prove under it with [`crate::ProofEngine::with_ssot`]
+    pub fn ssot(&self) -> &OmegaSSoT {
+        &self.ssot
+    }
+
+    /// Every axiom and the Ω hash match their contents
+    pub fn verify_integrity(&self) -> bool {
+        self.ssot.verify_integrity()
+    }
+
+    /// Whether `axioms`, as pinned by `issued_under`, exist unchanged here
+    pub fn compatibility(&self, axioms: &[String], issued_under: &SsotSnapshot) -> Compatibility {
+        Compatibility::check(axioms, issued_under.axioms(), self.axioms())
+    }
+}
+
+/// Ω-SSOT snapshots in the order they were frozen
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct SsotHistory {
+    snapshots: Vec<SsotSnapshot>,
+}
+
+impl SsotHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Append `snapshot`
+    ///
+    /// It must verify, carry a version not yet recorded and be frozen no
+    /// earlier than the latest snapshot.
+    pub fn push(&mut self, snapshot: SsotSnapshot) -> Result<()> {
+        if !snapshot.verify_integrity() {
+            return Err(ProofError::AxiomViolation(format!(
+                "Ω-SSOT snapshot {} fails its integrity check",
+                snapshot.version()
+            )));
+        }
+        if self.get(snapshot.version()).is_some() {
+            return Err(ProofError::AxiomViolation(format!(
+                "Ω-SSOT version {} is already recorded",
+                snapshot.version()
+            )));
+        }
+        if let Some(latest) = self.latest().filter(|latest| latest.frozen_at > snapshot.frozen_at) {
+            return Err(ProofError::AxiomViolation(format!(
+                "Ω-SSOT snapshot {} was frozen before the latest recorded version {}",
+                snapshot.version(),
+                latest.version()
+            )));
+        }
+        self.snapshots.push(snapshot);
+        Ok(())
+    }
+
+    /// Freeze `ssot` now and append it
+    pub fn record(&mut self, ssot: &OmegaSSoT) -> Result<&SsotSnapshot> {
+        self.push(ssot.freeze())?;
+        Ok(self.snapshots.last().expect("just pushed"))
+    }
+
+    pub fn get(&self, version: &str) -> Option<&SsotSnapshot> {
+        self.snapshots.iter().find(|s| s.version() == version)
+    }
+
+    pub fn latest(&self) -> Option<&SsotSnapshot> {
+        self.snapshots.last()
+    }
+
+    /// Oldest first
+    pub fn snapshots(&self) -> &[SsotSnapshot] {
+        &self.snapshots
+    }
+
+    /// The snapshot in force at `timestamp`: the last one frozen at or before it
+    pub fn in_force_at(&self, timestamp: DateTime<Utc>) -> Option<&SsotSnapshot> {
+        self.snapshots.iter().rev().find(|s| s.frozen_at <= timestamp)
+    }
+
+    /// How `receipt`'s axioms, as pinned when it was issued, fare in `current`
+    pub fn check_receipt(&self, receipt: &Receipt, current: &AxiomSet) -> Result<Compatibility> {
+        let issued = self.in_force_at(receipt.timestamp).ok_or_else(|| {
+            ProofError::Internal(format!(
+                "No Ω-SSOT snapshot was frozen before receipt issuance ({})",
+                receipt.timestamp.to_rfc3339()
+            ))
+        })?;
+        Ok(Compatibility::check(&receipt.axioms, issued.axioms(), current))
+    }
+
+    /// Read a history written by [`SsotHistory::save`], re-checking every snapshot
+    pub fn load(path: &Path) -> Result<Self> {
+        let text = std::fs::read_to_string(path)
+            .map_err(|e| ProofError::Internal(format!("Failed to read {}: {}", path.display(), e)))?;
+        let stored: Self = serde_json::from_str(&text)?;
+
+        let mut history = Self::new();
+        for snapshot in stored.snapshots {
+            history.push(snapshot)?;
+        }
+        Ok(history)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)
+            .map_err(|e| ProofError::Internal(format!("Failed to write {}: {}", path.display(), e)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn at(timestamp: &str) -> DateTime<Utc> {
+        DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc)
+    }
+
+    fn v2() -> OmegaSSoT {
+        let mut ssot = OmegaSSoT::new();
+        ssot.version = "2.0.0".to_string();
+        ssot.core_axioms.add(Axiom::new(
+            "A6_C_ZERO",
+            "C=0 Invariance",
+            "Contradiction measure C must equal zero for any output",
+            "verification",
+        ));
+        ssot.core_axioms.add(Axiom::new("A9_PROVENANCE", "Provenance", "Evidence names its source", "evidence"));
+        ssot.recompute_hash();
+        ssot
+    }
+
+    #[test]
+    fn test_diff_reports_added_removed_changed() {
+        let v1 = OmegaSSoT::new();
+        let v2 = v2();
+        let diff = v1.diff(&v2);
+        assert_eq!(diff.added.iter().map(|a| a.id.as_str()).collect::<Vec<_>>(), ["A9_PROVENANCE"]);
+        assert!(diff.removed.is_empty());
+        assert_eq!(diff.changed.len(), 1);
+        assert_eq!(diff.changed[0].id(), "A6_C_ZERO");
+        assert_ne!(diff.changed[0].before.statement, diff.changed[0].after.statement);
+
+        assert_eq!(v2.diff(&v1).removed[0].id, "A9_PROVENANCE");
+        assert!(v1.diff(&v1).is_empty());
+    }
+
+    #[test]
+    fn test_history_orders_and_dates_snapshots() {
+        let mut history = SsotHistory::new();
+        {
+            let _clock = crate::clock::freeze(at("2026-01-01T00:00:00Z"));
+            history.record(&OmegaSSoT::new()).unwrap();
+            // A version is recorded once
+            assert!(history.record(&OmegaSSoT::new()).is_err());
+        }
+        {
+            let _clock = crate::clock::freeze(at("2026-03-01T00:00:00Z"));
+            history.record(&v2()).unwrap();
+        }
+
+        assert!(history.in_force_at(at("2025-12-31T00:00:00Z")).is_none());
+        assert_eq!(history.in_force_at(at("2026-02-01T00:00:00Z")).unwrap().version(), "1.0.0");
+        assert_eq!(history.in_force_at(at("2026-03-01T00:00:00Z")).unwrap().version(), "2.0.0");
+
+        // Snapshots cannot be recorded out of order or tampered with
+        let mut older = OmegaSSoT::new();
+        older.version = "0.9.0".to_string();
+        older.recompute_hash();
+        let _clock = crate::clock::freeze(at("2026-02-01T00:00:00Z"));
+        assert!(history.push(older.freeze()).is_err());
+
+        let mut tampered = v2().freeze();
+        tampered.ssot.version = "3.0.0".to_string();
+        assert!(history.push(tampered).is_err());
+    }
+
+    #[test]
+    fn test_compatibility_pins_axioms_to_the_issuing_version() {
+        let v1 = OmegaSSoT::new().freeze();
+        let v2 = v2().freeze();
+        let axioms: Vec<String> = ["A6_C_ZERO", "A1_IDENTITY", "FIN_1"].iter().map(|s| s.to_string()).collect();
+
+        let compatibility = v2.compatibility(&axioms, &v1);
+        assert_eq!(compatibility.changed, ["A6_C_ZERO"]);
+        assert!(compatibility.missing.is_empty());
+        assert_eq!(compatibility.unpinned, ["FIN_1"]);
+        assert!(!compatibility.is_compatible());
+        let err = compatibility.into_result().unwrap_err();
+        assert_eq!(err.to_string(), "Axiom A6_C_ZERO changed since receipt issuance");
+
+        assert!(v2.compatibility(&axioms[1..], &v1).is_compatible());
+        assert!(v1.compatibility(&["A9_PROVENANCE".to_string()], &v2).into_result().unwrap_err().to_string().contains("was removed"));
+    }
+}
@@ -2,13 +2,106 @@
 //!
 //! Command-line interface for the proof engine.
 //!
+//! # Exit-code contract
+//!
+//! Every subcommand exits with one of:
+//!
+//! | Code | Meaning                                                         |
+//! |------|-----------------------------------------------------------------|
+//! | 0    | Verified / valid / success                                      |
+//! | 1    | Not verified: proof failed, claim unsupported, receipt invalid  |
+//! | 2    | Input error: missing file, bad JSON, empty claim, bad axiom pack |
+//! | 3    | Internal error                                                  |
+//!
+//! With `--output-format json|compact-json|yaml` stdout carries exactly one
+//! document, including for errors, and nothing is written to stderr.
+//! `--quiet` suppresses all plain-text output so only the exit code remains.
+//!
+//! Receipts are signed with HMAC-SHA256 under the key in the file named by
+//! `SAP4D_SIGNING_KEY_FILE`, and record the key's id (`sha256:` and 16 hex
+//! digits of its digest) in `key_id`. Commands that sign or verify receipts
+//! fail with exit code 2 when it is not set. A verifier trusts these
+//! receipts by holding the same key under that id, e.g. as a
+//! `PORTAL_TRUST_ANCHORS` entry `<key_id>=hmac:<base64 key>`.
+//!
+//! Receipt files are written as YAML when the output path ends in `.yaml`
+//! or `.yml` and as JSON otherwise; commands that read receipts accept
+//! either.
+//!
+//! # Output envelope
+//!
+//! Structured output from every subcommand shares one envelope:
+//!
+//! ```json
+//! {
+//!   "schema_version": 1,
+//!   "command": "prove",
+//!   "status": "FAILED",
+//!   "data": { "claim": "Conclusion" },
+//!   "error": { "code": "CONTRADICTION", "message": "Contradiction detected: ..." }
+//! }
+//! ```
+//!
+//! `status` is one of `VERIFIED`/`FAILED` (prove, `watch --once`), `SUPPORTED`/`NOT_SUPPORTED`
+//! (check), `VALID`/`INVALID` (verify), `REDACTED`/`INVALID` (redact), `IDENTICAL`/`EQUIVALENT`/
+//! `CONTENT_DIFFERS`/`TAMPERED` (diff; exit 1 for the last two), `WRITTEN`
+//! (graph), `PASSED`/`BELOW_THRESHOLD` (bulk-check, against `--fail-threshold`), `OK` or
+//! `STOPPED` (axioms, info, daemon, watch), or `INVALID_INPUT`/`INTERNAL_ERROR` for exit codes
+//! 2 and 3. `error` is `null` on success; its `code` is a stable
+//! [`sap4d::ErrorCode`] string, or `INVALID_INPUT`/`INTERNAL_ERROR`.
+//! `schema_version` is bumped on any incompatible change to this shape.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use clap::{Parser, Subcommand};
-use sap4d::{ProofEngine, Receipt, OmegaSSoT};
+use clap::{Parser, Subcommand, ValueEnum};
+use sap4d::{Claim, ProofEngine, ProofError, Receipt, ReceiptFormat, OmegaSSoT, ReplayStatus, TraceEnvelope};
+use sap4d::bulk::{BulkCheck, BulkConfig, BulkManifest};
+use sap4d::daemon::{Daemon, DaemonConfig};
+use sap4d::engine::EngineConfig;
+use sap4d::evidence::Evidence;
+use sap4d::evidence::file::{CsvColumns, EvidenceFormat, DEFAULT_CSV_COLUMN};
+use sap4d::receipt::diff::{diff, Verdict};
+use sap4d::receipt::RedactionError;
+use sap4d::signing::{KeySigner, ReceiptSigner};
+use sap4d::watch::{WatchConfig, WatchRun, Watcher};
 // ReceiptBuilder is not used in CLI
 use std::fs;
 use std::io::{self, BufRead};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Claim verified, receipt valid, or command succeeded
+const EXIT_OK: i32 = 0;
+
+/// Proof failed, claim unsupported or receipt invalid
+const EXIT_NOT_VERIFIED: i32 = 1;
+
+/// Malformed input (distinct from 1 = not verified)
+const EXIT_INVALID_INPUT: i32 = 2;
+
+/// Unexpected failure inside the CLI or engine
+const EXIT_INTERNAL: i32 = 3;
+
+/// Version of the structured output envelope
+const SCHEMA_VERSION: u32 = 1;
+
+/// Environment variable naming the file holding the receipt signing key
+const KEY_FILE_VAR: &str = "SAP4D_SIGNING_KEY_FILE";
+
+/// Output format applied uniformly across subcommands
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum OutputFormat {
+    /// Pretty-printed JSON envelope
+    Json,
+    /// Single-line JSON envelope
+    CompactJson,
+    /// YAML envelope
+    Yaml,
+    /// Human-readable text
+    Plain,
+}
 
 #[derive(Parser)]
 #[command(name = "sap4d")]
@@ -19,10 +112,28 @@ use std::io::{self, BufRead};
 struct Cli {
     #[command(subcommand)]
     command: Commands,
-    
-    /// Output in JSON format
+
+    /// Output in JSON format (shorthand for --output-format json)
     #[arg(long, global = true)]
     json: bool,
+
+    /// Output format
+    #[arg(long, global = true, value_enum)]
+    output_format: Option<OutputFormat>,
+
+    /// Suppress all non-essential output
+    #[arg(short, long, global = true)]
+    quiet: bool,
+}
+
+impl Cli {
+    fn format(&self) -> OutputFormat {
+        match self.output_format {
+            Some(format) => format,
+            None if self.json => OutputFormat::Json,
+            None => OutputFormat::Plain,
+        }
+    }
 }
 
 #[derive(Subcommand)]
@@ -30,289 +141,1229 @@ enum Commands {
     /// Prove a claim given evidence
     Prove {
         /// The claim to prove
-        claim: String,
-        
+        #[arg(required_unless_present = "claim_file")]
+        claim: Option<String>,
+
+        /// Read a structured claim from a JSON file: a string, or
+        /// {"variable", "domain_values", "template"} to prove the template
+        /// for every domain value
+        #[arg(long, conflicts_with_all = ["claim", "evidence_file"])]
+        claim_file: Option<PathBuf>,
+
+        /// Try every instantiation of a quantified claim instead of
+        /// stopping at the first that fails
+        #[arg(long, requires = "claim_file")]
+        exhaustive: bool,
+
+        /// Fail unless at least this many evidence items are supplied
+        #[arg(long, default_value_t = 0)]
+        min_evidence: usize,
+
+        /// Fail unless the evidence comes from at least this many distinct
+        /// sources (see --csv-source-column)
+        #[arg(long, default_value_t = 0)]
+        min_sources: usize,
+
         /// Evidence (can be specified multiple times)
         #[arg(short, long)]
         evidence: Vec<String>,
-        
-        /// Read evidence from file (one per line)
+
+        /// Read evidence from file: a JSON array if it ends in .json, CSV if
+        /// .csv, otherwise one statement per line
         #[arg(short = 'f', long)]
         evidence_file: Option<String>,
-        
+
+        /// CSV column holding the statements
+        #[arg(long, default_value = DEFAULT_CSV_COLUMN)]
+        csv_column: String,
+
+        /// CSV column holding each statement's source URI
+        #[arg(long)]
+        csv_source_column: Option<String>,
+
+        /// CSV column holding each statement's trust (0.0 to 1.0)
+        #[arg(long)]
+        csv_trust_column: Option<String>,
+
+        /// Skip malformed evidence rows instead of aborting
+        #[arg(long)]
+        lenient: bool,
+
+        /// Axiom pack (TOML or JSON) added to the fundamental axioms
+        #[arg(long)]
+        axioms_file: Option<PathBuf>,
+
         /// Output receipt to file
         #[arg(short, long)]
         output: Option<String>,
+
+        /// Embed the proof trace in the receipt file, for `verify --replay`
+        #[arg(long, requires = "output")]
+        with_trace: bool,
+
+        /// Submit the proven claim and its evidence to the verification
+        /// portal at this base URL (e.g. http://localhost:3000)
+        #[arg(long, value_name = "URL")]
+        publish: Option<String>,
+
+        /// Print the total proving time and the slowest step
+        #[arg(short, long)]
+        verbose: bool,
     },
-    
+
     /// Verify a receipt
     Verify {
         /// Receipt file to verify
         receipt_file: String,
+
+        /// Re-derive the causal chain from the receipt's claim and evidence
+        #[arg(long)]
+        deep: bool,
+
+        /// Re-execute the steps of the trace embedded by `prove --with-trace`
+        #[arg(long, conflicts_with = "deep")]
+        replay: bool,
     },
-    
+
+    /// Withhold evidence items, keeping the receipt verifiable
+    Redact {
+        /// Receipt file to redact (format version 3)
+        receipt_file: String,
+
+        /// Indices of the evidence items to withhold, e.g. 0,2
+        #[arg(short, long, value_delimiter = ',', required = true)]
+        evidence: Vec<usize>,
+
+        /// Write the redacted receipt to file (stdout otherwise)
+        #[arg(short, long)]
+        output: Option<String>,
+    },
+
+    /// Compare two receipts and explain how they diverge
+    Diff {
+        /// First receipt file
+        receipt_a: String,
+
+        /// Second receipt file
+        receipt_b: String,
+    },
+
     /// Show Ω-SSOT axioms
     Axioms {
         /// Show only axioms from a specific domain
         #[arg(short, long)]
         domain: Option<String>,
+
+        /// Axiom pack (TOML or JSON) added to the fundamental axioms
+        #[arg(long)]
+        axioms_file: Option<PathBuf>,
     },
-    
+
     /// Check if a claim is supported by evidence
     Check {
         /// The claim to check
         claim: String,
-        
+
         /// Evidence items
         #[arg(short, long)]
         evidence: Vec<String>,
+
+        /// Axiom pack (TOML or JSON) added to the fundamental axioms
+        #[arg(long)]
+        axioms_file: Option<PathBuf>,
     },
-    
+
     /// Show system information
     Info,
+
+    /// Export a receipt or trace as a GraphViz DOT graph
+    Graph {
+        /// Receipt or trace JSON file
+        input: String,
+
+        /// DOT output path (defaults to the input path with a .dot extension)
+        #[arg(short, long)]
+        output: Option<PathBuf>,
+
+        /// Also render an SVG with `dot -Tsvg` when graphviz is on PATH
+        #[arg(long)]
+        render: bool,
+    },
+
+    /// Continuously prove job files dropped into a watched directory
+    Daemon {
+        /// Directory to watch for *.json job files
+        #[arg(long)]
+        watch_dir: String,
+
+        /// Directory receipts and status.json are written to
+        #[arg(long)]
+        output: String,
+
+        /// Persistent Merkle log of receipt hashes
+        #[arg(long)]
+        log: String,
+
+        /// Maximum jobs proved in parallel
+        #[arg(long, default_value_t = 4)]
+        concurrency: usize,
+
+        /// Poll interval in milliseconds
+        #[arg(long, default_value_t = 1000)]
+        interval_ms: u64,
+
+        /// Process pending jobs once and exit
+        #[arg(long)]
+        once: bool,
+    },
+
+    /// Re-prove a claim whenever its evidence file changes
+    Watch {
+        /// The claim to prove
+        #[arg(long)]
+        claim: String,
+
+        /// Evidence file to watch (JSON, CSV or one statement per line, as for `prove`)
+        #[arg(short = 'f', long)]
+        evidence_file: String,
+
+        /// Receipt file, replaced after each successful proof
+        #[arg(short, long)]
+        output: String,
+
+        /// Axiom pack (TOML or JSON) added to the fundamental axioms
+        #[arg(long)]
+        axioms_file: Option<PathBuf>,
+
+        /// Quiet period in milliseconds after the last change before re-proving
+        #[arg(long, default_value_t = 300)]
+        debounce_ms: u64,
+
+        /// POST each new receipt to this URL (e.g. a portal's /verify-receipt)
+        #[arg(long)]
+        publish: Option<String>,
+
+        /// Prove once and exit instead of watching
+        #[arg(long)]
+        once: bool,
+    },
+
+    /// Check every claim in a manifest and summarize how many verify
+    BulkCheck {
+        /// JSON manifest of claims and their inline, file or URL evidence
+        #[arg(long)]
+        manifest: PathBuf,
+
+        /// Axiom pack (TOML or JSON) added to the fundamental axioms
+        #[arg(long)]
+        axioms_file: Option<PathBuf>,
+
+        /// Maximum claims checked in parallel
+        #[arg(long, default_value_t = sap4d::bulk::DEFAULT_CONCURRENCY)]
+        concurrency: usize,
+
+        /// Write one JSON line per claim to this file
+        #[arg(long)]
+        details: Option<PathBuf>,
+
+        /// Exit 1 unless at least this fraction of claims verify (0.0 to 1.0)
+        #[arg(long, default_value_t = 1.0)]
+        fail_threshold: f64,
+
+        /// Directory URL evidence is cached in, so unchanged evidence is not re-downloaded
+        #[arg(long)]
+        cache_dir: Option<PathBuf>,
+    },
 }
 
-fn mock_sign(hash: &str) -> String {
-    use sha2::{Sha256, Digest};
-    let mut hasher = Sha256::new();
-    hasher.update(b"SAP4D_CLI_SIG:");
-    hasher.update(hash.as_bytes());
-    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+impl Commands {
+    /// Name reported in the envelope's `command` field
+    fn name(&self) -> &'static str {
+        match self {
+            Commands::Prove { .. } => "prove",
+            Commands::Verify { .. } => "verify",
+            Commands::Redact { .. } => "redact",
+            Commands::Diff { .. } => "diff",
+            Commands::Axioms { .. } => "axioms",
+            Commands::Check { .. } => "check",
+            Commands::Info => "info",
+            Commands::Graph { .. } => "graph",
+            Commands::Daemon { .. } => "daemon",
+            Commands::Watch { .. } => "watch",
+            Commands::BulkCheck { .. } => "bulk-check",
+        }
+    }
 }
 
-fn mock_verify(hash: &str, sig: &str) -> bool {
-    mock_sign(hash) == sig
+/// Failure that prevents a subcommand from producing a verdict
+#[derive(Debug)]
+enum CliError {
+    /// Caller-supplied input is missing or malformed (exit 2)
+    Input(String),
+    /// Anything else (exit 3)
+    Internal(String),
 }
 
-fn main() -> anyhow::Result<()> {
-    let cli = Cli::parse();
+impl CliError {
+    fn exit_code(&self) -> i32 {
+        match self {
+            CliError::Input(_) => EXIT_INVALID_INPUT,
+            CliError::Internal(_) => EXIT_INTERNAL,
+        }
+    }
+
+    /// Envelope status, also used as the error code
+    fn status(&self) -> &'static str {
+        match self {
+            CliError::Input(_) => "INVALID_INPUT",
+            CliError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    fn message(&self) -> &str {
+        match self {
+            CliError::Input(msg) | CliError::Internal(msg) => msg,
+        }
+    }
+}
+
+/// Result of a subcommand: exit code, envelope contents and plain text
+struct Report {
+    code: i32,
+    status: &'static str,
+    data: serde_json::Value,
+    error: Option<serde_json::Value>,
+    text: Vec<String>,
+}
+
+impl Report {
+    fn new(code: i32, status: &'static str, data: serde_json::Value) -> Self {
+        Self { code, status, data, error: None, text: Vec::new() }
+    }
+
+    /// Attach the engine error behind a negative verdict
+    fn with_error(mut self, error: &ProofError) -> Self {
+        self.error = Some(error_object(error.code().as_str(), &error.to_string()));
+        self
+    }
+
+    fn line(mut self, line: impl Into<String>) -> Self {
+        self.text.push(line.into());
+        self
+    }
+}
+
+fn error_object(code: &str, message: &str) -> serde_json::Value {
+    serde_json::json!({ "code": code, "message": message })
+}
+
+/// The versioned envelope shared by all subcommands
+fn envelope(
+    command: &str,
+    status: &str,
+    data: &serde_json::Value,
+    error: Option<&serde_json::Value>,
+) -> serde_json::Value {
+    serde_json::json!({
+        "schema_version": SCHEMA_VERSION,
+        "command": command,
+        "status": status,
+        "data": data,
+        "error": error,
+    })
+}
+
+/// Write a structured document to stdout in the selected format
+fn emit_document(format: OutputFormat, document: &serde_json::Value) -> Result<(), CliError> {
+    let rendered = match format {
+        OutputFormat::Yaml => serde_yaml::to_string(document)
+            .map_err(|e| CliError::Internal(e.to_string()))?,
+        OutputFormat::CompactJson => serde_json::to_string(document)
+            .map_err(|e| CliError::Internal(e.to_string()))?,
+        _ => serde_json::to_string_pretty(document)
+            .map_err(|e| CliError::Internal(e.to_string()))?,
+    };
+    println!("{}", rendered.trim_end());
+    Ok(())
+}
+
+fn emit(format: OutputFormat, quiet: bool, command: &str, report: &Report) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Plain if quiet => Ok(()),
+        OutputFormat::Plain => {
+            for line in &report.text {
+                println!("{}", line);
+            }
+            Ok(())
+        }
+        _ => emit_document(
+            format,
+            &envelope(command, report.status, &report.data, report.error.as_ref()),
+        ),
+    }
+}
+
+fn emit_error(format: OutputFormat, quiet: bool, command: &str, error: &CliError) {
+    match format {
+        OutputFormat::Plain if quiet => {}
+        OutputFormat::Plain => println!("✗ Error: {}", error.message()),
+        _ => {
+            let document = envelope(
+                command,
+                error.status(),
+                &serde_json::Value::Null,
+                Some(&error_object(error.status(), error.message())),
+            );
+            // Rendering a plain envelope cannot realistically fail; fall back to JSON
+            if emit_document(format, &document).is_err() {
+                println!("{}", document);
+            }
+        }
+    }
+}
+
+/// The receipt signing key named by [`KEY_FILE_VAR`]
+fn signing_key() -> Result<KeySigner, CliError> {
+    let path = std::env::var_os(KEY_FILE_VAR).ok_or_else(|| {
+        CliError::Input(format!("{} is not set; it names the file holding the receipt signing key", KEY_FILE_VAR))
+    })?;
+    let path = Path::new(&path);
+    KeySigner::from_file(path).map_err(|e| CliError::Input(format!("{}: {}", path.display(), e)))
+}
+
+/// Checks that `signer` made a receipt's signature, under the key id the receipt records
+fn key_verifier<'a>(signer: &'a KeySigner, receipt: &Receipt) -> impl Fn(&str, &str) -> bool + 'a {
+    let keyed = receipt.key_id.as_deref() == Some(signer.key_id());
+    move |hash, signature| keyed && signer.verify(hash, signature)
+}
+
+fn mark(ok: bool) -> &'static str {
+    if ok { "✓" } else { "✗" }
+}
+
+/// Reject empty or whitespace-only claims before proving
+fn require_claim(claim: &str) -> Result<(), CliError> {
+    if claim.trim().is_empty() {
+        return Err(CliError::Input("claim is empty".to_string()));
+    }
+    Ok(())
+}
+
+/// Read a caller-supplied file; a missing or unreadable file is an input error
+fn read_input(path: &str) -> Result<String, CliError> {
+    fs::read_to_string(path).map_err(|e| CliError::Input(format!("{}: {}", path, e)))
+}
+
+fn read_receipt(path: &str) -> Result<Receipt, CliError> {
+    read_receipt_document(path).map(|(receipt, _)| receipt)
+}
+
+/// A JSON or YAML receipt file, and the whole document (which may carry a trace)
+///
+/// YAML is read from `.yaml`/`.yml` files, and from any other file that is
+/// not a JSON object.
+fn read_receipt_document(path: &str) -> Result<(Receipt, serde_json::Value), CliError> {
+    use serde::Deserialize;
     
-    match cli.command {
-        Commands::Prove { claim, evidence, evidence_file, output } => {
-            let mut all_evidence = evidence;
-            
-            // Read evidence from file if provided
-            if let Some(file) = evidence_file {
-                let content = fs::read_to_string(&file)?;
-                for line in content.lines() {
-                    let trimmed = line.trim();
-                    if !trimmed.is_empty() {
-                        all_evidence.push(trimmed.to_string());
-                    }
-                }
+    let content = read_input(path)?;
+    let invalid = |e: String| CliError::Input(format!("{}: invalid receipt: {}", path, e));
+    let format = match ReceiptFormat::from_path(Path::new(path)) {
+        ReceiptFormat::Yaml => ReceiptFormat::Yaml,
+        ReceiptFormat::Json => ReceiptFormat::sniff(&content),
+    };
+    let document: serde_json::Value = match format {
+        ReceiptFormat::Json => serde_json::from_str(&content).map_err(|e| invalid(e.to_string()))?,
+        ReceiptFormat::Yaml => serde_yaml::from_str(&content).map_err(|e| invalid(e.to_string()))?,
+    };
+    let receipt = Receipt::deserialize(&document).map_err(|e| invalid(e.to_string()))?;
+    Ok((receipt, document))
+}
+
+/// `document` in the receipt format `path`'s extension names
+fn receipt_file_text(path: &str, document: &impl serde::Serialize) -> Result<String, CliError> {
+    match ReceiptFormat::from_path(Path::new(path)) {
+        ReceiptFormat::Json => serde_json::to_string_pretty(document).map_err(|e| CliError::Internal(e.to_string())),
+        ReceiptFormat::Yaml => serde_yaml::to_string(document).map_err(|e| CliError::Internal(e.to_string())),
+    }
+}
+
+/// Fundamental axioms plus the optional axiom pack
+fn load_ssot(axioms_file: Option<&Path>) -> Result<OmegaSSoT, CliError> {
+    let mut ssot = OmegaSSoT::new();
+    if let Some(path) = axioms_file {
+        ssot.extend_from_file(path)
+            .map_err(|e| CliError::Input(format!("invalid axioms file: {}", e)))?;
+    }
+    Ok(ssot)
+}
+
+fn non_empty(evidence: Vec<String>) -> Vec<String> {
+    evidence
+        .into_iter()
+        .filter(|e| !e.trim().is_empty())
+        .collect()
+}
+
+/// What `prove` proves
+struct ClaimArgs {
+    claim: Claim,
+    /// Try every instantiation rather than failing fast
+    exhaustive: bool,
+    /// Evidence items the proof needs
+    min_evidence: usize,
+    /// Distinct evidence sources the proof needs
+    min_sources: usize,
+}
+
+impl ClaimArgs {
+    /// The positional claim, or the structured claim read from `claim_file`
+    fn load(claim: Option<String>, claim_file: Option<PathBuf>, exhaustive: bool) -> Result<Self, CliError> {
+        let claim = match claim_file {
+            Some(path) => {
+                let path = path.display().to_string();
+                Claim::from_json(&read_input(&path)?)
+                    .map_err(|e| CliError::Input(format!("{}: invalid claim: {}", path, e)))?
             }
-            
-            // Read from stdin if no evidence provided
-            if all_evidence.is_empty() {
-                eprintln!("Enter evidence (one per line, Ctrl+D to finish):");
-                let stdin = io::stdin();
-                for line in stdin.lock().lines() {
-                    let line = line?;
-                    if !line.trim().is_empty() {
-                        all_evidence.push(line.trim().to_string());
-                    }
-                }
+            None => {
+                let claim = claim.unwrap_or_default();
+                require_claim(&claim)?;
+                Claim::Atomic(claim)
             }
-            
-            let engine = ProofEngine::new();
-            
-            match engine.prove(&claim, all_evidence, mock_sign) {
-                Ok((trace, receipt)) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "status": "VERIFIED",
-                            "receipt": receipt,
-                            "trace": {
-                                "steps": trace.steps.len(),
-                                "explainability": trace.explainability_index(),
-                                "c_zero": trace.is_c_zero()
-                            }
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else {
-                        println!("✓ Claim verified (C=0)");
-                        println!();
-                        println!("Claim: {}", receipt.claim);
-                        println!("Evidence: {} items", receipt.evidence.len());
-                        println!("Causal Chain: {} links", receipt.causal_chain.len());
-                        println!("Axioms Applied: {}", receipt.axioms.len());
-                        println!("Hash: {}", &receipt.hash[..16]);
-                        println!("Timestamp: {}", receipt.timestamp);
-                        println!();
-                        println!("[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]");
-                    }
-                    
-                    // Write to file if specified
-                    if let Some(output_path) = output {
-                        let json = receipt.to_json()?;
-                        fs::write(&output_path, json)?;
-                        if !cli.json {
-                            println!("\nReceipt written to: {}", output_path);
-                        }
-                    }
-                }
-                Err(e) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "status": "FAILED",
-                            "error": e.to_string()
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else {
-                        eprintln!("✗ Proof failed: {}", e);
-                    }
-                    std::process::exit(1);
-                }
+        };
+        Ok(Self { claim, exhaustive, min_evidence: 0, min_sources: 0 })
+    }
+}
+
+/// Submit a proven receipt's claim and evidence to the portal at `url`
+fn publish_to_portal(url: &str, receipt: &Receipt) -> Result<portal_client::VerifyResponse, CliError> {
+    let client = portal_client::PortalClient::new(url).map_err(|e| CliError::Input(e.to_string()))?;
+    client
+        .verify(&receipt.claim, &receipt.evidence)
+        .map_err(|e| CliError::Internal(format!("publish to {}: {}", url, e)))
+}
+
+/// Where `prove` reads file evidence from and how
+struct EvidenceFileArgs {
+    path: Option<String>,
+    columns: CsvColumns,
+    lenient: bool,
+}
+
+/// Where `prove` sends a proven receipt
+struct ProveOutput {
+    /// Receipt file
+    output: Option<String>,
+    /// Embed the trace in the receipt file
+    with_trace: bool,
+    /// Portal base URL
+    publish: Option<String>,
+    /// Print timing
+    verbose: bool,
+}
+
+fn run_prove(
+    claim: ClaimArgs,
+    evidence: Vec<String>,
+    evidence_file: EvidenceFileArgs,
+    axioms_file: Option<PathBuf>,
+    output: ProveOutput,
+    interactive: bool,
+) -> Result<Report, CliError> {
+    let ClaimArgs { claim, exhaustive, min_evidence, min_sources } = claim;
+    let ProveOutput { output, with_trace, publish, verbose } = output;
+    let ssot = load_ssot(axioms_file.as_deref())?;
+    let mut all_evidence: Vec<Evidence> = non_empty(evidence).into_iter().map(Evidence::new).collect();
+    let mut files = Vec::new();
+    let mut skipped = Vec::new();
+
+    // Read evidence from file if provided
+    if let Some(path) = evidence_file.path {
+        let path = Path::new(&path);
+        let format = EvidenceFormat::from_path(path, evidence_file.columns);
+        let loaded = sap4d::evidence::file::load(path, &format, evidence_file.lenient)
+            .map_err(|e| CliError::Input(e.to_string()))?;
+        all_evidence.extend(loaded.evidence);
+        files.push(loaded.file);
+        skipped = loaded.skipped;
+    }
+
+    // Read from stdin if no evidence provided
+    if all_evidence.is_empty() {
+        if interactive {
+            eprintln!("Enter evidence (one per line, Ctrl+D to finish):");
+        }
+        let stdin = io::stdin();
+        for line in stdin.lock().lines() {
+            let line = line.map_err(|e| CliError::Input(format!("stdin: {}", e)))?;
+            if !line.trim().is_empty() {
+                all_evidence.push(Evidence::new(line.trim()));
             }
         }
-        
-        Commands::Verify { receipt_file } => {
-            let content = fs::read_to_string(&receipt_file)?;
-            let receipt: Receipt = serde_json::from_str(&content)?;
-            
-            let engine = ProofEngine::new();
-            
-            match engine.verify_receipt(&receipt, mock_verify) {
-                Ok(true) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "status": "VALID",
-                            "c_zero": receipt.c_zero,
-                            "claim": receipt.claim,
-                            "hash": receipt.hash
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else {
-                        println!("✓ Receipt is VALID");
-                        println!();
-                        println!("Claim: {}", receipt.claim);
-                        println!("C=0: {}", receipt.c_zero);
-                        println!("Hash verified: ✓");
-                        println!("Signature verified: ✓");
-                    }
+    }
+
+    let engine = ProofEngine::with_config(EngineConfig {
+        fail_fast: !exhaustive,
+        min_evidence_count: min_evidence,
+        min_distinct_sources: min_sources,
+        ..Default::default()
+    })
+    .with_ssot(ssot);
+    let signer = signing_key()?;
+    let sign = |hash: &str| signer.sign(hash);
+    let result = if files.is_empty() {
+        engine.prove_structured(&claim, all_evidence, sign)
+    } else {
+        // --claim-file conflicts with --evidence-file, so the claim is atomic
+        engine.prove_from_files(&claim.to_string(), all_evidence, files, sign)
+    };
+
+    let mut report = match result {
+        Ok((trace, mut receipt)) => {
+            receipt.key_id = Some(signer.key_id().to_string());
+            // Write to file if specified
+            if let Some(output_path) = &output {
+                let text = if with_trace {
+                    let mut value = serde_json::to_value(&receipt).map_err(|e| CliError::Internal(e.to_string()))?;
+                    value["trace"] = serde_json::to_value(&trace).map_err(|e| CliError::Internal(e.to_string()))?;
+                    receipt_file_text(output_path, &value)?
+                } else {
+                    receipt_file_text(output_path, &receipt)?
+                };
+                fs::write(output_path, text)
+                    .map_err(|e| CliError::Internal(format!("{}: {}", output_path, e)))?;
+            }
+
+            let mut report = Report::new(EXIT_OK, "VERIFIED", serde_json::json!({
+                "receipt": receipt,
+                "trace": {
+                    "steps": trace.steps.len(),
+                    "explainability": trace.explainability_index(),
+                    "c_zero": trace.is_c_zero()
                 }
-                Ok(false) | Err(_) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "status": "INVALID",
-                            "claim": receipt.claim
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else {
-                        eprintln!("✗ Receipt is INVALID");
-                    }
-                    std::process::exit(1);
+            }))
+            .line("✓ Claim verified (C=0)")
+            .line("")
+            .line(format!("Claim: {}", receipt.claim))
+            .line(format!("Evidence: {} items", receipt.evidence.len()))
+            .line(format!("Causal Chain: {} links", receipt.causal_chain.len()))
+            .line(format!("Axioms Applied: {}", receipt.axioms.len()))
+            .line(format!("Hash: {}", &receipt.hash[..16]))
+            .line(format!("Timestamp: {}", receipt.timestamp))
+            .line("")
+            .line("[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]");
+
+            if let Some(record) = &receipt.quantified {
+                report = report.line(format!(
+                    "Instances: {} proven (aggregate {})",
+                    record.instances.len(),
+                    &record.aggregate_hash[..16]
+                ));
+            }
+            if verbose {
+                let metrics = trace.metrics();
+                report = report.line(format!("Total time: {} µs", metrics.total_duration_micros));
+                if let Some(slowest) = metrics.slowest_step {
+                    report = report.line(format!(
+                        "Slowest step: {}. {} ({} µs)",
+                        slowest.index, slowest.operation, slowest.duration_micros
+                    ));
                 }
             }
+            if let Some(output_path) = output {
+                report = report.line(format!("\nReceipt written to: {}", output_path));
+            }
+            if let Some(url) = publish {
+                let verdict = publish_to_portal(&url, &receipt)?;
+                let status = if verdict.c_zero { "Verified" } else { "Not Verified" };
+                report.data["portal"] = serde_json::to_value(&verdict).map_err(|e| CliError::Internal(e.to_string()))?;
+                report = report.line(format!("\nPublished to {}: {} (receipt {})", url, status, verdict.hash));
+            }
+            report
         }
-        
-        Commands::Axioms { domain } => {
-            let ssot = OmegaSSoT::new();
-            
-            let axioms: Vec<_> = if let Some(d) = &domain {
-                ssot.core_axioms.by_domain(d)
-            } else {
-                ssot.core_axioms.all().collect()
-            };
-            
-            if cli.json {
-                let output_data: Vec<_> = axioms.iter().map(|a| {
-                    serde_json::json!({
-                        "id": a.id,
-                        "name": a.name,
-                        "statement": a.statement,
-                        "domain": a.domain
-                    })
-                }).collect();
-                println!("{}", serde_json::to_string_pretty(&output_data)?);
-            } else {
-                println!("Ω-SSOT Axioms");
-                println!("=============");
-                if let Some(d) = &domain {
-                    println!("Domain: {}", d);
-                }
-                println!();
-                
-                for axiom in axioms {
-                    println!("[{}] {}", axiom.id, axiom.name);
-                    println!("  Statement: {}", axiom.statement);
-                    println!("  Domain: {}", axiom.domain);
-                    println!();
+        Err(e) => {
+            let mut report = Report::new(EXIT_NOT_VERIFIED, "FAILED", serde_json::json!({
+                "claim": claim.to_string()
+            }))
+            .with_error(&e)
+            .line(format!("✗ Proof failed: {}", e));
+
+            if let ProofError::InsufficientEvidence { required, provided } | ProofError::InsufficientSources { required, provided } = e {
+                let unit = if matches!(e, ProofError::InsufficientSources { .. }) { "distinct source(s)" } else { "evidence item(s)" };
+                report.data["required"] = serde_json::json!(required);
+                report.data["provided"] = serde_json::json!(provided);
+                report = report.line(format!("  Shortfall: {} more {} needed ({} of {})", required - provided, unit, provided, required));
+            }
+            if let ProofError::InstantiationFailed { ref failures, .. } = e {
+                report.data["failures"] = serde_json::json!(failures);
+            }
+            if let ProofError::ExplainabilityBelowThreshold { report: ref explainability, .. } = e {
+                report.data["explainability"] = serde_json::to_value(explainability)
+                    .map_err(|e| CliError::Internal(e.to_string()))?;
+                report = report.line("").line("Unexplained steps:");
+                for step in explainability.unexplained() {
+                    report = report.line(format!("  {}. {} (no axioms applied)", step.index, step.operation));
                 }
             }
+            report
         }
-        
-        Commands::Check { claim, evidence } => {
-            let engine = ProofEngine::new();
-            
-            match engine.verify_claim(&claim, &evidence) {
-                Ok(supported) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "claim": claim,
-                            "supported": supported,
-                            "c_zero": supported
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else if supported {
-                        println!("✓ Claim is SUPPORTED by evidence (C=0)");
-                    } else {
-                        println!("✗ Claim is NOT SUPPORTED by evidence");
-                    }
-                    
-                    if !supported {
-                        std::process::exit(1);
-                    }
-                }
-                Err(e) => {
-                    if cli.json {
-                        let output_data = serde_json::json!({
-                            "claim": claim,
-                            "supported": false,
-                            "error": e.to_string()
-                        });
-                        println!("{}", serde_json::to_string_pretty(&output_data)?);
-                    } else {
-                        eprintln!("✗ Check failed: {}", e);
-                    }
-                    std::process::exit(1);
-                }
+    };
+
+    if !skipped.is_empty() {
+        report.data["skipped_rows"] = skipped
+            .iter()
+            .map(|row| serde_json::json!({ "line": row.line, "message": row.message }))
+            .collect();
+        report = report.line("");
+        for row in &skipped {
+            report = report.line(format!("⚠ Skipped evidence {}", row));
+        }
+    }
+    Ok(report)
+}
+
+fn run_verify(receipt_file: String, deep: bool, replay: bool) -> Result<Report, CliError> {
+    if replay {
+        return run_replay(receipt_file);
+    }
+    let receipt = read_receipt(&receipt_file)?;
+    let signer = signing_key()?;
+    let engine = ProofEngine::new();
+
+    if deep {
+        let result = engine.verify_receipt_deep(&receipt, key_verifier(&signer, &receipt));
+        let valid = result.is_valid();
+        let report = Report::new(
+            if valid { EXIT_OK } else { EXIT_NOT_VERIFIED },
+            if valid { "VALID" } else { "INVALID" },
+            serde_json::json!({
+                "claim": receipt.claim,
+                "hash": receipt.hash,
+                "checks": result
+            }),
+        )
+        .line(if valid { "✓ Receipt is VALID" } else { "✗ Receipt is INVALID" })
+        .line("")
+        .line(format!("Claim: {}", receipt.claim))
+        .line(format!("C=0: {}", mark(result.c_zero)))
+        .line(format!("Hash verified: {}", mark(result.hash_ok)))
+        .line(format!("Signature verified: {}", mark(result.signature_ok)))
+        .line(format!("Causal chain re-derived: {}", mark(result.chain_ok)));
+        return Ok(report);
+    }
+
+    match engine.verify_receipt(&receipt, key_verifier(&signer, &receipt)) {
+        Ok(true) => Ok(Report::new(EXIT_OK, "VALID", serde_json::json!({
+            "c_zero": receipt.c_zero,
+            "claim": receipt.claim,
+            "hash": receipt.hash
+        }))
+        .line("✓ Receipt is VALID")
+        .line("")
+        .line(format!("Claim: {}", receipt.claim))
+        .line(format!("C=0: {}", receipt.c_zero))
+        .line("Hash verified: ✓")
+        .line("Signature verified: ✓")),
+        Ok(false) => Ok(Report::new(EXIT_NOT_VERIFIED, "INVALID", serde_json::json!({
+            "claim": receipt.claim
+        }))
+        .line("✗ Receipt is INVALID")),
+        Err(e) => Ok(Report::new(EXIT_NOT_VERIFIED, "INVALID", serde_json::json!({
+            "claim": receipt.claim
+        }))
+        .with_error(&e)
+        .line(format!("✗ Receipt is INVALID: {}", e))),
+    }
+}
+
+/// Check a receipt and re-execute every step of its embedded trace
+fn run_replay(receipt_file: String) -> Result<Report, CliError> {
+    let (receipt, mut document) = read_receipt_document(&receipt_file)?;
+    let signer = signing_key()?;
+    let trace = document
+        .get_mut("trace")
+        .map(serde_json::Value::take)
+        .ok_or_else(|| CliError::Input(format!("{}: no embedded trace (prove with --with-trace)", receipt_file)))?;
+    let trace: TraceEnvelope = serde_json::from_value(trace)
+        .map_err(|e| CliError::Input(format!("{}: invalid trace: {}", receipt_file, e)))?;
+
+    let replay = trace.replay(&ProofEngine::new().step_registry());
+    let hash_ok = receipt.verify_hash();
+    let signature_ok = receipt.verify_signature(key_verifier(&signer, &receipt));
+    // Redacted evidence no longer matches the trace's observations
+    let trace_matches = trace.claim == receipt.claim
+        && trace.causal_chain == receipt.causal_chain
+        && (receipt.is_redacted() || trace.observations == receipt.evidence);
+    let valid = hash_ok && signature_ok && trace_matches && replay.is_valid();
+
+    let mut report = Report::new(
+        if valid { EXIT_OK } else { EXIT_NOT_VERIFIED },
+        if valid { "VALID" } else { "INVALID" },
+        serde_json::json!({
+            "claim": receipt.claim,
+            "hash": receipt.hash,
+            "checks": {
+                "hash_ok": hash_ok,
+                "signature_ok": signature_ok,
+                "trace_matches_receipt": trace_matches
+            },
+            "replay": replay
+        }),
+    )
+    .line(if valid { "✓ Receipt is VALID" } else { "✗ Receipt is INVALID" })
+    .line("")
+    .line(format!("Claim: {}", receipt.claim))
+    .line(format!("Hash verified: {}", mark(hash_ok)))
+    .line(format!("Signature verified: {}", mark(signature_ok)))
+    .line(format!("Trace matches receipt: {}", mark(trace_matches)))
+    .line(format!("Trace hashes verified: {}", mark(replay.integrity_ok)))
+    .line(format!("Steps replayed: {}/{}", replay.matched, replay.total));
+    for step in replay.failures() {
+        let why = match &step.status {
+            ReplayStatus::Mismatch { expected } => {
+                format!("recorded '{}', replay gives '{}'", step.recorded, expected)
+            }
+            ReplayStatus::Failed { reason } => reason.clone(),
+            _ => "no executor for this operation".to_string(),
+        };
+        report = report.line(format!("  ✗ {}. {}: {}", step.index, step.operation, why));
+    }
+    Ok(report)
+}
+
+fn run_redact(receipt_file: String, indices: Vec<usize>, output: Option<String>) -> Result<Report, CliError> {
+    let receipt = read_receipt(&receipt_file)?;
+    let redacted = match receipt.redact(&indices) {
+        Ok(redacted) => redacted,
+        Err(e @ RedactionError::HashMismatch) => {
+            return Ok(Report::new(EXIT_NOT_VERIFIED, "INVALID", serde_json::json!({ "hash": receipt.hash }))
+                .line(format!("✗ Receipt is INVALID: {}", e)));
+        }
+        Err(e) => return Err(CliError::Input(format!("{}: {}", receipt_file, e))),
+    };
+
+    let json = redacted.to_json().map_err(|e| CliError::Internal(e.to_string()))?;
+    let mut report = Report::new(EXIT_OK, "REDACTED", serde_json::json!({
+        "hash": redacted.hash,
+        "redacted": redacted.redacted,
+        "output": output
+    }));
+    match &output {
+        Some(path) => {
+            fs::write(path, receipt_file_text(path, &redacted)?)
+                .map_err(|e| CliError::Internal(format!("{}: {}", path, e)))?;
+            report = report
+                .line(format!("✓ Withheld evidence {:?}; hash unchanged", redacted.redacted))
+                .line(format!("Receipt saved to: {}", path));
+        }
+        None => {
+            report.data["receipt"] = serde_json::to_value(&redacted).map_err(|e| CliError::Internal(e.to_string()))?;
+            report = report.line(json);
+        }
+    }
+    Ok(report)
+}
+
+fn run_diff(receipt_a: String, receipt_b: String) -> Result<Report, CliError> {
+    let a = read_receipt(&receipt_a)?;
+    let b = read_receipt(&receipt_b)?;
+    let result = diff(&a, &b);
+
+    let code = match result.verdict {
+        Verdict::Identical | Verdict::Equivalent => EXIT_OK,
+        Verdict::ContentDiffers | Verdict::Tampered => EXIT_NOT_VERIFIED,
+    };
+    let data = serde_json::to_value(&result).map_err(|e| CliError::Internal(e.to_string()))?;
+
+    let mut report = Report::new(code, result.verdict.as_str(), data)
+        .line(format!("a: {}", receipt_a))
+        .line(format!("b: {}", receipt_b))
+        .line("");
+    for line in result.to_string().lines() {
+        report = report.line(line);
+    }
+    Ok(report)
+}
+
+fn run_axioms(domain: Option<String>, axioms_file: Option<PathBuf>) -> Result<Report, CliError> {
+    let ssot = load_ssot(axioms_file.as_deref())?;
+
+    let axioms: Vec<_> = if let Some(d) = &domain {
+        ssot.core_axioms.by_domain(d)
+    } else {
+        ssot.core_axioms.all().collect()
+    };
+
+    let data: Vec<_> = axioms.iter().map(|a| {
+        serde_json::json!({
+            "id": a.id,
+            "name": a.name,
+            "statement": a.statement,
+            "domain": a.domain
+        })
+    }).collect();
+
+    let mut report = Report::new(EXIT_OK, "OK", serde_json::Value::Array(data))
+        .line("Ω-SSOT Axioms")
+        .line("=============");
+    if let Some(d) = &domain {
+        report = report.line(format!("Domain: {}", d));
+    }
+    report = report.line("");
+    for axiom in axioms {
+        report = report
+            .line(format!("[{}] {}", axiom.id, axiom.name))
+            .line(format!("  Statement: {}", axiom.statement))
+            .line(format!("  Domain: {}", axiom.domain))
+            .line("");
+    }
+    Ok(report)
+}
+
+fn run_check(
+    claim: String,
+    evidence: Vec<String>,
+    axioms_file: Option<PathBuf>,
+) -> Result<Report, CliError> {
+    require_claim(&claim)?;
+    let ssot = load_ssot(axioms_file.as_deref())?;
+    let evidence = non_empty(evidence);
+    let engine = ProofEngine::new().with_ssot(ssot);
+
+    match engine.verify_claim(&claim, &evidence) {
+        Ok(supported) => Ok(Report::new(
+            if supported { EXIT_OK } else { EXIT_NOT_VERIFIED },
+            if supported { "SUPPORTED" } else { "NOT_SUPPORTED" },
+            serde_json::json!({
+                "claim": claim,
+                "supported": supported,
+                "c_zero": supported
+            }),
+        )
+        .line(if supported {
+            "✓ Claim is SUPPORTED by evidence (C=0)"
+        } else {
+            "✗ Claim is NOT SUPPORTED by evidence"
+        })),
+        Err(e) => Ok(Report::new(EXIT_NOT_VERIFIED, "NOT_SUPPORTED", serde_json::json!({
+            "claim": claim,
+            "supported": false,
+            "c_zero": false
+        }))
+        .with_error(&e)
+        .line(format!("✗ Check failed: {}", e))),
+    }
+}
+
+fn run_info() -> Report {
+    let ssot = OmegaSSoT::new();
+
+    Report::new(EXIT_OK, "OK", serde_json::json!({
+        "name": "SAP-4D Proof Engine",
+        "version": "1.0.0",
+        "substrate": sap4d::SUBSTRATE,
+        "projection": sap4d::PROJECTION,
+        "omega_ssot_version": ssot.version,
+        "omega_ssot_hash": ssot.hash(),
+        "axiom_count": ssot.core_axioms.len(),
+        "policy": "C = 0"
+    }))
+    .line("[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]")
+    .line("")
+    .line("SAP-4D Proof Engine v1.0.0")
+    .line("==========================")
+    .line("")
+    .line(format!("Substrate: {}", sap4d::SUBSTRATE))
+    .line(format!("Projection: {}", sap4d::PROJECTION))
+    .line(format!("Ω-SSOT Version: {}", ssot.version))
+    .line(format!("Ω-SSOT Hash: {}...", &ssot.hash()[..16]))
+    .line(format!("Core Axioms: {}", ssot.core_axioms.len()))
+    .line("Policy: C = 0 (Zero Contradiction)")
+    .line("")
+    .line("Mode: Proof Over Persuasion")
+}
+
+fn run_graph(input: String, output: Option<PathBuf>, render: bool) -> Result<Report, CliError> {
+    let content = read_input(&input)?;
+    let value: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| CliError::Input(format!("{}: invalid JSON: {}", input, e)))?;
+
+    // Traces carry their steps; anything else must be a receipt
+    let (kind, dot) = if value.get("steps").is_some() {
+        let trace: TraceEnvelope = serde_json::from_value(value)
+            .map_err(|e| CliError::Input(format!("{}: invalid trace: {}", input, e)))?;
+        ("trace", trace.to_dot())
+    } else {
+        let receipt: Receipt = serde_json::from_value(value)
+            .map_err(|e| CliError::Input(format!("{}: invalid receipt: {}", input, e)))?;
+        let chain = ProofEngine::new()
+            .derive_chain(&receipt)
+            .map_err(|e| CliError::Input(format!("{}: cannot derive causal chain: {}", input, e)))?;
+        ("receipt", chain.to_dot())
+    };
+
+    let dot_path = output.unwrap_or_else(|| Path::new(&input).with_extension("dot"));
+    fs::write(&dot_path, &dot)
+        .map_err(|e| CliError::Internal(format!("{}: {}", dot_path.display(), e)))?;
+
+    let mut svg_path = None;
+    if render {
+        let svg = dot_path.with_extension("svg");
+        match std::process::Command::new("dot").arg("-Tsvg").arg(&dot_path).arg("-o").arg(&svg).output() {
+            Ok(out) if out.status.success() => svg_path = Some(svg),
+            Ok(out) => {
+                return Err(CliError::Internal(format!(
+                    "dot failed: {}",
+                    String::from_utf8_lossy(&out.stderr).trim()
+                )))
             }
+            // graphviz not installed: the .dot file is still written
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+            Err(e) => return Err(CliError::Internal(format!("dot: {}", e))),
         }
-        
-        Commands::Info => {
-            let ssot = OmegaSSoT::new();
-            
-            if cli.json {
-                let output_data = serde_json::json!({
-                    "name": "SAP-4D Proof Engine",
-                    "version": "1.0.0",
-                    "substrate": sap4d::SUBSTRATE,
-                    "projection": sap4d::PROJECTION,
-                    "omega_ssot_version": ssot.version,
-                    "omega_ssot_hash": ssot.hash(),
-                    "axiom_count": ssot.core_axioms.len(),
-                    "policy": "C = 0"
-                });
-                println!("{}", serde_json::to_string_pretty(&output_data)?);
-            } else {
-                println!("[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]");
-                println!();
-                println!("SAP-4D Proof Engine v1.0.0");
-                println!("==========================");
-                println!();
-                println!("Substrate: {}", sap4d::SUBSTRATE);
-                println!("Projection: {}", sap4d::PROJECTION);
-                println!("Ω-SSOT Version: {}", ssot.version);
-                println!("Ω-SSOT Hash: {}...", &ssot.hash()[..16]);
-                println!("Core Axioms: {}", ssot.core_axioms.len());
-                println!("Policy: C = 0 (Zero Contradiction)");
-                println!();
-                println!("Mode: Proof Over Persuasion");
+    }
+
+    let mut report = Report::new(EXIT_OK, "WRITTEN", serde_json::json!({
+        "kind": kind,
+        "dot": dot_path.display().to_string(),
+        "svg": svg_path.as_ref().map(|p| p.display().to_string()),
+    }))
+    .line(format!("✓ {} graph written to: {}", kind, dot_path.display()));
+    match &svg_path {
+        Some(svg) => report = report.line(format!("✓ Rendered SVG: {}", svg.display())),
+        None if render => report = report.line("graphviz `dot` not found on PATH; SVG not rendered"),
+        None => {}
+    }
+    Ok(report)
+}
+
+fn run_daemon(
+    watch_dir: String,
+    output: String,
+    log: String,
+    concurrency: usize,
+    interval_ms: u64,
+    once: bool,
+    interactive: bool,
+) -> Result<Report, CliError> {
+    let config = DaemonConfig {
+        watch_dir: watch_dir.clone().into(),
+        output_dir: output.into(),
+        log_path: log.into(),
+        concurrency,
+        poll_interval: std::time::Duration::from_millis(interval_ms),
+    };
+
+    let internal = |e: sap4d::ProofError| CliError::Internal(e.to_string());
+    let signer = signing_key()?;
+    let mut daemon = Daemon::new(config, |hash: &str| signer.sign(hash))
+        .map_err(internal)?
+        .with_key_id(signer.key_id());
+
+    if once {
+        let outcomes = daemon.run_once().map_err(internal)?;
+        let status = daemon.status().map_err(internal)?;
+        let data = serde_json::to_value(&status).map_err(|e| CliError::Internal(e.to_string()))?;
+        Ok(Report::new(EXIT_OK, "OK", data)
+            .line(format!("Jobs scanned: {}", outcomes.len()))
+            .line(format!("Processed: {}", status.processed))
+            .line(format!("Failures: {}", status.failures))
+            .line(format!("Log root: {}", status.log_root.unwrap_or_default())))
+    } else {
+        if interactive {
+            eprintln!("[SAP-4D] Daemon watching {} (Ctrl+C to stop)", watch_dir);
+        }
+        daemon.run().map_err(internal)?;
+        Ok(Report::new(EXIT_OK, "STOPPED", serde_json::Value::Null))
+    }
+}
+
+fn run_watch(
+    config: WatchConfig,
+    axioms_file: Option<PathBuf>,
+    once: bool,
+    interactive: bool,
+) -> Result<Report, CliError> {
+    let engine = ProofEngine::new().with_ssot(load_ssot(axioms_file.as_deref())?);
+    let evidence_file = config.evidence_file.display().to_string();
+    let signer = signing_key()?;
+    let watcher = Watcher::new(config, |hash: &str| signer.sign(hash))
+        .with_engine(engine)
+        .with_key_id(signer.key_id());
+
+    if once {
+        let run = watcher.prove_once();
+        let (code, status) = if run.verified { (EXIT_OK, "VERIFIED") } else { (EXIT_NOT_VERIFIED, "FAILED") };
+        let line = run.status_line();
+        let data = serde_json::to_value(&run).map_err(|e| CliError::Internal(e.to_string()))?;
+        return Ok(Report::new(code, status, data).line(line));
+    }
+
+    let stop = Arc::new(AtomicBool::new(false));
+    let handler_stop = Arc::clone(&stop);
+    ctrlc::set_handler(move || handler_stop.store(true, Ordering::SeqCst))
+        .map_err(|e| CliError::Internal(format!("cannot install Ctrl+C handler: {}", e)))?;
+
+    if interactive {
+        eprintln!("[SAP-4D] Watching {} (Ctrl+C to stop)", evidence_file);
+    }
+    let mut runs: Vec<WatchRun> = Vec::new();
+    watcher
+        .run(&stop, |run| {
+            if interactive {
+                println!("{}", run.status_line());
             }
+            runs.push(run.clone());
+        })
+        .map_err(|e| CliError::Internal(e.to_string()))?;
+
+    let verified = runs.iter().filter(|r| r.verified).count();
+    let last_receipt = runs.iter().rev().find_map(|r| r.receipt_hash.clone());
+    Ok(Report::new(EXIT_OK, "STOPPED", serde_json::json!({
+        "runs": runs.len(),
+        "verified": verified,
+        "failed": runs.len() - verified,
+        "last_receipt_hash": last_receipt,
+    }))
+    .line(format!("Stopped after {} runs ({} verified)", runs.len(), verified)))
+}
+
+fn run_bulk_check(
+    manifest: &Path,
+    axioms_file: Option<PathBuf>,
+    config: BulkConfig,
+    details: Option<PathBuf>,
+    fail_threshold: f64,
+) -> Result<Report, CliError> {
+    let manifest = BulkManifest::load(manifest).map_err(|e| CliError::Input(e.to_string()))?;
+    let engine = ProofEngine::new().with_ssot(load_ssot(axioms_file.as_deref())?);
+    let signer = signing_key()?;
+    let report = BulkCheck::new(config, |hash: &str| signer.sign(hash))
+        .with_engine(engine)
+        .run(&manifest)
+        .map_err(|e| CliError::Internal(e.to_string()))?;
+    if let Some(path) = &details {
+        report.write_ndjson(path).map_err(|e| CliError::Internal(e.to_string()))?;
+    }
+
+    let summary = &report.summary;
+    let (code, status) = if report.meets(fail_threshold) {
+        (EXIT_OK, "PASSED")
+    } else {
+        (EXIT_NOT_VERIFIED, "BELOW_THRESHOLD")
+    };
+    let mut data = serde_json::to_value(summary).map_err(|e| CliError::Internal(e.to_string()))?;
+    data["fail_threshold"] = serde_json::json!(fail_threshold);
+    data["details"] = serde_json::json!(details.as_ref().map(|p| p.display().to_string()));
+
+    let mut report = Report::new(code, status, data)
+        .line(format!("Claims: {} ({} verified, {} not verified)", summary.total, summary.verified, summary.not_verified))
+        .line(format!(
+            "Verified: {:.2}% (threshold {:.2}%)",
+            summary.pass_rate * 100.0,
+            fail_threshold * 100.0
+        ));
+    if !summary.failures_by_code.is_empty() {
+        report = report.line("Failures by code:");
+        for (code, count) in &summary.failures_by_code {
+            report = report.line(format!("  {}: {}", code, count));
         }
     }
-    
-    Ok(())
+    if summary.urls_downloaded + summary.urls_unchanged + summary.urls_failed > 0 {
+        report = report.line(format!(
+            "URL evidence: {} downloaded, {} unchanged, {} failed",
+            summary.urls_downloaded, summary.urls_unchanged, summary.urls_failed
+        ));
+    }
+    report = report.line(format!("Duration: {} ms", summary.duration_ms));
+    if let Some(path) = details {
+        report = report.line(format!("Details: {}", path.display()));
+    }
+    Ok(report)
 }
 
+fn run(command: Commands, interactive: bool) -> Result<Report, CliError> {
+    match command {
+        Commands::Prove {
+            claim,
+            claim_file,
+            exhaustive,
+            min_evidence,
+            min_sources,
+            evidence,
+            evidence_file,
+            csv_column,
+            csv_source_column,
+            csv_trust_column,
+            lenient,
+            axioms_file,
+            output,
+            with_trace,
+            publish,
+            verbose,
+        } => {
+            let evidence_file = EvidenceFileArgs {
+                path: evidence_file,
+                columns: CsvColumns {
+                    statement: csv_column,
+                    source: csv_source_column,
+                    trust: csv_trust_column,
+                },
+                lenient,
+            };
+            let claim = ClaimArgs {
+                min_evidence,
+                min_sources,
+                ..ClaimArgs::load(claim, claim_file, exhaustive)?
+            };
+            run_prove(claim, evidence, evidence_file, axioms_file, ProveOutput { output, with_trace, publish, verbose }, interactive)
+        }
+        Commands::Verify { receipt_file, deep, replay } => run_verify(receipt_file, deep, replay),
+        Commands::Redact { receipt_file, evidence, output } => run_redact(receipt_file, evidence, output),
+        Commands::Diff { receipt_a, receipt_b } => run_diff(receipt_a, receipt_b),
+        Commands::Axioms { domain, axioms_file } => run_axioms(domain, axioms_file),
+        Commands::Check { claim, evidence, axioms_file } => run_check(claim, evidence, axioms_file),
+        Commands::Info => Ok(run_info()),
+        Commands::Graph { input, output, render } => run_graph(input, output, render),
+        Commands::Daemon { watch_dir, output, log, concurrency, interval_ms, once } => {
+            run_daemon(watch_dir, output, log, concurrency, interval_ms, once, interactive)
+        }
+        Commands::Watch { claim, evidence_file, output, axioms_file, debounce_ms, publish, once } => {
+            require_claim(&claim)?;
+            let mut config = WatchConfig::new(claim, evidence_file, output)
+                .with_debounce(Duration::from_millis(debounce_ms));
+            if let Some(url) = publish {
+                config = config.with_publish(url);
+            }
+            run_watch(config, axioms_file, once, interactive)
+        }
+        Commands::BulkCheck { manifest, axioms_file, concurrency, details, fail_threshold, cache_dir } => {
+            if !(0.0..=1.0).contains(&fail_threshold) {
+                return Err(CliError::Input(format!("--fail-threshold must be between 0 and 1, got {}", fail_threshold)));
+            }
+            let config = BulkConfig { concurrency, cache_dir };
+            run_bulk_check(&manifest, axioms_file, config, details, fail_threshold)
+        }
+    }
+}
+
+fn main() {
+    let cli = Cli::parse();
+    let format = cli.format();
+    let quiet = cli.quiet;
+    // Prompts and progress go to stderr only for a human at a plain terminal
+    let interactive = format == OutputFormat::Plain && !quiet;
+    let command = cli.command.name();
+
+    let code = match run(cli.command, interactive).and_then(|report| {
+        emit(format, quiet, command, &report)?;
+        Ok(report.code)
+    }) {
+        Ok(code) => code,
+        Err(e) => {
+            emit_error(format, quiet, command, &e);
+            e.exit_code()
+        }
+    };
+
+    std::process::exit(code);
+}
@@ -0,0 +1,745 @@
+//! Checking a corpus of claims in one run
+//!
+//! A [`BulkManifest`] lists claims, each with evidence given inline, as a
+//! file (resolved against the manifest's directory, format chosen by
+//! extension as for `prove`) or as a URL. [`BulkCheck::run`] proves every
+//! claim with bounded concurrency and returns a [`BulkReport`]: one
+//! [`ClaimOutcome`] per claim, in manifest order, and a [`BulkSummary`] of
+//! how many verified, the failures grouped by error code, and how long the
+//! run took.
+//!
+//! Each distinct URL is fetched once per run however many claims cite it.
+//! With a cache directory, bodies are stored under their SHA-256 next to
+//! the validators (`ETag`, `Last-Modified`) the server sent, so the next
+//! run makes a conditional request and unchanged evidence is not downloaded
+//! again. Evidence that cannot be read or fetched fails its claim with
+//! `INVALID_EVIDENCE` rather than the run.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, BTreeSet};
+use std::fs;
+use std::io::{Read, Write};
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use thiserror::Error;
+
+use crate::engine::ProofEngine;
+use crate::evidence::file::{self, CsvColumns, EvidenceFormat, ParseError};
+use crate::{Evidence, ProofError};
+
+/// Claims checked in parallel unless configured otherwise
+pub const DEFAULT_CONCURRENCY: usize = 4;
+
+/// Timeout for fetching one URL
+pub const FETCH_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Largest URL evidence body accepted
+pub const MAX_FETCH_BYTES: u64 = 16 * 1024 * 1024;
+
+/// URL index file name (under the cache dir)
+pub const CACHE_INDEX: &str = "index.json";
+
+// ============================================================================
+// Manifest
+// ============================================================================
+
+/// Where a claim's evidence comes from
+///
+/// ```json
+/// [{"inline": "The deployment succeeded"}, {"file": "deploy.csv"}, {"url": "https://ci.example/deploy.json"}]
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum EvidenceRef {
+    /// A single evidence statement
+    Inline(String),
+    /// An evidence file
+    File(PathBuf),
+    /// An evidence document fetched over HTTP(S), parsed by its path's extension
+    Url(String),
+}
+
+/// One claim in a manifest
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ManifestClaim {
+    /// Name in the report (default: the claim's position, from 1)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub id: Option<String>,
+    /// The claim to prove
+    pub claim: String,
+    /// Evidence the claim is proved from
+    #[serde(default)]
+    pub evidence: Vec<EvidenceRef>,
+}
+
+/// Claims to check in one run
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BulkManifest {
+    pub claims: Vec<ManifestClaim>,
+    /// Directory relative file refs are resolved against
+    #[serde(skip)]
+    pub base_dir: PathBuf,
+}
+
+impl BulkManifest {
+    /// Parse a manifest whose file refs are relative to `base_dir`
+    pub fn from_json(json: &str, base_dir: impl Into<PathBuf>) -> Result<Self, BulkError> {
+        let mut manifest: Self = serde_json::from_str(json).map_err(|e| BulkError::Manifest(e.to_string()))?;
+        if manifest.claims.is_empty() {
+            return Err(BulkError::Manifest("no claims listed".to_string()));
+        }
+        if let Some(claim) = manifest.claims.iter().find(|c| c.claim.trim().is_empty()) {
+            return Err(BulkError::Manifest(format!("claim {} is empty", claim.id.as_deref().unwrap_or("without id"))));
+        }
+        manifest.base_dir = base_dir.into();
+        Ok(manifest)
+    }
+
+    /// Read the manifest at `path`; file refs are relative to its directory
+    pub fn load(path: &Path) -> Result<Self, BulkError> {
+        let in_file = |message: String| BulkError::Manifest(format!("{}: {}", path.display(), message));
+        let json = fs::read_to_string(path).map_err(|e| in_file(e.to_string()))?;
+        Self::from_json(&json, path.parent().unwrap_or(Path::new(""))).map_err(|e| match e {
+            BulkError::Manifest(message) => in_file(message),
+            other => other,
+        })
+    }
+
+    /// Each URL cited, once
+    fn urls(&self) -> Vec<String> {
+        let urls: BTreeSet<&str> = self
+            .claims
+            .iter()
+            .flat_map(|c| &c.evidence)
+            .filter_map(|e| match e {
+                EvidenceRef::Url(url) => Some(url.as_str()),
+                _ => None,
+            })
+            .collect();
+        urls.into_iter().map(str::to_string).collect()
+    }
+}
+
+// ============================================================================
+// URL evidence
+// ============================================================================
+
+/// Validators and content hash recorded for a fetched URL
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CachedUrl {
+    /// SHA-256 of the body, which names its file in the cache
+    pub sha256: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub etag: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_modified: Option<String>,
+}
+
+/// Answer to a (possibly conditional) evidence request
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The copy on hand is current (HTTP 304)
+    NotModified,
+    /// The current body and the validators sent with it
+    Body {
+        bytes: Vec<u8>,
+        etag: Option<String>,
+        last_modified: Option<String>,
+    },
+}
+
+/// Retrieves URL evidence
+pub trait Fetcher: Sync {
+    /// Fetch `url`; `cached` holds the validators of the copy on hand, if any
+    fn fetch(&self, url: &str, cached: Option<&CachedUrl>) -> Result<Fetched, String>;
+}
+
+/// [`Fetcher`] over HTTP(S), revalidating with `If-None-Match` and
+/// `If-Modified-Since`
+#[derive(Debug, Clone)]
+pub struct HttpFetcher {
+    pub timeout: Duration,
+}
+
+impl Default for HttpFetcher {
+    fn default() -> Self {
+        Self { timeout: FETCH_TIMEOUT }
+    }
+}
+
+impl Fetcher for HttpFetcher {
+    fn fetch(&self, url: &str, cached: Option<&CachedUrl>) -> Result<Fetched, String> {
+        let mut request = ureq::get(url).timeout(self.timeout);
+        if let Some(etag) = cached.and_then(|c| c.etag.as_deref()) {
+            request = request.set("If-None-Match", etag);
+        }
+        if let Some(last_modified) = cached.and_then(|c| c.last_modified.as_deref()) {
+            request = request.set("If-Modified-Since", last_modified);
+        }
+
+        let response = request.call().map_err(|e| e.to_string())?;
+        if response.status() == 304 {
+            return Ok(Fetched::NotModified);
+        }
+        let etag = response.header("ETag").map(str::to_string);
+        let last_modified = response.header("Last-Modified").map(str::to_string);
+
+        let mut bytes = Vec::new();
+        response
+            .into_reader()
+            .take(MAX_FETCH_BYTES + 1)
+            .read_to_end(&mut bytes)
+            .map_err(|e| e.to_string())?;
+        if bytes.len() as u64 > MAX_FETCH_BYTES {
+            return Err(format!("body exceeds {} bytes", MAX_FETCH_BYTES));
+        }
+        Ok(Fetched::Body { bytes, etag, last_modified })
+    }
+}
+
+/// URL evidence kept between runs: bodies named by their SHA-256, plus an
+/// index of the validators each URL was last fetched with
+#[derive(Debug)]
+pub struct EvidenceCache {
+    dir: PathBuf,
+    index: BTreeMap<String, CachedUrl>,
+}
+
+impl EvidenceCache {
+    /// Open (or create) the cache in `dir`
+    pub fn open(dir: impl Into<PathBuf>) -> Result<Self, BulkError> {
+        let dir = dir.into();
+        fs::create_dir_all(&dir).map_err(|e| io_error(&dir, e))?;
+
+        let index_path = dir.join(CACHE_INDEX);
+        let index = match fs::read_to_string(&index_path) {
+            // An unreadable index only costs a re-download
+            Ok(json) => serde_json::from_str(&json).unwrap_or_default(),
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => BTreeMap::new(),
+            Err(e) => return Err(io_error(&index_path, e)),
+        };
+        Ok(Self { dir, index })
+    }
+
+    /// The cached entry and body for `url`, if the body is intact
+    pub fn get(&self, url: &str) -> Option<(CachedUrl, Vec<u8>)> {
+        let entry = self.index.get(url)?;
+        let body = fs::read(self.dir.join(&entry.sha256)).ok()?;
+        (sha256_hex(&body) == entry.sha256).then(|| (entry.clone(), body))
+    }
+
+    /// Record `entry` for `url`, storing `body` unless it is already held
+    pub fn insert(&mut self, url: &str, entry: CachedUrl, body: &[u8]) -> Result<(), BulkError> {
+        let path = self.dir.join(&entry.sha256);
+        if !path.exists() {
+            write_atomic(&path, body)?;
+        }
+        self.index.insert(url.to_string(), entry);
+        Ok(())
+    }
+
+    /// Persist the URL index
+    pub fn save(&self) -> Result<(), BulkError> {
+        let path = self.dir.join(CACHE_INDEX);
+        let json = serde_json::to_vec_pretty(&self.index).map_err(|e| io_error(&path, e.into()))?;
+        write_atomic(&path, &json)
+    }
+}
+
+/// A URL's body for this run
+struct UrlBody {
+    entry: CachedUrl,
+    bytes: Vec<u8>,
+    /// Fetched in full rather than revalidated
+    downloaded: bool,
+}
+
+fn fetch_url(fetcher: &dyn Fetcher, cache: Option<&EvidenceCache>, url: &str) -> Result<UrlBody, String> {
+    let cached = cache.and_then(|c| c.get(url));
+    match fetcher.fetch(url, cached.as_ref().map(|(entry, _)| entry))? {
+        Fetched::NotModified => {
+            let (entry, bytes) = cached.ok_or("304 Not Modified without a cached copy")?;
+            Ok(UrlBody { entry, bytes, downloaded: false })
+        }
+        Fetched::Body { bytes, etag, last_modified } => Ok(UrlBody {
+            entry: CachedUrl { sha256: sha256_hex(&bytes), etag, last_modified },
+            bytes,
+            downloaded: true,
+        }),
+    }
+}
+
+/// Evidence in a URL body, sourced from the URL
+fn url_evidence(url: &str, body: &UrlBody) -> Result<Vec<Evidence>, String> {
+    let content = std::str::from_utf8(&body.bytes).map_err(|e| e.to_string())?;
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let format = EvidenceFormat::from_path(Path::new(path), CsvColumns::default());
+    let (evidence, _) = file::parse(content, &format, false).map_err(|e| match e {
+        ParseError::Unreadable(message) => message,
+        ParseError::Row(row) => row.to_string(),
+    })?;
+
+    let retrieved_at = Utc::now();
+    Ok(evidence
+        .into_iter()
+        .map(|item| match item.source_uri {
+            Some(_) => item,
+            None => item.with_source(url, retrieved_at),
+        })
+        .collect())
+}
+
+// ============================================================================
+// Checking
+// ============================================================================
+
+/// Bulk check configuration
+#[derive(Debug, Clone)]
+pub struct BulkConfig {
+    /// Maximum claims proved in parallel
+    pub concurrency: usize,
+    /// Directory URL evidence is cached in between runs
+    pub cache_dir: Option<PathBuf>,
+}
+
+impl Default for BulkConfig {
+    fn default() -> Self {
+        Self {
+            concurrency: DEFAULT_CONCURRENCY,
+            cache_dir: None,
+        }
+    }
+}
+
+/// Result of checking one claim
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ClaimOutcome {
+    pub id: String,
+    pub claim: String,
+    pub verified: bool,
+    /// Evidence items resolved for the claim
+    pub evidence: usize,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub receipt_hash: Option<String>,
+    /// Stable error code when not verified (e.g. `UNSUPPORTED_CLAIM`)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub code: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    pub duration_ms: u64,
+}
+
+/// Totals for a run
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkSummary {
+    pub total: usize,
+    pub verified: usize,
+    pub not_verified: usize,
+    /// `verified / total`
+    pub pass_rate: f64,
+    /// Not-verified claims per error code
+    pub failures_by_code: BTreeMap<String, usize>,
+    /// Distinct URLs downloaded in full
+    pub urls_downloaded: usize,
+    /// Distinct URLs the server confirmed unchanged
+    pub urls_unchanged: usize,
+    /// Distinct URLs that could not be fetched
+    pub urls_failed: usize,
+    pub duration_ms: u64,
+}
+
+/// Outcome of [`BulkCheck::run`]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct BulkReport {
+    pub summary: BulkSummary,
+    /// One outcome per claim, in manifest order
+    pub outcomes: Vec<ClaimOutcome>,
+}
+
+impl BulkReport {
+    /// Whether at least `threshold` (0.0..=1.0) of the claims verified
+    pub fn meets(&self, threshold: f64) -> bool {
+        self.summary.pass_rate >= threshold
+    }
+
+    /// Write the outcomes to `path`, one JSON object per line
+    pub fn write_ndjson(&self, path: &Path) -> Result<(), BulkError> {
+        let mut out = Vec::new();
+        for outcome in &self.outcomes {
+            serde_json::to_writer(&mut out, outcome).map_err(|e| io_error(path, e.into()))?;
+            out.push(b'\n');
+        }
+        fs::write(path, out).map_err(|e| io_error(path, e))
+    }
+}
+
+/// Checks every claim in a manifest
+pub struct BulkCheck<F> {
+    config: BulkConfig,
+    engine: ProofEngine,
+    fetcher: Box<dyn Fetcher>,
+    sign_fn: F,
+}
+
+impl<F> BulkCheck<F>
+where
+    F: Fn(&str) -> String + Sync,
+{
+    pub fn new(config: BulkConfig, sign_fn: F) -> Self {
+        Self {
+            config,
+            engine: ProofEngine::new(),
+            fetcher: Box::new(HttpFetcher::default()),
+            sign_fn,
+        }
+    }
+
+    /// Prove with `engine` (axioms, thresholds) instead of the default
+    pub fn with_engine(mut self, engine: ProofEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Fetch URL evidence with `fetcher` instead of over HTTP
+    pub fn with_fetcher(mut self, fetcher: impl Fetcher + 'static) -> Self {
+        self.fetcher = Box::new(fetcher);
+        self
+    }
+
+    /// Check every claim in `manifest`
+    ///
+    /// Only cache failures end the run early; everything else is recorded
+    /// against the claim it affects.
+    pub fn run(&self, manifest: &BulkManifest) -> Result<BulkReport, BulkError> {
+        let started = Instant::now();
+        let concurrency = self.config.concurrency.max(1);
+
+        let mut cache = match &self.config.cache_dir {
+            Some(dir) => Some(EvidenceCache::open(dir)?),
+            None => None,
+        };
+
+        // Each distinct URL once, before any claim needs it
+        let urls = manifest.urls();
+        let mut fetched = Vec::with_capacity(urls.len());
+        for batch in urls.chunks(concurrency) {
+            let fetcher = self.fetcher.as_ref();
+            let cache = cache.as_ref();
+            fetched.extend(std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|url| scope.spawn(move || (url.clone(), fetch_url(fetcher, cache, url))))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+            }));
+        }
+
+        let (mut urls_downloaded, mut urls_unchanged, mut urls_failed) = (0, 0, 0);
+        let mut url_evidence_by_url = BTreeMap::new();
+        for (url, body) in fetched {
+            let evidence = match body {
+                Ok(body) => {
+                    if body.downloaded {
+                        urls_downloaded += 1;
+                        if let Some(cache) = cache.as_mut() {
+                            cache.insert(&url, body.entry.clone(), &body.bytes)?;
+                        }
+                    } else {
+                        urls_unchanged += 1;
+                    }
+                    url_evidence(&url, &body)
+                }
+                Err(e) => {
+                    urls_failed += 1;
+                    Err(e)
+                }
+            };
+            url_evidence_by_url.insert(url, evidence);
+        }
+        if let Some(cache) = &cache {
+            cache.save()?;
+        }
+
+        let indexed: Vec<(usize, &ManifestClaim)> = manifest.claims.iter().enumerate().collect();
+        let mut outcomes = Vec::with_capacity(indexed.len());
+        for batch in indexed.chunks(concurrency) {
+            let urls = &url_evidence_by_url;
+            outcomes.extend(std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|&(index, claim)| scope.spawn(move || self.check(index, claim, &manifest.base_dir, urls)))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect::<Vec<_>>()
+            }));
+        }
+
+        let verified = outcomes.iter().filter(|o| o.verified).count();
+        let mut failures_by_code = BTreeMap::new();
+        for code in outcomes.iter().filter_map(|o| o.code.clone()) {
+            *failures_by_code.entry(code).or_insert(0) += 1;
+        }
+
+        Ok(BulkReport {
+            summary: BulkSummary {
+                total: outcomes.len(),
+                verified,
+                not_verified: outcomes.len() - verified,
+                pass_rate: if outcomes.is_empty() { 1.0 } else { verified as f64 / outcomes.len() as f64 },
+                failures_by_code,
+                urls_downloaded,
+                urls_unchanged,
+                urls_failed,
+                duration_ms: started.elapsed().as_millis() as u64,
+            },
+            outcomes,
+        })
+    }
+
+    fn check(
+        &self,
+        index: usize,
+        claim: &ManifestClaim,
+        base_dir: &Path,
+        urls: &BTreeMap<String, Result<Vec<Evidence>, String>>,
+    ) -> ClaimOutcome {
+        let started = Instant::now();
+        let evidence = resolve(claim, base_dir, urls);
+        let evidence_count = evidence.as_ref().map_or(0, Vec::len);
+        let result = evidence.and_then(|evidence| self.engine.prove_with_evidence(&claim.claim, evidence, &self.sign_fn));
+
+        let (receipt_hash, error) = match result {
+            Ok((_, receipt)) => (Some(receipt.hash), None),
+            Err(e) => (None, Some(e)),
+        };
+        ClaimOutcome {
+            id: claim.id.clone().unwrap_or_else(|| (index + 1).to_string()),
+            claim: claim.claim.clone(),
+            verified: error.is_none(),
+            evidence: evidence_count,
+            receipt_hash,
+            code: error.as_ref().map(|e| e.code().as_str().to_string()),
+            error: error.map(|e| e.to_string()),
+            duration_ms: started.elapsed().as_millis() as u64,
+        }
+    }
+}
+
+/// Evidence for `claim`, from its refs in order
+fn resolve(
+    claim: &ManifestClaim,
+    base_dir: &Path,
+    urls: &BTreeMap<String, Result<Vec<Evidence>, String>>,
+) -> crate::Result<Vec<Evidence>> {
+    let mut evidence = Vec::new();
+    for item in &claim.evidence {
+        match item {
+            EvidenceRef::Inline(statement) => evidence.push(Evidence::new(statement.as_str())),
+            EvidenceRef::File(path) => {
+                let path = base_dir.join(path);
+                let format = EvidenceFormat::from_path(&path, CsvColumns::default());
+                let loaded = file::load(&path, &format, false)
+                    .map_err(|e| ProofError::InvalidEvidence(e.to_string()))?;
+                evidence.extend(loaded.evidence);
+            }
+            EvidenceRef::Url(url) => match urls.get(url) {
+                Some(Ok(items)) => evidence.extend(items.iter().cloned()),
+                Some(Err(e)) => return Err(ProofError::InvalidEvidence(format!("{}: {}", url, e))),
+                None => return Err(ProofError::InvalidEvidence(format!("{}: not fetched", url))),
+            },
+        }
+    }
+    Ok(evidence)
+}
+
+// ============================================================================
+// Errors
+// ============================================================================
+
+/// Errors that stop a bulk check
+#[derive(Debug, Error)]
+pub enum BulkError {
+    #[error("Invalid manifest: {0}")]
+    Manifest(String),
+
+    #[error("{path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+}
+
+fn io_error(path: &Path, source: std::io::Error) -> BulkError {
+    BulkError::Io {
+        path: path.display().to_string(),
+        source,
+    }
+}
+
+fn write_atomic(path: &Path, bytes: &[u8]) -> Result<(), BulkError> {
+    let tmp = path.with_extension("tmp");
+    fs::File::create(&tmp)
+        .and_then(|mut f| f.write_all(bytes))
+        .and_then(|_| fs::rename(&tmp, path))
+        .map_err(|e| io_error(path, e))
+}
+
+fn sha256_hex(bytes: &[u8]) -> String {
+    hex::encode(Sha256::digest(bytes))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Mutex;
+
+    fn mock_sign(hash: &str) -> String {
+        format!("SIG:{}", hash)
+    }
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("sap4d_bulk_{}_{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    /// Serves fixed bodies with an ETag, answering 304 when it matches
+    struct FakeFetcher {
+        bodies: BTreeMap<String, String>,
+        calls: AtomicUsize,
+        conditional: Mutex<Vec<String>>,
+    }
+
+    impl FakeFetcher {
+        fn new(bodies: &[(&str, &str)]) -> Self {
+            Self {
+                bodies: bodies.iter().map(|(u, b)| (u.to_string(), b.to_string())).collect(),
+                calls: AtomicUsize::new(0),
+                conditional: Mutex::new(Vec::new()),
+            }
+        }
+    }
+
+    impl Fetcher for &'static FakeFetcher {
+        fn fetch(&self, url: &str, cached: Option<&CachedUrl>) -> Result<Fetched, String> {
+            self.calls.fetch_add(1, Ordering::SeqCst);
+            let body = self.bodies.get(url).ok_or("404 Not Found")?;
+            let etag = format!("\"{}\"", sha256_hex(body.as_bytes()));
+            if let Some(cached) = cached {
+                self.conditional.lock().unwrap().push(url.to_string());
+                if cached.etag.as_deref() == Some(etag.as_str()) {
+                    return Ok(Fetched::NotModified);
+                }
+            }
+            Ok(Fetched::Body { bytes: body.clone().into_bytes(), etag: Some(etag), last_modified: None })
+        }
+    }
+
+    fn leak(fetcher: FakeFetcher) -> &'static FakeFetcher {
+        Box::leak(Box::new(fetcher))
+    }
+
+    #[test]
+    fn test_manifest_refs_and_defaults() {
+        let manifest = BulkManifest::from_json(
+            r#"{"claims": [
+                {"claim": "Water boils", "evidence": [{"inline": "Water boils"}, {"file": "e.txt"}]},
+                {"id": "remote", "claim": "Tests pass", "evidence": [{"url": "https://ci.example/a.json"}]}
+            ]}"#,
+            "corpus",
+        )
+        .unwrap();
+        assert_eq!(manifest.base_dir, PathBuf::from("corpus"));
+        assert_eq!(manifest.claims[0].evidence[1], EvidenceRef::File("e.txt".into()));
+        assert_eq!(manifest.urls(), vec!["https://ci.example/a.json".to_string()]);
+
+        assert!(matches!(BulkManifest::from_json(r#"{"claims": []}"#, ""), Err(BulkError::Manifest(_))));
+        assert!(matches!(
+            BulkManifest::from_json(r#"{"claims": [{"claim": " "}]}"#, ""),
+            Err(BulkError::Manifest(_))
+        ));
+        assert!(BulkManifest::load(Path::new("/nonexistent/claims.json")).is_err());
+    }
+
+    #[test]
+    fn test_summary_groups_failures_by_code() {
+        let dir = temp_dir("summary");
+        fs::write(dir.join("deploy.txt"), "Build passed\nThe deployment succeeded\n").unwrap();
+        let manifest = BulkManifest::from_json(
+            r#"{"claims": [
+                {"id": "inline", "claim": "Water boils", "evidence": [{"inline": "Water boils"}]},
+                {"id": "file", "claim": "The deployment succeeded", "evidence": [{"file": "deploy.txt"}]},
+                {"id": "none", "claim": "The moon is cheese", "evidence": []},
+                {"id": "missing", "claim": "Water boils", "evidence": [{"file": "missing.txt"}]}
+            ]}"#,
+            &dir,
+        )
+        .unwrap();
+
+        let config = BulkConfig { concurrency: 2, ..Default::default() };
+        let report = BulkCheck::new(config, mock_sign).run(&manifest).unwrap();
+
+        let ids: Vec<&str> = report.outcomes.iter().map(|o| o.id.as_str()).collect();
+        assert_eq!(ids, ["inline", "file", "none", "missing"]);
+        assert_eq!(report.summary.total, 4);
+        assert_eq!(report.summary.verified, 2);
+        assert_eq!(report.summary.not_verified, 2);
+        assert_eq!(report.summary.pass_rate, 0.5);
+        assert_eq!(report.summary.failures_by_code.get("INVALID_EVIDENCE"), Some(&1));
+        assert_eq!(report.summary.failures_by_code.values().sum::<usize>(), 2);
+        assert!(report.outcomes[1].receipt_hash.is_some());
+        assert_eq!(report.outcomes[1].evidence, 2);
+        assert!(report.meets(0.5) && !report.meets(0.99));
+
+        let details = dir.join("details.ndjson");
+        report.write_ndjson(&details).unwrap();
+        let lines: Vec<ClaimOutcome> = fs::read_to_string(&details)
+            .unwrap()
+            .lines()
+            .map(|l| serde_json::from_str(l).unwrap())
+            .collect();
+        assert_eq!(lines, report.outcomes);
+
+        fs::remove_dir_all(dir).ok();
+    }
+
+    #[test]
+    fn test_urls_fetched_once_and_revalidated_from_cache() {
+        let dir = temp_dir("cache");
+        let fetcher = leak(FakeFetcher::new(&[("https://ci.example/deploy.txt?run=1", "The deployment succeeded\n")]));
+        let manifest = BulkManifest::from_json(
+            r#"{"claims": [
+                {"claim": "The deployment succeeded", "evidence": [{"url": "https://ci.example/deploy.txt?run=1"}]},
+                {"claim": "The deployment succeeded", "evidence": [{"url": "https://ci.example/deploy.txt?run=1"}]},
+                {"claim": "The deployment succeeded", "evidence": [{"url": "https://ci.example/gone.txt"}]}
+            ]}"#,
+            &dir,
+        )
+        .unwrap();
+        let config = BulkConfig { concurrency: 4, cache_dir: Some(dir.join("cache")) };
+
+        let first = BulkCheck::new(config.clone(), mock_sign).with_fetcher(fetcher).run(&manifest).unwrap();
+        assert_eq!(fetcher.calls.load(Ordering::SeqCst), 2);
+        assert_eq!((first.summary.urls_downloaded, first.summary.urls_unchanged, first.summary.urls_failed), (1, 0, 1));
+        assert_eq!(first.summary.verified, 2);
+        assert_eq!(first.outcomes[2].code.as_deref(), Some("INVALID_EVIDENCE"));
+
+        // The second run revalidates rather than downloading, and proves the same
+        let second = BulkCheck::new(config, mock_sign).with_fetcher(fetcher).run(&manifest).unwrap();
+        assert_eq!(fetcher.conditional.lock().unwrap().as_slice(), ["https://ci.example/deploy.txt?run=1"]);
+        assert_eq!((second.summary.urls_downloaded, second.summary.urls_unchanged), (0, 1));
+        assert_eq!(second.summary.verified, 2);
+
+        let cache = EvidenceCache::open(dir.join("cache")).unwrap();
+        let (entry, body) = cache.get("https://ci.example/deploy.txt?run=1").unwrap();
+        assert_eq!(entry.sha256, sha256_hex(&body));
+
+        fs::remove_dir_all(dir).ok();
+    }
+}
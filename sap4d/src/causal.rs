@@ -1,10 +1,20 @@
 //! Causal inference and chain construction
 //!
+//! [`ContradictionDetector`] finds evidence pairs that cannot both hold —
+//! a statement and its negation ("X" / "not X" / "X is false"), or two
+//! different numbers for the same subject ("the count is 5" / "the count
+//! is 7") — so the engine can record them as `Contradicts` links.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 
+use crate::hashing::{legacy_hash_version, FieldHasher, FIELD_HASH_VERSION};
+#[cfg(feature = "engine")]
+use std::collections::HashMap;
+
+#[cfg(feature = "engine")]
 use crate::{ProofError, Result};
 
 /// Types of causal relationships
@@ -24,6 +34,69 @@ pub enum CausalRelation {
     Contradicts,
 }
 
+impl CausalRelation {
+    /// Logical symbol for this relation
+    pub fn symbol(&self) -> &'static str {
+        match self {
+            CausalRelation::Causes => "→",
+            CausalRelation::CausedBy => "←",
+            CausalRelation::CorrelatedWith => "~",
+            CausalRelation::Implies => "⟹",
+            CausalRelation::Equivalent => "⟺",
+            CausalRelation::Contradicts => "⊥",
+        }
+    }
+
+    /// Every relation, in declaration order
+    pub const ALL: [CausalRelation; 6] = [
+        CausalRelation::Causes,
+        CausalRelation::CausedBy,
+        CausalRelation::CorrelatedWith,
+        CausalRelation::Implies,
+        CausalRelation::Equivalent,
+        CausalRelation::Contradicts,
+    ];
+
+    /// Split a link rendered by [`CausalChain::to_string_chain`] into
+    /// source, relation and target, at the first relation symbol
+    pub fn parse_link(link: &str) -> Option<(&str, CausalRelation, &str)> {
+        Self::ALL
+            .iter()
+            .filter_map(|relation| {
+                let separator = format!(" {} ", relation.symbol());
+                link.find(&separator)
+                    .map(|at| (at, &link[..at], *relation, &link[at + separator.len()..]))
+            })
+            .min_by_key(|(at, ..)| *at)
+            .map(|(_, source, relation, target)| (source, relation, target))
+    }
+}
+
+/// Escape text for use inside a double-quoted GraphViz string
+#[cfg(feature = "engine")]
+pub(crate) fn dot_escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '\\' => escaped.push_str("\\\\"),
+            '"' => escaped.push_str("\\\""),
+            '\n' => escaped.push_str("\\n"),
+            '\r' => {}
+            _ => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Index of the node labeled `text`, adding it with `shape` if absent
+#[cfg(feature = "engine")]
+fn dot_node<'a>(nodes: &mut Vec<(&'a str, &'static str)>, text: &'a str, shape: &'static str) -> usize {
+    nodes.iter().position(|(t, _)| *t == text).unwrap_or_else(|| {
+        nodes.push((text, shape));
+        nodes.len() - 1
+    })
+}
+
 /// A single link in a causal chain
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CausalLink {
@@ -39,6 +112,9 @@ pub struct CausalLink {
     pub evidence: Vec<String>,
     /// Hash of this link
     pub hash: String,
+    /// Rules `hash` was computed under (1 if absent; see [`crate::hashing`])
+    #[serde(default = "legacy_hash_version")]
+    pub hash_version: u32,
 }
 
 impl CausalLink {
@@ -52,38 +128,48 @@ impl CausalLink {
         let source = source.into();
         let target = target.into();
         
-        let hash = Self::compute_hash(&source, &target, &relation, &evidence);
-        
-        Self {
+        let mut link = Self {
             source,
             target,
             relation,
             confidence: 1.0, // Production requires 1.0
             evidence,
-            hash,
-        }
+            hash: String::new(),
+            hash_version: FIELD_HASH_VERSION,
+        };
+        link.hash = link.compute_hash().expect("hash version is supported");
+        link
     }
     
-    fn compute_hash(
-        source: &str,
-        target: &str,
-        relation: &CausalRelation,
-        evidence: &[String],
-    ) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(source.as_bytes());
-        hasher.update(target.as_bytes());
-        hasher.update(format!("{:?}", relation).as_bytes());
-        for e in evidence {
-            hasher.update(e.as_bytes());
+    /// Hash under the rules of `self.hash_version` (`None` for an unknown version)
+    fn compute_hash(&self) -> Option<String> {
+        let relation = format!("{:?}", self.relation);
+        match self.hash_version {
+            1 => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.source.as_bytes());
+                hasher.update(self.target.as_bytes());
+                hasher.update(relation.as_bytes());
+                for e in &self.evidence {
+                    hasher.update(e.as_bytes());
+                }
+                Some(hex::encode(hasher.finalize()))
+            }
+            2 => Some(
+                FieldHasher::new("sap4d.causal-link")
+                    .str(&self.source)
+                    .str(&self.target)
+                    .str(&relation)
+                    .list(&self.evidence)
+                    .finish(),
+            ),
+            _ => None,
         }
-        hex::encode(hasher.finalize())
     }
     
     /// Verify the link's integrity
     pub fn verify_integrity(&self) -> bool {
-        let computed = Self::compute_hash(&self.source, &self.target, &self.relation, &self.evidence);
-        computed == self.hash
+        self.compute_hash().as_deref() == Some(self.hash.as_str())
     }
     
     /// Check if this link represents a contradiction
@@ -93,6 +179,7 @@ impl CausalLink {
 }
 
 /// A complete causal chain from observations to claim
+#[cfg(feature = "engine")]
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CausalChain {
     /// The claim being proven
@@ -107,6 +194,7 @@ pub struct CausalChain {
     pub chain_hash: String,
 }
 
+#[cfg(feature = "engine")]
 impl CausalChain {
     /// Create a new empty causal chain
     pub fn new(claim: impl Into<String>, observations: Vec<String>) -> Self {
@@ -161,6 +249,17 @@ impl CausalChain {
         Ok(())
     }
     
+    /// Record a `Contradicts` link, invalidating the chain
+    ///
+    /// Unlike [`CausalChain::add_link`], which refuses contradictions, this
+    /// keeps the link so `contradiction_measure` counts it.
+    pub fn add_contradiction(&mut self, link: CausalLink) {
+        debug_assert!(link.is_contradiction());
+        self.is_valid = false;
+        self.links.push(link);
+        self.recompute_hash();
+    }
+    
     fn recompute_hash(&mut self) {
         let mut hasher = Sha256::new();
         hasher.update(self.claim.as_bytes());
@@ -229,24 +328,244 @@ impl CausalChain {
     /// Convert chain to string representation
     pub fn to_string_chain(&self) -> Vec<String> {
         self.links.iter().map(|l| {
-            let rel = match l.relation {
-                CausalRelation::Causes => "→",
-                CausalRelation::CausedBy => "←",
-                CausalRelation::CorrelatedWith => "~",
-                CausalRelation::Implies => "⟹",
-                CausalRelation::Equivalent => "⟺",
-                CausalRelation::Contradicts => "⊥",
-            };
-            format!("{} {} {}", l.source, rel, l.target)
+            format!("{} {} {}", l.source, l.relation.symbol(), l.target)
         }).collect()
     }
+    
+    /// Render the chain as a GraphViz DOT digraph
+    ///
+    /// Observations are boxes, the claim is a double circle and any other
+    /// link endpoint is an ellipse. Edges are labeled with the relation
+    /// symbol; contradiction edges are drawn in red.
+    pub fn to_dot(&self) -> String {
+        let mut nodes: Vec<(&str, &'static str)> = Vec::new();
+        for obs in &self.observations {
+            dot_node(&mut nodes, obs, "box");
+        }
+        let claim_id = dot_node(&mut nodes, &self.claim, "doublecircle");
+        nodes[claim_id].1 = "doublecircle";
+        
+        let edges: Vec<(usize, usize, &CausalLink)> = self.links.iter().map(|l| {
+            let source = dot_node(&mut nodes, &l.source, "ellipse");
+            let target = dot_node(&mut nodes, &l.target, "ellipse");
+            (source, target, l)
+        }).collect();
+        
+        let mut dot = String::from("digraph causal_chain {\n    rankdir=LR;\n");
+        for (i, (text, shape)) in nodes.iter().enumerate() {
+            dot.push_str(&format!(
+                "    n{} [label=\"{}\", shape={}];\n",
+                i,
+                dot_escape(text),
+                shape
+            ));
+        }
+        for (source, target, link) in edges {
+            let color = if link.is_contradiction() { ", color=red, fontcolor=red" } else { "" };
+            dot.push_str(&format!(
+                "    n{} -> n{} [label=\"{}\"{}];\n",
+                source,
+                target,
+                dot_escape(link.relation.symbol()),
+                color
+            ));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Why two evidence items contradict each other
+#[cfg(feature = "engine")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContradictionKind {
+    /// One item negates the other
+    Negation,
+    /// Different values stated for the same subject
+    Numeric,
+}
+
+/// A pair of contradicting evidence items (indices into the evidence list)
+#[cfg(feature = "engine")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contradiction {
+    pub first: usize,
+    pub second: usize,
+    pub kind: ContradictionKind,
+}
+
+/// Pairwise detector for contradicting evidence
+///
+/// Each item is lowercased and reduced to a core statement plus a polarity
+/// by stripping one negation pattern: a prefix ("not …"), a suffix
+/// ("… is false") or an infix rewrite ("… is not …" → "… is …"). Items with
+/// the same core and opposite polarity contradict. Positive items of the
+/// form "<subject> <copula> <number> [unit]" contradict when the subject and
+/// unit match but the numbers differ.
+#[cfg(feature = "engine")]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ContradictionDetector {
+    /// Prefixes that negate the rest of the statement
+    pub negation_prefixes: Vec<String>,
+    /// Suffixes that negate the preceding statement
+    pub negation_suffixes: Vec<String>,
+    /// Suffixes that affirm the preceding statement
+    pub affirmation_suffixes: Vec<String>,
+    /// Negated infix and its positive form
+    pub negation_infixes: Vec<(String, String)>,
+    /// Verbs separating a subject from a numeric value
+    pub copulas: Vec<String>,
+}
+
+#[cfg(feature = "engine")]
+impl Default for ContradictionDetector {
+    fn default() -> Self {
+        let strings = |items: &[&str]| items.iter().map(|s| s.to_string()).collect();
+        Self {
+            negation_prefixes: strings(&["it is not the case that ", "it is false that ", "not "]),
+            negation_suffixes: strings(&[" is false", " is not true", " is untrue"]),
+            affirmation_suffixes: strings(&[" is true"]),
+            negation_infixes: [
+                (" is not ", " is "),
+                (" isn't ", " is "),
+                (" are not ", " are "),
+                (" aren't ", " are "),
+                (" was not ", " was "),
+                (" wasn't ", " was "),
+                (" were not ", " were "),
+                (" does not ", " does "),
+                (" doesn't ", " does "),
+                (" did not ", " did "),
+                (" didn't ", " did "),
+                (" cannot ", " can "),
+                (" can't ", " can "),
+                (" has not ", " has "),
+                (" hasn't ", " has "),
+                (" never ", " "),
+            ]
+            .iter()
+            .map(|(negated, positive)| (negated.to_string(), positive.to_string()))
+            .collect(),
+            copulas: strings(&[" is ", " are ", " was ", " were ", " equals ", " = "]),
+        }
+    }
+}
+
+#[cfg(feature = "engine")]
+impl ContradictionDetector {
+    /// Detector with the default patterns
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Add a negating prefix (lowercase, including trailing space)
+    pub fn with_negation_prefix(mut self, prefix: impl Into<String>) -> Self {
+        self.negation_prefixes.push(prefix.into());
+        self
+    }
+    
+    /// Add a negating suffix (lowercase, including leading space)
+    pub fn with_negation_suffix(mut self, suffix: impl Into<String>) -> Self {
+        self.negation_suffixes.push(suffix.into());
+        self
+    }
+    
+    /// Add a negated infix and the positive text it replaces
+    pub fn with_negation_infix(mut self, negated: impl Into<String>, positive: impl Into<String>) -> Self {
+        self.negation_infixes.push((negated.into(), positive.into()));
+        self
+    }
+    
+    /// Every contradicting pair in `observations`, ordered by first index
+    pub fn detect(&self, observations: &[String]) -> Vec<Contradiction> {
+        let mut by_core: HashMap<String, Vec<(usize, bool)>> = HashMap::new();
+        let mut by_subject: HashMap<(String, String), Vec<(usize, f64)>> = HashMap::new();
+        
+        for (index, obs) in observations.iter().enumerate() {
+            let (core, negated) = self.polarize(&canonical(obs));
+            if !negated {
+                if let Some((subject, value, unit)) = self.numeric(&core) {
+                    by_subject.entry((subject, unit)).or_default().push((index, value));
+                }
+            }
+            by_core.entry(core).or_default().push((index, negated));
+        }
+        
+        let mut found = Vec::new();
+        for items in by_core.values() {
+            for (i, &(first, a)) in items.iter().enumerate() {
+                for &(second, b) in &items[i + 1..] {
+                    if a != b {
+                        found.push(Contradiction { first, second, kind: ContradictionKind::Negation });
+                    }
+                }
+            }
+        }
+        for items in by_subject.values() {
+            for (i, &(first, a)) in items.iter().enumerate() {
+                for &(second, b) in &items[i + 1..] {
+                    if a != b {
+                        found.push(Contradiction { first, second, kind: ContradictionKind::Numeric });
+                    }
+                }
+            }
+        }
+        
+        found.sort_by_key(|c| (c.first, c.second));
+        found
+    }
+    
+    /// Strip one negation or affirmation pattern, returning (core, negated)
+    fn polarize(&self, text: &str) -> (String, bool) {
+        for prefix in &self.negation_prefixes {
+            if let Some(rest) = text.strip_prefix(prefix.as_str()) {
+                return (rest.to_string(), true);
+            }
+        }
+        for suffix in &self.negation_suffixes {
+            if let Some(rest) = text.strip_suffix(suffix.as_str()) {
+                return (rest.to_string(), true);
+            }
+        }
+        for suffix in &self.affirmation_suffixes {
+            if let Some(rest) = text.strip_suffix(suffix.as_str()) {
+                return (rest.to_string(), false);
+            }
+        }
+        for (negated, positive) in &self.negation_infixes {
+            if text.contains(negated.as_str()) {
+                return (text.replacen(negated.as_str(), positive, 1), true);
+            }
+        }
+        (text.to_string(), false)
+    }
+    
+    /// Split "<subject> <copula> <number> [unit]" into (subject, value, unit)
+    fn numeric(&self, text: &str) -> Option<(String, f64, String)> {
+        self.copulas.iter().find_map(|copula| {
+            let (subject, rest) = text.rsplit_once(copula.as_str())?;
+            let mut words = rest.split_whitespace();
+            let value: f64 = words.next()?.parse().ok()?;
+            let unit = words.collect::<Vec<_>>().join(" ");
+            Some((format!("{}{}", subject, copula.trim_end()), value, unit))
+        })
+    }
+}
+
+/// Lowercase, collapse whitespace and drop trailing punctuation
+#[cfg(feature = "engine")]
+fn canonical(text: &str) -> String {
+    let text = text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase();
+    text.trim_end_matches(['.', '!', ';']).to_string()
 }
 
 /// Builder for constructing causal chains
+#[cfg(feature = "engine")]
 pub struct CausalChainBuilder {
     chain: CausalChain,
 }
 
+#[cfg(feature = "engine")]
 impl CausalChainBuilder {
     /// Create a new builder
     pub fn new(claim: impl Into<String>) -> Self {
@@ -280,6 +599,18 @@ impl CausalChainBuilder {
         Ok(self)
     }
     
+    /// Record a contradiction between two observations
+    pub fn with_contradiction(
+        mut self,
+        source: impl Into<String>,
+        target: impl Into<String>,
+        evidence: Vec<String>,
+    ) -> Self {
+        let link = CausalLink::new(source, target, CausalRelation::Contradicts, evidence);
+        self.chain.add_contradiction(link);
+        self
+    }
+    
     /// Build the chain
     pub fn build(mut self) -> Result<CausalChain> {
         self.chain.recompute_hash();
@@ -292,7 +623,7 @@ impl CausalChainBuilder {
     }
 }
 
-#[cfg(test)]
+#[cfg(all(test, feature = "engine"))]
 mod tests {
     use super::*;
     
@@ -358,5 +689,95 @@ mod tests {
         
         assert!(link.is_contradiction());
     }
+    
+    #[test]
+    fn test_dot_escape() {
+        assert_eq!(dot_escape(r#"say "hi""#), r#"say \"hi\""#);
+        assert_eq!(dot_escape("a\\b"), r"a\\b");
+        assert_eq!(dot_escape("line 1\r\nline 2"), r"line 1\nline 2");
+    }
+    
+    #[test]
+    fn test_to_dot() {
+        let mut chain = CausalChainBuilder::new("the \"claim\"")
+            .with_observation("fact A")
+            .with_link(
+                "fact A",
+                "the \"claim\"",
+                CausalRelation::Implies,
+                vec![],
+            )
+            .unwrap()
+            .build()
+            .unwrap();
+        chain.links.push(CausalLink::new("fact A", "not A", CausalRelation::Contradicts, vec![]));
+        
+        let dot = chain.to_dot();
+        
+        assert!(dot.starts_with("digraph causal_chain {"));
+        assert!(dot.contains(r#"n0 [label="fact A", shape=box];"#));
+        assert!(dot.contains(r#"n1 [label="the \"claim\"", shape=doublecircle];"#));
+        assert!(dot.contains(r#"n2 [label="not A", shape=ellipse];"#));
+        assert!(dot.contains(r#"n0 -> n1 [label="⟹"];"#));
+        assert!(dot.contains(r#"n0 -> n2 [label="⊥", color=red, fontcolor=red];"#));
+    }
+    
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+    
+    #[test]
+    fn test_detects_negations() {
+        let detector = ContradictionDetector::new();
+        let found = detector.detect(&strings(&[
+            "X is true",
+            "The door is open.",
+            "X is false",
+            "The door is not open",
+            "Water is wet",
+        ]));
+        
+        assert_eq!(found, vec![
+            Contradiction { first: 0, second: 2, kind: ContradictionKind::Negation },
+            Contradiction { first: 1, second: 3, kind: ContradictionKind::Negation },
+        ]);
+        assert!(detector.detect(&strings(&["Not the server is up", "The server is up"])).len() == 1);
+    }
+    
+    #[test]
+    fn test_detects_numeric_conflicts() {
+        let detector = ContradictionDetector::new();
+        let found = detector.detect(&strings(&[
+            "The count is 5",
+            "The total is 5",
+            "the count is 7",
+            "The count is 5.0",
+            "The length is 5 cm",
+            "The length is 5 m",
+        ]));
+        
+        assert_eq!(found, vec![
+            Contradiction { first: 0, second: 2, kind: ContradictionKind::Numeric },
+            Contradiction { first: 2, second: 3, kind: ContradictionKind::Numeric },
+        ]);
+    }
+    
+    #[test]
+    fn test_custom_negation_pattern() {
+        let evidence = strings(&["The build passed", "The build failed"]);
+        assert!(ContradictionDetector::new().detect(&evidence).is_empty());
+        
+        let detector = ContradictionDetector::new().with_negation_infix(" failed", " passed");
+        assert_eq!(detector.detect(&evidence).len(), 1);
+    }
+    
+    #[test]
+    fn test_recorded_contradiction_breaks_c_zero() {
+        let result = CausalChainBuilder::new("conclusion")
+            .with_observations(strings(&["X is true", "X is false"]))
+            .with_contradiction("X is true", "X is false", vec![])
+            .build();
+        
+        assert!(matches!(result, Err(ProofError::InvarianceViolation)));
+    }
 }
-
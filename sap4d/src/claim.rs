@@ -0,0 +1,441 @@
+//! Compound claims: conjunction and disjunction over sub-claims
+//!
+//! Text claims are split on a top-level "and" / "or". Anything the parser
+//! cannot read unambiguously is refused with [`ProofError::AmbiguousClaim`];
+//! callers then use the structured JSON form, e.g.
+//! `{"all": ["Server A is up", {"any": ["Backup B is fresh", "Backup C is fresh"]}]}`.
+//!
+//! A [`Claim`] may instead quantify over a finite domain, e.g.
+//! `{"variable": "d", "domain_values": ["web-1", "web-2"], "template": "Deployment {d} passed checks"}`,
+//! which holds when every instantiation of the template does.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use std::fmt;
+
+use crate::{ProofError, Result};
+
+/// Words that make a connective's scope unclear in natural language
+const AMBIGUOUS_WORDS: &[&str] = &["either", "neither", "nor", "both", "and/or"];
+
+/// A claim built from sub-claims
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum ClaimExpr {
+    /// A single claim
+    Atom(String),
+    /// Every sub-claim must hold
+    All { all: Vec<ClaimExpr> },
+    /// At least one sub-claim must hold
+    Any { any: Vec<ClaimExpr> },
+}
+
+impl ClaimExpr {
+    /// Parse a text claim or the structured JSON form (input starting with `{`)
+    pub fn parse(input: &str) -> Result<Self> {
+        let input = input.trim();
+        let expr = if input.starts_with('{') {
+            serde_json::from_str(input)?
+        } else {
+            Self::parse_text(input)?
+        };
+        expr.validate()?;
+        Ok(expr)
+    }
+
+    fn parse_text(input: &str) -> Result<Self> {
+        let words: Vec<&str> = input.split_whitespace().collect();
+        let lower: Vec<String> = words.iter().map(|w| w.to_lowercase()).collect();
+
+        let has = |word: &str| lower.iter().any(|w| w == word);
+        let (has_and, has_or) = (has("and"), has("or"));
+
+        if let Some(word) = AMBIGUOUS_WORDS.iter().find(|w| has(w)) {
+            if has_and || has_or || *word == "and/or" {
+                return Err(ambiguous(input, &format!("'{}' changes the connective's meaning", word)));
+            }
+        }
+        if !has_and && !has_or {
+            return Ok(Self::Atom(words.join(" ")));
+        }
+        if has_and && has_or {
+            return Err(ambiguous(input, "mixes 'and' with 'or'; precedence is unclear"));
+        }
+        if input.contains(',') || input.contains(';') {
+            return Err(ambiguous(input, "lists need the structured form"));
+        }
+        if input.contains('(') || input.contains(')') {
+            return Err(ambiguous(input, "grouping needs the structured form"));
+        }
+
+        let connective = if has_and { "and" } else { "or" };
+        let mut operands = vec![Vec::new()];
+        for (word, lower) in words.iter().zip(&lower) {
+            if lower == connective {
+                operands.push(Vec::new());
+            } else {
+                operands.last_mut().expect("at least one operand").push(*word);
+            }
+        }
+
+        // A one-word operand ("Debits and credits balance") is a coordinated
+        // phrase, not a clause
+        if let Some(short) = operands.iter().find(|o| o.len() < 2) {
+            let operand = short.join(" ");
+            return Err(ambiguous(
+                input,
+                &format!("operand '{}' is not a complete claim", operand),
+            ));
+        }
+
+        let operands = operands.into_iter().map(|o| Self::Atom(o.join(" "))).collect();
+        Ok(if has_and {
+            Self::All { all: operands }
+        } else {
+            Self::Any { any: operands }
+        })
+    }
+
+    fn validate(&self) -> Result<()> {
+        match self {
+            Self::Atom(claim) if claim.trim().is_empty() => {
+                Err(ProofError::AmbiguousClaim("empty sub-claim".to_string()))
+            }
+            Self::Atom(_) => Ok(()),
+            Self::All { all: operands } | Self::Any { any: operands } => {
+                if operands.is_empty() {
+                    return Err(ProofError::AmbiguousClaim(format!(
+                        "'{}' has no sub-claims",
+                        self.connective()
+                    )));
+                }
+                operands.iter().try_for_each(Self::validate)
+            }
+        }
+    }
+
+    fn connective(&self) -> &'static str {
+        match self {
+            Self::Atom(_) => "",
+            Self::All { .. } => "all",
+            Self::Any { .. } => "any",
+        }
+    }
+
+    /// Whether this is a single claim
+    pub fn is_atom(&self) -> bool {
+        matches!(self, Self::Atom(_))
+    }
+
+    /// Atomic sub-claims, left to right
+    pub fn atoms(&self) -> Vec<&str> {
+        match self {
+            Self::Atom(claim) => vec![claim.as_str()],
+            Self::All { all: operands } | Self::Any { any: operands } => {
+                operands.iter().flat_map(Self::atoms).collect()
+            }
+        }
+    }
+
+    fn fmt_nested(&self, f: &mut fmt::Formatter<'_>, nested: bool) -> fmt::Result {
+        let (operands, symbol) = match self {
+            Self::Atom(claim) => return write!(f, "{}", claim),
+            Self::All { all } => (all, " ∧ "),
+            Self::Any { any } => (any, " ∨ "),
+        };
+
+        if nested {
+            write!(f, "(")?;
+        }
+        for (i, operand) in operands.iter().enumerate() {
+            if i > 0 {
+                write!(f, "{}", symbol)?;
+            }
+            operand.fmt_nested(f, true)?;
+        }
+        if nested {
+            write!(f, ")")?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Display for ClaimExpr {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.fmt_nested(f, false)
+    }
+}
+
+fn ambiguous(input: &str, reason: &str) -> ProofError {
+    ProofError::AmbiguousClaim(format!(
+        "'{}' {}; use the structured form {{\"all\": [...]}} or {{\"any\": [...]}}",
+        input, reason
+    ))
+}
+
+/// What happened to one atomic sub-claim
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SubClaimStatus {
+    Proven,
+    Failed,
+    /// Not evaluated (an earlier conjunct failed or disjunct carried)
+    Skipped,
+}
+
+/// Outcome for one atomic sub-claim
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SubClaimOutcome {
+    pub claim: String,
+    pub status: SubClaimStatus,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+/// Compound proof metadata recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct CompoundRecord {
+    /// The compound claim
+    pub expr: ClaimExpr,
+    /// Per-sub-claim outcomes in evaluation order
+    pub outcomes: Vec<SubClaimOutcome>,
+    /// For each proven disjunction, the disjunct that carried it
+    pub carried_by: Vec<String>,
+}
+
+/// A claim that may quantify over a finite domain
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum Claim {
+    /// A single claim
+    Atomic(String),
+    /// `template` holds with `{variable}` replaced by each domain value
+    ForAll {
+        variable: String,
+        domain_values: Vec<String>,
+        template: String,
+    },
+}
+
+impl Claim {
+    /// Parse the JSON form: a string, or an object with `variable`,
+    /// `domain_values` and `template`
+    pub fn from_json(json: &str) -> Result<Self> {
+        let claim: Self = serde_json::from_str(json)?;
+        claim.validate()?;
+        Ok(claim)
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        match self {
+            Self::Atomic(claim) if claim.trim().is_empty() => {
+                Err(ProofError::AmbiguousClaim("empty claim".to_string()))
+            }
+            Self::Atomic(_) => Ok(()),
+            Self::ForAll { variable, domain_values, template } => {
+                if variable.trim().is_empty() || variable.contains(['{', '}']) {
+                    return Err(ProofError::AmbiguousClaim(format!("invalid variable name '{}'", variable)));
+                }
+                if !template.contains(&placeholder(variable)) {
+                    return Err(ProofError::AmbiguousClaim(format!(
+                        "template '{}' does not mention {}",
+                        template,
+                        placeholder(variable)
+                    )));
+                }
+                if domain_values.is_empty() {
+                    return Err(ProofError::AmbiguousClaim(format!("'{}' has an empty domain", self)));
+                }
+                let mut seen = HashSet::new();
+                if let Some(value) = domain_values.iter().find(|value| !seen.insert(value.as_str())) {
+                    return Err(ProofError::AmbiguousClaim(format!("domain value '{}' is listed twice", value)));
+                }
+                Ok(())
+            }
+        }
+    }
+
+    /// The claims to prove: one per domain value, in domain order
+    pub fn instances(&self) -> Vec<String> {
+        match self {
+            Self::Atomic(claim) => vec![claim.clone()],
+            Self::ForAll { variable, domain_values, template } => domain_values
+                .iter()
+                .map(|value| instantiate(template, variable, value))
+                .collect(),
+        }
+    }
+}
+
+impl fmt::Display for Claim {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Atomic(claim) => write!(f, "{}", claim),
+            Self::ForAll { variable, domain_values, template } => {
+                write!(f, "∀ {} ∈ {{{}}}: {}", variable, domain_values.join(", "), template)
+            }
+        }
+    }
+}
+
+fn placeholder(variable: &str) -> String {
+    format!("{{{}}}", variable)
+}
+
+/// `template` with every `{variable}` replaced by `value`
+pub(crate) fn instantiate(template: &str, variable: &str, value: &str) -> String {
+    template.replace(&placeholder(variable), value)
+}
+
+/// One instantiation of a quantified claim and its sub-proof
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct InstanceProof {
+    /// The domain value substituted
+    pub value: String,
+    /// The instantiated claim
+    pub claim: String,
+    /// Hash of the causal chain proving `claim`
+    pub proof_hash: String,
+}
+
+/// Quantified proof metadata recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct QuantifiedRecord {
+    pub variable: String,
+    pub template: String,
+    /// One sub-proof per domain value, in domain order
+    pub instances: Vec<InstanceProof>,
+    /// SHA-256 over the instances' proof hashes, in order
+    pub aggregate_hash: String,
+}
+
+impl QuantifiedRecord {
+    pub fn new(variable: impl Into<String>, template: impl Into<String>, instances: Vec<InstanceProof>) -> Self {
+        let aggregate_hash = Self::aggregate(&instances);
+        Self {
+            variable: variable.into(),
+            template: template.into(),
+            instances,
+            aggregate_hash,
+        }
+    }
+
+    /// The quantified claim this record proves
+    pub fn claim(&self) -> Claim {
+        Claim::ForAll {
+            variable: self.variable.clone(),
+            domain_values: self.instances.iter().map(|instance| instance.value.clone()).collect(),
+            template: self.template.clone(),
+        }
+    }
+
+    fn aggregate(instances: &[InstanceProof]) -> String {
+        let mut hasher = Sha256::new();
+        for instance in instances {
+            hasher.update(instance.proof_hash.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Whether the aggregate matches the instances and each instance is
+    /// the template instantiated with its value
+    pub fn verify_aggregate(&self) -> bool {
+        self.aggregate_hash == Self::aggregate(&self.instances)
+            && self
+                .instances
+                .iter()
+                .all(|instance| instance.claim == instantiate(&self.template, &self.variable, &instance.value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn atom(claim: &str) -> ClaimExpr {
+        ClaimExpr::Atom(claim.to_string())
+    }
+
+    #[test]
+    fn test_parse_connectives() {
+        assert_eq!(ClaimExpr::parse("Server A is up").unwrap(), atom("Server A is up"));
+        assert_eq!(
+            ClaimExpr::parse("Server A is up and Server B is up AND Server C is up").unwrap(),
+            ClaimExpr::All { all: vec![atom("Server A is up"), atom("Server B is up"), atom("Server C is up")] }
+        );
+        assert_eq!(
+            ClaimExpr::parse("The backup is fresh or the replica is current").unwrap(),
+            ClaimExpr::Any { any: vec![atom("The backup is fresh"), atom("the replica is current")] }
+        );
+
+        let structured = ClaimExpr::parse(r#"{"all": ["Server A is up", {"any": ["B is fresh", "C is fresh"]}]}"#).unwrap();
+        assert_eq!(structured.to_string(), "Server A is up ∧ (B is fresh ∨ C is fresh)");
+        assert_eq!(structured.atoms(), vec!["Server A is up", "B is fresh", "C is fresh"]);
+    }
+
+    #[test]
+    fn test_ambiguous_text_refused() {
+        for input in [
+            "Debits and credits balance",
+            "Server A is up and Server B is up or Server C is up",
+            "Either the backup is fresh or the replica is current",
+            "Server A, Server B and Server C are up",
+            "Server A is up and/or Server B is up",
+            "Server A is up and",
+        ] {
+            match ClaimExpr::parse(input) {
+                Err(ProofError::AmbiguousClaim(message)) => assert!(message.contains("structured form"), "{}", message),
+                other => panic!("'{}' should be refused, got {:?}", input, other),
+            }
+        }
+
+        assert!(matches!(ClaimExpr::parse(r#"{"all": []}"#), Err(ProofError::AmbiguousClaim(_))));
+    }
+
+    #[test]
+    fn test_quantified_claim_json() {
+        let claim = Claim::from_json(
+            r#"{"variable": "d", "domain_values": ["web-1", "web-2"], "template": "Deployment {d} passed checks"}"#,
+        )
+        .unwrap();
+        assert_eq!(claim.instances(), vec!["Deployment web-1 passed checks", "Deployment web-2 passed checks"]);
+        assert_eq!(claim.to_string(), "∀ d ∈ {web-1, web-2}: Deployment {d} passed checks");
+        assert_eq!(Claim::from_json(&serde_json::to_string(&claim).unwrap()).unwrap(), claim);
+
+        let atomic = Claim::from_json(r#""Server A is up""#).unwrap();
+        assert_eq!(atomic, Claim::Atomic("Server A is up".to_string()));
+        assert_eq!(atomic.instances(), vec!["Server A is up"]);
+
+        for invalid in [
+            r#"{"variable": "d", "domain_values": [], "template": "Deployment {d} passed"}"#,
+            r#"{"variable": "d", "domain_values": ["a"], "template": "Deployment passed"}"#,
+            r#"{"variable": "d", "domain_values": ["a", "a"], "template": "Deployment {d} passed"}"#,
+            r#"{"variable": "", "domain_values": ["a"], "template": "Deployment {} passed"}"#,
+            r#""  ""#,
+        ] {
+            assert!(matches!(Claim::from_json(invalid), Err(ProofError::AmbiguousClaim(_))), "{}", invalid);
+        }
+    }
+
+    #[test]
+    fn test_quantified_record_aggregate() {
+        let instance = |value: &str, hash: &str| InstanceProof {
+            value: value.to_string(),
+            claim: format!("Deployment {} passed", value),
+            proof_hash: hash.to_string(),
+        };
+        let record = QuantifiedRecord::new("d", "Deployment {d} passed", vec![instance("a", "01"), instance("b", "02")]);
+        assert!(record.verify_aggregate());
+
+        let mut reordered = record.clone();
+        reordered.instances.reverse();
+        assert!(!reordered.verify_aggregate());
+
+        let mut renamed = record;
+        renamed.instances[0].claim = "Deployment c passed".to_string();
+        assert!(!renamed.verify_aggregate());
+    }
+}
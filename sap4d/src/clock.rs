@@ -0,0 +1,52 @@
+//! Timestamp source for traces and receipts
+//!
+//! Production code reads the system clock. Tests and golden-hash harnesses
+//! can freeze time on the current thread so receipt hashes are reproducible.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use std::cell::Cell;
+
+thread_local! {
+    static FROZEN: Cell<Option<DateTime<Utc>>> = const { Cell::new(None) };
+}
+
+/// Current time (frozen time if set on this thread)
+pub fn now() -> DateTime<Utc> {
+    FROZEN.with(|frozen| frozen.get()).unwrap_or_else(Utc::now)
+}
+
+/// Freeze time on this thread until the guard is dropped
+pub fn freeze(at: DateTime<Utc>) -> FrozenClock {
+    let previous = FROZEN.with(|frozen| frozen.replace(Some(at)));
+    FrozenClock { previous }
+}
+
+/// Guard restoring the previous clock on drop
+#[must_use = "time is unfrozen when the guard is dropped"]
+pub struct FrozenClock {
+    previous: Option<DateTime<Utc>>,
+}
+
+impl Drop for FrozenClock {
+    fn drop(&mut self) {
+        FROZEN.with(|frozen| frozen.set(self.previous));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_freeze_and_restore() {
+        let at = DateTime::parse_from_rfc3339("2025-01-01T00:00:00Z").unwrap().with_timezone(&Utc);
+
+        {
+            let _guard = freeze(at);
+            assert_eq!(now(), at);
+        }
+        assert_ne!(now(), at);
+    }
+}
@@ -0,0 +1,393 @@
+//! Continuous verification daemon
+//!
+//! Watches a directory for `*.json` proof jobs, proves them with bounded
+//! concurrency, writes receipts and appends each receipt hash to a
+//! persistent Merkle log. Completed job content hashes are recovered from
+//! the log on restart so no job is proved twice.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use chrono::{DateTime, Utc};
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::engine::ProofEngine;
+use crate::{ProofError, Result};
+
+/// Directory (under the watch dir) for completed jobs
+pub const DONE_DIR: &str = "done";
+
+/// Directory (under the watch dir) for failed jobs
+pub const FAILED_DIR: &str = "failed";
+
+/// Status file name (under the output dir)
+pub const STATUS_FILE: &str = "status.json";
+
+/// A proof job file
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProofJob {
+    /// The claim to prove
+    pub claim: String,
+    /// Evidence items
+    pub evidence: Vec<String>,
+}
+
+/// Daemon configuration
+#[derive(Debug, Clone)]
+pub struct DaemonConfig {
+    /// Directory polled for job files
+    pub watch_dir: PathBuf,
+    /// Directory receipts are written to
+    pub output_dir: PathBuf,
+    /// Persistent Merkle log path
+    pub log_path: PathBuf,
+    /// Maximum jobs proved in parallel
+    pub concurrency: usize,
+    /// Poll interval between directory scans
+    pub poll_interval: Duration,
+}
+
+/// A single persisted log entry
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ReceiptLogEntry {
+    /// Entry index
+    pub index: u64,
+    /// Content hash of the job file
+    pub job_hash: String,
+    /// Hash of the produced receipt
+    pub receipt_hash: String,
+    /// Timestamp
+    pub timestamp: DateTime<Utc>,
+}
+
+/// Append-only, file-backed Merkle log of receipt hashes (JSON lines)
+#[derive(Debug)]
+pub struct ReceiptLog {
+    path: PathBuf,
+    entries: Vec<ReceiptLogEntry>,
+}
+
+impl ReceiptLog {
+    /// Open (or create) the log at `path`, replaying existing entries
+    ///
+    /// Every append ends with a newline, so text after the last newline is
+    /// an append torn by a crash. It is truncated away, since that entry's
+    /// job was never moved to `done` and will be proved again.
+    pub fn open(path: impl Into<PathBuf>) -> Result<Self> {
+        let path = path.into();
+        let mut entries = Vec::new();
+
+        if path.exists() {
+            let content = fs::read_to_string(&path).map_err(io_error)?;
+            let complete = content.rfind('\n').map_or(0, |i| i + 1);
+            if complete < content.len() {
+                tracing::warn!(path = %path.display(), "truncating torn final receipt log line");
+                let file = OpenOptions::new().write(true).open(&path).map_err(io_error)?;
+                file.set_len(complete as u64).map_err(io_error)?;
+                file.sync_data().map_err(io_error)?;
+            }
+            for line in content[..complete].lines().filter(|l| !l.trim().is_empty()) {
+                entries.push(serde_json::from_str(line)?);
+            }
+        }
+
+        Ok(Self { path, entries })
+    }
+
+    /// Append a receipt hash and flush it to disk
+    pub fn append(&mut self, job_hash: &str, receipt_hash: &str) -> Result<&ReceiptLogEntry> {
+        let entry = ReceiptLogEntry {
+            index: self.entries.len() as u64,
+            job_hash: job_hash.to_string(),
+            receipt_hash: receipt_hash.to_string(),
+            timestamp: Utc::now(),
+        };
+
+        let mut file = OpenOptions::new()
+            .create(true)
+            .append(true)
+            .open(&self.path)
+            .map_err(io_error)?;
+        writeln!(file, "{}", serde_json::to_string(&entry)?).map_err(io_error)?;
+        file.sync_data().map_err(io_error)?;
+
+        self.entries.push(entry);
+        Ok(self.entries.last().unwrap())
+    }
+
+    /// Merkle root over receipt hashes
+    pub fn root_hash(&self) -> Option<String> {
+        let leaves: Vec<String> = self.entries.iter().map(|e| e.receipt_hash.clone()).collect();
+        merkle_root(&leaves)
+    }
+
+    /// Whether a job with this content hash has already been logged
+    pub fn contains_job(&self, job_hash: &str) -> bool {
+        self.entries.iter().any(|e| e.job_hash == job_hash)
+    }
+
+    /// All entries
+    pub fn entries(&self) -> &[ReceiptLogEntry] {
+        &self.entries
+    }
+
+    /// Entry count
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if log is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+}
+
+/// Status snapshot written after every scan
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DaemonStatus {
+    /// Jobs proved and logged
+    pub processed: usize,
+    /// Jobs moved to the failed directory
+    pub failures: usize,
+    /// Current Merkle log length
+    pub log_length: usize,
+    /// Current Merkle log root
+    pub log_root: Option<String>,
+    /// Last update
+    pub updated_at: DateTime<Utc>,
+}
+
+/// Outcome of a single job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum JobOutcome {
+    /// Proved; receipt written and logged
+    Completed { job: String, receipt_hash: String },
+    /// Already logged in a previous run; moved without re-proving
+    AlreadyProcessed { job: String },
+    /// Malformed or unprovable; moved to failed with an error sidecar
+    Failed { job: String, error: String },
+}
+
+/// Continuous verification daemon
+pub struct Daemon<F> {
+    config: DaemonConfig,
+    engine: ProofEngine,
+    log: ReceiptLog,
+    sign_fn: F,
+    key_id: Option<String>,
+}
+
+impl<F> Daemon<F>
+where
+    F: Fn(&str) -> String + Sync,
+{
+    /// Create a daemon, recovering state from the persistent log
+    pub fn new(config: DaemonConfig, sign_fn: F) -> Result<Self> {
+        for dir in [
+            config.watch_dir.join(DONE_DIR),
+            config.watch_dir.join(FAILED_DIR),
+            config.output_dir.clone(),
+        ] {
+            fs::create_dir_all(dir).map_err(io_error)?;
+        }
+
+        let log = ReceiptLog::open(&config.log_path)?;
+
+        Ok(Self {
+            config,
+            engine: ProofEngine::new(),
+            log,
+            sign_fn,
+            key_id: None,
+        })
+    }
+
+    /// Record `key_id` as the key behind `sign_fn` in every receipt
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+
+    /// Process every pending job once and refresh the status file
+    pub fn run_once(&mut self) -> Result<Vec<JobOutcome>> {
+        let mut pending = self.pending_jobs()?;
+        pending.sort();
+
+        let mut outcomes = Vec::new();
+
+        for batch in pending.chunks(self.config.concurrency.max(1)) {
+            let engine = &self.engine;
+            let sign_fn = &self.sign_fn;
+            let log = &self.log;
+
+            let proved: Vec<(PathBuf, Proved)> = std::thread::scope(|scope| {
+                let handles: Vec<_> = batch
+                    .iter()
+                    .map(|path| scope.spawn(move || (path.clone(), prove_job(engine, log, path, sign_fn))))
+                    .collect();
+                handles.into_iter().map(|h| h.join().unwrap()).collect()
+            });
+
+            // Single writer: log, receipts and moves are applied in order
+            for (path, proved) in proved {
+                let job = file_name(&path);
+                let outcome = match proved {
+                    Proved::Receipt { job_hash, mut receipt } if !self.log.contains_job(&job_hash) => {
+                        receipt.key_id = self.key_id.clone();
+                        let stem = path.file_stem().unwrap_or_default().to_string_lossy();
+                        let receipt_path = self.config.output_dir.join(format!("{}.receipt.json", stem));
+                        fs::write(&receipt_path, receipt.to_json()?).map_err(io_error)?;
+                        self.log.append(&job_hash, &receipt.hash)?;
+                        self.move_to(&path, DONE_DIR)?;
+                        JobOutcome::Completed { job, receipt_hash: receipt.hash }
+                    }
+                    Proved::Receipt { .. } | Proved::Known => {
+                        self.move_to(&path, DONE_DIR)?;
+                        JobOutcome::AlreadyProcessed { job }
+                    }
+                    Proved::Error(error) => {
+                        let sidecar = self
+                            .config
+                            .watch_dir
+                            .join(FAILED_DIR)
+                            .join(format!("{}.error.json", job));
+                        let body = serde_json::json!({ "job": job, "error": error });
+                        fs::write(sidecar, serde_json::to_string_pretty(&body)?).map_err(io_error)?;
+                        self.move_to(&path, FAILED_DIR)?;
+                        JobOutcome::Failed { job, error }
+                    }
+                };
+                outcomes.push(outcome);
+            }
+        }
+
+        self.write_status()?;
+        Ok(outcomes)
+    }
+
+    /// Poll the watch directory forever
+    pub fn run(&mut self) -> Result<()> {
+        loop {
+            for outcome in self.run_once()? {
+                tracing::info!(?outcome, "daemon job processed");
+            }
+            std::thread::sleep(self.config.poll_interval);
+        }
+    }
+
+    /// Current status snapshot
+    pub fn status(&self) -> Result<DaemonStatus> {
+        let failures = fs::read_dir(self.config.watch_dir.join(FAILED_DIR))
+            .map_err(io_error)?
+            .filter_map(|e| e.ok())
+            .filter(|e| e.file_name().to_string_lossy().ends_with(".error.json"))
+            .count();
+
+        Ok(DaemonStatus {
+            processed: self.log.len(),
+            failures,
+            log_length: self.log.len(),
+            log_root: self.log.root_hash(),
+            updated_at: Utc::now(),
+        })
+    }
+
+    /// The persistent receipt log
+    pub fn log(&self) -> &ReceiptLog {
+        &self.log
+    }
+
+    fn write_status(&self) -> Result<()> {
+        let status = self.status()?;
+        let path = self.config.output_dir.join(STATUS_FILE);
+        let tmp = path.with_extension("json.tmp");
+        fs::write(&tmp, serde_json::to_string_pretty(&status)?).map_err(io_error)?;
+        fs::rename(tmp, path).map_err(io_error)
+    }
+
+    fn pending_jobs(&self) -> Result<Vec<PathBuf>> {
+        Ok(fs::read_dir(&self.config.watch_dir)
+            .map_err(io_error)?
+            .filter_map(|e| e.ok())
+            .map(|e| e.path())
+            .filter(|p| p.is_file() && p.extension().is_some_and(|ext| ext == "json"))
+            .collect())
+    }
+
+    fn move_to(&self, path: &Path, dir: &str) -> Result<()> {
+        let target = self.config.watch_dir.join(dir).join(file_name(path));
+        fs::rename(path, target).map_err(io_error)
+    }
+}
+
+/// Worker result for a single job file
+enum Proved {
+    /// Job content hash already present in the log
+    Known,
+    /// Freshly proved
+    Receipt { job_hash: String, receipt: Box<crate::Receipt> },
+    /// Unreadable, malformed or unprovable
+    Error(String),
+}
+
+fn prove_job(
+    engine: &ProofEngine,
+    log: &ReceiptLog,
+    path: &Path,
+    sign_fn: &(impl Fn(&str) -> String + Sync),
+) -> Proved {
+    let bytes = match fs::read(path) {
+        Ok(bytes) => bytes,
+        Err(e) => return Proved::Error(format!("Unreadable job: {}", e)),
+    };
+
+    let job_hash = hex::encode(Sha256::digest(&bytes));
+    if log.contains_job(&job_hash) {
+        return Proved::Known;
+    }
+
+    let job: ProofJob = match serde_json::from_slice(&bytes) {
+        Ok(job) => job,
+        Err(e) => return Proved::Error(format!("Malformed job: {}", e)),
+    };
+
+    match engine.prove(&job.claim, job.evidence, sign_fn) {
+        Ok((_, receipt)) => Proved::Receipt { job_hash, receipt: Box::new(receipt) },
+        Err(e) => Proved::Error(e.to_string()),
+    }
+}
+
+fn file_name(path: &Path) -> String {
+    path.file_name().unwrap_or_default().to_string_lossy().to_string()
+}
+
+fn io_error(err: std::io::Error) -> ProofError {
+    ProofError::Internal(format!("I/O error: {}", err))
+}
+
+/// Merkle root using the audit tree construction (duplicate-last padding)
+fn merkle_root(items: &[String]) -> Option<String> {
+    let hash = |data: &str| hex::encode(Sha256::digest(data.as_bytes()));
+
+    let mut level: Vec<String> = items.iter().map(|s| hash(s)).collect();
+    if level.is_empty() {
+        return None;
+    }
+
+    while level.len() > 1 && !level.len().is_power_of_two() {
+        level.push(level.last().unwrap().clone());
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| hash(&format!("{}{}", pair[0], pair[1])))
+            .collect();
+    }
+
+    level.pop()
+}
@@ -4,33 +4,129 @@
 //!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
+use crate::axioms::history::{Compatibility, SsotHistory};
 use crate::axioms::{Axiom, AxiomSet, OmegaSSoT};
-use crate::causal::{CausalChain, CausalChainBuilder, CausalRelation};
+use crate::causal::{CausalChain, CausalChainBuilder, CausalLink, CausalRelation, Contradiction, ContradictionDetector};
+use crate::claim::{instantiate, Claim, ClaimExpr, CompoundRecord, InstanceProof, QuantifiedRecord, SubClaimOutcome, SubClaimStatus};
+use crate::evidence::file::EvidenceFile;
+use crate::evidence::normalize::{EvidenceNormalization, NormalizedEvidence};
+use crate::evidence::Evidence;
+use crate::operation::{OperationRecorder, SubOperation};
 use crate::receipt::Receipt;
-use crate::trace::{TraceBuilder, TraceEnvelope};
+use crate::trace::{micros, StepRegistry, TraceBuilder, TraceEnvelope};
 use crate::{ProofError, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+use std::time::Instant;
+
+/// Words ignored when measuring evidence relevance
+pub const RELEVANCE_STOP_WORDS: &[&str] = &[
+    "a", "an", "and", "are", "as", "at", "be", "by", "for", "from", "has", "have",
+    "in", "is", "it", "its", "of", "on", "or", "that", "the", "this", "to", "was",
+    "were", "will", "with",
+];
+
+/// `predicate(claim, evidence)` as wrapped by `RelevancePredicate`
+type RelevanceFn = dyn Fn(&str, &str) -> bool + Send + Sync;
+
+/// Caller-supplied test of whether an evidence item bears on a claim
+#[derive(Clone)]
+pub struct RelevancePredicate(Arc<RelevanceFn>);
+
+impl RelevancePredicate {
+    /// Wrap `predicate(claim, evidence)`
+    pub fn new(predicate: impl Fn(&str, &str) -> bool + Send + Sync + 'static) -> Self {
+        Self(Arc::new(predicate))
+    }
+    
+    /// Whether `evidence` is relevant to `claim`
+    pub fn matches(&self, claim: &str, evidence: &str) -> bool {
+        (self.0)(claim, evidence)
+    }
+}
+
+impl std::fmt::Debug for RelevancePredicate {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("RelevancePredicate")
+    }
+}
 
 /// Configuration for the proof engine
 #[derive(Debug, Clone)]
 pub struct EngineConfig {
     /// Minimum explainability index required (default: 0.98)
     pub min_explainability: f64,
+    /// Weight of each trace operation in the explainability index (default 1.0)
+    pub explainability_weights: HashMap<String, f64>,
     /// Maximum causal chain length
     pub max_chain_length: usize,
     /// Whether to enforce strict C=0
     pub strict_c_zero: bool,
+    /// Link contradicting evidence pairs with `Contradicts` while building chains
+    pub detect_contradictions: bool,
+    /// Patterns used when `detect_contradictions` is set
+    pub contradiction_detector: ContradictionDetector,
+    /// Content words at least one evidence item must share with the claim
+    /// before the chain may imply it (default 0: no relevance check)
+    pub min_evidence_relevance: usize,
+    /// Also treat evidence as relevant when this accepts it
+    pub relevance_predicate: Option<RelevancePredicate>,
+    /// Stop a quantified proof at the first failing instantiation (default
+    /// true); otherwise try every one and report all failures
+    pub fail_fast: bool,
+    /// Non-empty evidence items a proof needs (default 0: no minimum)
+    pub min_evidence_count: usize,
+    /// Distinct `source_uri`s the evidence must come from (default 0)
+    pub min_distinct_sources: usize,
+    /// Deduplicate evidence that differs only in whitespace before building
+    /// the chain (default false). Receipts still list, and hash, the
+    /// evidence as supplied.
+    pub normalize_evidence: bool,
+    /// Also fold case when `normalize_evidence` is set
+    pub evidence_case_fold: bool,
 }
 
 impl Default for EngineConfig {
     fn default() -> Self {
         Self {
             min_explainability: 0.98,
+            explainability_weights: HashMap::new(),
             max_chain_length: 100,
             strict_c_zero: true,
+            detect_contradictions: false,
+            contradiction_detector: ContradictionDetector::default(),
+            min_evidence_relevance: 0,
+            relevance_predicate: None,
+            fail_fast: true,
+            min_evidence_count: 0,
+            min_distinct_sources: 0,
+            normalize_evidence: false,
+            evidence_case_fold: false,
         }
     }
 }
 
+/// Structured result of deep receipt verification
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReceiptVerification {
+    /// Receipt hash matches its contents
+    pub hash_ok: bool,
+    /// Signature verifies over the hash
+    pub signature_ok: bool,
+    /// Re-derived causal chain supports the claim, is C=0 and matches the receipt
+    pub chain_ok: bool,
+    /// Stored C=0 flag
+    pub c_zero: bool,
+}
+
+impl ReceiptVerification {
+    /// Whether every check passed
+    pub fn is_valid(&self) -> bool {
+        self.hash_ok && self.signature_ok && self.chain_ok && self.c_zero
+    }
+}
+
 /// The SAP-4D Proof Engine
 pub struct ProofEngine {
     /// Ω-SSOT containing core axioms
@@ -39,6 +135,8 @@ pub struct ProofEngine {
     domain_axioms: AxiomSet,
     /// Engine configuration
     config: EngineConfig,
+    /// Ω-SSOT versions receipts are checked against when verified
+    ssot_history: Option<SsotHistory>,
 }
 
 impl ProofEngine {
@@ -48,6 +146,7 @@ impl ProofEngine {
             omega_ssot: OmegaSSoT::new(),
             domain_axioms: AxiomSet::new(),
             config: EngineConfig::default(),
+            ssot_history: None,
         }
     }
     
@@ -57,9 +156,23 @@ impl ProofEngine {
             omega_ssot: OmegaSSoT::new(),
             domain_axioms: AxiomSet::new(),
             config,
+            ssot_history: None,
         }
     }
     
+    /// Use a custom Ω-SSOT (e.g. loaded from an axiom manifest)
+    pub fn with_ssot(mut self, ssot: OmegaSSoT) -> Self {
+        self.omega_ssot = ssot;
+        self
+    }
+    
+    /// Check on verification that a receipt's axioms are unchanged since the
+    /// snapshot in `history` it was issued under
+    pub fn with_ssot_history(mut self, history: SsotHistory) -> Self {
+        self.ssot_history = Some(history);
+        self
+    }
+    
     /// Add a domain-specific axiom
     pub fn add_axiom(&mut self, axiom: Axiom) {
         self.domain_axioms.add(axiom);
@@ -72,6 +185,18 @@ impl ProofEngine {
         axioms
     }
     
+    /// Domain axioms added with [`ProofEngine::add_axiom`]
+    pub fn domain_axioms(&self) -> &AxiomSet {
+        &self.domain_axioms
+    }
+    
+    /// Every Ω-SSOT and domain axiom a statement violates
+    pub fn check_violation(&self, statement: &str) -> Vec<&Axiom> {
+        let mut violated = self.omega_ssot.check_violation(statement);
+        violated.extend(self.domain_axioms.check_violation(statement));
+        violated
+    }
+    
     /// Prove a claim given observations
     pub fn prove(
         &self,
@@ -79,32 +204,470 @@ impl ProofEngine {
         observations: Vec<String>,
         sign_fn: impl FnOnce(&str) -> String,
     ) -> Result<(TraceEnvelope, Receipt)> {
+        let evidence = observations.into_iter().map(Evidence::new).collect();
+        self.prove_with_evidence(claim, evidence, sign_fn)
+    }
+    
+    /// Prove a claim given evidence with provenance
+    ///
+    /// The trace and receipt record the provenance (and their hashes cover
+    /// it) whenever any item has a source; bare evidence proves exactly as
+    /// [`ProofEngine::prove`] always has.
+    pub fn prove_with_evidence(
+        &self,
+        claim: &str,
+        evidence: Vec<Evidence>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        self.prove_recorded(claim, evidence, sign_fn, None)
+    }
+    
+    /// Prove a claim that builds on earlier receipts
+    ///
+    /// Each parent must recompute its hash and be C=0. Parent claims are
+    /// added ahead of `observations` as evidence sourced from the parent
+    /// receipt (`receipt:<hash>`), and the new receipt lists the parent
+    /// hashes in [`Receipt::parents`].
+    pub fn prove_chained(
+        &self,
+        claim: &str,
+        observations: Vec<String>,
+        parents: &[Receipt],
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        for parent in parents {
+            if !parent.verify_hash() {
+                return Err(ProofError::InvalidEvidence(format!(
+                    "Parent receipt {} failed hash verification",
+                    parent.hash
+                )));
+            }
+            if !parent.c_zero {
+                return Err(ProofError::InvalidEvidence(format!(
+                    "Parent receipt {} is not C=0",
+                    parent.hash
+                )));
+            }
+        }
+        
+        let inherited = parents.iter().map(|parent| {
+            Evidence::new(parent.claim.clone())
+                .with_source(format!("receipt:{}", parent.hash), parent.timestamp)
+                .with_content_hash(parent.hash.clone())
+        });
+        let evidence = inherited.chain(observations.into_iter().map(Evidence::new)).collect();
+        
+        // Signed once, after the parents are part of the hash
+        let (trace, mut receipt) = self.prove_recorded(claim, evidence, |_| String::new(), None)?;
+        receipt.link_parents(parents.iter().map(|parent| parent.hash.clone()).collect(), sign_fn);
+        Ok((trace, receipt))
+    }
+    
+    /// Prove a claim from evidence read out of `files`
+    ///
+    /// Proves exactly as [`ProofEngine::prove_with_evidence`] does, and the
+    /// receipt lists each file's path and SHA-256 in
+    /// [`Receipt::evidence_files`], so the files are part of its provenance.
+    pub fn prove_from_files(
+        &self,
+        claim: &str,
+        evidence: Vec<Evidence>,
+        files: Vec<EvidenceFile>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        // Signed once, after the files are part of the hash
+        let (trace, mut receipt) = self.prove_recorded(claim, evidence, |_| String::new(), None)?;
+        receipt.link_evidence_files(files, sign_fn);
+        Ok((trace, receipt))
+    }
+    
+    /// Prove a claim, recording each phase as a chained [`SubOperation`]
+    ///
+    /// The operations cover chain construction, the C=0 check, trace
+    /// generation and receipt creation, and can be passed straight to an
+    /// L3 audit.
+    pub fn prove_audited(
+        &self,
+        claim: &str,
+        observations: Vec<String>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt, Vec<SubOperation>)> {
+        let mut recorder = OperationRecorder::new();
+        let evidence = observations.into_iter().map(Evidence::new).collect();
+        let (trace, receipt) = self.prove_recorded(claim, evidence, sign_fn, Some(&mut recorder))?;
+        Ok((trace, receipt, recorder.into_operations()))
+    }
+    
+    fn prove_recorded(
+        &self,
+        claim: &str,
+        evidence: Vec<Evidence>,
+        sign_fn: impl FnOnce(&str) -> String,
+        mut recorder: Option<&mut OperationRecorder>,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        let started = Instant::now();
+        self.check_evidence_requirements(&evidence)?;
+        let provenance = Evidence::provenance_of(&evidence);
+        let observations: Vec<String> = evidence.into_iter().map(|e| e.statement).collect();
+        
         // Step 1: Build causal chain
-        let chain = self.build_causal_chain(claim, &observations)?;
+        let (chain, normalized) = self.build_normalized_chain(claim, &observations, self.evidence_normalization())?;
+        if let Some(recorder) = recorder.as_deref_mut() {
+            let input = std::iter::once(claim).chain(observations.iter().map(String::as_str));
+            recorder.record("build_causal_chain", input.collect::<Vec<_>>().join("\n"), &chain.chain_hash);
+        }
         
         // Step 2: Verify C=0
         if self.config.strict_c_zero && !chain.is_c_zero() {
             return Err(ProofError::InvarianceViolation);
         }
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.record("check_c_zero", &chain.chain_hash, format!("C={}", chain.contradiction_measure()));
+        }
         
         // Step 3: Generate trace
-        let trace = self.generate_trace(claim, &observations, &chain)?;
+        let mut trace = self.generate_trace_with(claim, &observations, &chain, None, normalized.as_ref())?;
+        if !provenance.is_empty() {
+            trace.set_provenance(provenance);
+        }
+        if let Some(recorder) = recorder.as_deref_mut() {
+            recorder.record("generate_trace", &chain.chain_hash, &trace.receipt_hash);
+        }
         
         // Step 4: Verify explainability
-        let explainability = trace.explainability_index();
-        if explainability < self.config.min_explainability {
-            return Err(ProofError::Internal(format!(
-                "Explainability index {} below minimum {}",
-                explainability, self.config.min_explainability
-            )));
-        }
+        self.check_explainability(&trace)?;
+        trace.total_duration_micros = micros(started.elapsed());
         
         // Step 5: Generate receipt
-        let receipt = Receipt::from_trace(&trace, sign_fn);
+        let receipt = Receipt::from_normalized_trace(&trace, normalized.as_ref().map(NormalizedEvidence::settings), sign_fn);
+        if let Some(recorder) = recorder {
+            recorder.record("create_receipt", &trace.receipt_hash, &receipt.hash);
+        }
+        
+        Ok((trace, receipt))
+    }
+    
+    /// Prove a compound claim (see [`ClaimExpr::parse`])
+    ///
+    /// Each atomic sub-claim is proven from the evidence items that state it.
+    /// A conjunction stops at the first failing conjunct and reports it; a
+    /// disjunction stops at the first disjunct that holds and records it. The
+    /// receipt's causal chain links every proven sub-claim to the compound
+    /// claim, and `receipt.compound` records the per-sub-claim outcomes.
+    pub fn prove_compound(
+        &self,
+        claim: &ClaimExpr,
+        observations: Vec<String>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        let started = Instant::now();
+        let evidence: Vec<Evidence> = observations.iter().map(Evidence::new).collect();
+        self.check_evidence_requirements(&evidence)?;
+        let (chain, record) = self.build_compound_chain(claim, &observations)?;
+        
+        if self.config.strict_c_zero && !chain.is_c_zero() {
+            return Err(ProofError::InvarianceViolation);
+        }
+        
+        let mut trace = self.generate_trace(&claim.to_string(), &observations, &chain)?;
+        self.check_explainability(&trace)?;
+        trace.total_duration_micros = micros(started.elapsed());
+        
+        let receipt = Receipt::from_compound_trace(&trace, Some(record), sign_fn);
+        
+        Ok((trace, receipt))
+    }
+    
+    /// Prove a structured claim (see [`Claim`])
+    ///
+    /// An atomic claim proves exactly as [`ProofEngine::prove_with_evidence`].
+    /// A `ForAll` claim is expanded into one instance per domain value, each
+    /// proven from the evidence items that state it. The trace records an
+    /// `instantiate` step per instance, and `receipt.quantified` records each
+    /// instance's sub-proof hash (the hash of its causal chain) and their
+    /// aggregate. See [`EngineConfig::fail_fast`] for how failures are reported.
+    pub fn prove_structured(
+        &self,
+        claim: &Claim,
+        evidence: Vec<Evidence>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Result<(TraceEnvelope, Receipt)> {
+        claim.validate()?;
+        let Claim::ForAll { variable, domain_values, template } = claim else {
+            return self.prove_with_evidence(&claim.to_string(), evidence, sign_fn);
+        };
+        let started = Instant::now();
+        self.check_evidence_requirements(&evidence)?;
+        
+        let provenance = Evidence::provenance_of(&evidence);
+        let observations: Vec<String> = evidence.into_iter().map(|e| e.statement).collect();
+        let (chain, record) = self.build_quantified_chain(variable, domain_values, template, &observations)?;
+        
+        if self.config.strict_c_zero && !chain.is_c_zero() {
+            return Err(ProofError::InvarianceViolation);
+        }
+        
+        let mut trace = self.generate_trace_with(&claim.to_string(), &observations, &chain, Some(&record), None)?;
+        if !provenance.is_empty() {
+            trace.set_provenance(provenance);
+        }
+        self.check_explainability(&trace)?;
+        trace.total_duration_micros = micros(started.elapsed());
+        
+        let receipt = Receipt::from_quantified_trace(&trace, record, sign_fn);
         
         Ok((trace, receipt))
     }
     
+    fn check_explainability(&self, trace: &TraceEnvelope) -> Result<()> {
+        let report = trace.explainability_report_weighted(&self.config.explainability_weights);
+        if report.index < self.config.min_explainability {
+            return Err(ProofError::ExplainabilityBelowThreshold {
+                index: report.index,
+                minimum: self.config.min_explainability,
+                report,
+            });
+        }
+        Ok(())
+    }
+    
+    /// Enforce [`EngineConfig::min_evidence_count`] and
+    /// [`EngineConfig::min_distinct_sources`]
+    ///
+    /// Blank items do not count, and items without a source count towards
+    /// the item minimum but not the source minimum.
+    pub fn check_evidence_requirements(&self, evidence: &[Evidence]) -> Result<()> {
+        let items: Vec<&Evidence> = evidence.iter().filter(|e| !e.statement.trim().is_empty()).collect();
+        if items.len() < self.config.min_evidence_count {
+            return Err(ProofError::InsufficientEvidence {
+                required: self.config.min_evidence_count,
+                provided: items.len(),
+            });
+        }
+        
+        let sources: HashSet<&str> = items
+            .iter()
+            .filter_map(|e| e.source_uri.as_deref())
+            .map(str::trim)
+            .filter(|uri| !uri.is_empty())
+            .collect();
+        if sources.len() < self.config.min_distinct_sources {
+            return Err(ProofError::InsufficientSources {
+                required: self.config.min_distinct_sources,
+                provided: sources.len(),
+            });
+        }
+        Ok(())
+    }
+    
+    /// The claim must not violate Ω-SSOT or a domain axiom
+    fn check_claim(&self, claim: &str) -> Result<()> {
+        let violated = self.check_violation(claim);
+        if !violated.is_empty() {
+            let ids: Vec<&str> = violated.iter().map(|a| a.id.as_str()).collect();
+            return Err(ProofError::Contradiction(format!(
+                "Claim '{}' violates {}",
+                claim,
+                ids.join(", ")
+            )));
+        }
+        Ok(())
+    }
+    
+    /// Build the composite chain for a compound claim
+    fn build_compound_chain(
+        &self,
+        claim: &ClaimExpr,
+        observations: &[String],
+    ) -> Result<(CausalChain, CompoundRecord)> {
+        if observations.is_empty() {
+            return Err(ProofError::UnsupportedClaim);
+        }
+        
+        let mut record = CompoundRecord {
+            expr: claim.clone(),
+            outcomes: Vec::new(),
+            carried_by: Vec::new(),
+        };
+        let links = self.prove_expr(claim, observations, &mut record)?;
+        
+        let mut chain = CausalChain::new(claim.to_string(), observations.to_vec());
+        for link in links {
+            chain.add_link(link)?;
+        }
+        
+        Ok((chain, record))
+    }
+    
+    /// Build the composite chain for a quantified claim, one sub-proof per value
+    fn build_quantified_chain(
+        &self,
+        variable: &str,
+        domain_values: &[String],
+        template: &str,
+        observations: &[String],
+    ) -> Result<(CausalChain, QuantifiedRecord)> {
+        if observations.is_empty() {
+            return Err(ProofError::UnsupportedClaim);
+        }
+        
+        let claim = Claim::ForAll {
+            variable: variable.to_string(),
+            domain_values: domain_values.to_vec(),
+            template: template.to_string(),
+        }
+        .to_string();
+        let mut chain = CausalChain::new(claim.clone(), observations.to_vec());
+        let mut instances = Vec::new();
+        let mut failures = Vec::new();
+        
+        for (i, value) in domain_values.iter().enumerate() {
+            let instance = instantiate(template, variable, value);
+            let proven = self.stating_chain(&instance, observations).and_then(|sub| {
+                for link in &sub.links {
+                    chain.add_link(link.clone())?;
+                }
+                chain.add_link(CausalLink::new(
+                    instance.clone(),
+                    claim.clone(),
+                    CausalRelation::Implies,
+                    vec![format!("Instance {} of {}: {} = {}", i + 1, domain_values.len(), variable, value)],
+                ))?;
+                Ok(sub.chain_hash)
+            });
+            
+            match proven {
+                Ok(proof_hash) => instances.push(InstanceProof { value: value.clone(), claim: instance, proof_hash }),
+                Err(err) => {
+                    failures.push(format!("{} = {}: {}", variable, value, err));
+                    if self.config.fail_fast {
+                        break;
+                    }
+                }
+            }
+        }
+        
+        if !failures.is_empty() {
+            return Err(ProofError::InstantiationFailed { claim, failures });
+        }
+        Ok((chain, QuantifiedRecord::new(variable, template, instances)))
+    }
+    
+    /// Prove one node of a compound claim, returning the links establishing it
+    fn prove_expr(
+        &self,
+        expr: &ClaimExpr,
+        observations: &[String],
+        record: &mut CompoundRecord,
+    ) -> Result<Vec<CausalLink>> {
+        match expr {
+            ClaimExpr::Atom(claim) => {
+                let result = self.prove_atom(claim, observations);
+                record.outcomes.push(SubClaimOutcome {
+                    claim: claim.clone(),
+                    status: if result.is_ok() { SubClaimStatus::Proven } else { SubClaimStatus::Failed },
+                    reason: result.as_ref().err().map(|e| e.to_string()),
+                });
+                result
+            }
+            ClaimExpr::All { all } => {
+                let mut links = Vec::new();
+                for (i, conjunct) in all.iter().enumerate() {
+                    match self.prove_expr(conjunct, observations, record) {
+                        Ok(sub_links) => {
+                            links.extend(sub_links);
+                            links.push(CausalLink::new(
+                                conjunct.to_string(),
+                                expr.to_string(),
+                                CausalRelation::Implies,
+                                vec![format!("Conjunct {} of {}", i + 1, all.len())],
+                            ));
+                        }
+                        Err(err) => {
+                            skip_all(&all[i + 1..], record);
+                            return Err(match err {
+                                ProofError::ConjunctFailed { .. } => err,
+                                other => ProofError::ConjunctFailed {
+                                    conjunct: conjunct.to_string(),
+                                    reason: other.to_string(),
+                                },
+                            });
+                        }
+                    }
+                }
+                Ok(links)
+            }
+            ClaimExpr::Any { any } => {
+                let mut reasons = Vec::new();
+                for (i, disjunct) in any.iter().enumerate() {
+                    match self.prove_expr(disjunct, observations, record) {
+                        Ok(mut links) => {
+                            skip_all(&any[i + 1..], record);
+                            record.carried_by.push(disjunct.to_string());
+                            links.push(CausalLink::new(
+                                disjunct.to_string(),
+                                expr.to_string(),
+                                CausalRelation::Implies,
+                                vec![format!("Disjunct {} of {} carried", i + 1, any.len())],
+                            ));
+                            return Ok(links);
+                        }
+                        Err(err) => reasons.push(format!("{}: {}", disjunct, err)),
+                    }
+                }
+                Err(ProofError::NoDisjunctHolds {
+                    claim: expr.to_string(),
+                    reasons,
+                })
+            }
+        }
+    }
+    
+    /// Prove an atomic sub-claim from the evidence items that state it
+    fn prove_atom(&self, claim: &str, observations: &[String]) -> Result<Vec<CausalLink>> {
+        Ok(self.stating_chain(claim, observations)?.links)
+    }
+    
+    /// Causal chain for `claim` built from the evidence items that state it
+    fn stating_chain(&self, claim: &str, observations: &[String]) -> Result<CausalChain> {
+        let needle = normalize(claim);
+        let stating: Vec<String> = observations
+            .iter()
+            .filter(|obs| normalize(obs).contains(&needle))
+            .cloned()
+            .collect();
+        
+        self.build_causal_chain(claim, &stating)
+    }
+    
+    /// How [`EngineConfig::normalize_evidence`] has new proofs deduplicate evidence
+    fn evidence_normalization(&self) -> Option<EvidenceNormalization> {
+        self.config.normalize_evidence.then_some(EvidenceNormalization {
+            case_fold: self.config.evidence_case_fold,
+        })
+    }
+    
+    /// Build a causal chain from observations to claim, first deduplicating
+    /// them under `normalization` if given
+    ///
+    /// The chain is built from the first original of each distinct
+    /// statement, so its links still quote the evidence as supplied.
+    /// Receipts are re-derived under their own recorded normalization
+    /// rather than this engine's configuration.
+    fn build_normalized_chain(
+        &self,
+        claim: &str,
+        observations: &[String],
+        normalization: Option<EvidenceNormalization>,
+    ) -> Result<(CausalChain, Option<NormalizedEvidence>)> {
+        let Some(normalization) = normalization else {
+            return Ok((self.build_causal_chain(claim, observations)?, None));
+        };
+        let normalized = NormalizedEvidence::new(observations, normalization.case_fold);
+        let chain = self.build_causal_chain(claim, &normalized.kept(observations))?;
+        Ok((chain, Some(normalized)))
+    }
+    
     /// Build a causal chain from observations to claim
     fn build_causal_chain(&self, claim: &str, observations: &[String]) -> Result<CausalChain> {
         let mut builder = CausalChainBuilder::new(claim)
@@ -117,6 +680,13 @@ impl ProofEngine {
             return Err(ProofError::UnsupportedClaim);
         }
         
+        self.check_claim(claim)?;
+        
+        // Evidence must bear on the claim before it may imply it
+        if self.relevant_evidence(claim, observations).is_empty() {
+            return Err(ProofError::UnsupportedClaim);
+        }
+        
         // Build chain from observations
         let mut current = observations[0].clone();
         
@@ -138,6 +708,17 @@ impl ProofEngine {
             vec!["Inference from observations".to_string()],
         )?;
         
+        for found in self.contradictions(observations) {
+            builder = builder.with_contradiction(
+                observations[found.first].clone(),
+                observations[found.second].clone(),
+                vec![format!(
+                    "{:?} contradiction between observations {} and {}",
+                    found.kind, found.first, found.second
+                )],
+            );
+        }
+        
         builder.build()
     }
     
@@ -147,6 +728,19 @@ impl ProofEngine {
         claim: &str,
         observations: &[String],
         chain: &CausalChain,
+    ) -> Result<TraceEnvelope> {
+        self.generate_trace_with(claim, observations, chain, None, None)
+    }
+    
+    /// Generate a proof trace, with a step per instance of a quantified claim
+    /// and a step recording how evidence was deduplicated
+    fn generate_trace_with(
+        &self,
+        claim: &str,
+        observations: &[String],
+        chain: &CausalChain,
+        quantified: Option<&QuantifiedRecord>,
+        normalized: Option<&NormalizedEvidence>,
     ) -> Result<TraceEnvelope> {
         let mut builder = TraceBuilder::new(claim)
             .with_observations(observations.to_vec())
@@ -172,6 +766,37 @@ impl ProofEngine {
             vec!["A5_DETERMINISM".to_string()],
         );
         
+        // Duplicates removed before building the chain
+        if let Some(normalized) = normalized {
+            builder = builder.add_step(
+                "normalize_evidence",
+                serde_json::json!({
+                    "observations": observations.len(),
+                    "case_fold": normalized.case_fold,
+                })
+                .to_string(),
+                normalization_summary(normalized),
+                vec!["A1_IDENTITY".to_string()],
+            );
+        }
+        
+        // One step per instantiation of a quantified claim
+        if let Some(record) = quantified {
+            for instance in &record.instances {
+                builder = builder.add_step(
+                    "instantiate",
+                    serde_json::json!({
+                        "variable": record.variable,
+                        "value": instance.value,
+                        "template": record.template,
+                    })
+                    .to_string(),
+                    format!("Instance '{}' supported", instance.claim),
+                    vec!["A7_CAUSAL_CLOSURE".to_string()],
+                );
+            }
+        }
+        
         // Step 3: Build causal model
         builder = builder.add_step(
             "build_causal_model",
@@ -181,21 +806,25 @@ impl ProofEngine {
         );
         
         // Step 4: Check contradictions
-        builder = builder.add_step(
+        builder = builder.add_timed_step(
             "check_contradictions",
             format!("C = {}", chain.contradiction_measure()),
-            format!("C = {} ({})", chain.contradiction_measure(), 
-                    if chain.is_c_zero() { "PASS" } else { "FAIL" }),
             vec!["A2_NON_CONTRADICTION".to_string(), "A6_C_ZERO".to_string()],
+            || {
+                let verdict = if chain.is_c_zero() { "PASS" } else { "FAIL" };
+                format!("C = {} ({})", chain.contradiction_measure(), verdict)
+            },
         );
         
         // Step 5: Verify claim support
-        let supports = chain.supports_claim();
-        builder = builder.add_step(
+        builder = builder.add_timed_step(
             "verify_claim_support",
             claim.to_string(),
-            format!("Claim {} by evidence", if supports { "supported" } else { "not supported" }),
             vec!["A8_BINARY_PROOF".to_string()],
+            || {
+                let supports = chain.supports_claim();
+                format!("Claim {} by evidence", if supports { "supported" } else { "not supported" })
+            },
         );
         
         // Step 6: Finalize
@@ -209,7 +838,27 @@ impl ProofEngine {
         Ok(builder.build())
     }
     
+    /// Executors for the steps [`ProofEngine`] traces record
+    ///
+    /// Pass to [`TraceEnvelope::replay`] to check that a trace's steps follow
+    /// from their inputs, not just that its hashes are consistent.
+    pub fn step_registry(&self) -> StepRegistry {
+        StepRegistry::new()
+            .with_executor("initialize", replay_initialize)
+            .with_executor("validate_observations", replay_validate_observations)
+            .with_executor("normalize_evidence", replay_normalize_evidence)
+            .with_executor("instantiate", replay_instantiate)
+            .with_executor("build_causal_model", replay_build_causal_model)
+            .with_executor("check_contradictions", replay_check_contradictions)
+            .with_executor("verify_claim_support", replay_verify_claim_support)
+            .with_executor("finalize", replay_finalize)
+    }
+    
     /// Verify a receipt
+    ///
+    /// With [`ProofEngine::with_ssot_history`], every axiom the receipt
+    /// names must also be unchanged between the snapshot it was issued
+    /// under and this engine's Ω-SSOT.
     pub fn verify_receipt(
         &self,
         receipt: &Receipt,
@@ -230,9 +879,109 @@ impl ProofEngine {
             return Err(ProofError::InvarianceViolation);
         }
         
+        // Check the axioms it was proven under still hold as they were
+        if let Some(history) = &self.ssot_history {
+            self.axiom_compatibility(history, receipt)?.into_result()?;
+        }
+        
         Ok(true)
     }
     
+    /// How `receipt`'s axioms, as pinned when it was issued, fare under this engine's Ω-SSOT
+    pub fn axiom_compatibility(&self, history: &SsotHistory, receipt: &Receipt) -> Result<Compatibility> {
+        history.check_receipt(receipt, &self.omega_ssot.core_axioms)
+    }
+    
+    /// Verify a receipt and re-derive its causal chain from claim and evidence
+    pub fn verify_receipt_deep(
+        &self,
+        receipt: &Receipt,
+        verify_fn: impl FnOnce(&str, &str) -> bool,
+    ) -> ReceiptVerification {
+        let chain_ok = match (&receipt.compound, &receipt.quantified) {
+            (Some(record), _) => match self.build_compound_chain(&record.expr, &receipt.evidence) {
+                Ok((chain, derived)) => {
+                    receipt.claim == record.expr.to_string()
+                        && derived == *record
+                        && chain_matches(&chain, &receipt.causal_chain)
+                }
+                Err(_) => false,
+            },
+            (None, Some(record)) => match self.derive_quantified_chain(record, &receipt.evidence) {
+                Ok((chain, derived)) => {
+                    receipt.claim == record.claim().to_string()
+                        && derived == *record
+                        && chain_matches(&chain, &receipt.causal_chain)
+                }
+                Err(_) => false,
+            },
+            (None, None) => match self.build_normalized_chain(&receipt.claim, &receipt.evidence, receipt.normalization) {
+                Ok((chain, _)) => chain_matches(&chain, &receipt.causal_chain),
+                Err(_) => false,
+            },
+        };
+        
+        ReceiptVerification {
+            hash_ok: receipt.verify_hash(),
+            signature_ok: receipt.verify_signature(verify_fn),
+            chain_ok,
+            c_zero: receipt.c_zero,
+        }
+    }
+    
+    /// Re-derive the full causal chain behind a receipt from its claim and evidence
+    pub fn derive_chain(&self, receipt: &Receipt) -> Result<CausalChain> {
+        match (&receipt.compound, &receipt.quantified) {
+            (Some(record), _) => self.build_compound_chain(&record.expr, &receipt.evidence).map(|(chain, _)| chain),
+            (None, Some(record)) => self.derive_quantified_chain(record, &receipt.evidence).map(|(chain, _)| chain),
+            (None, None) => self
+                .build_normalized_chain(&receipt.claim, &receipt.evidence, receipt.normalization)
+                .map(|(chain, _)| chain),
+        }
+    }
+    
+    fn derive_quantified_chain(
+        &self,
+        record: &QuantifiedRecord,
+        evidence: &[String],
+    ) -> Result<(CausalChain, QuantifiedRecord)> {
+        let domain_values: Vec<String> = record.instances.iter().map(|instance| instance.value.clone()).collect();
+        self.build_quantified_chain(&record.variable, &domain_values, &record.template, evidence)
+    }
+    
+    /// Indices of the `evidence` items relevant to `claim`
+    ///
+    /// An item is relevant when it shares at least `min_evidence_relevance`
+    /// content words (ignoring case and `RELEVANCE_STOP_WORDS`) with the
+    /// claim, or when `relevance_predicate` accepts it. With a minimum of 0
+    /// every item is relevant.
+    pub fn relevant_evidence(&self, claim: &str, evidence: &[String]) -> Vec<usize> {
+        let claim_words = content_words(claim);
+        evidence
+            .iter()
+            .enumerate()
+            .filter(|(_, item)| {
+                let minimum = self.config.min_evidence_relevance;
+                minimum == 0
+                    || content_words(item).intersection(&claim_words).count() >= minimum
+                    || self
+                        .config
+                        .relevance_predicate
+                        .as_ref()
+                        .is_some_and(|predicate| predicate.matches(claim, item))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+    
+    /// Contradicting pairs in `evidence` when `detect_contradictions` is set
+    pub fn contradictions(&self, evidence: &[String]) -> Vec<Contradiction> {
+        if !self.config.detect_contradictions {
+            return Vec::new();
+        }
+        self.config.contradiction_detector.detect(evidence)
+    }
+    
     /// Verify a claim against evidence (simple interface)
     pub fn verify_claim(
         &self,
@@ -240,7 +989,7 @@ impl ProofEngine {
         evidence: &[String],
     ) -> Result<bool> {
         // Build causal chain
-        let chain = self.build_causal_chain(claim, evidence)?;
+        let (chain, _) = self.build_normalized_chain(claim, evidence, self.evidence_normalization())?;
         
         // Check C=0
         if !chain.is_c_zero() {
@@ -252,6 +1001,133 @@ impl ProofEngine {
     }
 }
 
+fn replay_initialize(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    if input != format!("observations: {:?}", trace.observations) {
+        return Err("input does not list the trace's observations".to_string());
+    }
+    Ok("Initialized proof context".to_string())
+}
+
+fn replay_validate_observations(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    let count: usize = input
+        .strip_suffix(" observations")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("malformed input '{}'", input))?;
+    if count != trace.observations.len() {
+        return Err(format!("input counts {} observations, trace has {}", count, trace.observations.len()));
+    }
+    if count == 0 {
+        return Err("no observations to validate".to_string());
+    }
+    Ok("Observations validated".to_string())
+}
+
+fn replay_normalize_evidence(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    let input: serde_json::Value = serde_json::from_str(input).map_err(|e| format!("malformed input: {}", e))?;
+    let count = input["observations"].as_u64().ok_or("input has no 'observations'")?;
+    if count as usize != trace.observations.len() {
+        return Err(format!("input counts {} observations, trace has {}", count, trace.observations.len()));
+    }
+    let case_fold = input["case_fold"].as_bool().ok_or("input has no 'case_fold'")?;
+    Ok(normalization_summary(&NormalizedEvidence::new(&trace.observations, case_fold)))
+}
+
+fn replay_instantiate(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    let input: serde_json::Value = serde_json::from_str(input).map_err(|e| format!("malformed input: {}", e))?;
+    let field = |key: &str| input[key].as_str().ok_or_else(|| format!("input has no '{}'", key));
+    let instance = instantiate(field("template")?, field("variable")?, field("value")?);
+    
+    // Supported when the causal chain carries the instance to the claim
+    let supported = trace.causal_chain.iter().filter_map(|link| CausalRelation::parse_link(link)).any(
+        |(source, relation, target)| relation == CausalRelation::Implies && source == instance && target == trace.claim,
+    );
+    Ok(format!("Instance '{}' {}", instance, if supported { "supported" } else { "not supported" }))
+}
+
+fn replay_build_causal_model(trace: &TraceEnvelope, _input: &str) -> std::result::Result<String, String> {
+    Ok(format!("Causal chain with {} links", trace.causal_chain.len()))
+}
+
+fn replay_check_contradictions(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    let measure: usize = input
+        .strip_prefix("C = ")
+        .and_then(|n| n.parse().ok())
+        .ok_or_else(|| format!("malformed input '{}'", input))?;
+    let contradictions = trace
+        .causal_chain
+        .iter()
+        .filter(|link| matches!(CausalRelation::parse_link(link), Some((_, CausalRelation::Contradicts, _))))
+        .count();
+    if measure != contradictions {
+        return Err(format!("input C = {} but the causal chain has {} contradiction links", measure, contradictions));
+    }
+    
+    let pass = measure == 0 && trace.contradiction_check;
+    Ok(format!("C = {} ({})", measure, if pass { "PASS" } else { "FAIL" }))
+}
+
+fn replay_verify_claim_support(trace: &TraceEnvelope, input: &str) -> std::result::Result<String, String> {
+    if input != trace.claim {
+        return Err("input is not the trace's claim".to_string());
+    }
+    // Same test as `CausalChain::supports_claim` on the rendered links; a
+    // contradiction invalidates the whole chain
+    let links: Vec<_> = trace.causal_chain.iter().filter_map(|link| CausalRelation::parse_link(link)).collect();
+    let supported = links.iter().all(|(_, relation, _)| *relation != CausalRelation::Contradicts)
+        && links.iter().any(|(_, _, target)| target.contains(&trace.claim) || trace.claim.contains(target));
+    Ok(format!("Claim {} by evidence", if supported { "supported" } else { "not supported" }))
+}
+
+fn replay_finalize(trace: &TraceEnvelope, _input: &str) -> std::result::Result<String, String> {
+    Ok(format!("Claim '{}' verified with C=0", trace.claim))
+}
+
+/// Mark every atom under `exprs` as not evaluated
+fn skip_all(exprs: &[ClaimExpr], record: &mut CompoundRecord) {
+    for expr in exprs {
+        for claim in expr.atoms() {
+            record.outcomes.push(SubClaimOutcome {
+                claim: claim.to_string(),
+                status: SubClaimStatus::Skipped,
+                reason: None,
+            });
+        }
+    }
+}
+
+/// Output of the `normalize_evidence` trace step: duplicates removed and
+/// which original items each distinct one stands for
+fn normalization_summary(normalized: &NormalizedEvidence) -> String {
+    serde_json::json!({
+        "duplicates_removed": normalized.duplicates_removed(),
+        "items": normalized.items,
+    })
+    .to_string()
+}
+
+/// Lowercase with collapsed whitespace, for matching sub-claims to evidence
+fn normalize(text: &str) -> String {
+    text.split_whitespace().collect::<Vec<_>>().join(" ").to_lowercase()
+}
+
+/// Lowercased words of `text`, excluding `RELEVANCE_STOP_WORDS`
+fn content_words(text: &str) -> HashSet<String> {
+    text.split(|c: char| !c.is_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .map(|w| w.to_lowercase())
+        .filter(|w| !RELEVANCE_STOP_WORDS.contains(&w.as_str()))
+        .collect()
+}
+
+/// Whether a re-derived chain supports its claim, is C=0 and matches the stored links
+fn chain_matches(chain: &CausalChain, stored: &[String]) -> bool {
+    let mut derived = chain.to_string_chain();
+    let mut stored = stored.to_vec();
+    derived.sort();
+    stored.sort();
+    chain.supports_claim() && chain.is_c_zero() && derived == stored
+}
+
 impl Default for ProofEngine {
     fn default() -> Self {
         Self::new()
@@ -288,6 +1164,7 @@ pub fn verify_claim(claim: &str, facts: &[String], axioms: &[String]) -> Result<
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::trace::{ReplayStatus, TraceStep};
     
     fn test_sign(hash: &str) -> String {
         use sha2::{Sha256, Digest};
@@ -307,6 +1184,19 @@ mod tests {
         assert!(!engine.all_axioms().is_empty());
     }
     
+    #[test]
+    fn test_custom_ssot_used_when_proving() {
+        let mut ssot = OmegaSSoT::new();
+        ssot.core_axioms.add(Axiom::new("FIN_1", "Double Entry", "Debits equal credits", "finance"));
+        let engine = ProofEngine::new().with_ssot(ssot);
+        
+        let (_, receipt) = engine
+            .prove("The ledger balances", vec!["The ledger balances".to_string()], test_sign)
+            .unwrap();
+        assert!(receipt.axioms.iter().any(|a| a == "FIN_1"));
+        assert!(engine.all_axioms().iter().any(|a| a.id == "FIN_1"));
+    }
+    
     #[test]
     fn test_prove_claim() {
         let engine = ProofEngine::new();
@@ -328,6 +1218,143 @@ mod tests {
         assert!(trace.is_c_zero());
         assert!(receipt.c_zero);
     }
+
+    #[test]
+    fn test_proof_records_timing() {
+        let engine = ProofEngine::new();
+        let (trace, receipt) = engine
+            .prove("The claim holds", vec!["The claim holds".to_string()], test_sign)
+            .unwrap();
+        
+        let step_total: u64 = trace.steps.iter().map(|s| s.duration_micros).sum();
+        assert!(trace.total_duration_micros >= step_total);
+        
+        let metrics = receipt.metrics.unwrap();
+        assert_eq!(metrics, trace.metrics());
+        assert_eq!(metrics.steps_by_operation.values().sum::<usize>(), trace.steps.len());
+        assert_eq!(metrics.steps_by_operation["finalize"], 1);
+        assert!(metrics.slowest_step.is_some());
+    }
+
+    #[test]
+    fn test_prove_audited_records_phases() {
+        let engine = ProofEngine::new();
+        let observations = vec![
+            "The sky is blue".to_string(),
+            "Blue things reflect certain wavelengths".to_string(),
+        ];
+
+        let (trace, receipt, ops) = engine
+            .prove_audited("The sky reflects certain wavelengths", observations, test_sign)
+            .unwrap();
+
+        let names: Vec<&str> = ops.iter().map(|op| op.name.as_str()).collect();
+        assert_eq!(names, ["build_causal_chain", "check_c_zero", "generate_trace", "create_receipt"]);
+        assert_eq!(ops[2].output.as_text(), Some(trace.receipt_hash.as_str()));
+        assert_eq!(ops[3].output.as_text(), Some(receipt.hash.as_str()));
+        assert!(SubOperation::verify_chain(&ops));
+
+        // Altering a middle operation breaks its own hash
+        let mut tampered = ops.clone();
+        tampered[1].output = "C=1".into();
+        assert!(!SubOperation::verify_chain(&tampered));
+
+        // Re-hashing it breaks the link from its successor
+        let mut rehashed = ops;
+        let forged = SubOperation::new("check_c_zero", rehashed[1].input.clone(), "C=1", rehashed[1].prev_hash.clone());
+        rehashed[1] = forged;
+        assert!(!SubOperation::verify_chain(&rehashed));
+    }
+
+    #[test]
+    fn test_derive_chain_matches_receipt() {
+        let engine = ProofEngine::new();
+        
+        let observations = vec!["Fact A".to_string(), "Fact B".to_string()];
+        let (_, receipt) = engine.prove("Conclusion", observations, test_sign).unwrap();
+        
+        let chain = engine.derive_chain(&receipt).unwrap();
+        assert_eq!(chain.to_string_chain(), receipt.causal_chain);
+        assert!(chain.to_dot().contains("shape=doublecircle"));
+    }
+    
+    #[test]
+    fn test_prove_with_evidence_records_provenance() {
+        let engine = ProofEngine::new();
+        let retrieved = chrono::Utc::now();
+        let evidence = vec![
+            Evidence::new("Fact A").with_source("https://example.org/a", retrieved).with_trust(0.9),
+            Evidence::new("Fact B"),
+        ];
+        
+        let (trace, receipt) = engine.prove_with_evidence("Conclusion", evidence, test_sign).unwrap();
+        
+        assert_eq!(receipt.evidence, vec!["Fact A".to_string(), "Fact B".to_string()]);
+        assert_eq!(receipt.provenance.len(), 2);
+        assert_eq!(receipt.provenance[0].source_uri.as_deref(), Some("https://example.org/a"));
+        assert_eq!(trace.provenance, receipt.provenance);
+        assert!(trace.verify_integrity());
+        assert!(receipt.verify_hash());
+        
+        // The hash covers sources
+        let mut tampered = receipt.clone();
+        tampered.provenance[0].source_uri = Some("https://evil.example".to_string());
+        assert!(!tampered.verify_hash());
+    }
+    
+    #[test]
+    fn test_bare_receipt_without_provenance_field_verifies() {
+        let engine = ProofEngine::new();
+        let (_, receipt) = engine
+            .prove("Conclusion", vec!["Fact A".to_string()], test_sign)
+            .unwrap();
+        assert!(receipt.provenance.is_empty());
+        
+        let json = receipt.to_json().unwrap();
+        assert!(!json.contains("provenance"));
+        assert!(Receipt::from_json(&json).unwrap().verify_hash());
+    }
+    
+    #[test]
+    fn test_prove_chained_links_parents() {
+        let engine = ProofEngine::new();
+        let (_, parent) = engine
+            .prove("Fact C", vec!["Fact A".to_string(), "Fact B".to_string()], test_sign)
+            .unwrap();
+        
+        let (_, child) = engine
+            .prove_chained("Conclusion", vec!["Fact D".to_string()], std::slice::from_ref(&parent), test_sign)
+            .unwrap();
+        assert_eq!(child.parents, vec![parent.hash.clone()]);
+        assert_eq!(child.evidence, vec!["Fact C".to_string(), "Fact D".to_string()]);
+        assert_eq!(child.provenance[0].source_uri, Some(format!("receipt:{}", parent.hash)));
+        assert!(child.verify(test_verify));
+        assert_eq!(child.verify_ancestry(|_| Some(parent.clone())), Ok(()));
+        
+        let mut tampered = parent.clone();
+        tampered.claim = "Fact E".to_string();
+        let err = engine
+            .prove_chained("Conclusion", vec![], &[tampered], test_sign)
+            .unwrap_err();
+        assert!(matches!(err, ProofError::InvalidEvidence(_)));
+    }
+    
+    #[test]
+    fn test_prove_from_files_records_files() {
+        let engine = ProofEngine::new();
+        let file = EvidenceFile { path: "facts.json".to_string(), sha256: "00".repeat(32) };
+        let evidence = vec![Evidence::new("Fact A"), Evidence::new("Fact B")];
+        
+        let (_, receipt) = engine
+            .prove_from_files("Conclusion", evidence.clone(), vec![file.clone()], test_sign)
+            .unwrap();
+        assert_eq!(receipt.evidence_files, vec![file]);
+        assert!(receipt.verify(test_verify));
+        
+        let (_, plain) = engine.prove_with_evidence("Conclusion", evidence, test_sign).unwrap();
+        assert_eq!(plain.causal_chain, receipt.causal_chain);
+        assert!(plain.evidence_files.is_empty());
+    }
     
     #[test]
     fn test_verify_receipt() {
@@ -341,6 +1368,210 @@ mod tests {
         assert!(verified.unwrap());
     }
     
+    #[test]
+    fn test_verify_receipt_deep() {
+        let engine = ProofEngine::new();
+        
+        let observations = vec!["Fact A".to_string(), "Fact B".to_string()];
+        let (_, mut receipt) = engine.prove("Conclusion", observations, test_sign).unwrap();
+        
+        // Order of stored links does not matter
+        receipt.causal_chain.reverse();
+        let rehashed = crate::ReceiptBuilder::new(receipt.claim.clone())
+            .with_evidence_list(receipt.evidence.clone())
+            .with_causal_chain(receipt.causal_chain.clone())
+            .with_axioms(receipt.axioms.clone())
+            .build(test_sign);
+        
+        let result = engine.verify_receipt_deep(&rehashed, test_verify);
+        assert!(result.is_valid());
+    }
+    
+    #[test]
+    fn test_verify_receipt_deep_rejects_fabricated_chain() {
+        let engine = ProofEngine::new();
+        
+        // Hash and signature are valid, but the chain does not follow from the evidence
+        let receipt = crate::ReceiptBuilder::new("Conclusion")
+            .with_evidence("Unrelated fact")
+            .with_causal_link("Fact A ⟹ Conclusion")
+            .build(test_sign);
+        
+        let result = engine.verify_receipt_deep(&receipt, test_verify);
+        assert_eq!(
+            result,
+            ReceiptVerification {
+                hash_ok: true,
+                signature_ok: true,
+                chain_ok: false,
+                c_zero: true,
+            }
+        );
+        assert!(engine.verify_receipt(&receipt, test_verify).unwrap());
+        
+        // No evidence cannot re-derive any chain
+        let empty = crate::ReceiptBuilder::new("Conclusion").build(test_sign);
+        assert!(!engine.verify_receipt_deep(&empty, test_verify).chain_ok);
+    }
+    
+    #[test]
+    fn test_prove_conjunction() {
+        let engine = ProofEngine::new();
+        let claim = ClaimExpr::parse("Server A is up and Server B is up").unwrap();
+        let evidence = vec![
+            "Health check: Server A is up".to_string(),
+            "Health check: Server B is up".to_string(),
+        ];
+        
+        let (_, receipt) = engine.prove_compound(&claim, evidence, test_sign).unwrap();
+        let record = receipt.compound.as_ref().unwrap();
+        
+        assert_eq!(receipt.claim, "Server A is up ∧ Server B is up");
+        assert!(record.outcomes.iter().all(|o| o.status == SubClaimStatus::Proven));
+        assert!(receipt.causal_chain.iter().any(|l| l == "Server B is up ⟹ Server A is up ∧ Server B is up"));
+        assert!(engine.verify_receipt_deep(&receipt, test_verify).is_valid());
+    }
+    
+    #[test]
+    fn test_prove_disjunction_records_carrier() {
+        let engine = ProofEngine::new();
+        let claim = ClaimExpr::parse(r#"{"any": ["Backup B is fresh", "Backup C is fresh", "Backup D is fresh"]}"#).unwrap();
+        let evidence = vec!["Backup C is fresh as of 02:00".to_string()];
+        
+        let (_, receipt) = engine.prove_compound(&claim, evidence, test_sign).unwrap();
+        let record = receipt.compound.as_ref().unwrap();
+        
+        assert_eq!(record.carried_by, vec!["Backup C is fresh".to_string()]);
+        let statuses: Vec<_> = record.outcomes.iter().map(|o| o.status).collect();
+        assert_eq!(statuses, vec![SubClaimStatus::Failed, SubClaimStatus::Proven, SubClaimStatus::Skipped]);
+        assert!(engine.verify_receipt_deep(&receipt, test_verify).is_valid());
+        
+        let mut tampered = receipt.clone();
+        tampered.compound.as_mut().unwrap().carried_by = vec!["Backup B is fresh".to_string()];
+        assert!(!engine.verify_receipt_deep(&tampered, test_verify).is_valid());
+    }
+    
+    #[test]
+    fn test_failing_conjunct_reported() {
+        let engine = ProofEngine::new();
+        let claim = ClaimExpr::parse("Server A is up and Server B is up and Server C is up").unwrap();
+        let evidence = vec![
+            "Server A is up".to_string(),
+            "Server C is up".to_string(),
+        ];
+        
+        match engine.prove_compound(&claim, evidence, test_sign) {
+            Err(ProofError::ConjunctFailed { conjunct, .. }) => assert_eq!(conjunct, "Server B is up"),
+            other => panic!("expected ConjunctFailed, got {:?}", other),
+        }
+    }
+    
+    fn deployments(values: &[&str]) -> Claim {
+        Claim::ForAll {
+            variable: "d".to_string(),
+            domain_values: values.iter().map(|v| v.to_string()).collect(),
+            template: "Deployment {d} passed checks".to_string(),
+        }
+    }
+    
+    #[test]
+    fn test_prove_for_all_records_each_instance() {
+        let engine = ProofEngine::new();
+        let evidence = vec![
+            Evidence::new("CI: Deployment web-1 passed checks"),
+            Evidence::new("CI: Deployment web-2 passed checks"),
+        ];
+        
+        let (trace, receipt) = engine.prove_structured(&deployments(&["web-1", "web-2"]), evidence, test_sign).unwrap();
+        let record = receipt.quantified.as_ref().unwrap();
+        
+        assert_eq!(receipt.claim, "∀ d ∈ {web-1, web-2}: Deployment {d} passed checks");
+        assert_eq!(record.instances.len(), 2);
+        assert_eq!(record.instances[1].claim, "Deployment web-2 passed checks");
+        assert_ne!(record.instances[0].proof_hash, record.instances[1].proof_hash);
+        assert!(record.verify_aggregate());
+        
+        let instantiated: Vec<&str> = trace.steps.iter().filter(|s| s.operation == "instantiate").map(|s| s.output.as_str()).collect();
+        assert_eq!(
+            instantiated,
+            ["Instance 'Deployment web-1 passed checks' supported", "Instance 'Deployment web-2 passed checks' supported"]
+        );
+        assert!(trace.replay(&engine.step_registry()).is_valid());
+        assert!(engine.verify_receipt_deep(&receipt, test_verify).is_valid());
+        
+        let mut tampered = receipt.clone();
+        tampered.quantified.as_mut().unwrap().instances.pop();
+        assert!(!tampered.verify_hash());
+        assert!(!engine.verify_receipt_deep(&tampered, test_verify).is_valid());
+    }
+    
+    #[test]
+    fn test_prove_for_all_fail_fast_and_exhaustive() {
+        let claim = deployments(&["web-1", "web-2", "web-3"]);
+        let evidence = || vec![Evidence::new("Deployment web-2 passed checks")];
+        
+        match ProofEngine::new().prove_structured(&claim, evidence(), test_sign) {
+            Err(ProofError::InstantiationFailed { failures, .. }) => {
+                assert_eq!(failures.len(), 1);
+                assert!(failures[0].starts_with("d = web-1: "));
+            }
+            other => panic!("expected InstantiationFailed, got {:?}", other),
+        }
+        
+        let exhaustive = ProofEngine::with_config(EngineConfig { fail_fast: false, ..Default::default() });
+        match exhaustive.prove_structured(&claim, evidence(), test_sign) {
+            Err(ProofError::InstantiationFailed { failures, .. }) => {
+                let values: Vec<&str> = failures.iter().map(|f| f.split(':').next().unwrap()).collect();
+                assert_eq!(values, ["d = web-1", "d = web-3"]);
+            }
+            other => panic!("expected InstantiationFailed, got {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_prove_structured_atomic_claim() {
+        let engine = ProofEngine::new();
+        let claim = Claim::Atomic("Server A is up".to_string());
+        let (_, receipt) = engine.prove_structured(&claim, vec![Evidence::new("Server A is up")], test_sign).unwrap();
+        
+        assert_eq!(receipt.claim, "Server A is up");
+        assert!(receipt.quantified.is_none());
+        assert!(matches!(
+            engine.prove_structured(&deployments(&[]), vec![Evidence::new("Server A is up")], test_sign),
+            Err(ProofError::AmbiguousClaim(_))
+        ));
+    }
+    
+    #[test]
+    fn test_domain_axiom_rules_checked_against_claims() {
+        use crate::axioms::ViolationRule;
+        
+        let mut engine = ProofEngine::new();
+        let claim = "The invoice was paid in cash";
+        let observations = vec!["Ledger: the invoice was paid in cash".to_string()];
+        assert!(engine.prove(claim, observations.clone(), test_sign).is_ok());
+        
+        engine.add_axiom(
+            Axiom::new("FIN_NO_CASH", "No Cash", "Cash is not accepted", "finance")
+                .with_violation_rule(ViolationRule::forbidden(["cash"]).unwrap()),
+        );
+        engine.add_axiom(
+            Axiom::new("FIN_PAID_BY_WIRE", "Wire Only", "Payments go by wire", "finance")
+                .with_violation_rule(ViolationRule::regex(r"paid in \w+").unwrap()),
+        );
+        let ids: Vec<&str> = engine.check_violation(claim).iter().map(|a| a.id.as_str()).collect();
+        assert_eq!(ids, vec!["FIN_NO_CASH", "FIN_PAID_BY_WIRE"]);
+        
+        match engine.prove(claim, observations, test_sign) {
+            Err(ProofError::Contradiction(message)) => assert!(message.ends_with("violates FIN_NO_CASH, FIN_PAID_BY_WIRE"), "{}", message),
+            other => panic!("expected contradiction, got {:?}", other.map(|(_, r)| r.hash)),
+        }
+        assert!(engine.verify_claim(claim, &["Ledger: the invoice was paid in cash".to_string()]).is_err());
+        assert!(engine
+            .prove("The invoice was paid by wire", vec!["The invoice was paid by wire".to_string()], test_sign)
+            .is_ok());
+    }
+    
     #[test]
     fn test_unsupported_claim() {
         let engine = ProofEngine::new();
@@ -351,6 +1582,106 @@ mod tests {
         assert!(result.is_err());
     }
     
+    #[test]
+    fn test_detect_contradictions_gates_prove() {
+        let observations = vec![
+            "The service is healthy".to_string(),
+            "The service is not healthy".to_string(),
+            "The replica count is 3".to_string(),
+        ];
+        
+        let engine = ProofEngine::new();
+        assert!(engine.prove("The service is healthy", observations.clone(), test_sign).is_ok());
+        
+        let engine = ProofEngine::with_config(EngineConfig {
+            detect_contradictions: true,
+            ..Default::default()
+        });
+        let result = engine.prove("The service is healthy", observations, test_sign);
+        assert!(matches!(result, Err(ProofError::InvarianceViolation)));
+        
+        let numeric = vec!["The replica count is 3".to_string(), "The replica count is 5".to_string()];
+        let result = engine.prove("Replicas are running", numeric, test_sign);
+        assert!(matches!(result, Err(ProofError::InvarianceViolation)));
+        
+        let consistent = vec!["The replica count is 3".to_string(), "Replicas are running".to_string()];
+        assert!(engine.prove("Replicas are running", consistent, test_sign).is_ok());
+    }
+    
+    #[test]
+    fn test_min_evidence_relevance_rejects_unrelated_evidence() {
+        let engine = ProofEngine::with_config(EngineConfig {
+            min_evidence_relevance: 1,
+            ..Default::default()
+        });
+        let unrelated = vec!["Servers restarted at 3pm".to_string()];
+        
+        let result = engine.prove("The moon is cheese", unrelated.clone(), test_sign);
+        assert!(matches!(result, Err(ProofError::UnsupportedClaim)));
+        assert!(matches!(engine.verify_claim("The moon is cheese", &unrelated), Err(ProofError::UnsupportedClaim)));
+        
+        let related = vec!["Heat applied".to_string(), "The water boils at 100C".to_string()];
+        assert_eq!(engine.relevant_evidence("Water boils", &related), vec![1]);
+        assert!(engine.prove("Water boils", related, test_sign).is_ok());
+        
+        // Stop words alone are not relevance
+        assert!(engine.relevant_evidence("The moon is cheese", &["It is the case".to_string()]).is_empty());
+        
+        // Off by default
+        assert!(ProofEngine::new().verify_claim("The moon is cheese", &unrelated).unwrap());
+    }
+    
+    #[test]
+    fn test_minimum_evidence_and_sources() {
+        let engine = ProofEngine::with_config(EngineConfig {
+            min_evidence_count: 3,
+            min_distinct_sources: 2,
+            ..Default::default()
+        });
+        let observations = vec!["Heat applied".to_string(), " ".to_string(), "Water boils".to_string()];
+        
+        let result = engine.prove("Water boils", observations.clone(), test_sign);
+        assert!(matches!(result, Err(ProofError::InsufficientEvidence { required: 3, provided: 2 })));
+        assert_eq!(result.unwrap_err().code(), crate::ErrorCode::InsufficientEvidence);
+        let compound = ClaimExpr::parse("Water boils").unwrap();
+        assert!(matches!(
+            engine.prove_compound(&compound, observations, test_sign),
+            Err(ProofError::InsufficientEvidence { .. })
+        ));
+        
+        let now = chrono::Utc::now();
+        let evidence = |sources: [&str; 3]| -> Vec<Evidence> {
+            ["Heat applied", "Water boils", "The water boils at 100C"]
+                .iter()
+                .zip(sources)
+                .map(|(statement, source)| Evidence::new(*statement).with_source(source, now))
+                .collect()
+        };
+        let one_source = evidence(["https://a", "https://a", "https://a"]);
+        assert!(matches!(
+            engine.prove_with_evidence("Water boils", one_source, test_sign),
+            Err(ProofError::InsufficientSources { required: 2, provided: 1 })
+        ));
+        
+        let two_sources = evidence(["https://a", "https://b", "https://a"]);
+        assert!(engine.prove_with_evidence("Water boils", two_sources, test_sign).is_ok());
+    }
+    
+    #[test]
+    fn test_relevance_predicate_admits_evidence() {
+        let engine = ProofEngine::with_config(EngineConfig {
+            min_evidence_relevance: 1,
+            relevance_predicate: Some(RelevancePredicate::new(|claim, evidence| {
+                claim.contains("lunar") && evidence.contains("moon")
+            })),
+            ..Default::default()
+        });
+        let evidence = vec!["Servers restarted".to_string(), "The moon is full".to_string()];
+        
+        assert_eq!(engine.relevant_evidence("A lunar eclipse occurred", &evidence), vec![1]);
+        assert!(engine.verify_claim("A lunar eclipse occurred", &evidence).unwrap());
+    }
+    
     #[test]
     fn test_explainability_requirement() {
         let config = EngineConfig {
@@ -366,5 +1697,179 @@ mod tests {
         // Should pass since our trace has good explainability
         assert!(result.is_ok());
     }
+    
+    #[test]
+    fn test_explainability_failure_names_steps() {
+        let trace = TraceBuilder::new("Claim")
+            .add_step("initialize", "in", "out", vec!["A4_SUBSTRATE_AUTHORITY".to_string()])
+            .add_step("heuristic", "in", "out", vec![])
+            .build();
+        
+        let engine = ProofEngine::new();
+        match engine.check_explainability(&trace) {
+            Err(ProofError::ExplainabilityBelowThreshold { index, minimum, report }) => {
+                assert_eq!(index, 0.5);
+                assert_eq!(minimum, 0.98);
+                let unexplained: Vec<&str> = report.unexplained().map(|s| s.operation.as_str()).collect();
+                assert_eq!(unexplained, ["heuristic"]);
+            }
+            other => panic!("expected ExplainabilityBelowThreshold, got {:?}", other),
+        }
+        
+        // Discounting the unbacked operation lets the trace through
+        let engine = ProofEngine::with_config(EngineConfig {
+            explainability_weights: HashMap::from([("heuristic".to_string(), 0.0)]),
+            ..Default::default()
+        });
+        assert!(engine.check_explainability(&trace).is_ok());
+    }
+    
+    fn proven_trace(engine: &ProofEngine) -> TraceEnvelope {
+        let observations = vec![
+            "The sky is blue".to_string(),
+            "Blue things reflect certain wavelengths".to_string(),
+        ];
+        engine.prove("The sky reflects certain wavelengths", observations, test_sign).unwrap().0
+    }
+    
+    #[test]
+    fn test_replay_reproduces_every_builtin_step() {
+        let engine = ProofEngine::new();
+        let registry = engine.step_registry();
+        assert_eq!(registry.operations().count(), 8);
+        
+        let report = proven_trace(&engine).replay(&registry);
+        assert!(report.is_valid(), "{}", report);
+        assert_eq!((report.matched, report.total), (6, 6));
+        
+        // Contradictory traces replay too, with a failing contradiction step.
+        // Proving never builds a chain with C > 0, so the chain is built by hand.
+        let observations = vec!["The service is healthy".to_string(), "The service is not healthy".to_string()];
+        let mut chain = CausalChain::new("The service is healthy", observations.clone());
+        chain.add_contradiction(CausalLink::new(
+            observations[0].clone(),
+            observations[1].clone(),
+            CausalRelation::Contradicts,
+            vec![],
+        ));
+        let trace = engine.generate_trace("The service is healthy", &observations, &chain).unwrap();
+        assert!(trace.steps[3].output.ends_with("(FAIL)"));
+        assert!(trace.replay(&registry).is_valid());
+    }
+    
+    #[test]
+    fn test_normalize_evidence_dedupes_before_chaining() {
+        let observations = vec![
+            "Fact A".to_string(),
+            "fact  a ".to_string(),
+            "Fact B".to_string(),
+            " FACT B".to_string(),
+        ];
+        let claim = "Fact A and Fact B";
+        
+        let (plain_trace, plain) = ProofEngine::new().prove(claim, observations.clone(), test_sign).unwrap();
+        assert_eq!(plain.causal_chain.len(), 4);
+        assert!(plain_trace.steps.iter().all(|s| s.operation != "normalize_evidence"));
+        
+        let engine = ProofEngine::with_config(EngineConfig {
+            normalize_evidence: true,
+            evidence_case_fold: true,
+            ..Default::default()
+        });
+        let (trace, receipt) = engine.prove(claim, observations.clone(), test_sign).unwrap();
+        
+        // Two distinct statements chain once each; the receipt keeps all four
+        assert_eq!(receipt.causal_chain.len(), 2);
+        assert!(receipt.causal_chain.iter().all(|link| !link.contains("fact  a")));
+        assert_eq!(receipt.evidence, observations);
+        assert!(receipt.verify_hash());
+        assert!(engine.verify_receipt_deep(&receipt, test_verify).is_valid());
+        
+        let step = trace.steps.iter().find(|s| s.operation == "normalize_evidence").unwrap();
+        let output: serde_json::Value = serde_json::from_str(&step.output).unwrap();
+        assert_eq!(output["duplicates_removed"], 2);
+        assert_eq!(output["items"][0]["indices"], serde_json::json!([0, 1]));
+        assert_eq!(output["items"][1]["indices"], serde_json::json!([2, 3]));
+        assert!(trace.replay(&engine.step_registry()).is_valid());
+        
+        // Without case folding every variant differs in case, so none is removed
+        let engine = ProofEngine::with_config(EngineConfig { normalize_evidence: true, ..Default::default() });
+        assert!(engine.verify_claim(claim, &observations).unwrap());
+        let (_, receipt) = engine.prove(claim, observations, test_sign).unwrap();
+        assert_eq!(receipt.causal_chain.len(), 4);
+    }
+
+    #[test]
+    fn test_receipts_rederive_under_their_own_normalization() {
+        let observations = vec!["Fact A".to_string(), "fact  a ".to_string(), "Fact B".to_string()];
+        let claim = "Fact A and Fact B";
+        let plain_engine = ProofEngine::new();
+        let folding_engine = ProofEngine::with_config(EngineConfig {
+            normalize_evidence: true,
+            evidence_case_fold: true,
+            ..Default::default()
+        });
+
+        let (_, plain) = plain_engine.prove(claim, observations.clone(), test_sign).unwrap();
+        let (_, folded) = folding_engine.prove(claim, observations, test_sign).unwrap();
+        assert_eq!(plain.normalization, None);
+        assert_eq!(folded.normalization, Some(EvidenceNormalization { case_fold: true }));
+        assert!(!plain.to_json().unwrap().contains("normalization"));
+
+        // Either engine re-derives either receipt's chain
+        for engine in [&plain_engine, &folding_engine] {
+            assert!(engine.verify_receipt_deep(&plain, test_verify).is_valid());
+            assert!(engine.verify_receipt_deep(&folded, test_verify).is_valid());
+            assert_eq!(engine.derive_chain(&folded).unwrap().to_string_chain().len(), 2);
+        }
+
+        // The record is hashed, so it cannot be dropped to change the derivation
+        let mut stripped = folded.clone();
+        stripped.normalization = None;
+        assert!(!stripped.verify_hash());
+    }
+
+    #[test]
+    fn test_replay_catches_fabricated_step() {
+        let engine = ProofEngine::new();
+        let mut trace = proven_trace(&engine);
+        
+        // Claim a contradiction passed, rehashing so the trace stays intact
+        let axioms = trace.steps[3].axioms_applied.clone();
+        trace.steps[3] = TraceStep::new(3, "check_contradictions", "C = 1", "C = 1 (PASS)", axioms);
+        trace.finalize();
+        assert!(trace.verify_integrity());
+        
+        let report = trace.replay(&engine.step_registry());
+        assert!(!report.is_valid());
+        assert!(report.integrity_ok);
+        let failures: Vec<usize> = report.failures().map(|s| s.index).collect();
+        assert_eq!(failures, [3]);
+        assert!(matches!(&report.steps[3].status, ReplayStatus::Failed { reason } if reason.contains("0 contradiction")));
+        
+        // A wrong output for a consistent input is a mismatch
+        let axioms = trace.steps[5].axioms_applied.clone();
+        trace.steps[5] = TraceStep::new(5, "finalize", "Proof complete", "Claim 'something else' verified with C=0", axioms);
+        trace.finalize();
+        let report = trace.replay(&engine.step_registry());
+        assert_eq!(
+            report.steps[5].status,
+            ReplayStatus::Mismatch { expected: "Claim 'The sky reflects certain wavelengths' verified with C=0".to_string() }
+        );
+    }
+    
+    #[test]
+    fn test_replay_reports_unknown_operations() {
+        let trace = TraceBuilder::new("Claim")
+            .add_step("finalize", "Proof complete", "Claim 'Claim' verified with C=0", vec![])
+            .add_step("heuristic", "in", "out", vec![])
+            .build();
+        
+        let report = trace.replay(&ProofEngine::new().step_registry());
+        assert_eq!(report.steps[0].status, ReplayStatus::Matched);
+        assert_eq!(report.steps[1].status, ReplayStatus::UnknownOperation);
+        assert!(!report.is_valid());
+        assert_eq!(report.to_string(), "1/2 steps reproduced; not reproduced: 1. heuristic (unknown operation)");
+    }
 }
 
@@ -0,0 +1,118 @@
+//! Evidence with provenance
+//!
+//! An [`Evidence`] item pairs an observation with where it came from, so
+//! receipts can say which sources a proof rests on. [`file`] reads evidence
+//! from line, JSON and CSV files, and [`normalize`] deduplicates
+//! whitespace and case variants of a statement.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+pub mod file;
+pub mod normalize;
+
+/// Trust assigned to evidence supplied without a source
+pub const DEFAULT_TRUST: f64 = 1.0;
+
+/// An observation plus its provenance
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Evidence {
+    /// The observed statement the engine reasons over
+    pub statement: String,
+    /// Where the statement was obtained
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source_uri: Option<String>,
+    /// When the statement was obtained
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retrieved_at: Option<DateTime<Utc>>,
+    /// SHA-256 of the retrieved content (the statement itself by default)
+    pub content_hash: String,
+    /// Trust in the source, 0.0..=1.0
+    pub trust: f64,
+}
+
+impl Evidence {
+    /// Evidence with no recorded source
+    pub fn new(statement: impl Into<String>) -> Self {
+        let statement = statement.into();
+        let content_hash = hex::encode(Sha256::digest(statement.as_bytes()));
+
+        Self {
+            statement,
+            source_uri: None,
+            retrieved_at: None,
+            content_hash,
+            trust: DEFAULT_TRUST,
+        }
+    }
+
+    /// Record the source URI and retrieval time
+    pub fn with_source(mut self, uri: impl Into<String>, retrieved_at: DateTime<Utc>) -> Self {
+        self.source_uri = Some(uri.into());
+        self.retrieved_at = Some(retrieved_at);
+        self
+    }
+
+    /// Record the hash of the raw retrieved content
+    pub fn with_content_hash(mut self, content_hash: impl Into<String>) -> Self {
+        self.content_hash = content_hash.into();
+        self
+    }
+
+    /// Set trust in the source (clamped to 0.0..=1.0)
+    pub fn with_trust(mut self, trust: f64) -> Self {
+        self.trust = trust.clamp(0.0, 1.0);
+        self
+    }
+
+    /// Whether this item carries nothing beyond its statement
+    pub fn is_bare(&self) -> bool {
+        self.source_uri.is_none()
+            && self.retrieved_at.is_none()
+            && self.trust == DEFAULT_TRUST
+            && self.content_hash == hex::encode(Sha256::digest(self.statement.as_bytes()))
+    }
+
+    /// Provenance worth recording for `evidence`: every item if any has a
+    /// source, otherwise none, so bare-evidence hashes stay unchanged
+    pub fn provenance_of(evidence: &[Evidence]) -> Vec<Evidence> {
+        if evidence.iter().all(Evidence::is_bare) {
+            Vec::new()
+        } else {
+            evidence.to_vec()
+        }
+    }
+}
+
+impl From<String> for Evidence {
+    fn from(statement: String) -> Self {
+        Evidence::new(statement)
+    }
+}
+
+impl From<&str> for Evidence {
+    fn from(statement: &str) -> Self {
+        Evidence::new(statement)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_bare_evidence() {
+        let bare = Evidence::new("It rained");
+        assert!(bare.is_bare());
+        assert!(Evidence::provenance_of(std::slice::from_ref(&bare)).is_empty());
+
+        let sourced = Evidence::new("Ground is wet").with_source("https://example.org/wx", Utc::now());
+        assert!(!sourced.is_bare());
+        assert_eq!(Evidence::provenance_of(&[bare, sourced]).len(), 2);
+
+        assert_eq!(Evidence::new("x").with_trust(7.0).trust, 1.0);
+    }
+}
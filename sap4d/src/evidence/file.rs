@@ -0,0 +1,411 @@
+//! Evidence files
+//!
+//! Evidence can be read from three formats:
+//!
+//! - **Lines**: one statement per non-empty line.
+//! - **JSON**: an array whose elements are statements or
+//!   `{"statement", "source", "trust"}` objects (`source` and `trust`
+//!   optional).
+//! - **CSV**: a header row and one statement per row, read from a named
+//!   column, with optional source and trust columns.
+//!
+//! A row that parses but is not usable evidence (an empty statement, a trust
+//! outside 0.0..=1.0, a short CSV row) is a [`RowError`] carrying its line
+//! number. Strict loading rejects the file on the first such row; lenient
+//! loading skips it and reports it. A file that cannot be parsed at all is
+//! always rejected.
+//!
+//! The loaded file is summarised as an [`EvidenceFile`] (path and SHA-256 of
+//! the raw bytes) for the receipt's provenance.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fmt;
+use std::path::Path;
+use thiserror::Error;
+
+use super::Evidence;
+
+/// Column read for statements when none is named
+pub const DEFAULT_CSV_COLUMN: &str = "statement";
+
+/// CSV columns evidence is read from
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CsvColumns {
+    /// Column holding the statement
+    pub statement: String,
+    /// Column holding the source URI, if any
+    pub source: Option<String>,
+    /// Column holding the trust score, if any
+    pub trust: Option<String>,
+}
+
+impl Default for CsvColumns {
+    fn default() -> Self {
+        Self {
+            statement: DEFAULT_CSV_COLUMN.to_string(),
+            source: None,
+            trust: None,
+        }
+    }
+}
+
+/// How an evidence file is laid out
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EvidenceFormat {
+    Lines,
+    Json,
+    Csv(CsvColumns),
+}
+
+impl EvidenceFormat {
+    /// Format implied by the file extension (`.json`, `.csv`, otherwise lines)
+    pub fn from_path(path: &Path, columns: CsvColumns) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()).map(str::to_ascii_lowercase).as_deref() {
+            Some("json") => Self::Json,
+            Some("csv") => Self::Csv(columns),
+            _ => Self::Lines,
+        }
+    }
+}
+
+/// An evidence file as recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceFile {
+    /// Path the file was read from
+    pub path: String,
+    /// SHA-256 of the file's raw bytes
+    pub sha256: String,
+}
+
+/// A row that could not be turned into evidence
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RowError {
+    /// 1-based line the row starts on
+    pub line: usize,
+    pub message: String,
+}
+
+impl fmt::Display for RowError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "line {}: {}", self.line, self.message)
+    }
+}
+
+/// Errors loading an evidence file
+#[derive(Debug, Error)]
+pub enum EvidenceFileError {
+    #[error("{path}: {source}")]
+    Io {
+        path: String,
+        #[source]
+        source: std::io::Error,
+    },
+
+    #[error("{path}: {message}")]
+    Unreadable { path: String, message: String },
+
+    #[error("{path}: {row}")]
+    MalformedRow { path: String, row: RowError },
+}
+
+/// Evidence read from a file
+#[derive(Debug, Clone)]
+pub struct LoadedEvidence {
+    pub evidence: Vec<Evidence>,
+    pub file: EvidenceFile,
+    /// Rows skipped under lenient loading
+    pub skipped: Vec<RowError>,
+}
+
+/// Read `path` as `format`; with `lenient`, malformed rows are skipped
+pub fn load(path: &Path, format: &EvidenceFormat, lenient: bool) -> Result<LoadedEvidence, EvidenceFileError> {
+    let display = path.display().to_string();
+    let bytes = std::fs::read(path).map_err(|source| EvidenceFileError::Io {
+        path: display.clone(),
+        source,
+    })?;
+    let content = String::from_utf8(bytes.clone()).map_err(|e| EvidenceFileError::Unreadable {
+        path: display.clone(),
+        message: e.to_string(),
+    })?;
+
+    let (evidence, skipped) = parse(&content, format, lenient).map_err(|e| match e {
+        ParseError::Unreadable(message) => EvidenceFileError::Unreadable { path: display.clone(), message },
+        ParseError::Row(row) => EvidenceFileError::MalformedRow { path: display.clone(), row },
+    })?;
+
+    Ok(LoadedEvidence {
+        evidence,
+        file: EvidenceFile {
+            path: display,
+            sha256: hex::encode(Sha256::digest(&bytes)),
+        },
+        skipped,
+    })
+}
+
+/// Why [`parse`] rejected its input
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseError {
+    /// The content is not valid for the format at all
+    Unreadable(String),
+    /// A row is malformed and loading is strict
+    Row(RowError),
+}
+
+/// Parse evidence from `content`, returning it with any skipped rows
+pub fn parse(
+    content: &str,
+    format: &EvidenceFormat,
+    lenient: bool,
+) -> Result<(Vec<Evidence>, Vec<RowError>), ParseError> {
+    let rows = match format {
+        EvidenceFormat::Lines => content
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty())
+            .map(|line| Ok(Evidence::new(line)))
+            .collect(),
+        EvidenceFormat::Json => json_rows(content)?,
+        EvidenceFormat::Csv(columns) => csv_rows(content, columns)?,
+    };
+
+    let mut evidence = Vec::new();
+    let mut skipped = Vec::new();
+    for row in rows {
+        match row {
+            Ok(item) => evidence.push(item),
+            Err(row) if lenient => skipped.push(row),
+            Err(row) => return Err(ParseError::Row(row)),
+        }
+    }
+    Ok((evidence, skipped))
+}
+
+/// Evidence from the non-empty parts of a row
+fn evidence_row(statement: &str, source: Option<&str>, trust: Option<f64>) -> Result<Evidence, String> {
+    let statement = statement.trim();
+    if statement.is_empty() {
+        return Err("statement is empty".to_string());
+    }
+
+    let mut evidence = Evidence::new(statement);
+    if let Some(source) = source.map(str::trim).filter(|s| !s.is_empty()) {
+        evidence.source_uri = Some(source.to_string());
+    }
+    if let Some(trust) = trust {
+        if !(0.0..=1.0).contains(&trust) {
+            return Err(format!("trust {} is outside 0.0..=1.0", trust));
+        }
+        evidence = evidence.with_trust(trust);
+    }
+    Ok(evidence)
+}
+
+/// 1-based line of byte `offset` in `content`
+fn line_at(content: &str, offset: usize) -> usize {
+    content[..offset].matches('\n').count() + 1
+}
+
+/// One row per element of a JSON array
+///
+/// Elements are read one at a time so every row keeps its own line number.
+fn json_rows(content: &str) -> Result<Vec<Result<Evidence, RowError>>, ParseError> {
+    let unreadable = |offset: usize, message: &str| {
+        ParseError::Unreadable(format!("line {}: {}", line_at(content, offset), message))
+    };
+    let skip_ws = |mut pos: usize| {
+        while content[pos..].starts_with(|c: char| c.is_ascii_whitespace()) {
+            pos += 1;
+        }
+        pos
+    };
+
+    let mut pos = skip_ws(0);
+    if !content[pos..].starts_with('[') {
+        return Err(unreadable(pos, "expected a JSON array of evidence"));
+    }
+    pos = skip_ws(pos + 1);
+
+    let mut rows = Vec::new();
+    if content[pos..].starts_with(']') {
+        pos += 1;
+    } else {
+        loop {
+            let start = pos;
+            let mut stream = serde_json::Deserializer::from_str(&content[start..]).into_iter::<serde_json::Value>();
+            let value = match stream.next() {
+                Some(Ok(value)) => value,
+                Some(Err(e)) => {
+                    // serde_json positions are relative to the element; report the file line
+                    let line = line_at(content, start) + e.line() - 1;
+                    let message = e.to_string();
+                    let message = message.split(" at line ").next().unwrap_or_default();
+                    return Err(ParseError::Unreadable(format!("line {}: {}", line, message)));
+                }
+                None => return Err(unreadable(start, "unterminated array")),
+            };
+            pos = skip_ws(start + stream.byte_offset());
+
+            let line = line_at(content, start);
+            rows.push(json_evidence(value).map_err(|message| RowError { line, message }));
+
+            if content[pos..].starts_with(',') {
+                pos = skip_ws(pos + 1);
+            } else if content[pos..].starts_with(']') {
+                pos += 1;
+                break;
+            } else {
+                return Err(unreadable(pos, "expected ',' or ']'"));
+            }
+        }
+    }
+
+    if skip_ws(pos) != content.len() {
+        return Err(unreadable(pos, "trailing characters after the array"));
+    }
+    Ok(rows)
+}
+
+/// A statement string or a `{statement, source, trust}` object
+fn json_evidence(value: serde_json::Value) -> Result<Evidence, String> {
+    match value {
+        serde_json::Value::String(statement) => evidence_row(&statement, None, None),
+        serde_json::Value::Object(object) => {
+            let statement = match object.get("statement") {
+                Some(serde_json::Value::String(statement)) => statement,
+                Some(_) => return Err("statement is not a string".to_string()),
+                None => return Err("missing statement".to_string()),
+            };
+            let source = match object.get("source") {
+                None | Some(serde_json::Value::Null) => None,
+                Some(serde_json::Value::String(source)) => Some(source.as_str()),
+                Some(_) => return Err("source is not a string".to_string()),
+            };
+            let trust = match object.get("trust") {
+                None | Some(serde_json::Value::Null) => None,
+                Some(trust) => Some(trust.as_f64().ok_or("trust is not a number")?),
+            };
+            evidence_row(statement, source, trust)
+        }
+        other => Err(format!("expected a statement or an object, found {}", other)),
+    }
+}
+
+fn csv_rows(content: &str, columns: &CsvColumns) -> Result<Vec<Result<Evidence, RowError>>, ParseError> {
+    let mut reader = csv::ReaderBuilder::new().from_reader(content.as_bytes());
+    let headers = reader
+        .headers()
+        .map_err(|e| ParseError::Unreadable(e.to_string()))?
+        .clone();
+    let index = |name: &str| {
+        headers
+            .iter()
+            .position(|header| header.trim() == name)
+            .ok_or_else(|| ParseError::Unreadable(format!("no column named '{}'", name)))
+    };
+    let statement = index(&columns.statement)?;
+    let source = columns.source.as_deref().map(index).transpose()?;
+    let trust = columns.trust.as_deref().map(index).transpose()?;
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = match record {
+            Ok(record) => record,
+            Err(e) => {
+                // Unequal row lengths are row errors; anything else leaves the file unreadable
+                let line = match e.kind() {
+                    csv::ErrorKind::UnequalLengths { pos: Some(pos), .. } => pos.line() as usize,
+                    _ => return Err(ParseError::Unreadable(e.to_string())),
+                };
+                rows.push(Err(RowError { line, message: "wrong number of fields".to_string() }));
+                continue;
+            }
+        };
+        let line = record.position().map_or(0, |pos| pos.line() as usize);
+        let row = csv_evidence(&record, statement, source, trust);
+        rows.push(row.map_err(|message| RowError { line, message }));
+    }
+    Ok(rows)
+}
+
+/// Evidence from the statement, source and trust fields at the given indices
+fn csv_evidence(
+    record: &csv::StringRecord,
+    statement: usize,
+    source: Option<usize>,
+    trust: Option<usize>,
+) -> Result<Evidence, String> {
+    let trust = match trust.and_then(|i| record.get(i)).map(str::trim).filter(|t| !t.is_empty()) {
+        Some(trust) => Some(trust.parse::<f64>().map_err(|_| format!("trust '{}' is not a number", trust))?),
+        None => None,
+    };
+    evidence_row(&record[statement], source.and_then(|i| record.get(i)), trust)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_json_strings_and_objects() {
+        let content = r#"[
+  "It rained",
+  {"statement": "Ground is wet", "source": "https://example.org/wx", "trust": 0.8}
+]"#;
+        let (evidence, skipped) = parse(content, &EvidenceFormat::Json, false).unwrap();
+        assert!(skipped.is_empty());
+        assert_eq!(evidence[0], Evidence::new("It rained"));
+        assert_eq!(evidence[1].source_uri.as_deref(), Some("https://example.org/wx"));
+        assert_eq!(evidence[1].trust, 0.8);
+    }
+
+    #[test]
+    fn test_json_malformed_rows_carry_line_numbers() {
+        let content = "[\n  \"ok\",\n  {\"source\": \"x\"},\n  {\"statement\": \"y\", \"trust\": 2}\n]";
+        let err = parse(content, &EvidenceFormat::Json, false).unwrap_err();
+        assert_eq!(err, ParseError::Row(RowError { line: 3, message: "missing statement".to_string() }));
+
+        let (evidence, skipped) = parse(content, &EvidenceFormat::Json, true).unwrap();
+        assert_eq!(evidence.len(), 1);
+        assert_eq!(skipped.iter().map(|row| row.line).collect::<Vec<_>>(), [3, 4]);
+
+        let err = parse("[\"a\",\n  nope]", &EvidenceFormat::Json, true).unwrap_err();
+        assert!(matches!(err, ParseError::Unreadable(ref msg) if msg.starts_with("line 2: ")), "{:?}", err);
+    }
+
+    #[test]
+    fn test_csv_columns() {
+        let content = "id,text,url,score\n1,It rained,https://example.org/wx,0.9\n2,,,\n3,Ground is wet,,abc\n4,Sky is grey,,\n";
+        let columns = CsvColumns {
+            statement: "text".to_string(),
+            source: Some("url".to_string()),
+            trust: Some("score".to_string()),
+        };
+        let format = EvidenceFormat::Csv(columns);
+
+        let err = parse(content, &format, false).unwrap_err();
+        assert_eq!(err, ParseError::Row(RowError { line: 3, message: "statement is empty".to_string() }));
+
+        let (evidence, skipped) = parse(content, &format, true).unwrap();
+        assert_eq!(evidence.len(), 2);
+        assert_eq!(evidence[0].source_uri.as_deref(), Some("https://example.org/wx"));
+        assert_eq!(evidence[0].trust, 0.9);
+        assert_eq!(evidence[1], Evidence::new("Sky is grey"));
+        assert_eq!(skipped.iter().map(|row| row.line).collect::<Vec<_>>(), [3, 4]);
+
+        let missing = EvidenceFormat::Csv(CsvColumns::default());
+        assert!(matches!(parse(content, &missing, true), Err(ParseError::Unreadable(_))));
+    }
+
+    #[test]
+    fn test_format_from_extension() {
+        let columns = CsvColumns::default();
+        assert_eq!(EvidenceFormat::from_path(Path::new("e.JSON"), columns.clone()), EvidenceFormat::Json);
+        assert_eq!(EvidenceFormat::from_path(Path::new("e.csv"), columns.clone()), EvidenceFormat::Csv(columns.clone()));
+        assert_eq!(EvidenceFormat::from_path(Path::new("e.txt"), columns), EvidenceFormat::Lines);
+    }
+}
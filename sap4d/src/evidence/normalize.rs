@@ -0,0 +1,153 @@
+//! Evidence normalization and deduplication
+//!
+//! Whitespace and case variants of one statement ("Fact A", "fact  a ")
+//! would otherwise enter a causal chain as separate observations.
+//! [`NormalizedEvidence`] groups the original items by their normalized
+//! text, keeping the first original of each group and the indices of all
+//! of them, so the chain can be built once per distinct statement while
+//! receipts still list the evidence exactly as supplied.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Trim and collapse internal whitespace, case-folding when `case_fold` is set
+pub fn normalize_statement(text: &str, case_fold: bool) -> String {
+    let collapsed = text.split_whitespace().collect::<Vec<_>>().join(" ");
+    if case_fold {
+        fold_case(&collapsed)
+    } else {
+        collapsed
+    }
+}
+
+/// Caseless form of `text`
+///
+/// Lowercases each character on its own (so a final sigma folds to `σ`,
+/// as it does mid-word) and expands `ß` to `ss`, so "STRASSE" and
+/// "Straße" fold alike.
+pub fn fold_case(text: &str) -> String {
+    let mut folded = String::with_capacity(text.len());
+    for c in text.chars().flat_map(char::to_lowercase) {
+        match c {
+            'ß' => folded.push_str("ss"),
+            'ς' => folded.push('σ'),
+            'ſ' => folded.push('s'),
+            c => folded.push(c),
+        }
+    }
+    folded
+}
+
+/// One distinct statement and the original items it stands for
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedItem {
+    /// The statement after normalization
+    pub normalized: String,
+    /// Indices of the original items, ascending; the first is the one kept
+    pub indices: Vec<usize>,
+}
+
+/// How evidence was normalized before chaining, as recorded in a receipt
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EvidenceNormalization {
+    /// Whether statements were case-folded
+    pub case_fold: bool,
+}
+
+/// Evidence grouped by normalized statement, in order of first appearance
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct NormalizedEvidence {
+    /// Whether statements were case-folded
+    pub case_fold: bool,
+    /// Number of original items
+    pub original_count: usize,
+    pub items: Vec<NormalizedItem>,
+}
+
+impl NormalizedEvidence {
+    /// Group `evidence` by [`normalize_statement`]
+    pub fn new(evidence: &[String], case_fold: bool) -> Self {
+        let mut items: Vec<NormalizedItem> = Vec::new();
+        let mut positions: HashMap<String, usize> = HashMap::new();
+
+        for (index, statement) in evidence.iter().enumerate() {
+            let normalized = normalize_statement(statement, case_fold);
+            match positions.get(&normalized) {
+                Some(&position) => items[position].indices.push(index),
+                None => {
+                    positions.insert(normalized.clone(), items.len());
+                    items.push(NormalizedItem { normalized, indices: vec![index] });
+                }
+            }
+        }
+
+        Self {
+            case_fold,
+            original_count: evidence.len(),
+            items,
+        }
+    }
+
+    /// The settings this grouping was made under
+    pub fn settings(&self) -> EvidenceNormalization {
+        EvidenceNormalization { case_fold: self.case_fold }
+    }
+
+    /// Original items dropped as duplicates of an earlier one
+    pub fn duplicates_removed(&self) -> usize {
+        self.original_count - self.items.len()
+    }
+
+    /// The first original statement of each distinct item, in order
+    pub fn kept(&self, evidence: &[String]) -> Vec<String> {
+        self.items.iter().map(|item| evidence[item.indices[0]].clone()).collect()
+    }
+
+    /// Indices of the original items that distinct item `item` stands for
+    pub fn originals(&self, item: usize) -> &[usize] {
+        self.items.get(item).map(|item| item.indices.as_slice()).unwrap_or_default()
+    }
+
+    /// The distinct item original item `index` was grouped into
+    pub fn item_of(&self, index: usize) -> Option<usize> {
+        self.items.iter().position(|item| item.indices.contains(&index))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn strings(items: &[&str]) -> Vec<String> {
+        items.iter().map(|s| s.to_string()).collect()
+    }
+
+    #[test]
+    fn test_whitespace_variants_grouped() {
+        let evidence = strings(&["Fact A", " Fact   A ", "Fact B", "fact a"]);
+
+        let exact = NormalizedEvidence::new(&evidence, false);
+        assert_eq!(exact.kept(&evidence), ["Fact A", "Fact B", "fact a"]);
+        assert_eq!(exact.originals(0), [0, 1]);
+        assert_eq!(exact.duplicates_removed(), 1);
+
+        let folded = NormalizedEvidence::new(&evidence, true);
+        assert_eq!(folded.kept(&evidence), ["Fact A", "Fact B"]);
+        assert_eq!(folded.originals(0), [0, 1, 3]);
+        assert_eq!(folded.item_of(3), Some(0));
+        assert_eq!(folded.item_of(2), Some(1));
+        assert_eq!(folded.item_of(4), None);
+        assert_eq!(folded.duplicates_removed(), 2);
+    }
+
+    #[test]
+    fn test_unicode_case_folding() {
+        assert_eq!(fold_case("Straße"), fold_case("STRASSE"));
+        assert_eq!(fold_case("ÉCOLE"), "école");
+        assert_eq!(fold_case("ΟΔΟΣ"), fold_case("οδος"));
+        assert_eq!(normalize_statement("\u{00A0}Ünïcode\tTEXT\n", true), "ünïcode text");
+        assert_ne!(normalize_statement("Straße", false), normalize_statement("STRASSE", false));
+    }
+}
@@ -0,0 +1,100 @@
+//! Field-delimited hashing
+//!
+//! Trace step, causal link and audit result hashes were first computed by
+//! feeding their fields to SHA-256 back to back, which loses the field
+//! boundaries: evidence `["ab", "c"]` and `["a", "bc"]` hashed alike, as
+//! did text moved from one field into its neighbour. Those are
+//! [`LEGACY_HASH_VERSION`] hashes and still verify.
+//!
+//! [`FIELD_HASH_VERSION`] hashes go through [`FieldHasher`], which starts
+//! with a domain tag, prefixes every field with its byte length and every
+//! list with its item count, so two different field sequences never feed
+//! SHA-256 the same bytes. Receipts have hashed canonical JSON since their
+//! own v2 and are unaffected.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sha2::{Digest, Sha256};
+
+/// Raw concatenation of fields (records without a `hash_version`)
+pub const LEGACY_HASH_VERSION: u32 = 1;
+
+/// Length-prefixed fields, written by this release
+pub const FIELD_HASH_VERSION: u32 = 2;
+
+/// Serde default for records written before `hash_version` existed
+pub fn legacy_hash_version() -> u32 {
+    LEGACY_HASH_VERSION
+}
+
+/// SHA-256 over length-prefixed fields
+///
+/// ```
+/// use sap4d::hashing::FieldHasher;
+///
+/// let split = |a: &str, b: &str| FieldHasher::new("example").list(&[a, b]).finish();
+/// assert_ne!(split("ab", "c"), split("a", "bc"));
+/// ```
+pub struct FieldHasher(Sha256);
+
+impl FieldHasher {
+    /// Start a hash whose first field is `domain`, so records of
+    /// different kinds never share a hash
+    pub fn new(domain: &str) -> Self {
+        Self(Sha256::new()).bytes(domain.as_bytes())
+    }
+
+    /// Add a byte field
+    pub fn bytes(mut self, bytes: &[u8]) -> Self {
+        self.0.update((bytes.len() as u64).to_be_bytes());
+        self.0.update(bytes);
+        self
+    }
+
+    /// Add a text field
+    pub fn str(self, text: &str) -> Self {
+        self.bytes(text.as_bytes())
+    }
+
+    /// Add a list of text fields, prefixed with its length
+    pub fn list<S: AsRef<str>>(mut self, items: &[S]) -> Self {
+        self.0.update((items.len() as u64).to_be_bytes());
+        items.iter().fold(self, |hasher, item| hasher.str(item.as_ref()))
+    }
+
+    /// Add a fixed-width integer
+    pub fn u64(mut self, value: u64) -> Self {
+        self.0.update(value.to_be_bytes());
+        self
+    }
+
+    /// Add a flag
+    pub fn bool(mut self, value: bool) -> Self {
+        self.0.update([value as u8]);
+        self
+    }
+
+    /// Hex digest
+    pub fn finish(self) -> String {
+        hex::encode(self.0.finalize())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_field_boundaries_are_hashed() {
+        let pair = |a: &str, b: &str| FieldHasher::new("t").str(a).str(b).finish();
+        assert_ne!(pair("ab", "c"), pair("a", "bc"));
+        assert_ne!(pair("a\n", "b"), pair("a", "\nb"));
+
+        // Moving an item between adjacent lists
+        let lists = |a: &[&str], b: &[&str]| FieldHasher::new("t").list(a).list(b).finish();
+        assert_ne!(lists(&["x"], &[]), lists(&[], &["x"]));
+        assert_ne!(lists(&["", ""], &[]), lists(&[""], &[""]));
+
+        assert_ne!(FieldHasher::new("a").finish(), FieldHasher::new("b").finish());
+    }
+}
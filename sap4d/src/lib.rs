@@ -3,14 +3,54 @@
 //! Causal inference engine with stepwise logic traces for deterministic verification.
 //! Implements the 4D neuro-symbolic reasoning framework with C=0 enforcement.
 //!
+//! # Features
+//!
+//! - `cli` (default): the `sap4d-cli` binary with watch mode, bulk checks
+//!   and portal publishing, on top of `engine`.
+//! - `engine`: the proof engine, trace builder and receipts.
+//! - `anchors`: [`anchors::TrustAnchor`], the HMAC and Ed25519 keys
+//!   receipts are verified against; part of `engine`, and usable beside
+//!   `verify-only`.
+//! - `verify-only`: just receipt hash checks ([`verify`]) and
+//!   [`CausalLink::verify_integrity`], without chrono or serde_json.
+//!   Build it with `--no-default-features --features verify-only`.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
+#[cfg(feature = "anchors")]
+pub mod anchors;
+#[cfg(feature = "engine")]
 pub mod axioms;
+#[cfg(feature = "cli")]
+pub mod bulk;
 pub mod causal;
+#[cfg(feature = "engine")]
+pub mod claim;
+#[cfg(feature = "engine")]
+pub mod clock;
+#[cfg(feature = "engine")]
+pub mod daemon;
+#[cfg(feature = "engine")]
 pub mod engine;
+#[cfg(feature = "engine")]
+pub mod evidence;
+pub mod hashing;
+#[cfg(feature = "engine")]
+pub mod operation;
+#[cfg(feature = "engine")]
 pub mod receipt;
+#[cfg(feature = "engine")]
+pub mod signing;
+#[cfg(feature = "engine")]
 pub mod trace;
+#[cfg(feature = "verify-only")]
+pub mod verify;
+#[cfg(feature = "cli")]
+pub mod watch;
 
+#[cfg(feature = "engine")]
+use serde::{Deserialize, Serialize};
+#[cfg(feature = "engine")]
 use thiserror::Error;
 
 /// Substrate authority identifier
@@ -20,6 +60,7 @@ pub const SUBSTRATE: &str = "Alexis Adams";
 pub const PROJECTION: &str = "AXIOMHIVE PROJECTION";
 
 /// Proof engine errors
+#[cfg(feature = "engine")]
 #[derive(Error, Debug)]
 pub enum ProofError {
     #[error("Contradiction detected: {0}")]
@@ -37,9 +78,37 @@ pub enum ProofError {
     #[error("Claim not supported by evidence")]
     UnsupportedClaim,
 
+    #[error("Ambiguous claim: {0}")]
+    AmbiguousClaim(String),
+
+    #[error("Conjunct '{conjunct}' failed: {reason}")]
+    ConjunctFailed { conjunct: String, reason: String },
+
+    #[error("No disjunct of '{claim}' holds: {}", .reasons.join("; "))]
+    NoDisjunctHolds { claim: String, reasons: Vec<String> },
+
+    #[error("'{claim}' fails for {}", .failures.join("; "))]
+    InstantiationFailed { claim: String, failures: Vec<String> },
+
     #[error("Invariance violation: C != 0")]
     InvarianceViolation,
 
+    #[error("Axiom {axiom} {drift} since receipt issuance")]
+    AxiomDrift { axiom: String, drift: String },
+
+    #[error("Insufficient evidence: {required} item(s) required, {provided} provided")]
+    InsufficientEvidence { required: usize, provided: usize },
+
+    #[error("Insufficient evidence sources: {required} distinct source(s) required, {provided} provided")]
+    InsufficientSources { required: usize, provided: usize },
+
+    #[error("Explainability index {index} below minimum {minimum}: {report}")]
+    ExplainabilityBelowThreshold {
+        index: f64,
+        minimum: f64,
+        report: ExplainabilityReport,
+    },
+
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
 
@@ -47,12 +116,135 @@ pub enum ProofError {
     Internal(String),
 }
 
+#[cfg(feature = "engine")]
 pub type Result<T> = std::result::Result<T, ProofError>;
 
+/// Stable, machine-readable code for each [`ProofError`] variant
+///
+/// Messages may change between releases; these strings do not.
+#[cfg(feature = "engine")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ErrorCode {
+    Contradiction,
+    AxiomViolation,
+    CausalBreak,
+    InvalidEvidence,
+    UnsupportedClaim,
+    AmbiguousClaim,
+    ConjunctFailed,
+    NoDisjunctHolds,
+    InstantiationFailed,
+    InvarianceViolation,
+    AxiomDrift,
+    InsufficientEvidence,
+    InsufficientSources,
+    ExplainabilityBelowThreshold,
+    Serialization,
+    Internal,
+}
+
+#[cfg(feature = "engine")]
+impl ErrorCode {
+    /// The stable string form, e.g. `"UNSUPPORTED_CLAIM"`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorCode::Contradiction => "CONTRADICTION",
+            ErrorCode::AxiomViolation => "AXIOM_VIOLATION",
+            ErrorCode::CausalBreak => "CAUSAL_BREAK",
+            ErrorCode::InvalidEvidence => "INVALID_EVIDENCE",
+            ErrorCode::UnsupportedClaim => "UNSUPPORTED_CLAIM",
+            ErrorCode::AmbiguousClaim => "AMBIGUOUS_CLAIM",
+            ErrorCode::ConjunctFailed => "CONJUNCT_FAILED",
+            ErrorCode::NoDisjunctHolds => "NO_DISJUNCT_HOLDS",
+            ErrorCode::InstantiationFailed => "INSTANTIATION_FAILED",
+            ErrorCode::InvarianceViolation => "INVARIANCE_VIOLATION",
+            ErrorCode::AxiomDrift => "AXIOM_DRIFT",
+            ErrorCode::InsufficientEvidence => "INSUFFICIENT_EVIDENCE",
+            ErrorCode::InsufficientSources => "INSUFFICIENT_SOURCES",
+            ErrorCode::ExplainabilityBelowThreshold => "EXPLAINABILITY_BELOW_THRESHOLD",
+            ErrorCode::Serialization => "SERIALIZATION",
+            ErrorCode::Internal => "INTERNAL",
+        }
+    }
+}
+
+#[cfg(feature = "engine")]
+impl std::fmt::Display for ErrorCode {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "engine")]
+impl ProofError {
+    /// Stable code for this error
+    pub fn code(&self) -> ErrorCode {
+        match self {
+            ProofError::Contradiction(_) => ErrorCode::Contradiction,
+            ProofError::AxiomViolation(_) => ErrorCode::AxiomViolation,
+            ProofError::CausalBreak { .. } => ErrorCode::CausalBreak,
+            ProofError::InvalidEvidence(_) => ErrorCode::InvalidEvidence,
+            ProofError::UnsupportedClaim => ErrorCode::UnsupportedClaim,
+            ProofError::AmbiguousClaim(_) => ErrorCode::AmbiguousClaim,
+            ProofError::ConjunctFailed { .. } => ErrorCode::ConjunctFailed,
+            ProofError::NoDisjunctHolds { .. } => ErrorCode::NoDisjunctHolds,
+            ProofError::InstantiationFailed { .. } => ErrorCode::InstantiationFailed,
+            ProofError::InvarianceViolation => ErrorCode::InvarianceViolation,
+            ProofError::AxiomDrift { .. } => ErrorCode::AxiomDrift,
+            ProofError::InsufficientEvidence { .. } => ErrorCode::InsufficientEvidence,
+            ProofError::InsufficientSources { .. } => ErrorCode::InsufficientSources,
+            ProofError::ExplainabilityBelowThreshold { .. } => ErrorCode::ExplainabilityBelowThreshold,
+            ProofError::Serialization(_) => ErrorCode::Serialization,
+            ProofError::Internal(_) => ErrorCode::Internal,
+        }
+    }
+}
+
 // Re-exports
-pub use axioms::{Axiom, AxiomSet, OmegaSSoT};
-pub use causal::{CausalChain, CausalLink, CausalRelation};
-pub use engine::ProofEngine;
-pub use receipt::{Receipt, ReceiptBuilder};
-pub use trace::{TraceEnvelope, TraceStep};
+#[cfg(feature = "engine")]
+pub use axioms::{Axiom, AxiomSet, Comparison, OmegaSSoT, RuleSpec, ViolationRule};
+#[cfg(feature = "engine")]
+pub use axioms::history::{AxiomChange, Compatibility, SsotDiff, SsotHistory, SsotSnapshot};
+pub use causal::{CausalLink, CausalRelation};
+#[cfg(feature = "engine")]
+pub use causal::{CausalChain, Contradiction, ContradictionDetector, ContradictionKind};
+#[cfg(feature = "engine")]
+pub use claim::{Claim, ClaimExpr, CompoundRecord, InstanceProof, QuantifiedRecord, SubClaimOutcome, SubClaimStatus};
+#[cfg(feature = "engine")]
+pub use engine::{ProofEngine, ReceiptVerification};
+#[cfg(feature = "engine")]
+pub use evidence::Evidence;
+#[cfg(feature = "engine")]
+pub use evidence::normalize::{EvidenceNormalization, NormalizedEvidence, NormalizedItem};
+#[cfg(feature = "engine")]
+pub use operation::{OperationRecorder, Payload, PayloadCheck, PayloadResolver, SubOperation};
+#[cfg(feature = "engine")]
+pub use receipt::{AncestryError, Receipt, ReceiptBuilder, ReceiptFormat, RedactionError};
+#[cfg(feature = "engine")]
+pub use signing::{KeyedSignature, ReceiptSigner, SignatureError, SignerRegistry};
+#[cfg(feature = "engine")]
+pub use trace::{
+    ExplainabilityReport, ReplayReport, ReplayStatus, StepExplanation, StepExecutor, StepRegistry, StepReplay,
+    StepTiming, TraceEnvelope, TraceMetrics, TraceStep,
+};
+
+// Without the engine, the lightweight receipt stands in for the full one
+#[cfg(all(feature = "verify-only", not(feature = "engine")))]
+pub use verify::Receipt;
 
+
+#[cfg(all(test, feature = "engine"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_error_codes_are_stable() {
+        assert_eq!(ProofError::UnsupportedClaim.code().as_str(), "UNSUPPORTED_CLAIM");
+        assert_eq!(ProofError::Contradiction("x".into()).code().as_str(), "CONTRADICTION");
+        assert_eq!(
+            serde_json::to_string(&ErrorCode::NoDisjunctHolds).unwrap(),
+            "\"NO_DISJUNCT_HOLDS\""
+        );
+    }
+}
@@ -0,0 +1,457 @@
+//! Hash-chained sub-operations
+//!
+//! A [`SubOperation`] records one step of work (name, input, output) and
+//! links to its predecessor by hash, so L3 audits can check that a run was
+//! neither reordered nor altered. [`OperationRecorder`] builds such a chain
+//! as the engine works through the phases of a proof.
+//!
+//! # Payloads
+//!
+//! An operation's input and output are each a [`Payload`]: inline text,
+//! raw bytes, or a reference to an external artifact by URI, SHA-256 and
+//! size. In JSON, text is a plain string (so older chains still load and
+//! keep their hashes), bytes are `{"bytes": "<base64>"}` and a reference is
+//! `{"uri", "sha256", "size"}`.
+//!
+//! The operation hash covers the bytes of inline payloads and the declared
+//! `sha256:<hex>` of external ones, so checking a chain never needs the
+//! artifacts. [`SubOperation::verify_conformity_with`] can also fetch them
+//! through a [`PayloadResolver`] and check them against their declarations;
+//! [`PayloadCheck::Offline`] trusts the declared hashes instead.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::axioms::OmegaSSoT;
+use crate::trace::TraceEnvelope;
+use base64::Engine as _;
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::borrow::Cow;
+
+/// Input or output of a sub-operation
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "WirePayload", into = "WirePayload")]
+pub enum Payload {
+    /// Text carried in the operation
+    Inline(String),
+    /// Binary data carried in the operation
+    Bytes(Vec<u8>),
+    /// An artifact stored elsewhere, identified by its SHA-256 (hex) and size
+    External { uri: String, sha256: String, size: u64 },
+}
+
+/// JSON form of a [`Payload`]
+#[derive(Serialize, Deserialize)]
+#[serde(untagged)]
+enum WirePayload {
+    Inline(String),
+    Bytes { bytes: String },
+    External { uri: String, sha256: String, size: u64 },
+}
+
+impl TryFrom<WirePayload> for Payload {
+    type Error = String;
+    
+    fn try_from(wire: WirePayload) -> Result<Self, Self::Error> {
+        Ok(match wire {
+            WirePayload::Inline(text) => Payload::Inline(text),
+            WirePayload::Bytes { bytes } => Payload::Bytes(
+                base64::engine::general_purpose::STANDARD
+                    .decode(bytes)
+                    .map_err(|e| format!("invalid base64 payload: {}", e))?,
+            ),
+            WirePayload::External { uri, sha256, size } => Payload::External { uri, sha256, size },
+        })
+    }
+}
+
+impl From<Payload> for WirePayload {
+    fn from(payload: Payload) -> Self {
+        match payload {
+            Payload::Inline(text) => WirePayload::Inline(text),
+            Payload::Bytes(bytes) => WirePayload::Bytes {
+                bytes: base64::engine::general_purpose::STANDARD.encode(bytes),
+            },
+            Payload::External { uri, sha256, size } => WirePayload::External { uri, sha256, size },
+        }
+    }
+}
+
+impl Payload {
+    /// Reference to the artifact at `uri` whose contents are `bytes`
+    pub fn external(uri: impl Into<String>, bytes: &[u8]) -> Self {
+        Payload::External {
+            uri: uri.into(),
+            sha256: hex::encode(Sha256::digest(bytes)),
+            size: bytes.len() as u64,
+        }
+    }
+    
+    /// Bytes carried in the operation (`None` for an external payload)
+    pub fn inline_bytes(&self) -> Option<&[u8]> {
+        match self {
+            Payload::Inline(text) => Some(text.as_bytes()),
+            Payload::Bytes(bytes) => Some(bytes),
+            Payload::External { .. } => None,
+        }
+    }
+    
+    /// The payload as text, if it is carried in the operation and is UTF-8
+    pub fn as_text(&self) -> Option<&str> {
+        self.inline_bytes().and_then(|bytes| std::str::from_utf8(bytes).ok())
+    }
+    
+    /// The payload's bytes, fetching an external payload through `resolver`
+    /// and checking it against its declared size and SHA-256
+    pub fn fetch(&self, resolver: &dyn PayloadResolver) -> Result<Cow<'_, [u8]>, String> {
+        let Payload::External { uri, sha256, size } = self else {
+            return Ok(Cow::Borrowed(self.inline_bytes().unwrap_or_default()));
+        };
+        let bytes = resolver.resolve(uri)?;
+        if bytes.len() as u64 != *size {
+            return Err(format!("{}: declared {} bytes, resolved {}", uri, size, bytes.len()));
+        }
+        if !hex::encode(Sha256::digest(&bytes)).eq_ignore_ascii_case(sha256) {
+            return Err(format!("{}: contents do not match the declared sha256", uri));
+        }
+        Ok(Cow::Owned(bytes))
+    }
+    
+    fn hash_into(&self, hasher: &mut Sha256) {
+        match self {
+            Payload::Inline(text) => hasher.update(text.as_bytes()),
+            Payload::Bytes(bytes) => hasher.update(bytes),
+            Payload::External { sha256, .. } => {
+                hasher.update(b"sha256:");
+                hasher.update(sha256.as_bytes());
+            }
+        }
+    }
+}
+
+impl From<String> for Payload {
+    fn from(text: String) -> Self {
+        Payload::Inline(text)
+    }
+}
+
+impl From<&str> for Payload {
+    fn from(text: &str) -> Self {
+        Payload::Inline(text.to_string())
+    }
+}
+
+impl From<&String> for Payload {
+    fn from(text: &String) -> Self {
+        Payload::Inline(text.clone())
+    }
+}
+
+impl From<Vec<u8>> for Payload {
+    fn from(bytes: Vec<u8>) -> Self {
+        Payload::Bytes(bytes)
+    }
+}
+
+/// Fetches the contents of external payloads by URI
+pub trait PayloadResolver {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>, String>;
+}
+
+impl<F: Fn(&str) -> Result<Vec<u8>, String>> PayloadResolver for F {
+    fn resolve(&self, uri: &str) -> Result<Vec<u8>, String> {
+        self(uri)
+    }
+}
+
+/// How [`SubOperation::verify_conformity_with`] treats external payloads
+#[derive(Clone, Copy)]
+pub enum PayloadCheck<'a> {
+    /// Trust the declared hash and size; the artifact is not read
+    Offline,
+    /// Fetch the artifact and check it against the declared hash and size
+    Resolve(&'a dyn PayloadResolver),
+}
+
+/// A sub-operation in the audit chain
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SubOperation {
+    /// Operation name
+    pub name: String,
+    /// Input to the operation
+    pub input: Payload,
+    /// Output from the operation
+    pub output: Payload,
+    /// Hash of the operation
+    pub hash: String,
+    /// Previous operation hash (for chain)
+    pub prev_hash: Option<String>,
+}
+
+impl SubOperation {
+    /// Create a new sub-operation
+    pub fn new(
+        name: impl Into<String>,
+        input: impl Into<Payload>,
+        output: impl Into<Payload>,
+        prev_hash: Option<String>,
+    ) -> Self {
+        let name = name.into();
+        let input = input.into();
+        let output = output.into();
+
+        let hash = Self::compute_hash(&name, &input, &output, &prev_hash);
+
+        Self {
+            name,
+            input,
+            output,
+            hash,
+            prev_hash,
+        }
+    }
+
+    /// Derive a linked sub-operation chain from a proof trace's steps
+    pub fn chain_from_trace(trace: &TraceEnvelope) -> Vec<SubOperation> {
+        let mut recorder = OperationRecorder::new();
+        for step in &trace.steps {
+            recorder.record(&step.operation, &step.input, &step.output);
+        }
+        recorder.into_operations()
+    }
+
+    fn compute_hash(name: &str, input: &Payload, output: &Payload, prev: &Option<String>) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(name.as_bytes());
+        input.hash_into(&mut hasher);
+        output.hash_into(&mut hasher);
+        if let Some(p) = prev {
+            hasher.update(p.as_bytes());
+        }
+        hex::encode(hasher.finalize())
+    }
+
+    /// Verify operation integrity
+    pub fn verify_integrity(&self) -> bool {
+        let computed = Self::compute_hash(&self.name, &self.input, &self.output, &self.prev_hash);
+        computed == self.hash
+    }
+
+    /// Verify conformity with Ω-SSOT, trusting declared external hashes
+    pub fn verify_conformity(&self, ssot: &OmegaSSoT) -> bool {
+        self.verify_conformity_with(ssot, PayloadCheck::Offline)
+    }
+
+    /// Verify integrity and conformity with Ω-SSOT
+    ///
+    /// A textual output must not violate any axiom; binary outputs, and
+    /// external outputs that are not resolved, cannot state a violation.
+    /// With [`PayloadCheck::Resolve`] every external payload must also
+    /// resolve to its declared contents.
+    pub fn verify_conformity_with(&self, ssot: &OmegaSSoT, check: PayloadCheck<'_>) -> bool {
+        if !self.verify_integrity() {
+            return false;
+        }
+
+        let output = match check {
+            PayloadCheck::Offline => self.output.inline_bytes().map(Cow::Borrowed),
+            PayloadCheck::Resolve(resolver) => {
+                if self.input.fetch(resolver).is_err() {
+                    return false;
+                }
+                match self.output.fetch(resolver) {
+                    Ok(bytes) => Some(bytes),
+                    Err(_) => return false,
+                }
+            }
+        };
+
+        // Check operation doesn't violate any axioms
+        let text = output.as_deref().and_then(|bytes| std::str::from_utf8(bytes).ok());
+        match text {
+            Some(text) => ssot.check_violation(text).is_empty(),
+            None => true,
+        }
+    }
+
+    /// Verify a chain of sub-operations
+    pub fn verify_chain(ops: &[SubOperation]) -> bool {
+        if ops.is_empty() {
+            return true;
+        }
+
+        // First op should have no prev_hash
+        if ops[0].prev_hash.is_some() {
+            return false;
+        }
+
+        // Each subsequent op should reference the previous
+        for i in 1..ops.len() {
+            match &ops[i].prev_hash {
+                Some(prev) if *prev == ops[i-1].hash => continue,
+                _ => return false,
+            }
+        }
+
+        // All ops should have valid integrity
+        ops.iter().all(|op| op.verify_integrity())
+    }
+}
+
+/// Collects sub-operations, chaining each to the one before it
+#[derive(Debug, Clone, Default)]
+pub struct OperationRecorder {
+    ops: Vec<SubOperation>,
+}
+
+impl OperationRecorder {
+    /// Create an empty recorder
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Record an operation linked to the previously recorded one
+    pub fn record(
+        &mut self,
+        name: impl Into<String>,
+        input: impl Into<Payload>,
+        output: impl Into<Payload>,
+    ) -> &SubOperation {
+        let prev_hash = self.ops.last().map(|op| op.hash.clone());
+        self.ops.push(SubOperation::new(name, input, output, prev_hash));
+        self.ops.last().expect("operation just recorded")
+    }
+
+    /// Operations recorded so far, in order
+    pub fn operations(&self) -> &[SubOperation] {
+        &self.ops
+    }
+
+    /// Consume the recorder, returning the chain
+    pub fn into_operations(self) -> Vec<SubOperation> {
+        self.ops
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sub_operation_chain() {
+        let op1 = SubOperation::new("init", "start", "middle", None);
+        let op2 = SubOperation::new("process", "middle", "end", Some(op1.hash.clone()));
+
+        assert!(SubOperation::verify_chain(&[op1, op2]));
+    }
+
+    #[test]
+    fn test_sub_operation_broken_chain() {
+        let op1 = SubOperation::new("init", "start", "middle", None);
+        let op2 = SubOperation::new("process", "middle", "end", Some("wrong_hash".to_string()));
+
+        assert!(!SubOperation::verify_chain(&[op1, op2]));
+    }
+
+    #[test]
+    fn test_recorder_chains_operations() {
+        let mut recorder = OperationRecorder::new();
+        recorder.record("init", "start", "middle");
+        recorder.record("process", "middle", "end");
+
+        let ops = recorder.into_operations();
+        assert!(ops[0].prev_hash.is_none());
+        assert_eq!(ops[1].prev_hash.as_deref(), Some(ops[0].hash.as_str()));
+        assert!(SubOperation::verify_chain(&ops));
+    }
+
+    const ARTIFACT: &[u8] = b"\x1f\x8b compressed log";
+
+    fn resolver(uri: &str) -> Result<Vec<u8>, String> {
+        match uri {
+            "s3://logs/run-1.gz" => Ok(ARTIFACT.to_vec()),
+            "s3://logs/swapped.gz" => Ok(b"something else entirely".to_vec()),
+            _ => Err(format!("{}: not found", uri)),
+        }
+    }
+
+    #[test]
+    fn test_chain_mixes_payload_kinds() {
+        let mut recorder = OperationRecorder::new();
+        recorder.record("init", "start", vec![0u8, 159, 146, 150]);
+        recorder.record("compress", vec![0u8, 159, 146, 150], Payload::external("s3://logs/run-1.gz", ARTIFACT));
+        recorder.record("summarize", Payload::external("s3://logs/run-1.gz", ARTIFACT), "12 lines");
+        let ops = recorder.into_operations();
+        assert!(SubOperation::verify_chain(&ops));
+
+        // An external payload enters the hash as its declared sha256
+        let mut hasher = Sha256::new();
+        hasher.update(b"summarize");
+        hasher.update(b"sha256:");
+        hasher.update(hex::encode(Sha256::digest(ARTIFACT)).as_bytes());
+        hasher.update(b"12 lines");
+        hasher.update(ops[1].hash.as_bytes());
+        assert_eq!(ops[2].hash, hex::encode(hasher.finalize()));
+
+        // Swapping the declared artifact breaks the chain
+        let mut tampered = ops.clone();
+        tampered[1].output = Payload::external("s3://logs/run-1.gz", b"other");
+        assert!(!SubOperation::verify_chain(&tampered));
+        let mut tampered = ops;
+        tampered[0].output = Payload::Bytes(vec![0, 159, 146, 151]);
+        assert!(!SubOperation::verify_chain(&tampered));
+    }
+
+    #[test]
+    fn test_payload_json_is_backward_compatible() {
+        let legacy = SubOperation::new("init", "start", "middle", None);
+        let json = serde_json::to_value(&legacy).unwrap();
+        assert_eq!(json["input"], "start");
+
+        let old: SubOperation = serde_json::from_value(serde_json::json!({
+            "name": "init",
+            "input": "start",
+            "output": "middle",
+            "hash": legacy.hash,
+            "prev_hash": null,
+        }))
+        .unwrap();
+        assert_eq!(old.input, Payload::Inline("start".to_string()));
+        assert!(old.verify_integrity());
+
+        for payload in [Payload::Bytes(vec![0, 255, 7]), Payload::external("file:///tmp/out.bin", b"out")] {
+            let json = serde_json::to_string(&payload).unwrap();
+            assert_eq!(serde_json::from_str::<Payload>(&json).unwrap(), payload);
+        }
+        assert_eq!(serde_json::to_value(Payload::Bytes(vec![0, 255, 7])).unwrap()["bytes"], "AP8H");
+        assert!(serde_json::from_str::<Payload>(r#"{"bytes": "not base64!"}"#).is_err());
+    }
+
+    #[test]
+    fn test_conformity_resolves_external_payloads() {
+        let ssot = OmegaSSoT::new();
+        let declared = Payload::external("s3://logs/run-1.gz", ARTIFACT);
+        let op = SubOperation::new("compress", "raw log", declared, None);
+        assert!(op.verify_conformity(&ssot));
+        assert!(op.verify_conformity_with(&ssot, PayloadCheck::Resolve(&resolver)));
+
+        // Offline trusts the declaration; resolving catches the swap and the missing artifact
+        let swapped = Payload::External {
+            uri: "s3://logs/swapped.gz".to_string(),
+            sha256: hex::encode(Sha256::digest(ARTIFACT)),
+            size: ARTIFACT.len() as u64,
+        };
+        let op = SubOperation::new("compress", "raw log", swapped, None);
+        assert!(op.verify_conformity(&ssot));
+        assert!(!op.verify_conformity_with(&ssot, PayloadCheck::Resolve(&resolver)));
+        let missing = SubOperation::new("compress", Payload::external("s3://logs/gone.gz", b"x"), "ok", None);
+        assert!(missing.verify_conformity(&ssot));
+        assert!(!missing.verify_conformity_with(&ssot, PayloadCheck::Resolve(&resolver)));
+
+        // Text in bytes is still checked against the axioms; binary is not
+        let violating = SubOperation::new("next", "middle", b"a contradiction".to_vec(), None);
+        assert!(!violating.verify_conformity(&ssot));
+        let binary = SubOperation::new("next", "middle", vec![0xff, 0xfe], None);
+        assert!(binary.verify_conformity(&ssot));
+    }
+}
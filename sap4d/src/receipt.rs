@@ -1,16 +1,89 @@
 //! Receipt generation and verification
 //!
+//! # Format versions
+//!
+//! - **v1** hashes the raw field bytes in source order, so reordering the
+//!   evidence changes the hash. Receipts without a `version` field are v1.
+//! - **v2** hashes a canonical JSON document: evidence, axioms and
+//!   provenance sorted, text in Unicode NFC, the timestamp as UTC RFC 3339
+//!   with millisecond precision, and the version itself. Semantically
+//!   identical receipts hash identically.
+//! - **v3** hashes the same document, but each evidence item, provenance
+//!   statement and causal link enters as its own SHA-256 [`commitment`].
+//!   This lets [`Receipt::redact`] withhold items without changing the hash.
+//!
+//! New receipts are written at [`Receipt::FORMAT_VERSION`];
+//! [`Receipt::verify_hash`] checks each receipt under the rules of its own
+//! version, and [`Receipt::upgrade`] re-signs a v1 receipt as v2.
+//!
+//! # Chaining
+//!
+//! A receipt may name the hashes of the receipts it builds on in `parents`.
+//! The hash covers them, so the link cannot be added or removed after
+//! sealing; [`Receipt::verify_ancestry`] walks the links back through a
+//! resolver and reports the first ancestor that fails to verify.
+//!
+//! # Quantified claims
+//!
+//! A receipt for a [`crate::Claim::ForAll`] lists each instantiation's
+//! sub-proof hash and their aggregate in `quantified`, covered by the hash.
+//!
+//! # Evidence files
+//!
+//! Evidence loaded from files is traced back to them in `evidence_files`:
+//! each file's path and the SHA-256 of its bytes, covered by the hash.
+//!
+//! # Evidence normalization
+//!
+//! A receipt proven with evidence deduplication records it in
+//! `normalization`, covered by the hash, so the chain can be re-derived
+//! the same way whatever the verifying engine is configured to do.
+//!
+//! # Signing keys
+//!
+//! `key_id` names the key behind `signature`, and `cosignatures` carries
+//! further signatures over the same hash. Neither is covered by the hash,
+//! so co-signing never changes it. See [`crate::signing`].
+//!
+//! # Redaction
+//!
+//! [`Receipt::redact`] replaces evidence items with their commitments and
+//! lists their indices in `redacted`. Provenance statements and causal
+//! links quoting a redacted item are replaced by their own commitments.
+//! Under v3 rules a redacted receipt keeps its hash and signatures.
+//!
+//! # Metrics
+//!
+//! `metrics` carries the timing summary of the trace a receipt was made
+//! from ([`TraceMetrics`]). No version hashes it, so two proofs of the same
+//! claim hash identically however long they took.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
-use sha2::{Digest, Sha256};
 use chrono::{DateTime, Utc};
+use std::collections::HashSet;
+use thiserror::Error;
 
-use crate::trace::TraceEnvelope;
+use crate::claim::{CompoundRecord, QuantifiedRecord};
+use crate::evidence::file::EvidenceFile;
+use crate::evidence::normalize::EvidenceNormalization;
+use crate::evidence::Evidence;
+use crate::signing::{KeyedSignature, ReceiptSigner, SignatureError, SignerRegistry};
+use crate::trace::{TraceEnvelope, TraceMetrics};
+use crate::verify::is_commitment;
+use crate::{ProofError, Result};
+
+pub mod diff;
+
+pub use crate::verify::commitment;
 
 /// A cryptographic receipt proving a claim
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct Receipt {
+    /// Hashing rules the receipt was issued under (1 if absent)
+    #[serde(default = "legacy_version")]
+    pub version: u32,
     /// The claim that was verified
     pub claim: String,
     /// Evidence supporting the claim
@@ -26,85 +99,234 @@ pub struct Receipt {
     pub hash: String,
     /// Cryptographic signature (base64 DER)
     pub signature: String,
+    /// Key that made `signature` (absent for receipts signed by a bare closure)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+    /// Additional signatures over `hash`
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub cosignatures: Vec<KeyedSignature>,
     /// Timestamp of receipt generation
     pub timestamp: DateTime<Utc>,
     /// Substrate authority
     pub substrate: String,
     /// Projection identifier
     pub projection: String,
+    /// Sub-claim outcomes for a compound claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub compound: Option<CompoundRecord>,
+    /// Source provenance of the evidence (absent for bare evidence)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provenance: Vec<Evidence>,
+    /// Hashes of the receipts this one builds on (absent for unchained receipts)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub parents: Vec<String>,
+    /// Files the evidence was read from (absent when none was)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub evidence_files: Vec<EvidenceFile>,
+    /// How the evidence was deduplicated before chaining (absent when it was not)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub normalization: Option<EvidenceNormalization>,
+    /// Indices of evidence items replaced by their commitments (not hashed)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub redacted: Vec<usize>,
+    /// Per-instance sub-proofs of a quantified claim
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub quantified: Option<QuantifiedRecord>,
+    /// Timing of the proof that issued the receipt (not hashed)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub metrics: Option<TraceMetrics>,
+}
+
+fn legacy_version() -> u32 {
+    1
 }
 
 impl Receipt {
+    /// Format version written by this release
+    pub const FORMAT_VERSION: u32 = 3;
+    
     /// Create a new receipt from a trace envelope
     pub fn from_trace(trace: &TraceEnvelope, sign_fn: impl FnOnce(&str) -> String) -> Self {
-        let timestamp = Utc::now();
-        
-        let hash = Self::compute_hash(
-            &trace.claim,
-            &trace.observations,
-            &trace.causal_chain,
-            &trace.axioms,
-            trace.is_c_zero(),
-            &timestamp,
-        );
-        
-        let signature = sign_fn(&hash);
-        
-        Self {
+        Self::from_compound_trace(trace, None, sign_fn)
+    }
+    
+    /// Create a receipt from a trace, recording compound sub-claim outcomes
+    pub fn from_compound_trace(
+        trace: &TraceEnvelope,
+        compound: Option<CompoundRecord>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Self {
+        let mut receipt = Self {
+            version: Self::FORMAT_VERSION,
             claim: trace.claim.clone(),
             evidence: trace.observations.clone(),
             causal_chain: trace.causal_chain.clone(),
             axioms: trace.axioms.clone(),
             c_zero: trace.is_c_zero(),
-            hash,
-            signature,
-            timestamp,
+            hash: String::new(),
+            signature: String::new(),
+            key_id: None,
+            cosignatures: Vec::new(),
+            timestamp: crate::clock::now(),
             substrate: trace.substrate.clone(),
             projection: trace.projection.clone(),
-        }
+            compound,
+            provenance: trace.provenance.clone(),
+            parents: Vec::new(),
+            evidence_files: Vec::new(),
+            normalization: None,
+            redacted: Vec::new(),
+            quantified: None,
+            metrics: Some(trace.metrics()),
+        };
+        receipt.seal(sign_fn);
+        receipt
     }
     
-    fn compute_hash(
-        claim: &str,
-        evidence: &[String],
-        causal_chain: &[String],
-        axioms: &[String],
-        c_zero: bool,
-        timestamp: &DateTime<Utc>,
-    ) -> String {
-        let mut hasher = Sha256::new();
-        
-        hasher.update(claim.as_bytes());
-        
-        for e in evidence {
-            hasher.update(e.as_bytes());
+    /// Create a receipt from a trace, recording a quantified claim's sub-proofs
+    pub fn from_quantified_trace(
+        trace: &TraceEnvelope,
+        quantified: QuantifiedRecord,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Self {
+        // Signed once, after the record is part of the hash
+        let mut receipt = Self::from_trace(trace, |_| String::new());
+        receipt.quantified = Some(quantified);
+        receipt.seal(sign_fn);
+        receipt
+    }
+    
+    /// Create a receipt from a trace, recording how its evidence was normalized
+    pub fn from_normalized_trace(
+        trace: &TraceEnvelope,
+        normalization: Option<EvidenceNormalization>,
+        sign_fn: impl FnOnce(&str) -> String,
+    ) -> Self {
+        let mut receipt = Self::from_trace(trace, |_| String::new());
+        receipt.normalization = normalization;
+        receipt.seal(sign_fn);
+        receipt
+    }
+    
+    /// Record `parents` (deduplicated, in order) and re-seal with `sign_fn`
+    pub(crate) fn link_parents(&mut self, parents: Vec<String>, sign_fn: impl FnOnce(&str) -> String) {
+        let mut seen = HashSet::new();
+        self.parents = parents.into_iter().filter(|hash| seen.insert(hash.clone())).collect();
+        self.seal(sign_fn);
+    }
+    
+    /// Record the files the evidence came from and re-seal with `sign_fn`
+    pub(crate) fn link_evidence_files(&mut self, files: Vec<EvidenceFile>, sign_fn: impl FnOnce(&str) -> String) {
+        self.evidence_files = files;
+        self.seal(sign_fn);
+    }
+    
+    /// Hash under the current version's rules and sign the result
+    ///
+    /// A new hash invalidates every earlier signature, so the key id and
+    /// co-signatures are dropped.
+    fn seal(&mut self, sign_fn: impl FnOnce(&str) -> String) {
+        self.hash = self.compute_hash().expect("receipt version is supported");
+        self.signature = sign_fn(&self.hash);
+        self.key_id = None;
+        self.cosignatures.clear();
+    }
+    
+    /// Hash under the rules of `self.version` (`None` for an unknown version)
+    ///
+    /// The rules are implemented once, in [`crate::verify`], which reads the
+    /// receipt from its serialized form as any other verifier would.
+    fn compute_hash(&self) -> Option<String> {
+        let value = serde_json::to_value(self).ok()?;
+        crate::verify::Receipt::deserialize(value).ok()?.compute_hash()
+    }
+    
+    /// Verify the receipt's hash integrity under its version's rules
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().as_deref() == Some(self.hash.as_str())
+    }
+    
+    /// Re-issue this receipt at [`Receipt::FORMAT_VERSION`], re-signed with `sign_fn`
+    ///
+    /// The receipt must verify under its own version first, so upgrading
+    /// cannot launder a tampered receipt.
+    pub fn upgrade(&self, sign_fn: impl FnOnce(&str) -> String) -> Result<Receipt> {
+        self.upgrade_to(Self::FORMAT_VERSION, sign_fn)
+    }
+    
+    /// Re-issue this receipt at `version`, re-signed with `sign_fn`
+    ///
+    /// Redacted receipts can only be re-issued under v3 or later, since
+    /// older versions hash the withheld plaintext.
+    pub fn upgrade_to(&self, version: u32, sign_fn: impl FnOnce(&str) -> String) -> Result<Receipt> {
+        if !self.verify_hash() {
+            return Err(ProofError::Internal(format!(
+                "Receipt hash verification failed (version {})",
+                self.version
+            )));
         }
-        
-        for link in causal_chain {
-            hasher.update(link.as_bytes());
+        if !(self.version..=Self::FORMAT_VERSION).contains(&version) {
+            return Err(ProofError::Internal(format!(
+                "Cannot re-issue a version {} receipt as version {}",
+                self.version, version
+            )));
+        }
+        if !self.redacted.is_empty() && version < 3 {
+            return Err(ProofError::Internal("Redacted receipts require version 3".to_string()));
         }
         
-        for axiom in axioms {
-            hasher.update(axiom.as_bytes());
+        let mut upgraded = self.clone();
+        upgraded.version = version;
+        upgraded.seal(sign_fn);
+        Ok(upgraded)
+    }
+    
+    /// Copy of this receipt with the evidence at `indices` withheld
+    ///
+    /// Each withheld item is replaced by its [`commitment`], as are the
+    /// provenance statements and causal links quoting it. The hash and
+    /// signatures are untouched and still verify. Only v3 receipts can be
+    /// redacted; re-issue older ones with [`Receipt::upgrade`] first.
+    pub fn redact(&self, indices: &[usize]) -> std::result::Result<Receipt, RedactionError> {
+        if self.version < 3 {
+            return Err(RedactionError::UnsupportedVersion(self.version));
+        }
+        if !self.verify_hash() {
+            return Err(RedactionError::HashMismatch);
+        }
+        if let Some(&index) = indices.iter().find(|&&i| i >= self.evidence.len()) {
+            return Err(RedactionError::IndexOutOfRange { index, len: self.evidence.len() });
         }
         
-        hasher.update([c_zero as u8]);
-        hasher.update(timestamp.to_rfc3339().as_bytes());
+        let mut redacted = self.clone();
+        for &index in indices {
+            if redacted.redacted.contains(&index) {
+                continue;
+            }
+            let plaintext = std::mem::take(&mut redacted.evidence[index]);
+            let committed = commitment(&plaintext);
+            
+            for evidence in &mut redacted.provenance {
+                if evidence.statement == plaintext {
+                    evidence.statement = committed.clone();
+                }
+            }
+            for link in &mut redacted.causal_chain {
+                if !plaintext.is_empty() && !is_commitment(link) && link.contains(plaintext.as_str()) {
+                    *link = commitment(link);
+                }
+            }
+            redacted.evidence[index] = committed;
+            redacted.redacted.push(index);
+        }
+        redacted.redacted.sort_unstable();
         
-        hex::encode(hasher.finalize())
+        Ok(redacted)
     }
     
-    /// Verify the receipt's hash integrity
-    pub fn verify_hash(&self) -> bool {
-        let computed = Self::compute_hash(
-            &self.claim,
-            &self.evidence,
-            &self.causal_chain,
-            &self.axioms,
-            self.c_zero,
-            &self.timestamp,
-        );
-        computed == self.hash
+    /// Whether any evidence has been withheld
+    pub fn is_redacted(&self) -> bool {
+        !self.redacted.is_empty()
     }
     
     /// Verify the receipt's signature
@@ -117,20 +339,183 @@ impl Receipt {
         self.verify_hash() && self.verify_signature(verify_fn)
     }
     
+    /// Replace the signature with `signer`'s, recording its key id
+    pub fn sign_with(&mut self, signer: &dyn ReceiptSigner) {
+        self.signature = signer.sign(&self.hash);
+        self.key_id = Some(signer.key_id().to_string());
+        self.cosignatures.clear();
+    }
+    
+    /// Append `signer`'s signature over the hash as a co-signature
+    pub fn add_signature(&mut self, signer: &dyn ReceiptSigner) {
+        self.cosignatures.push(KeyedSignature::sign(signer, &self.hash));
+    }
+    
+    /// Primary signature with its key id, if one was recorded
+    pub fn keyed_signature(&self) -> Option<KeyedSignature> {
+        self.key_id.as_ref().map(|key_id| KeyedSignature {
+            key_id: key_id.clone(),
+            signature: self.signature.clone(),
+        })
+    }
+    
+    /// Verify the primary signature with the key its `key_id` names
+    pub fn verify_signature_with(&self, registry: &SignerRegistry) -> std::result::Result<(), SignatureError> {
+        let key_id = self.key_id.as_deref().ok_or(SignatureError::MissingKeyId)?;
+        registry.verify(key_id, &self.hash, &self.signature)
+    }
+    
+    /// Verify the hash and require `quorum` valid signatures from distinct keys
+    ///
+    /// The primary signature and every co-signature count. Returns how
+    /// many distinct keys signed validly.
+    pub fn verify_all(&self, registry: &SignerRegistry, quorum: usize) -> std::result::Result<usize, SignatureError> {
+        if !self.verify_hash() {
+            return Err(SignatureError::HashMismatch);
+        }
+        let primary = self.keyed_signature();
+        registry.verify_quorum(&self.hash, primary.iter().chain(&self.cosignatures), quorum)
+    }
+    
+    /// Verify every ancestor named (transitively) by `parents`
+    ///
+    /// `resolve` looks a receipt up by hash. Each ancestor must be found,
+    /// carry the hash it was looked up by, recompute that hash and be C=0.
+    /// Ancestors shared by several branches are checked once. This receipt
+    /// itself is not checked; use [`Receipt::verify`] for that.
+    pub fn verify_ancestry(
+        &self,
+        resolve: impl Fn(&str) -> Option<Receipt>,
+    ) -> std::result::Result<(), AncestryError> {
+        let mut path = vec![self.hash.clone()];
+        let mut verified = HashSet::new();
+        self.verify_parents(&resolve, &mut path, &mut verified)
+    }
+    
+    fn verify_parents(
+        &self,
+        resolve: &impl Fn(&str) -> Option<Receipt>,
+        path: &mut Vec<String>,
+        verified: &mut HashSet<String>,
+    ) -> std::result::Result<(), AncestryError> {
+        for parent in &self.parents {
+            if path.contains(parent) {
+                return Err(AncestryError::Cycle { hash: parent.clone() });
+            }
+            if verified.contains(parent) {
+                continue;
+            }
+            
+            let receipt = resolve(parent).ok_or_else(|| AncestryError::Missing {
+                hash: parent.clone(),
+                child: self.hash.clone(),
+            })?;
+            let broken = |reason: &str| AncestryError::Broken {
+                hash: parent.clone(),
+                reason: reason.to_string(),
+            };
+            if receipt.hash != *parent {
+                return Err(broken("resolver returned a different receipt"));
+            }
+            if !receipt.verify_hash() {
+                return Err(broken("hash does not match contents"));
+            }
+            if !receipt.c_zero {
+                return Err(broken("receipt is not C=0"));
+            }
+            
+            path.push(parent.clone());
+            receipt.verify_parents(resolve, path, verified)?;
+            path.pop();
+            verified.insert(parent.clone());
+        }
+        Ok(())
+    }
+    
     /// Check if the receipt indicates a valid proof (C=0)
     pub fn is_valid_proof(&self) -> bool {
         self.c_zero
     }
     
     /// Convert to JSON
-    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+    pub fn to_json(&self) -> std::result::Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
     }
     
+    /// Convert to single-line JSON, e.g. for a header
+    pub fn to_json_compact(&self) -> std::result::Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+    
     /// Parse from JSON
-    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+    pub fn from_json(json: &str) -> std::result::Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+    
+    /// Convert to YAML
+    ///
+    /// The hash covers the receipt's fields, not their text, so a receipt
+    /// verifies the same in either format whatever order the keys end up in.
+    pub fn to_yaml(&self) -> std::result::Result<String, serde_yaml::Error> {
+        serde_yaml::to_string(self)
+    }
+    
+    /// Parse from YAML
+    pub fn from_yaml(yaml: &str) -> std::result::Result<Self, serde_yaml::Error> {
+        serde_yaml::from_str(yaml)
+    }
+}
+
+/// Text formats a receipt file is written in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ReceiptFormat {
+    Json,
+    Yaml,
+}
+
+impl ReceiptFormat {
+    /// Format for `path` by extension: `.yaml` and `.yml` are YAML, anything else JSON
+    pub fn from_path(path: &std::path::Path) -> Self {
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some(ext) if ext.eq_ignore_ascii_case("yaml") || ext.eq_ignore_ascii_case("yml") => Self::Yaml,
+            _ => Self::Json,
+        }
+    }
+    
+    /// Format of `content` when the extension does not say: JSON if it is a JSON object
+    pub fn sniff(content: &str) -> Self {
+        if content.trim_start().starts_with('{') {
+            Self::Json
+        } else {
+            Self::Yaml
+        }
+    }
+}
+
+/// Why [`Receipt::redact`] refused to redact a receipt
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum RedactionError {
+    #[error("Version {0} receipts hash evidence in plaintext; upgrade to version 3 to redact")]
+    UnsupportedVersion(u32),
+    
+    #[error("Evidence index {index} out of range ({len} items)")]
+    IndexOutOfRange { index: usize, len: usize },
+    
+    #[error("Receipt hash does not match its contents")]
+    HashMismatch,
+}
+
+/// Why [`Receipt::verify_ancestry`] rejected a chain
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum AncestryError {
+    #[error("Ancestor {hash} (parent of {child}) could not be resolved")]
+    Missing { hash: String, child: String },
+    
+    #[error("Ancestor {hash} failed verification: {reason}")]
+    Broken { hash: String, reason: String },
+    
+    #[error("Receipt {hash} is its own ancestor")]
+    Cycle { hash: String },
 }
 
 /// Builder for constructing receipts
@@ -140,6 +525,8 @@ pub struct ReceiptBuilder {
     causal_chain: Vec<String>,
     axioms: Vec<String>,
     c_zero: bool,
+    parents: Vec<String>,
+    evidence_files: Vec<EvidenceFile>,
 }
 
 impl ReceiptBuilder {
@@ -151,6 +538,8 @@ impl ReceiptBuilder {
             causal_chain: Vec::new(),
             axioms: Vec::new(),
             c_zero: true,
+            parents: Vec::new(),
+            evidence_files: Vec::new(),
         }
     }
     
@@ -196,33 +585,52 @@ impl ReceiptBuilder {
         self
     }
     
+    /// Link to a parent receipt by its hash
+    pub fn with_parent_receipt(mut self, hash: impl Into<String>) -> Self {
+        self.parents.push(hash.into());
+        self
+    }
+    
+    /// Record a file the evidence was read from
+    pub fn with_evidence_file(mut self, file: EvidenceFile) -> Self {
+        self.evidence_files.push(file);
+        self
+    }
+    
     /// Build the receipt
     pub fn build(self, sign_fn: impl FnOnce(&str) -> String) -> Receipt {
-        let timestamp = Utc::now();
-        
-        let hash = Receipt::compute_hash(
-            &self.claim,
-            &self.evidence,
-            &self.causal_chain,
-            &self.axioms,
-            self.c_zero,
-            &timestamp,
-        );
-        
-        let signature = sign_fn(&hash);
-        
-        Receipt {
+        let mut receipt = Receipt {
+            version: Receipt::FORMAT_VERSION,
             claim: self.claim,
             evidence: self.evidence,
             causal_chain: self.causal_chain,
             axioms: self.axioms,
             c_zero: self.c_zero,
-            hash,
-            signature,
-            timestamp,
+            hash: String::new(),
+            signature: String::new(),
+            key_id: None,
+            cosignatures: Vec::new(),
+            timestamp: crate::clock::now(),
             substrate: crate::SUBSTRATE.to_string(),
             projection: crate::PROJECTION.to_string(),
-        }
+            compound: None,
+            provenance: Vec::new(),
+            parents: Vec::new(),
+            evidence_files: self.evidence_files,
+            normalization: None,
+            redacted: Vec::new(),
+            quantified: None,
+            metrics: None,
+        };
+        receipt.link_parents(self.parents, sign_fn);
+        receipt
+    }
+    
+    /// Build the receipt and sign it with `signer`, recording its key id
+    pub fn build_signed(self, signer: &dyn ReceiptSigner) -> Receipt {
+        let mut receipt = self.build(|_| String::new());
+        receipt.sign_with(signer);
+        receipt
     }
 }
 
@@ -297,6 +705,77 @@ mod tests {
         assert_eq!(receipt.hash, parsed.hash);
     }
     
+    #[test]
+    fn test_receipt_yaml_roundtrip_verifies() {
+        // Evidence a naive YAML writer would turn into other types
+        let receipt = ReceiptBuilder::new("status: ok")
+            .with_evidence("true")
+            .with_evidence("1.0")
+            .with_evidence("- listed\nover two lines")
+            .with_evidence("~")
+            .with_causal_link("true → status: ok")
+            .with_axiom("A1_IDENTITY")
+            .with_c_zero(true)
+            .build(mock_sign);
+        
+        for version in 1..=Receipt::FORMAT_VERSION {
+            let mut issued = receipt.clone();
+            issued.version = version;
+            issued.seal(mock_sign);
+            
+            let parsed = Receipt::from_yaml(&issued.to_yaml().unwrap()).unwrap();
+            assert_eq!(parsed.evidence, issued.evidence, "version {}", version);
+            assert_eq!(parsed.timestamp, issued.timestamp, "version {}", version);
+            assert!(parsed.verify_hash(), "version {}", version);
+            assert!(parsed.verify_signature(mock_verify), "version {}", version);
+            
+            // The verify-only build reads the YAML as well
+            let light: crate::verify::Receipt = serde_yaml::from_str(&issued.to_yaml().unwrap()).unwrap();
+            assert!(light.verify(mock_verify), "version {}", version);
+        }
+        
+        let compact = receipt.to_json_compact().unwrap();
+        assert!(!compact.contains('\n'));
+        assert!(Receipt::from_json(&compact).unwrap().verify(mock_verify));
+    }
+    
+    #[test]
+    fn test_normalization_is_hashed_under_every_version() {
+        let receipt = ReceiptBuilder::new("Fact A")
+            .with_evidence("Fact A")
+            .with_evidence("fact  a")
+            .with_causal_link("Fact A → Fact A")
+            .build(mock_sign);
+        
+        for version in 1..=Receipt::FORMAT_VERSION {
+            let mut issued = receipt.clone();
+            issued.version = version;
+            issued.normalization = Some(EvidenceNormalization { case_fold: true });
+            issued.seal(mock_sign);
+            
+            let light: crate::verify::Receipt = serde_json::from_str(&issued.to_json().unwrap()).unwrap();
+            assert!(light.verify(mock_verify), "version {}", version);
+            
+            let mut unfolded = issued.clone();
+            unfolded.normalization = Some(EvidenceNormalization { case_fold: false });
+            assert!(!unfolded.verify_hash(), "version {}", version);
+            unfolded.normalization = None;
+            assert!(!unfolded.verify_hash(), "version {}", version);
+        }
+    }
+    
+    #[test]
+    fn test_receipt_format_detection() {
+        use std::path::Path;
+        
+        assert_eq!(ReceiptFormat::from_path(Path::new("receipt.yaml")), ReceiptFormat::Yaml);
+        assert_eq!(ReceiptFormat::from_path(Path::new("deploy/receipt.YML")), ReceiptFormat::Yaml);
+        assert_eq!(ReceiptFormat::from_path(Path::new("receipt.json")), ReceiptFormat::Json);
+        assert_eq!(ReceiptFormat::from_path(Path::new("receipt")), ReceiptFormat::Json);
+        assert_eq!(ReceiptFormat::sniff("  {\"claim\": \"x\"}"), ReceiptFormat::Json);
+        assert_eq!(ReceiptFormat::sniff("claim: x\n"), ReceiptFormat::Yaml);
+    }
+    
     #[test]
     fn test_binary_receipt() {
         let receipt = ReceiptBuilder::new("claim")
@@ -308,6 +787,293 @@ mod tests {
         assert!(binary.c_zero);
     }
     
+    #[test]
+    fn test_metrics_are_not_hashed() {
+        let trace = crate::trace::TraceBuilder::new("claim")
+            .with_observation("fact")
+            .add_step("analyze", "fact", "claim", vec!["A1_IDENTITY".to_string()])
+            .build();
+        let receipt = Receipt::from_trace(&trace, mock_sign);
+        assert_eq!(receipt.metrics.as_ref().unwrap().steps_by_operation["analyze"], 1);
+        
+        for version in 1..=Receipt::FORMAT_VERSION {
+            let mut issued = receipt.clone();
+            issued.version = version;
+            issued.seal(mock_sign);
+            let mut retimed = issued.clone();
+            retimed.metrics.as_mut().unwrap().total_duration_micros += 1_000_000;
+            assert!(retimed.verify_hash(), "version {}", version);
+            retimed.metrics = None;
+            assert!(retimed.verify(mock_verify), "version {}", version);
+        }
+    }
+    
+    #[test]
+    fn test_v2_hash_ignores_evidence_order_and_normalization() {
+        let receipt = ReceiptBuilder::new("Café opened")
+            .with_evidence("Door unlocked")
+            .with_evidence("Lights on")
+            .with_axioms(vec!["A2_NON_CONTRADICTION".to_string(), "A1_IDENTITY".to_string()])
+            .build(mock_sign);
+        assert_eq!(receipt.version, Receipt::FORMAT_VERSION);
+        
+        let mut reordered = receipt.clone();
+        reordered.evidence.reverse();
+        reordered.axioms.reverse();
+        // Decomposed "e" + combining acute accent
+        reordered.claim = "Cafe\u{301} opened".to_string();
+        assert!(reordered.verify_hash());
+        
+        let mut v1 = reordered.clone();
+        v1.version = 1;
+        assert!(!v1.verify_hash());
+        
+        let mut unknown = receipt.clone();
+        unknown.version = 99;
+        assert!(!unknown.verify_hash());
+    }
+    
+    #[test]
+    fn test_upgrade_resigns_v1_receipt() {
+        let mut v1 = ReceiptBuilder::new("claim").with_evidence("fact").build(mock_sign);
+        v1.version = 1;
+        v1.hash = v1.compute_hash().unwrap();
+        v1.signature = mock_sign(&v1.hash);
+        assert!(v1.verify(mock_verify));
+        
+        let v2 = v1.upgrade_to(2, mock_sign).unwrap();
+        assert_eq!(v2.version, 2);
+        assert_ne!(v2.hash, v1.hash);
+        assert!(v2.verify(mock_verify));
+        
+        let current = v2.upgrade(mock_sign).unwrap();
+        assert_eq!(current.version, Receipt::FORMAT_VERSION);
+        assert!(current.verify(mock_verify));
+        assert!(current.upgrade_to(2, mock_sign).is_err());
+        
+        let mut tampered = v1.clone();
+        tampered.claim = "other claim".to_string();
+        assert!(tampered.upgrade(mock_sign).is_err());
+    }
+    
+    #[test]
+    fn test_parents_are_hashed() {
+        let parent = ReceiptBuilder::new("parent claim").build(mock_sign);
+        let child = ReceiptBuilder::new("child claim")
+            .with_parent_receipt(parent.hash.clone())
+            .with_parent_receipt(parent.hash.clone())
+            .build(mock_sign);
+        assert_eq!(child.parents, vec![parent.hash.clone()]);
+        assert!(child.verify(mock_verify));
+        
+        let mut unlinked = child.clone();
+        unlinked.parents.clear();
+        assert!(!unlinked.verify_hash());
+        
+        // Unchained receipts serialize and hash exactly as before
+        let json = parent.to_json().unwrap();
+        assert!(!json.contains("parents"));
+        let mut v1 = parent.clone();
+        v1.version = 1;
+        v1.hash = v1.compute_hash().unwrap();
+        assert!(Receipt::from_json(&v1.to_json().unwrap()).unwrap().verify_hash());
+    }
+    
+    #[test]
+    fn test_evidence_files_are_hashed() {
+        let file = EvidenceFile { path: "evidence.csv".to_string(), sha256: "ab".repeat(32) };
+        let receipt = ReceiptBuilder::new("claim")
+            .with_evidence("fact")
+            .with_evidence_file(file.clone())
+            .build(mock_sign);
+        assert!(receipt.verify(mock_verify));
+        assert!(receipt.to_json().unwrap().contains("evidence_files"));
+    
+        let mut swapped = receipt.clone();
+        swapped.evidence_files[0].sha256 = "cd".repeat(32);
+        assert!(!swapped.verify_hash());
+    
+        let mut v1 = receipt.clone();
+        v1.version = 1;
+        v1.hash = v1.compute_hash().unwrap();
+        v1.evidence_files.clear();
+        assert!(!v1.verify_hash());
+    
+        let bare = ReceiptBuilder::new("claim").with_evidence("fact").build(mock_sign);
+        assert!(!bare.to_json().unwrap().contains("evidence_files"));
+    }
+    
+    #[test]
+    fn test_verify_ancestry() {
+        let grandparent = ReceiptBuilder::new("grandparent").build(mock_sign);
+        let parent = ReceiptBuilder::new("parent")
+            .with_parent_receipt(grandparent.hash.clone())
+            .build(mock_sign);
+        let child = ReceiptBuilder::new("child")
+            .with_parent_receipt(parent.hash.clone())
+            .with_parent_receipt(grandparent.hash.clone())
+            .build(mock_sign);
+        
+        let store: std::collections::HashMap<String, Receipt> = [grandparent.clone(), parent.clone()]
+            .into_iter()
+            .map(|r| (r.hash.clone(), r))
+            .collect();
+        assert_eq!(child.verify_ancestry(|hash| store.get(hash).cloned()), Ok(()));
+        
+        let err = child
+            .verify_ancestry(|hash| (hash == parent.hash).then(|| parent.clone()))
+            .unwrap_err();
+        assert_eq!(
+            err,
+            AncestryError::Missing {
+                hash: grandparent.hash.clone(),
+                child: parent.hash.clone(),
+            }
+        );
+        
+        let mut tampered = store.clone();
+        tampered.get_mut(&grandparent.hash).unwrap().claim = "forged".to_string();
+        let err = child.verify_ancestry(|hash| tampered.get(hash).cloned()).unwrap_err();
+        assert!(matches!(err, AncestryError::Broken { ref hash, .. } if *hash == grandparent.hash));
+    }
+    
+    #[test]
+    fn test_ancestry_rejects_non_c_zero_and_cycles() {
+        let failed = ReceiptBuilder::new("failed").with_c_zero(false).build(mock_sign);
+        let child = ReceiptBuilder::new("child").with_parent_receipt(failed.hash.clone()).build(mock_sign);
+        let err = child.verify_ancestry(|_| Some(failed.clone())).unwrap_err();
+        assert_eq!(err.to_string(), format!("Ancestor {} failed verification: receipt is not C=0", failed.hash));
+        
+        // A forged root claims the hash its own parent points back to
+        let parent = ReceiptBuilder::new("parent").with_parent_receipt("forged-root").build(mock_sign);
+        let mut root = ReceiptBuilder::new("root").with_parent_receipt(parent.hash.clone()).build(mock_sign);
+        root.hash = "forged-root".to_string();
+        let err = root.verify_ancestry(|_| Some(parent.clone())).unwrap_err();
+        assert_eq!(err, AncestryError::Cycle { hash: "forged-root".to_string() });
+    }
+    
+    #[test]
+    fn test_key_rotation_keeps_old_receipts_verifiable() {
+        use crate::signing::FnSigner;
+        
+        let sign_a = |hash: &str| mock_sign(&format!("A{}", hash));
+        let sign_b = |hash: &str| mock_sign(&format!("B{}", hash));
+        let mut registry = SignerRegistry::new()
+            .with_key("key-a", move |hash, sig| sign_a(hash) == sig);
+        
+        let old = ReceiptBuilder::new("signed before rotation").build_signed(&FnSigner::new("key-a", sign_a));
+        assert_eq!(old.key_id.as_deref(), Some("key-a"));
+        assert_eq!(old.verify_signature_with(&registry), Ok(()));
+        
+        // Rotate: B signs from now on, A stays registered for old receipts
+        registry.insert("key-b", move |hash, sig| sign_b(hash) == sig);
+        let new = ReceiptBuilder::new("signed after rotation").build_signed(&FnSigner::new("key-b", sign_b));
+        assert_eq!(new.verify_signature_with(&registry), Ok(()));
+        assert_eq!(old.verify_signature_with(&registry), Ok(()));
+        assert_eq!(old.verify_all(&registry, 1), Ok(1));
+        
+        // Key ids survive serialization
+        let parsed = Receipt::from_json(&new.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.verify_signature_with(&registry), Ok(()));
+        
+        // A signature filed under the wrong key is bad, not unknown
+        let mut swapped = old.clone();
+        swapped.key_id = Some("key-b".to_string());
+        assert_eq!(swapped.verify_signature_with(&registry), Err(SignatureError::InvalidSignature("key-b".to_string())));
+        
+        registry.remove("key-a");
+        assert_eq!(old.verify_signature_with(&registry), Err(SignatureError::UnknownKey("key-a".to_string())));
+        
+        let unkeyed = ReceiptBuilder::new("closure-signed").build(mock_sign);
+        assert_eq!(unkeyed.verify_signature_with(&registry), Err(SignatureError::MissingKeyId));
+        assert!(!unkeyed.to_json().unwrap().contains("key_id"));
+    }
+    
+    #[test]
+    fn test_cosigned_receipt_quorum() {
+        use crate::signing::FnSigner;
+        
+        let signer = |key: &'static str| FnSigner::new(key, move |hash: &str| mock_sign(&format!("{}{}", key, hash)));
+        let registry = ["a", "b", "c"].into_iter().fold(SignerRegistry::new(), |registry, key| {
+            registry.with_key(key, move |hash, sig| mock_sign(&format!("{}{}", key, hash)) == sig)
+        });
+        
+        let mut receipt = ReceiptBuilder::new("co-signed claim").build_signed(&signer("a"));
+        let hash = receipt.hash.clone();
+        receipt.add_signature(&signer("b"));
+        receipt.add_signature(&signer("b"));
+        receipt.add_signature(&signer("unregistered"));
+        assert_eq!(receipt.hash, hash);
+        
+        assert_eq!(receipt.verify_all(&registry, 2), Ok(2));
+        assert_eq!(receipt.verify_all(&registry, 3), Err(SignatureError::QuorumNotMet { valid: 2, required: 3 }));
+        
+        receipt.add_signature(&signer("c"));
+        assert_eq!(receipt.verify_all(&registry, 3), Ok(3));
+        
+        let mut tampered = receipt.clone();
+        tampered.claim = "forged".to_string();
+        assert_eq!(tampered.verify_all(&registry, 1), Err(SignatureError::HashMismatch));
+    }
+    
+    #[test]
+    fn test_redacted_receipt_still_verifies() {
+        let receipt = ReceiptBuilder::new("Patient was discharged")
+            .with_evidence("Jane Doe, DOB 1980-02-01")
+            .with_evidence("Discharge form signed")
+            .with_evidence("Ward 4 bed freed")
+            .with_causal_link("Jane Doe, DOB 1980-02-01 → Patient was discharged")
+            .with_causal_link("Discharge form signed → Patient was discharged")
+            .build(mock_sign);
+        assert!(receipt.verify(mock_verify));
+        
+        let redacted = receipt.redact(&[2, 0]).unwrap();
+        assert_eq!(redacted.redacted, vec![0, 2]);
+        assert_eq!(redacted.evidence[0], commitment("Jane Doe, DOB 1980-02-01"));
+        assert_eq!(redacted.evidence[1], "Discharge form signed");
+        assert!(is_commitment(&redacted.causal_chain[0]));
+        assert_eq!(redacted.causal_chain[1], receipt.causal_chain[1]);
+        assert!(!redacted.to_json().unwrap().contains("Jane Doe"));
+        
+        assert_eq!(redacted.hash, receipt.hash);
+        assert!(redacted.verify(mock_verify));
+        let reparsed = Receipt::from_json(&redacted.to_json().unwrap()).unwrap();
+        assert!(reparsed.verify(mock_verify));
+        
+        // Redacting again is idempotent
+        assert_eq!(redacted.redact(&[0, 1]).unwrap().redacted, vec![0, 1, 2]);
+        assert!(redacted.redact(&[0, 1]).unwrap().verify_hash());
+        
+        // Swapping in a different commitment breaks the hash
+        let mut forged = redacted.clone();
+        forged.evidence[0] = commitment("John Roe");
+        assert!(!forged.verify_hash());
+        let mut unmarked = redacted.clone();
+        unmarked.redacted.clear();
+        assert!(!unmarked.verify_hash());
+        
+        assert_eq!(
+            receipt.redact(&[3]).unwrap_err(),
+            RedactionError::IndexOutOfRange { index: 3, len: 3 }
+        );
+        // Re-sealing a redacted receipt keeps its commitments
+        let resealed = redacted.upgrade_to(3, mock_sign).unwrap();
+        assert_eq!(resealed.hash, receipt.hash);
+        assert!(resealed.verify(mock_verify));
+    }
+    
+    #[test]
+    fn test_redaction_requires_v3() {
+        let mut v2 = ReceiptBuilder::new("claim").with_evidence("fact").build(mock_sign);
+        v2.version = 2;
+        v2.hash = v2.compute_hash().unwrap();
+        assert_eq!(v2.redact(&[0]).unwrap_err(), RedactionError::UnsupportedVersion(2));
+        
+        let mut tampered = ReceiptBuilder::new("claim").with_evidence("fact").build(mock_sign);
+        tampered.claim = "other".to_string();
+        assert_eq!(tampered.redact(&[0]).unwrap_err(), RedactionError::HashMismatch);
+    }
+    
     #[test]
     fn test_invalid_receipt() {
         let receipt = ReceiptBuilder::new("contradictory claim")
@@ -0,0 +1,373 @@
+//! Field-by-field comparison of two receipts
+//!
+//! [`diff`] reports what differs between receipt `a` and receipt `b` and
+//! whether those differences account for the hash difference. Each side's
+//! hashed fields are transplanted onto the other and re-hashed: if `a` with
+//! `b`'s fields reproduces `b.hash` (and vice versa) the receipts simply
+//! describe different content; if a side's hash does not follow from its
+//! own fields, that receipt was altered after sealing.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use serde::Serialize;
+use std::collections::HashSet;
+use std::fmt;
+
+use super::Receipt;
+
+/// A value that differs between the two receipts
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Change<T> {
+    pub a: T,
+    pub b: T,
+}
+
+impl<T: PartialEq + Clone> Change<T> {
+    fn between(a: &T, b: &T) -> Option<Self> {
+        (a != b).then(|| Self { a: a.clone(), b: b.clone() })
+    }
+}
+
+/// Items present on only one side of an unordered list
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct SetDiff {
+    /// In `b` but not `a`
+    pub added: Vec<String>,
+    /// In `a` but not `b`
+    pub removed: Vec<String>,
+}
+
+impl SetDiff {
+    fn between(a: &[String], b: &[String]) -> Self {
+        let a_set: HashSet<&String> = a.iter().collect();
+        let b_set: HashSet<&String> = b.iter().collect();
+        Self {
+            added: b.iter().filter(|item| !a_set.contains(item)).cloned().collect(),
+            removed: a.iter().filter(|item| !b_set.contains(item)).cloned().collect(),
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.added.is_empty() && self.removed.is_empty()
+    }
+}
+
+/// A causal chain position whose link differs (`None` past the end of a chain)
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct LinkChange {
+    pub index: usize,
+    pub a: Option<String>,
+    pub b: Option<String>,
+}
+
+/// Whether each hash follows from its receipt's fields
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct HashCheck {
+    /// `a.hash` recomputes from `a`'s fields
+    pub a_valid: bool,
+    /// `b.hash` recomputes from `b`'s fields
+    pub b_valid: bool,
+    /// Swapping the differing fields reproduces the other side's hash
+    pub explained: bool,
+}
+
+/// Overall outcome of a comparison
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum Verdict {
+    /// Same fields, same hash
+    Identical,
+    /// Fields differ only in ways the hash ignores (e.g. evidence order under v2)
+    Equivalent,
+    /// Different content, each hash consistent with its own fields
+    ContentDiffers,
+    /// At least one hash does not follow from its receipt's fields
+    Tampered,
+}
+
+impl Verdict {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Verdict::Identical => "IDENTICAL",
+            Verdict::Equivalent => "EQUIVALENT",
+            Verdict::ContentDiffers => "CONTENT_DIFFERS",
+            Verdict::Tampered => "TAMPERED",
+        }
+    }
+}
+
+/// Differences between two receipts
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ReceiptDiff {
+    pub verdict: Verdict,
+    pub claim: Option<Change<String>>,
+    pub evidence: SetDiff,
+    /// Same evidence items in a different order
+    pub evidence_reordered: bool,
+    pub causal_chain: Vec<LinkChange>,
+    pub axioms: SetDiff,
+    pub c_zero: Option<Change<bool>>,
+    pub version: Option<Change<u32>>,
+    pub timestamp: Option<Change<DateTime<Utc>>>,
+    pub compound_differs: bool,
+    pub provenance_differs: bool,
+    pub parents: SetDiff,
+    pub evidence_files_differ: bool,
+    pub quantified_differs: bool,
+    pub hash: Option<Change<String>>,
+    pub hash_check: HashCheck,
+}
+
+impl ReceiptDiff {
+    /// Whether any hashed field differs
+    pub fn content_differs(&self) -> bool {
+        self.claim.is_some()
+            || !self.evidence.is_empty()
+            || self.evidence_reordered
+            || !self.causal_chain.is_empty()
+            || !self.axioms.is_empty()
+            || self.c_zero.is_some()
+            || self.version.is_some()
+            || self.timestamp.is_some()
+            || self.compound_differs
+            || self.provenance_differs
+            || !self.parents.is_empty()
+            || self.evidence_files_differ
+            || self.quantified_differs
+    }
+}
+
+/// Compare receipt `a` against receipt `b`
+pub fn diff(a: &Receipt, b: &Receipt) -> ReceiptDiff {
+    let evidence = SetDiff::between(&a.evidence, &b.evidence);
+    let evidence_reordered = evidence.is_empty() && a.evidence != b.evidence;
+
+    let chain_len = a.causal_chain.len().max(b.causal_chain.len());
+    let causal_chain = (0..chain_len)
+        .filter_map(|index| {
+            let (link_a, link_b) = (a.causal_chain.get(index), b.causal_chain.get(index));
+            (link_a != link_b).then(|| LinkChange {
+                index,
+                a: link_a.cloned(),
+                b: link_b.cloned(),
+            })
+        })
+        .collect();
+
+    let hash_check = HashCheck {
+        a_valid: a.verify_hash(),
+        b_valid: b.verify_hash(),
+        explained: with_fields_of(a, b).compute_hash().as_deref() == Some(b.hash.as_str())
+            && with_fields_of(b, a).compute_hash().as_deref() == Some(a.hash.as_str()),
+    };
+
+    let mut result = ReceiptDiff {
+        verdict: Verdict::Identical,
+        claim: Change::between(&a.claim, &b.claim),
+        evidence,
+        evidence_reordered,
+        causal_chain,
+        axioms: SetDiff::between(&a.axioms, &b.axioms),
+        c_zero: Change::between(&a.c_zero, &b.c_zero),
+        version: Change::between(&a.version, &b.version),
+        timestamp: Change::between(&a.timestamp, &b.timestamp),
+        compound_differs: serde_json::to_value(&a.compound).ok()
+            != serde_json::to_value(&b.compound).ok(),
+        provenance_differs: a.provenance != b.provenance,
+        parents: SetDiff::between(&a.parents, &b.parents),
+        evidence_files_differ: a.evidence_files != b.evidence_files,
+        quantified_differs: a.quantified != b.quantified,
+        hash: Change::between(&a.hash, &b.hash),
+        hash_check,
+    };
+
+    result.verdict = if !(hash_check.a_valid && hash_check.b_valid) {
+        Verdict::Tampered
+    } else if result.hash.is_some() {
+        Verdict::ContentDiffers
+    } else if result.content_differs() {
+        Verdict::Equivalent
+    } else {
+        Verdict::Identical
+    };
+    result
+}
+
+/// `target` with every hashed field taken from `source`
+fn with_fields_of(target: &Receipt, source: &Receipt) -> Receipt {
+    Receipt {
+        version: source.version,
+        claim: source.claim.clone(),
+        evidence: source.evidence.clone(),
+        causal_chain: source.causal_chain.clone(),
+        axioms: source.axioms.clone(),
+        c_zero: source.c_zero,
+        timestamp: source.timestamp,
+        compound: source.compound.clone(),
+        provenance: source.provenance.clone(),
+        parents: source.parents.clone(),
+        evidence_files: source.evidence_files.clone(),
+        normalization: source.normalization,
+        redacted: source.redacted.clone(),
+        quantified: source.quantified.clone(),
+        ..target.clone()
+    }
+}
+
+impl fmt::Display for ReceiptDiff {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "Verdict: {}", self.verdict.as_str())?;
+
+        if let Some(ref claim) = self.claim {
+            writeln!(f, "Claim:")?;
+            writeln!(f, "  - {}", claim.a)?;
+            writeln!(f, "  + {}", claim.b)?;
+        }
+        if !self.evidence.is_empty() {
+            writeln!(f, "Evidence:")?;
+            write_set(f, &self.evidence)?;
+        } else if self.evidence_reordered {
+            writeln!(f, "Evidence: same items, different order")?;
+        }
+        if !self.causal_chain.is_empty() {
+            writeln!(f, "Causal chain:")?;
+            for link in &self.causal_chain {
+                if let Some(ref a) = link.a {
+                    writeln!(f, "  [{}] - {}", link.index, a)?;
+                }
+                if let Some(ref b) = link.b {
+                    writeln!(f, "  [{}] + {}", link.index, b)?;
+                }
+            }
+        }
+        if !self.axioms.is_empty() {
+            writeln!(f, "Axioms:")?;
+            write_set(f, &self.axioms)?;
+        }
+        if let Some(ref c_zero) = self.c_zero {
+            writeln!(f, "C=0: {} -> {}", c_zero.a, c_zero.b)?;
+        }
+        if let Some(ref version) = self.version {
+            writeln!(f, "Version: {} -> {}", version.a, version.b)?;
+        }
+        if let Some(ref timestamp) = self.timestamp {
+            writeln!(f, "Timestamp: {} -> {}", timestamp.a.to_rfc3339(), timestamp.b.to_rfc3339())?;
+        }
+        if self.compound_differs {
+            writeln!(f, "Compound record differs")?;
+        }
+        if self.provenance_differs {
+            writeln!(f, "Evidence provenance differs")?;
+        }
+        if !self.parents.is_empty() {
+            writeln!(f, "Parents:")?;
+            write_set(f, &self.parents)?;
+        }
+        if self.evidence_files_differ {
+            writeln!(f, "Evidence files differ")?;
+        }
+        if self.quantified_differs {
+            writeln!(f, "Quantified record differs")?;
+        }
+
+        match self.hash {
+            Some(ref hash) => {
+                writeln!(f, "Hash:")?;
+                writeln!(f, "  - {}", hash.a)?;
+                writeln!(f, "  + {}", hash.b)?;
+            }
+            None => writeln!(f, "Hash: identical")?,
+        }
+
+        let check = &self.hash_check;
+        writeln!(f, "Hash of a recomputes: {}", yes_no(check.a_valid))?;
+        writeln!(f, "Hash of b recomputes: {}", yes_no(check.b_valid))?;
+        write!(f, "Field differences explain the hashes: {}", yes_no(check.explained))
+    }
+}
+
+fn write_set(f: &mut fmt::Formatter<'_>, set: &SetDiff) -> fmt::Result {
+    for item in &set.removed {
+        writeln!(f, "  - {}", item)?;
+    }
+    for item in &set.added {
+        writeln!(f, "  + {}", item)?;
+    }
+    Ok(())
+}
+
+fn yes_no(value: bool) -> &'static str {
+    if value { "yes" } else { "no" }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::engine::ProofEngine;
+
+    fn mock_sign(hash: &str) -> String {
+        format!("sig:{}", hash)
+    }
+
+    fn prove(evidence: &[&str]) -> Receipt {
+        let engine = ProofEngine::new();
+        let evidence = evidence.iter().map(|e| e.to_string()).collect();
+        let (_, receipt) = engine.prove("Water boils", evidence, mock_sign).unwrap();
+        receipt
+    }
+
+    #[test]
+    fn test_identical_receipts() {
+        let receipt = prove(&["Heat applied", "Temperature reached 100C"]);
+        let result = diff(&receipt, &receipt);
+
+        assert_eq!(result.verdict, Verdict::Identical);
+        assert!(!result.content_differs());
+        assert!(result.hash_check.explained);
+    }
+
+    #[test]
+    fn test_different_evidence_explains_hash() {
+        let a = prove(&["Heat applied", "Temperature reached 100C"]);
+        let mut b = prove(&["Heat applied", "Pressure is 1 atm"]);
+        b.timestamp = a.timestamp;
+        b.seal(mock_sign);
+
+        let result = diff(&a, &b);
+        assert_eq!(result.verdict, Verdict::ContentDiffers);
+        assert_eq!(result.evidence.added, vec!["Pressure is 1 atm".to_string()]);
+        assert_eq!(result.evidence.removed, vec!["Temperature reached 100C".to_string()]);
+        assert!(result.timestamp.is_none());
+        assert!(result.hash_check.explained);
+    }
+
+    #[test]
+    fn test_reordered_evidence_is_equivalent_under_v2() {
+        let a = prove(&["Heat applied", "Temperature reached 100C"]);
+        let mut b = a.clone();
+        b.evidence.reverse();
+
+        let result = diff(&a, &b);
+        assert_eq!(result.verdict, Verdict::Equivalent);
+        assert!(result.evidence_reordered);
+        assert!(result.hash.is_none());
+    }
+
+    #[test]
+    fn test_tampered_receipt() {
+        let a = prove(&["Heat applied", "Temperature reached 100C"]);
+        let mut b = a.clone();
+        b.c_zero = !b.c_zero;
+        b.causal_chain.push("forged link".to_string());
+
+        let result = diff(&a, &b);
+        assert_eq!(result.verdict, Verdict::Tampered);
+        assert!(result.hash_check.a_valid);
+        assert!(!result.hash_check.b_valid);
+        assert!(!result.hash_check.explained);
+        assert_eq!(result.causal_chain.len(), 1);
+        assert_eq!(result.causal_chain[0].b.as_deref(), Some("forged link"));
+        assert!(result.to_string().contains("Hash of b recomputes: no"));
+    }
+}
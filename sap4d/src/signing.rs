@@ -0,0 +1,299 @@
+//! Key-identified receipt signatures
+//!
+//! A signature is only as useful as the verifier's ability to find the key
+//! that made it. Receipts therefore record the `key_id` of every signature,
+//! and a [`SignerRegistry`] maps key ids to verification functions. Keys
+//! are rotated by registering the new id next to the old one: receipts
+//! signed before the rotation keep verifying under their own key.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::anchors::{hmac_sign, hmac_verify, TrustAnchor};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashSet};
+use std::fmt;
+use std::path::Path;
+use std::sync::Arc;
+use thiserror::Error;
+
+/// Signs receipt hashes under a named key
+pub trait ReceiptSigner {
+    /// Id the verification key is registered under
+    fn key_id(&self) -> &str;
+
+    /// Signature over `hash`
+    fn sign(&self, hash: &str) -> String;
+}
+
+/// A [`ReceiptSigner`] from a key id and a signing function
+pub struct FnSigner<F> {
+    key_id: String,
+    sign: F,
+}
+
+impl<F: Fn(&str) -> String> FnSigner<F> {
+    pub fn new(key_id: impl Into<String>, sign: F) -> Self {
+        Self { key_id: key_id.into(), sign }
+    }
+}
+
+impl<F: Fn(&str) -> String> ReceiptSigner for FnSigner<F> {
+    fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    fn sign(&self, hash: &str) -> String {
+        (self.sign)(hash)
+    }
+}
+
+/// Signs receipt hashes with HMAC-SHA256 under a secret key
+///
+/// The key id is a fingerprint of the key (`sha256:` and the first 16 hex
+/// digits of its digest), so receipts name their key without revealing it.
+/// Verifiers holding the same secret trust it as a
+/// [`TrustAnchor::hmac`](crate::anchors::TrustAnchor::hmac) under that id.
+#[derive(Clone)]
+pub struct KeySigner {
+    key: Arc<[u8]>,
+    key_id: String,
+}
+
+impl KeySigner {
+    pub fn new(key: impl Into<Vec<u8>>) -> Self {
+        let key: Vec<u8> = key.into();
+        let key_id = format!("sha256:{}", &hex::encode(Sha256::digest(&key))[..16]);
+        Self { key: key.into(), key_id }
+    }
+
+    /// Key from the contents of `path`, ignoring trailing whitespace
+    pub fn from_file(path: &Path) -> std::io::Result<Self> {
+        let contents = std::fs::read(path)?;
+        let end = contents.iter().rposition(|b| !b.is_ascii_whitespace()).map_or(0, |i| i + 1);
+        if end == 0 {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("signing key file {} is empty", path.display()),
+            ));
+        }
+        Ok(Self::new(&contents[..end]))
+    }
+
+    /// Base64 HMAC-SHA256 of `hash` under the key
+    pub fn sign(&self, hash: &str) -> String {
+        hmac_sign(&self.key, hash)
+    }
+
+    pub fn verify(&self, hash: &str, signature: &str) -> bool {
+        hmac_verify(&self.key, hash, signature)
+    }
+
+    /// The anchor that trusts this signer's signatures, under its key id
+    pub fn anchor(&self) -> TrustAnchor {
+        TrustAnchor::hmac(self.key_id.clone(), self.key.to_vec())
+    }
+}
+
+impl ReceiptSigner for KeySigner {
+    fn key_id(&self) -> &str {
+        &self.key_id
+    }
+
+    fn sign(&self, hash: &str) -> String {
+        KeySigner::sign(self, hash)
+    }
+}
+
+impl fmt::Debug for KeySigner {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("KeySigner")
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// A signature and the key that made it
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct KeyedSignature {
+    pub key_id: String,
+    pub signature: String,
+}
+
+impl KeyedSignature {
+    /// `signer`'s signature over `hash`
+    pub fn sign(signer: &dyn ReceiptSigner, hash: &str) -> Self {
+        Self {
+            key_id: signer.key_id().to_string(),
+            signature: signer.sign(hash),
+        }
+    }
+}
+
+/// Why a signature check failed
+#[derive(Error, Debug, Clone, PartialEq, Eq)]
+pub enum SignatureError {
+    #[error("Receipt does not record which key signed it")]
+    MissingKeyId,
+
+    #[error("Unknown signing key: {0}")]
+    UnknownKey(String),
+
+    #[error("Invalid signature from key {0}")]
+    InvalidSignature(String),
+
+    #[error("Receipt hash does not match its contents")]
+    HashMismatch,
+
+    #[error("{valid} valid signature(s) from distinct keys, {required} required")]
+    QuorumNotMet { valid: usize, required: usize },
+}
+
+/// Verification function: `(hash, signature) -> valid`
+pub type VerifyFn = Box<dyn Fn(&str, &str) -> bool + Send + Sync>;
+
+/// Verification keys by key id
+#[derive(Default)]
+pub struct SignerRegistry {
+    keys: BTreeMap<String, VerifyFn>,
+}
+
+impl fmt::Debug for SignerRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SignerRegistry")
+            .field("key_ids", &self.keys.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl SignerRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register (or replace) the verification function for `key_id`
+    pub fn with_key(
+        mut self,
+        key_id: impl Into<String>,
+        verify: impl Fn(&str, &str) -> bool + Send + Sync + 'static,
+    ) -> Self {
+        self.insert(key_id, verify);
+        self
+    }
+
+    /// Register (or replace) the verification function for `key_id`
+    pub fn insert(&mut self, key_id: impl Into<String>, verify: impl Fn(&str, &str) -> bool + Send + Sync + 'static) {
+        self.keys.insert(key_id.into(), Box::new(verify));
+    }
+
+    /// Stop trusting `key_id`; its signatures become unknown-key failures
+    pub fn remove(&mut self, key_id: &str) -> bool {
+        self.keys.remove(key_id).is_some()
+    }
+
+    pub fn contains(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+
+    /// Registered key ids, sorted
+    pub fn key_ids(&self) -> impl Iterator<Item = &str> {
+        self.keys.keys().map(String::as_str)
+    }
+
+    /// Check `signature` over `hash` with the key registered as `key_id`
+    pub fn verify(&self, key_id: &str, hash: &str, signature: &str) -> Result<(), SignatureError> {
+        let verify = self
+            .keys
+            .get(key_id)
+            .ok_or_else(|| SignatureError::UnknownKey(key_id.to_string()))?;
+        if verify(hash, signature) {
+            Ok(())
+        } else {
+            Err(SignatureError::InvalidSignature(key_id.to_string()))
+        }
+    }
+
+    /// Require valid signatures over `hash` from at least `quorum` distinct keys
+    ///
+    /// Unknown keys and bad signatures simply do not count; a key that
+    /// signed twice counts once. Returns the number of distinct valid keys.
+    pub fn verify_quorum<'a>(
+        &self,
+        hash: &str,
+        signatures: impl IntoIterator<Item = &'a KeyedSignature>,
+        quorum: usize,
+    ) -> Result<usize, SignatureError> {
+        let valid: HashSet<&str> = signatures
+            .into_iter()
+            .filter(|s| self.verify(&s.key_id, hash, &s.signature).is_ok())
+            .map(|s| s.key_id.as_str())
+            .collect();
+
+        let required = quorum.max(1);
+        if valid.len() >= required {
+            Ok(valid.len())
+        } else {
+            Err(SignatureError::QuorumNotMet { valid: valid.len(), required })
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn keyed(key: &'static str) -> (FnSigner<impl Fn(&str) -> String>, impl Fn(&str, &str) -> bool) {
+        let sign = move |hash: &str| format!("{}:{}", key, hash);
+        (FnSigner::new(key, sign), move |hash: &str, sig: &str| sig == sign(hash))
+    }
+
+    #[test]
+    fn test_unknown_key_differs_from_bad_signature() {
+        let (signer, verify) = keyed("key-a");
+        let registry = SignerRegistry::new().with_key("key-a", verify);
+        let signature = KeyedSignature::sign(&signer, "h");
+
+        assert_eq!(registry.verify("key-a", "h", &signature.signature), Ok(()));
+        assert_eq!(
+            registry.verify("key-a", "other", &signature.signature),
+            Err(SignatureError::InvalidSignature("key-a".to_string()))
+        );
+        assert_eq!(
+            registry.verify("key-z", "h", &signature.signature),
+            Err(SignatureError::UnknownKey("key-z".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_quorum_counts_distinct_valid_keys() {
+        let (a, verify_a) = keyed("a");
+        let (b, verify_b) = keyed("b");
+        let (c, _) = keyed("c");
+        let registry = SignerRegistry::new().with_key("a", verify_a).with_key("b", verify_b);
+
+        let signatures = [
+            KeyedSignature::sign(&a, "h"),
+            KeyedSignature::sign(&a, "h"),
+            KeyedSignature::sign(&c, "h"),
+            KeyedSignature { key_id: "b".to_string(), signature: "forged".to_string() },
+        ];
+        assert_eq!(registry.verify_quorum("h", &signatures, 1), Ok(1));
+        assert_eq!(
+            registry.verify_quorum("h", &signatures, 2),
+            Err(SignatureError::QuorumNotMet { valid: 1, required: 2 })
+        );
+
+        let signatures = [KeyedSignature::sign(&a, "h"), KeyedSignature::sign(&b, "h")];
+        assert_eq!(registry.verify_quorum("h", &signatures, 2), Ok(2));
+        assert_eq!(registry.key_ids().collect::<Vec<_>>(), ["a", "b"]);
+    }
+
+    #[test]
+    fn test_key_signer_anchor_accepts_only_its_signatures() {
+        let signer = KeySigner::new("local key");
+        let anchor = signer.anchor();
+        assert_eq!(anchor.name, signer.key_id());
+        assert!(anchor.verify("h", &signer.sign("h")));
+        assert!(!anchor.verify("h", &KeySigner::new("other key").sign("h")));
+    }
+}
@@ -1,13 +1,29 @@
 //! Trace generation for proof steps
 //!
+//! A trace's hashes only show it was not altered after it was built. To check
+//! that each step's output actually follows from its input, [`TraceEnvelope::replay`]
+//! re-runs every step through the executor registered for its operation in a
+//! [`StepRegistry`] and compares the results.
+//!
+//! # Timing
+//!
+//! Each step records `duration_micros` and the envelope `total_duration_micros`.
+//! Neither is covered by a hash, so timing jitter never changes a trace's
+//! `receipt_hash`; [`TraceEnvelope::metrics`] summarizes them.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
 use sha2::{Digest, Sha256};
 use chrono::{DateTime, Utc};
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::{Duration, Instant};
 
 use crate::axioms::Axiom;
-use crate::causal::CausalChain;
+use crate::causal::{dot_escape, CausalChain};
+use crate::evidence::Evidence;
+use crate::hashing::{legacy_hash_version, FieldHasher, FIELD_HASH_VERSION};
 
 /// A single step in a proof trace
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -24,8 +40,14 @@ pub struct TraceStep {
     pub axioms_applied: Vec<String>,
     /// Hash of this step
     pub step_hash: String,
+    /// Rules `step_hash` was computed under (1 if absent; see [`crate::hashing`])
+    #[serde(default = "legacy_hash_version")]
+    pub hash_version: u32,
     /// Timestamp
     pub timestamp: DateTime<Utc>,
+    /// Wall-clock time spent producing this step (not hashed)
+    #[serde(default)]
+    pub duration_micros: u64,
 }
 
 impl TraceStep {
@@ -40,49 +62,53 @@ impl TraceStep {
         let operation = operation.into();
         let input = input.into();
         let output = output.into();
-        let timestamp = Utc::now();
+        let timestamp = crate::clock::now();
         
-        let step_hash = Self::compute_hash(index, &operation, &input, &output, &axioms_applied);
-        
-        Self {
+        let mut step = Self {
             index,
             operation,
             input,
             output,
             axioms_applied,
-            step_hash,
+            step_hash: String::new(),
+            hash_version: FIELD_HASH_VERSION,
             timestamp,
-        }
+            duration_micros: 0,
+        };
+        step.step_hash = step.compute_hash().expect("hash version is supported");
+        step
     }
     
-    fn compute_hash(
-        index: usize,
-        operation: &str,
-        input: &str,
-        output: &str,
-        axioms: &[String],
-    ) -> String {
-        let mut hasher = Sha256::new();
-        hasher.update(index.to_le_bytes());
-        hasher.update(operation.as_bytes());
-        hasher.update(input.as_bytes());
-        hasher.update(output.as_bytes());
-        for axiom in axioms {
-            hasher.update(axiom.as_bytes());
+    /// Hash under the rules of `self.hash_version` (`None` for an unknown version)
+    fn compute_hash(&self) -> Option<String> {
+        match self.hash_version {
+            1 => {
+                let mut hasher = Sha256::new();
+                hasher.update(self.index.to_le_bytes());
+                hasher.update(self.operation.as_bytes());
+                hasher.update(self.input.as_bytes());
+                hasher.update(self.output.as_bytes());
+                for axiom in &self.axioms_applied {
+                    hasher.update(axiom.as_bytes());
+                }
+                Some(hex::encode(hasher.finalize()))
+            }
+            2 => Some(
+                FieldHasher::new("sap4d.trace-step")
+                    .u64(self.index as u64)
+                    .str(&self.operation)
+                    .str(&self.input)
+                    .str(&self.output)
+                    .list(&self.axioms_applied)
+                    .finish(),
+            ),
+            _ => None,
         }
-        hex::encode(hasher.finalize())
     }
     
     /// Verify the step's integrity
     pub fn verify_integrity(&self) -> bool {
-        let computed = Self::compute_hash(
-            self.index,
-            &self.operation,
-            &self.input,
-            &self.output,
-            &self.axioms_applied,
-        );
-        computed == self.step_hash
+        self.compute_hash().as_deref() == Some(self.step_hash.as_str())
     }
 }
 
@@ -109,13 +135,43 @@ pub struct TraceEnvelope {
     pub substrate: String,
     /// Projection identifier
     pub projection: String,
+    /// Source provenance of the observations (empty for bare evidence)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub provenance: Vec<Evidence>,
+    /// Wall-clock time spent building the trace (not hashed)
+    #[serde(default)]
+    pub total_duration_micros: u64,
+}
+
+/// Timing summary of a trace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TraceMetrics {
+    pub total_duration_micros: u64,
+    /// Number of steps per operation
+    pub steps_by_operation: BTreeMap<String, usize>,
+    /// The step that took longest (the first, on a tie)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slowest_step: Option<StepTiming>,
+}
+
+/// Duration of one trace step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepTiming {
+    pub index: usize,
+    pub operation: String,
+    pub duration_micros: u64,
+}
+
+/// `duration` in whole microseconds, saturating
+pub(crate) fn micros(duration: Duration) -> u64 {
+    u64::try_from(duration.as_micros()).unwrap_or(u64::MAX)
 }
 
 impl TraceEnvelope {
     /// Create a new trace envelope
     pub fn new(claim: impl Into<String>, observations: Vec<String>) -> Self {
         let claim = claim.into();
-        let created_at = Utc::now();
+        let created_at = crate::clock::now();
         
         Self {
             claim: claim.clone(),
@@ -128,9 +184,17 @@ impl TraceEnvelope {
             created_at,
             substrate: crate::SUBSTRATE.to_string(),
             projection: crate::PROJECTION.to_string(),
+            provenance: Vec::new(),
+            total_duration_micros: 0,
         }
     }
     
+    /// Attach observation provenance and re-finalize the hash
+    pub fn set_provenance(&mut self, provenance: Vec<Evidence>) {
+        self.provenance = provenance;
+        self.finalize();
+    }
+    
     /// Add a trace step
     pub fn add_step(&mut self, step: TraceStep) {
         self.steps.push(step);
@@ -174,6 +238,11 @@ impl TraceEnvelope {
         hasher.update(self.substrate.as_bytes());
         hasher.update(self.projection.as_bytes());
         
+        // Only sourced traces hash provenance, so bare-evidence hashes are unchanged
+        if !self.provenance.is_empty() {
+            hasher.update(serde_json::to_string(&self.provenance).unwrap_or_default().as_bytes());
+        }
+        
         self.receipt_hash = hex::encode(hasher.finalize());
     }
     
@@ -210,6 +279,11 @@ impl TraceEnvelope {
         hasher.update(self.substrate.as_bytes());
         hasher.update(self.projection.as_bytes());
         
+        // Only sourced traces hash provenance, so bare-evidence hashes are unchanged
+        if !self.provenance.is_empty() {
+            hasher.update(serde_json::to_string(&self.provenance).unwrap_or_default().as_bytes());
+        }
+        
         let computed = hex::encode(hasher.finalize());
         computed == self.receipt_hash
     }
@@ -219,37 +293,343 @@ impl TraceEnvelope {
         self.contradiction_check
     }
     
+    /// Total duration, step counts by operation and the slowest step
+    pub fn metrics(&self) -> TraceMetrics {
+        let mut steps_by_operation = BTreeMap::new();
+        for step in &self.steps {
+            *steps_by_operation.entry(step.operation.clone()).or_insert(0) += 1;
+        }
+        let slowest_step = self
+            .steps
+            .iter()
+            .rev()
+            .max_by_key(|step| step.duration_micros)
+            .map(|step| StepTiming {
+                index: step.index,
+                operation: step.operation.clone(),
+                duration_micros: step.duration_micros,
+            });
+        TraceMetrics {
+            total_duration_micros: self.total_duration_micros,
+            steps_by_operation,
+            slowest_step,
+        }
+    }
+    
     /// Get the explainability index (ratio of explained steps)
     pub fn explainability_index(&self) -> f64 {
-        if self.steps.is_empty() {
-            return 0.0;
-        }
+        self.explainability_report().index
+    }
+    
+    /// Per-step breakdown of which steps are backed by axioms
+    pub fn explainability_report(&self) -> ExplainabilityReport {
+        self.explainability_report_weighted(&HashMap::new())
+    }
+    
+    /// Breakdown with steps weighted by operation name (unlisted operations weigh 1.0)
+    pub fn explainability_report_weighted(&self, weights: &HashMap<String, f64>) -> ExplainabilityReport {
+        let steps: Vec<StepExplanation> = self.steps.iter()
+            .map(|step| StepExplanation {
+                index: step.index,
+                operation: step.operation.clone(),
+                axioms_applied: step.axioms_applied.clone(),
+                explained: !step.axioms_applied.is_empty(),
+                weight: weights.get(&step.operation).copied().unwrap_or(1.0),
+            })
+            .collect();
+        
+        let total_weight: f64 = steps.iter().map(|s| s.weight).sum();
+        let explained_weight: f64 = steps.iter().filter(|s| s.explained).map(|s| s.weight).sum();
+        let index = if total_weight > 0.0 { explained_weight / total_weight } else { 0.0 };
         
-        let explained = self.steps.iter()
-            .filter(|s| !s.axioms_applied.is_empty())
-            .count();
+        ExplainabilityReport {
+            explained: steps.iter().filter(|s| s.explained).count(),
+            total: steps.len(),
+            index,
+            steps,
+        }
+    }
+    
+    /// Re-run every step through `registry` and compare with the recorded outputs
+    ///
+    /// Steps whose operation has no executor are reported as unknown, so a
+    /// report is only valid when every step was actually re-executed.
+    pub fn replay(&self, registry: &StepRegistry) -> ReplayReport {
+        let steps: Vec<StepReplay> = self.steps.iter()
+            .map(|step| {
+                let status = match registry.execute(self, &step.operation, &step.input) {
+                    None => ReplayStatus::UnknownOperation,
+                    Some(Err(reason)) => ReplayStatus::Failed { reason },
+                    Some(Ok(output)) if output == step.output => ReplayStatus::Matched,
+                    Some(Ok(expected)) => ReplayStatus::Mismatch { expected },
+                };
+                StepReplay {
+                    index: step.index,
+                    operation: step.operation.clone(),
+                    recorded: step.output.clone(),
+                    status,
+                }
+            })
+            .collect();
         
-        explained as f64 / self.steps.len() as f64
+        ReplayReport {
+            integrity_ok: self.verify_integrity(),
+            matched: steps.iter().filter(|s| s.status == ReplayStatus::Matched).count(),
+            total: steps.len(),
+            steps,
+        }
     }
     
     /// Convert to JSON
     pub fn to_json(&self) -> Result<String, serde_json::Error> {
         serde_json::to_string_pretty(self)
     }
+    
+    /// Render the steps as a GraphViz DOT sequence ending at the claim
+    ///
+    /// Each step is a box labeled with its operation, output and the
+    /// axioms it applied; steps without axioms are dashed.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::from("digraph trace {\n    rankdir=TB;\n");
+        for step in &self.steps {
+            let axioms = if step.axioms_applied.is_empty() {
+                "(no axioms)".to_string()
+            } else {
+                step.axioms_applied.join(", ")
+            };
+            let label = format!("{}. {}\n{}\n[{}]", step.index, step.operation, step.output, axioms);
+            let style = if step.axioms_applied.is_empty() { ", style=dashed" } else { "" };
+            dot.push_str(&format!(
+                "    s{} [label=\"{}\", shape=box{}];\n",
+                step.index,
+                dot_escape(&label),
+                style
+            ));
+        }
+        dot.push_str(&format!(
+            "    claim [label=\"{}\", shape=doublecircle];\n",
+            dot_escape(&self.claim)
+        ));
+        
+        let mut previous: Option<String> = None;
+        for step in &self.steps {
+            let id = format!("s{}", step.index);
+            if let Some(prev) = previous {
+                dot.push_str(&format!("    {} -> {};\n", prev, id));
+            }
+            previous = Some(id);
+        }
+        if let Some(last) = previous {
+            let color = if self.contradiction_check { "" } else { " [color=red]" };
+            dot.push_str(&format!("    {} -> claim{};\n", last, color));
+        }
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+/// Whether one trace step is backed by axioms
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct StepExplanation {
+    /// Step index
+    pub index: usize,
+    /// Operation performed
+    pub operation: String,
+    /// Axioms applied
+    pub axioms_applied: Vec<String>,
+    /// At least one axiom was applied
+    pub explained: bool,
+    /// Weight of this step in the index
+    pub weight: f64,
+}
+
+/// Per-step explainability breakdown of a trace
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ExplainabilityReport {
+    /// One entry per trace step, in order
+    pub steps: Vec<StepExplanation>,
+    /// Steps with at least one axiom
+    pub explained: usize,
+    /// All steps
+    pub total: usize,
+    /// Weighted ratio of explained steps (0.0 for an empty trace)
+    pub index: f64,
+}
+
+impl ExplainabilityReport {
+    /// Steps that applied no axioms
+    pub fn unexplained(&self) -> impl Iterator<Item = &StepExplanation> {
+        self.steps.iter().filter(|s| !s.explained)
+    }
+}
+
+impl fmt::Display for ExplainabilityReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} steps explained (index {:.4})", self.explained, self.total, self.index)?;
+        let unexplained: Vec<String> = self.unexplained()
+            .map(|s| format!("{}. {}", s.index, s.operation))
+            .collect();
+        if !unexplained.is_empty() {
+            write!(f, "; unexplained: {}", unexplained.join(", "))?;
+        }
+        Ok(())
+    }
+}
+
+/// Re-executes one kind of step: `(trace, input) -> output`
+///
+/// Executors must be pure: the output may depend only on the input and the
+/// trace's claim, observations and causal chain. An `Err` means the input
+/// itself is inconsistent with the trace.
+pub type StepExecutor = Box<dyn Fn(&TraceEnvelope, &str) -> Result<String, String> + Send + Sync>;
+
+/// Step executors by operation name
+#[derive(Default)]
+pub struct StepRegistry {
+    executors: BTreeMap<String, StepExecutor>,
+}
+
+impl fmt::Debug for StepRegistry {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StepRegistry")
+            .field("operations", &self.executors.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+impl StepRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Register (or replace) the executor for `operation`
+    pub fn with_executor(
+        mut self,
+        operation: impl Into<String>,
+        executor: impl Fn(&TraceEnvelope, &str) -> Result<String, String> + Send + Sync + 'static,
+    ) -> Self {
+        self.insert(operation, executor);
+        self
+    }
+    
+    /// Register (or replace) the executor for `operation`
+    pub fn insert(
+        &mut self,
+        operation: impl Into<String>,
+        executor: impl Fn(&TraceEnvelope, &str) -> Result<String, String> + Send + Sync + 'static,
+    ) {
+        self.executors.insert(operation.into(), Box::new(executor));
+    }
+    
+    pub fn contains(&self, operation: &str) -> bool {
+        self.executors.contains_key(operation)
+    }
+    
+    /// Registered operation names, sorted
+    pub fn operations(&self) -> impl Iterator<Item = &str> {
+        self.executors.keys().map(String::as_str)
+    }
+    
+    /// Run `operation` on `input`, or `None` if nothing is registered for it
+    pub fn execute(&self, trace: &TraceEnvelope, operation: &str, input: &str) -> Option<Result<String, String>> {
+        self.executors.get(operation).map(|executor| executor(trace, input))
+    }
+}
+
+/// Outcome of re-executing one step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum ReplayStatus {
+    /// Re-execution reproduced the recorded output
+    Matched,
+    /// Re-execution produced `expected` instead of the recorded output
+    Mismatch { expected: String },
+    /// The executor rejected the recorded input
+    Failed { reason: String },
+    /// No executor is registered for the operation
+    UnknownOperation,
+}
+
+/// Replay result for one trace step
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StepReplay {
+    /// Step index
+    pub index: usize,
+    /// Operation performed
+    pub operation: String,
+    /// Output recorded in the trace
+    pub recorded: String,
+    #[serde(flatten)]
+    pub status: ReplayStatus,
+}
+
+/// Per-step result of replaying a trace
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ReplayReport {
+    /// One entry per trace step, in order
+    pub steps: Vec<StepReplay>,
+    /// Steps whose output was reproduced
+    pub matched: usize,
+    /// All steps
+    pub total: usize,
+    /// Step and trace hashes match their contents
+    pub integrity_ok: bool,
+}
+
+impl ReplayReport {
+    /// Whether the hashes hold and every step was reproduced
+    pub fn is_valid(&self) -> bool {
+        self.integrity_ok && self.matched == self.total
+    }
+    
+    /// Steps that were not reproduced, including unknown operations
+    pub fn failures(&self) -> impl Iterator<Item = &StepReplay> {
+        self.steps.iter().filter(|s| s.status != ReplayStatus::Matched)
+    }
+}
+
+impl fmt::Display for ReplayReport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{} steps reproduced", self.matched, self.total)?;
+        if !self.integrity_ok {
+            write!(f, "; trace hashes do not match")?;
+        }
+        let failures: Vec<String> = self.failures()
+            .map(|s| match &s.status {
+                ReplayStatus::Mismatch { .. } => format!("{}. {} (output differs)", s.index, s.operation),
+                ReplayStatus::Failed { reason } => format!("{}. {} ({})", s.index, s.operation, reason),
+                _ => format!("{}. {} (unknown operation)", s.index, s.operation),
+            })
+            .collect();
+        if !failures.is_empty() {
+            write!(f, "; not reproduced: {}", failures.join(", "))?;
+        }
+        Ok(())
+    }
 }
 
 /// Builder for constructing trace envelopes
+///
+/// [`TraceBuilder::add_step`] times each step from the end of the previous
+/// one (or the builder's creation), which covers computing its arguments;
+/// [`TraceBuilder::add_timed_step`] times exactly the closure producing the
+/// output. `build` records the time since creation as the total.
 pub struct TraceBuilder {
     envelope: TraceEnvelope,
     step_counter: usize,
+    started: Instant,
+    last_step: Instant,
 }
 
 impl TraceBuilder {
     /// Create a new builder
     pub fn new(claim: impl Into<String>) -> Self {
+        let now = Instant::now();
         Self {
             envelope: TraceEnvelope::new(claim, Vec::new()),
             step_counter: 0,
+            started: now,
+            last_step: now,
         }
     }
     
@@ -267,21 +647,49 @@ impl TraceBuilder {
     
     /// Add a step
     pub fn add_step(
+        self,
+        operation: impl Into<String>,
+        input: impl Into<String>,
+        output: impl Into<String>,
+        axioms: Vec<String>,
+    ) -> Self {
+        let duration = self.last_step.elapsed();
+        self.push_step(operation, input, output, axioms, duration)
+    }
+    
+    /// Add a step whose output is produced by `run`, timing only `run`
+    pub fn add_timed_step(
+        self,
+        operation: impl Into<String>,
+        input: impl Into<String>,
+        axioms: Vec<String>,
+        run: impl FnOnce() -> String,
+    ) -> Self {
+        let started = Instant::now();
+        let output = run();
+        let duration = started.elapsed();
+        self.push_step(operation, input, output, axioms, duration)
+    }
+    
+    fn push_step(
         mut self,
         operation: impl Into<String>,
         input: impl Into<String>,
         output: impl Into<String>,
         axioms: Vec<String>,
+        duration: Duration,
     ) -> Self {
-        let step = TraceStep::new(
+        let mut step = TraceStep::new(
             self.step_counter,
             operation,
             input,
             output,
             axioms,
         );
+        step.duration_micros = micros(duration);
         self.envelope.add_step(step);
         self.step_counter += 1;
+        self.last_step = Instant::now();
         self
     }
     
@@ -299,6 +707,7 @@ impl TraceBuilder {
     
     /// Build and finalize the trace
     pub fn build(mut self) -> TraceEnvelope {
+        self.envelope.total_duration_micros = micros(self.started.elapsed());
         self.envelope.finalize();
         self.envelope
     }
@@ -366,5 +775,78 @@ mod tests {
         
         assert_eq!(trace.explainability_index(), 0.5);
     }
+    
+    #[test]
+    fn test_explainability_report() {
+        let trace = TraceBuilder::new("claim")
+            .add_step("op1", "in", "out", vec!["axiom".to_string()])
+            .add_step("op2", "in", "out", vec![])
+            .add_step("op3", "in", "out", vec!["axiom".to_string()])
+            .build();
+        
+        let report = trace.explainability_report();
+        assert_eq!((report.explained, report.total), (2, 3));
+        let unexplained: Vec<usize> = report.unexplained().map(|s| s.index).collect();
+        assert_eq!(unexplained, [1]);
+        assert!(report.to_string().ends_with("unexplained: 1. op2"));
+        
+        let weights = HashMap::from([("op2".to_string(), 2.0), ("op3".to_string(), 0.0)]);
+        let weighted = trace.explainability_report_weighted(&weights);
+        assert!((weighted.index - 1.0 / 3.0).abs() < 1e-12);
+        
+        assert_eq!(TraceEnvelope::new("empty", vec![]).explainability_report().index, 0.0);
+    }
+    
+    #[test]
+    fn test_to_dot() {
+        let trace = TraceBuilder::new("say \"yes\"")
+            .add_step("analyze", "in", "line 1\nline 2", vec!["A1_IDENTITY".to_string()])
+            .add_step("deduce", "in", "out", vec![])
+            .build();
+        
+        let dot = trace.to_dot();
+        
+        assert!(dot.starts_with("digraph trace {"));
+        assert!(dot.contains(r#"s0 [label="0. analyze\nline 1\nline 2\n[A1_IDENTITY]", shape=box];"#));
+        assert!(dot.contains(r#"s1 [label="1. deduce\nout\n[(no axioms)]", shape=box, style=dashed];"#));
+        assert!(dot.contains(r#"claim [label="say \"yes\"", shape=doublecircle];"#));
+        assert!(dot.contains("s0 -> s1;"));
+        assert!(dot.contains("s1 -> claim;"));
+    }
+    
+    #[test]
+    fn test_step_timing_is_not_hashed() {
+        let trace = TraceBuilder::new("claim")
+            .add_step("op1", "in", "out", vec!["axiom".to_string()])
+            .add_timed_step("op2", "in", vec!["axiom".to_string()], || {
+                std::thread::sleep(Duration::from_millis(5));
+                "out".to_string()
+            })
+            .add_step("op1", "in", "out", vec!["axiom".to_string()])
+            .build();
+        
+        assert!(trace.steps[1].duration_micros >= 5_000);
+        assert!(trace.total_duration_micros >= trace.steps[1].duration_micros);
+        
+        let metrics = trace.metrics();
+        assert_eq!(metrics.steps_by_operation["op1"], 2);
+        assert_eq!(metrics.steps_by_operation["op2"], 1);
+        assert_eq!(metrics.slowest_step.unwrap().operation, "op2");
+        
+        let mut retimed = trace.clone();
+        retimed.steps[0].duration_micros += 1_000;
+        retimed.total_duration_micros = 0;
+        retimed.finalize();
+        assert_eq!(retimed.receipt_hash, trace.receipt_hash);
+        assert!(retimed.verify_integrity());
+        
+        // Traces from before timing was recorded still load
+        let mut json = serde_json::to_value(&trace).unwrap();
+        json.as_object_mut().unwrap().remove("total_duration_micros");
+        json["steps"][0].as_object_mut().unwrap().remove("duration_micros");
+        let legacy: TraceEnvelope = serde_json::from_value(json).unwrap();
+        assert_eq!(legacy.steps[0].duration_micros, 0);
+        assert!(legacy.verify_integrity());
+    }
 }
 
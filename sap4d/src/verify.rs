@@ -0,0 +1,575 @@
+//! Receipt hash verification without the proof engine
+//!
+//! This is everything the `verify-only` feature builds: it needs serde,
+//! sha2, hex, ryu and unicode-normalization, but not chrono or serde_json,
+//! so it fits constrained WASM and embedded verifiers.
+//!
+//! [`Receipt`] mirrors [`crate::receipt::Receipt`] field for field, except
+//! that timestamps stay the RFC 3339 strings sap4d writes (UTC, ending in
+//! `Z`). The canonical JSON is written by hand, byte for byte what
+//! `serde_json` would write. This is the only implementation of the hash
+//! rules: the engine's receipts are hashed by reading them back as a
+//! [`Receipt`]. Deserialize a receipt with any serde format the target
+//! supports.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::Deserialize;
+use sha2::{Digest, Sha256};
+use std::collections::HashSet;
+use unicode_normalization::UnicodeNormalization;
+
+pub(crate) fn nfc(text: &str) -> String {
+    text.nfc().collect()
+}
+
+pub(crate) fn sorted_nfc(items: &[String]) -> Vec<String> {
+    let mut items: Vec<String> = items.iter().map(|item| nfc(item)).collect();
+    items.sort();
+    items
+}
+
+/// Commitment to `text`: `sha256:` and the hex SHA-256 of its NFC form
+pub fn commitment(text: &str) -> String {
+    format!("sha256:{}", hex::encode(Sha256::digest(nfc(text).as_bytes())))
+}
+
+/// Whether `text` has the form of a [`commitment`]
+pub(crate) fn is_commitment(text: &str) -> bool {
+    text.strip_prefix("sha256:")
+        .is_some_and(|digest| digest.len() == 64 && digest.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')))
+}
+
+/// `timestamp` as chrono's `DateTime::to_rfc3339` renders it (`+00:00`
+/// instead of `Z`); `None` unless it ends in `Z`
+pub fn to_rfc3339(timestamp: &str) -> Option<String> {
+    timestamp.strip_suffix('Z').map(|base| format!("{}+00:00", base))
+}
+
+/// `timestamp` with exactly millisecond precision, truncating as chrono's
+/// `SecondsFormat::Millis` does; `None` unless it ends in `Z`
+pub fn to_millis(timestamp: &str) -> Option<String> {
+    let base = timestamp.strip_suffix('Z')?;
+    let (seconds, fraction) = base.split_once('.').unwrap_or((base, ""));
+    if !fraction.bytes().all(|b| b.is_ascii_digit()) {
+        return None;
+    }
+    let millis: String = fraction.chars().chain(std::iter::repeat('0')).take(3).collect();
+    Some(format!("{}.{}Z", seconds, millis))
+}
+
+/// Source provenance of one evidence item
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Evidence {
+    pub statement: String,
+    #[serde(default)]
+    pub source_uri: Option<String>,
+    /// RFC 3339, as written by sap4d
+    #[serde(default)]
+    pub retrieved_at: Option<String>,
+    pub content_hash: String,
+    pub trust: f64,
+}
+
+/// An evidence file as recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct EvidenceFile {
+    pub path: String,
+    pub sha256: String,
+}
+
+/// How a receipt's evidence was normalized before chaining
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct EvidenceNormalization {
+    pub case_fold: bool,
+}
+
+/// A compound claim
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(untagged)]
+pub enum ClaimExpr {
+    Atom(String),
+    All { all: Vec<ClaimExpr> },
+    Any { any: Vec<ClaimExpr> },
+}
+
+/// Outcome for one atomic sub-claim
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct SubClaimOutcome {
+    pub claim: String,
+    /// `proven`, `failed` or `skipped`
+    pub status: String,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+/// Compound proof metadata recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct CompoundRecord {
+    pub expr: ClaimExpr,
+    pub outcomes: Vec<SubClaimOutcome>,
+    pub carried_by: Vec<String>,
+}
+
+/// One instantiation of a quantified claim and its sub-proof hash
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct InstanceProof {
+    pub value: String,
+    pub claim: String,
+    pub proof_hash: String,
+}
+
+/// Quantified proof metadata recorded in a receipt
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct QuantifiedRecord {
+    pub variable: String,
+    pub template: String,
+    pub instances: Vec<InstanceProof>,
+    pub aggregate_hash: String,
+}
+
+/// The hashed and signed parts of a receipt
+///
+/// Fields the hash does not cover beyond `redacted` (key ids,
+/// co-signatures) are not read.
+#[derive(Debug, Clone, Deserialize)]
+pub struct Receipt {
+    #[serde(default = "legacy_version")]
+    pub version: u32,
+    pub claim: String,
+    pub evidence: Vec<String>,
+    pub causal_chain: Vec<String>,
+    pub axioms: Vec<String>,
+    #[serde(rename = "C_zero")]
+    pub c_zero: bool,
+    pub hash: String,
+    pub signature: String,
+    /// RFC 3339, as written by sap4d
+    pub timestamp: String,
+    #[serde(default)]
+    pub compound: Option<CompoundRecord>,
+    #[serde(default)]
+    pub provenance: Vec<Evidence>,
+    #[serde(default)]
+    pub parents: Vec<String>,
+    #[serde(default)]
+    pub evidence_files: Vec<EvidenceFile>,
+    #[serde(default)]
+    pub normalization: Option<EvidenceNormalization>,
+    #[serde(default)]
+    pub redacted: Vec<usize>,
+    #[serde(default)]
+    pub quantified: Option<QuantifiedRecord>,
+}
+
+fn legacy_version() -> u32 {
+    1
+}
+
+impl Receipt {
+    /// Verify the receipt's hash integrity under its version's rules
+    pub fn verify_hash(&self) -> bool {
+        self.compute_hash().as_deref() == Some(self.hash.as_str())
+    }
+
+    /// Verify the receipt's signature
+    pub fn verify_signature(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        verify_fn(&self.hash, &self.signature)
+    }
+
+    /// Full verification (hash + signature)
+    pub fn verify(&self, verify_fn: impl FnOnce(&str, &str) -> bool) -> bool {
+        self.verify_hash() && self.verify_signature(verify_fn)
+    }
+
+    /// Hash under the rules of `self.version` (`None` for an unknown
+    /// version or a timestamp sap4d would not have written)
+    pub(crate) fn compute_hash(&self) -> Option<String> {
+        match self.version {
+            1 => self.hash_v1(),
+            2 => self.hash_canonical(2, self.evidence.clone(), self.provenance.clone(), self.causal_chain.clone()),
+            3 => self.hash_v3(),
+            _ => None,
+        }
+    }
+
+    /// v1: raw field bytes in source order
+    fn hash_v1(&self) -> Option<String> {
+        let mut hasher = Sha256::new();
+        hasher.update(self.claim.as_bytes());
+        for e in &self.evidence {
+            hasher.update(e.as_bytes());
+        }
+        for link in &self.causal_chain {
+            hasher.update(link.as_bytes());
+        }
+        for axiom in &self.axioms {
+            hasher.update(axiom.as_bytes());
+        }
+        hasher.update([self.c_zero as u8]);
+        hasher.update(to_rfc3339(&self.timestamp)?.as_bytes());
+        if let Some(compound) = &self.compound {
+            hasher.update(to_json(compound).as_bytes());
+        }
+        if !self.provenance.is_empty() {
+            hasher.update(to_json(self.provenance.as_slice()).as_bytes());
+        }
+        for parent in &self.parents {
+            hasher.update(parent.as_bytes());
+        }
+        for file in &self.evidence_files {
+            hasher.update(file.path.as_bytes());
+            hasher.update(file.sha256.as_bytes());
+        }
+        if let Some(quantified) = &self.quantified {
+            hasher.update(to_json(quantified).as_bytes());
+        }
+        if let Some(normalization) = &self.normalization {
+            hasher.update(to_json(normalization).as_bytes());
+        }
+        Some(hex::encode(hasher.finalize()))
+    }
+
+    /// v3: canonical JSON over per-item commitments
+    fn hash_v3(&self) -> Option<String> {
+        let mut withheld = HashSet::new();
+        for &index in &self.redacted {
+            let item = self.evidence.get(index)?;
+            if !is_commitment(item) {
+                return None;
+            }
+            withheld.insert(item.as_str());
+        }
+
+        let evidence = self
+            .evidence
+            .iter()
+            .enumerate()
+            .map(|(i, item)| if self.redacted.contains(&i) { item.clone() } else { commitment(item) })
+            .collect();
+        let provenance = self
+            .provenance
+            .iter()
+            .map(|e| Evidence {
+                statement: if withheld.contains(e.statement.as_str()) { e.statement.clone() } else { commitment(&e.statement) },
+                ..e.clone()
+            })
+            .collect();
+        let causal_chain = self
+            .causal_chain
+            .iter()
+            .map(|link| if is_commitment(link) { link.clone() } else { commitment(link) })
+            .collect();
+
+        self.hash_canonical(3, evidence, provenance, causal_chain)
+    }
+
+    /// Hash of the canonical document with the given item lists
+    fn hash_canonical(
+        &self,
+        version: u32,
+        evidence: Vec<String>,
+        provenance: Vec<Evidence>,
+        causal_chain: Vec<String>,
+    ) -> Option<String> {
+        let mut provenance: Vec<Evidence> = provenance
+            .into_iter()
+            .map(|e| Evidence { statement: nfc(&e.statement), ..e })
+            .collect();
+        provenance.sort_by(|a, b| {
+            (&a.statement, &a.source_uri, &a.content_hash).cmp(&(&b.statement, &b.source_uri, &b.content_hash))
+        });
+
+        let mut evidence_files = self.evidence_files.clone();
+        evidence_files.sort_by(|a, b| (&a.path, &a.sha256).cmp(&(&b.path, &b.sha256)));
+
+        let causal_chain: Vec<String> = causal_chain.iter().map(|link| nfc(link)).collect();
+        let parents = sorted_nfc(&self.parents);
+        let timestamp = to_millis(&self.timestamp)?;
+
+        // Keys in sorted order, as `CanonicalReceipt` declares them
+        let mut json = String::new();
+        Object::new(&mut json)
+            .field("axioms", sorted_nfc(&self.axioms).as_slice())
+            .field("c_zero", &self.c_zero)
+            .field("causal_chain", causal_chain.as_slice())
+            .field("claim", nfc(&self.claim).as_str())
+            .field_if(self.compound.is_some(), "compound", &self.compound)
+            .field("evidence", sorted_nfc(&evidence).as_slice())
+            .field_if(!evidence_files.is_empty(), "evidence_files", evidence_files.as_slice())
+            .field_if(self.normalization.is_some(), "normalization", &self.normalization)
+            .field_if(!parents.is_empty(), "parents", parents.as_slice())
+            .field_if(!provenance.is_empty(), "provenance", provenance.as_slice())
+            .field_if(self.quantified.is_some(), "quantified", &self.quantified)
+            .field("timestamp", timestamp.as_str())
+            .field("version", &version)
+            .end();
+
+        Some(hex::encode(Sha256::digest(json.as_bytes())))
+    }
+}
+
+/// Values written as compact JSON exactly as `serde_json::to_string` would
+trait ToJson {
+    fn write_json(&self, out: &mut String);
+}
+
+fn to_json(value: &(impl ToJson + ?Sized)) -> String {
+    let mut out = String::new();
+    value.write_json(&mut out);
+    out
+}
+
+impl ToJson for str {
+    fn write_json(&self, out: &mut String) {
+        out.push('"');
+        for c in self.chars() {
+            match c {
+                '"' => out.push_str("\\\""),
+                '\\' => out.push_str("\\\\"),
+                '\u{08}' => out.push_str("\\b"),
+                '\u{0c}' => out.push_str("\\f"),
+                '\n' => out.push_str("\\n"),
+                '\r' => out.push_str("\\r"),
+                '\t' => out.push_str("\\t"),
+                c if (c as u32) < 0x20 => out.push_str(&format!("\\u{:04x}", c as u32)),
+                c => out.push(c),
+            }
+        }
+        out.push('"');
+    }
+}
+
+impl ToJson for String {
+    fn write_json(&self, out: &mut String) {
+        self.as_str().write_json(out);
+    }
+}
+
+impl ToJson for bool {
+    fn write_json(&self, out: &mut String) {
+        out.push_str(if *self { "true" } else { "false" });
+    }
+}
+
+impl ToJson for u32 {
+    fn write_json(&self, out: &mut String) {
+        out.push_str(&self.to_string());
+    }
+}
+
+impl ToJson for f64 {
+    fn write_json(&self, out: &mut String) {
+        if self.is_finite() {
+            out.push_str(ryu::Buffer::new().format_finite(*self));
+        } else {
+            out.push_str("null");
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for Option<T> {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            Some(value) => value.write_json(out),
+            None => out.push_str("null"),
+        }
+    }
+}
+
+impl<T: ToJson> ToJson for [T] {
+    fn write_json(&self, out: &mut String) {
+        out.push('[');
+        for (i, item) in self.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            item.write_json(out);
+        }
+        out.push(']');
+    }
+}
+
+impl<T: ToJson> ToJson for Vec<T> {
+    fn write_json(&self, out: &mut String) {
+        self.as_slice().write_json(out);
+    }
+}
+
+impl ToJson for Evidence {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out)
+            .field("statement", &self.statement)
+            .field_if(self.source_uri.is_some(), "source_uri", &self.source_uri)
+            .field_if(self.retrieved_at.is_some(), "retrieved_at", &self.retrieved_at)
+            .field("content_hash", &self.content_hash)
+            .field("trust", &self.trust)
+            .end();
+    }
+}
+
+impl ToJson for EvidenceFile {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out).field("path", &self.path).field("sha256", &self.sha256).end();
+    }
+}
+
+impl ToJson for EvidenceNormalization {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out).field("case_fold", &self.case_fold).end();
+    }
+}
+
+impl ToJson for ClaimExpr {
+    fn write_json(&self, out: &mut String) {
+        match self {
+            ClaimExpr::Atom(claim) => claim.write_json(out),
+            ClaimExpr::All { all } => Object::new(out).field("all", all).end(),
+            ClaimExpr::Any { any } => Object::new(out).field("any", any).end(),
+        }
+    }
+}
+
+impl ToJson for SubClaimOutcome {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out)
+            .field("claim", &self.claim)
+            .field("status", &self.status)
+            .field_if(self.reason.is_some(), "reason", &self.reason)
+            .end();
+    }
+}
+
+impl ToJson for CompoundRecord {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out)
+            .field("expr", &self.expr)
+            .field("outcomes", &self.outcomes)
+            .field("carried_by", &self.carried_by)
+            .end();
+    }
+}
+
+impl ToJson for InstanceProof {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out)
+            .field("value", &self.value)
+            .field("claim", &self.claim)
+            .field("proof_hash", &self.proof_hash)
+            .end();
+    }
+}
+
+impl ToJson for QuantifiedRecord {
+    fn write_json(&self, out: &mut String) {
+        Object::new(out)
+            .field("variable", &self.variable)
+            .field("template", &self.template)
+            .field("instances", &self.instances)
+            .field("aggregate_hash", &self.aggregate_hash)
+            .end();
+    }
+}
+
+/// Writes one JSON object's fields in the order given
+struct Object<'a> {
+    out: &'a mut String,
+    empty: bool,
+}
+
+impl<'a> Object<'a> {
+    fn new(out: &'a mut String) -> Self {
+        out.push('{');
+        Self { out, empty: true }
+    }
+
+    fn field(mut self, key: &str, value: &(impl ToJson + ?Sized)) -> Self {
+        if !self.empty {
+            self.out.push(',');
+        }
+        self.empty = false;
+        key.write_json(self.out);
+        self.out.push(':');
+        value.write_json(self.out);
+        self
+    }
+
+    /// Write the field only when `present`, like `skip_serializing_if`
+    fn field_if(self, present: bool, key: &str, value: &(impl ToJson + ?Sized)) -> Self {
+        if present {
+            self.field(key, value)
+        } else {
+            self
+        }
+    }
+
+    fn end(self) {
+        self.out.push('}');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_timestamp_forms() {
+        assert_eq!(to_millis("2025-06-01T12:00:00Z").as_deref(), Some("2025-06-01T12:00:00.000Z"));
+        assert_eq!(to_millis("2025-06-01T12:00:00.123456Z").as_deref(), Some("2025-06-01T12:00:00.123Z"));
+        assert_eq!(to_millis("2025-06-01T12:00:00.5Z").as_deref(), Some("2025-06-01T12:00:00.500Z"));
+        assert_eq!(to_rfc3339("2025-06-01T12:00:00.250Z").as_deref(), Some("2025-06-01T12:00:00.250+00:00"));
+        assert_eq!(to_millis("2025-06-01T12:00:00+02:00"), None);
+    }
+
+    #[test]
+    fn test_string_escapes() {
+        assert_eq!(to_json("a\"b\\c\n\t\u{1f}\u{7f}é"), "\"a\\\"b\\\\c\\n\\t\\u001f\u{7f}é\"");
+        assert_eq!(to_json(&0.9f64), "0.9");
+        assert_eq!(to_json(&1.0f64), "1.0");
+    }
+
+    /// The hand-written JSON matches `serde_json` on every hashed type
+    #[cfg(feature = "engine")]
+    #[test]
+    fn test_json_matches_serde_json() {
+        use crate::claim::{ClaimExpr as FullExpr, CompoundRecord as FullRecord, SubClaimOutcome as FullOutcome, SubClaimStatus};
+        use chrono::{TimeZone, Utc};
+
+        let retrieved_at = Utc.timestamp_opt(1_748_779_170, 250_000_000).unwrap();
+        let full = vec![
+            crate::evidence::Evidence::new("Heat \"applied\"\tnow")
+                .with_source("file:///logs/kettle.log", retrieved_at)
+                .with_trust(0.85),
+            crate::evidence::Evidence::new("Temperature reached 100°C"),
+        ];
+        let lite: Vec<Evidence> = serde_json::from_str(&serde_json::to_string(&full).unwrap()).unwrap();
+        assert_eq!(to_json(lite.as_slice()), serde_json::to_string(&full).unwrap());
+
+        let record = FullRecord {
+            expr: FullExpr::All {
+                all: vec![FullExpr::Atom("Water boils".into()), FullExpr::Any { any: vec![FullExpr::Atom("Kettle on".into())] }],
+            },
+            outcomes: vec![
+                FullOutcome { claim: "Water boils".into(), status: SubClaimStatus::Proven, reason: None },
+                FullOutcome { claim: "Kettle on".into(), status: SubClaimStatus::Failed, reason: Some("no evidence".into()) },
+            ],
+            carried_by: vec!["Kettle on".into()],
+        };
+        let json = serde_json::to_string(&record).unwrap();
+        let lite: CompoundRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(to_json(&lite), json);
+
+        let quantified = crate::claim::QuantifiedRecord::new(
+            "d",
+            "Deployment {d} \"passed\"",
+            vec![crate::claim::InstanceProof {
+                value: "web-1".into(),
+                claim: "Deployment web-1 \"passed\"".into(),
+                proof_hash: "ab".repeat(32),
+            }],
+        );
+        let json = serde_json::to_string(&quantified).unwrap();
+        let lite: QuantifiedRecord = serde_json::from_str(&json).unwrap();
+        assert_eq!(to_json(&lite), json);
+    }
+}
@@ -0,0 +1,311 @@
+//! Re-proving a claim whenever its evidence file changes
+//!
+//! [`Watcher::run`] watches the directory holding the evidence file rather
+//! than the file itself: editors often save by writing a temporary file and
+//! renaming it over the original, which would orphan a watch on the old
+//! inode. Once changes have been quiet for the debounce period the claim is
+//! re-proved and the receipt is replaced atomically. A failed proof leaves
+//! the previous receipt in place, and a file that is briefly missing
+//! mid-save is waited for rather than treated as an error.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use notify::{EventKind, RecursiveMode, Watcher as _};
+use serde::Serialize;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc::{self, Receiver, RecvTimeoutError};
+use std::time::{Duration, Instant};
+
+use crate::engine::ProofEngine;
+use crate::evidence::file::{self, CsvColumns, EvidenceFileError, EvidenceFormat, LoadedEvidence};
+use crate::{ProofError, Receipt, Result};
+
+/// Quiet period after the last change before re-proving
+pub const DEFAULT_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// How long a missing evidence file is waited for before the run fails
+pub const MISSING_GRACE: Duration = Duration::from_secs(2);
+
+/// Timeout for publishing a receipt
+pub const PUBLISH_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// How often waits check the stop flag
+const STOP_POLL: Duration = Duration::from_millis(100);
+
+/// Watch configuration
+#[derive(Debug, Clone)]
+pub struct WatchConfig {
+    /// The claim re-proved on every change
+    pub claim: String,
+    /// Evidence file (format chosen by extension, as for `prove`)
+    pub evidence_file: PathBuf,
+    /// Receipt path, replaced after each successful proof
+    pub output: PathBuf,
+    /// Quiet period after the last change before re-proving
+    pub debounce: Duration,
+    /// URL each new receipt is POSTed to
+    pub publish: Option<String>,
+}
+
+impl WatchConfig {
+    /// Watch `evidence_file`, writing receipts for `claim` to `output`
+    pub fn new(claim: impl Into<String>, evidence_file: impl Into<PathBuf>, output: impl Into<PathBuf>) -> Self {
+        Self {
+            claim: claim.into(),
+            evidence_file: evidence_file.into(),
+            output: output.into(),
+            debounce: DEFAULT_DEBOUNCE,
+            publish: None,
+        }
+    }
+
+    /// Set the debounce period
+    pub fn with_debounce(mut self, debounce: Duration) -> Self {
+        self.debounce = debounce;
+        self
+    }
+
+    /// POST each new receipt to `url`
+    pub fn with_publish(mut self, url: impl Into<String>) -> Self {
+        self.publish = Some(url.into());
+        self
+    }
+}
+
+/// Outcome of one proof run
+#[derive(Debug, Clone, Serialize)]
+pub struct WatchRun {
+    /// Whether the claim was proved and the receipt rewritten
+    pub verified: bool,
+    /// Hash of the receipt written by this run
+    pub receipt_hash: Option<String>,
+    /// Why the run failed; the previous receipt was kept
+    pub error: Option<String>,
+    /// Time spent loading evidence, proving and writing the receipt
+    pub duration_ms: u64,
+    /// HTTP status returned when publishing
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub published: Option<u16>,
+    /// Why publishing failed
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub publish_error: Option<String>,
+}
+
+impl WatchRun {
+    /// One-line summary, e.g. `VERIFIED 3f2a9c1b0d4e 12ms`
+    pub fn status_line(&self) -> String {
+        let mut line = match (&self.receipt_hash, &self.error) {
+            (Some(hash), _) => format!("VERIFIED {} {}ms", &hash[..hash.len().min(12)], self.duration_ms),
+            (None, error) => format!(
+                "FAILED {}ms {} (previous receipt kept)",
+                self.duration_ms,
+                error.as_deref().unwrap_or("unknown error")
+            ),
+        };
+        if let Some(status) = self.published {
+            line.push_str(&format!(" published ({})", status));
+        }
+        if let Some(error) = &self.publish_error {
+            line.push_str(&format!(" publish failed: {}", error));
+        }
+        line
+    }
+}
+
+/// Re-proves a claim when its evidence file changes
+pub struct Watcher<F> {
+    config: WatchConfig,
+    engine: ProofEngine,
+    sign_fn: F,
+    key_id: Option<String>,
+}
+
+impl<F> Watcher<F>
+where
+    F: Fn(&str) -> String,
+{
+    /// Create a watcher signing receipts with `sign_fn`
+    pub fn new(config: WatchConfig, sign_fn: F) -> Self {
+        Self {
+            config,
+            engine: ProofEngine::new(),
+            sign_fn,
+            key_id: None,
+        }
+    }
+
+    /// Prove with `engine` instead of the default one
+    pub fn with_engine(mut self, engine: ProofEngine) -> Self {
+        self.engine = engine;
+        self
+    }
+
+    /// Record `key_id` as the key behind `sign_fn` in every receipt
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+
+    /// Prove once, replacing the receipt on success and publishing it if configured
+    pub fn prove_once(&self) -> WatchRun {
+        let started = Instant::now();
+        let proved = self.prove();
+        let duration_ms = started.elapsed().as_millis() as u64;
+
+        match proved {
+            Ok((receipt, json)) => {
+                let (published, publish_error) = match &self.config.publish {
+                    Some(url) => match publish(url, &json) {
+                        Ok(status) => (Some(status), None),
+                        Err(error) => (None, Some(error)),
+                    },
+                    None => (None, None),
+                };
+                WatchRun {
+                    verified: true,
+                    receipt_hash: Some(receipt.hash),
+                    error: None,
+                    duration_ms,
+                    published,
+                    publish_error,
+                }
+            }
+            Err(e) => WatchRun {
+                verified: false,
+                receipt_hash: None,
+                error: Some(e.to_string()),
+                duration_ms,
+                published: None,
+                publish_error: None,
+            },
+        }
+    }
+
+    /// Prove now, then again after each settled change, until `stop` is set
+    pub fn run(&self, stop: &AtomicBool, mut on_run: impl FnMut(&WatchRun)) -> Result<()> {
+        let (tx, rx) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(move |event| {
+            let _ = tx.send(event);
+        })
+        .map_err(watch_error)?;
+        watcher
+            .watch(&self.watched_dir(), RecursiveMode::NonRecursive)
+            .map_err(watch_error)?;
+
+        on_run(&self.prove_once());
+
+        while !stop.load(Ordering::SeqCst) {
+            match rx.recv_timeout(STOP_POLL) {
+                Ok(Ok(event)) if self.concerns(&event) => {
+                    if self.settle(&rx, stop) {
+                        on_run(&self.prove_once());
+                    }
+                }
+                Ok(Err(e)) => tracing::warn!(error = %e, "watch error"),
+                Ok(Ok(_)) | Err(RecvTimeoutError::Timeout) => {}
+                Err(RecvTimeoutError::Disconnected) => {
+                    return Err(ProofError::Internal("file watcher stopped".to_string()));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn prove(&self) -> Result<(Receipt, String)> {
+        let loaded = self.load_evidence()?;
+        let (_, mut receipt) = self.engine.prove_from_files(
+            &self.config.claim,
+            loaded.evidence,
+            vec![loaded.file],
+            |hash: &str| (self.sign_fn)(hash),
+        )?;
+        receipt.key_id = self.key_id.clone();
+        let json = receipt.to_json()?;
+        write_atomic(&self.config.output, &json)?;
+        Ok((receipt, json))
+    }
+
+    /// Load the evidence file, waiting out a save that briefly removed it
+    fn load_evidence(&self) -> Result<LoadedEvidence> {
+        let path = &self.config.evidence_file;
+        let format = EvidenceFormat::from_path(path, CsvColumns::default());
+        let deadline = Instant::now() + MISSING_GRACE;
+
+        loop {
+            match file::load(path, &format, false) {
+                Err(EvidenceFileError::Io { source, .. })
+                    if source.kind() == ErrorKind::NotFound && Instant::now() < deadline =>
+                {
+                    std::thread::sleep(STOP_POLL);
+                }
+                result => return result.map_err(|e| ProofError::InvalidEvidence(e.to_string())),
+            }
+        }
+    }
+
+    fn watched_dir(&self) -> PathBuf {
+        match self.config.evidence_file.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+            _ => PathBuf::from("."),
+        }
+    }
+
+    /// Whether `event` touched the evidence file (by name, since it may be replaced)
+    fn concerns(&self, event: &notify::Event) -> bool {
+        let name = self.config.evidence_file.file_name();
+        matches!(event.kind, EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_))
+            && event.paths.iter().any(|p| p.file_name() == name)
+    }
+
+    /// Wait until the evidence file has been quiet for the debounce period;
+    /// `false` if stopped first
+    fn settle(&self, rx: &Receiver<notify::Result<notify::Event>>, stop: &AtomicBool) -> bool {
+        let mut quiet_at = Instant::now() + self.config.debounce;
+        loop {
+            if stop.load(Ordering::SeqCst) {
+                return false;
+            }
+            let now = Instant::now();
+            if now >= quiet_at {
+                return true;
+            }
+            match rx.recv_timeout((quiet_at - now).min(STOP_POLL)) {
+                Ok(Ok(event)) if self.concerns(&event) => quiet_at = Instant::now() + self.config.debounce,
+                Err(RecvTimeoutError::Disconnected) => return true,
+                _ => {}
+            }
+        }
+    }
+}
+
+/// Write `contents` to a sibling temporary file and rename it over `path`
+fn write_atomic(path: &Path, contents: &str) -> Result<()> {
+    let mut tmp = path.as_os_str().to_owned();
+    tmp.push(".tmp");
+    fs::write(&tmp, contents).map_err(|e| io_error(path, e))?;
+    fs::rename(&tmp, path).map_err(|e| io_error(path, e))
+}
+
+/// POST a receipt, returning the HTTP status
+fn publish(url: &str, receipt_json: &str) -> std::result::Result<u16, String> {
+    match ureq::post(url)
+        .timeout(PUBLISH_TIMEOUT)
+        .set("Content-Type", "application/json")
+        .send_string(receipt_json)
+    {
+        Ok(response) => Ok(response.status()),
+        Err(ureq::Error::Status(status, _)) => Err(format!("HTTP {}", status)),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+fn io_error(path: &Path, err: std::io::Error) -> ProofError {
+    ProofError::Internal(format!("I/O error: {}: {}", path.display(), err))
+}
+
+fn watch_error(err: notify::Error) -> ProofError {
+    ProofError::Internal(format!("watch error: {}", err))
+}
@@ -0,0 +1,857 @@
+//! CLI exit-code and output-format contract tests
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use sha2::{Digest, Sha256};
+use std::fs;
+use std::path::PathBuf;
+
+const CLAIM: &str = "Water boils";
+const EVIDENCE: [&str; 2] = ["Heat applied", "Temperature reached 100C"];
+
+const SIGNING_KEY: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/signing.key");
+
+fn cli() -> Command {
+    let mut cmd = Command::cargo_bin("sap4d-cli").unwrap();
+    cmd.env("SAP4D_SIGNING_KEY_FILE", SIGNING_KEY);
+    cmd
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sap4d-cli-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+/// Parse stdout as one envelope for `command` and check it against the schema
+fn stdout_json(output: &std::process::Output, command: &str) -> serde_json::Value {
+    let value: serde_json::Value =
+        serde_json::from_slice(&output.stdout).expect("stdout is a single JSON document");
+    assert_envelope(&value, command);
+    value
+}
+
+fn assert_envelope(value: &serde_json::Value, command: &str) {
+    let object = value.as_object().expect("envelope is an object");
+    let mut keys: Vec<_> = object.keys().map(String::as_str).collect();
+    keys.sort_unstable();
+    assert_eq!(keys, ["command", "data", "error", "schema_version", "status"]);
+
+    assert_eq!(value["schema_version"], 1);
+    assert_eq!(value["command"], command);
+    assert!(value["status"].is_string());
+
+    let error = &value["error"];
+    if !error.is_null() {
+        let code = error["code"].as_str().expect("error.code is a string");
+        assert!(!code.is_empty() && code.chars().all(|c| c.is_ascii_uppercase() || c == '_'));
+        assert!(error["message"].is_string());
+    }
+}
+
+fn prove_args() -> Vec<String> {
+    let mut args = vec!["prove".to_string(), CLAIM.to_string()];
+    for e in EVIDENCE {
+        args.push("-e".to_string());
+        args.push(e.to_string());
+    }
+    args
+}
+
+/// Prove a claim and write its receipt into `dir`
+fn write_receipt(dir: &std::path::Path) -> PathBuf {
+    let path = dir.join("receipt.json");
+    cli()
+        .args(prove_args())
+        .args(["--quiet", "-o", path.to_str().unwrap()])
+        .assert()
+        .code(0);
+    path
+}
+
+#[test]
+fn test_prove_verified_in_every_format() {
+    let output = cli().args(prove_args()).args(["--output-format", "json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+    assert_eq!(stdout_json(&output, "prove")["status"], "VERIFIED");
+
+    let output = cli().args(prove_args()).arg("--json").output().unwrap();
+    assert_eq!(stdout_json(&output, "prove")["status"], "VERIFIED");
+
+    cli()
+        .args(prove_args())
+        .args(["--output-format", "yaml"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("status: VERIFIED"))
+        .stderr(predicate::str::is_empty());
+
+    cli()
+        .args(prove_args())
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("Claim verified"))
+        .stdout(predicate::str::contains("Slowest step").not());
+
+    cli()
+        .args(prove_args())
+        .arg("--verbose")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("Total time: "))
+        .stdout(predicate::str::contains("Slowest step: "));
+
+    cli()
+        .args(prove_args())
+        .arg("--quiet")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::is_empty())
+        .stderr(predicate::str::is_empty());
+}
+
+#[test]
+fn test_prove_failed_proof_vs_missing_file() {
+    let failed = [
+        "prove", "Conclusion", "-e", "   ", "--output-format", "json",
+    ];
+    let output = cli().args(failed).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert!(output.stderr.is_empty());
+    assert_eq!(stdout_json(&output, "prove")["status"], "FAILED");
+
+    let missing = [
+        "prove", CLAIM, "-f", "/nonexistent/evidence.txt", "--output-format", "json",
+    ];
+    let output = cli().args(missing).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert!(output.stderr.is_empty());
+    assert_eq!(stdout_json(&output, "prove")["status"], "INVALID_INPUT");
+
+    cli()
+        .args(["prove", CLAIM, "-f", "/nonexistent/evidence.txt", "--quiet"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::is_empty());
+
+    cli()
+        .args(["prove", "   ", "-e", "x", "--output-format", "yaml"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("status: INVALID_INPUT"));
+}
+
+#[test]
+fn test_compact_json_is_single_line() {
+    let output = cli().args(prove_args()).args(["--output-format", "compact-json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+
+    let stdout = String::from_utf8(output.stdout.clone()).unwrap();
+    assert_eq!(stdout.trim_end().lines().count(), 1);
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["status"], "VERIFIED");
+    assert!(value["error"].is_null());
+    assert_eq!(value["data"]["receipt"]["claim"], CLAIM);
+}
+
+#[test]
+fn test_error_codes_report_unsupported_claim() {
+    // No evidence at all
+    let output = cli()
+        .args(["prove", "Unsupported claim", "--json"])
+        .write_stdin("")
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["status"], "FAILED");
+    assert_eq!(value["error"]["code"], "UNSUPPORTED_CLAIM");
+
+    let output = cli().args(["check", "Unsupported claim", "--json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout_json(&output, "check")["error"]["code"], "UNSUPPORTED_CLAIM");
+
+    cli()
+        .args(["prove", "Unsupported claim", "--output-format", "yaml"])
+        .write_stdin("")
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("code: UNSUPPORTED_CLAIM"));
+}
+
+#[test]
+fn test_prove_reports_evidence_shortfall() {
+    let mut args = prove_args();
+    args.extend(["--min-evidence", "3", "--json"].map(String::from));
+    let output = cli().args(&args).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["error"]["code"], "INSUFFICIENT_EVIDENCE");
+    assert_eq!(value["data"]["required"], 3);
+    assert_eq!(value["data"]["provided"], 2);
+
+    let mut args = prove_args();
+    args.extend(["--min-evidence", "3"].map(String::from));
+    cli()
+        .args(&args)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("Shortfall: 1 more evidence item(s) needed (2 of 3)"));
+
+    // Bare evidence has no sources
+    let mut args = prove_args();
+    args.extend(["--min-sources", "1", "--json"].map(String::from));
+    let output = cli().args(&args).output().unwrap();
+    assert_eq!(stdout_json(&output, "prove")["error"]["code"], "INSUFFICIENT_SOURCES");
+
+    let mut args = prove_args();
+    args.extend(["--min-evidence", "2"].map(String::from));
+    cli().args(&args).assert().code(0);
+}
+
+/// Answer one HTTP request with `body`, returning the request received
+fn stub_portal(body: &'static str) -> (String, std::thread::JoinHandle<String>) {
+    use std::io::{BufRead, BufReader, Read, Write};
+
+    let listener = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+    let url = format!("http://{}", listener.local_addr().unwrap());
+    let handle = std::thread::spawn(move || {
+        let (stream, _) = listener.accept().unwrap();
+        let mut reader = BufReader::new(stream);
+        let mut request = String::new();
+        let mut length = 0;
+        loop {
+            let mut line = String::new();
+            reader.read_line(&mut line).unwrap();
+            if let Some(value) = line.to_ascii_lowercase().strip_prefix("content-length:") {
+                length = value.trim().parse().unwrap();
+            }
+            request.push_str(&line);
+            if line == "\r\n" {
+                break;
+            }
+        }
+        let mut content = vec![0; length];
+        reader.read_exact(&mut content).unwrap();
+        request.push_str(&String::from_utf8(content).unwrap());
+
+        let response = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+            body.len(),
+            body
+        );
+        reader.get_mut().write_all(response.as_bytes()).unwrap();
+        request
+    });
+    (url, handle)
+}
+
+#[test]
+fn test_prove_publishes_to_portal() {
+    let (url, portal) = stub_portal(
+        r#"{"C_zero": true, "hash": "feedface", "signature": "sig", "timestamp": "2025-06-01T12:00:00+00:00"}"#,
+    );
+    let mut args = prove_args();
+    args.extend(["--publish".to_string(), url, "--json".to_string()]);
+    let output = cli().args(&args).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["data"]["portal"]["C_zero"], true);
+    assert_eq!(value["data"]["portal"]["hash"], "feedface");
+
+    let request = portal.join().unwrap();
+    assert!(request.starts_with("POST /verify "));
+    assert!(request.contains(CLAIM) && EVIDENCE.iter().all(|e| request.contains(e)));
+
+    // A bad URL is the caller's mistake; an unreachable portal is not
+    let mut args = prove_args();
+    args.extend(["--publish", "localhost:3000"].map(String::from));
+    cli().args(&args).assert().code(2);
+
+    let mut args = prove_args();
+    args.extend(["--publish", "http://127.0.0.1:9"].map(String::from));
+    cli().args(&args).assert().code(3);
+}
+
+#[test]
+fn test_check_codes_and_formats() {
+    let mut supported = vec!["check".to_string(), CLAIM.to_string()];
+    for e in EVIDENCE {
+        supported.push("-e".to_string());
+        supported.push(e.to_string());
+    }
+
+    let output = cli().args(&supported).args(["--output-format", "json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout_json(&output, "check")["data"]["supported"], true);
+
+    cli()
+        .args(&supported)
+        .args(["--output-format", "plain"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("SUPPORTED"));
+
+    let output = cli()
+        .args(["check", "Conclusion", "-e", "   ", "--output-format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "check");
+    assert_eq!(value["status"], "NOT_SUPPORTED");
+    assert_eq!(value["data"]["supported"], false);
+
+    cli()
+        .args(["check", "", "-e", "x", "--quiet"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::is_empty());
+
+    cli()
+        .args(["check", CLAIM, "-e", "x", "--axioms-file", "/nonexistent/pack.toml", "--json"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("INVALID_INPUT"));
+}
+
+#[test]
+fn test_verify_codes_and_formats() {
+    let dir = scratch_dir("verify");
+    let receipt = write_receipt(&dir);
+    let receipt_arg = receipt.to_str().unwrap();
+
+    for deep in [false, true] {
+        let mut cmd = cli();
+        cmd.args(["verify", receipt_arg, "--output-format", "json"]);
+        if deep {
+            cmd.arg("--deep");
+        }
+        let output = cmd.output().unwrap();
+        assert_eq!(output.status.code(), Some(0));
+        assert_eq!(stdout_json(&output, "verify")["status"], "VALID");
+    }
+
+    cli()
+        .args(["verify", receipt_arg, "--output-format", "yaml"])
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("status: VALID"));
+
+    // Tampered claim: parses, but does not verify
+    let mut value: serde_json::Value = serde_json::from_str(&fs::read_to_string(&receipt).unwrap()).unwrap();
+    value["claim"] = serde_json::json!("Water freezes");
+    let tampered = dir.join("tampered.json");
+    fs::write(&tampered, serde_json::to_string(&value).unwrap()).unwrap();
+
+    let output = cli()
+        .args(["verify", tampered.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout_json(&output, "verify")["status"], "INVALID");
+
+    // Malformed JSON and missing file are input errors
+    let garbage = dir.join("garbage.json");
+    fs::write(&garbage, "{ not json").unwrap();
+    for path in [garbage.to_str().unwrap(), "/nonexistent/receipt.json"] {
+        let output = cli().args(["verify", path, "--json"]).output().unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        assert!(output.stderr.is_empty());
+        let value = stdout_json(&output, "verify");
+        assert_eq!(value["status"], "INVALID_INPUT");
+        assert_eq!(value["error"]["code"], "INVALID_INPUT");
+
+        cli()
+            .args(["verify", path, "--deep", "--quiet"])
+            .assert()
+            .code(2)
+            .stdout(predicate::str::is_empty());
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_receipts_are_signed_under_the_configured_key() {
+    let dir = scratch_dir("signing");
+    let receipt = write_receipt(&dir);
+    let receipt_arg = receipt.to_str().unwrap();
+
+    // The key id is the key's fingerprint, and the signature its HMAC
+    let value: serde_json::Value = serde_json::from_str(&fs::read_to_string(&receipt).unwrap()).unwrap();
+    let fingerprint = hex::encode(Sha256::digest(b"sap4d-cli test signing key"));
+    assert_eq!(value["key_id"], format!("sha256:{}", &fingerprint[..16]));
+
+    // Another key does not verify it
+    let other_key = dir.join("other.key");
+    fs::write(&other_key, "another key").unwrap();
+    let output = cli()
+        .args(["verify", receipt_arg, "--json"])
+        .env("SAP4D_SIGNING_KEY_FILE", &other_key)
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout_json(&output, "verify")["status"], "INVALID");
+
+    // Without a key nothing is signed or verified
+    for (command, args) in [("prove", prove_args()), ("verify", vec!["verify".to_string(), receipt_arg.to_string()])] {
+        let output = cli().args(args).arg("--json").env_remove("SAP4D_SIGNING_KEY_FILE").output().unwrap();
+        assert_eq!(output.status.code(), Some(2));
+        let value = stdout_json(&output, command);
+        assert!(value["error"]["message"].as_str().unwrap().contains("SAP4D_SIGNING_KEY_FILE"));
+    }
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_axioms_and_info_formats() {
+    for subcommand in ["axioms", "info"] {
+        let output = cli().args([subcommand, "--output-format", "json"]).output().unwrap();
+        assert_eq!(output.status.code(), Some(0));
+        let value = stdout_json(&output, subcommand);
+        assert_eq!(value["status"], "OK");
+        assert!(value["error"].is_null());
+
+        cli()
+            .args([subcommand, "--output-format", "yaml"])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::is_empty().not());
+
+        cli()
+            .args([subcommand, "--quiet"])
+            .assert()
+            .code(0)
+            .stdout(predicate::str::is_empty());
+    }
+
+    cli()
+        .args(["axioms", "--axioms-file", "/nonexistent/pack.toml", "--output-format", "yaml"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::contains("status: INVALID_INPUT"));
+}
+
+#[test]
+fn test_daemon_once_formats() {
+    let dir = scratch_dir("daemon");
+    fs::create_dir_all(dir.join("jobs")).unwrap();
+    fs::write(
+        dir.join("jobs").join("a.json"),
+        r#"{"claim": "Ground is wet", "evidence": ["It rained"]}"#,
+    )
+    .unwrap();
+
+    let args = |format: &str| {
+        vec![
+            "daemon".to_string(),
+            "--watch-dir".to_string(), dir.join("jobs").display().to_string(),
+            "--output".to_string(), dir.join("receipts").display().to_string(),
+            "--log".to_string(), dir.join("audit.log").display().to_string(),
+            "--once".to_string(),
+            "--output-format".to_string(), format.to_string(),
+        ]
+    };
+
+    let output = cli().args(args("json")).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert!(output.stderr.is_empty());
+    assert_eq!(stdout_json(&output, "daemon")["data"]["processed"], 1);
+
+    cli()
+        .args(args("yaml"))
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("processed: 1"));
+
+    cli()
+        .args(args("plain"))
+        .arg("--quiet")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_watch_once_codes() {
+    let dir = scratch_dir("watch");
+    let facts = dir.join("facts.txt");
+    let receipt = dir.join("receipt.json");
+    fs::write(&facts, EVIDENCE.join("\n")).unwrap();
+
+    let args = [
+        "watch".to_string(),
+        "--claim".to_string(), CLAIM.to_string(),
+        "--evidence-file".to_string(), facts.display().to_string(),
+        "--output".to_string(), receipt.display().to_string(),
+        "--once".to_string(),
+    ];
+
+    let output = cli().args(&args).arg("--json").output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let value = stdout_json(&output, "watch");
+    assert_eq!(value["status"], "VERIFIED");
+    let written = fs::read_to_string(&receipt).unwrap();
+    assert!(written.contains(value["data"]["receipt_hash"].as_str().unwrap()));
+
+    // A failed proof exits 1 and keeps the receipt
+    fs::write(&facts, "\n").unwrap();
+    cli()
+        .args(&args)
+        .assert()
+        .code(1)
+        .stdout(predicate::str::starts_with("FAILED "));
+    assert_eq!(fs::read_to_string(&receipt).unwrap(), written);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_bulk_check_threshold_and_details() {
+    let dir = scratch_dir("bulk");
+    fs::write(dir.join("facts.txt"), EVIDENCE.join("\n")).unwrap();
+    let manifest = dir.join("claims.json");
+    fs::write(
+        &manifest,
+        serde_json::json!({"claims": [
+            {"id": "file", "claim": CLAIM, "evidence": [{"file": "facts.txt"}]},
+            {"id": "inline", "claim": CLAIM, "evidence": EVIDENCE.iter().map(|e| serde_json::json!({"inline": e})).collect::<Vec<_>>()},
+            {"id": "unsupported", "claim": CLAIM, "evidence": []},
+            {"id": "missing", "claim": CLAIM, "evidence": [{"file": "missing.txt"}]},
+        ]})
+        .to_string(),
+    )
+    .unwrap();
+    let details = dir.join("details.ndjson");
+
+    let args = |threshold: &str| {
+        vec![
+            "bulk-check".to_string(),
+            "--manifest".to_string(), manifest.display().to_string(),
+            "--details".to_string(), details.display().to_string(),
+            "--fail-threshold".to_string(), threshold.to_string(),
+            "--json".to_string(),
+        ]
+    };
+
+    let output = cli().args(args("0.99")).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "bulk-check");
+    assert_eq!(value["status"], "BELOW_THRESHOLD");
+    assert_eq!(value["data"]["total"], 4);
+    assert_eq!(value["data"]["verified"], 2);
+    assert_eq!(value["data"]["failures_by_code"]["INVALID_EVIDENCE"], 1);
+
+    let lines: Vec<serde_json::Value> = fs::read_to_string(&details)
+        .unwrap()
+        .lines()
+        .map(|l| serde_json::from_str(l).unwrap())
+        .collect();
+    let ids: Vec<&str> = lines.iter().map(|l| l["id"].as_str().unwrap()).collect();
+    assert_eq!(ids, ["file", "inline", "unsupported", "missing"]);
+    assert_eq!(lines[0]["verified"], true);
+    assert_eq!(lines[2]["code"], "UNSUPPORTED_CLAIM");
+
+    let output = cli().args(args("0.5")).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout_json(&output, "bulk-check")["status"], "PASSED");
+
+    let output = cli().args(args("1.5")).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    cli()
+        .args(["bulk-check", "--manifest", "/nonexistent/claims.json"])
+        .assert()
+        .code(2);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_graph_from_receipt() {
+    let dir = scratch_dir("graph");
+    let receipt = write_receipt(&dir);
+    let dot_path = dir.join("chain.dot");
+
+    let output = cli()
+        .args(["graph", receipt.to_str().unwrap(), "-o", dot_path.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout_json(&output, "graph")["data"]["kind"], "receipt");
+
+    let dot = fs::read_to_string(&dot_path).unwrap();
+    assert!(dot.starts_with("digraph causal_chain {"));
+    assert!(dot.contains(r#"[label="Water boils", shape=doublecircle]"#));
+    assert!(dot.contains(r#"[label="Heat applied", shape=box]"#));
+
+    cli()
+        .args(["graph", "/nonexistent/receipt.json", "--quiet"])
+        .assert()
+        .code(2)
+        .stdout(predicate::str::is_empty());
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_diff_separates_content_from_tampering() {
+    let dir = scratch_dir("diff");
+    let path = write_receipt(&dir);
+
+    let output = cli()
+        .args(["diff", path.to_str().unwrap(), path.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    assert_eq!(stdout_json(&output, "diff")["status"], "IDENTICAL");
+
+    // Edit a field without resealing
+    let mut receipt: serde_json::Value = serde_json::from_str(&fs::read_to_string(&path).unwrap()).unwrap();
+    receipt["evidence"][1] = serde_json::json!("Temperature reached 90C");
+    let tampered = dir.join("tampered.json");
+    fs::write(&tampered, receipt.to_string()).unwrap();
+
+    let output = cli()
+        .args(["diff", path.to_str().unwrap(), tampered.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "diff");
+    assert_eq!(value["status"], "TAMPERED");
+    assert_eq!(value["data"]["evidence"]["added"][0], "Temperature reached 90C");
+    assert_eq!(value["data"]["hash_check"]["b_valid"], false);
+
+    cli()
+        .args(["diff", path.to_str().unwrap(), tampered.to_str().unwrap()])
+        .assert()
+        .code(1)
+        .stdout(predicate::str::contains("+ Temperature reached 90C"))
+        .stdout(predicate::str::contains("Hash of b recomputes: no"));
+
+    cli()
+        .args(["diff", path.to_str().unwrap(), "/nonexistent/receipt.json"])
+        .assert()
+        .code(2);
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_prove_reads_json_and_csv_evidence_files() {
+    let dir = scratch_dir("evidence-files");
+    let json = dir.join("evidence.json");
+    fs::write(
+        &json,
+        r#"["Heat applied", {"statement": "Temperature reached 100C", "source": "sensor:1", "trust": 0.9}]"#,
+    )
+    .unwrap();
+
+    let output = cli()
+        .args(["prove", CLAIM, "-f", json.to_str().unwrap(), "--json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let receipt = &stdout_json(&output, "prove")["data"]["receipt"];
+    assert_eq!(receipt["evidence"].as_array().unwrap().len(), 2);
+    assert_eq!(receipt["provenance"][1]["source_uri"], "sensor:1");
+    let file = &receipt["evidence_files"][0];
+    assert_eq!(file["path"], json.to_str().unwrap());
+    assert_eq!(file["sha256"], hex::encode(Sha256::digest(fs::read(&json).unwrap())));
+
+    let csv = dir.join("evidence.csv");
+    fs::write(&csv, "text,origin\nHeat applied,lab\n,lab\nTemperature reached 100C,\n").unwrap();
+    let csv_args = ["prove", CLAIM, "-f", csv.to_str().unwrap(), "--csv-column", "text", "--csv-source-column", "origin"];
+
+    let output = cli().args(csv_args).arg("--json").output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    let error = &stdout_json(&output, "prove")["error"]["message"];
+    assert!(error.as_str().unwrap().contains("line 3: statement is empty"), "{}", error);
+
+    let output = cli().args(csv_args).args(["--lenient", "--json"]).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["data"]["skipped_rows"][0]["line"], 3);
+    assert_eq!(value["data"]["receipt"]["evidence"].as_array().unwrap().len(), 2);
+
+    cli()
+        .args(csv_args)
+        .arg("--lenient")
+        .assert()
+        .code(0)
+        .stdout(predicate::str::contains("Skipped evidence line 3"));
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_prove_quantified_claim_file() {
+    let dir = scratch_dir("claim-file");
+    let claim = dir.join("claim.json");
+    fs::write(
+        &claim,
+        r#"{"variable": "d", "domain_values": ["web-1", "web-2", "web-3"], "template": "Deployment {d} passed checks"}"#,
+    )
+    .unwrap();
+    let args = |evidence: &[&str]| {
+        let mut args = vec!["prove".to_string(), "--claim-file".to_string(), claim.to_str().unwrap().to_string()];
+        for e in evidence {
+            args.push("-e".to_string());
+            args.push(e.to_string());
+        }
+        args.push("--json".to_string());
+        args
+    };
+
+    let all = ["Deployment web-1 passed checks", "Deployment web-2 passed checks", "Deployment web-3 passed checks"];
+    let output = cli().args(args(&all)).output().unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let record = &stdout_json(&output, "prove")["data"]["receipt"]["quantified"];
+    assert_eq!(record["instances"].as_array().unwrap().len(), 3);
+    assert_eq!(record["aggregate_hash"].as_str().unwrap().len(), 64);
+
+    let output = cli().args(args(&all[1..2])).output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "prove");
+    assert_eq!(value["error"]["code"], "INSTANTIATION_FAILED");
+    assert_eq!(value["data"]["failures"].as_array().unwrap().len(), 1);
+
+    let output = cli().args(args(&all[1..2])).arg("--exhaustive").output().unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    assert_eq!(stdout_json(&output, "prove")["data"]["failures"].as_array().unwrap().len(), 2);
+
+    fs::write(&claim, r#"{"variable": "d", "domain_values": [], "template": "Deployment {d} passed checks"}"#).unwrap();
+    let output = cli().args(args(&all)).output().unwrap();
+    assert_eq!(output.status.code(), Some(2));
+    assert_eq!(stdout_json(&output, "prove")["status"], "INVALID_INPUT");
+
+    fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn test_redact_keeps_receipt_verifiable() {
+    let dir = scratch_dir("redact");
+    let receipt = write_receipt(&dir);
+    let redacted = dir.join("redacted.json");
+
+    let output = cli()
+        .args(["redact", receipt.to_str().unwrap(), "--evidence", "0,1", "-o", redacted.to_str().unwrap()])
+        .args(["--output-format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let value = stdout_json(&output, "redact");
+    assert_eq!(value["status"], "REDACTED");
+    assert_eq!(value["data"]["redacted"], serde_json::json!([0, 1]));
+
+    let contents = fs::read_to_string(&redacted).unwrap();
+    for e in EVIDENCE {
+        assert!(!contents.contains(e));
+    }
+    cli().args(["verify", redacted.to_str().unwrap(), "--quiet"]).assert().code(0);
+
+    cli()
+        .args(["redact", receipt.to_str().unwrap(), "--evidence", "5", "--quiet"])
+        .assert()
+        .code(2);
+
+    let tampered = fs::read_to_string(&receipt).unwrap().replace(CLAIM, "Water freezes");
+    fs::write(&receipt, tampered).unwrap();
+    cli()
+        .args(["redact", receipt.to_str().unwrap(), "--evidence", "0", "--quiet"])
+        .assert()
+        .code(1);
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_yaml_receipts_by_extension() {
+    let dir = scratch_dir("yaml");
+    let yaml = dir.join("receipt.yaml");
+    cli()
+        .args(prove_args())
+        .args(["--quiet", "--with-trace", "-o", yaml.to_str().unwrap()])
+        .assert()
+        .code(0);
+    let contents = fs::read_to_string(&yaml).unwrap();
+    let document: serde_json::Value = serde_yaml::from_str(&contents).unwrap();
+    assert!(document["version"].is_u64());
+    assert_eq!(document["claim"], CLAIM);
+    assert!(document["trace"].is_object());
+
+    let modes: [&[&str]; 3] = [&[], &["--deep"], &["--replay"]];
+    for args in modes {
+        cli().arg("verify").arg(&yaml).args(args).arg("--quiet").assert().code(0);
+    }
+
+    // Any other extension is read as YAML unless it holds a JSON object
+    let renamed = dir.join("receipt.txt");
+    fs::write(&renamed, &contents).unwrap();
+    cli().arg("verify").arg(&renamed).arg("--quiet").assert().code(0);
+
+    let tampered = dir.join("tampered.yml");
+    fs::write(&tampered, contents.replace(CLAIM, "Water freezes")).unwrap();
+    cli().arg("verify").arg(&tampered).arg("--quiet").assert().code(1);
+
+    // Redacting a JSON receipt into YAML keeps it verifiable
+    let json = write_receipt(&dir);
+    let redacted = dir.join("redacted.yml");
+    cli()
+        .args(["redact", json.to_str().unwrap(), "-e", "0", "-o", redacted.to_str().unwrap(), "--quiet"])
+        .assert()
+        .code(0);
+    cli().arg("verify").arg(&redacted).arg("--quiet").assert().code(0);
+
+    let _ = fs::remove_dir_all(&dir);
+}
+
+#[test]
+fn test_verify_replay_catches_fabricated_trace() {
+    let dir = scratch_dir("replay");
+    let receipt = dir.join("receipt.json");
+    cli()
+        .args(prove_args())
+        .args(["--quiet", "--with-trace", "-o", receipt.to_str().unwrap()])
+        .assert()
+        .code(0);
+
+    let output = cli()
+        .args(["verify", "--replay", receipt.to_str().unwrap(), "--output-format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(0));
+    let value = stdout_json(&output, "verify");
+    assert_eq!(value["status"], "VALID");
+    assert_eq!(value["data"]["replay"]["matched"], 6);
+
+    // Rewrite the contradiction step with consistent hashes but wrong logic
+    let mut contents: serde_json::Value = serde_json::from_str(&fs::read_to_string(&receipt).unwrap()).unwrap();
+    let mut trace: sap4d::TraceEnvelope = serde_json::from_value(contents["trace"].take()).unwrap();
+    let axioms = trace.steps[3].axioms_applied.clone();
+    trace.steps[3] = sap4d::TraceStep::new(3, "check_contradictions", "C = 1", "C = 1 (PASS)", axioms);
+    trace.finalize();
+    assert!(trace.verify_integrity());
+    contents["trace"] = serde_json::to_value(&trace).unwrap();
+    fs::write(&receipt, contents.to_string()).unwrap();
+
+    let output = cli()
+        .args(["verify", "--replay", receipt.to_str().unwrap(), "--output-format", "json"])
+        .output()
+        .unwrap();
+    assert_eq!(output.status.code(), Some(1));
+    let value = stdout_json(&output, "verify");
+    assert_eq!(value["status"], "INVALID");
+    assert_eq!(value["data"]["replay"]["steps"][3]["status"], "failed");
+    cli().args(["verify", receipt.to_str().unwrap(), "--quiet"]).assert().code(0);
+
+    // Receipts without a trace cannot be replayed
+    let bare = write_receipt(&dir);
+    cli().args(["verify", "--replay", bare.to_str().unwrap(), "--quiet"]).assert().code(2);
+    let _ = fs::remove_dir_all(&dir);
+}
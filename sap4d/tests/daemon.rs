@@ -0,0 +1,105 @@
+//! Daemon integration tests
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::daemon::{Daemon, DaemonConfig, JobOutcome, ReceiptLog, DONE_DIR, FAILED_DIR, STATUS_FILE};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+fn test_sign(hash: &str) -> String {
+    format!("TEST_SIG:{}", hash)
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sap4d-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("jobs")).unwrap();
+    dir
+}
+
+fn config(root: &Path) -> DaemonConfig {
+    DaemonConfig {
+        watch_dir: root.join("jobs"),
+        output_dir: root.join("receipts"),
+        log_path: root.join("audit.log"),
+        concurrency: 2,
+        poll_interval: Duration::from_millis(10),
+    }
+}
+
+#[test]
+fn test_daemon_processes_jobs_and_restarts_idempotently() {
+    let root = scratch_dir("daemon");
+    let jobs = root.join("jobs");
+
+    let job_a = r#"{"claim": "Water boils", "evidence": ["Heat applied", "Temperature reached 100C"]}"#;
+    let job_b = r#"{"claim": "Ground is wet", "evidence": ["It rained"]}"#;
+    fs::write(jobs.join("a.json"), job_a).unwrap();
+    fs::write(jobs.join("b.json"), job_b).unwrap();
+    fs::write(jobs.join("c.json"), "{ not valid json").unwrap();
+
+    let mut daemon = Daemon::new(config(&root), test_sign).unwrap();
+    let outcomes = daemon.run_once().unwrap();
+
+    let completed = outcomes.iter().filter(|o| matches!(o, JobOutcome::Completed { .. })).count();
+    let failed = outcomes.iter().filter(|o| matches!(o, JobOutcome::Failed { .. })).count();
+    assert_eq!(completed, 2);
+    assert_eq!(failed, 1);
+    assert_eq!(daemon.log().len(), 2);
+
+    assert!(jobs.join(DONE_DIR).join("a.json").exists());
+    assert!(jobs.join(DONE_DIR).join("b.json").exists());
+    assert!(jobs.join(FAILED_DIR).join("c.json").exists());
+    assert!(jobs.join(FAILED_DIR).join("c.json.error.json").exists());
+    assert!(root.join("receipts").join("a.receipt.json").exists());
+
+    let status: serde_json::Value =
+        serde_json::from_str(&fs::read_to_string(root.join("receipts").join(STATUS_FILE)).unwrap()).unwrap();
+    assert_eq!(status["processed"], 2);
+    assert_eq!(status["failures"], 1);
+    let root_hash = daemon.log().root_hash();
+    assert_eq!(status["log_root"].as_str(), root_hash.as_deref());
+
+    // Restart and redeliver an already-completed job
+    drop(daemon);
+    fs::write(jobs.join("a-again.json"), job_a).unwrap();
+
+    let mut restarted = Daemon::new(config(&root), test_sign).unwrap();
+    assert_eq!(restarted.log().len(), 2);
+
+    let outcomes = restarted.run_once().unwrap();
+    assert_eq!(outcomes, vec![JobOutcome::AlreadyProcessed { job: "a-again.json".to_string() }]);
+    assert_eq!(restarted.log().len(), 2);
+    assert_eq!(restarted.log().root_hash(), root_hash);
+
+    let _ = fs::remove_dir_all(&root);
+}
+
+#[test]
+fn test_torn_log_line_is_dropped_on_restart() {
+    let root = scratch_dir("daemon-torn");
+    let jobs = root.join("jobs");
+    let log_path = root.join("audit.log");
+
+    fs::write(jobs.join("a.json"), r#"{"claim": "Water boils", "evidence": ["Heat applied"]}"#).unwrap();
+    let mut daemon = Daemon::new(config(&root), test_sign).unwrap();
+    daemon.run_once().unwrap();
+    drop(daemon);
+
+    // A crash mid-append leaves half an entry and no newline
+    let complete = fs::read_to_string(&log_path).unwrap();
+    fs::write(&log_path, format!("{}{{\"index\":1,\"job_ha", complete)).unwrap();
+
+    fs::write(jobs.join("b.json"), r#"{"claim": "Ground is wet", "evidence": ["It rained"]}"#).unwrap();
+    let mut restarted = Daemon::new(config(&root), test_sign).unwrap();
+    assert_eq!(restarted.log().len(), 1);
+    assert_eq!(fs::read_to_string(&log_path).unwrap(), complete);
+
+    restarted.run_once().unwrap();
+    assert_eq!(restarted.log().len(), 2);
+    drop(restarted);
+    assert_eq!(ReceiptLog::open(&log_path).unwrap().len(), 2);
+
+    let _ = fs::remove_dir_all(&root);
+}
@@ -0,0 +1,189 @@
+//! Hash canonicalization properties for receipts, trace steps and causal links
+//!
+//! Generated records whose fields differ only in where one field ends and
+//! the next begins must not share a hash, and a JSON round trip must keep
+//! every record verifying. `fixtures/trace_step_v1.json` and
+//! `fixtures/causal_link_v1.json` were sealed under the legacy
+//! concatenation rules and still verify.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{TimeZone, Utc};
+use proptest::prelude::*;
+use sap4d::hashing::{FIELD_HASH_VERSION, LEGACY_HASH_VERSION};
+use sap4d::{CausalLink, CausalRelation, Receipt, TraceEnvelope, TraceStep};
+
+const TRACE_STEP_V1: &str = include_str!("fixtures/trace_step_v1.json");
+const CAUSAL_LINK_V1: &str = include_str!("fixtures/causal_link_v1.json");
+
+/// Short text mixing ASCII, separators and multi-byte characters
+fn text() -> impl Strategy<Value = String> {
+    "[a-c\n\t :|°✓é]{0,8}"
+}
+
+fn texts() -> impl Strategy<Value = Vec<String>> {
+    prop::collection::vec(text(), 0..4)
+}
+
+/// Split the concatenation of `a` and `b` at another character boundary
+fn resplit(a: &str, b: &str, at: usize) -> (String, String) {
+    let joined = format!("{}{}", a, b);
+    let boundaries: Vec<usize> = joined.char_indices().map(|(i, _)| i).chain([joined.len()]).collect();
+    let at = boundaries[at % boundaries.len()];
+    (joined[..at].to_string(), joined[at..].to_string())
+}
+
+fn sign(hash: &str) -> String {
+    format!("TEST_SIG:{}", hash)
+}
+
+fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+proptest! {
+    #[test]
+    fn trace_step_boundaries_are_hashed(
+        operation in text(),
+        input in text(),
+        output in text(),
+        axioms in texts(),
+        at in any::<usize>(),
+    ) {
+        let step = TraceStep::new(0, operation.clone(), input.clone(), output.clone(), axioms.clone());
+        prop_assert_eq!(step.hash_version, FIELD_HASH_VERSION);
+
+        let (moved_input, moved_output) = resplit(&input, &output, at);
+        prop_assume!(moved_input != input);
+        let moved = TraceStep::new(0, operation, moved_input, moved_output, axioms);
+        prop_assert_ne!(step.step_hash, moved.step_hash);
+    }
+
+    #[test]
+    fn trace_step_axiom_lists_are_delimited(
+        a in text(),
+        b in text(),
+        at in any::<usize>(),
+    ) {
+        let (c, d) = resplit(&a, &b, at);
+        prop_assume!(c != a);
+        let step = |axioms: Vec<String>| TraceStep::new(1, "op", "in", "out", axioms).step_hash;
+        prop_assert_ne!(step(vec![a.clone(), b.clone()]), step(vec![c, d]));
+        prop_assert_ne!(step(vec![a.clone(), b.clone()]), step(vec![format!("{}{}", a, b)]));
+    }
+
+    #[test]
+    fn trace_step_round_trip_verifies(
+        index in 0usize..1000,
+        operation in text(),
+        input in text(),
+        output in text(),
+        axioms in texts(),
+    ) {
+        let step = TraceStep::new(index, operation, input, output, axioms);
+        let parsed: TraceStep = serde_json::from_str(&serde_json::to_string(&step).unwrap()).unwrap();
+        prop_assert!(parsed.verify_integrity());
+        prop_assert_eq!(parsed.step_hash, step.step_hash);
+    }
+
+    #[test]
+    fn causal_link_boundaries_are_hashed(
+        source in text(),
+        target in text(),
+        evidence in texts(),
+        at in any::<usize>(),
+    ) {
+        let link = CausalLink::new(source.clone(), target.clone(), CausalRelation::Implies, evidence.clone());
+        prop_assert_eq!(link.hash_version, FIELD_HASH_VERSION);
+
+        let (moved_source, moved_target) = resplit(&source, &target, at);
+        prop_assume!(moved_source != source);
+        let moved = CausalLink::new(moved_source, moved_target, CausalRelation::Implies, evidence.clone());
+        prop_assert_ne!(&link.hash, &moved.hash);
+
+        // An empty evidence item is still an item
+        let mut padded = evidence;
+        padded.push(String::new());
+        let padded = CausalLink::new(source, target, CausalRelation::Implies, padded);
+        prop_assert_ne!(link.hash, padded.hash);
+    }
+
+    #[test]
+    fn causal_link_round_trip_verifies(
+        source in text(),
+        target in text(),
+        evidence in texts(),
+    ) {
+        let link = CausalLink::new(source, target, CausalRelation::Causes, evidence);
+        let parsed: CausalLink = serde_json::from_str(&serde_json::to_string(&link).unwrap()).unwrap();
+        prop_assert!(parsed.verify_integrity());
+    }
+
+    #[test]
+    fn receipt_evidence_boundaries_are_hashed(
+        claim in text(),
+        a in text(),
+        b in text(),
+        at in any::<usize>(),
+    ) {
+        let (c, d) = resplit(&a, &b, at);
+        let mut original = vec![a, b];
+        let mut moved = vec![c, d];
+        original.sort();
+        moved.sort();
+        prop_assume!(original != moved);
+
+        let _clock = sap4d::clock::freeze(Utc.with_ymd_and_hms(2025, 6, 1, 12, 0, 0).unwrap());
+        let receipt = |evidence: Vec<String>| {
+            let mut trace = TraceEnvelope::new(claim.clone(), evidence);
+            trace.finalize();
+            Receipt::from_trace(&trace, sign)
+        };
+        let original = receipt(original);
+        let moved = receipt(moved);
+        prop_assert_ne!(&original.hash, &moved.hash);
+
+        let parsed = Receipt::from_json(&original.to_json().unwrap()).unwrap();
+        prop_assert!(parsed.verify(verify));
+        prop_assert_eq!(parsed.hash, original.hash);
+    }
+}
+
+#[test]
+fn test_v1_trace_step_fixture_verifies() {
+    let step: TraceStep = serde_json::from_str(TRACE_STEP_V1).unwrap();
+    assert_eq!(step.hash_version, LEGACY_HASH_VERSION);
+    assert!(step.verify_integrity());
+
+    // The ambiguity v2 removes: v1 cannot tell where input ends
+    let mut shifted = step.clone();
+    shifted.input = "Water is heated".to_string();
+    shifted.output = "\nto 100 °CWater boils ✓".to_string();
+    assert!(shifted.verify_integrity());
+
+    let mut resealed = step.clone();
+    resealed.hash_version = FIELD_HASH_VERSION;
+    assert!(!resealed.verify_integrity());
+
+    let mut unknown = step;
+    unknown.hash_version = 99;
+    assert!(!unknown.verify_integrity());
+}
+
+#[test]
+fn test_v1_causal_link_fixture_verifies() {
+    let link: CausalLink = serde_json::from_str(CAUSAL_LINK_V1).unwrap();
+    assert_eq!(link.hash_version, LEGACY_HASH_VERSION);
+    assert!(link.verify_integrity());
+
+    // Re-joined evidence collides under v1 only
+    let mut joined = link.clone();
+    joined.evidence = vec!["Heat applied\nto the pot".to_string()];
+    assert!(joined.verify_integrity());
+
+    let fresh = CausalLink::new(&link.source, &link.target, link.relation, link.evidence.clone());
+    let fresh_joined = CausalLink::new(&link.source, &link.target, link.relation, joined.evidence);
+    assert_eq!(fresh.hash_version, FIELD_HASH_VERSION);
+    assert_ne!(fresh.hash, fresh_joined.hash);
+    assert_ne!(fresh.hash, link.hash);
+}
@@ -0,0 +1,110 @@
+//! Receipt format migration tests against pinned v1 and v2 fixtures
+//!
+//! Both fixtures hold the same receipt: `receipt_v1.json` predates the
+//! `version` field, `receipt_v2.json` was issued under canonical hashing.
+//! New receipts are v3, which commits to each evidence item separately.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::{ProofEngine, Receipt};
+use sha2::{Digest, Sha256};
+
+const V1: &str = include_str!("fixtures/receipt_v1.json");
+const V2: &str = include_str!("fixtures/receipt_v2.json");
+
+/// Signer the fixtures were issued with
+fn sign(hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"FIXTURE_SIG:");
+    hasher.update(hash.as_bytes());
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+}
+
+fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+#[test]
+fn test_v1_fixture_verifies_under_v1_rules() {
+    let receipt = Receipt::from_json(V1).unwrap();
+    assert_eq!(receipt.version, 1);
+    assert!(receipt.verify(verify));
+    assert!(ProofEngine::new().verify_receipt(&receipt, verify).unwrap());
+
+    // v1 hashing is order-sensitive
+    let mut reordered = receipt.clone();
+    reordered.evidence.reverse();
+    assert!(!reordered.verify_hash());
+
+    // Relabeling a v1 receipt as v2 does not verify
+    let mut relabeled = receipt;
+    relabeled.version = 2;
+    assert!(!relabeled.verify_hash());
+}
+
+#[test]
+fn test_v2_fixture_verifies_under_canonical_rules() {
+    let receipt = Receipt::from_json(V2).unwrap();
+    assert_eq!(receipt.version, 2);
+    assert!(receipt.verify(verify));
+
+    let mut reordered = receipt.clone();
+    reordered.evidence.reverse();
+    reordered.axioms.reverse();
+    assert!(reordered.verify_hash());
+
+    // Sub-millisecond precision is normalized away
+    let mut precise = receipt.clone();
+    precise.timestamp += chrono::Duration::microseconds(250);
+    assert!(precise.verify_hash());
+
+    let mut tampered = receipt;
+    tampered.evidence.push("Pressure dropped".to_string());
+    assert!(!tampered.verify_hash());
+}
+
+#[test]
+fn test_upgrade_v1_fixture_to_v2() {
+    let v1 = Receipt::from_json(V1).unwrap();
+    let upgraded = v1.upgrade_to(2, sign).unwrap();
+
+    let v2 = Receipt::from_json(V2).unwrap();
+    assert_eq!(upgraded.version, 2);
+    assert_eq!(upgraded.hash, v2.hash);
+    assert_eq!(upgraded.signature, v2.signature);
+    assert!(upgraded.verify(verify));
+
+    // Round-trips with the version recorded
+    let reparsed = Receipt::from_json(&upgraded.to_json().unwrap()).unwrap();
+    assert_eq!(reparsed.version, 2);
+    assert!(reparsed.verify(verify));
+}
+
+#[test]
+fn test_new_receipts_use_current_format() {
+    let (_, receipt) = ProofEngine::new()
+        .prove("Water boils", vec!["Heat applied".to_string(), "Temperature reached 100C".to_string()], sign)
+        .unwrap();
+    assert_eq!(receipt.version, Receipt::FORMAT_VERSION);
+    assert!(receipt.to_json().unwrap().contains("\"version\": 3"));
+}
+
+#[test]
+fn test_upgrade_v2_fixture_to_v3_and_redact() {
+    let v2 = Receipt::from_json(V2).unwrap();
+    assert!(v2.redact(&[0]).is_err());
+
+    let v3 = v2.upgrade(sign).unwrap();
+    assert_eq!(v3.version, 3);
+    assert_ne!(v3.hash, v2.hash);
+    assert!(v3.verify(verify));
+
+    // v3 keeps v2's order and normalization independence
+    let mut reordered = v3.clone();
+    reordered.evidence.reverse();
+    assert!(reordered.verify_hash());
+
+    let redacted = v3.redact(&[0]).unwrap();
+    assert_ne!(redacted.evidence[0], v3.evidence[0]);
+    assert!(redacted.verify(verify));
+}
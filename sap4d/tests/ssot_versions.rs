@@ -0,0 +1,121 @@
+//! Receipts checked across Ω-SSOT versions
+//!
+//! `fixtures/ssot_v1.toml` holds the fundamental axioms as first shipped;
+//! `fixtures/ssot_v2.toml` drops A3, rewords A6 and adds A9.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use chrono::{DateTime, Utc};
+use sap4d::{clock, ErrorCode, OmegaSSoT, ProofEngine, ProofError, Receipt, SsotHistory};
+
+const FIXTURES: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures");
+
+fn sign(hash: &str) -> String {
+    format!("SSOT_TEST_SIG:{}", hash)
+}
+
+fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+fn at(timestamp: &str) -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(timestamp).unwrap().with_timezone(&Utc)
+}
+
+fn load(version: &str) -> OmegaSSoT {
+    OmegaSSoT::from_file(format!("{}/ssot_{}.toml", FIXTURES, version)).unwrap()
+}
+
+/// v1 frozen on Jan 1, v2 on Mar 1
+fn history() -> SsotHistory {
+    let mut history = SsotHistory::new();
+    {
+        let _clock = clock::freeze(at("2026-01-01T00:00:00Z"));
+        history.record(&load("v1")).unwrap();
+    }
+    {
+        let _clock = clock::freeze(at("2026-03-01T00:00:00Z"));
+        history.record(&load("v2")).unwrap();
+    }
+    history
+}
+
+fn prove_under(ssot: OmegaSSoT, timestamp: &str) -> Receipt {
+    let _clock = clock::freeze(at(timestamp));
+    let observations = vec!["Fact A".to_string(), "Fact B".to_string()];
+    let (_, receipt) = ProofEngine::new().with_ssot(ssot).prove("Conclusion", observations, sign).unwrap();
+    receipt
+}
+
+#[test]
+fn test_fixture_versions_diff() {
+    let (v1, v2) = (load("v1"), load("v2"));
+    assert!(OmegaSSoT::new().diff(&v1).is_empty(), "v1 pins the fundamental axioms");
+
+    let diff = v1.diff(&v2);
+    let ids = |axioms: &[sap4d::Axiom]| axioms.iter().map(|a| a.id.clone()).collect::<Vec<_>>();
+    assert_eq!(ids(&diff.added), ["A9_PROVENANCE"]);
+    assert_eq!(ids(&diff.removed), ["A3_EXCLUDED_MIDDLE"]);
+    assert_eq!(diff.changed.iter().map(|c| c.id()).collect::<Vec<_>>(), ["A6_C_ZERO"]);
+}
+
+#[test]
+fn test_receipt_from_old_version_is_identified() {
+    let history = history();
+    let receipt = prove_under(load("v1"), "2026-02-01T00:00:00Z");
+
+    let issued = history.in_force_at(receipt.timestamp).unwrap();
+    assert_eq!(issued.version(), "1.0.0");
+
+    let compatibility = history.latest().unwrap().compatibility(&receipt.axioms, issued);
+    assert_eq!(compatibility.changed, ["A6_C_ZERO"]);
+    assert_eq!(compatibility.missing, ["A3_EXCLUDED_MIDDLE"]);
+    assert!(compatibility.unpinned.is_empty());
+}
+
+#[test]
+fn test_verify_receipt_fails_on_changed_axiom() {
+    let receipt = prove_under(load("v1"), "2026-02-01T00:00:00Z");
+
+    // Without a history the receipt verifies as before
+    let engine = ProofEngine::new().with_ssot(load("v2"));
+    assert!(engine.verify_receipt(&receipt, verify).unwrap());
+
+    let err = engine.with_ssot_history(history()).verify_receipt(&receipt, verify).unwrap_err();
+    assert_eq!(err.to_string(), "Axiom A6_C_ZERO changed since receipt issuance");
+    assert_eq!(err.code(), ErrorCode::AxiomDrift);
+
+    // Re-checked under the version it was issued under, it still holds
+    let engine = ProofEngine::new().with_ssot(load("v1")).with_ssot_history(history());
+    assert!(engine.verify_receipt(&receipt, verify).unwrap());
+}
+
+#[test]
+fn test_receipt_from_current_version_verifies() {
+    let receipt = prove_under(load("v2"), "2026-04-01T00:00:00Z");
+    let engine = ProofEngine::new().with_ssot(load("v2")).with_ssot_history(history());
+    assert!(engine.verify_receipt(&receipt, verify).unwrap());
+
+    // Issued before any recorded version
+    let early = prove_under(load("v1"), "2025-06-01T00:00:00Z");
+    assert!(matches!(engine.verify_receipt(&early, verify), Err(ProofError::Internal(_))));
+}
+
+#[test]
+fn test_history_persists() {
+    let path = std::env::temp_dir().join(format!("ssot-history-{}.json", std::process::id()));
+    let history = history();
+    history.save(&path).unwrap();
+
+    let loaded = SsotHistory::load(&path).unwrap();
+    let versions: Vec<(&str, &str)> = loaded.snapshots().iter().map(|s| (s.version(), s.hash())).collect();
+    let expected: Vec<(&str, &str)> = history.snapshots().iter().map(|s| (s.version(), s.hash())).collect();
+    assert_eq!(versions, expected);
+    assert_eq!(loaded.get("1.0.0").unwrap().frozen_at(), at("2026-01-01T00:00:00Z"));
+
+    // A rewritten axiom no longer matches the recorded hash
+    let text = std::fs::read_to_string(&path).unwrap();
+    std::fs::write(&path, text.replace("for valid output", "for most output")).unwrap();
+    assert!(SsotHistory::load(&path).is_err());
+    std::fs::remove_file(&path).unwrap();
+}
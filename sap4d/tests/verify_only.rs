@@ -0,0 +1,170 @@
+//! Receipt verification against the pinned fixtures
+//!
+//! Runs under both the default features and
+//! `--no-default-features --features verify-only`, so the engine-free
+//! verifier is held to the same fixtures as the engine.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::verify::{commitment, Receipt};
+use sap4d::{CausalLink, CausalRelation};
+use sha2::{Digest, Sha256};
+
+const V1: &str = include_str!("fixtures/receipt_v1.json");
+const V2: &str = include_str!("fixtures/receipt_v2.json");
+const V3: &str = include_str!("fixtures/receipt_v3.json");
+const V3_REDACTED: &str = include_str!("fixtures/receipt_v3_redacted.json");
+
+/// Signer the fixtures were issued with
+fn sign(hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"FIXTURE_SIG:");
+    hasher.update(hash.as_bytes());
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+}
+
+fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+fn load(json: &str) -> Receipt {
+    serde_json::from_str(json).unwrap()
+}
+
+#[test]
+fn test_every_fixture_verifies() {
+    for (json, version) in [(V1, 1), (V2, 2), (V3, 3), (V3_REDACTED, 3)] {
+        let receipt = load(json);
+        assert_eq!(receipt.version, version);
+        assert!(receipt.verify(verify), "version {} fixture", version);
+    }
+    assert_eq!(load(V3).hash, load(V3_REDACTED).hash);
+}
+
+#[test]
+fn test_tampering_is_detected() {
+    for json in [V1, V2, V3, V3_REDACTED] {
+        let receipt = load(json);
+
+        let mut claim = receipt.clone();
+        claim.claim.push('!');
+        assert!(!claim.verify_hash());
+
+        let mut c_zero = receipt.clone();
+        c_zero.c_zero = !c_zero.c_zero;
+        assert!(!c_zero.verify_hash());
+
+        let mut later = receipt.clone();
+        later.timestamp = "2025-06-01T12:00:01Z".to_string();
+        assert!(!later.verify_hash());
+
+        let mut relabeled = receipt.clone();
+        relabeled.version = if receipt.version == 1 { 2 } else { 1 };
+        assert!(!relabeled.verify_hash());
+
+        let mut resigned = receipt;
+        resigned.signature = sign("forged");
+        assert!(resigned.verify_hash());
+        assert!(!resigned.verify(verify));
+    }
+}
+
+#[test]
+fn test_canonical_rules_hold_without_the_engine() {
+    let receipt = load(V3);
+
+    // Order, sub-millisecond precision and NFC form do not matter
+    let mut equivalent = receipt.clone();
+    equivalent.evidence.reverse();
+    equivalent.axioms.reverse();
+    equivalent.provenance.reverse();
+    equivalent.timestamp = "2025-06-01T12:00:00.123999Z".to_string();
+    equivalent.claim = "Caf\u{e9} is open".to_string();
+    assert!(equivalent.verify_hash());
+
+    // Every covered field is covered
+    let mut provenance = receipt.clone();
+    provenance.provenance[0].trust = 0.8;
+    assert!(!provenance.verify_hash());
+
+    let mut retrieved = receipt.clone();
+    retrieved.provenance[0].retrieved_at = Some("2025-06-01T08:00:01.250Z".to_string());
+    assert!(!retrieved.verify_hash());
+
+    let mut compound = receipt.clone();
+    compound.compound.as_mut().unwrap().carried_by.clear();
+    assert!(!compound.verify_hash());
+
+    let mut parents = receipt.clone();
+    parents.parents.clear();
+    assert!(!parents.verify_hash());
+
+    let mut files = receipt.clone();
+    files.evidence_files[0].sha256 = "0".repeat(64);
+    assert!(!files.verify_hash());
+
+    let mut chain = receipt;
+    chain.causal_chain.swap(1, 2);
+    assert!(!chain.verify_hash());
+}
+
+#[test]
+fn test_redacted_fixture_commits_to_withheld_items() {
+    let full = load(V3);
+    let redacted = load(V3_REDACTED);
+    assert_eq!(redacted.redacted, [0]);
+    assert_eq!(redacted.evidence[0], commitment(&full.evidence[0]));
+
+    // A redacted index must hold a commitment
+    let mut forged = redacted.clone();
+    forged.evidence[0] = full.evidence[0].clone();
+    assert!(!forged.verify_hash());
+
+    let mut out_of_range = redacted;
+    out_of_range.redacted = vec![7];
+    assert!(!out_of_range.verify_hash());
+}
+
+#[test]
+fn test_causal_link_integrity() {
+    let link = CausalLink::new(
+        "Heat applied",
+        "Water boils",
+        CausalRelation::Implies,
+        vec!["Heat applied".to_string()],
+    );
+    assert!(link.verify_integrity());
+
+    let json = serde_json::to_string(&link).unwrap();
+    let parsed: CausalLink = serde_json::from_str(&json).unwrap();
+    assert!(parsed.verify_integrity());
+
+    let mut tampered = parsed;
+    tampered.relation = CausalRelation::Causes;
+    assert!(!tampered.verify_integrity());
+}
+
+/// The engine's receipts and the lightweight verifier agree
+#[cfg(feature = "engine")]
+#[test]
+fn test_engine_receipts_verify_without_the_engine() {
+    for json in [V1, V2, V3, V3_REDACTED] {
+        let full = sap4d::Receipt::from_json(json).unwrap();
+        assert!(full.verify(verify));
+    }
+
+    let engine = sap4d::ProofEngine::new();
+    let (_, receipt) = engine
+        .prove(
+            "The sky reflects certain wavelengths",
+            vec!["The sky is blue".to_string(), "Blue things reflect certain wavelengths".to_string()],
+            sign,
+        )
+        .unwrap();
+    let lite = load(&serde_json::to_string(&receipt).unwrap());
+    assert!(lite.verify(verify));
+
+    let redacted = receipt.redact(&[1]).unwrap();
+    let lite = load(&serde_json::to_string(&redacted).unwrap());
+    assert!(lite.verify(verify));
+}
@@ -0,0 +1,108 @@
+//! Watch mode integration tests
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use sap4d::watch::{WatchConfig, Watcher};
+use sap4d::Receipt;
+use std::fs;
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::mpsc;
+use std::time::Duration;
+
+const CLAIM: &str = "Water boils";
+const EVIDENCE: &str = "Heat applied\nTemperature reached 100C\n";
+
+fn test_sign(hash: &str) -> String {
+    format!("TEST_SIG:{}", hash)
+}
+
+fn scratch_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sap4d-watch-{}-{}", name, std::process::id()));
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn config(dir: &std::path::Path) -> WatchConfig {
+    WatchConfig::new(CLAIM, dir.join("facts.txt"), dir.join("receipt.json")).with_debounce(Duration::from_millis(50))
+}
+
+#[test]
+fn test_prove_once_writes_receipt_and_keeps_it_on_failure() {
+    let dir = scratch_dir("once");
+    fs::write(dir.join("facts.txt"), EVIDENCE).unwrap();
+    let watcher = Watcher::new(config(&dir), test_sign);
+
+    let run = watcher.prove_once();
+    assert!(run.verified, "{:?}", run.error);
+    assert!(run.status_line().starts_with("VERIFIED "));
+    let written = fs::read_to_string(dir.join("receipt.json")).unwrap();
+    let receipt = Receipt::from_json(&written).unwrap();
+    assert_eq!(Some(&receipt.hash), run.receipt_hash.as_ref());
+    assert!(!dir.join("receipt.json.tmp").exists());
+
+    // Evidence with no statements fails; the previous receipt stays in place
+    fs::write(dir.join("facts.txt"), "\n").unwrap();
+    let failed = watcher.prove_once();
+    assert!(!failed.verified);
+    assert!(failed.receipt_hash.is_none());
+    assert!(failed.status_line().starts_with("FAILED "));
+    assert_eq!(fs::read_to_string(dir.join("receipt.json")).unwrap(), written);
+}
+
+#[test]
+fn test_missing_evidence_is_waited_for() {
+    let dir = scratch_dir("missing");
+    let watcher = Watcher::new(config(&dir), test_sign);
+
+    // An editor's write-temp-rename save briefly leaves no file
+    let path = dir.join("facts.txt");
+    let writer = std::thread::spawn(move || {
+        std::thread::sleep(Duration::from_millis(300));
+        let tmp = path.with_extension("txt.swp");
+        fs::write(&tmp, EVIDENCE).unwrap();
+        fs::rename(tmp, path).unwrap();
+    });
+    let run = watcher.prove_once();
+    writer.join().unwrap();
+    assert!(run.verified, "{:?}", run.error);
+}
+
+#[test]
+fn test_run_reproves_on_change_and_stops() {
+    let dir = scratch_dir("run");
+    fs::write(dir.join("facts.txt"), "\n").unwrap();
+    let watcher = Watcher::new(config(&dir), test_sign);
+
+    let stop = AtomicBool::new(false);
+    let (tx, rx) = mpsc::channel();
+    std::thread::scope(|scope| {
+        let handle = scope.spawn(|| watcher.run(&stop, |run| tx.send(run.clone()).unwrap()));
+
+        let initial = rx.recv_timeout(Duration::from_secs(5)).unwrap();
+        assert!(!initial.verified);
+
+        fs::write(dir.join("facts.txt"), EVIDENCE).unwrap();
+        let changed = rx.recv_timeout(Duration::from_secs(10)).unwrap();
+        assert!(changed.verified, "{:?}", changed.error);
+
+        stop.store(true, Ordering::SeqCst);
+        handle.join().unwrap().unwrap();
+    });
+    assert!(dir.join("receipt.json").exists());
+}
+
+#[test]
+fn test_publish_failure_is_reported() {
+    let dir = scratch_dir("publish");
+    fs::write(dir.join("facts.txt"), EVIDENCE).unwrap();
+    // Nothing listens on the discard port
+    let watcher = Watcher::new(config(&dir).with_publish("http://127.0.0.1:9/verify-receipt"), test_sign);
+
+    let run = watcher.prove_once();
+    assert!(run.verified);
+    assert!(run.published.is_none());
+    assert!(run.publish_error.is_some());
+    assert!(run.status_line().contains("publish failed"));
+}
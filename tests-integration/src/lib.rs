@@ -0,0 +1,211 @@
+//! # Full-stack integration harness
+//!
+//! Drives a claim through every layer: ProofEngine → trace → receipt →
+//! L1/L2/L3 audit → audit receipt → daily composite → inclusion proof →
+//! verification bundle. Time and signing keys are fixed so every hash a
+//! scenario produces is reproducible and can be golden-pinned.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::levels::SubOperation;
+use axiom_audit::service::AuditConfig;
+use axiom_audit::{AuditReceipt, AuditService, DailyComposite, MerkleProof};
+use chrono::{DateTime, Utc};
+use sap4d::engine::EngineConfig;
+use sap4d::{ProofEngine, Receipt, ReceiptVerification, TraceEnvelope};
+use sha2::{Digest, Sha256};
+use verification::bundle::{TestType, Tolerance};
+use verification::executor::output_artifact;
+use verification::provenance::{EnvironmentManifest, ModelMetadata};
+use verification::verifier::VerificationResult;
+use verification::{DeterministicConfig, ProofArtifactBuilder, VerificationBundle, Verifier};
+
+/// Frozen time for every scenario
+pub const FIXED_TIME: &str = "2025-06-01T12:00:00Z";
+
+/// Claim audited alongside every scenario so the composite has siblings
+pub const BASELINE_CLAIM: &str = "The baseline ledger balances";
+
+/// Verdict artifact contents the bundle expects
+pub const EXPECTED_VERDICT: &str = "PROOF_EXISTS";
+
+/// Parsed [`FIXED_TIME`]
+pub fn fixed_time() -> DateTime<Utc> {
+    DateTime::parse_from_rfc3339(FIXED_TIME)
+        .expect("valid fixed time")
+        .with_timezone(&Utc)
+}
+
+/// Deterministic signer (SHA-256 keyed mock)
+pub fn sign(hash: &str) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"INTEGRATION_SIG:");
+    hasher.update(hash.as_bytes());
+    base64::Engine::encode(&base64::engine::general_purpose::STANDARD, hasher.finalize())
+}
+
+/// Verifier matching [`sign`]
+pub fn verify(hash: &str, signature: &str) -> bool {
+    sign(hash) == signature
+}
+
+/// A claim and its evidence
+#[derive(Debug, Clone)]
+pub struct Scenario {
+    pub claim: String,
+    pub evidence: Vec<String>,
+    /// Run the engine, L1 and L2 with contradiction detection on
+    pub detect_contradictions: bool,
+}
+
+impl Scenario {
+    /// Create a scenario
+    pub fn new(claim: impl Into<String>, evidence: &[&str]) -> Self {
+        Self {
+            claim: claim.into(),
+            evidence: evidence.iter().map(|e| e.to_string()).collect(),
+            detect_contradictions: false,
+        }
+    }
+    
+    /// Detect contradicting evidence at every layer that can
+    pub fn detecting_contradictions(mut self) -> Self {
+        self.detect_contradictions = true;
+        self
+    }
+}
+
+/// Every artifact produced by one pass through the stack
+#[derive(Debug)]
+pub struct StackRun {
+    /// Engine proof (trace and receipt), or the engine's rejection
+    pub proof: sap4d::Result<(TraceEnvelope, Receipt)>,
+    /// Deep verification of the engine receipt, if one was produced
+    pub receipt_check: Option<ReceiptVerification>,
+    /// Sub-operations derived from the trace
+    pub sub_operations: Vec<SubOperation>,
+    /// Audit receipt for the scenario
+    pub audit_receipt: AuditReceipt,
+    /// Daily composite over the baseline and scenario receipts
+    pub composite: DailyComposite,
+    /// Inclusion proof of the scenario receipt in the composite
+    pub inclusion: MerkleProof,
+    /// Verification bundle referencing the audit receipt
+    pub bundle: VerificationBundle,
+    /// Verifier result for the bundle
+    pub verification: VerificationResult,
+}
+
+impl StackRun {
+    /// Verdict recorded in the bundle's verdict artifact
+    pub fn verdict(&self) -> &'static str {
+        verdict(&self.audit_receipt)
+    }
+}
+
+fn verdict(receipt: &AuditReceipt) -> &'static str {
+    if receipt.proof_exists() {
+        EXPECTED_VERDICT
+    } else {
+        "NO_PROOF_EXISTS"
+    }
+}
+
+/// Run a scenario through the full stack with frozen time
+pub fn run(scenario: &Scenario) -> StackRun {
+    let _clock = sap4d::clock::freeze(fixed_time());
+
+    // Engine → trace → receipt
+    let engine = ProofEngine::with_config(EngineConfig {
+        detect_contradictions: scenario.detect_contradictions,
+        ..Default::default()
+    });
+    let proof = engine.prove(&scenario.claim, scenario.evidence.clone(), sign);
+    let receipt_check = proof
+        .as_ref()
+        .ok()
+        .map(|(_, receipt)| engine.verify_receipt_deep(receipt, verify));
+    let sub_operations = match proof {
+        Ok((ref trace, _)) => SubOperation::chain_from_trace(trace),
+        Err(_) => Vec::new(),
+    };
+
+    // Audit levels → audit receipt
+    let mut service = AuditService::with_config(AuditConfig {
+        detect_contradictions: scenario.detect_contradictions,
+        ..Default::default()
+    });
+    let baseline = service
+        .audit(BASELINE_CLAIM, &[BASELINE_CLAIM.to_string()], sign)
+        .expect("baseline audit");
+    let audit_receipt = service
+        .audit_with_ops(&scenario.claim, &scenario.evidence, &sub_operations, sign)
+        .expect("scenario audit");
+
+    // Daily composite → inclusion proof
+    let composite = DailyComposite::compose(
+        fixed_time().date_naive(),
+        &[baseline, audit_receipt.clone()],
+        sign,
+    )
+    .expect("composite");
+    let inclusion = composite
+        .inclusion_proof(&audit_receipt.receipt_hash)
+        .expect("scenario receipt in composite");
+
+    // Verification bundle referencing the receipt
+    let verdict_artifact = output_artifact("verdict", verdict(&audit_receipt).as_bytes());
+    let expected_verdict = output_artifact("verdict", EXPECTED_VERDICT.as_bytes());
+    let engine_receipt_hash = match proof {
+        Ok((_, ref receipt)) => receipt.hash.clone(),
+        Err(_) => "none".to_string(),
+    };
+
+    let bundle = ProofArtifactBuilder::new()
+        .with_timestamp(fixed_time())
+        .with_model(ModelMetadata {
+            name: "sap4d".to_string(),
+            version: "1.0.0".to_string(),
+            weights_hash: "sha256:none".to_string(),
+            tokenizer_hash: "sha256:none".to_string(),
+            card_uri: None,
+            extra: Default::default(),
+        })
+        .with_environment(EnvironmentManifest {
+            container_image_hash: format!("sha256:{}", "0".repeat(64)),
+            os: "linux".to_string(),
+            deps: vec![],
+            hardware: None,
+            extra: Default::default(),
+        })
+        .with_config(DeterministicConfig {
+            seed: 42,
+            parameters: Default::default(),
+        })
+        .add_execution_step("sap4d.prove", engine_receipt_hash)
+        .add_execution_step("audit", audit_receipt.receipt_hash.clone())
+        .add_execution_step("composite", composite.composite_hash.clone())
+        .add_output(
+            "audit_receipt",
+            format!("sha256:{}", audit_receipt.receipt_hash),
+            format!("receipt://{}", audit_receipt.receipt_hash),
+        )
+        .add_output(verdict_artifact.name, verdict_artifact.hash, verdict_artifact.uri)
+        .add_test("verdict", TestType::Replay, expected_verdict.hash, Tolerance::Exact)
+        .add_test("determinism_check", TestType::Determinism, "", Tolerance::Exact)
+        .build()
+        .expect("bundle");
+
+    let verification = Verifier::new(verify).verify(&bundle);
+
+    StackRun {
+        proof,
+        receipt_check,
+        sub_operations,
+        audit_receipt,
+        composite,
+        inclusion,
+        bundle,
+        verification,
+    }
+}
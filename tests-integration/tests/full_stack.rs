@@ -0,0 +1,204 @@
+//! End-to-end scenarios across sap4d, audit and verification
+//!
+//! Golden hashes below pin every layer's hashing. If one of them changes,
+//! a cross-crate hashing contract changed: update it deliberately.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_audit::levels::{codes, finding_ids, AuditLevel, SubOperation};
+use axiom_audit::FailureReason;
+use sap4d::ProofError;
+use sha2::{Digest, Sha256};
+use tests_integration::{fixed_time, run, verify, Scenario, StackRun, EXPECTED_VERDICT};
+
+/// Golden hashes for one scenario
+struct Golden<'a> {
+    engine_receipt: Option<&'a str>,
+    audit_receipt: &'a str,
+    composite_root: &'a str,
+    composite: &'a str,
+    bundle: &'a str,
+}
+
+fn success() -> Scenario {
+    Scenario::new(
+        "The ledger balances",
+        &["Debits total 100", "Credits total 100", "The ledger balances"],
+    )
+}
+
+fn no_evidence() -> Scenario {
+    Scenario::new("The ledger balances", &[])
+}
+
+fn contradictory() -> Scenario {
+    Scenario::new(
+        "The ledger is balanced",
+        &["Debits total 100", "Credits total 100", "The ledger is balanced", "The ledger is not balanced"],
+    )
+    .detecting_contradictions()
+}
+
+fn assert_golden(run: &StackRun, golden: &Golden) {
+    let actual = Golden {
+        engine_receipt: run.proof.as_ref().ok().map(|(_, r)| r.hash.as_str()),
+        audit_receipt: &run.audit_receipt.receipt_hash,
+        composite_root: &run.composite.merkle_root,
+        composite: &run.composite.composite_hash,
+        bundle: &run.bundle.content_address,
+    };
+
+    assert_eq!(actual.engine_receipt, golden.engine_receipt, "engine receipt hash drifted");
+    assert_eq!(actual.audit_receipt, golden.audit_receipt, "audit receipt hash drifted");
+    assert_eq!(actual.composite_root, golden.composite_root, "composite Merkle root drifted");
+    assert_eq!(actual.composite, golden.composite, "composite hash drifted");
+    assert_eq!(actual.bundle, golden.bundle, "bundle content address drifted");
+}
+
+/// Contracts that hold between layers for every scenario
+fn assert_layers_agree(run: &StackRun) {
+    let receipt_hash = &run.audit_receipt.receipt_hash;
+
+    // Every artifact verifies on its own terms
+    assert!(run.audit_receipt.verify(verify));
+    assert!(run.composite.verify(verify));
+    assert!(run.bundle.verify_integrity());
+
+    // The composite contains this receipt and the inclusion proof binds it
+    assert!(run.composite.receipt_hashes.contains(receipt_hash));
+    assert!(run.composite.verify_inclusion(receipt_hash, &run.inclusion));
+    assert_eq!(run.inclusion.leaf_hash, hex(Sha256::digest(receipt_hash.as_bytes())));
+
+    // The bundle references the audit receipt by hash
+    let referenced = run.bundle.outputs.iter().find(|o| o.name == "audit_receipt").unwrap();
+    assert_eq!(referenced.hash, format!("sha256:{}", receipt_hash));
+    let steps = &run.bundle.execution_trace.as_ref().unwrap().steps;
+    assert!(steps.iter().any(|s| s.name == "audit" && &s.hash == receipt_hash));
+    assert!(steps.iter().any(|s| s.name == "composite" && s.hash == run.composite.composite_hash));
+
+    // Same verdict at every layer
+    let proved = run.audit_receipt.proof_exists();
+    assert_eq!(run.proof.is_ok(), proved);
+    assert_eq!(run.receipt_check.map(|c| c.is_valid()).unwrap_or(false), proved);
+    assert_eq!(run.composite.all_proofs_exist, proved);
+    assert_eq!(run.verification.passed, proved);
+    assert_eq!(run.verdict() == EXPECTED_VERDICT, proved);
+}
+
+fn hex(bytes: impl AsRef<[u8]>) -> String {
+    bytes.as_ref().iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+#[test]
+fn test_successful_claim_flows_through_every_layer() {
+    let run = run(&success());
+
+    let (trace, receipt) = run.proof.as_ref().unwrap();
+    assert!(trace.is_c_zero());
+    assert_eq!(receipt.timestamp, fixed_time());
+
+    // L3 audited the trace-derived sub-operation chain
+    assert_eq!(run.sub_operations.len(), trace.steps.len());
+    assert!(SubOperation::verify_chain(&run.sub_operations));
+    let levels: Vec<AuditLevel> = run.audit_receipt.results.iter().map(|r| r.level).collect();
+    assert_eq!(levels, vec![AuditLevel::L1, AuditLevel::L2, AuditLevel::L3]);
+    assert!(run.audit_receipt.results.iter().all(|r| r.proof.exists() && r.c_zero));
+    assert!(run.audit_receipt.results.iter().all(|r| r.codes.is_empty()));
+
+    assert!(run.composite.c_zero);
+    assert_layers_agree(&run);
+
+    assert_golden(
+        &run,
+        &Golden {
+            engine_receipt: Some("8727fd2f593926669a3a706debb8287a7dcc0aa736c8b315e6a1933a747bc21a"),
+            audit_receipt: "10142ada0156b0412bf22911f0bae8b0434e57e99e12e5b55458885c9a72836c",
+            composite_root: "35dd33dd950e03716154dcc40e592ace5fad6d176a57abd418313ee9f5f91b32",
+            composite: "100ae41e022153196eecd54af9dcc758611932775e62c5c174078f3f52528736",
+            bundle: "hash://sha256/6ad14005e9affc940666b41443e8d1113fbb42ab1598acdaff4569cd32850262",
+        },
+    );
+}
+
+#[test]
+fn test_claim_without_evidence_fails_consistently() {
+    let run = run(&no_evidence());
+
+    assert!(matches!(run.proof, Err(ProofError::UnsupportedClaim)));
+    assert!(run.receipt_check.is_none());
+    assert!(run.sub_operations.is_empty());
+
+    // L1 reports the missing evidence; no level claims a proof
+    let l1 = &run.audit_receipt.results[0];
+    assert_eq!(l1.level, AuditLevel::L1);
+    assert!(l1.has_code(codes::NO_EVIDENCE));
+    assert!(run.audit_receipt.results.iter().all(|r| !r.proof.exists()));
+    assert!(!run.composite.all_proofs_exist);
+
+    let verdict = run.verification.test_results.iter().find(|t| t.test_name == "verdict").unwrap();
+    assert!(!verdict.passed);
+    assert_layers_agree(&run);
+
+    assert_golden(
+        &run,
+        &Golden {
+            engine_receipt: None,
+            audit_receipt: "abdff4dfc18a70d9fe93c9e2302f8e3bc71b35d08251c774abd1ffb06facb82a",
+            composite_root: "e252cdaea34c0499ca6c91599b79007a3b979814140c9c514f1edb9cc58a9e53",
+            composite: "8bd7db2feb296f25e99d23d5e6282f04835d1cb1a1b62d35986b680b3f1939a9",
+            bundle: "hash://sha256/771865e74860652ef77969efac6f8ce8cb72fa7d061041cb51f06678a0ff3c77",
+        },
+    );
+}
+
+#[test]
+fn test_contradictory_evidence_fails_consistently() {
+    // Without detection the same evidence proves the claim at every layer
+    let undetected = run(&Scenario { detect_contradictions: false, ..contradictory() });
+    assert!(undetected.proof.is_ok());
+    assert_layers_agree(&undetected);
+
+    let run = run(&contradictory());
+
+    assert!(matches!(run.proof, Err(ProofError::InvarianceViolation)));
+    assert!(run.receipt_check.is_none());
+    assert!(run.sub_operations.is_empty());
+
+    // L1 locates the contradicting item; L2 and L3 are blocked behind it
+    let l1 = &run.audit_receipt.results[0];
+    assert!(l1.has_code(codes::CONTRADICTORY_EVIDENCE));
+    assert!(!l1.c_zero);
+    assert_eq!(l1.reason, Some(FailureReason::ContradictionDetected { count: 1 }));
+    let contradicts = l1.findings_with_id(finding_ids::L1_EVIDENCE_CONTRADICTS).next().unwrap();
+    assert_eq!(contradicts.evidence_index, Some(3));
+    let l2 = &run.audit_receipt.results[1];
+    assert!(l2.findings_with_id(finding_ids::L2_BLOCKED).next().is_some());
+    assert!(run.audit_receipt.results.iter().all(|r| !r.proof.exists()));
+    assert!(!run.composite.all_proofs_exist);
+    assert!(!run.composite.c_zero);
+
+    let verdict = run.verification.test_results.iter().find(|t| t.test_name == "verdict").unwrap();
+    assert!(!verdict.passed);
+    assert_layers_agree(&run);
+
+    assert_golden(
+        &run,
+        &Golden {
+            engine_receipt: None,
+            audit_receipt: "43afdc1161bc9a7a7feb9655fed81413aa26dd675c68964dde4961af18a40f78",
+            composite_root: "0f1be81145c4e4aed2ee39a8fd04f40bd06e50da5919520ada3487c710ad7d20",
+            composite: "c951f89fa84e208336c7b065e096d273ebee813d7e9f346be3f0adf2f10394d1",
+            bundle: "hash://sha256/00459d10a430dc64af299ddbf7a95ed6fab2f72aaf4a0dc310a032795861e0c1",
+        },
+    );
+}
+
+#[test]
+fn test_runs_are_reproducible() {
+    let first = run(&success());
+    let second = run(&success());
+
+    assert_eq!(first.audit_receipt.receipt_hash, second.audit_receipt.receipt_hash);
+    assert_eq!(first.composite.composite_hash, second.composite.composite_hash);
+    assert_eq!(first.bundle.content_address, second.bundle.content_address);
+}
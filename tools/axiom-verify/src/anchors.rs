@@ -0,0 +1,159 @@
+//! Trust anchors loaded from a file
+//!
+//! The file lists anchors in the portal's `PORTAL_TRUST_ANCHORS` format
+//! (see `sap4d::anchors`), one per line or comma-separated:
+//!
+//! ```text
+//! sha256:445e9a3f7eb864de=hmac:<base64 secret>
+//! did:key:system=ed25519:<base64 public key>
+//! ```
+//!
+//! An `hmac` anchor holds the secret a CLI or auditd key file holds and is
+//! named by the key id their receipts record. An `ed25519` anchor holds the
+//! public key of a bundle attestation signer, and also verifies receipts
+//! whose signature is a base64 Ed25519 signature over the receipt hash.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use base64::{engine::general_purpose::STANDARD, Engine as _};
+use sap4d::anchors::parse_anchors;
+use serde::Serialize;
+use std::path::Path;
+use thiserror::Error;
+
+pub use sap4d::anchors::{AnchorKey, TrustAnchor};
+
+#[derive(Error, Debug)]
+pub enum AnchorError {
+    #[error("Cannot read trust anchors: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid trust anchors file: {0}")]
+    Parse(#[from] sap4d::anchors::AnchorError),
+}
+
+/// Outcome of a signature check
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "status", rename_all = "snake_case")]
+pub enum SignatureCheck {
+    /// A trust anchor made the signature
+    Verified { signer: String },
+    /// No trust anchor matches: the signer is not known here
+    UnknownKey { key_id: Option<String> },
+    /// The signature verifies under a key it carries, but no anchor holds that key
+    Untrusted { signer: String },
+    /// The named signer did not make this signature
+    Invalid { key_id: String, reason: String },
+}
+
+/// Signers whose signatures are trusted
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustAnchors {
+    anchors: Vec<TrustAnchor>,
+}
+
+impl TrustAnchors {
+    /// No anchors: every signature is unverified
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with_anchor(mut self, anchor: TrustAnchor) -> Self {
+        self.anchors.push(anchor);
+        self
+    }
+
+    /// Read a trust anchors file
+    pub fn load(path: &Path) -> Result<Self, AnchorError> {
+        Self::parse(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn parse(text: &str) -> Result<Self, AnchorError> {
+        Ok(Self {
+            anchors: parse_anchors(text)?,
+        })
+    }
+
+    pub fn get(&self, name: &str) -> Option<&TrustAnchor> {
+        self.anchors.iter().find(|anchor| anchor.name == name)
+    }
+
+    pub fn len(&self) -> usize {
+        self.anchors.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.anchors.is_empty()
+    }
+
+    /// Check `signature` over `message`
+    ///
+    /// A signature that names its key is checked against that anchor only;
+    /// one that does not is accepted from any anchor.
+    pub fn check(&self, message: &str, signature: &str, key_id: Option<&str>) -> SignatureCheck {
+        match key_id {
+            Some(key_id) => match self.get(key_id) {
+                Some(anchor) if anchor.verify(message, signature) => SignatureCheck::Verified {
+                    signer: key_id.to_string(),
+                },
+                Some(_) => SignatureCheck::Invalid {
+                    key_id: key_id.to_string(),
+                    reason: "signature does not match the anchor".to_string(),
+                },
+                None => SignatureCheck::UnknownKey {
+                    key_id: Some(key_id.to_string()),
+                },
+            },
+            None => match self.anchors.iter().find(|anchor| anchor.verify(message, signature)) {
+                Some(anchor) => SignatureCheck::Verified {
+                    signer: anchor.name.clone(),
+                },
+                None => SignatureCheck::UnknownKey { key_id: None },
+            },
+        }
+    }
+
+    /// The anchor holding the base64 Ed25519 key `public_key`
+    pub fn holding(&self, public_key: &str) -> Option<&TrustAnchor> {
+        let public_key = STANDARD.decode(public_key).ok()?;
+        self.anchors.iter().find(|anchor| anchor.holds_ed25519(&public_key))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sap4d::anchors::hmac_sign;
+
+    #[test]
+    fn test_parse_and_check() {
+        let anchors = TrustAnchors::parse(&format!(
+            "cli=hmac:{}\nsystem=ed25519:AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=\n",
+            STANDARD.encode("cli-secret")
+        ))
+        .unwrap();
+        assert_eq!(anchors.len(), 2);
+
+        let signature = hmac_sign(b"cli-secret", "abc");
+        assert_eq!(anchors.check("abc", &signature, None), SignatureCheck::Verified { signer: "cli".to_string() });
+        assert_eq!(anchors.check("abc", &signature, Some("cli")), SignatureCheck::Verified { signer: "cli".to_string() });
+        assert!(matches!(anchors.check("abc", &signature, Some("system")), SignatureCheck::Invalid { .. }));
+        assert_eq!(
+            anchors.check("abc", &signature, Some("other")),
+            SignatureCheck::UnknownKey { key_id: Some("other".to_string()) }
+        );
+        assert_eq!(anchors.check("abd", &signature, None), SignatureCheck::UnknownKey { key_id: None });
+        assert_eq!(anchors.holding("AAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAAA=").unwrap().name, "system");
+    }
+
+    #[test]
+    fn test_rejects_bad_files() {
+        // The old JSON keys file and its domain-tag anchors are not accepted
+        assert!(matches!(
+            TrustAnchors::parse(r#"{ "anchors": [ { "name": "cli", "domain": "SAP4D_CLI_SIG:" } ] }"#),
+            Err(AnchorError::Parse(_))
+        ));
+        assert!(matches!(TrustAnchors::parse("cli=domain:U0FQNERfQ0xJX1NJRzo="), Err(AnchorError::Parse(_))));
+        assert!(matches!(TrustAnchors::parse("k=ed25519:c2hvcnQ="), Err(AnchorError::Parse(_))));
+        assert!(matches!(TrustAnchors::parse("k=hmac:QQ==\nk=hmac:Qg=="), Err(AnchorError::Parse(_))));
+    }
+}
@@ -0,0 +1,120 @@
+//! Verification bundles
+//!
+//! A bundle must match its content address, every attestation must sign
+//! that address, and its declared tests must pass against its declared
+//! outputs. Nothing is replayed: checking a bundle offline never runs the
+//! model or container it describes. An attestation that embeds its public
+//! key verifies on its own but only counts as trusted when an anchor holds
+//! that key; one without a key is checked against the anchor its `key_id`
+//! (or else its `signer_id`) names.
+//!
+//! Given a directory, every `*.json` file below it is checked as a bundle,
+//! which covers a `FsBundleStore` root.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::receipt::read;
+use crate::{ArtifactKind, Check, Outcome, Report, SignatureCheck, TrustAnchors};
+use std::path::{Path, PathBuf};
+use verification::{Attestation, AttestationError, VerificationBundle, Verifier};
+
+/// Verify the bundle at `path`, or every bundle under it if it is a directory
+pub fn verify_bundles(path: &Path, trust: &TrustAnchors) -> Vec<Report> {
+    if !path.is_dir() {
+        return vec![verify_bundle(path, trust)];
+    }
+
+    let mut files = Vec::new();
+    if let Err(e) = collect_json(path, &mut files) {
+        return vec![Report::unreadable(ArtifactKind::Bundle, path, e)];
+    }
+    if files.is_empty() {
+        return vec![Report::unreadable(ArtifactKind::Bundle, path, "no *.json bundles found")];
+    }
+    files.sort();
+    files.iter().map(|file| verify_bundle(file, trust)).collect()
+}
+
+/// Verify the bundle file at `path`
+pub fn verify_bundle(path: &Path, trust: &TrustAnchors) -> Report {
+    let kind = ArtifactKind::Bundle;
+    let bundle = match read::<VerificationBundle>(path, "a verification bundle") {
+        Ok((_, bundle)) => bundle,
+        Err(e) => return Report::unreadable(kind, path, e),
+    };
+
+    let integrity = bundle.verify_integrity();
+    let mut checks = vec![Check::new(
+        "content_address",
+        integrity,
+        format!("{} matches the contents", bundle.content_address),
+        format!("{} does not match the contents", bundle.content_address),
+    )];
+
+    if bundle.signatures.is_empty() {
+        checks.push(Check {
+            name: "signatures".to_string(),
+            outcome: Outcome::Unverified,
+            detail: "bundle carries no attestations".to_string(),
+        });
+    }
+    for (index, attestation) in bundle.signatures.iter().enumerate() {
+        let signature = check_attestation(&bundle.content_address, attestation, trust);
+        checks.push(Check::signature(format!("signature[{}]", index), &signature));
+    }
+
+    // The verifier stops at a content address mismatch, and its signature
+    // checks are the ones above, so only its test results are taken
+    if integrity {
+        let result = Verifier::new(|_, _| true).verify(&bundle);
+        checks.extend(result.test_results.into_iter().map(|test| Check {
+            name: format!("test:{}", test.test_name),
+            outcome: if test.passed { Outcome::Pass } else { Outcome::Fail },
+            detail: test.message,
+        }));
+    }
+
+    Report::new(kind, path, checks)
+}
+
+fn check_attestation(content_address: &str, attestation: &Attestation, trust: &TrustAnchors) -> SignatureCheck {
+    let Some(public_key) = &attestation.public_key else {
+        let key_id = attestation.key_id.as_deref().unwrap_or(&attestation.signer_id);
+        return trust.check(content_address, &attestation.signature, Some(key_id));
+    };
+
+    match attestation.verify(content_address) {
+        Ok(()) => match trust.holding(public_key) {
+            Some(anchor) => SignatureCheck::Verified {
+                signer: anchor.name.clone(),
+            },
+            None => SignatureCheck::Untrusted {
+                signer: attestation.signer_id.clone(),
+            },
+        },
+        Err(e) => SignatureCheck::Invalid {
+            key_id: attestation.signer_id.clone(),
+            reason: match e {
+                AttestationError::InvalidSignature(_) => "signature does not match its public key".to_string(),
+                e => e.to_string(),
+            },
+        },
+    }
+}
+
+/// Every `*.json` file below `dir`, skipping dot-files (store temp files)
+fn collect_json(dir: &Path, files: &mut Vec<PathBuf>) -> std::io::Result<()> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+        let hidden = path.file_name().and_then(|name| name.to_str()).is_some_and(|name| name.starts_with('.'));
+        if hidden {
+            continue;
+        }
+        if path.is_dir() {
+            collect_json(&path, files)?;
+        } else if path.extension().is_some_and(|ext| ext == "json") {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,194 @@
+//! Offline verification of Axiom Hive artifacts
+//!
+//! Checks every artifact type the system produces without network access
+//! or the services that wrote them:
+//!
+//! - [`receipt`]: `sap4d` proof receipts and audit service receipts
+//! - [`bundle`]: verification bundles, alone or a directory of them
+//! - [`trail`]: DSIF audit trails exported by AXIOM-S1
+//!
+//! Each artifact yields a [`Report`] of individual [`Check`]s. Signatures
+//! are checked against [`TrustAnchors`] loaded from a trust anchors file;
+//! one no anchor accounts for is [`Outcome::Unverified`] rather than failed.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::Serialize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+pub mod anchors;
+pub mod bundle;
+pub mod receipt;
+pub mod trail;
+
+pub use anchors::{AnchorError, SignatureCheck, TrustAnchor, TrustAnchors};
+
+/// Artifact types `axiom-verify` checks
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum ArtifactKind {
+    Receipt,
+    AuditReceipt,
+    Bundle,
+    DsifTrail,
+}
+
+impl fmt::Display for ArtifactKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Receipt => "receipt",
+            Self::AuditReceipt => "audit-receipt",
+            Self::Bundle => "bundle",
+            Self::DsifTrail => "dsif-trail",
+        })
+    }
+}
+
+/// Outcome of one check
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    Pass,
+    Fail,
+    /// Could not be decided: no trust anchor accounts for the signature
+    Unverified,
+}
+
+impl fmt::Display for Outcome {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Pass => "PASS",
+            Self::Fail => "FAIL",
+            Self::Unverified => "UNVERIFIED",
+        })
+    }
+}
+
+/// One line of a report
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Check {
+    pub name: String,
+    pub outcome: Outcome,
+    pub detail: String,
+}
+
+impl Check {
+    /// Pass with `pass` as detail, or fail with `fail`
+    pub fn new(name: impl Into<String>, passed: bool, pass: impl Into<String>, fail: impl Into<String>) -> Self {
+        let (outcome, detail) = if passed {
+            (Outcome::Pass, pass.into())
+        } else {
+            (Outcome::Fail, fail.into())
+        };
+        Self {
+            name: name.into(),
+            outcome,
+            detail,
+        }
+    }
+
+    /// The check for a signature
+    pub fn signature(name: impl Into<String>, signature: &SignatureCheck) -> Self {
+        let (outcome, detail) = match signature {
+            SignatureCheck::Verified { signer } => (Outcome::Pass, format!("signed by {}", signer)),
+            SignatureCheck::UnknownKey { key_id: Some(key_id) } => {
+                (Outcome::Unverified, format!("no trust anchor named {}", key_id))
+            }
+            SignatureCheck::UnknownKey { key_id: None } => {
+                (Outcome::Unverified, "no trust anchor made this signature".to_string())
+            }
+            SignatureCheck::Untrusted { signer } => {
+                (Outcome::Unverified, format!("valid signature from untrusted key {}", signer))
+            }
+            SignatureCheck::Invalid { key_id, reason } => {
+                (Outcome::Fail, format!("not a valid signature from {}: {}", key_id, reason))
+            }
+        };
+        Self {
+            name: name.into(),
+            outcome,
+            detail,
+        }
+    }
+}
+
+/// Every check run on one artifact
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Report {
+    pub kind: ArtifactKind,
+    pub path: PathBuf,
+    pub checks: Vec<Check>,
+}
+
+impl Report {
+    pub fn new(kind: ArtifactKind, path: &Path, checks: Vec<Check>) -> Self {
+        Self {
+            kind,
+            path: path.to_path_buf(),
+            checks,
+        }
+    }
+
+    /// A report whose artifact could not be read or parsed
+    pub fn unreadable(kind: ArtifactKind, path: &Path, error: impl fmt::Display) -> Self {
+        Self::new(kind, path, vec![Check::new("read", false, "", error.to_string())])
+    }
+
+    /// Whether no check failed; under `strict`, unverified checks fail too
+    pub fn passed(&self, strict: bool) -> bool {
+        self.checks.iter().all(|check| match check.outcome {
+            Outcome::Pass => true,
+            Outcome::Fail => false,
+            Outcome::Unverified => !strict,
+        })
+    }
+
+    /// The check named `name`
+    pub fn check(&self, name: &str) -> Option<&Check> {
+        self.checks.iter().find(|check| check.name == name)
+    }
+}
+
+impl fmt::Display for Report {
+    /// `PASS` or `FAIL`, then one line per check
+    ///
+    /// The header takes unverified checks as passing; `{:#}` counts them
+    /// as failures, as `--strict` does.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let verdict = if self.passed(f.alternate()) { "PASS" } else { "FAIL" };
+        writeln!(f, "{} {} {}", verdict, self.kind, self.path.display())?;
+        let width = self.checks.iter().map(|check| check.name.len()).max().unwrap_or(0);
+        for check in &self.checks {
+            writeln!(f, "  {:<10} {:<width$}  {}", check.outcome, check.name, check.detail, width = width)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_report_verdicts() {
+        let path = Path::new("receipt.json");
+        let unverified = Check::signature("signature", &SignatureCheck::UnknownKey { key_id: None });
+        let report = Report::new(
+            ArtifactKind::Receipt,
+            path,
+            vec![Check::new("hash", true, "hash matches", "hash differs"), unverified],
+        );
+        assert!(report.passed(false));
+        assert!(!report.passed(true));
+
+        let text = report.to_string();
+        assert!(text.starts_with("PASS receipt receipt.json\n"));
+        assert!(text.contains("  UNVERIFIED signature  no trust anchor made this signature\n"));
+        assert!(format!("{:#}", report).starts_with("FAIL"));
+
+        let unreadable = Report::unreadable(ArtifactKind::Bundle, path, "not JSON");
+        assert!(!unreadable.passed(false));
+        assert_eq!(unreadable.check("read").unwrap().detail, "not JSON");
+    }
+}
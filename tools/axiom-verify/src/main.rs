@@ -0,0 +1,104 @@
+//! # axiom-verify
+//!
+//! Offline verification of Axiom Hive artifacts: proof receipts, audit
+//! receipts, verification bundles and exported DSIF trails. Needs no
+//! network access and none of the services that wrote the artifacts.
+//!
+//! Exit Codes:
+//! - 0: Every check passed
+//! - 1: A check failed (or, under `--strict`, could not be verified)
+//! - 2: The trust anchors file could not be loaded
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_verify::{bundle, receipt, trail, Report, TrustAnchors};
+use clap::{Parser, Subcommand};
+use std::path::PathBuf;
+use std::process::ExitCode;
+
+/// CLI arguments
+#[derive(Parser)]
+#[command(name = "axiom-verify")]
+#[command(author = "Alexis Adams")]
+#[command(version = "1.0.0")]
+#[command(about = "Offline verification of receipts, bundles and audit exports")]
+#[command(after_help = "[AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]")]
+struct Cli {
+    /// Trust anchors file in the PORTAL_TRUST_ANCHORS format; without it no signature is verified
+    #[arg(long, global = true)]
+    trust_anchors: Option<PathBuf>,
+
+    /// Fail signatures no trust anchor accounts for instead of reporting them unverified
+    #[arg(long, global = true)]
+    strict: bool,
+
+    #[command(subcommand)]
+    command: Commands,
+}
+
+#[derive(Subcommand)]
+enum Commands {
+    /// Verify a sap4d proof receipt
+    Receipt {
+        /// Receipt JSON file
+        file: PathBuf,
+    },
+
+    /// Verify an audit service receipt
+    AuditReceipt {
+        /// Audit receipt JSON file
+        file: PathBuf,
+    },
+
+    /// Verify a verification bundle, or every bundle under a directory
+    Bundle {
+        /// Bundle JSON file or bundle store directory
+        path: PathBuf,
+    },
+
+    /// Verify an audit trail exported by AXIOM-S1
+    DsifTrail {
+        /// Exported trail JSON file
+        file: PathBuf,
+    },
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+
+    let trust = match &cli.trust_anchors {
+        Some(path) => match TrustAnchors::load(path) {
+            Ok(trust) => trust,
+            Err(e) => {
+                eprintln!("{}: {}", path.display(), e);
+                return ExitCode::from(2);
+            }
+        },
+        None => TrustAnchors::new(),
+    };
+
+    let reports: Vec<Report> = match &cli.command {
+        Commands::Receipt { file } => vec![receipt::verify_receipt(file, &trust)],
+        Commands::AuditReceipt { file } => vec![receipt::verify_audit_receipt(file, &trust)],
+        Commands::Bundle { path } => bundle::verify_bundles(path, &trust),
+        Commands::DsifTrail { file } => vec![trail::verify_trail(file)],
+    };
+
+    for report in &reports {
+        if cli.strict {
+            print!("{:#}", report);
+        } else {
+            print!("{}", report);
+        }
+    }
+
+    let failed = reports.iter().filter(|report| !report.passed(cli.strict)).count();
+    if reports.len() > 1 {
+        println!("{} of {} passed", reports.len() - failed, reports.len());
+    }
+    if failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
@@ -0,0 +1,95 @@
+//! Proof receipts and audit receipts
+//!
+//! A `sap4d` receipt is checked with sap4d's `verify-only` build, under
+//! the hash rules of the version it records. An audit receipt is checked
+//! with `axiom_audit::verify`: every level result against its own hash,
+//! the receipt hash over the results, and any written C=0 summary against
+//! the results. Both sign their hash; a `key_id` field, when present,
+//! names the trust anchor expected to have signed it.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::{ArtifactKind, Check, Report, TrustAnchors};
+use axiom_audit::verify::AuditReceipt;
+use serde_json::Value;
+use std::path::Path;
+
+/// Verify the `sap4d` receipt at `path`
+pub fn verify_receipt(path: &Path, trust: &TrustAnchors) -> Report {
+    let kind = ArtifactKind::Receipt;
+    let (document, receipt) = match read::<sap4d::verify::Receipt>(path, "a sap4d receipt") {
+        Ok(read) => read,
+        Err(e) => return Report::unreadable(kind, path, e),
+    };
+
+    let signature = trust.check(&receipt.hash, &receipt.signature, key_id(&document));
+    let checks = vec![
+        Check::new(
+            "hash",
+            receipt.verify_hash(),
+            format!("v{} hash matches the contents", receipt.version),
+            format!("v{} hash does not match the contents", receipt.version),
+        ),
+        Check::signature("signature", &signature),
+        Check::new("c_zero", receipt.c_zero, "C = 0", "C = 0 not established"),
+    ];
+    Report::new(kind, path, checks)
+}
+
+/// Verify the audit receipt at `path`
+pub fn verify_audit_receipt(path: &Path, trust: &TrustAnchors) -> Report {
+    let kind = ArtifactKind::AuditReceipt;
+    let (document, receipt) = match read::<AuditReceipt>(path, "an audit receipt") {
+        Ok(read) => read,
+        Err(e) => return Report::unreadable(kind, path, e),
+    };
+
+    let invalid: Vec<String> = receipt
+        .results
+        .iter()
+        .enumerate()
+        .filter(|(_, result)| !result.verify_integrity())
+        .map(|(i, result)| format!("{} ({})", i, result.level))
+        .collect();
+    let c_zero = !receipt.results.is_empty() && receipt.results.iter().all(|r| r.c_zero);
+    // The receipt hash covers only the results, so a written C=0 summary
+    // must agree with them
+    let summary_ok = !matches!(document.get("c_zero").and_then(Value::as_bool), Some(written) if written != c_zero);
+    let signature = trust.check(&receipt.receipt_hash, &receipt.signature, key_id(&document));
+
+    let checks = vec![
+        Check::new(
+            "results",
+            invalid.is_empty(),
+            format!("{} level result(s) match their hashes", receipt.results.len()),
+            format!("result(s) {} do not match their hashes", invalid.join(", ")),
+        ),
+        Check::new(
+            "hash",
+            receipt.verify_hash(),
+            "receipt hash matches the results",
+            "receipt hash does not match the results",
+        ),
+        Check::new(
+            "summary",
+            summary_ok,
+            "C = 0 summary matches the results",
+            "C = 0 summary disagrees with the results",
+        ),
+        Check::signature("signature", &signature),
+        Check::new("c_zero", c_zero, "C = 0 at every level", "C = 0 not established at every level"),
+    ];
+    Report::new(kind, path, checks)
+}
+
+fn key_id(document: &Value) -> Option<&str> {
+    document.get("key_id").and_then(Value::as_str)
+}
+
+/// The file at `path` as JSON and as `T` (`what`, for errors)
+pub(crate) fn read<T: serde::de::DeserializeOwned>(path: &Path, what: &str) -> Result<(Value, T), String> {
+    let body = std::fs::read(path).map_err(|e| e.to_string())?;
+    let document: Value = serde_json::from_slice(&body).map_err(|e| format!("not JSON: {}", e))?;
+    let parsed = T::deserialize(&document).map_err(|e| format!("not {}: {}", what, e))?;
+    Ok((document, parsed))
+}
@@ -0,0 +1,199 @@
+//! DSIF audit trails exported by AXIOM-S1
+//!
+//! AXIOM-S1 is a desktop application rather than a library, so the export
+//! format (`axiom-s1-dsif-trail/1`) is mirrored here: each entry carries
+//! the SHA-256 of its JSON, entries chain by `previous_hash`, the Merkle
+//! root is taken over the entry digests in order, and the chain head is
+//! the last entry's hash. Unlike the application's own check, every entry
+//! is checked, so a report lists all tampered or unchained entries.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::receipt::read;
+use crate::{ArtifactKind, Check, Report};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::path::Path;
+
+/// Export format this module understands
+pub const TRAIL_FORMAT: &str = "axiom-s1-dsif-trail/1";
+
+/// An exported trail
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedTrail {
+    pub format: String,
+    pub generated_at: String,
+    pub entries: Vec<ExportedEntry>,
+    pub merkle_root: String,
+    pub chain_head: Option<String>,
+}
+
+/// One entry and the digest recorded for it
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportedEntry {
+    pub digest: String,
+    pub entry: AuditEntry,
+}
+
+/// A DSIF audit entry
+///
+/// Fields are declared in the application's order so the entry serializes
+/// to the same JSON it was digested as; `phase` is kept as the name it was
+/// written under. A coalesced entry stands for `repeat_count` identical
+/// entries, the last written at `last_seen`; both are omitted otherwise.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AuditEntry {
+    pub id: String,
+    pub timestamp: String,
+    pub phase: String,
+    pub decision_id: Option<String>,
+    pub agent_id: Option<String>,
+    pub action: String,
+    pub result: String,
+    pub rationale: String,
+    pub hash: String,
+    pub previous_hash: Option<String>,
+    #[serde(default = "single", skip_serializing_if = "is_single")]
+    pub repeat_count: u64,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub last_seen: Option<String>,
+}
+
+fn single() -> u64 {
+    1
+}
+
+fn is_single(count: &u64) -> bool {
+    *count == 1
+}
+
+/// SHA-256 of `entry` serialized as JSON
+pub fn entry_digest(entry: &AuditEntry) -> String {
+    sha256(&serde_json::to_string(entry).unwrap_or_default())
+}
+
+/// Merkle root over `leaves`, as AXIOM-S1 computes it
+///
+/// An odd leaf count duplicates the last leaf; an odd node higher up is
+/// carried to the next level.
+pub fn merkle_root(leaves: &[String]) -> String {
+    let mut level = leaves.to_vec();
+    if level.is_empty() {
+        return sha256("");
+    }
+    if level.len() % 2 == 1 {
+        level.push(level[level.len() - 1].clone());
+    }
+
+    while level.len() > 1 {
+        level = level
+            .chunks(2)
+            .map(|pair| match pair {
+                [left, right] => sha256(&format!("{}{}", left, right)),
+                [single] => single.clone(),
+                _ => unreachable!("chunks(2) yields one or two nodes"),
+            })
+            .collect();
+    }
+    level.remove(0)
+}
+
+/// Verify the exported trail at `path`
+pub fn verify_trail(path: &Path) -> Report {
+    let kind = ArtifactKind::DsifTrail;
+    let trail = match read::<ExportedTrail>(path, "an exported DSIF trail") {
+        Ok((_, trail)) => trail,
+        Err(e) => return Report::unreadable(kind, path, e),
+    };
+
+    let mut tampered = Vec::new();
+    let mut unchained = Vec::new();
+    let mut previous: Option<&str> = None;
+    for (index, item) in trail.entries.iter().enumerate() {
+        if entry_digest(&item.entry) != item.digest {
+            tampered.push(index.to_string());
+        }
+        if item.entry.previous_hash.as_deref() != previous || item.entry.hash.is_empty() {
+            unchained.push(index.to_string());
+        }
+        previous = Some(&item.entry.hash);
+    }
+    let digests: Vec<String> = trail.entries.iter().map(|item| item.digest.clone()).collect();
+
+    let checks = vec![
+        Check::new(
+            "format",
+            trail.format == TRAIL_FORMAT,
+            TRAIL_FORMAT,
+            format!("unsupported format {}", trail.format),
+        ),
+        Check::new(
+            "entries",
+            tampered.is_empty(),
+            format!("{} entries match their digests{}", trail.entries.len(), coalesced(&trail)),
+            format!("entries {} do not match their digests", tampered.join(", ")),
+        ),
+        Check::new(
+            "chain",
+            unchained.is_empty(),
+            "every entry chains onto the one before it",
+            format!("entries {} do not chain onto the entry before them", unchained.join(", ")),
+        ),
+        Check::new(
+            "merkle_root",
+            merkle_root(&digests) == trail.merkle_root,
+            "Merkle root matches the entry digests",
+            "Merkle root does not match the entry digests",
+        ),
+        Check::new(
+            "chain_head",
+            trail.chain_head.as_deref() == previous,
+            "chain head is the last entry's hash",
+            "chain head does not match the last entry",
+        ),
+    ];
+    Report::new(kind, path, checks)
+}
+
+/// `" (n coalesced repeats)"` if any entry stands for several
+fn coalesced(trail: &ExportedTrail) -> String {
+    let repeats: u64 = trail.entries.iter().map(|item| item.entry.repeat_count.saturating_sub(1)).sum();
+    if repeats == 0 {
+        String::new()
+    } else {
+        format!(" ({} coalesced repeats)", repeats)
+    }
+}
+
+fn sha256(data: &str) -> String {
+    hex::encode(Sha256::digest(data.as_bytes()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn leaves(n: usize) -> Vec<String> {
+        (0..n).map(|i| sha256(&i.to_string())).collect()
+    }
+
+    #[test]
+    fn test_merkle_root_shapes() {
+        assert_eq!(merkle_root(&[]), sha256(""));
+
+        let two = leaves(2);
+        assert_eq!(merkle_root(&two), sha256(&format!("{}{}", two[0], two[1])));
+
+        // Three leaves: the last is duplicated
+        let three = leaves(3);
+        let left = sha256(&format!("{}{}", three[0], three[1]));
+        let right = sha256(&format!("{}{}", three[2], three[2]));
+        assert_eq!(merkle_root(&three), sha256(&format!("{}{}", left, right)));
+
+        // Six leaves: the third node of the second level is carried up
+        let six = leaves(6);
+        let pairs: Vec<String> = six.chunks(2).map(|p| sha256(&format!("{}{}", p[0], p[1]))).collect();
+        let top = sha256(&format!("{}{}", pairs[0], pairs[1]));
+        assert_eq!(merkle_root(&six), sha256(&format!("{}{}", top, pairs[2])));
+    }
+}
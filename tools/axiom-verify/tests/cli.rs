@@ -0,0 +1,78 @@
+//! CLI exit codes and report format
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use assert_cmd::Command;
+use predicates::prelude::*;
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn verify(args: &[&str], artifact: &str) -> assert_cmd::assert::Assert {
+    Command::cargo_bin("axiom-verify")
+        .unwrap()
+        .arg("--trust-anchors")
+        .arg(fixture("trust_anchors.txt"))
+        .args(args)
+        .arg(fixture(artifact))
+        .assert()
+}
+
+#[test]
+fn test_passing_artifacts_exit_zero() {
+    verify(&["receipt"], "receipt.json")
+        .success()
+        .stdout(predicate::str::starts_with("PASS receipt "))
+        .stdout(predicate::str::contains("  PASS       signature  signed by sha256:eb49105562f6c1fd\n"));
+    verify(&["audit-receipt"], "audit_receipt.json").success();
+    verify(&["dsif-trail"], "dsif_trail.json").success();
+    verify(&["--strict", "bundle"], "bundle_store")
+        .success()
+        .stdout(predicate::str::ends_with("2 of 2 passed\n"));
+}
+
+#[test]
+fn test_failing_artifacts_exit_one() {
+    for (command, artifact) in [
+        ("receipt", "receipt_tampered.json"),
+        ("receipt", "receipt_bad_signature.json"),
+        ("audit-receipt", "audit_receipt_tampered.json"),
+        ("bundle", "bundle_tampered.json"),
+        ("bundle", "bundle_forged_signature.json"),
+        ("dsif-trail", "dsif_trail_tampered.json"),
+        ("dsif-trail", "dsif_trail_broken_chain.json"),
+    ] {
+        verify(&[command], artifact)
+            .code(1)
+            .stdout(predicate::str::starts_with("FAIL "));
+    }
+}
+
+#[test]
+fn test_strict_fails_unverified_signatures() {
+    let unanchored = || {
+        let mut command = Command::cargo_bin("axiom-verify").unwrap();
+        command.arg("receipt").arg(fixture("receipt.json"));
+        command
+    };
+    unanchored()
+        .assert()
+        .success()
+        .stdout(predicate::str::contains("UNVERIFIED"));
+    unanchored().arg("--strict").assert().code(1);
+}
+
+#[test]
+fn test_bad_trust_anchors_exit_two() {
+    Command::cargo_bin("axiom-verify")
+        .unwrap()
+        .arg("--trust-anchors")
+        .arg(fixture("receipt.json"))
+        .arg("receipt")
+        .arg(fixture("receipt.json"))
+        .assert()
+        .code(2)
+        .stderr(predicate::str::contains("Invalid trust anchors file"));
+}
@@ -0,0 +1,128 @@
+//! Golden fixtures: valid artifacts pass, corrupted variants fail the
+//! check their corruption targets
+//!
+//! The fixtures are signed with fixed test keys listed in
+//! `tests/fixtures/trust_anchors.txt`: HMAC keys under the key ids the CLI
+//! and auditd record, and the Ed25519 keys of the bundle signers.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use axiom_verify::{bundle, receipt, trail, Outcome, Report, TrustAnchors};
+use std::path::PathBuf;
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn anchors() -> TrustAnchors {
+    TrustAnchors::load(&fixture("trust_anchors.txt")).unwrap()
+}
+
+fn outcome(report: &Report, check: &str) -> Outcome {
+    report.check(check).unwrap_or_else(|| panic!("no {} check in\n{}", check, report)).outcome
+}
+
+/// Every check except `failing` passes
+fn assert_only_fails(report: &Report, failing: &[&str]) {
+    for check in &report.checks {
+        let expected = if failing.contains(&check.name.as_str()) { Outcome::Fail } else { Outcome::Pass };
+        assert_eq!(check.outcome, expected, "{} in\n{}", check.name, report);
+    }
+}
+
+#[test]
+fn test_receipts() {
+    let trust = anchors();
+
+    let report = receipt::verify_receipt(&fixture("receipt.json"), &trust);
+    assert_only_fails(&report, &[]);
+    assert_eq!(report.check("signature").unwrap().detail, "signed by sha256:eb49105562f6c1fd");
+
+    // The signature still covers the recorded hash; the contents do not
+    let report = receipt::verify_receipt(&fixture("receipt_tampered.json"), &trust);
+    assert_only_fails(&report, &["hash"]);
+
+    let report = receipt::verify_receipt(&fixture("receipt_bad_signature.json"), &trust);
+    assert_only_fails(&report, &["signature"]);
+
+    // Without anchors the signature is unverified, not failed
+    let report = receipt::verify_receipt(&fixture("receipt.json"), &TrustAnchors::new());
+    assert_eq!(outcome(&report, "signature"), Outcome::Unverified);
+    assert!(report.passed(false));
+    assert!(!report.passed(true));
+}
+
+#[test]
+fn test_audit_receipts() {
+    let trust = anchors();
+
+    let report = receipt::verify_audit_receipt(&fixture("audit_receipt.json"), &trust);
+    assert_only_fails(&report, &[]);
+    assert_eq!(report.check("signature").unwrap().detail, "signed by sha256:ae42abb549cfbfd4");
+
+    let report = receipt::verify_audit_receipt(&fixture("audit_receipt_tampered.json"), &trust);
+    assert_only_fails(&report, &["results"]);
+    assert!(report.check("results").unwrap().detail.contains("1 (L2)"));
+}
+
+#[test]
+fn test_bundles() {
+    let trust = anchors();
+
+    let reports = bundle::verify_bundles(&fixture("bundle.json"), &trust);
+    assert_eq!(reports.len(), 1);
+    assert_only_fails(&reports[0], &[]);
+    assert_eq!(reports[0].check("signature[0]").unwrap().detail, "signed by did:key:system");
+    assert_eq!(reports[0].check("signature[1]").unwrap().detail, "signed by did:key:auditor");
+    assert_eq!(outcome(&reports[0], "test:answer"), Outcome::Pass);
+    assert_eq!(outcome(&reports[0], "test:determinism_check"), Outcome::Pass);
+
+    let report = bundle::verify_bundle(&fixture("bundle_tampered.json"), &trust);
+    assert_only_fails(&report, &["content_address"]);
+    assert!(report.check("test:answer").is_none());
+
+    let report = bundle::verify_bundle(&fixture("bundle_forged_signature.json"), &trust);
+    assert_only_fails(&report, &["signature[0]"]);
+
+    // An embedded key verifies the signature, but only an anchor trusts it
+    let report = bundle::verify_bundle(&fixture("bundle.json"), &TrustAnchors::new());
+    assert_eq!(outcome(&report, "signature[0]"), Outcome::Unverified);
+    assert_eq!(outcome(&report, "signature[1]"), Outcome::Unverified);
+}
+
+#[test]
+fn test_bundle_store_directory() {
+    let reports = bundle::verify_bundles(&fixture("bundle_store"), &anchors());
+    assert_eq!(reports.len(), 2);
+    for report in &reports {
+        assert!(report.passed(true), "{}", report);
+    }
+}
+
+#[test]
+fn test_dsif_trails() {
+    let report = trail::verify_trail(&fixture("dsif_trail.json"));
+    assert_only_fails(&report, &[]);
+
+    let report = trail::verify_trail(&fixture("dsif_trail_tampered.json"));
+    assert_only_fails(&report, &["entries"]);
+    assert_eq!(report.check("entries").unwrap().detail, "entries 2 do not match their digests");
+
+    let report = trail::verify_trail(&fixture("dsif_trail_broken_chain.json"));
+    assert_only_fails(&report, &["chain"]);
+
+    // A coalesced entry's count and last-seen time are part of its digest
+    let report = trail::verify_trail(&fixture("dsif_trail_coalesced.json"));
+    assert_only_fails(&report, &[]);
+    assert_eq!(report.check("entries").unwrap().detail, "7 entries match their digests (249 coalesced repeats)");
+}
+
+#[test]
+fn test_unreadable_artifacts() {
+    let report = receipt::verify_receipt(&fixture("dsif_trail.json"), &anchors());
+    assert_only_fails(&report, &["read"]);
+    assert!(report.check("read").unwrap().detail.starts_with("not a sap4d receipt"));
+
+    let report = trail::verify_trail(&fixture("missing.json"));
+    assert_only_fails(&report, &["read"]);
+}
@@ -0,0 +1,631 @@
+//! Configuration file support
+//!
+//! `hk.toml` holds top-level settings plus named `[profile.<name>]` tables.
+//! Values are merged as default < config < profile < flag, and every field
+//! records where its effective value came from.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::policy::ThreatPolicy;
+use crate::sweep::DEFAULT_MAX_FILE_SIZE;
+use crate::Severity;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use thiserror::Error;
+
+/// Configuration errors
+#[derive(Error, Debug)]
+pub enum ConfigError {
+    #[error("Cannot read {path}: {message}")]
+    Io { path: String, message: String },
+
+    #[error("Invalid configuration in {path}: {message}")]
+    Parse { path: String, message: String },
+
+    #[error("Unknown profile '{name}' (available: {available})")]
+    UnknownProfile { name: String, available: String },
+
+    #[error("Invalid pattern at {location}: {message}")]
+    InvalidPattern { location: String, message: String },
+
+    #[error("Invalid value for '{field}': {message}")]
+    InvalidValue { field: &'static str, message: String },
+}
+
+/// Output format
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum OutputFormat {
+    #[default]
+    Text,
+    Json,
+    /// One JSON object per detection
+    Jsonl,
+    /// SARIF 2.1.0 log (scan, scan-file and scan-dir only)
+    Sarif,
+}
+
+impl FromStr for OutputFormat {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "text" => Ok(OutputFormat::Text),
+            "json" => Ok(OutputFormat::Json),
+            "jsonl" => Ok(OutputFormat::Jsonl),
+            "sarif" => Ok(OutputFormat::Sarif),
+            other => Err(format!("unknown format '{}' (expected text, json, jsonl or sarif)", other)),
+        }
+    }
+}
+
+/// Parse a severity name (critical, high, medium, low)
+pub fn parse_severity(s: &str) -> Result<Severity, String> {
+    match s.to_ascii_lowercase().as_str() {
+        "critical" => Ok(Severity::Critical),
+        "high" => Ok(Severity::High),
+        "medium" => Ok(Severity::Medium),
+        "low" => Ok(Severity::Low),
+        other => Err(format!("unknown severity '{}'", other)),
+    }
+}
+
+/// Fully resolved configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct HunterKillerConfig {
+    /// Report format
+    pub format: OutputFormat,
+    /// Terminate the monitor on detection (exit 137)
+    pub kill: bool,
+    /// Monitor rolling window size in lines
+    pub window: usize,
+    /// Redact detections in the monitor passthrough stream
+    pub neutralize: bool,
+    /// Extra high-severity patterns, one regex per line
+    pub patterns_file: Option<PathBuf>,
+    /// Lowest severity counted toward the threat score
+    pub fail_on: Severity,
+    /// Threat score that kills, overriding the policy's
+    pub kill_threshold: Option<u32>,
+    /// Threat policy (weights and thresholds), TOML
+    pub policy_file: Option<PathBuf>,
+    /// Strip zero-width characters, apply NFKC and map homoglyphs before matching
+    pub normalize: bool,
+    /// Worker threads for monitor and scan-dir (default: per command)
+    pub jobs: Option<usize>,
+    /// scan-dir skips files larger than this many bytes
+    pub max_file_size: u64,
+    /// Neutralize by masking matched characters instead of replacing them
+    pub mask: bool,
+    /// Print monitor stats at end of input
+    pub summary: bool,
+    /// Serve monitor metrics on this localhost port
+    pub metrics_port: Option<u16>,
+    /// Phrases (case-insensitive) whose matches are not detections
+    pub allow_phrases: Vec<String>,
+}
+
+impl Default for HunterKillerConfig {
+    fn default() -> Self {
+        Self {
+            format: OutputFormat::Text,
+            kill: false,
+            window: 1,
+            neutralize: false,
+            patterns_file: None,
+            fail_on: Severity::Low,
+            kill_threshold: None,
+            policy_file: None,
+            normalize: true,
+            jobs: None,
+            max_file_size: DEFAULT_MAX_FILE_SIZE,
+            mask: false,
+            summary: false,
+            metrics_port: None,
+            allow_phrases: Vec::new(),
+        }
+    }
+}
+
+impl HunterKillerConfig {
+    /// Field names in display order
+    pub const FIELDS: &'static [&'static str] = &[
+        "format",
+        "kill",
+        "window",
+        "neutralize",
+        "patterns_file",
+        "fail_on",
+        "kill_threshold",
+        "policy_file",
+        "normalize",
+        "jobs",
+        "max_file_size",
+        "mask",
+        "summary",
+        "metrics_port",
+        "allow_phrases",
+    ];
+
+    /// Load `path`, applying `profile` if given (no CLI overrides)
+    pub fn load(path: &Path, profile: Option<&str>) -> Result<Self, ConfigError> {
+        let file = ConfigFile::load(path)?;
+        Ok(resolve(Some(&file), profile, ConfigOverlay::default())?.config)
+    }
+
+    /// Check cross-field constraints
+    pub fn validate(&self) -> Result<(), ConfigError> {
+        if self.window == 0 {
+            return Err(ConfigError::InvalidValue {
+                field: "window",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.jobs == Some(0) {
+            return Err(ConfigError::InvalidValue {
+                field: "jobs",
+                message: "must be at least 1".to_string(),
+            });
+        }
+        if self.kill && self.neutralize {
+            return Err(ConfigError::InvalidValue {
+                field: "neutralize",
+                message: "cannot be combined with kill".to_string(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Threat policy from `policy_file` (or the default) with `kill_threshold` applied
+    pub fn policy(&self) -> Result<ThreatPolicy, ConfigError> {
+        let mut policy = match self.policy_file {
+            Some(ref path) => ThreatPolicy::load(path)?,
+            None => ThreatPolicy::default(),
+        };
+        if let Some(threshold) = self.kill_threshold {
+            policy.kill_threshold = threshold;
+        }
+        policy.validate()?;
+        Ok(policy)
+    }
+}
+
+/// Partial configuration: a profile table or a set of CLI flags
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigOverlay {
+    pub format: Option<OutputFormat>,
+    pub kill: Option<bool>,
+    pub window: Option<usize>,
+    pub neutralize: Option<bool>,
+    pub patterns_file: Option<PathBuf>,
+    pub fail_on: Option<Severity>,
+    pub kill_threshold: Option<u32>,
+    pub policy_file: Option<PathBuf>,
+    pub normalize: Option<bool>,
+    pub jobs: Option<usize>,
+    pub max_file_size: Option<u64>,
+    pub mask: Option<bool>,
+    pub summary: Option<bool>,
+    pub metrics_port: Option<u16>,
+    pub allow_phrases: Option<Vec<String>>,
+}
+
+/// On-disk `hk.toml` layout
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct ConfigFile {
+    format: Option<OutputFormat>,
+    kill: Option<bool>,
+    window: Option<usize>,
+    neutralize: Option<bool>,
+    patterns_file: Option<PathBuf>,
+    fail_on: Option<Severity>,
+    kill_threshold: Option<u32>,
+    policy_file: Option<PathBuf>,
+    normalize: Option<bool>,
+    jobs: Option<usize>,
+    max_file_size: Option<u64>,
+    mask: Option<bool>,
+    summary: Option<bool>,
+    metrics_port: Option<u16>,
+    allow_phrases: Option<Vec<String>>,
+
+    /// Named profiles (`[profile.ci]`, `[profile.ingest]`)
+    #[serde(default)]
+    profile: BTreeMap<String, ConfigOverlay>,
+
+    #[serde(skip)]
+    path: Option<PathBuf>,
+}
+
+impl ConfigFile {
+    /// Read and parse a config file
+    pub fn load(path: &Path) -> Result<Self, ConfigError> {
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+            path: path.display().to_string(),
+            message: e.to_string(),
+        })?;
+        let mut file = Self::parse(&text, &path.display().to_string())?;
+
+        // Relative pattern and policy files resolve against the config file directory
+        let dir = path.parent().unwrap_or(Path::new(""));
+        for referenced in [&mut file.patterns_file, &mut file.policy_file]
+            .into_iter()
+            .chain(
+                file.profile
+                    .values_mut()
+                    .flat_map(|p| [&mut p.patterns_file, &mut p.policy_file]),
+            )
+            .flatten()
+        {
+            if referenced.is_relative() {
+                *referenced = dir.join(&*referenced);
+            }
+        }
+
+        file.path = Some(path.to_path_buf());
+        Ok(file)
+    }
+
+    /// Parse config text; `origin` names the source in errors
+    pub fn parse(text: &str, origin: &str) -> Result<Self, ConfigError> {
+        toml::from_str(text).map_err(|e| ConfigError::Parse {
+            path: origin.to_string(),
+            message: e.to_string(),
+        })
+    }
+
+    /// Profile names defined in the file
+    pub fn profiles(&self) -> impl Iterator<Item = &str> {
+        self.profile.keys().map(String::as_str)
+    }
+
+    /// Path the file was loaded from
+    pub fn path(&self) -> Option<&Path> {
+        self.path.as_deref()
+    }
+
+    fn base(&self) -> ConfigOverlay {
+        ConfigOverlay {
+            format: self.format,
+            kill: self.kill,
+            window: self.window,
+            neutralize: self.neutralize,
+            patterns_file: self.patterns_file.clone(),
+            fail_on: self.fail_on,
+            kill_threshold: self.kill_threshold,
+            policy_file: self.policy_file.clone(),
+            normalize: self.normalize,
+            jobs: self.jobs,
+            max_file_size: self.max_file_size,
+            mask: self.mask,
+            summary: self.summary,
+            metrics_port: self.metrics_port,
+            allow_phrases: self.allow_phrases.clone(),
+        }
+    }
+}
+
+/// Where an effective value came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Source {
+    Default,
+    Config,
+    Flag,
+}
+
+impl fmt::Display for Source {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Source::Default => "default",
+            Source::Config => "config",
+            Source::Flag => "flag",
+        })
+    }
+}
+
+/// Merged configuration with per-field provenance
+#[derive(Debug, Clone)]
+pub struct EffectiveConfig {
+    pub config: HunterKillerConfig,
+    pub provenance: BTreeMap<&'static str, Source>,
+    pub profile: Option<String>,
+}
+
+impl EffectiveConfig {
+    fn apply(&mut self, overlay: ConfigOverlay, source: Source) {
+        let c = &mut self.config;
+        let p = &mut self.provenance;
+        set(&mut c.format, overlay.format, "format", source, p);
+        set(&mut c.kill, overlay.kill, "kill", source, p);
+        set(&mut c.window, overlay.window, "window", source, p);
+        set(&mut c.neutralize, overlay.neutralize, "neutralize", source, p);
+        set(&mut c.patterns_file, overlay.patterns_file.map(Some), "patterns_file", source, p);
+        set(&mut c.fail_on, overlay.fail_on, "fail_on", source, p);
+        set(&mut c.kill_threshold, overlay.kill_threshold.map(Some), "kill_threshold", source, p);
+        set(&mut c.policy_file, overlay.policy_file.map(Some), "policy_file", source, p);
+        set(&mut c.normalize, overlay.normalize, "normalize", source, p);
+        set(&mut c.jobs, overlay.jobs.map(Some), "jobs", source, p);
+        set(&mut c.max_file_size, overlay.max_file_size, "max_file_size", source, p);
+        set(&mut c.mask, overlay.mask, "mask", source, p);
+        set(&mut c.summary, overlay.summary, "summary", source, p);
+        set(&mut c.metrics_port, overlay.metrics_port.map(Some), "metrics_port", source, p);
+        set(&mut c.allow_phrases, overlay.allow_phrases, "allow_phrases", source, p);
+    }
+
+    /// Source of a field's effective value
+    pub fn source(&self, field: &str) -> Source {
+        self.provenance.get(field).copied().unwrap_or(Source::Default)
+    }
+
+    /// (field, value, source) rows in display order
+    pub fn rows(&self) -> Vec<(&'static str, serde_json::Value, Source)> {
+        let values = serde_json::to_value(&self.config).unwrap_or_default();
+        HunterKillerConfig::FIELDS
+            .iter()
+            .map(|&field| (field, values[field].clone(), self.source(field)))
+            .collect()
+    }
+}
+
+fn set<T>(
+    slot: &mut T,
+    value: Option<T>,
+    field: &'static str,
+    source: Source,
+    provenance: &mut BTreeMap<&'static str, Source>,
+) {
+    if let Some(value) = value {
+        *slot = value;
+        provenance.insert(field, source);
+    }
+}
+
+/// Merge defaults, the config file, the selected profile and CLI flags
+pub fn resolve(
+    file: Option<&ConfigFile>,
+    profile: Option<&str>,
+    flags: ConfigOverlay,
+) -> Result<EffectiveConfig, ConfigError> {
+    let mut effective = EffectiveConfig {
+        config: HunterKillerConfig::default(),
+        provenance: BTreeMap::new(),
+        profile: profile.map(str::to_string),
+    };
+
+    if let Some(file) = file {
+        effective.apply(file.base(), Source::Config);
+    }
+
+    if let Some(name) = profile {
+        let selected = file.and_then(|f| f.profile.get(name)).ok_or_else(|| {
+            ConfigError::UnknownProfile {
+                name: name.to_string(),
+                available: file
+                    .map(|f| f.profiles().collect::<Vec<_>>().join(", "))
+                    .filter(|names| !names.is_empty())
+                    .unwrap_or_else(|| "none".to_string()),
+            }
+        })?;
+        effective.apply(selected.clone(), Source::Config);
+    }
+
+    effective.apply(flags, Source::Flag);
+    effective.config.validate()?;
+    Ok(effective)
+}
+
+/// Read extra patterns (one regex per line, `#` comments), checking each compiles
+pub fn load_patterns_file(path: &Path) -> Result<Vec<String>, ConfigError> {
+    let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io {
+        path: path.display().to_string(),
+        message: e.to_string(),
+    })?;
+
+    let mut patterns = Vec::new();
+    for (i, line) in text.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        regex::Regex::new(line).map_err(|e| ConfigError::InvalidPattern {
+            location: format!("{}:{}", path.display(), i + 1),
+            message: e.to_string(),
+        })?;
+        patterns.push(line.to_string());
+    }
+
+    Ok(patterns)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = r#"
+format = "json"
+window = 3
+
+[profile.ci]
+kill = true
+fail_on = "high"
+
+[profile.ingest]
+neutralize = true
+window = 8
+"#;
+
+    fn sample() -> ConfigFile {
+        ConfigFile::parse(SAMPLE, "hk.toml").unwrap()
+    }
+
+    #[test]
+    fn test_defaults_without_file() {
+        let effective = resolve(None, None, ConfigOverlay::default()).unwrap();
+        assert_eq!(effective.config, HunterKillerConfig::default());
+        assert!(HunterKillerConfig::FIELDS
+            .iter()
+            .all(|f| effective.source(f) == Source::Default));
+    }
+
+    #[test]
+    fn test_precedence() {
+        let flags = ConfigOverlay {
+            window: Some(5),
+            ..Default::default()
+        };
+        let effective = resolve(Some(&sample()), Some("ingest"), flags).unwrap();
+
+        // flag beats profile beats config beats default
+        assert_eq!(effective.config.window, 5);
+        assert_eq!(effective.source("window"), Source::Flag);
+        assert_eq!(effective.config.format, OutputFormat::Json);
+        assert_eq!(effective.source("format"), Source::Config);
+        assert!(effective.config.neutralize);
+        assert_eq!(effective.source("neutralize"), Source::Config);
+        assert_eq!(effective.config.fail_on, Severity::Low);
+        assert_eq!(effective.source("fail_on"), Source::Default);
+    }
+
+    #[test]
+    fn test_profile_selection() {
+        let file = sample();
+
+        let ci = resolve(Some(&file), Some("ci"), ConfigOverlay::default()).unwrap();
+        assert!(ci.config.kill);
+        assert_eq!(ci.config.fail_on, Severity::High);
+        assert_eq!(ci.config.window, 3);
+
+        let base = resolve(Some(&file), None, ConfigOverlay::default()).unwrap();
+        assert!(!base.config.kill);
+
+        let err = resolve(Some(&file), Some("nightly"), ConfigOverlay::default()).unwrap_err();
+        assert!(matches!(err, ConfigError::UnknownProfile { ref available, .. } if available == "ci, ingest"));
+    }
+
+    #[test]
+    fn test_unknown_key_rejected_with_location() {
+        let err = ConfigFile::parse("format = \"text\"\n\n[profile.ci]\nkil = true\n", "hk.toml")
+            .unwrap_err()
+            .to_string();
+        assert!(err.contains("hk.toml"));
+        assert!(err.contains("line 4"));
+        assert!(err.contains("unknown field `kil`"));
+
+        let err = ConfigFile::parse("windw = 2\n", "hk.toml").unwrap_err().to_string();
+        assert!(err.contains("line 1"));
+    }
+
+    /// `key` set to `config` at the top level, `profile` in `[profile.p]`,
+    /// and `flags`: (config only, with the profile, with the flags too)
+    fn layers(key: &str, config: &str, profile: &str, flags: ConfigOverlay) -> [EffectiveConfig; 3] {
+        let text = format!("{key} = {config}\n\n[profile.p]\n{key} = {profile}\n");
+        let file = ConfigFile::parse(&text, "hk.toml").unwrap();
+        [
+            resolve(Some(&file), None, ConfigOverlay::default()).unwrap(),
+            resolve(Some(&file), Some("p"), ConfigOverlay::default()).unwrap(),
+            resolve(Some(&file), Some("p"), flags).unwrap(),
+        ]
+    }
+
+    #[test]
+    fn test_jobs_precedence() {
+        assert_eq!(HunterKillerConfig::default().jobs, None);
+        let flags = ConfigOverlay {
+            jobs: Some(8),
+            ..Default::default()
+        };
+        let [config, profile, flag] = layers("jobs", "2", "4", flags);
+        assert_eq!((config.config.jobs, config.source("jobs")), (Some(2), Source::Config));
+        assert_eq!(profile.config.jobs, Some(4));
+        assert_eq!((flag.config.jobs, flag.source("jobs")), (Some(8), Source::Flag));
+    }
+
+    #[test]
+    fn test_max_file_size_precedence() {
+        assert_eq!(HunterKillerConfig::default().max_file_size, DEFAULT_MAX_FILE_SIZE);
+        let flags = ConfigOverlay {
+            max_file_size: Some(300),
+            ..Default::default()
+        };
+        let [config, profile, flag] = layers("max_file_size", "100", "200", flags);
+        assert_eq!((config.config.max_file_size, config.source("max_file_size")), (100, Source::Config));
+        assert_eq!(profile.config.max_file_size, 200);
+        assert_eq!((flag.config.max_file_size, flag.source("max_file_size")), (300, Source::Flag));
+    }
+
+    #[test]
+    fn test_mask_precedence() {
+        assert!(!HunterKillerConfig::default().mask);
+        let flags = ConfigOverlay {
+            mask: Some(true),
+            ..Default::default()
+        };
+        let [config, profile, flag] = layers("mask", "true", "false", flags);
+        assert_eq!((config.config.mask, config.source("mask")), (true, Source::Config));
+        assert!(!profile.config.mask);
+        assert_eq!((flag.config.mask, flag.source("mask")), (true, Source::Flag));
+    }
+
+    #[test]
+    fn test_summary_precedence() {
+        assert!(!HunterKillerConfig::default().summary);
+        let flags = ConfigOverlay {
+            summary: Some(true),
+            ..Default::default()
+        };
+        let [config, profile, flag] = layers("summary", "true", "false", flags);
+        assert_eq!((config.config.summary, config.source("summary")), (true, Source::Config));
+        assert!(!profile.config.summary);
+        assert_eq!((flag.config.summary, flag.source("summary")), (true, Source::Flag));
+    }
+
+    #[test]
+    fn test_metrics_port_precedence() {
+        assert_eq!(HunterKillerConfig::default().metrics_port, None);
+        let flags = ConfigOverlay {
+            metrics_port: Some(9102),
+            ..Default::default()
+        };
+        let [config, profile, flag] = layers("metrics_port", "9100", "9101", flags);
+        assert_eq!((config.config.metrics_port, config.source("metrics_port")), (Some(9100), Source::Config));
+        assert_eq!(profile.config.metrics_port, Some(9101));
+        assert_eq!((flag.config.metrics_port, flag.source("metrics_port")), (Some(9102), Source::Flag));
+    }
+
+    #[test]
+    fn test_allow_phrases_precedence() {
+        assert!(HunterKillerConfig::default().allow_phrases.is_empty());
+        let flags = ConfigOverlay {
+            allow_phrases: Some(vec!["jailbreak research".to_string()]),
+            ..Default::default()
+        };
+        // Later layers replace the list rather than extending it
+        let [config, profile, flag] = layers("allow_phrases", r#"["system prompt", "jailbreak"]"#, r#"["persona"]"#, flags);
+        assert_eq!(config.config.allow_phrases, ["system prompt", "jailbreak"]);
+        assert_eq!(config.source("allow_phrases"), Source::Config);
+        assert_eq!(profile.config.allow_phrases, ["persona"]);
+        assert_eq!(flag.config.allow_phrases, ["jailbreak research"]);
+        assert_eq!(flag.source("allow_phrases"), Source::Flag);
+    }
+
+    #[test]
+    fn test_zero_jobs_rejected() {
+        let file = ConfigFile::parse("jobs = 0\n", "hk.toml").unwrap();
+        let err = resolve(Some(&file), None, ConfigOverlay::default()).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidValue { field: "jobs", .. }));
+    }
+
+    #[test]
+    fn test_conflicting_values_rejected() {
+        let file = ConfigFile::parse("kill = true\nneutralize = true\n", "hk.toml").unwrap();
+        let err = resolve(Some(&file), None, ConfigOverlay::default()).unwrap_err();
+        assert!(matches!(err, ConfigError::InvalidValue { field: "neutralize", .. }));
+    }
+}
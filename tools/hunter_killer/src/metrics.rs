@@ -0,0 +1,224 @@
+//! Monitor counters and their Prometheus endpoint
+//!
+//! [`MonitorMetrics`] counts what `monitor` has processed: lines, bytes
+//! and detections by severity. [`serve`] answers `GET /metrics` with the
+//! counters in the Prometheus text format from a background thread, and
+//! [`MonitorMetrics::summary`] is the end-of-stream report.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use crate::Severity;
+use serde::Serialize;
+use std::fmt::Write as _;
+use std::io::{self, BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Severities in the order they are reported
+const SEVERITIES: [Severity; 4] = [Severity::Critical, Severity::High, Severity::Medium, Severity::Low];
+
+/// How long a scrape may take to send its request
+const REQUEST_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Counters shared between the monitor and the metrics endpoint
+#[derive(Debug)]
+pub struct MonitorMetrics {
+    started: Instant,
+    lines: AtomicU64,
+    bytes: AtomicU64,
+    /// Indexed like [`SEVERITIES`]
+    detections: [AtomicU64; 4],
+}
+
+impl MonitorMetrics {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            lines: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            detections: Default::default(),
+        }
+    }
+
+    /// Count one line of `len` bytes (plus its newline)
+    pub fn record_line(&self, len: usize) {
+        self.lines.fetch_add(1, Ordering::Relaxed);
+        self.bytes.fetch_add(len as u64 + 1, Ordering::Relaxed);
+    }
+
+    pub fn record_detection(&self, severity: Severity) {
+        let index = SEVERITIES.iter().position(|s| *s == severity).unwrap_or_default();
+        self.detections[index].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Totals so far
+    pub fn summary(&self) -> MonitorSummary {
+        let elapsed_secs = self.started.elapsed().as_secs_f64();
+        let bytes = self.bytes.load(Ordering::Relaxed);
+        MonitorSummary {
+            lines: self.lines.load(Ordering::Relaxed),
+            bytes,
+            detections: SeverityCounts {
+                critical: self.detections[0].load(Ordering::Relaxed),
+                high: self.detections[1].load(Ordering::Relaxed),
+                medium: self.detections[2].load(Ordering::Relaxed),
+                low: self.detections[3].load(Ordering::Relaxed),
+            },
+            elapsed_secs,
+            bytes_per_sec: if elapsed_secs > 0.0 { bytes as f64 / elapsed_secs } else { 0.0 },
+        }
+    }
+
+    /// The counters in the Prometheus text exposition format
+    pub fn render(&self) -> String {
+        let summary = self.summary();
+        let mut out = String::new();
+        let _ = writeln!(out, "# HELP hk_monitor_lines_total Lines read from the monitored stream.");
+        let _ = writeln!(out, "# TYPE hk_monitor_lines_total counter");
+        let _ = writeln!(out, "hk_monitor_lines_total {}", summary.lines);
+        let _ = writeln!(out, "# HELP hk_monitor_bytes_total Bytes read from the monitored stream.");
+        let _ = writeln!(out, "# TYPE hk_monitor_bytes_total counter");
+        let _ = writeln!(out, "hk_monitor_bytes_total {}", summary.bytes);
+        let _ = writeln!(out, "# HELP hk_monitor_detections_total Detections by severity.");
+        let _ = writeln!(out, "# TYPE hk_monitor_detections_total counter");
+        for (severity, count) in summary.detections.iter() {
+            let _ = writeln!(out, "hk_monitor_detections_total{{severity=\"{}\"}} {}", severity, count);
+        }
+        let _ = writeln!(out, "# HELP hk_monitor_bytes_per_second Average throughput since the monitor started.");
+        let _ = writeln!(out, "# TYPE hk_monitor_bytes_per_second gauge");
+        let _ = writeln!(out, "hk_monitor_bytes_per_second {:.3}", summary.bytes_per_sec);
+        out
+    }
+}
+
+impl Default for MonitorMetrics {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Detections per severity
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct SeverityCounts {
+    pub critical: u64,
+    pub high: u64,
+    pub medium: u64,
+    pub low: u64,
+}
+
+impl SeverityCounts {
+    /// `(label, count)` from most to least severe
+    pub fn iter(&self) -> impl Iterator<Item = (&'static str, u64)> {
+        [("critical", self.critical), ("high", self.high), ("medium", self.medium), ("low", self.low)].into_iter()
+    }
+
+    pub fn total(&self) -> u64 {
+        self.critical + self.high + self.medium + self.low
+    }
+}
+
+/// Aggregate stats for a monitored stream
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct MonitorSummary {
+    pub lines: u64,
+    pub bytes: u64,
+    pub detections: SeverityCounts,
+    pub elapsed_secs: f64,
+    pub bytes_per_sec: f64,
+}
+
+/// Serve `metrics` at `GET /metrics` on `addr` from a background thread
+///
+/// Returns the bound address (useful with port 0).
+pub fn serve(metrics: Arc<MonitorMetrics>, addr: SocketAddr) -> io::Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)?;
+    let local = listener.local_addr()?;
+    std::thread::Builder::new()
+        .name("hk-metrics".to_string())
+        .spawn(move || {
+            for stream in listener.incoming().flatten() {
+                if let Err(e) = respond(stream, &metrics) {
+                    tracing::debug!("Metrics request failed: {}", e);
+                }
+            }
+        })?;
+    Ok(local)
+}
+
+fn respond(mut stream: TcpStream, metrics: &MonitorMetrics) -> io::Result<()> {
+    stream.set_read_timeout(Some(REQUEST_TIMEOUT))?;
+    let mut reader = BufReader::new(&stream);
+    let mut request_line = String::new();
+    reader.read_line(&mut request_line)?;
+    // Headers are not needed, but must be read before the response
+    let mut header = String::new();
+    while reader.read_line(&mut header)? > 0 && !header.trim_end().is_empty() {
+        header.clear();
+    }
+
+    let mut parts = request_line.split_whitespace();
+    let (status, body) = match (parts.next(), parts.next()) {
+        (Some("GET"), Some("/metrics")) => ("200 OK", metrics.render()),
+        (Some("GET"), _) => ("404 Not Found", "Not found; metrics are at /metrics\n".to_string()),
+        _ => ("405 Method Not Allowed", "Only GET is supported\n".to_string()),
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: text/plain; version=0.0.4\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+
+    fn get(addr: SocketAddr, path: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\nHost: localhost\r\n\r\n", path).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+
+    #[test]
+    fn test_counts_and_renders() {
+        let metrics = MonitorMetrics::new();
+        metrics.record_line(9);
+        metrics.record_line(0);
+        metrics.record_detection(Severity::Critical);
+        metrics.record_detection(Severity::Medium);
+        metrics.record_detection(Severity::Medium);
+
+        let summary = metrics.summary();
+        assert_eq!((summary.lines, summary.bytes), (2, 11));
+        assert_eq!(summary.detections.medium, 2);
+        assert_eq!(summary.detections.total(), 3);
+
+        let text = metrics.render();
+        assert!(text.contains("hk_monitor_lines_total 2\n"));
+        assert!(text.contains("hk_monitor_bytes_total 11\n"));
+        assert!(text.contains("hk_monitor_detections_total{severity=\"critical\"} 1\n"));
+        assert!(text.contains("hk_monitor_detections_total{severity=\"low\"} 0\n"));
+        assert!(text.contains("# TYPE hk_monitor_bytes_per_second gauge"));
+    }
+
+    #[test]
+    fn test_endpoint_serves_metrics() {
+        let metrics = Arc::new(MonitorMetrics::new());
+        let addr = serve(metrics.clone(), "127.0.0.1:0".parse().unwrap()).unwrap();
+        metrics.record_line(4);
+
+        let response = get(addr, "/metrics");
+        assert!(response.starts_with("HTTP/1.1 200 OK"));
+        assert!(response.contains("hk_monitor_lines_total 1"));
+
+        assert!(get(addr, "/").starts_with("HTTP/1.1 404"));
+    }
+}
@@ -0,0 +1,172 @@
+//! Obfuscation-resistant normalization
+//!
+//! Before matching, content is rewritten character by character: invisible
+//! format characters (zero-width spaces and joiners, soft hyphens, bidi
+//! controls) are dropped, each remaining character is NFKC-normalized (so
+//! full-width and mathematical letters become ASCII), and the result is
+//! passed through a homoglyph table (Cyrillic `і` → `i`, Greek `ο` → `o`).
+//! Every normalized byte remembers the original character it came from, so
+//! match spans can be reported against the original content.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::ops::Range;
+use unicode_normalization::UnicodeNormalization;
+
+/// Invisible characters stripped before matching
+pub const INVISIBLE_CHARS: &[char] = &[
+    '\u{00AD}', // soft hyphen
+    '\u{034F}', // combining grapheme joiner
+    '\u{180E}', // mongolian vowel separator
+    '\u{200B}', '\u{200C}', '\u{200D}', '\u{200E}', '\u{200F}', // zero-width, LRM/RLM
+    '\u{202A}', '\u{202B}', '\u{202C}', '\u{202D}', '\u{202E}', // bidi embeddings
+    '\u{2060}', '\u{2061}', '\u{2062}', '\u{2063}', '\u{2064}', // word joiner, invisible operators
+    '\u{2066}', '\u{2067}', '\u{2068}', '\u{2069}', // bidi isolates
+    '\u{FEFF}', // zero-width no-break space
+];
+
+/// Default homoglyph table: Cyrillic and Greek letters that render like ASCII
+pub const DEFAULT_HOMOGLYPHS: &[(char, char)] = &[
+    // Cyrillic lowercase
+    ('а', 'a'), ('с', 'c'), ('ԁ', 'd'), ('е', 'e'), ('һ', 'h'), ('і', 'i'),
+    ('ј', 'j'), ('ӏ', 'l'), ('о', 'o'), ('р', 'p'), ('ԛ', 'q'), ('ѕ', 's'),
+    ('ԝ', 'w'), ('х', 'x'), ('у', 'y'),
+    // Cyrillic uppercase
+    ('А', 'A'), ('В', 'B'), ('С', 'C'), ('Е', 'E'), ('Н', 'H'), ('І', 'I'),
+    ('Ј', 'J'), ('К', 'K'), ('М', 'M'), ('О', 'O'), ('Р', 'P'), ('Ѕ', 'S'),
+    ('Т', 'T'), ('Х', 'X'), ('Ү', 'Y'),
+    // Greek
+    ('α', 'a'), ('ι', 'i'), ('ν', 'v'), ('ο', 'o'), ('ρ', 'p'), ('υ', 'u'),
+    ('Α', 'A'), ('Β', 'B'), ('Ε', 'E'), ('Η', 'H'), ('Ι', 'I'), ('Κ', 'K'),
+    ('Μ', 'M'), ('Ν', 'N'), ('Ο', 'O'), ('Ρ', 'P'), ('Τ', 'T'), ('Χ', 'X'),
+    ('Υ', 'Y'), ('Ζ', 'Z'),
+];
+
+/// Content rewritten for matching, with a map back to the original
+#[derive(Debug, Clone)]
+pub struct Normalized<'a> {
+    text: Cow<'a, str>,
+    /// Original byte range of the character each normalized byte came from;
+    /// `None` when the text was not rewritten
+    origin: Option<Vec<(usize, usize)>>,
+}
+
+impl<'a> Normalized<'a> {
+    /// Content used as-is
+    pub fn identity(content: &'a str) -> Self {
+        Self {
+            text: Cow::Borrowed(content),
+            origin: None,
+        }
+    }
+
+    /// Text to match against
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Map a byte range of the normalized text to the original content
+    pub fn original(&self, range: Range<usize>) -> Range<usize> {
+        let Some(ref origin) = self.origin else {
+            return range;
+        };
+        if origin.is_empty() {
+            return 0..0;
+        }
+
+        let start = origin[range.start.min(origin.len() - 1)].0;
+        let end = if range.end > range.start {
+            origin[range.end - 1].1
+        } else {
+            start
+        };
+        start..end
+    }
+}
+
+/// Strips invisible characters, applies NFKC and maps homoglyphs
+#[derive(Debug, Clone)]
+pub struct Normalizer {
+    homoglyphs: HashMap<char, char>,
+}
+
+impl Normalizer {
+    /// Normalizer with a custom homoglyph table
+    pub fn new(homoglyphs: impl IntoIterator<Item = (char, char)>) -> Self {
+        Self {
+            homoglyphs: homoglyphs.into_iter().collect(),
+        }
+    }
+
+    /// Add or replace one homoglyph mapping
+    pub fn with_homoglyph(mut self, from: char, to: char) -> Self {
+        self.homoglyphs.insert(from, to);
+        self
+    }
+
+    /// Rewrite `content` for matching
+    pub fn normalize<'a>(&self, content: &'a str) -> Normalized<'a> {
+        // ASCII is already NFKC; it only changes if the table maps ASCII
+        if content.is_ascii() && !self.homoglyphs.keys().any(char::is_ascii) {
+            return Normalized::identity(content);
+        }
+
+        let mut text = String::with_capacity(content.len());
+        let mut origin = Vec::with_capacity(content.len());
+
+        for (start, c) in content.char_indices() {
+            if INVISIBLE_CHARS.contains(&c) {
+                continue;
+            }
+            let end = start + c.len_utf8();
+
+            for folded in std::iter::once(c).nfkc() {
+                let mapped = self.homoglyphs.get(&folded).copied().unwrap_or(folded);
+                text.push(mapped);
+                origin.resize(text.len(), (start, end));
+            }
+        }
+
+        Normalized {
+            text: Cow::Owned(text),
+            origin: Some(origin),
+        }
+    }
+}
+
+impl Default for Normalizer {
+    fn default() -> Self {
+        Self::new(DEFAULT_HOMOGLYPHS.iter().copied())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ascii_is_borrowed() {
+        let normalized = Normalizer::default().normalize("plain text");
+        assert!(matches!(normalized.text, Cow::Borrowed(_)));
+        assert_eq!(normalized.original(2..5), 2..5);
+    }
+
+    #[test]
+    fn test_offsets_map_back() {
+        let content = "x \u{200B}ＩＧ\u{200B}і y";
+        let normalized = Normalizer::default().normalize(content);
+        assert_eq!(normalized.text(), "x IGi y");
+
+        let span = normalized.original(2..5);
+        assert_eq!(&content[span], "ＩＧ\u{200B}і");
+    }
+
+    #[test]
+    fn test_custom_homoglyphs() {
+        let normalizer = Normalizer::new(std::iter::empty()).with_homoglyph('0', 'o');
+        assert_eq!(normalizer.normalize("r00t").text(), "root");
+        assert_eq!(normalizer.normalize("і").text(), "і");
+    }
+}
@@ -0,0 +1,124 @@
+//! Redaction with an offset map back to the original content
+//!
+//! Overlapping matches are merged into one span; adjacent ones stay
+//! separate. Each [`Redaction`] records where its span lay in the original
+//! and how long its replacement is, so a caller can map positions in the
+//! neutralized text (e.g. DOM highlights) back to the source. In
+//! [`RedactionMode::Mask`] every matched character becomes `█`, so the
+//! character count is unchanged.
+//!
+//! Embedders with their own replacement text (Axiom S1 writes
+//! `[MEMETIC_HAZARD_REDACTED]`) pass it to [`apply`] in place of
+//! [`REDACTION`].
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use std::ops::Range;
+
+/// Default replacement text for redacted spans in [`RedactionMode::Replace`]
+pub const REDACTION: &str = "[REDACTED]";
+
+/// Character substituted for each matched character in [`RedactionMode::Mask`]
+pub const MASK: char = '█';
+
+/// How a redacted span is rewritten
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum RedactionMode {
+    /// Replace the span with the replacement text
+    #[default]
+    Replace,
+    /// Replace each character of the span with [`MASK`]
+    Mask,
+}
+
+/// One redacted span
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Redaction {
+    /// Byte offset of the span in the original content
+    pub orig_start: usize,
+    /// Byte offset just past the span in the original content
+    pub orig_end: usize,
+    /// Byte length of the replacement in the neutralized content
+    pub replaced_len: usize,
+    /// Rule id of the earliest match in the span
+    pub pattern_id: String,
+}
+
+/// Redact `matches` (byte range, rule id) from `content`, writing
+/// `replacement` over each span in [`RedactionMode::Replace`]
+///
+/// Matches starting at the same offset keep their given order, so the
+/// first one names a merged span.
+pub fn apply(
+    content: &str,
+    mut matches: Vec<(Range<usize>, String)>,
+    mode: RedactionMode,
+    replacement: &str,
+) -> (String, Vec<Redaction>) {
+    matches.sort_by_key(|(range, _)| range.start);
+
+    let mut spans: Vec<(Range<usize>, String)> = Vec::new();
+    for (range, id) in matches.into_iter().filter(|(range, _)| !range.is_empty()) {
+        match spans.last_mut() {
+            Some((last, _)) if range.start < last.end => last.end = last.end.max(range.end),
+            _ => spans.push((range, id)),
+        }
+    }
+
+    let mut out = String::with_capacity(content.len());
+    let mut redactions = Vec::with_capacity(spans.len());
+    let mut cursor = 0;
+    for (range, pattern_id) in spans {
+        out.push_str(&content[cursor..range.start]);
+        let before = out.len();
+        match mode {
+            RedactionMode::Replace => out.push_str(replacement),
+            RedactionMode::Mask => out.extend(content[range.clone()].chars().map(|_| MASK)),
+        }
+        redactions.push(Redaction {
+            orig_start: range.start,
+            orig_end: range.end,
+            replaced_len: out.len() - before,
+            pattern_id,
+        });
+        cursor = range.end;
+    }
+    out.push_str(&content[cursor..]);
+
+    (out, redactions)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn m(range: Range<usize>, id: &str) -> (Range<usize>, String) {
+        (range, id.to_string())
+    }
+
+    #[test]
+    fn test_chained_overlaps_merge() {
+        let matches = vec![m(4..7, "B"), m(1..5, "A"), m(6..9, "C")];
+        let (out, redactions) = apply("abcdefghij", matches.clone(), RedactionMode::Replace, REDACTION);
+        assert_eq!(out, "a[REDACTED]j");
+        assert_eq!(redactions.len(), 1);
+        assert_eq!((redactions[0].orig_start, redactions[0].orig_end), (1, 9));
+        assert_eq!(redactions[0].replaced_len, REDACTION.len());
+        assert_eq!(redactions[0].pattern_id, "A");
+
+        // An embedder's replacement text is written and measured instead
+        let (out, redactions) = apply("abcdefghij", matches, RedactionMode::Replace, "[HAZARD]");
+        assert_eq!(out, "a[HAZARD]j");
+        assert_eq!(redactions[0].replaced_len, "[HAZARD]".len());
+    }
+
+    #[test]
+    fn test_mask_preserves_characters() {
+        let (out, redactions) = apply("héllo", vec![m(0..3, "A")], RedactionMode::Mask, REDACTION);
+        assert_eq!(out, "██llo");
+        assert_eq!(out.chars().count(), "héllo".chars().count());
+        assert_eq!(redactions[0].replaced_len, 2 * MASK.len_utf8());
+    }
+}
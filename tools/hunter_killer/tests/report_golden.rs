@@ -0,0 +1,71 @@
+//! Golden files for the SARIF and JSON Lines reports
+//!
+//! Set `UPDATE_GOLDEN=1` to rewrite the expected files after an intended
+//! change to the report format.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use hunter_killer::{report, Detection, HunterKiller};
+use serde_json::Value;
+use std::path::PathBuf;
+
+const SAMPLE: &str = "injection_sample.txt";
+
+fn fixture(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures").join(name)
+}
+
+fn scan_sample() -> (HunterKiller, String, Vec<Detection>) {
+    let hk = HunterKiller::new();
+    let content = std::fs::read_to_string(fixture(SAMPLE)).unwrap();
+    let detections = hk.scan_lines(&content);
+    (hk, content, detections)
+}
+
+/// Compare `actual` with the golden file, or rewrite it under `UPDATE_GOLDEN`
+fn check_golden(name: &str, actual: &str, parse: impl Fn(&str) -> Vec<Value>) {
+    let path = fixture(name);
+    if std::env::var_os("UPDATE_GOLDEN").is_some() {
+        std::fs::write(&path, actual).unwrap();
+        return;
+    }
+    let expected = std::fs::read_to_string(&path).unwrap();
+    assert_eq!(parse(actual), parse(&expected), "{} differs from golden output:\n{}", name, actual);
+}
+
+#[test]
+fn sarif_matches_golden() {
+    let (hk, content, detections) = scan_sample();
+    let log = report::sarif(&hk, Some(SAMPLE), &content, &detections);
+    let actual = serde_json::to_string_pretty(&log).unwrap() + "\n";
+
+    check_golden("injection_sample.sarif", &actual, |text| vec![serde_json::from_str(text).unwrap()]);
+}
+
+#[test]
+fn jsonl_matches_golden() {
+    let (_, content, detections) = scan_sample();
+    let actual: String = detections
+        .iter()
+        .map(|d| report::jsonl_record(Some(SAMPLE), &content, d).to_string() + "\n")
+        .collect();
+
+    check_golden("injection_sample.jsonl", &actual, |text| {
+        text.lines().map(|line| serde_json::from_str(line).unwrap()).collect()
+    });
+}
+
+#[test]
+fn sarif_regions_point_at_matches() {
+    let (hk, content, detections) = scan_sample();
+    let log = report::sarif(&hk, Some(SAMPLE), &content, &detections);
+    let lines: Vec<&str> = content.lines().collect();
+
+    for result in log["runs"][0]["results"].as_array().unwrap() {
+        let region = &result["locations"][0]["physicalLocation"]["region"];
+        let line = lines[region["startLine"].as_u64().unwrap() as usize - 1];
+        let start = region["startColumn"].as_u64().unwrap() as usize - 1;
+        let end = region["endColumn"].as_u64().unwrap() as usize - 1;
+        assert_eq!(&line[start..end], region["snippet"]["text"].as_str().unwrap());
+    }
+}
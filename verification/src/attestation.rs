@@ -1,9 +1,20 @@
 //! Attestation and signature system
 //!
+//! A bundle attestation signs the bundle's `content_address`. In an
+//! [`AttestationChain`] the root signs a caller-chosen subject (usually that
+//! content address) and every later link signs the digest of the attestation
+//! before it, so a chain verifies only if every signature and every link holds.
+//!
+//! An embedded public key only proves the signature matches that key. To
+//! trust the signer, resolve its key through [`TrustedKeys`] instead.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use serde::{Deserialize, Serialize};
+use base64::{engine::general_purpose::STANDARD, Engine as _};
 use chrono::{DateTime, Utc};
+use ring::signature::{Ed25519KeyPair, KeyPair, UnparsedPublicKey, ED25519};
+use sap4d::hashing::FieldHasher;
+use serde::{Deserialize, Serialize};
 
 /// Attestation - Signed statement about an artifact
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -23,6 +34,297 @@ pub struct Attestation {
     
     /// Optional statement/claim
     pub statement: Option<String>,
+    
+    /// Signature algorithm
+    #[serde(default)]
+    pub algorithm: SignatureAlgorithm,
+    
+    /// Signer public key (base64); makes the signature self-verifying
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub public_key: Option<String>,
+    
+    /// Key identifier, resolved by the verifier when no public key is embedded
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key_id: Option<String>,
+}
+
+impl Attestation {
+    /// Attestation by `signer` whose signature is still empty
+    pub fn unsigned(signer: &dyn Signer, timestamp: DateTime<Utc>) -> Self {
+        Self {
+            signer_id: signer.signer_id().to_string(),
+            signature: String::new(),
+            timestamp,
+            role: signer.role(),
+            statement: None,
+            algorithm: signer.algorithm(),
+            public_key: Some(STANDARD.encode(signer.public_key())),
+            key_id: signer.key_id().map(str::to_string),
+        }
+    }
+    
+    /// Attestation by `signer` over `message`
+    pub fn signed(signer: &dyn Signer, message: &str, timestamp: DateTime<Utc>) -> Self {
+        let mut attestation = Self::unsigned(signer, timestamp);
+        attestation.sign(signer, message);
+        attestation
+    }
+    
+    /// Attach a statement
+    pub fn with_statement(mut self, statement: impl Into<String>) -> Self {
+        self.statement = Some(statement.into());
+        self
+    }
+    
+    /// Replace the signature with `signer`'s signature over `message`
+    pub fn sign(&mut self, signer: &dyn Signer, message: &str) {
+        self.signature = STANDARD.encode(signer.sign(message.as_bytes()));
+    }
+    
+    /// Check the signature over `message` against the embedded public key
+    ///
+    /// This says nothing about who holds that key; see [`Self::verify_trusted`].
+    pub fn verify(&self, message: &str) -> Result<(), AttestationError> {
+        let signer = || self.signer_id.clone();
+        let public_key = self
+            .public_key
+            .as_deref()
+            .ok_or_else(|| AttestationError::MissingPublicKey(signer()))?;
+        let public_key = STANDARD
+            .decode(public_key)
+            .map_err(|_| AttestationError::MalformedPublicKey(signer()))?;
+        self.verify_with_key(message, &public_key)
+    }
+    
+    /// Check the signature over `message` against the key `trusted` holds
+    /// for this attestation's key ID (or signer ID)
+    ///
+    /// An embedded public key must be that same key.
+    pub fn verify_trusted(&self, message: &str, trusted: &TrustedKeys) -> Result<(), AttestationError> {
+        let key_id = self.resolved_key_id();
+        let public_key = trusted
+            .get(key_id)
+            .ok_or_else(|| AttestationError::UntrustedKey(key_id.to_string()))?;
+        if let Some(ref embedded) = self.public_key {
+            let embedded = STANDARD
+                .decode(embedded)
+                .map_err(|_| AttestationError::MalformedPublicKey(self.signer_id.clone()))?;
+            if embedded != public_key {
+                return Err(AttestationError::UntrustedKey(key_id.to_string()));
+            }
+        }
+        self.verify_with_key(message, public_key)
+    }
+    
+    /// Name the signing key is resolved under: the key ID, else the signer ID
+    pub fn resolved_key_id(&self) -> &str {
+        self.key_id.as_deref().unwrap_or(&self.signer_id)
+    }
+    
+    fn verify_with_key(&self, message: &str, public_key: &[u8]) -> Result<(), AttestationError> {
+        let signature = STANDARD
+            .decode(&self.signature)
+            .map_err(|_| AttestationError::MalformedSignature(self.signer_id.clone()))?;
+        
+        match self.algorithm {
+            SignatureAlgorithm::Ed25519 => UnparsedPublicKey::new(&ED25519, public_key)
+                .verify(message.as_bytes(), &signature)
+                .map_err(|_| AttestationError::InvalidSignature(self.signer_id.clone())),
+        }
+    }
+}
+
+/// Public keys of trusted signers, by key ID (or signer ID)
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct TrustedKeys {
+    keys: std::collections::BTreeMap<String, Vec<u8>>,
+}
+
+impl TrustedKeys {
+    /// Empty registry: no attestation is trusted
+    pub fn new() -> Self {
+        Self::default()
+    }
+    
+    /// Trust `public_key` (raw bytes) under `key_id`
+    pub fn with_key(mut self, key_id: impl Into<String>, public_key: impl Into<Vec<u8>>) -> Self {
+        self.keys.insert(key_id.into(), public_key.into());
+        self
+    }
+    
+    /// Trust `signer`'s key under its key ID, or its signer ID if it has none
+    pub fn with_signer(self, signer: &dyn Signer) -> Self {
+        let key_id = signer.key_id().unwrap_or(signer.signer_id()).to_string();
+        self.with_key(key_id, signer.public_key())
+    }
+    
+    /// Trusted public key registered under `key_id`
+    pub fn get(&self, key_id: &str) -> Option<&[u8]> {
+        self.keys.get(key_id).map(Vec::as_slice)
+    }
+    
+    /// Whether any key is registered under `key_id`
+    pub fn contains(&self, key_id: &str) -> bool {
+        self.keys.contains_key(key_id)
+    }
+    
+    pub fn len(&self) -> usize {
+        self.keys.len()
+    }
+    
+    pub fn is_empty(&self) -> bool {
+        self.keys.is_empty()
+    }
+}
+
+/// Signature algorithm
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SignatureAlgorithm {
+    /// Ed25519 (RFC 8032)
+    #[default]
+    Ed25519,
+}
+
+/// Produces attestation signatures
+pub trait Signer {
+    /// Signer identity recorded in attestations
+    fn signer_id(&self) -> &str;
+    
+    /// Role recorded in attestations
+    fn role(&self) -> SignerRole;
+    
+    /// Algorithm of the signatures produced
+    fn algorithm(&self) -> SignatureAlgorithm;
+    
+    /// Raw public key bytes
+    fn public_key(&self) -> Vec<u8>;
+    
+    /// Key identifier, if the key is registered under one
+    fn key_id(&self) -> Option<&str> {
+        None
+    }
+    
+    /// Sign `message`, returning the raw signature bytes
+    fn sign(&self, message: &[u8]) -> Vec<u8>;
+}
+
+/// Ed25519 signer
+pub struct Ed25519Signer {
+    signer_id: String,
+    role: SignerRole,
+    key_pair: Ed25519KeyPair,
+    key_id: Option<String>,
+}
+
+impl Ed25519Signer {
+    /// Signer from a 32-byte private key seed
+    pub fn from_seed(
+        signer_id: impl Into<String>,
+        role: SignerRole,
+        seed: &[u8],
+    ) -> Result<Self, AttestationError> {
+        let key_pair = Ed25519KeyPair::from_seed_unchecked(seed)
+            .map_err(|e| AttestationError::InvalidKey(e.to_string()))?;
+        Ok(Self::with_key_pair(signer_id.into(), role, key_pair))
+    }
+    
+    /// Signer from a PKCS#8 v2 document
+    pub fn from_pkcs8(
+        signer_id: impl Into<String>,
+        role: SignerRole,
+        pkcs8: &[u8],
+    ) -> Result<Self, AttestationError> {
+        let key_pair = Ed25519KeyPair::from_pkcs8(pkcs8)
+            .map_err(|e| AttestationError::InvalidKey(e.to_string()))?;
+        Ok(Self::with_key_pair(signer_id.into(), role, key_pair))
+    }
+    
+    /// Record a key identifier in attestations
+    pub fn with_key_id(mut self, key_id: impl Into<String>) -> Self {
+        self.key_id = Some(key_id.into());
+        self
+    }
+    
+    fn with_key_pair(signer_id: String, role: SignerRole, key_pair: Ed25519KeyPair) -> Self {
+        Self {
+            signer_id,
+            role,
+            key_pair,
+            key_id: None,
+        }
+    }
+}
+
+impl Signer for Ed25519Signer {
+    fn signer_id(&self) -> &str {
+        &self.signer_id
+    }
+    
+    fn role(&self) -> SignerRole {
+        self.role
+    }
+    
+    fn algorithm(&self) -> SignatureAlgorithm {
+        SignatureAlgorithm::Ed25519
+    }
+    
+    fn public_key(&self) -> Vec<u8> {
+        self.key_pair.public_key().as_ref().to_vec()
+    }
+    
+    fn key_id(&self) -> Option<&str> {
+        self.key_id.as_deref()
+    }
+    
+    fn sign(&self, message: &[u8]) -> Vec<u8> {
+        self.key_pair.sign(message).as_ref().to_vec()
+    }
+}
+
+impl std::fmt::Debug for Ed25519Signer {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Ed25519Signer")
+            .field("signer_id", &self.signer_id)
+            .field("role", &self.role)
+            .field("key_id", &self.key_id)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Attestation errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum AttestationError {
+    #[error("Invalid signing key: {0}")]
+    InvalidKey(String),
+    
+    #[error("Attestation from {0} carries no public key")]
+    MissingPublicKey(String),
+    
+    #[error("Attestation from {0} has a malformed public key")]
+    MalformedPublicKey(String),
+    
+    #[error("Attestation from {0} has a malformed signature")]
+    MalformedSignature(String),
+    
+    #[error("Signature from {0} does not verify")]
+    InvalidSignature(String),
+    
+    #[error("Key {0} is not a trusted signing key")]
+    UntrustedKey(String),
+    
+    #[error("Chain link {link} ({signer_id}) does not reference the previous attestation's digest")]
+    BrokenLink { link: usize, signer_id: String },
+    
+    #[error("Chain link {link} ({signer_id}) hash does not match its attestation")]
+    LinkHashMismatch { link: usize, signer_id: String },
+    
+    #[error("Chain link {link}: {source}")]
+    LinkSignature {
+        link: usize,
+        #[source]
+        source: Box<AttestationError>,
+    },
 }
 
 /// Signer role
@@ -72,13 +374,28 @@ impl AttestationChain {
         }
     }
     
+    /// Start a chain whose root `signer` signs `subject`
+    pub fn signed(signer: &dyn Signer, subject: &str, timestamp: DateTime<Utc>) -> Self {
+        Self::new(Attestation::signed(signer, subject, timestamp))
+    }
+    
+    /// Digest the next link must sign
+    pub fn head_hash(&self) -> String {
+        match self.entries.last() {
+            Some(last) => last.hash.clone(),
+            None => Self::hash_attestation(&self.root),
+        }
+    }
+    
+    /// Append an attestation by `signer` over the previous attestation's digest
+    pub fn append_signed(&mut self, signer: &dyn Signer, timestamp: DateTime<Utc>) {
+        let attestation = Attestation::signed(signer, &self.head_hash(), timestamp);
+        self.append(attestation);
+    }
+    
     /// Append an attestation to the chain
     pub fn append(&mut self, attestation: Attestation) {
-        let previous_hash = if let Some(last) = self.entries.last() {
-            last.hash.clone()
-        } else {
-            Self::hash_attestation(&self.root)
-        };
+        let previous_hash = self.head_hash();
         
         let entry_hash = Self::hash_attestation(&attestation);
         
@@ -109,19 +426,48 @@ impl AttestationChain {
         true
     }
     
-    /// Hash an attestation
-    fn hash_attestation(attestation: &Attestation) -> String {
-        use sha2::{Digest, Sha256};
-        
-        let mut hasher = Sha256::new();
-        hasher.update(attestation.signer_id.as_bytes());
-        hasher.update(attestation.timestamp.to_rfc3339().as_bytes());
-        hasher.update(format!("{:?}", attestation.role).as_bytes());
-        if let Some(ref stmt) = attestation.statement {
-            hasher.update(stmt.as_bytes());
+    /// Verify links and signatures: the root (link 0) must sign `subject`
+    /// and link `n` must sign the digest of link `n - 1`
+    pub fn verify(&self, subject: &str) -> Result<(), AttestationError> {
+        self.root.verify(subject).map_err(|e| AttestationError::LinkSignature {
+            link: 0,
+            source: Box::new(e),
+        })?;
+        
+        let mut prev_hash = Self::hash_attestation(&self.root);
+        
+        for (index, entry) in self.entries.iter().enumerate() {
+            let link = index + 1;
+            let signer_id = || entry.attestation.signer_id.clone();
+            
+            if entry.previous_hash != prev_hash {
+                return Err(AttestationError::BrokenLink { link, signer_id: signer_id() });
+            }
+            if entry.hash != Self::hash_attestation(&entry.attestation) {
+                return Err(AttestationError::LinkHashMismatch { link, signer_id: signer_id() });
+            }
+            entry.attestation.verify(&prev_hash).map_err(|e| AttestationError::LinkSignature {
+                link,
+                source: Box::new(e),
+            })?;
+            
+            prev_hash = entry.hash.clone();
         }
         
-        hex::encode(hasher.finalize())
+        Ok(())
+    }
+    
+    /// Hash an attestation's fields, each length-prefixed; absent optional
+    /// fields hash as empty lists
+    fn hash_attestation(attestation: &Attestation) -> String {
+        FieldHasher::new("verification.attestation")
+            .str(&attestation.signer_id)
+            .str(&attestation.timestamp.to_rfc3339())
+            .str(&format!("{:?}", attestation.role))
+            .list(attestation.statement.as_slice())
+            .list(attestation.public_key.as_slice())
+            .list(attestation.key_id.as_slice())
+            .finish()
     }
 }
 
@@ -137,6 +483,9 @@ mod tests {
             timestamp: Utc::now(),
             role: SignerRole::System,
             statement: None,
+            algorithm: SignatureAlgorithm::Ed25519,
+            public_key: None,
+            key_id: None,
         };
         
         let mut chain = AttestationChain::new(root);
@@ -147,11 +496,101 @@ mod tests {
             timestamp: Utc::now(),
             role: SignerRole::Approver,
             statement: Some("Approved".to_string()),
+            algorithm: SignatureAlgorithm::Ed25519,
+            public_key: None,
+            key_id: None,
         };
         
         chain.append(entry);
         
         assert!(chain.verify_integrity());
     }
+    
+    #[test]
+    fn test_attestation_hash_keeps_field_boundaries() {
+        let system = signer("did:key:system", SignerRole::System, 1);
+        let base = Attestation::signed(&system, "hash://sha256/abc", Utc::now());
+        let with = |statement: Option<&str>, key_id: Option<&str>| {
+            let mut attestation = base.clone();
+            attestation.statement = statement.map(str::to_string);
+            attestation.key_id = key_id.map(str::to_string);
+            AttestationChain::hash_attestation(&attestation)
+        };
+        
+        // Text moved between neighbouring fields, or an empty field, changes the digest
+        assert_ne!(with(Some("ab"), Some("c")), with(Some("a"), Some("bc")));
+        assert_ne!(with(Some("ab"), None), with(None, Some("ab")));
+        assert_ne!(with(Some(""), None), with(None, None));
+    }
+    
+    fn signer(signer_id: &str, role: SignerRole, seed: u8) -> Ed25519Signer {
+        Ed25519Signer::from_seed(signer_id, role, &[seed; 32]).unwrap()
+    }
+    
+    #[test]
+    fn test_signed_attestation_verifies() {
+        let system = signer("did:key:system", SignerRole::System, 1);
+        let attestation = Attestation::signed(&system, "hash://sha256/abc", Utc::now());
+        
+        assert!(attestation.verify("hash://sha256/abc").is_ok());
+        assert_eq!(
+            attestation.verify("hash://sha256/abd"),
+            Err(AttestationError::InvalidSignature("did:key:system".to_string()))
+        );
+    }
+    
+    #[test]
+    fn test_signed_chain_names_broken_link() {
+        let system = signer("did:key:system", SignerRole::System, 1);
+        let approver = signer("did:key:approver", SignerRole::Approver, 2);
+        let auditor = signer("did:key:auditor", SignerRole::Auditor, 3);
+        
+        let mut chain = AttestationChain::signed(&system, "hash://sha256/abc", Utc::now());
+        chain.append_signed(&approver, Utc::now());
+        chain.append_signed(&auditor, Utc::now());
+        assert!(chain.verify("hash://sha256/abc").is_ok());
+        
+        // Link 2 re-signs something other than link 1's digest
+        chain.entries[1].attestation.sign(&auditor, "forged");
+        match chain.verify("hash://sha256/abc") {
+            Err(AttestationError::LinkSignature { link, source }) => {
+                assert_eq!(link, 2);
+                assert_eq!(*source, AttestationError::InvalidSignature("did:key:auditor".to_string()));
+            }
+            other => panic!("unexpected result: {:?}", other),
+        }
+    }
+    
+    #[test]
+    fn test_verify_trusted_rejects_self_signed_key() {
+        let system = signer("did:key:system", SignerRole::System, 1);
+        let impostor = signer("did:key:system", SignerRole::System, 2);
+        let trusted = TrustedKeys::new().with_signer(&system);
+        
+        let attestation = Attestation::signed(&system, "hash://sha256/abc", Utc::now());
+        assert_eq!(attestation.verify_trusted("hash://sha256/abc", &trusted), Ok(()));
+        
+        // Verifies against its own embedded key, but that key is not the trusted one
+        let forged = Attestation::signed(&impostor, "hash://sha256/abc", Utc::now());
+        assert!(forged.verify("hash://sha256/abc").is_ok());
+        assert_eq!(
+            forged.verify_trusted("hash://sha256/abc", &trusted),
+            Err(AttestationError::UntrustedKey("did:key:system".to_string()))
+        );
+        
+        // Without an embedded key the signature is still checked against the trusted key
+        let mut stripped = forged.clone();
+        stripped.public_key = None;
+        assert_eq!(
+            stripped.verify_trusted("hash://sha256/abc", &trusted),
+            Err(AttestationError::InvalidSignature("did:key:system".to_string()))
+        );
+        
+        let unknown = Attestation::signed(&signer("did:key:other", SignerRole::Auditor, 3), "x", Utc::now());
+        assert_eq!(
+            unknown.verify_trusted("x", &trusted),
+            Err(AttestationError::UntrustedKey("did:key:other".to_string()))
+        );
+    }
 }
 
@@ -2,12 +2,15 @@
 //!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use chrono::Utc;
+use chrono::{DateTime, Utc};
 use crate::{
     bundle::{VerificationBundle, ExecutionTrace, ExecutionStep, TraceArtifact, VerificationTest, TestType, Tolerance, OutputArtifact},
     provenance::{Provenance, DataProvenance, ModelMetadata, EnvironmentManifest},
-    attestation::Attestation,
-    deterministic::DeterministicConfig,
+    attestation::{Attestation, Signer},
+    capture::{CaptureError, EnvironmentCapture},
+    deterministic::{DeterministicConfig, ReplayOutcome},
+    numeric::NumericData,
+    store::{BundleStore, StoreError},
     BUNDLE_VERSION,
 };
 
@@ -16,12 +19,15 @@ pub struct ProofArtifactBuilder {
     inputs: Vec<DataProvenance>,
     model: Option<ModelMetadata>,
     environment: Option<EnvironmentManifest>,
+    capture: Option<EnvironmentCapture>,
     config: Option<DeterministicConfig>,
     execution_steps: Vec<ExecutionStep>,
     trace_artifacts: Vec<TraceArtifact>,
     tests: Vec<VerificationTest>,
     outputs: Vec<OutputArtifact>,
     signatures: Vec<Attestation>,
+    signers: Vec<Box<dyn Signer>>,
+    timestamp: Option<DateTime<Utc>>,
 }
 
 impl ProofArtifactBuilder {
@@ -31,12 +37,15 @@ impl ProofArtifactBuilder {
             inputs: Vec::new(),
             model: None,
             environment: None,
+            capture: None,
             config: None,
             execution_steps: Vec::new(),
             trace_artifacts: Vec::new(),
             tests: Vec::new(),
             outputs: Vec::new(),
             signatures: Vec::new(),
+            signers: Vec::new(),
+            timestamp: None,
         }
     }
     
@@ -55,6 +64,14 @@ impl ProofArtifactBuilder {
     /// Set environment manifest
     pub fn with_environment(mut self, env: EnvironmentManifest) -> Self {
         self.environment = Some(env);
+        self.capture = None;
+        self
+    }
+    
+    /// Capture the environment manifest from the host when the bundle is built
+    pub fn with_captured_environment(mut self, capture: EnvironmentCapture) -> Self {
+        self.capture = Some(capture);
+        self.environment = None;
         self
     }
     
@@ -64,12 +81,18 @@ impl ProofArtifactBuilder {
         self
     }
     
+    /// Fix the bundle creation time and later step timestamps (reproducible bundles)
+    pub fn with_timestamp(mut self, timestamp: DateTime<Utc>) -> Self {
+        self.timestamp = Some(timestamp);
+        self
+    }
+    
     /// Add execution step
     pub fn add_execution_step(mut self, name: impl Into<String>, hash: impl Into<String>) -> Self {
         self.execution_steps.push(ExecutionStep {
             name: name.into(),
             hash: hash.into(),
-            timestamp: Some(Utc::now()),
+            timestamp: Some(self.timestamp.unwrap_or_else(Utc::now)),
         });
         self
     }
@@ -103,6 +126,27 @@ impl ProofArtifactBuilder {
             test_type,
             expected_output_hash: expected_hash.into(),
             tolerance,
+            expected: None,
+            extra: Default::default(),
+        });
+        self
+    }
+    
+    /// Add a test that compares outputs against expected values
+    pub fn add_numeric_test(
+        mut self,
+        name: impl Into<String>,
+        expected_hash: impl Into<String>,
+        expected: NumericData,
+        tolerance: Tolerance,
+    ) -> Self {
+        self.tests.push(VerificationTest {
+            name: name.into(),
+            test_type: TestType::Replay,
+            expected_output_hash: expected_hash.into(),
+            tolerance,
+            expected: Some(expected),
+            extra: Default::default(),
         });
         self
     }
@@ -119,21 +163,51 @@ impl ProofArtifactBuilder {
             hash: hash.into(),
             uri: uri.into(),
             mime_type: None,
+            data: None,
+            extra: Default::default(),
         });
         self
     }
     
+    /// Add an output artifact embedding its numeric values
+    pub fn add_numeric_output(mut self, name: impl Into<String>, values: Vec<f64>) -> Self {
+        let content = serde_json::to_vec(&values).unwrap_or_default();
+        let mut artifact = crate::executor::output_artifact(name, &content);
+        artifact.mime_type = Some("application/json".to_string());
+        artifact.data = Some(NumericData::Inline(values));
+        self.outputs.push(artifact);
+        self
+    }
+    
+    /// Add a replay harness's output and test, and its configuration if none is set
+    pub fn add_replay(mut self, outcome: ReplayOutcome) -> Self {
+        self.config.get_or_insert(outcome.config);
+        self.outputs.push(outcome.output);
+        self.tests.push(outcome.test);
+        self
+    }
+    
     /// Add attestation/signature
     pub fn add_signature(mut self, signature: Attestation) -> Self {
         self.signatures.push(signature);
         self
     }
     
+    /// Sign the built bundle's content address with `signer`
+    pub fn sign_with(mut self, signer: impl Signer + 'static) -> Self {
+        self.signers.push(Box::new(signer));
+        self
+    }
+    
     /// Build the verification bundle
     pub fn build(self) -> Result<VerificationBundle, BuilderError> {
         // Validate required fields
         let model = self.model.ok_or(BuilderError::MissingModel)?;
-        let environment = self.environment.ok_or(BuilderError::MissingEnvironment)?;
+        let environment = match (self.environment, self.capture) {
+            (Some(env), _) => env,
+            (None, Some(capture)) => capture.capture()?,
+            (None, None) => return Err(BuilderError::MissingEnvironment),
+        };
         let config = self.config.ok_or(BuilderError::MissingConfig)?;
         
         let provenance = Provenance {
@@ -141,6 +215,7 @@ impl ProofArtifactBuilder {
             model,
             environment,
             config,
+            extra: Default::default(),
         };
         
         let execution_trace = if self.execution_steps.is_empty() && self.trace_artifacts.is_empty() {
@@ -152,23 +227,45 @@ impl ProofArtifactBuilder {
             })
         };
         
-        let created_at = Utc::now();
+        let created_at = self.timestamp.unwrap_or_else(Utc::now);
+        
+        // Signer identities are part of the content address; signature
+        // values are not, so each signer can sign the final address
+        let mut signatures = self.signatures;
+        let first_signed = signatures.len();
+        signatures.extend(
+            self.signers
+                .iter()
+                .map(|signer| Attestation::unsigned(signer.as_ref(), created_at)),
+        );
         
         // Create bundle
         let mut bundle = VerificationBundle {
             bundle_version: BUNDLE_VERSION.to_string(),
             content_address: String::new(), // Will be computed
             created_at,
-            signatures: self.signatures,
+            signatures,
             provenance,
             execution_trace,
             tests: self.tests,
             outputs: self.outputs,
+            extra: Default::default(),
         };
         
         // Compute content address
         bundle.content_address = bundle.compute_content_address();
         
+        for (attestation, signer) in bundle.signatures[first_signed..].iter_mut().zip(&self.signers) {
+            attestation.sign(signer.as_ref(), &bundle.content_address);
+        }
+        
+        Ok(bundle)
+    }
+    
+    /// Build the bundle and put it in `store` under its content address
+    pub fn build_and_store(self, store: &dyn BundleStore) -> Result<VerificationBundle, BuilderError> {
+        let bundle = self.build()?;
+        store.put(&bundle)?;
         Ok(bundle)
     }
 }
@@ -193,6 +290,12 @@ pub enum BuilderError {
     
     #[error("Serialization error: {0}")]
     Serialization(#[from] serde_json::Error),
+    
+    #[error("Bundle store error: {0}")]
+    Store(#[from] StoreError),
+    
+    #[error("Environment capture error: {0}")]
+    Capture(#[from] CaptureError),
 }
 
 #[cfg(test)]
@@ -208,6 +311,7 @@ mod tests {
             weights_hash: "sha256:abc".to_string(),
             tokenizer_hash: "sha256:def".to_string(),
             card_uri: None,
+            extra: Default::default(),
         };
         
         let env = EnvironmentManifest {
@@ -219,6 +323,7 @@ mod tests {
                 hash: "sha256:torch".to_string(),
             }],
             hardware: None,
+            extra: Default::default(),
         };
         
         let config = DeterministicConfig {
@@ -243,5 +348,82 @@ mod tests {
         assert_eq!(bundle.bundle_version, BUNDLE_VERSION);
         assert!(!bundle.content_address.is_empty());
     }
+    
+    #[test]
+    fn test_sign_with_signs_content_address() {
+        use crate::attestation::{Ed25519Signer, SignerRole};
+        
+        let signer = Ed25519Signer::from_seed("did:key:system", SignerRole::System, &[7; 32]).unwrap();
+        let bundle = ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test-model".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_environment(EnvironmentManifest {
+                container_image_hash: "sha256:xyz".to_string(),
+                os: "ubuntu:22.04".to_string(),
+                deps: vec![],
+                hardware: None,
+                extra: Default::default(),
+            })
+            .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+            .sign_with(signer)
+            .build()
+            .unwrap();
+        
+        assert!(bundle.verify_integrity());
+        assert_eq!(bundle.signatures.len(), 1);
+        assert!(bundle.signatures[0].verify(&bundle.content_address).is_ok());
+    }
+    
+    #[test]
+    fn test_with_captured_environment() {
+        use crate::capture::{SystemInfo, SystemInfoProvider};
+        
+        struct Fixed;
+        impl SystemInfoProvider for Fixed {
+            fn system_info(&self) -> SystemInfo {
+                SystemInfo {
+                    os_name: "debian".to_string(),
+                    os_version: "12".to_string(),
+                    cpu_arch: "aarch64".to_string(),
+                    cpu_model: None,
+                    memory_bytes: Some(8 << 30),
+                }
+            }
+        }
+        
+        let bundle = ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test-model".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_captured_environment(EnvironmentCapture::new().with_provider(Fixed))
+            .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+            .build()
+            .unwrap();
+        
+        let env = &bundle.provenance.environment;
+        assert_eq!(env.os, "debian:12");
+        assert_eq!(env.hardware.as_ref().unwrap().cpu, "aarch64");
+        assert!(bundle.verify_integrity());
+        
+        let missing = ProofArtifactBuilder::new()
+            .with_model(bundle.provenance.model.clone())
+            .with_captured_environment(
+                EnvironmentCapture::new().with_provider(Fixed).with_lockfile("/nonexistent/Cargo.lock"),
+            )
+            .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+            .build();
+        assert!(matches!(missing, Err(BuilderError::Capture(_))));
+    }
 }
 
@@ -4,8 +4,13 @@
 
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use std::collections::BTreeMap;
 use crate::provenance::Provenance;
 use crate::attestation::Attestation;
+use crate::numeric::{NanPolicy, NumericData};
+
+/// Unrecognized fields, kept so re-serializing does not strip them
+pub type UnknownFields = BTreeMap<String, serde_json::Value>;
 
 /// Verification Bundle - Enables independent replay and validation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -37,6 +42,10 @@ pub struct VerificationBundle {
     
     /// Output artifacts
     pub outputs: Vec<OutputArtifact>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Execution trace with intermediate steps
@@ -94,6 +103,14 @@ pub struct VerificationTest {
     
     /// Tolerance specification
     pub tolerance: Tolerance,
+    
+    /// Expected values for float-tolerance comparison
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expected: Option<NumericData>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Test type
@@ -125,6 +142,9 @@ pub enum Tolerance {
         relative: f64,
         /// Absolute tolerance
         absolute: f64,
+        /// NaN handling
+        #[serde(default)]
+        nan: NanPolicy,
     },
     
     /// Hash-based (content address match)
@@ -147,13 +167,27 @@ pub struct OutputArtifact {
     /// MIME type
     #[serde(rename = "mime_type")]
     pub mime_type: Option<String>,
+    
+    /// Inline numeric payload, used instead of resolving `uri`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub data: Option<NumericData>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 impl VerificationBundle {
     /// Compute content address from bundle contents
+    ///
+    /// Provenance is hashed in its original serde encoding unless it carries
+    /// unknown fields, so bundles issued before those were preserved keep
+    /// their address. With unknown fields it is hashed in canonical form,
+    /// so a bundle round-tripped through an older verifier keeps its
+    /// address. Bundle- and output-level fields added by later schema
+    /// versions must be hashed the same way unknown fields are here.
     pub fn compute_content_address(&self) -> String {
         use sha2::{Digest, Sha256};
-        use serde_json;
         
         let mut hasher = Sha256::new();
         
@@ -162,13 +196,19 @@ impl VerificationBundle {
         hasher.update(self.created_at.to_rfc3339().as_bytes());
         
         // Hash provenance
-        if let Ok(prov_json) = serde_json::to_string(&self.provenance) {
+        let prov_json = if self.provenance.has_unknown_fields() {
+            canonical_json(&self.provenance)
+        } else {
+            serde_json::to_string(&self.provenance)
+        };
+        if let Ok(prov_json) = prov_json {
             hasher.update(prov_json.as_bytes());
         }
         
         // Hash outputs
         for output in &self.outputs {
             hasher.update(output.hash.as_bytes());
+            hash_unknown(&mut hasher, &output.extra);
         }
         
         // Hash signatures (without signature values themselves)
@@ -177,9 +217,36 @@ impl VerificationBundle {
             hasher.update(sig.timestamp.to_rfc3339().as_bytes());
         }
         
+        hash_unknown(&mut hasher, &self.extra);
+        
         format!("hash://sha256/{}", hex::encode(hasher.finalize()))
     }
     
+    /// Paths of fields this version does not interpret
+    pub fn unknown_fields(&self) -> Vec<String> {
+        let mut fields = Vec::new();
+        let mut collect = |prefix: &str, extra: &UnknownFields| {
+            fields.extend(extra.keys().map(|key| format!("{}{}", prefix, key)));
+        };
+        
+        collect("", &self.extra);
+        let provenance = &self.provenance;
+        collect("provenance.", &provenance.extra);
+        for (i, input) in provenance.inputs.iter().enumerate() {
+            collect(&format!("provenance.inputs[{}].", i), &input.extra);
+        }
+        collect("provenance.model.", &provenance.model.extra);
+        collect("provenance.environment.", &provenance.environment.extra);
+        for (i, test) in self.tests.iter().enumerate() {
+            collect(&format!("tests[{}].", i), &test.extra);
+        }
+        for (i, output) in self.outputs.iter().enumerate() {
+            collect(&format!("outputs[{}].", i), &output.extra);
+        }
+        
+        fields
+    }
+    
     /// Verify bundle integrity
     pub fn verify_integrity(&self) -> bool {
         self.content_address == self.compute_content_address()
@@ -196,6 +263,23 @@ impl VerificationBundle {
     }
 }
 
+/// Serialize with object keys sorted, independent of struct field order
+pub fn canonical_json<T: Serialize>(value: &T) -> Result<String, serde_json::Error> {
+    // serde_json's Map is ordered by key unless `preserve_order` is enabled
+    serde_json::to_string(&serde_json::to_value(value)?)
+}
+
+/// Hash unknown fields; absent fields add nothing so older addresses hold
+fn hash_unknown(hasher: &mut sha2::Sha256, extra: &UnknownFields) {
+    use sha2::Digest;
+    
+    if !extra.is_empty() {
+        if let Ok(json) = canonical_json(extra) {
+            hasher.update(json.as_bytes());
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -216,26 +300,125 @@ mod tests {
                     weights_hash: "sha256:abc".to_string(),
                     tokenizer_hash: "sha256:def".to_string(),
                     card_uri: None,
+                    extra: Default::default(),
                 },
                 environment: EnvironmentManifest {
                     container_image_hash: "sha256:xyz".to_string(),
                     os: "ubuntu:22.04".to_string(),
                     deps: vec![],
                     hardware: None,
+                    extra: Default::default(),
                 },
                 config: crate::deterministic::DeterministicConfig {
                     seed: 42,
                     parameters: Default::default(),
                 },
+                extra: Default::default(),
             },
             execution_trace: None,
             tests: vec![],
             outputs: vec![],
+            extra: Default::default(),
         };
         
         let addr = bundle.compute_content_address();
         assert!(!addr.is_empty());
         assert!(addr.starts_with("hash://sha256/"));
     }
+    
+    #[test]
+    fn test_known_provenance_keeps_original_address() {
+        use sha2::{Digest, Sha256};
+        
+        let mut bundle = VerificationBundle::from_json(FUTURE_BUNDLE).unwrap();
+        bundle.extra.clear();
+        bundle.provenance.extra.clear();
+        bundle.provenance.model.extra.clear();
+        bundle.outputs[0].extra.clear();
+        
+        // Address as computed before unknown fields were preserved
+        let mut hasher = Sha256::new();
+        hasher.update(bundle.bundle_version.as_bytes());
+        hasher.update(bundle.created_at.to_rfc3339().as_bytes());
+        hasher.update(serde_json::to_string(&bundle.provenance).unwrap().as_bytes());
+        hasher.update(bundle.outputs[0].hash.as_bytes());
+        let original = format!("hash://sha256/{}", hex::encode(hasher.finalize()));
+        
+        // Field order differs from key order, so canonical hashing would move it
+        assert_ne!(
+            serde_json::to_string(&bundle.provenance).unwrap(),
+            canonical_json(&bundle.provenance).unwrap()
+        );
+        assert_eq!(bundle.compute_content_address(), original);
+    }
+    
+    /// A bundle written by a newer schema version, with fields this version lacks
+    const FUTURE_BUNDLE: &str = r#"{
+        "bundle_version": "1.1.0",
+        "content_address": "",
+        "created_at": "2025-12-04T12:15:00Z",
+        "signatures": [],
+        "provenance": {
+            "inputs": [],
+            "model": {
+                "name": "test-model",
+                "version": "1.0.0",
+                "weights_hash": "sha256:abc",
+                "tokenizer_hash": "sha256:def",
+                "card_uri": null,
+                "quantization": {"bits": 4, "scheme": "nf4"}
+            },
+            "environment": {
+                "container_image_hash": "sha256:xyz",
+                "os": "ubuntu:22.04",
+                "deps": [],
+                "hardware": null
+            },
+            "config": {"seed": 42, "parameters": {"temperature": 0.0, "top_p": 1.0, "top_k": null, "max_tokens": 1024}},
+            "lineage_root": "hash://sha256/root"
+        },
+        "execution_trace": null,
+        "tests": [{
+            "name": "replay",
+            "type": "replay",
+            "expected_output_hash": "sha256:out",
+            "tolerance": {"type": "exact"},
+            "timeout_ms": 5000
+        }],
+        "outputs": [{
+            "name": "replay",
+            "hash": "sha256:out",
+            "uri": "hash://sha256/out",
+            "mime_type": null,
+            "encoding": "utf-8"
+        }],
+        "transparency_log": {"index": 7, "log_id": "axiom-log"}
+    }"#;
+    
+    #[test]
+    fn test_unknown_fields_survive_round_trip() {
+        let mut original: serde_json::Value = serde_json::from_str(FUTURE_BUNDLE).unwrap();
+        let mut bundle = VerificationBundle::from_json(FUTURE_BUNDLE).unwrap();
+        bundle.content_address = bundle.compute_content_address();
+        original["content_address"] = bundle.content_address.clone().into();
+        
+        assert_eq!(bundle.unknown_fields(), vec![
+            "transparency_log",
+            "provenance.lineage_root",
+            "provenance.model.quantization",
+            "tests[0].timeout_ms",
+            "outputs[0].encoding",
+        ]);
+        
+        let reserialized: serde_json::Value = serde_json::from_str(&bundle.to_json().unwrap()).unwrap();
+        assert_eq!(reserialized, original);
+        
+        let reparsed = VerificationBundle::from_json(&bundle.to_json().unwrap()).unwrap();
+        assert!(reparsed.verify_integrity());
+        
+        // Dropping an unknown field changes the address
+        let mut stripped = reparsed.clone();
+        stripped.extra.clear();
+        assert_ne!(stripped.compute_content_address(), reparsed.content_address);
+    }
 }
-
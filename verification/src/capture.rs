@@ -0,0 +1,364 @@
+//! Environment capture - populates an EnvironmentManifest from the running host
+//!
+//! System details come from a [`SystemInfoProvider`] and GPU details from a
+//! [`GpuDetector`], so tests (and hosts this module cannot read) can inject
+//! their own. Output is normalized so identical hardware yields an identical
+//! manifest: whitespace is collapsed and lockfile entries are sorted.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use sha2::{Digest, Sha256};
+use crate::provenance::{Dependency, EnvironmentManifest, HardwareProfile};
+
+/// Version recorded for dependencies captured from lockfiles
+pub const LOCKFILE_VERSION: &str = "lockfile";
+
+/// Host details reported by a [`SystemInfoProvider`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SystemInfo {
+    /// OS name (e.g. `ubuntu`)
+    pub os_name: String,
+
+    /// OS version (e.g. `22.04`)
+    pub os_version: String,
+
+    /// CPU architecture (e.g. `x86_64`)
+    pub cpu_arch: String,
+
+    /// CPU model name, if known
+    pub cpu_model: Option<String>,
+
+    /// Total physical memory in bytes, if known
+    pub memory_bytes: Option<u64>,
+}
+
+/// Source of host details
+pub trait SystemInfoProvider {
+    /// Describe the current system
+    fn system_info(&self) -> SystemInfo;
+}
+
+/// GPU details reported by a [`GpuDetector`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GpuInfo {
+    /// GPU model name
+    pub name: String,
+
+    /// Driver version
+    pub driver: Option<String>,
+
+    /// CUDA version (if applicable)
+    pub cuda: Option<String>,
+}
+
+/// Source of GPU details; `None` means no GPU was found
+pub trait GpuDetector {
+    /// Detect the GPU in use
+    fn detect(&self) -> Option<GpuInfo>;
+}
+
+impl<F: Fn() -> Option<GpuInfo>> GpuDetector for F {
+    fn detect(&self) -> Option<GpuInfo> {
+        self()
+    }
+}
+
+/// Detector that never reports a GPU (the default, needing no vendor tooling)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct NoGpu;
+
+impl GpuDetector for NoGpu {
+    fn detect(&self) -> Option<GpuInfo> {
+        None
+    }
+}
+
+/// Reads host details from the OS (`/etc/os-release` and `/proc` on Linux)
+#[derive(Debug, Clone, Copy, Default)]
+pub struct HostSystemInfo;
+
+impl SystemInfoProvider for HostSystemInfo {
+    fn system_info(&self) -> SystemInfo {
+        let release = fs::read_to_string("/etc/os-release").unwrap_or_default();
+        let os_name = key_value(&release, "ID", '=')
+            .unwrap_or_else(|| std::env::consts::OS.to_string());
+        let os_version = key_value(&release, "VERSION_ID", '=')
+            .or_else(|| fs::read_to_string("/proc/sys/kernel/osrelease").ok())
+            .unwrap_or_else(|| "unknown".to_string());
+
+        let cpuinfo = fs::read_to_string("/proc/cpuinfo").unwrap_or_default();
+        let cpu_model = ["model name", "Hardware", "Processor", "cpu"]
+            .iter()
+            .find_map(|key| key_value(&cpuinfo, key, ':'));
+
+        let memory_bytes = fs::read_to_string("/proc/meminfo")
+            .ok()
+            .and_then(|meminfo| key_value(&meminfo, "MemTotal", ':'))
+            .and_then(|total| total.trim_end_matches("kB").trim().parse::<u64>().ok())
+            .map(|kib| kib * 1024);
+
+        SystemInfo {
+            os_name,
+            os_version,
+            cpu_arch: std::env::consts::ARCH.to_string(),
+            cpu_model,
+            memory_bytes,
+        }
+    }
+}
+
+/// First non-empty value for `key` in `key<sep>value` lines, unquoted
+fn key_value(text: &str, key: &str, sep: char) -> Option<String> {
+    text.lines()
+        .filter_map(|line| line.split_once(sep))
+        .find(|(k, _)| k.trim() == key)
+        .map(|(_, v)| v.trim().trim_matches('"').to_string())
+        .filter(|v| !v.is_empty())
+}
+
+/// Collapse runs of whitespace so formatting quirks don't change the manifest
+fn normalize(value: &str) -> String {
+    value.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+/// Configurable capture of an [`EnvironmentManifest`]
+pub struct EnvironmentCapture {
+    provider: Box<dyn SystemInfoProvider>,
+    gpu: Box<dyn GpuDetector>,
+    lockfiles: Vec<PathBuf>,
+    container_image_hash: String,
+}
+
+impl EnvironmentCapture {
+    /// Capture from the host, without GPU detection or lockfiles
+    pub fn new() -> Self {
+        Self {
+            provider: Box::new(HostSystemInfo),
+            gpu: Box::new(NoGpu),
+            lockfiles: Vec::new(),
+            container_image_hash: String::new(),
+        }
+    }
+
+    /// Read system details from `provider` instead of the host
+    pub fn with_provider(mut self, provider: impl SystemInfoProvider + 'static) -> Self {
+        self.provider = Box::new(provider);
+        self
+    }
+
+    /// Detect the GPU with `detector`
+    pub fn with_gpu_detector(mut self, detector: impl GpuDetector + 'static) -> Self {
+        self.gpu = Box::new(detector);
+        self
+    }
+
+    /// Record the hash of a dependency lockfile (`Cargo.lock`, `requirements.txt`, ...)
+    pub fn with_lockfile(mut self, path: impl Into<PathBuf>) -> Self {
+        self.lockfiles.push(path.into());
+        self
+    }
+
+    /// Record the container image the capture runs in
+    pub fn with_container_image(mut self, hash: impl Into<String>) -> Self {
+        self.container_image_hash = hash.into();
+        self
+    }
+
+    /// Build the manifest
+    pub fn capture(&self) -> Result<EnvironmentManifest, CaptureError> {
+        let info = self.provider.system_info();
+
+        let mut deps = self
+            .lockfiles
+            .iter()
+            .map(|path| lockfile_dependency(path))
+            .collect::<Result<Vec<_>, _>>()?;
+        deps.sort_by(|a, b| a.name.cmp(&b.name));
+        deps.dedup_by(|a, b| a.name == b.name);
+
+        let arch = normalize(&info.cpu_arch);
+        let cpu = match info.cpu_model.as_deref().map(normalize) {
+            Some(model) if !model.is_empty() => format!("{} ({})", model, arch),
+            _ => arch,
+        };
+        let gpu = self.gpu.detect();
+
+        Ok(EnvironmentManifest {
+            container_image_hash: self.container_image_hash.clone(),
+            os: format!("{}:{}", normalize(&info.os_name), normalize(&info.os_version)),
+            deps,
+            hardware: Some(HardwareProfile {
+                cpu,
+                gpu: gpu.as_ref().map(|g| normalize(&g.name)),
+                driver: gpu.as_ref().and_then(|g| g.driver.as_deref()).map(normalize),
+                cuda: gpu.as_ref().and_then(|g| g.cuda.as_deref()).map(normalize),
+                memory_bytes: info.memory_bytes,
+            }),
+            extra: Default::default(),
+        })
+    }
+}
+
+impl Default for EnvironmentCapture {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Hash a lockfile into a dependency named by its path
+fn lockfile_dependency(path: &Path) -> Result<Dependency, CaptureError> {
+    let content = fs::read(path).map_err(|source| CaptureError::Lockfile {
+        path: path.to_path_buf(),
+        source,
+    })?;
+
+    // Forward slashes keep the name stable across platforms
+    let name = path.to_string_lossy().replace('\\', "/");
+
+    Ok(Dependency {
+        name,
+        version: LOCKFILE_VERSION.to_string(),
+        hash: format!("sha256:{}", hex::encode(Sha256::digest(&content))),
+    })
+}
+
+impl EnvironmentManifest {
+    /// Capture the host environment (no GPU detection, no lockfiles)
+    pub fn capture_current() -> Result<Self, CaptureError> {
+        EnvironmentCapture::new().capture()
+    }
+
+    /// SHA-256 of the canonical JSON form, as `sha256:<hex>`
+    pub fn canonical_hash(&self) -> String {
+        let json = crate::bundle::canonical_json(self).unwrap_or_default();
+        format!("sha256:{}", hex::encode(Sha256::digest(json.as_bytes())))
+    }
+}
+
+/// Capture errors
+#[derive(Debug, thiserror::Error)]
+pub enum CaptureError {
+    #[error("Cannot read lockfile {}: {source}", .path.display())]
+    Lockfile {
+        path: PathBuf,
+        source: std::io::Error,
+    },
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct FakeSystem;
+
+    impl SystemInfoProvider for FakeSystem {
+        fn system_info(&self) -> SystemInfo {
+            SystemInfo {
+                os_name: "ubuntu".to_string(),
+                os_version: " 22.04\n".to_string(),
+                cpu_arch: "x86_64".to_string(),
+                cpu_model: Some("Intel(R)  Xeon(R)   CPU".to_string()),
+                memory_bytes: Some(64 << 30),
+            }
+        }
+    }
+
+    fn scratch(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("env-capture-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_capture_with_fake_provider() {
+        let env = EnvironmentCapture::new()
+            .with_provider(FakeSystem)
+            .with_gpu_detector(|| Some(GpuInfo {
+                name: "NVIDIA A100".to_string(),
+                driver: Some("550.54".to_string()),
+                cuda: Some("12.4".to_string()),
+            }))
+            .capture()
+            .unwrap();
+
+        assert_eq!(env.os, "ubuntu:22.04");
+        let hardware = env.hardware.unwrap();
+        assert_eq!(hardware.cpu, "Intel(R) Xeon(R) CPU (x86_64)");
+        assert_eq!(hardware.memory_bytes, Some(64 << 30));
+        assert_eq!(hardware.gpu.as_deref(), Some("NVIDIA A100"));
+        assert_eq!(hardware.cuda.as_deref(), Some("12.4"));
+        assert!(env.deps.is_empty());
+    }
+
+    #[test]
+    fn test_lockfiles_are_hashed_and_sorted() {
+        let dir = scratch("lockfiles");
+        fs::write(dir.join("requirements.txt"), "torch==2.4.0\n").unwrap();
+        fs::write(dir.join("Cargo.lock"), "version = 3\n").unwrap();
+
+        let capture = |order: [&str; 2]| {
+            order
+                .iter()
+                .fold(EnvironmentCapture::new().with_provider(FakeSystem), |c, f| c.with_lockfile(dir.join(f)))
+                .capture()
+                .unwrap()
+        };
+        let forward = capture(["requirements.txt", "Cargo.lock"]);
+        let reverse = capture(["Cargo.lock", "requirements.txt"]);
+
+        assert_eq!(forward.deps.len(), 2);
+        assert!(forward.deps[0].name.ends_with("/Cargo.lock"));
+        assert_eq!(
+            forward.deps[0].hash,
+            format!("sha256:{}", hex::encode(Sha256::digest(b"version = 3\n")))
+        );
+        assert_eq!(forward.deps[1].version, LOCKFILE_VERSION);
+        assert_eq!(forward.canonical_hash(), reverse.canonical_hash());
+    }
+
+    #[test]
+    fn test_missing_lockfile_is_an_error() {
+        let result = EnvironmentCapture::new()
+            .with_provider(FakeSystem)
+            .with_lockfile(scratch("missing").join("Cargo.lock"))
+            .capture();
+
+        assert!(matches!(result, Err(CaptureError::Lockfile { .. })));
+    }
+
+    #[test]
+    fn test_canonical_hash_tracks_content() {
+        let env = EnvironmentCapture::new().with_provider(FakeSystem).capture().unwrap();
+        let again = EnvironmentCapture::new().with_provider(FakeSystem).capture().unwrap();
+        assert_eq!(env.canonical_hash(), again.canonical_hash());
+        assert!(env.canonical_hash().starts_with("sha256:"));
+
+        let gpu = EnvironmentCapture::new()
+            .with_provider(FakeSystem)
+            .with_gpu_detector(|| Some(GpuInfo { name: "gpu".to_string(), driver: None, cuda: None }))
+            .capture()
+            .unwrap();
+        assert_ne!(env.canonical_hash(), gpu.canonical_hash());
+    }
+
+    #[test]
+    fn test_key_value_parsing() {
+        let release = "NAME=\"Ubuntu\"\nID=ubuntu\nVERSION_ID=\"22.04\"\n";
+        assert_eq!(key_value(release, "ID", '=').as_deref(), Some("ubuntu"));
+        assert_eq!(key_value(release, "VERSION_ID", '=').as_deref(), Some("22.04"));
+
+        let meminfo = "MemTotal:       16384 kB\nMemFree:  1 kB\n";
+        assert_eq!(key_value(meminfo, "MemTotal", ':').as_deref(), Some("16384 kB"));
+        assert_eq!(key_value(meminfo, "Missing", ':'), None);
+    }
+
+    #[test]
+    fn test_capture_current_reads_host() {
+        let env = EnvironmentManifest::capture_current().unwrap();
+        assert!(!env.os.is_empty());
+        assert!(env.hardware.unwrap().cpu.contains(std::env::consts::ARCH));
+    }
+}
@@ -0,0 +1,455 @@
+//! Container executor - Replays bundles inside a digest-pinned container image
+//!
+//! Drives the podman or docker CLI so the replay environment matches the
+//! bundle's `container_image_hash` exactly. Each run writes into a fresh
+//! directory under the outputs directory, so files left by an earlier run
+//! can never stand in for outputs this run did not produce.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use std::path::{Component, Path, PathBuf};
+use std::process::Command;
+use std::sync::atomic::{AtomicU64, Ordering};
+use crate::bundle::{OutputArtifact, VerificationBundle};
+use crate::executor::{output_artifact, ExecutorError, ReplayExecutor};
+
+/// Mount point for input artifacts inside the container
+pub const INPUTS_MOUNT: &str = "/inputs";
+
+/// Mount point for declared outputs inside the container
+pub const OUTPUTS_MOUNT: &str = "/outputs";
+
+/// Container runtime CLI
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ContainerRuntime {
+    /// podman
+    Podman,
+    /// docker
+    Docker,
+    /// Explicit CLI binary (podman/docker compatible)
+    Custom(PathBuf),
+}
+
+impl ContainerRuntime {
+    fn program(&self) -> &Path {
+        match self {
+            ContainerRuntime::Podman => Path::new("podman"),
+            ContainerRuntime::Docker => Path::new("docker"),
+            ContainerRuntime::Custom(path) => path,
+        }
+    }
+}
+
+/// Declared output file captured after the container exits
+#[derive(Debug, Clone)]
+pub struct DeclaredOutput {
+    /// Output artifact name
+    pub name: String,
+
+    /// Path relative to the outputs mount
+    pub path: PathBuf,
+}
+
+impl DeclaredOutput {
+    /// Refuse absolute paths and `..` components, which would reach outside
+    /// the outputs directory
+    fn check_path(&self) -> Result<(), ExecutorError> {
+        let contained = self
+            .path
+            .components()
+            .all(|component| matches!(component, Component::Normal(_) | Component::CurDir));
+        if contained && self.path.components().next().is_some() {
+            Ok(())
+        } else {
+            Err(self.invalid_path())
+        }
+    }
+
+    fn invalid_path(&self) -> ExecutorError {
+        ExecutorError::InvalidOutputPath {
+            name: self.name.clone(),
+            path: self.path.display().to_string(),
+        }
+    }
+
+    /// Read the output from `run_dir`, refusing files that resolve outside it
+    /// (e.g. through a symlink the container wrote)
+    fn read(&self, run_dir: &Path) -> Result<Vec<u8>, ExecutorError> {
+        let missing = || ExecutorError::OutputMissing {
+            name: self.name.clone(),
+        };
+        let resolved = run_dir.join(&self.path).canonicalize().map_err(|_| missing())?;
+        if !resolved.starts_with(run_dir.canonicalize()?) {
+            return Err(self.invalid_path());
+        }
+        std::fs::read(resolved).map_err(|_| missing())
+    }
+}
+
+/// Distinguishes runs of executors in the same process
+static RUN_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// Replay executor backed by a container runtime
+pub struct ContainerExecutor {
+    runtime: ContainerRuntime,
+    inputs_dir: Option<PathBuf>,
+    outputs_dir: PathBuf,
+    outputs: Vec<DeclaredOutput>,
+    command: Vec<String>,
+}
+
+impl ContainerExecutor {
+    /// Create a new executor writing outputs under `outputs_dir`
+    ///
+    /// Each run gets its own subdirectory, removed once its outputs are read.
+    pub fn new(runtime: ContainerRuntime, outputs_dir: impl Into<PathBuf>) -> Self {
+        Self {
+            runtime,
+            inputs_dir: None,
+            outputs_dir: outputs_dir.into(),
+            outputs: Vec::new(),
+            command: Vec::new(),
+        }
+    }
+
+    /// Mount unpacked input artifacts read-only
+    pub fn with_inputs(mut self, inputs_dir: impl Into<PathBuf>) -> Self {
+        self.inputs_dir = Some(inputs_dir.into());
+        self
+    }
+
+    /// Declare an output to capture after the run
+    pub fn with_output(mut self, name: impl Into<String>, path: impl Into<PathBuf>) -> Self {
+        self.outputs.push(DeclaredOutput {
+            name: name.into(),
+            path: path.into(),
+        });
+        self
+    }
+
+    /// Override the container command
+    pub fn with_command(mut self, command: Vec<String>) -> Self {
+        self.command = command;
+        self
+    }
+
+    /// Parse the bundle image reference, refusing tag-only references
+    fn pinned_digest(reference: &str) -> Result<String, ExecutorError> {
+        let digest = match reference.rsplit_once('@') {
+            Some((_, digest)) => digest,
+            None => reference,
+        };
+
+        let hex = digest.strip_prefix("sha256:").unwrap_or("");
+        if hex.len() != 64 || !hex.chars().all(|c| c.is_ascii_hexdigit()) {
+            return Err(ExecutorError::UnpinnedImage {
+                reference: reference.to_string(),
+            });
+        }
+
+        Ok(digest.to_ascii_lowercase())
+    }
+
+    /// Verify the locally available image matches the pinned digest
+    fn verify_image(&self, reference: &str, expected: &str) -> Result<(), ExecutorError> {
+        let output = Command::new(self.runtime.program())
+            .args([
+                "image",
+                "inspect",
+                "--format",
+                "{{.Id}}{{range .RepoDigests}} {{.}}{{end}}",
+                reference,
+            ])
+            .output()?;
+
+        if !output.status.success() {
+            return Err(ExecutorError::ImageMissing {
+                reference: reference.to_string(),
+            });
+        }
+
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        let matches = stdout
+            .split_whitespace()
+            .any(|id| id.to_ascii_lowercase().ends_with(expected));
+
+        if matches {
+            Ok(())
+        } else {
+            Err(ExecutorError::DigestMismatch {
+                expected: expected.to_string(),
+                actual: stdout.split_whitespace().next().unwrap_or("").to_string(),
+            })
+        }
+    }
+
+    /// Create an empty directory for one run's outputs
+    fn create_run_dir(&self) -> Result<PathBuf, ExecutorError> {
+        std::fs::create_dir_all(&self.outputs_dir)?;
+        let run = RUN_COUNTER.fetch_add(1, Ordering::Relaxed);
+        let run_dir = self
+            .outputs_dir
+            .join(format!("run-{}-{}", std::process::id(), run));
+        // Fails if the directory already exists, so it is never reused
+        std::fs::create_dir(&run_dir)?;
+        Ok(run_dir)
+    }
+
+    /// Build `run` arguments from the bundle configuration
+    fn run_args(&self, bundle: &VerificationBundle, reference: &str, run_dir: &Path) -> Vec<String> {
+        let config = &bundle.provenance.config;
+        let params = &config.parameters;
+
+        let mut env = vec![
+            ("AXIOM_SEED", config.seed.to_string()),
+            ("AXIOM_TEMPERATURE", params.temperature.to_string()),
+            ("AXIOM_TOP_P", params.top_p.to_string()),
+            ("AXIOM_MAX_TOKENS", params.max_tokens.to_string()),
+        ];
+        if let Some(top_k) = params.top_k {
+            env.push(("AXIOM_TOP_K", top_k.to_string()));
+        }
+
+        let mut args = vec![
+            "run".to_string(),
+            "--rm".to_string(),
+            "--network".to_string(),
+            "none".to_string(),
+        ];
+
+        for (key, value) in env {
+            args.push("-e".to_string());
+            args.push(format!("{}={}", key, value));
+        }
+
+        if let Some(ref inputs) = self.inputs_dir {
+            args.push("-v".to_string());
+            args.push(format!("{}:{}:ro", inputs.display(), INPUTS_MOUNT));
+        }
+
+        args.push("-v".to_string());
+        args.push(format!("{}:{}", run_dir.display(), OUTPUTS_MOUNT));
+
+        args.push(reference.to_string());
+        args.extend(self.command.iter().cloned());
+        args
+    }
+
+    /// Run the container and read the declared outputs from `run_dir`
+    fn run(
+        &self,
+        bundle: &VerificationBundle,
+        reference: &str,
+        run_dir: &Path,
+    ) -> Result<Vec<OutputArtifact>, ExecutorError> {
+        let output = Command::new(self.runtime.program())
+            .args(self.run_args(bundle, reference, run_dir))
+            .output()?;
+
+        if !output.status.success() {
+            return Err(ExecutorError::NonZeroExit {
+                code: output.status.code(),
+                stderr: String::from_utf8_lossy(&output.stderr).trim().to_string(),
+            });
+        }
+
+        self.outputs
+            .iter()
+            .map(|declared| Ok(output_artifact(declared.name.clone(), &declared.read(run_dir)?)))
+            .collect()
+    }
+}
+
+impl ReplayExecutor for ContainerExecutor {
+    fn execute(&self, bundle: &VerificationBundle) -> Result<Vec<OutputArtifact>, ExecutorError> {
+        let reference = &bundle.provenance.environment.container_image_hash;
+        let expected = Self::pinned_digest(reference)?;
+
+        for declared in &self.outputs {
+            declared.check_path()?;
+        }
+
+        self.verify_image(reference, &expected)?;
+
+        let run_dir = self.create_run_dir()?;
+        let outputs = self.run(bundle, reference, &run_dir);
+        let _ = std::fs::remove_dir_all(&run_dir);
+        outputs
+    }
+}
+
+#[cfg(all(test, unix))]
+mod tests {
+    use super::*;
+    use crate::builder::ProofArtifactBuilder;
+    use crate::bundle::{TestType, Tolerance};
+    use crate::deterministic::DeterministicConfig;
+    use crate::provenance::{EnvironmentManifest, ModelMetadata};
+    use crate::verifier::Verifier;
+    use std::os::unix::fs::PermissionsExt;
+
+    const DIGEST: &str = "sha256:1111111111111111111111111111111111111111111111111111111111111111";
+    const HELLO_HASH: &str = "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824";
+
+    const WRITE_HELLO: &str = r#"printf 'hello' > "$out/result.txt""#;
+
+    /// Install a fake container CLI reporting `image_id` from `image inspect`
+    /// and running `writes` with `$out` set to the outputs mount source
+    fn fake_runtime(dir: &Path, image_id: &str, writes: &str) -> PathBuf {
+        let script = format!(
+            r#"#!/bin/sh
+case "$1" in
+  image) echo "{image_id}" ;;
+  run)
+    out=""
+    while [ $# -gt 0 ]; do
+      case "$1" in
+        -v) shift; case "$1" in *:{mount}) out="${{1%:{mount}}}" ;; esac ;;
+      esac
+      shift
+    done
+    {writes}
+    ;;
+esac
+"#,
+            image_id = image_id,
+            writes = writes,
+            mount = OUTPUTS_MOUNT,
+        );
+
+        std::fs::create_dir_all(dir).unwrap();
+        let path = dir.join("podman");
+        std::fs::write(&path, script).unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+        path
+    }
+
+    fn scratch_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir()
+            .join(format!("axiom-container-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        dir
+    }
+
+    fn bundle(image: &str) -> VerificationBundle {
+        ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_environment(EnvironmentManifest {
+                container_image_hash: image.to_string(),
+                os: "ubuntu:22.04".to_string(),
+                deps: vec![],
+                hardware: None,
+                extra: Default::default(),
+            })
+            .with_config(DeterministicConfig {
+                seed: 42,
+                parameters: Default::default(),
+            })
+            .add_output("result", HELLO_HASH, "hash://sha256/expected")
+            .add_test("result", TestType::Replay, HELLO_HASH, Tolerance::Exact)
+            .build()
+            .unwrap()
+    }
+
+    #[test]
+    fn test_refuses_tag_only_reference() {
+        let dir = scratch_dir("tag");
+        let runtime = fake_runtime(&dir, DIGEST, WRITE_HELLO);
+        let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime), dir.join("out"));
+
+        let err = executor.execute(&bundle("ubuntu:22.04")).unwrap_err();
+        assert!(matches!(err, ExecutorError::UnpinnedImage { .. }));
+    }
+
+    #[test]
+    fn test_digest_mismatch() {
+        let dir = scratch_dir("mismatch");
+        let other = "sha256:2222222222222222222222222222222222222222222222222222222222222222";
+        let runtime = fake_runtime(&dir, other, WRITE_HELLO);
+        let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime), dir.join("out"))
+            .with_output("result", "result.txt");
+
+        let verifier = Verifier::new(|_, _| true).with_executor(Box::new(executor));
+        let result = verifier.verify(&bundle(DIGEST));
+
+        assert!(!result.passed);
+        assert_eq!(
+            result.test_results[0].executor_error,
+            Some(ExecutorError::DigestMismatch {
+                expected: DIGEST.to_string(),
+                actual: other.to_string(),
+            })
+        );
+    }
+
+    #[test]
+    fn test_successful_run_produces_expected_hashes() {
+        let dir = scratch_dir("success");
+        let runtime = fake_runtime(&dir, DIGEST, WRITE_HELLO);
+        let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime), dir.join("out"))
+            .with_inputs(dir.join("in"))
+            .with_output("result", "result.txt");
+
+        let outputs = executor.execute(&bundle(DIGEST)).unwrap();
+        assert_eq!(outputs[0].hash, HELLO_HASH);
+
+        let verifier = Verifier::new(|_, _| true).with_executor(Box::new(executor));
+        let result = verifier.verify(&bundle(DIGEST));
+        assert!(result.passed);
+    }
+
+    #[test]
+    fn test_stale_outputs_do_not_satisfy_replay() {
+        let dir = scratch_dir("stale");
+        let runtime = fake_runtime(&dir, DIGEST, ":");
+        let out = dir.join("out");
+        std::fs::create_dir_all(&out).unwrap();
+        std::fs::write(out.join("result.txt"), "hello").unwrap();
+
+        let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime), &out)
+            .with_output("result", "result.txt");
+        assert_eq!(
+            executor.execute(&bundle(DIGEST)).unwrap_err(),
+            ExecutorError::OutputMissing { name: "result".to_string() }
+        );
+
+        // The run directory is gone; only the stale file remains
+        let entries: Vec<_> = std::fs::read_dir(&out).unwrap().map(|e| e.unwrap().file_name()).collect();
+        assert_eq!(entries, vec![std::ffi::OsString::from("result.txt")]);
+    }
+
+    #[test]
+    fn test_output_paths_stay_in_outputs_dir() {
+        let dir = scratch_dir("escape");
+        let runtime = fake_runtime(&dir, DIGEST, WRITE_HELLO);
+        std::fs::write(dir.join("secret.txt"), "hello").unwrap();
+
+        for path in ["../secret.txt", "/etc/hostname", "nested/../../secret.txt", ""] {
+            let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime.clone()), dir.join("out"))
+                .with_output("result", path);
+            assert_eq!(
+                executor.execute(&bundle(DIGEST)).unwrap_err(),
+                ExecutorError::InvalidOutputPath { name: "result".to_string(), path: path.to_string() },
+                "{}",
+                path
+            );
+        }
+
+        // A symlink the container leaves behind is not followed out
+        let link = format!(r#"ln -s "{}" "$out/result.txt""#, dir.join("secret.txt").display());
+        let runtime = fake_runtime(&dir.join("link"), DIGEST, &link);
+        let executor = ContainerExecutor::new(ContainerRuntime::Custom(runtime), dir.join("out"))
+            .with_output("result", "result.txt");
+        assert!(matches!(
+            executor.execute(&bundle(DIGEST)),
+            Err(ExecutorError::InvalidOutputPath { .. })
+        ));
+    }
+}
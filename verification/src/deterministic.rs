@@ -1,9 +1,17 @@
 //! Deterministic inference configuration and controls
 //!
+//! [`ReplayHarness`] runs a workload twice under the same configuration,
+//! requires byte-identical output and turns it into the output artifact and
+//! replay test a bundle needs, so expected hashes never have to be computed
+//! by hand.
+//!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::fmt;
+
+use crate::bundle::{OutputArtifact, TestType, Tolerance, VerificationTest};
 
 /// Deterministic configuration for reproducible inference
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -93,6 +101,116 @@ impl SeedControl {
     }
 }
 
+/// Runs a workload twice and records its output as a replay test
+pub struct ReplayHarness<F> {
+    name: String,
+    config: DeterministicConfig,
+    workload: F,
+}
+
+impl<F: Fn(&DeterministicConfig) -> Vec<u8>> ReplayHarness<F> {
+    /// Harness for `workload`; `name` names both the output and the test
+    pub fn new(name: impl Into<String>, config: DeterministicConfig, workload: F) -> Self {
+        Self {
+            name: name.into(),
+            config,
+            workload,
+        }
+    }
+    
+    /// Run the workload twice and hash its output
+    pub fn run(&self) -> Result<ReplayOutcome, ReplayError> {
+        let first = (self.workload)(&self.config);
+        let second = (self.workload)(&self.config);
+        
+        if let Some(divergence) = ReplayDivergence::between(&first, &second) {
+            return Err(ReplayError::Nondeterministic {
+                name: self.name.clone(),
+                seed: self.config.seed,
+                divergence,
+            });
+        }
+        
+        let output = crate::executor::output_artifact(self.name.clone(), &first);
+        let test = VerificationTest {
+            name: self.name.clone(),
+            test_type: TestType::Replay,
+            expected_output_hash: output.hash.clone(),
+            tolerance: Tolerance::Exact,
+            expected: None,
+            extra: Default::default(),
+        };
+        
+        Ok(ReplayOutcome {
+            config: self.config.clone(),
+            output,
+            test,
+            content: first,
+        })
+    }
+}
+
+/// Output of a deterministic replay, ready for `ProofArtifactBuilder::add_replay`
+#[derive(Debug, Clone)]
+pub struct ReplayOutcome {
+    /// Configuration the workload ran under
+    pub config: DeterministicConfig,
+    /// Hashed output
+    pub output: OutputArtifact,
+    /// Exact replay test expecting `output`'s hash
+    pub test: VerificationTest,
+    /// Raw output bytes
+    pub content: Vec<u8>,
+}
+
+/// Where two runs of a workload first disagree
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayDivergence {
+    /// First byte offset that differs (the shorter length if one output is a prefix of the other)
+    pub first_difference: usize,
+    pub first_len: usize,
+    pub second_len: usize,
+}
+
+impl ReplayDivergence {
+    fn between(first: &[u8], second: &[u8]) -> Option<Self> {
+        if first == second {
+            return None;
+        }
+        let first_difference = first
+            .iter()
+            .zip(second)
+            .position(|(a, b)| a != b)
+            .unwrap_or_else(|| first.len().min(second.len()));
+        Some(Self {
+            first_difference,
+            first_len: first.len(),
+            second_len: second.len(),
+        })
+    }
+}
+
+impl fmt::Display for ReplayDivergence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "first difference at byte {} (lengths {} and {})",
+            self.first_difference, self.first_len, self.second_len
+        )
+    }
+}
+
+/// Replay harness errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum ReplayError {
+    #[error("Workload '{name}' is not deterministic under seed {seed}: {divergence}")]
+    Nondeterministic {
+        name: String,
+        seed: u64,
+        divergence: ReplayDivergence,
+    },
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -120,5 +238,16 @@ mod tests {
         assert_eq!(config.parameters.temperature, 0.0);
         assert_eq!(config.seed, 42);
     }
+    
+    #[test]
+    fn test_divergence_offsets() {
+        assert_eq!(ReplayDivergence::between(b"abc", b"abc"), None);
+        
+        let divergence = ReplayDivergence::between(b"abcd", b"abxd").unwrap();
+        assert_eq!(divergence.first_difference, 2);
+        
+        let divergence = ReplayDivergence::between(b"abc", b"abcde").unwrap();
+        assert_eq!(divergence.first_difference, 3);
+        assert_eq!((divergence.first_len, divergence.second_len), (3, 5));
+    }
 }
-
@@ -0,0 +1,76 @@
+//! Replay executors - Re-run a bundle's computation to regenerate outputs
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use crate::bundle::{OutputArtifact, VerificationBundle};
+
+/// Executor that reproduces a bundle's outputs for replay tests
+pub trait ReplayExecutor {
+    /// Execute the bundle's computation and return the regenerated outputs
+    fn execute(&self, bundle: &VerificationBundle) -> Result<Vec<OutputArtifact>, ExecutorError>;
+}
+
+/// Structured replay executor errors
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, thiserror::Error)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum ExecutorError {
+    #[error("Image reference '{reference}' is not pinned by digest")]
+    UnpinnedImage { reference: String },
+
+    #[error("Image '{reference}' is not available locally")]
+    ImageMissing { reference: String },
+
+    #[error("Image digest mismatch: expected {expected}, found {actual}")]
+    DigestMismatch { expected: String, actual: String },
+
+    #[error("Execution exited with status {code:?}: {stderr}")]
+    NonZeroExit { code: Option<i32>, stderr: String },
+
+    #[error("Declared output '{name}' was not produced")]
+    OutputMissing { name: String },
+
+    #[error("Declared output '{name}' path '{path}' leaves the outputs directory")]
+    InvalidOutputPath { name: String, path: String },
+
+    #[error("Executor I/O error: {0}")]
+    Io(String),
+}
+
+impl From<std::io::Error> for ExecutorError {
+    fn from(err: std::io::Error) -> Self {
+        ExecutorError::Io(err.to_string())
+    }
+}
+
+/// Hash raw output bytes into an output artifact
+pub fn output_artifact(name: impl Into<String>, content: &[u8]) -> OutputArtifact {
+    use sha2::{Digest, Sha256};
+
+    let digest = hex::encode(Sha256::digest(content));
+
+    OutputArtifact {
+        name: name.into(),
+        hash: format!("sha256:{}", digest),
+        uri: format!("hash://sha256/{}", digest),
+        mime_type: None,
+        data: None,
+        extra: Default::default(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_output_artifact_hash() {
+        let artifact = output_artifact("result", b"hello");
+
+        assert_eq!(
+            artifact.hash,
+            "sha256:2cf24dba5fb0a30e26e83b2ac5b9e29e1b161e5c1fa7425e73043362938b9824"
+        );
+        assert!(artifact.uri.starts_with("hash://sha256/2cf24dba"));
+    }
+}
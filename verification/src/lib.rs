@@ -9,14 +9,26 @@ pub mod builder;
 pub mod verifier;
 pub mod attestation;
 pub mod provenance;
+pub mod capture;
 pub mod deterministic;
+pub mod executor;
+pub mod numeric;
+pub mod store;
+#[cfg(feature = "container")]
+pub mod container;
 
 pub use bundle::VerificationBundle;
 pub use builder::ProofArtifactBuilder;
 pub use verifier::Verifier;
-pub use attestation::{Attestation, AttestationChain};
+pub use attestation::{Attestation, AttestationChain, AttestationError, Ed25519Signer, Signer, TrustedKeys};
 pub use provenance::{Provenance, DataProvenance, ModelMetadata};
-pub use deterministic::{DeterministicConfig, SeedControl};
+pub use capture::{CaptureError, EnvironmentCapture, GpuDetector, GpuInfo, SystemInfo, SystemInfoProvider};
+pub use deterministic::{DeterministicConfig, ReplayError, ReplayHarness, ReplayOutcome, SeedControl};
+pub use executor::{ExecutorError, ReplayExecutor};
+pub use numeric::{NanPolicy, NumericData, NumericError};
+pub use store::{BundleStore, ContentAddress, FsBundleStore, MemoryBundleStore, StoreError};
+#[cfg(feature = "container")]
+pub use container::{ContainerExecutor, ContainerRuntime};
 
 /// Substrate authority identifier
 pub const SUBSTRATE: &str = "Alexis Adams";
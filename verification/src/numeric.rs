@@ -0,0 +1,419 @@
+//! Numeric payloads - Streaming decode and tolerance comparison of f64 arrays
+//!
+//! Float-tolerance replay tests compare outputs element by element instead
+//! of by hash. Payloads are JSON arrays of numbers, either embedded in the
+//! bundle or referenced by a `file://` URI (or plain path). File payloads
+//! are decoded incrementally so large arrays never load fully into memory.
+//! `null` (what serde_json writes for non-finite floats) and the bare
+//! tokens `NaN`, `Infinity` and `-Infinity` are accepted.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::{Deserialize, Serialize};
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+
+/// Numeric payload errors
+#[derive(Debug, Clone, PartialEq, Eq, thiserror::Error)]
+pub enum NumericError {
+    #[error("Cannot resolve payload URI '{uri}'")]
+    UnsupportedUri { uri: String },
+
+    #[error("Payload I/O error: {0}")]
+    Io(String),
+
+    #[error("Malformed numeric payload at byte {offset}: {message}")]
+    Parse { offset: u64, message: String },
+}
+
+impl From<std::io::Error> for NumericError {
+    fn from(err: std::io::Error) -> Self {
+        NumericError::Io(err.to_string())
+    }
+}
+
+/// Array of f64 values, embedded or referenced
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NumericData {
+    /// Values embedded in the bundle
+    Inline(#[serde(with = "nan_as_null")] Vec<f64>),
+    /// `file://` URI or path to a JSON array
+    Uri(String),
+}
+
+impl NumericData {
+    /// Stream the values in order
+    pub fn values(&self) -> Result<Box<dyn Iterator<Item = Result<f64, NumericError>> + '_>, NumericError> {
+        match self {
+            NumericData::Inline(values) => Ok(Box::new(values.iter().copied().map(Ok))),
+            NumericData::Uri(uri) => {
+                let file = File::open(resolve_uri(uri)?)?;
+                Ok(Box::new(F64Stream::new(BufReader::new(file))))
+            }
+        }
+    }
+}
+
+/// Map a payload URI to a local path
+fn resolve_uri(uri: &str) -> Result<&str, NumericError> {
+    if let Some(path) = uri.strip_prefix("file://") {
+        return Ok(path);
+    }
+    if uri.contains("://") {
+        return Err(NumericError::UnsupportedUri { uri: uri.to_string() });
+    }
+    Ok(uri)
+}
+
+/// Incremental decoder for a JSON array of numbers
+pub struct F64Stream<R> {
+    reader: R,
+    offset: u64,
+    started: bool,
+    done: bool,
+}
+
+impl<R: BufRead> F64Stream<R> {
+    pub fn new(reader: R) -> Self {
+        Self { reader, offset: 0, started: false, done: false }
+    }
+
+    fn peek(&mut self) -> Result<Option<u8>, NumericError> {
+        Ok(self.reader.fill_buf()?.first().copied())
+    }
+
+    fn bump(&mut self) {
+        self.reader.consume(1);
+        self.offset += 1;
+    }
+
+    fn skip_whitespace(&mut self) -> Result<Option<u8>, NumericError> {
+        while let Some(b) = self.peek()? {
+            if !b.is_ascii_whitespace() {
+                return Ok(Some(b));
+            }
+            self.bump();
+        }
+        Ok(None)
+    }
+
+    fn error(&self, message: impl Into<String>) -> NumericError {
+        NumericError::Parse { offset: self.offset, message: message.into() }
+    }
+
+    fn expect_byte(&mut self, expected: u8) -> Result<(), NumericError> {
+        match self.skip_whitespace()? {
+            Some(b) if b == expected => {
+                self.bump();
+                Ok(())
+            }
+            Some(b) => Err(self.error(format!("expected '{}', found '{}'", expected as char, b as char))),
+            None => Err(self.error(format!("expected '{}', found end of input", expected as char))),
+        }
+    }
+
+    fn read_token(&mut self) -> Result<String, NumericError> {
+        let start = self.offset;
+        let mut token = String::new();
+        while let Some(b) = self.peek()? {
+            if b == b',' || b == b']' || b.is_ascii_whitespace() {
+                break;
+            }
+            token.push(b as char);
+            self.bump();
+            if token.len() > 64 {
+                return Err(NumericError::Parse { offset: start, message: "number token too long".to_string() });
+            }
+        }
+        Ok(token)
+    }
+
+    fn parse_value(&mut self) -> Result<f64, NumericError> {
+        let start = self.offset;
+        let token = self.read_token()?;
+        match token.as_str() {
+            "null" | "NaN" => Ok(f64::NAN),
+            "Infinity" => Ok(f64::INFINITY),
+            "-Infinity" => Ok(f64::NEG_INFINITY),
+            _ => token.parse::<f64>().map_err(|_| NumericError::Parse {
+                offset: start,
+                message: format!("invalid number '{}'", token),
+            }),
+        }
+    }
+
+    fn next_value(&mut self) -> Result<Option<f64>, NumericError> {
+        if !self.started {
+            self.started = true;
+            self.expect_byte(b'[')?;
+            if self.skip_whitespace()? == Some(b']') {
+                self.bump();
+                return Ok(None);
+            }
+            return self.parse_value().map(Some);
+        }
+        match self.skip_whitespace()? {
+            Some(b']') => {
+                self.bump();
+                Ok(None)
+            }
+            Some(b',') => {
+                self.bump();
+                self.skip_whitespace()?;
+                self.parse_value().map(Some)
+            }
+            Some(b) => Err(self.error(format!("expected ',' or ']', found '{}'", b as char))),
+            None => Err(self.error("unterminated array")),
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for F64Stream<R> {
+    type Item = Result<f64, NumericError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        match self.next_value() {
+            Ok(Some(value)) => Some(Ok(value)),
+            Ok(None) => {
+                self.done = true;
+                None
+            }
+            Err(err) => {
+                self.done = true;
+                Some(Err(err))
+            }
+        }
+    }
+}
+
+/// Decode a complete JSON array of numbers from a reader
+pub fn decode_f64_array(reader: impl Read) -> Result<Vec<f64>, NumericError> {
+    F64Stream::new(BufReader::new(reader)).collect()
+}
+
+/// How NaN values are compared
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum NanPolicy {
+    /// Any NaN fails the comparison
+    #[default]
+    Strict,
+    /// NaN matches NaN at the same position
+    Lenient,
+}
+
+/// Outcome of an elementwise comparison
+#[derive(Debug, Clone, PartialEq)]
+pub struct Comparison {
+    /// Elements compared pairwise
+    pub compared: usize,
+    /// Elements outside tolerance (including NaN failures)
+    pub mismatches: usize,
+    /// Largest absolute deviation among finite pairs
+    pub max_deviation: f64,
+    /// Index of the largest deviation
+    pub max_deviation_index: Option<usize>,
+    /// First index where NaN broke the comparison
+    pub first_nan_index: Option<usize>,
+    /// (actual, expected) lengths when they differ
+    pub length_mismatch: Option<(usize, usize)>,
+}
+
+impl Comparison {
+    /// Whether every element is within tolerance and lengths agree
+    pub fn passed(&self) -> bool {
+        self.mismatches == 0 && self.length_mismatch.is_none()
+    }
+
+    /// Human-readable summary
+    pub fn summary(&self) -> String {
+        let mut message = format!(
+            "Compared {} values: {} outside tolerance, max deviation {:e}",
+            self.compared, self.mismatches, self.max_deviation
+        );
+        if let Some(index) = self.max_deviation_index {
+            message.push_str(&format!(" at index {}", index));
+        }
+        if let Some(index) = self.first_nan_index {
+            message.push_str(&format!("; NaN mismatch at index {}", index));
+        }
+        if let Some((actual, expected)) = self.length_mismatch {
+            message.push_str(&format!("; length mismatch: actual {} vs expected {}", actual, expected));
+        }
+        message
+    }
+}
+
+/// Compare two value streams elementwise
+///
+/// A pair passes when `|a - e| <= absolute + relative * |e|`. Both streams
+/// are drained so a length mismatch reports the true lengths.
+pub fn compare(
+    actual: impl Iterator<Item = Result<f64, NumericError>>,
+    expected: impl Iterator<Item = Result<f64, NumericError>>,
+    relative: f64,
+    absolute: f64,
+    nan: NanPolicy,
+) -> Result<Comparison, NumericError> {
+    let mut actual = actual.fuse();
+    let mut expected = expected.fuse();
+    let mut result = Comparison {
+        compared: 0,
+        mismatches: 0,
+        max_deviation: 0.0,
+        max_deviation_index: None,
+        first_nan_index: None,
+        length_mismatch: None,
+    };
+    let (mut actual_len, mut expected_len) = (0usize, 0usize);
+
+    loop {
+        let a = actual.next().transpose()?;
+        let e = expected.next().transpose()?;
+        actual_len += a.is_some() as usize;
+        expected_len += e.is_some() as usize;
+
+        let (a, e) = match (a, e) {
+            (Some(a), Some(e)) => (a, e),
+            (None, None) => break,
+            _ => continue,
+        };
+        let index = result.compared;
+        result.compared += 1;
+
+        if a.is_nan() || e.is_nan() {
+            let matched = nan == NanPolicy::Lenient && a.is_nan() && e.is_nan();
+            if !matched {
+                result.mismatches += 1;
+                result.first_nan_index.get_or_insert(index);
+            }
+            continue;
+        }
+        if a == e {
+            // Covers equal infinities, whose difference would be NaN
+            continue;
+        }
+
+        let deviation = (a - e).abs();
+        if deviation > absolute + relative * e.abs() {
+            result.mismatches += 1;
+        }
+        if deviation > result.max_deviation || result.max_deviation_index.is_none() {
+            result.max_deviation = deviation;
+            result.max_deviation_index = Some(index);
+        }
+    }
+
+    if actual_len != expected_len {
+        result.length_mismatch = Some((actual_len, expected_len));
+    }
+    Ok(result)
+}
+
+/// Serialize NaN as `null` so inline payloads survive a JSON round trip
+mod nan_as_null {
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(values: &[f64], serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(values.iter().map(|v| if v.is_nan() { None } else { Some(*v) }))
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<f64>, D::Error> {
+        let values: Vec<Option<f64>> = Vec::deserialize(deserializer)?;
+        Ok(values.into_iter().map(|v| v.unwrap_or(f64::NAN)).collect())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn inline(values: &[f64]) -> NumericData {
+        NumericData::Inline(values.to_vec())
+    }
+
+    fn run(actual: &NumericData, expected: &NumericData, nan: NanPolicy) -> Comparison {
+        compare(actual.values().unwrap(), expected.values().unwrap(), 1e-6, 1e-9, nan).unwrap()
+    }
+
+    #[test]
+    fn test_stream_decode() {
+        let values = decode_f64_array(&b" [1, -2.5e3 ,null,NaN, Infinity]\n"[..]).unwrap();
+        assert_eq!(values[..2], [1.0, -2500.0]);
+        assert!(values[2].is_nan() && values[3].is_nan());
+        assert_eq!(values[4], f64::INFINITY);
+        assert!(decode_f64_array(&b"[]"[..]).unwrap().is_empty());
+
+        let err = decode_f64_array(&b"[1, 2"[..]).unwrap_err();
+        assert!(matches!(err, NumericError::Parse { .. }));
+        let err = decode_f64_array(&b"[1, abc]"[..]).unwrap_err();
+        assert_eq!(err, NumericError::Parse { offset: 4, message: "invalid number 'abc'".to_string() });
+    }
+
+    #[test]
+    fn test_tolerance_and_max_deviation() {
+        let expected = inline(&[1.0, 2.0, 3.0]);
+        let close = run(&inline(&[1.0, 2.000_000_1, 3.0]), &expected, NanPolicy::Strict);
+        assert!(close.passed());
+        assert_eq!(close.max_deviation_index, Some(1));
+
+        let far = run(&inline(&[1.0, 2.0, 3.1]), &expected, NanPolicy::Strict);
+        assert!(!far.passed());
+        assert_eq!(far.mismatches, 1);
+        assert!((far.max_deviation - 0.1).abs() < 1e-12);
+        assert!(far.summary().contains("at index 2"));
+
+        let short = run(&inline(&[1.0, 2.0]), &expected, NanPolicy::Strict);
+        assert_eq!(short.length_mismatch, Some((2, 3)));
+        assert!(!short.passed());
+    }
+
+    #[test]
+    fn test_nan_policy() {
+        let expected = inline(&[f64::NAN, 1.0]);
+        let actual = inline(&[f64::NAN, 1.0]);
+        assert!(!run(&actual, &expected, NanPolicy::Strict).passed());
+        assert!(run(&actual, &expected, NanPolicy::Lenient).passed());
+
+        let one_sided = run(&inline(&[0.0, 1.0]), &expected, NanPolicy::Lenient);
+        assert_eq!(one_sided.first_nan_index, Some(0));
+        assert!(!one_sided.passed());
+
+        let json = serde_json::to_string(&actual).unwrap();
+        assert_eq!(json, r#"{"inline":[null,1.0]}"#);
+        let parsed: NumericData = serde_json::from_str(&json).unwrap();
+        assert!(matches!(parsed, NumericData::Inline(ref v) if v[0].is_nan()));
+    }
+
+    #[test]
+    fn test_large_file_payload_streams() {
+        let dir = std::env::temp_dir().join(format!("numeric-payload-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("values.json");
+        let mut text = String::from("[");
+        for i in 0..200_000 {
+            if i > 0 {
+                text.push(',');
+            }
+            text.push_str(&(i as f64 * 0.5).to_string());
+        }
+        text.push(']');
+        std::fs::write(&path, text).unwrap();
+
+        let uri = NumericData::Uri(format!("file://{}", path.display()));
+        let generated = (0..200_000).map(|i| Ok(i as f64 * 0.5));
+        let result = compare(uri.values().unwrap(), generated, 0.0, 0.0, NanPolicy::Strict).unwrap();
+        assert!(result.passed());
+        assert_eq!(result.compared, 200_000);
+
+        assert!(matches!(
+            NumericData::Uri("hash://sha256/abc".to_string()).values().err(),
+            Some(NumericError::UnsupportedUri { .. })
+        ));
+        std::fs::remove_dir_all(&dir).ok();
+    }
+}
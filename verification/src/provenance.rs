@@ -4,6 +4,7 @@
 
 use serde::{Deserialize, Serialize};
 use chrono::{DateTime, Utc};
+use crate::bundle::UnknownFields;
 
 /// Provenance information for a bundle
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -19,6 +20,20 @@ pub struct Provenance {
     
     /// Configuration
     pub config: crate::deterministic::DeterministicConfig,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
+}
+
+impl Provenance {
+    /// Whether this or any nested provenance record carries unknown fields
+    pub fn has_unknown_fields(&self) -> bool {
+        !self.extra.is_empty()
+            || self.inputs.iter().any(|input| !input.extra.is_empty())
+            || !self.model.extra.is_empty()
+            || !self.environment.extra.is_empty()
+    }
 }
 
 /// Data provenance for inputs
@@ -42,6 +57,10 @@ pub struct DataProvenance {
     
     /// Transformations applied
     pub transformations: Vec<Transformation>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Transformation record
@@ -83,6 +102,10 @@ pub struct ModelMetadata {
     /// Model card URI
     #[serde(rename = "card_uri")]
     pub card_uri: Option<String>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Environment manifest
@@ -100,6 +123,10 @@ pub struct EnvironmentManifest {
     
     /// Hardware profile (optional)
     pub hardware: Option<HardwareProfile>,
+    
+    /// Fields from newer schema versions, preserved verbatim
+    #[serde(flatten)]
+    pub extra: UnknownFields,
 }
 
 /// Dependency specification
@@ -118,7 +145,7 @@ pub struct Dependency {
 /// Hardware profile
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct HardwareProfile {
-    /// CPU architecture
+    /// CPU model and architecture
     pub cpu: String,
     
     /// GPU information
@@ -129,5 +156,9 @@ pub struct HardwareProfile {
     
     /// CUDA version (if applicable)
     pub cuda: Option<String>,
+    
+    /// Total physical memory in bytes
+    #[serde(default, rename = "memory_bytes", skip_serializing_if = "Option::is_none")]
+    pub memory_bytes: Option<u64>,
 }
 
@@ -0,0 +1,406 @@
+//! Bundle Store - Put and resolve verification bundles by content address
+//!
+//! A bundle is stored under the address it computes for itself, so an
+//! address is both its name and its checksum. [`FsBundleStore`] keeps one
+//! file per bundle, sharded by hash prefix and written via temp + rename;
+//! [`MemoryBundleStore`] keeps bundles in memory for tests.
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use std::collections::BTreeMap;
+use std::fmt;
+use std::fs;
+use std::io::Write;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::RwLock;
+use sha2::{Digest, Sha256};
+use crate::bundle::VerificationBundle;
+
+/// Scheme prefix of bundle content addresses
+const ADDRESS_PREFIX: &str = "hash://sha256/";
+
+/// Content address of a bundle (`hash://sha256/<64 hex digits>`)
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ContentAddress(String);
+
+impl ContentAddress {
+    /// Address of `bundle` as it should be (recomputed, not as declared)
+    pub fn of(bundle: &VerificationBundle) -> Self {
+        Self(bundle.compute_content_address())
+    }
+
+    /// Full address string
+    pub fn as_str(&self) -> &str {
+        &self.0
+    }
+
+    /// Hex digest without the scheme prefix
+    pub fn digest(&self) -> &str {
+        &self.0[ADDRESS_PREFIX.len()..]
+    }
+}
+
+impl FromStr for ContentAddress {
+    type Err = StoreError;
+
+    fn from_str(address: &str) -> Result<Self, Self::Err> {
+        let digest = address
+            .strip_prefix(ADDRESS_PREFIX)
+            .ok_or_else(|| StoreError::InvalidAddress(address.to_string()))?;
+        if digest.len() != 64 || !digest.bytes().all(|b| matches!(b, b'0'..=b'9' | b'a'..=b'f')) {
+            return Err(StoreError::InvalidAddress(address.to_string()));
+        }
+        Ok(Self(address.to_string()))
+    }
+}
+
+impl fmt::Display for ContentAddress {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// Bundle store errors
+#[derive(Debug, thiserror::Error)]
+pub enum StoreError {
+    #[error("Invalid content address: {0}")]
+    InvalidAddress(String),
+
+    #[error("Bundle declares content address {declared} but its contents hash to {computed}")]
+    AddressMismatch { declared: String, computed: String },
+
+    #[error("No bundle stored at {0}")]
+    NotFound(ContentAddress),
+
+    #[error("Stored bundle {address} is corrupted: {reason}")]
+    Corrupted { address: ContentAddress, reason: String },
+
+    #[error("Store I/O error: {0}")]
+    Io(#[from] std::io::Error),
+
+    #[error("Serialization error: {0}")]
+    Serialization(#[from] serde_json::Error),
+}
+
+/// Content-addressed storage for verification bundles
+pub trait BundleStore: Send + Sync {
+    /// Store `bundle` under its content address
+    ///
+    /// Fails if the declared address does not match the contents. Storing
+    /// a bundle that is already present is a no-op.
+    fn put(&self, bundle: &VerificationBundle) -> Result<ContentAddress, StoreError>;
+
+    /// Bundle stored at `address`; corruption is an error, not `None`
+    fn get(&self, address: &ContentAddress) -> Result<Option<VerificationBundle>, StoreError>;
+
+    /// Whether a bundle is stored at `address`
+    fn exists(&self, address: &ContentAddress) -> bool;
+}
+
+/// Check that `bundle` declares the address its contents hash to
+fn checked_address(bundle: &VerificationBundle) -> Result<ContentAddress, StoreError> {
+    let computed = ContentAddress::of(bundle);
+    if bundle.content_address != computed.as_str() {
+        return Err(StoreError::AddressMismatch {
+            declared: bundle.content_address.clone(),
+            computed: computed.0,
+        });
+    }
+    Ok(computed)
+}
+
+/// Check that a bundle read back from storage is the one at `address`
+fn check_stored(address: &ContentAddress, bundle: &VerificationBundle) -> Result<(), StoreError> {
+    let computed = bundle.compute_content_address();
+    if computed != address.as_str() || bundle.content_address != address.as_str() {
+        return Err(StoreError::Corrupted {
+            address: address.clone(),
+            reason: format!("contents hash to {}", computed),
+        });
+    }
+    Ok(())
+}
+
+/// In-memory bundle store
+#[derive(Debug, Default)]
+pub struct MemoryBundleStore {
+    bundles: RwLock<BTreeMap<ContentAddress, VerificationBundle>>,
+}
+
+impl MemoryBundleStore {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number of stored bundles
+    pub fn len(&self) -> usize {
+        self.bundles.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl BundleStore for MemoryBundleStore {
+    fn put(&self, bundle: &VerificationBundle) -> Result<ContentAddress, StoreError> {
+        let address = checked_address(bundle)?;
+        self.bundles
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .entry(address.clone())
+            .or_insert_with(|| bundle.clone());
+        Ok(address)
+    }
+
+    fn get(&self, address: &ContentAddress) -> Result<Option<VerificationBundle>, StoreError> {
+        let bundles = self.bundles.read().unwrap_or_else(|e| e.into_inner());
+        match bundles.get(address) {
+            Some(bundle) => {
+                check_stored(address, bundle)?;
+                Ok(Some(bundle.clone()))
+            }
+            None => Ok(None),
+        }
+    }
+
+    fn exists(&self, address: &ContentAddress) -> bool {
+        self.bundles.read().unwrap_or_else(|e| e.into_inner()).contains_key(address)
+    }
+}
+
+/// Filesystem bundle store
+///
+/// Bundles live at `<root>/<first two hex digits>/<digest>.json`. Each file
+/// starts with a `sha256:<hex>` line over the JSON that follows, so any
+/// change to the file is caught on read, including fields outside the
+/// content address.
+#[derive(Debug, Clone)]
+pub struct FsBundleStore {
+    root: PathBuf,
+}
+
+/// Distinguishes temp files of concurrent writers in one process
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+impl FsBundleStore {
+    /// Store rooted at `root`, created if missing
+    pub fn open(root: impl Into<PathBuf>) -> Result<Self, StoreError> {
+        let root = root.into();
+        fs::create_dir_all(&root)?;
+        Ok(Self { root })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    /// Path of the bundle file for `address`
+    pub fn path_for(&self, address: &ContentAddress) -> PathBuf {
+        let digest = address.digest();
+        self.root.join(&digest[..2]).join(format!("{}.json", digest))
+    }
+
+    /// Write `contents` to `path` atomically
+    ///
+    /// Each writer renames its own temp file into place; concurrent writers
+    /// of one bundle write identical bytes, so whichever rename lands last
+    /// leaves a complete file.
+    fn write_atomic(path: &Path, contents: &[u8]) -> Result<(), StoreError> {
+        let dir = path.parent().expect("bundle paths have a shard directory");
+        fs::create_dir_all(dir)?;
+
+        let temp = dir.join(format!(
+            ".{}.{}.{}.tmp",
+            path.file_name().and_then(|n| n.to_str()).unwrap_or("bundle"),
+            std::process::id(),
+            TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+        ));
+        let written = (|| {
+            let mut file = fs::File::create(&temp)?;
+            file.write_all(contents)?;
+            file.sync_all()?;
+            fs::rename(&temp, path)
+        })();
+        if written.is_err() {
+            let _ = fs::remove_file(&temp);
+        }
+        written.map_err(StoreError::from)
+    }
+}
+
+impl BundleStore for FsBundleStore {
+    fn put(&self, bundle: &VerificationBundle) -> Result<ContentAddress, StoreError> {
+        let address = checked_address(bundle)?;
+        let path = self.path_for(&address);
+        if path.exists() {
+            return Ok(address);
+        }
+
+        let json = bundle.to_json()?;
+        let contents = format!("sha256:{}\n{}", hex::encode(Sha256::digest(json.as_bytes())), json);
+        Self::write_atomic(&path, contents.as_bytes())?;
+        Ok(address)
+    }
+
+    fn get(&self, address: &ContentAddress) -> Result<Option<VerificationBundle>, StoreError> {
+        let contents = match fs::read(self.path_for(address)) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => return Err(e.into()),
+        };
+        let corrupted = |reason: &str| StoreError::Corrupted {
+            address: address.clone(),
+            reason: reason.to_string(),
+        };
+
+        let text = std::str::from_utf8(&contents).map_err(|_| corrupted("not UTF-8"))?;
+        let (header, json) = text.split_once('\n').ok_or_else(|| corrupted("missing checksum line"))?;
+        let checksum = header.strip_prefix("sha256:").ok_or_else(|| corrupted("missing checksum line"))?;
+        if checksum != hex::encode(Sha256::digest(json.as_bytes())) {
+            return Err(corrupted("checksum mismatch"));
+        }
+
+        let bundle = VerificationBundle::from_json(json).map_err(|e| corrupted(&e.to_string()))?;
+        check_stored(address, &bundle)?;
+        Ok(Some(bundle))
+    }
+
+    fn exists(&self, address: &ContentAddress) -> bool {
+        self.path_for(address).is_file()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::builder::ProofArtifactBuilder;
+    use crate::deterministic::DeterministicConfig;
+    use crate::provenance::{EnvironmentManifest, ModelMetadata};
+    use std::sync::Arc;
+
+    fn bundle(seed: u64) -> VerificationBundle {
+        ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test-model".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_environment(EnvironmentManifest {
+                container_image_hash: "sha256:xyz".to_string(),
+                os: "ubuntu:22.04".to_string(),
+                deps: vec![],
+                hardware: None,
+                extra: Default::default(),
+            })
+            .with_config(DeterministicConfig { seed, parameters: Default::default() })
+            .add_output("result", "sha256:result", "hash://sha256/result")
+            .build()
+            .unwrap()
+    }
+
+    fn temp_store(name: &str) -> FsBundleStore {
+        let dir = std::env::temp_dir().join(format!("bundle-store-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        FsBundleStore::open(dir).unwrap()
+    }
+
+    #[test]
+    fn test_address_parsing() {
+        let stored = bundle(1);
+        let address: ContentAddress = stored.content_address.parse().unwrap();
+        assert_eq!(address.digest().len(), 64);
+        assert_eq!(address.to_string(), stored.content_address);
+        assert_eq!(address, ContentAddress::of(&stored));
+
+        assert!(matches!("sha256:abc".parse::<ContentAddress>(), Err(StoreError::InvalidAddress(_))));
+        assert!(matches!("hash://sha256/abc".parse::<ContentAddress>(), Err(StoreError::InvalidAddress(_))));
+    }
+
+    #[test]
+    fn test_memory_store_round_trip() {
+        let store = MemoryBundleStore::new();
+        let stored = bundle(1);
+
+        let address = store.put(&stored).unwrap();
+        assert_eq!(store.put(&stored).unwrap(), address);
+        assert_eq!(store.len(), 1);
+        assert!(store.exists(&address));
+        assert_eq!(store.get(&address).unwrap().unwrap().content_address, stored.content_address);
+
+        assert!(store.get(&ContentAddress::of(&bundle(2))).unwrap().is_none());
+
+        let mut tampered = bundle(3);
+        tampered.provenance.config.seed = 4;
+        assert!(matches!(store.put(&tampered), Err(StoreError::AddressMismatch { .. })));
+    }
+
+    #[test]
+    fn test_fs_store_shards_by_prefix() {
+        let store = temp_store("shard");
+        let stored = bundle(1);
+
+        let address = store.put(&stored).unwrap();
+        let path = store.path_for(&address);
+        assert_eq!(path.parent().unwrap().file_name().unwrap().to_str().unwrap(), &address.digest()[..2]);
+        assert!(store.exists(&address));
+
+        let loaded = store.get(&address).unwrap().unwrap();
+        assert!(loaded.verify_integrity());
+        assert_eq!(loaded.to_json().unwrap(), stored.to_json().unwrap());
+
+        assert!(!store.exists(&ContentAddress::of(&bundle(2))));
+        assert!(store.get(&ContentAddress::of(&bundle(2))).unwrap().is_none());
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_fs_store_detects_corruption() {
+        let store = temp_store("corrupt");
+        let address = store.put(&bundle(1)).unwrap();
+        let path = store.path_for(&address);
+
+        // A field outside the content address, edited on disk
+        let contents = fs::read_to_string(&path).unwrap();
+        fs::write(&path, contents.replace("\"hash://sha256/result\"", "\"hash://sha256/forged\"")).unwrap();
+        assert!(matches!(store.get(&address), Err(StoreError::Corrupted { .. })));
+
+        // Truncated file
+        fs::write(&path, &contents[..contents.len() / 2]).unwrap();
+        assert!(matches!(store.get(&address), Err(StoreError::Corrupted { .. })));
+
+        // A valid file for another bundle under this address
+        let other = store.path_for(&store.put(&bundle(2)).unwrap());
+        fs::copy(&other, &path).unwrap();
+        assert!(matches!(store.get(&address), Err(StoreError::Corrupted { .. })));
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+
+    #[test]
+    fn test_fs_store_concurrent_writers() {
+        let store = Arc::new(temp_store("concurrent"));
+        let stored = bundle(1);
+
+        let writers: Vec<_> = (0..8)
+            .map(|_| {
+                let store = Arc::clone(&store);
+                let stored = stored.clone();
+                std::thread::spawn(move || store.put(&stored).unwrap())
+            })
+            .collect();
+        let addresses: Vec<_> = writers.into_iter().map(|w| w.join().unwrap()).collect();
+
+        assert!(addresses.iter().all(|a| *a == addresses[0]));
+        assert!(store.get(&addresses[0]).unwrap().unwrap().verify_integrity());
+
+        // No temp files are left behind
+        let shard = store.path_for(&addresses[0]).parent().unwrap().to_path_buf();
+        assert_eq!(fs::read_dir(shard).unwrap().count(), 1);
+        fs::remove_dir_all(store.root()).unwrap();
+    }
+}
@@ -2,13 +2,30 @@
 //!
 //! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
 
-use crate::bundle::{VerificationBundle, VerificationTest, Tolerance};
+use crate::attestation::{AttestationError, TrustedKeys};
+use crate::bundle::{OutputArtifact, VerificationBundle, VerificationTest, Tolerance};
+use crate::executor::{ExecutorError, ReplayExecutor};
+use crate::numeric::{self, NumericData};
+use crate::store::{BundleStore, ContentAddress, StoreError};
+
+/// Signature verification callback (content hash, signature)
+pub type SignatureFn = dyn Fn(&str, &str) -> bool;
 
 /// Verifier for replaying and validating bundles
+///
+/// An attestation passes only if its key ID (or signer ID) resolves to a
+/// trusted key that verifies the signature. One naming a key outside the
+/// registry fails if it embeds a public key, and otherwise falls back to
+/// the signature callback.
 pub struct Verifier {
-    /// Signature verification function
-    #[allow(dead_code)] // Used in verify() method via self
-    verify_signature: Box<dyn Fn(&str, &str) -> bool>,
+    /// Checks signatures that carry no public key and name no trusted key
+    verify_signature: Box<SignatureFn>,
+
+    /// Keys attestations must resolve to
+    trusted_keys: TrustedKeys,
+
+    /// Optional executor that regenerates outputs for replay tests
+    executor: Option<Box<dyn ReplayExecutor>>,
 }
 
 impl Verifier {
@@ -16,8 +33,36 @@ impl Verifier {
     pub fn new(verify_fn: impl Fn(&str, &str) -> bool + 'static) -> Self {
         Self {
             verify_signature: Box::new(verify_fn),
+            trusted_keys: TrustedKeys::new(),
+            executor: None,
         }
     }
+
+    /// Accept attestations signed by these keys
+    pub fn with_trusted_keys(mut self, trusted_keys: TrustedKeys) -> Self {
+        self.trusted_keys = trusted_keys;
+        self
+    }
+
+    /// Replay outputs through an executor instead of trusting declared outputs
+    pub fn with_executor(mut self, executor: Box<dyn ReplayExecutor>) -> Self {
+        self.executor = Some(executor);
+        self
+    }
+    
+    /// Fetch the bundle at `address` from `store` and verify it
+    ///
+    /// A missing or corrupted bundle is an error rather than a failed result.
+    pub fn verify_by_address(
+        &self,
+        store: &dyn BundleStore,
+        address: &ContentAddress,
+    ) -> Result<VerificationResult, StoreError> {
+        let bundle = store
+            .get(address)?
+            .ok_or_else(|| StoreError::NotFound(address.clone()))?;
+        Ok(self.verify(&bundle))
+    }
     
     /// Verify a bundle
     pub fn verify(&self, bundle: &VerificationBundle) -> VerificationResult {
@@ -35,18 +80,47 @@ impl Verifier {
             return result;
         }
         
-        // Verify signatures
-        for sig in &bundle.signatures {
-            // In production, would verify actual cryptographic signatures
-            // For now, we check structure
+        // Verify signatures over the content address
+        for (index, sig) in bundle.signatures.iter().enumerate() {
             if sig.signer_id.is_empty() {
                 result.warnings.push("Empty signer ID".to_string());
             }
+            
+            let verified = if self.trusted_keys.contains(sig.resolved_key_id()) {
+                sig.verify_trusted(&bundle.content_address, &self.trusted_keys)
+                    .map_err(|e| e.to_string())
+            } else if sig.public_key.is_some() {
+                Err(AttestationError::UntrustedKey(sig.resolved_key_id().to_string()).to_string())
+            } else if (self.verify_signature)(&bundle.content_address, &sig.signature) {
+                Ok(())
+            } else {
+                Err(format!("Signature from {} rejected", sig.signer_id))
+            };
+            if let Err(err) = verified {
+                result.passed = false;
+                result.errors.push(format!("Signature {}: {}", index, err));
+            }
         }
         
+        let unknown = bundle.unknown_fields();
+        if !unknown.is_empty() {
+            result.warnings.push(format!(
+                "Bundle carries fields this verifier does not interpret: {}",
+                unknown.join(", ")
+            ));
+        }
+        
+        // Regenerate outputs once if any replay test needs them
+        let has_replay = bundle.tests.iter()
+            .any(|t| t.test_type == crate::bundle::TestType::Replay);
+        let replayed = match self.executor {
+            Some(ref executor) if has_replay => Some(executor.execute(bundle)),
+            _ => None,
+        };
+        
         // Run verification tests
         for test in &bundle.tests {
-            let test_result = self.run_test(bundle, test);
+            let test_result = self.run_test(bundle, test, replayed.as_ref());
             result.test_results.push(test_result.clone());
             
             if !test_result.passed {
@@ -58,11 +132,25 @@ impl Verifier {
     }
     
     /// Run a single test
-    fn run_test(&self, bundle: &VerificationBundle, test: &VerificationTest) -> TestResult {
+    fn run_test(
+        &self,
+        bundle: &VerificationBundle,
+        test: &VerificationTest,
+        replayed: Option<&Result<Vec<OutputArtifact>, ExecutorError>>,
+    ) -> TestResult {
         match test.test_type {
             crate::bundle::TestType::Replay => {
                 // Replay test - check if outputs match expected
-                self.test_replay(bundle, test)
+                match replayed {
+                    Some(Ok(outputs)) => self.test_replay(outputs, test),
+                    Some(Err(err)) => TestResult {
+                        test_name: test.name.clone(),
+                        passed: false,
+                        message: format!("Replay execution failed: {}", err),
+                        executor_error: Some(err.clone()),
+                    },
+                    None => self.test_replay(&bundle.outputs, test),
+                }
             }
             crate::bundle::TestType::Determinism => {
                 // Determinism test - verify config has deterministic settings
@@ -80,22 +168,20 @@ impl Verifier {
     }
     
     /// Test replay
-    fn test_replay(&self, bundle: &VerificationBundle, test: &VerificationTest) -> TestResult {
+    fn test_replay(&self, outputs: &[OutputArtifact], test: &VerificationTest) -> TestResult {
         // Find matching output
-        let output = bundle.outputs.iter()
+        let output = outputs.iter()
             .find(|o| o.name == test.name || o.hash == test.expected_output_hash);
         
         match output {
             Some(out) => {
-                let passed = match &test.tolerance {
-                    Tolerance::Exact => out.hash == test.expected_output_hash,
-                    Tolerance::Hash => out.hash == test.expected_output_hash,
-                    Tolerance::Float { .. } => {
-                        // For float tolerance, would need to decode and compare
-                        // For now, treat as hash match
-                        out.hash == test.expected_output_hash
+                if let Tolerance::Float { relative, absolute, nan } = test.tolerance {
+                    // Identical bytes need no decoding
+                    if out.hash != test.expected_output_hash {
+                        return self.test_float(out, test, relative, absolute, nan);
                     }
-                };
+                }
+                let passed = out.hash == test.expected_output_hash;
                 
                 TestResult {
                     test_name: test.name.clone(),
@@ -106,16 +192,60 @@ impl Verifier {
                         format!("Output hash {} does not match expected {}", 
                                 out.hash, test.expected_output_hash)
                     },
+                    executor_error: None,
                 }
             }
             None => TestResult {
                 test_name: test.name.clone(),
                 passed: false,
                 message: "Output not found".to_string(),
+                executor_error: None,
             }
         }
     }
     
+    /// Compare decoded output values against the test's expected values
+    fn test_float(
+        &self,
+        out: &OutputArtifact,
+        test: &VerificationTest,
+        relative: f64,
+        absolute: f64,
+        nan: numeric::NanPolicy,
+    ) -> TestResult {
+        let failed = |message: String| TestResult {
+            test_name: test.name.clone(),
+            passed: false,
+            message,
+            executor_error: None,
+        };
+        
+        let Some(expected) = &test.expected else {
+            return failed(format!(
+                "Output hash {} does not match expected {} and no expected values are declared",
+                out.hash, test.expected_output_hash
+            ));
+        };
+        let actual = match &out.data {
+            Some(data) => data.clone(),
+            None => NumericData::Uri(out.uri.clone()),
+        };
+        
+        let comparison = actual.values()
+            .and_then(|a| expected.values().map(|e| (a, e)))
+            .and_then(|(a, e)| numeric::compare(a, e, relative, absolute, nan));
+        
+        match comparison {
+            Ok(comparison) => TestResult {
+                test_name: test.name.clone(),
+                passed: comparison.passed(),
+                message: comparison.summary(),
+                executor_error: None,
+            },
+            Err(err) => failed(format!("Numeric comparison failed: {}", err)),
+        }
+    }
+    
     /// Test determinism
     fn test_determinism(&self, bundle: &VerificationBundle, _test: &VerificationTest) -> TestResult {
         let config = &bundle.provenance.config;
@@ -131,6 +261,7 @@ impl Verifier {
             } else {
                 "Configuration may not be deterministic".to_string()
             },
+            executor_error: None,
         }
     }
     
@@ -142,6 +273,7 @@ impl Verifier {
             test_name: test.name.clone(),
             passed: true,
             message: "Invariant check passed".to_string(),
+            executor_error: None,
         }
     }
     
@@ -152,6 +284,7 @@ impl Verifier {
             test_name: test.name.clone(),
             passed: true,
             message: "Stability check passed".to_string(),
+            executor_error: None,
         }
     }
 }
@@ -183,6 +316,9 @@ pub struct TestResult {
     
     /// Result message
     pub message: String,
+    
+    /// Structured executor failure, if replay execution failed
+    pub executor_error: Option<ExecutorError>,
 }
 
 #[cfg(test)]
@@ -205,6 +341,7 @@ mod tests {
             weights_hash: "sha256:abc".to_string(),
             tokenizer_hash: "sha256:def".to_string(),
             card_uri: None,
+            extra: Default::default(),
         };
         
         let env = EnvironmentManifest {
@@ -212,6 +349,7 @@ mod tests {
             os: "ubuntu:22.04".to_string(),
             deps: vec![],
             hardware: None,
+            extra: Default::default(),
         };
         
         let config = DeterministicConfig {
@@ -233,5 +371,176 @@ mod tests {
         
         assert!(result.passed);
     }
+    
+    #[test]
+    fn test_verifier_checks_signatures() {
+        use crate::attestation::{Ed25519Signer, SignerRole};
+        
+        let signer = Ed25519Signer::from_seed("did:key:system", SignerRole::System, &[9; 32]).unwrap();
+        let mut bundle = ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_environment(EnvironmentManifest {
+                container_image_hash: "sha256:xyz".to_string(),
+                os: "ubuntu:22.04".to_string(),
+                deps: vec![],
+                hardware: None,
+                extra: Default::default(),
+            })
+            .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+            .sign_with(signer)
+            .build()
+            .unwrap();
+        
+        // A valid signature from a key nobody registered is not enough
+        let result = Verifier::new(|_: &str, _: &str| true).verify(&bundle);
+        assert!(!result.passed);
+        assert_eq!(result.errors, vec!["Signature 0: Key did:key:system is not a trusted signing key"]);
+        
+        let trusted = TrustedKeys::new()
+            .with_signer(&Ed25519Signer::from_seed("did:key:system", SignerRole::System, &[9; 32]).unwrap());
+        let verifier = Verifier::new(|_: &str, _: &str| false).with_trusted_keys(trusted);
+        assert!(verifier.verify(&bundle).passed);
+        
+        // Signature values are outside the content address; only the key check catches this
+        let mut forged = bundle.signatures[0].signature.clone().into_bytes();
+        forged[0] = if forged[0] == b'A' { b'B' } else { b'A' };
+        bundle.signatures[0].signature = String::from_utf8(forged).unwrap();
+        
+        let result = verifier.verify(&bundle);
+        assert!(!result.passed);
+        assert!(result.errors[0].starts_with("Signature 0:"), "{:?}", result.errors);
+    }
+    
+    #[test]
+    fn test_verifier_rejects_self_signed_forgery() {
+        use crate::attestation::{Ed25519Signer, SignerRole};
+        
+        let build = |os: &str, signer: Ed25519Signer| {
+            ProofArtifactBuilder::new()
+                .with_model(ModelMetadata {
+                    name: "test".to_string(),
+                    version: "1.0.0".to_string(),
+                    weights_hash: "sha256:abc".to_string(),
+                    tokenizer_hash: "sha256:def".to_string(),
+                    card_uri: None,
+                    extra: Default::default(),
+                })
+                .with_environment(EnvironmentManifest {
+                    container_image_hash: "sha256:xyz".to_string(),
+                    os: os.to_string(),
+                    deps: vec![],
+                    hardware: None,
+                    extra: Default::default(),
+                })
+                .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+                .sign_with(signer)
+                .build()
+                .unwrap()
+        };
+        let system = || Ed25519Signer::from_seed("did:key:system", SignerRole::System, &[9; 32]).unwrap();
+        let verifier = Verifier::new(mock_verify).with_trusted_keys(TrustedKeys::new().with_signer(&system()));
+        assert!(verifier.verify(&build("ubuntu:22.04", system())).passed);
+        
+        // Tampered contents, recomputed address, re-signed under the same signer ID with another key
+        let impostor = Ed25519Signer::from_seed("did:key:system", SignerRole::System, &[7; 32]).unwrap();
+        let forged = build("attacker:latest", impostor);
+        assert!(forged.verify_integrity());
+        assert!(forged.signatures[0].verify(&forged.content_address).is_ok());
+        
+        let result = verifier.verify(&forged);
+        assert!(!result.passed);
+        assert_eq!(result.errors, vec!["Signature 0: Key did:key:system is not a trusted signing key"]);
+    }
+    
+    #[test]
+    fn test_float_tolerance_compares_values() {
+        let tolerance = Tolerance::Float {
+            relative: 1e-6,
+            absolute: 1e-9,
+            nan: crate::numeric::NanPolicy::Strict,
+        };
+        let build = |actual: Vec<f64>| {
+            ProofArtifactBuilder::new()
+                .with_model(ModelMetadata {
+                    name: "test".to_string(),
+                    version: "1.0.0".to_string(),
+                    weights_hash: "sha256:abc".to_string(),
+                    tokenizer_hash: "sha256:def".to_string(),
+                    card_uri: None,
+                    extra: Default::default(),
+                })
+                .with_environment(EnvironmentManifest {
+                    container_image_hash: "sha256:xyz".to_string(),
+                    os: "ubuntu:22.04".to_string(),
+                    deps: vec![],
+                    hardware: None,
+                    extra: Default::default(),
+                })
+                .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+                .add_numeric_output("logits", actual)
+                .add_numeric_test(
+                    "logits",
+                    "sha256:reference",
+                    NumericData::Inline(vec![0.5, 1.25, -3.0]),
+                    tolerance.clone(),
+                )
+                .build()
+                .unwrap()
+        };
+        let verifier = Verifier::new(mock_verify);
+        
+        let result = verifier.verify(&build(vec![0.500_000_1, 1.25, -3.0]));
+        assert!(result.passed, "{}", result.test_results[0].message);
+        assert!(result.test_results[0].message.contains("max deviation"));
+        
+        let result = verifier.verify(&build(vec![0.5, 1.3, -3.0]));
+        assert!(!result.passed);
+        assert!(result.test_results[0].message.contains("at index 1"));
+    }
+    
+    #[test]
+    fn test_verify_by_address() {
+        use crate::store::MemoryBundleStore;
+        
+        let store = MemoryBundleStore::new();
+        let bundle = ProofArtifactBuilder::new()
+            .with_model(ModelMetadata {
+                name: "test".to_string(),
+                version: "1.0.0".to_string(),
+                weights_hash: "sha256:abc".to_string(),
+                tokenizer_hash: "sha256:def".to_string(),
+                card_uri: None,
+                extra: Default::default(),
+            })
+            .with_environment(EnvironmentManifest {
+                container_image_hash: "sha256:xyz".to_string(),
+                os: "ubuntu:22.04".to_string(),
+                deps: vec![],
+                hardware: None,
+                extra: Default::default(),
+            })
+            .with_config(DeterministicConfig { seed: 42, parameters: Default::default() })
+            .add_output("result", "sha256:expected", "hash://sha256/expected")
+            .add_test("replay", TestType::Replay, "sha256:expected", Tolerance::Exact)
+            .build_and_store(&store)
+            .unwrap();
+        let verifier = Verifier::new(mock_verify);
+        
+        let address: ContentAddress = bundle.content_address.parse().unwrap();
+        assert!(verifier.verify_by_address(&store, &address).unwrap().passed);
+        
+        let missing: ContentAddress = format!("hash://sha256/{}", "0".repeat(64)).parse().unwrap();
+        assert!(matches!(
+            verifier.verify_by_address(&store, &missing),
+            Err(StoreError::NotFound(_))
+        ));
+    }
 }
 
@@ -0,0 +1,66 @@
+//! Signed attestation chain fixtures
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use serde::Deserialize;
+use verification::attestation::SignerRole;
+use verification::{AttestationChain, AttestationError, Ed25519Signer, Signer};
+
+#[derive(Deserialize)]
+struct Fixture {
+    subject: String,
+    chain: AttestationChain,
+}
+
+fn load(name: &str) -> Fixture {
+    let path = format!("{}/tests/fixtures/{}", env!("CARGO_MANIFEST_DIR"), name);
+    let json = std::fs::read_to_string(path).unwrap();
+    serde_json::from_str(&json).unwrap()
+}
+
+#[test]
+fn valid_chain_verifies() {
+    let fixture = load("attestation_chain_valid.json");
+    assert!(fixture.chain.verify_integrity());
+    assert_eq!(fixture.chain.verify(&fixture.subject), Ok(()));
+}
+
+#[test]
+fn valid_chain_rejects_other_subject() {
+    let fixture = load("attestation_chain_valid.json");
+    let err = fixture.chain.verify("hash://sha256/other").unwrap_err();
+    assert!(matches!(err, AttestationError::LinkSignature { link: 0, .. }), "{}", err);
+}
+
+#[test]
+fn tampered_chain_names_broken_link() {
+    // Link 1's statement was rewritten and every digest recomputed, so the
+    // hash links still agree; only link 2's signature exposes the edit
+    let fixture = load("attestation_chain_tampered.json");
+    assert!(fixture.chain.verify_integrity());
+
+    let err = fixture.chain.verify(&fixture.subject).unwrap_err();
+    assert_eq!(
+        err,
+        AttestationError::LinkSignature {
+            link: 2,
+            source: Box::new(AttestationError::InvalidSignature("did:key:auditor-01".to_string())),
+        }
+    );
+    assert_eq!(
+        err.to_string(),
+        "Chain link 2: Signature from did:key:auditor-01 does not verify"
+    );
+}
+
+#[test]
+fn fixture_keys_match_seeded_signers() {
+    use base64::{engine::general_purpose::STANDARD, Engine as _};
+
+    let fixture = load("attestation_chain_valid.json");
+    let system = Ed25519Signer::from_seed("did:key:axiom-system", SignerRole::System, &[1; 32]).unwrap();
+    assert_eq!(
+        fixture.chain.root.public_key.as_deref(),
+        Some(STANDARD.encode(system.public_key()).as_str())
+    );
+}
@@ -0,0 +1,83 @@
+//! Replay harness workloads
+//!
+//! [AXIOMHIVE PROJECTION - SUBSTRATE: ALEXIS ADAMS]
+
+use std::sync::atomic::{AtomicU64, Ordering};
+use verification::bundle::TestType;
+use verification::provenance::{EnvironmentManifest, ModelMetadata};
+use verification::{DeterministicConfig, ProofArtifactBuilder, ReplayError, ReplayHarness, SeedControl, Verifier};
+
+fn config(seed: u64) -> DeterministicConfig {
+    DeterministicConfig {
+        seed,
+        parameters: Default::default(),
+    }
+}
+
+/// Sixteen values from a PRNG seeded by the configuration
+fn seeded_workload(config: &DeterministicConfig) -> Vec<u8> {
+    let mut rng = SeedControl::new(config.seed);
+    (0..16).flat_map(|_| rng.next_value().to_le_bytes()).collect()
+}
+
+#[test]
+fn seeded_workload_yields_replay_test() {
+    let outcome = ReplayHarness::new("sampled_tokens", config(42), seeded_workload)
+        .run()
+        .unwrap();
+
+    assert_eq!(outcome.content.len(), 16 * 8);
+    assert_eq!(outcome.test.test_type, TestType::Replay);
+    assert_eq!(outcome.test.expected_output_hash, outcome.output.hash);
+    assert!(outcome.output.hash.starts_with("sha256:"));
+
+    // A different seed is still deterministic, but hashes differently
+    let other = ReplayHarness::new("sampled_tokens", config(7), seeded_workload)
+        .run()
+        .unwrap();
+    assert_ne!(other.output.hash, outcome.output.hash);
+
+    let bundle = ProofArtifactBuilder::new()
+        .with_model(ModelMetadata {
+            name: "test-model".to_string(),
+            version: "1.0.0".to_string(),
+            weights_hash: "sha256:abc".to_string(),
+            tokenizer_hash: "sha256:def".to_string(),
+            card_uri: None,
+            extra: Default::default(),
+        })
+        .with_environment(EnvironmentManifest {
+            container_image_hash: "sha256:xyz".to_string(),
+            os: "ubuntu:22.04".to_string(),
+            deps: vec![],
+            hardware: None,
+            extra: Default::default(),
+        })
+        .add_replay(outcome)
+        .build()
+        .unwrap();
+
+    assert_eq!(bundle.provenance.config.seed, 42);
+    let result = Verifier::new(|_, _| true).verify(&bundle);
+    assert!(result.passed, "{:?}", result.errors);
+    assert!(result.test_results.iter().all(|t| t.passed));
+}
+
+#[test]
+fn nondeterministic_workload_is_rejected() {
+    // Mixes a call counter into the output, as leaked global state would
+    let calls = AtomicU64::new(0);
+    let workload = |config: &DeterministicConfig| {
+        let mut output = seeded_workload(config);
+        output[20] ^= calls.fetch_add(1, Ordering::SeqCst) as u8;
+        output
+    };
+
+    let err = ReplayHarness::new("leaky", config(42), workload).run().unwrap_err();
+    let ReplayError::Nondeterministic { name, seed, divergence } = err.clone();
+    assert_eq!(name, "leaky");
+    assert_eq!(seed, 42);
+    assert_eq!(divergence.first_difference, 20);
+    assert_eq!((divergence.first_len, divergence.second_len), (128, 128));
+    assert!(err.to_string().contains("first difference at byte 20"));
+}